@@ -9,6 +9,7 @@ use tokio::runtime::Runtime;
 
 mod sol_val_calc_arg;
 mod subcmd;
+mod valuation_mode_arg;
 
 use subcmd::Subcmd;
 
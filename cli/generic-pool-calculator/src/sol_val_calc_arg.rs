@@ -7,6 +7,7 @@ use spl_calculator_lib::{
 
 #[derive(Clone, Copy, Debug)]
 pub enum SolValCalcArg {
+    Everstake,
     Lido,
     Marinade,
     SanctumSpl,
@@ -18,6 +19,7 @@ pub enum SolValCalcArg {
 
 impl SolValCalcArg {
     pub const HELP_STR: &'static str = "A SOL Value Calculator Program. Can either be a program ID pubkey or one of the following known programs:
+- everstake
 - lido
 - marinade
 - sanctum-spl
@@ -27,6 +29,7 @@ impl SolValCalcArg {
 
     pub fn parse_arg(arg: &str) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
         Ok(match arg {
+            "everstake" => Self::Everstake,
             "lido" => Self::Lido,
             "marinade" => Self::Marinade,
             "sanctum-spl" => Self::SanctumSpl,
@@ -36,6 +39,7 @@ impl SolValCalcArg {
             _ => {
                 let pk = Pubkey::from_str(arg)?;
                 match pk {
+                    everstake_calculator_lib::program::ID => Self::Everstake,
                     lido_calculator_lib::program::ID => Self::Lido,
                     marinade_calculator_lib::program::ID => Self::Marinade,
                     sanctum_spl_sol_val_calc_program::ID => Self::SanctumSpl,
@@ -50,6 +54,7 @@ impl SolValCalcArg {
 
     pub fn program_id(&self) -> Pubkey {
         match self {
+            Self::Everstake => everstake_calculator_lib::program::ID,
             Self::Lido => lido_calculator_lib::program::ID,
             Self::Marinade => marinade_calculator_lib::program::ID,
             Self::SanctumSpl => sanctum_spl_sol_val_calc_program::ID,
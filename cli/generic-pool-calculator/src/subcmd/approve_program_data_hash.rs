@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+
+use clap::Args;
+use data_encoding::HEXLOWER;
+use generic_pool_calculator_interface::{
+    set_approved_program_data_hash_ix_with_program_id, SetApprovedProgramDataHashIxArgs,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::SetApprovedProgramDataHashFreeArgs,
+    pda::CalculatorStateFindPdaArgs,
+    utils::{executable_hash, try_calculator_state},
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::keyed::Keyed;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Approves the hash of a local stake pool program .so file so that anyone can update the last upgrade slot once the pool program is upgraded to it"
+)]
+pub struct ApproveProgramDataHashArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(help = "Path to the reviewed stake pool program .so file")]
+    pub so_file: PathBuf,
+}
+
+impl ApproveProgramDataHashArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { manager, so_file } = match args.subcmd {
+            Subcmd::ApproveProgramDataHash(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program.program_id();
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let executable = std::fs::read(so_file).unwrap();
+        let approved_program_data_hash = executable_hash(&executable);
+        eprintln!(
+            "Approving program data hash {}",
+            HEXLOWER.encode(&approved_program_data_hash)
+        );
+
+        let state_pda = CalculatorStateFindPdaArgs { program_id }
+            .get_calculator_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_calculator_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let keys = SetApprovedProgramDataHashFreeArgs {
+            state: Keyed {
+                pubkey: state_pda,
+                account: state_acc,
+            },
+        }
+        .resolve_for_prog(program_id)
+        .unwrap();
+        let ix = set_approved_program_data_hash_ix_with_program_id(
+            program_id,
+            keys,
+            SetApprovedProgramDataHashIxArgs {
+                approved_program_data_hash,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -2,6 +2,7 @@ use std::convert::Infallible;
 
 use borsh::BorshDeserialize;
 use data_encoding::BASE64;
+use everstake_calculator_lib::EverstakeLstSolCommonFreeArgsConst;
 use generic_pool_calculator_interface::CalculatorState;
 use lido_calculator_lib::lido_sol_val_calc_account_metas;
 use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
@@ -42,6 +43,19 @@ pub async fn lst_sol_common_account_metas(
         SolValCalcArg::Lido => lido_sol_val_calc_account_metas().to_vec(),
         SolValCalcArg::Marinade => marinade_sol_val_calc_account_metas().to_vec(),
         SolValCalcArg::Wsol => WSOL_LST_SOL_COMMON_METAS.to_vec(),
+        SolValCalcArg::Everstake => {
+            let pool = pool.expect("pool pubkey must be provided for everstake");
+            let pool_acc = rpc.get_account(&pool).await.unwrap();
+            EverstakeLstSolCommonFreeArgsConst {
+                everstake_stake_pool: Keyed {
+                    account: pool_acc,
+                    pubkey: pool,
+                },
+            }
+            .resolve_to_account_metas()
+            .unwrap()
+            .to_vec()
+        }
         SolValCalcArg::Spl | SolValCalcArg::SanctumSpl | SolValCalcArg::SanctumSplMulti => {
             let pool =
                 pool.expect("pool pubkey must be provided for spl, sanctum-spl, sanctum-spl-multi");
@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+use clap::Args;
+use data_encoding::HEXLOWER;
+use generic_pool_calculator_lib::utils::executable_hash;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Computes the hash of a local stake pool program .so file that would be pinned by approve-program-data-hash"
+)]
+pub struct ComputeProgramDataHashArgs {
+    #[arg(help = "Path to the stake pool program's .so file")]
+    pub so_file: PathBuf,
+}
+
+impl ComputeProgramDataHashArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { so_file } = match args.subcmd {
+            Subcmd::ComputeProgramDataHash(a) => a,
+            _ => unreachable!(),
+        };
+        let executable = std::fs::read(so_file).unwrap();
+        println!("{}", HEXLOWER.encode(&executable_hash(&executable)));
+    }
+}
@@ -0,0 +1,57 @@
+use clap::Args;
+use generic_pool_calculator_interface::migrate_state_ix_with_program_id;
+use generic_pool_calculator_lib::{
+    account_resolvers::MigrateStateFreeArgs, pda::CalculatorStateFindPdaArgs,
+    utils::try_calculator_state, LEGACY_CALCULATOR_STATE_SIZES,
+};
+use s_cli_utils::handle_tx_full;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Migrates the SOL value calculator program's state from a legacy layout to the current layout. Permissionless."
+)]
+pub struct MigrateStateArgs;
+
+impl MigrateStateArgs {
+    pub async fn run(args: crate::Args) {
+        let Self = match args.subcmd {
+            Subcmd::MigrateState(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program.program_id();
+
+        let state_pda = CalculatorStateFindPdaArgs { program_id }
+            .get_calculator_state_address_and_bump_seed()
+            .0;
+        let state_data = rpc.get_account_data(&state_pda).await.unwrap();
+        if !LEGACY_CALCULATOR_STATE_SIZES.contains(&state_data.len()) {
+            eprintln!("State PDA {state_pda} already migrated:");
+            let state = try_calculator_state(&state_data).unwrap();
+            eprintln!("{state:#?}");
+            return;
+        }
+
+        let ix = migrate_state_ix_with_program_id(
+            program_id,
+            MigrateStateFreeArgs {
+                payer: payer.pubkey(),
+            }
+            .resolve_for_prog(program_id),
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref()],
+        )
+        .await;
+    }
+}
@@ -1,9 +1,18 @@
 use clap::Subcommand;
 
+mod approve_program_data_hash;
 mod common;
+mod compute_program_data_hash;
 mod init;
 mod lst_to_sol;
+mod migrate_state;
+mod permissionless_update_last_upgrade_slot;
+mod record_withdrawal_fees;
+mod set_delayed_unstake_fallback;
 mod set_manager;
+mod set_stale_discount;
+mod set_valuation_mode;
+mod set_withdrawal_fee_guard;
 mod sol_to_lst;
 mod update_last_upgrade_slot;
 mod view;
@@ -11,7 +20,14 @@ mod view;
 use init::InitArgs;
 
 use self::{
-    lst_to_sol::LstToSolArgs, set_manager::SetManagerArgs, sol_to_lst::SolToLstArgs,
+    approve_program_data_hash::ApproveProgramDataHashArgs,
+    compute_program_data_hash::ComputeProgramDataHashArgs, lst_to_sol::LstToSolArgs,
+    migrate_state::MigrateStateArgs,
+    permissionless_update_last_upgrade_slot::PermissionlessUpdateLastUpgradeSlotArgs,
+    record_withdrawal_fees::RecordWithdrawalFeesArgs,
+    set_delayed_unstake_fallback::SetDelayedUnstakeFallbackArgs, set_manager::SetManagerArgs,
+    set_stale_discount::SetStaleDiscountArgs, set_valuation_mode::SetValuationModeArgs,
+    set_withdrawal_fee_guard::SetWithdrawalFeeGuardArgs, sol_to_lst::SolToLstArgs,
     update_last_upgrade_slot::UpdateLastUpgradeSlotArgs, view::ViewArgs,
 };
 
@@ -23,6 +39,15 @@ pub enum Subcmd {
     View(ViewArgs),
     SolToLst(SolToLstArgs),
     LstToSol(LstToSolArgs),
+    SetStaleDiscount(SetStaleDiscountArgs),
+    MigrateState(MigrateStateArgs),
+    ComputeProgramDataHash(ComputeProgramDataHashArgs),
+    ApproveProgramDataHash(ApproveProgramDataHashArgs),
+    PermissionlessUpdateLastUpgradeSlot(PermissionlessUpdateLastUpgradeSlotArgs),
+    SetValuationMode(SetValuationModeArgs),
+    SetDelayedUnstakeFallback(SetDelayedUnstakeFallbackArgs),
+    SetWithdrawalFeeGuard(SetWithdrawalFeeGuardArgs),
+    RecordWithdrawalFees(RecordWithdrawalFeesArgs),
 }
 
 impl Subcmd {
@@ -34,6 +59,17 @@ impl Subcmd {
             Self::View(_) => ViewArgs::run(args).await,
             Self::SolToLst(_) => SolToLstArgs::run(args).await,
             Self::LstToSol(_) => LstToSolArgs::run(args).await,
+            Self::SetStaleDiscount(_) => SetStaleDiscountArgs::run(args).await,
+            Self::MigrateState(_) => MigrateStateArgs::run(args).await,
+            Self::ComputeProgramDataHash(_) => ComputeProgramDataHashArgs::run(args).await,
+            Self::ApproveProgramDataHash(_) => ApproveProgramDataHashArgs::run(args).await,
+            Self::PermissionlessUpdateLastUpgradeSlot(_) => {
+                PermissionlessUpdateLastUpgradeSlotArgs::run(args).await
+            }
+            Self::SetValuationMode(_) => SetValuationModeArgs::run(args).await,
+            Self::SetDelayedUnstakeFallback(_) => SetDelayedUnstakeFallbackArgs::run(args).await,
+            Self::SetWithdrawalFeeGuard(_) => SetWithdrawalFeeGuardArgs::run(args).await,
+            Self::RecordWithdrawalFees(_) => RecordWithdrawalFeesArgs::run(args).await,
         }
     }
 }
@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use data_encoding::HEXLOWER;
+use generic_pool_calculator_interface::permissionless_update_last_upgrade_slot_ix_with_program_id;
+use generic_pool_calculator_lib::{
+    account_resolvers::PermissionlessUpdateLastUpgradeSlotFreeArgs,
+    pda::CalculatorStateFindPdaArgs,
+    utils::{read_stake_pool_progdata_hash, try_calculator_state},
+};
+use s_cli_utils::handle_tx_full;
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::pubkey::Pubkey;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Updates the SOL value calculator program's pool program last upgrade slot to the current one if the pool program's current program data matches the approved hash. Permissionless."
+)]
+pub struct PermissionlessUpdateLastUpgradeSlotArgs {
+    #[arg(
+        help = "Pubkey of the pool program this calculator program works for.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub pool_program_id: Pubkey,
+}
+
+impl PermissionlessUpdateLastUpgradeSlotArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { pool_program_id } = match args.subcmd {
+            Subcmd::PermissionlessUpdateLastUpgradeSlot(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program.program_id();
+
+        let pool_program = rpc.get_account(&pool_program_id).await.unwrap();
+        let keys = PermissionlessUpdateLastUpgradeSlotFreeArgs {
+            pool_program: Keyed {
+                pubkey: pool_program_id,
+                account: pool_program,
+            },
+        }
+        .resolve_for_prog(program_id)
+        .unwrap();
+
+        let state_pda = CalculatorStateFindPdaArgs { program_id }
+            .get_calculator_state_address_and_bump_seed()
+            .0;
+        let state_data = rpc.get_account_data(&state_pda).await.unwrap();
+        let state = try_calculator_state(&state_data).unwrap();
+        let pool_progdata = rpc.get_account(&keys.pool_program_data).await.unwrap();
+        let current_hash = read_stake_pool_progdata_hash(&pool_progdata).unwrap();
+        if current_hash != state.approved_program_data_hash {
+            eprintln!(
+                "Current program data hash {} does not match approved hash {}",
+                HEXLOWER.encode(&current_hash),
+                HEXLOWER.encode(&state.approved_program_data_hash),
+            );
+            std::process::exit(-1);
+        }
+
+        let ix =
+            permissionless_update_last_upgrade_slot_ix_with_program_id(program_id, keys).unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,70 @@
+use clap::Args;
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use s_cli_utils::handle_tx_full;
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use spl_calculator_interface::{
+    record_withdrawal_fees_ix_with_program_id, RecordWithdrawalFeesKeys,
+};
+use spl_calculator_lib::{
+    RecordWithdrawalFeesFreeArgs, SanctumSplMultiSolValCalc, SanctumSplSolValCalc, SplSolValCalc,
+};
+
+use crate::sol_val_calc_arg::SolValCalcArg;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Records a stake pool's current stake withdrawal fees in its SplPoolConfig, starting a new fee change window if they changed. Permissionless. Only works for the spl, sanctum-spl and sanctum-spl-multi programs."
+)]
+pub struct RecordWithdrawalFeesArgs {
+    #[arg(help = "The stake pool whose fees to record")]
+    pub pool: Pubkey,
+}
+
+impl RecordWithdrawalFeesArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { pool } = match args.subcmd {
+            Subcmd::RecordWithdrawalFees(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program.program_id();
+
+        let pool_acc = rpc.get_account(&pool).await.unwrap();
+        let free_args = RecordWithdrawalFeesFreeArgs {
+            pool_state: Keyed {
+                pubkey: pool,
+                account: pool_acc,
+            },
+        };
+        let keys = match args.program {
+            SolValCalcArg::Spl => resolve::<SplSolValCalc>(free_args),
+            SolValCalcArg::SanctumSpl => resolve::<SanctumSplSolValCalc>(free_args),
+            SolValCalcArg::SanctumSplMulti => resolve::<SanctumSplMultiSolValCalc>(free_args),
+            _ => {
+                eprintln!("{program_id} is not an SPL calculator program");
+                std::process::exit(-1);
+            }
+        };
+        let ix = record_withdrawal_fees_ix_with_program_id(program_id, keys).unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref()],
+        )
+        .await;
+    }
+}
+
+fn resolve<P: GenericPoolSolValCalc>(
+    free_args: RecordWithdrawalFeesFreeArgs<Keyed<Account>>,
+) -> RecordWithdrawalFeesKeys {
+    free_args.resolve::<P>().unwrap().0
+}
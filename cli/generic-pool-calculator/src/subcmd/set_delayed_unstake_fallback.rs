@@ -0,0 +1,84 @@
+use clap::{ArgAction, Args};
+use generic_pool_calculator_lib::{pda::CalculatorStateFindPdaArgs, utils::try_calculator_state};
+use marinade_calculator_interface::SetDelayedUnstakeFallbackIxArgs;
+use marinade_calculator_lib::{
+    marinade_set_delayed_unstake_fallback_ix, MarinadeSolValCalc, SetDelayedUnstakeFallbackFreeArgs,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::keyed::Keyed;
+
+use crate::sol_val_calc_arg::SolValCalcArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets whether the marinade calculator program values mSOL through marinade's delayed unstake path when stake account withdrawal is disabled. Only works for the marinade program."
+)]
+pub struct SetDelayedUnstakeFallbackArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        action = ArgAction::Set,
+        help = "true to enable the delayed unstake fallback, false to disable it"
+    )]
+    pub enabled: bool,
+}
+
+impl SetDelayedUnstakeFallbackArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { manager, enabled } = match args.subcmd {
+            Subcmd::SetDelayedUnstakeFallback(a) => a,
+            _ => unreachable!(),
+        };
+        let program_id = args.program.program_id();
+        if !matches!(args.program, SolValCalcArg::Marinade) {
+            eprintln!("{program_id} is not the marinade calculator program");
+            std::process::exit(-1);
+        }
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = CalculatorStateFindPdaArgs { program_id }
+            .get_calculator_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_calculator_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let keys = SetDelayedUnstakeFallbackFreeArgs {
+            payer: payer.pubkey(),
+            state: Keyed {
+                pubkey: state_pda,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap();
+        let ix = marinade_set_delayed_unstake_fallback_ix(
+            keys,
+            SetDelayedUnstakeFallbackIxArgs { enabled },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
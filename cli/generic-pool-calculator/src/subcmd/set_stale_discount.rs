@@ -0,0 +1,80 @@
+use clap::Args;
+use generic_pool_calculator_interface::{
+    set_stale_discount_ix_with_program_id, SetStaleDiscountIxArgs,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::SetStaleDiscountFreeArgs, pda::CalculatorStateFindPdaArgs,
+    utils::try_calculator_state,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::keyed::Keyed;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets the discount in bips applied when valuing an LST whose pool was last updated an epoch ago. 0 disables stale valuation."
+)]
+pub struct SetStaleDiscountArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(help = "The stale discount to set, in bips. Must be less than 10000.")]
+    pub stale_discount_bps: u16,
+}
+
+impl SetStaleDiscountArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            stale_discount_bps,
+        } = match args.subcmd {
+            Subcmd::SetStaleDiscount(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program.program_id();
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = CalculatorStateFindPdaArgs { program_id }
+            .get_calculator_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_calculator_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let keys = SetStaleDiscountFreeArgs {
+            state: Keyed {
+                pubkey: state_pda,
+                account: state_acc,
+            },
+        }
+        .resolve_for_prog(program_id)
+        .unwrap();
+        let ix = set_stale_discount_ix_with_program_id(
+            program_id,
+            keys,
+            SetStaleDiscountIxArgs { stale_discount_bps },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
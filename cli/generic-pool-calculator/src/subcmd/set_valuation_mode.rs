@@ -0,0 +1,111 @@
+use clap::Args;
+use generic_pool_calculator_lib::{
+    pda::CalculatorStateFindPdaArgs, utils::try_calculator_state, GenericPoolSolValCalc,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use spl_calculator_interface::{
+    set_valuation_mode_ix_with_program_id, SetValuationModeIxArgs, SetValuationModeKeys,
+};
+use spl_calculator_lib::{
+    SanctumSplMultiSolValCalc, SanctumSplSolValCalc, SetValuationModeFreeArgs, SplSolValCalc,
+    SplValuationMode,
+};
+
+use crate::{sol_val_calc_arg::SolValCalcArg, valuation_mode_arg::ValuationModeArg};
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets how an SPL calculator program values a stake pool's LST: at its stake withdrawal rate, SOL withdrawal rate, or the lower of both. Only works for the spl, sanctum-spl and sanctum-spl-multi programs."
+)]
+pub struct SetValuationModeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(help = "The stake pool to configure")]
+    pub pool: Pubkey,
+
+    #[arg(help = "The valuation mode to set")]
+    pub valuation_mode: ValuationModeArg,
+}
+
+impl SetValuationModeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            pool,
+            valuation_mode,
+        } = match args.subcmd {
+            Subcmd::SetValuationMode(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program.program_id();
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = CalculatorStateFindPdaArgs { program_id }
+            .get_calculator_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_calculator_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let pool_acc = rpc.get_account(&pool).await.unwrap();
+        let free_args = SetValuationModeFreeArgs {
+            payer: payer.pubkey(),
+            state: Keyed {
+                pubkey: state_pda,
+                account: state_acc,
+            },
+            pool_state: Keyed {
+                pubkey: pool,
+                account: pool_acc,
+            },
+        };
+        let keys = match args.program {
+            SolValCalcArg::Spl => resolve::<SplSolValCalc>(free_args),
+            SolValCalcArg::SanctumSpl => resolve::<SanctumSplSolValCalc>(free_args),
+            SolValCalcArg::SanctumSplMulti => resolve::<SanctumSplMultiSolValCalc>(free_args),
+            _ => {
+                eprintln!("{program_id} is not an SPL calculator program");
+                std::process::exit(-1);
+            }
+        };
+        let ix = set_valuation_mode_ix_with_program_id(
+            program_id,
+            keys,
+            SetValuationModeIxArgs {
+                valuation_mode: SplValuationMode::from(valuation_mode).into(),
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
+
+fn resolve<P: GenericPoolSolValCalc>(
+    free_args: SetValuationModeFreeArgs<Keyed<Account>, Keyed<Account>>,
+) -> SetValuationModeKeys {
+    free_args.resolve::<P>().unwrap().0
+}
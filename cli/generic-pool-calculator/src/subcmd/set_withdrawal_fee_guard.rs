@@ -0,0 +1,134 @@
+use clap::Args;
+use generic_pool_calculator_lib::{
+    pda::CalculatorStateFindPdaArgs, utils::try_calculator_state, GenericPoolSolValCalc,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use spl_calculator_interface::{
+    set_withdrawal_fee_guard_ix_with_program_id, Fee, SetWithdrawalFeeGuardIxArgs,
+    SetWithdrawalFeeGuardKeys,
+};
+use spl_calculator_lib::{
+    SanctumSplMultiSolValCalc, SanctumSplSolValCalc, SetWithdrawalFeeGuardFreeArgs, SplSolValCalc,
+};
+
+use crate::sol_val_calc_arg::SolValCalcArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets the stake withdrawal fee checks an SPL calculator program applies before valuing a stake pool's LST, recording the pool's current fees as acknowledged. Only works for the spl, sanctum-spl and sanctum-spl-multi programs."
+)]
+pub struct SetWithdrawalFeeGuardArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        long,
+        default_value_t = 0,
+        help = "Number of epochs after a recorded stake withdrawal fee change during which the LST is not valued. 0 disables fee change detection."
+    )]
+    pub fee_change_window_epochs: u64,
+
+    #[arg(
+        long,
+        help = "The maximum stake withdrawal fee, in bips, above which the LST is not valued. No ceiling if not set."
+    )]
+    pub max_fee_bps: Option<u16>,
+
+    #[arg(help = "The stake pool to configure")]
+    pub pool: Pubkey,
+}
+
+impl SetWithdrawalFeeGuardArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            fee_change_window_epochs,
+            max_fee_bps,
+            pool,
+        } = match args.subcmd {
+            Subcmd::SetWithdrawalFeeGuard(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program.program_id();
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = CalculatorStateFindPdaArgs { program_id }
+            .get_calculator_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_calculator_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let pool_acc = rpc.get_account(&pool).await.unwrap();
+        let free_args = SetWithdrawalFeeGuardFreeArgs {
+            payer: payer.pubkey(),
+            state: Keyed {
+                pubkey: state_pda,
+                account: state_acc,
+            },
+            pool_state: Keyed {
+                pubkey: pool,
+                account: pool_acc,
+            },
+        };
+        let keys = match args.program {
+            SolValCalcArg::Spl => resolve::<SplSolValCalc>(free_args),
+            SolValCalcArg::SanctumSpl => resolve::<SanctumSplSolValCalc>(free_args),
+            SolValCalcArg::SanctumSplMulti => resolve::<SanctumSplMultiSolValCalc>(free_args),
+            _ => {
+                eprintln!("{program_id} is not an SPL calculator program");
+                std::process::exit(-1);
+            }
+        };
+        // a zero denominator disables the ceiling
+        let max_stake_withdrawal_fee = max_fee_bps.map_or(
+            Fee {
+                denominator: 0,
+                numerator: 0,
+            },
+            |bps| Fee {
+                denominator: 10_000,
+                numerator: bps.into(),
+            },
+        );
+        let ix = set_withdrawal_fee_guard_ix_with_program_id(
+            program_id,
+            keys,
+            SetWithdrawalFeeGuardIxArgs {
+                fee_change_window_epochs,
+                max_stake_withdrawal_fee,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
+
+fn resolve<P: GenericPoolSolValCalc>(
+    free_args: SetWithdrawalFeeGuardFreeArgs<Keyed<Account>, Keyed<Account>>,
+) -> SetWithdrawalFeeGuardKeys {
+    free_args.resolve::<P>().unwrap().0
+}
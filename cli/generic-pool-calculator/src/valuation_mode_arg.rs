@@ -0,0 +1,19 @@
+use clap::ValueEnum;
+use spl_calculator_lib::SplValuationMode;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ValuationModeArg {
+    StakeWithdrawal,
+    SolWithdrawal,
+    MinOfBoth,
+}
+
+impl From<ValuationModeArg> for SplValuationMode {
+    fn from(value: ValuationModeArg) -> Self {
+        match value {
+            ValuationModeArg::StakeWithdrawal => Self::StakeWithdrawal,
+            ValuationModeArg::SolWithdrawal => Self::SolWithdrawal,
+            ValuationModeArg::MinOfBoth => Self::MinOfBoth,
+        }
+    }
+}
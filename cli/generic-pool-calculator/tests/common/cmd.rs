@@ -12,7 +12,7 @@ pub fn cargo_bin() -> Command {
     Command::cargo_bin("gpc").unwrap()
 }
 
-fn base_cmd(cfg: &TempCliConfig) -> Command {
+pub fn base_cmd(cfg: &TempCliConfig) -> Command {
     let mut cmd = cargo_bin();
     cmd.with_send_mode_dump_msg().with_cfg_temp_cli(cfg);
     cmd
@@ -53,9 +53,23 @@ pub trait TestGpcCmd {
 
     fn cmd_set_manager(&mut self) -> &mut Self;
 
+    fn cmd_set_stale_discount(&mut self) -> &mut Self;
+
     fn cmd_update_last_upgrade_slot(&mut self) -> &mut Self;
 
     fn cmd_view(&mut self) -> &mut Self;
+
+    fn cmd_approve_program_data_hash(&mut self) -> &mut Self;
+
+    fn cmd_permissionless_update_last_upgrade_slot(&mut self) -> &mut Self;
+
+    fn cmd_set_valuation_mode(&mut self) -> &mut Self;
+
+    fn cmd_set_delayed_unstake_fallback(&mut self) -> &mut Self;
+
+    fn cmd_set_withdrawal_fee_guard(&mut self) -> &mut Self;
+
+    fn cmd_record_withdrawal_fees(&mut self) -> &mut Self;
 }
 
 impl TestGpcCmd for Command {
@@ -75,6 +89,10 @@ impl TestGpcCmd for Command {
         self.arg("set-manager")
     }
 
+    fn cmd_set_stale_discount(&mut self) -> &mut Self {
+        self.arg("set-stale-discount")
+    }
+
     fn cmd_update_last_upgrade_slot(&mut self) -> &mut Self {
         self.arg("update-last-upgrade-slot")
     }
@@ -82,4 +100,28 @@ impl TestGpcCmd for Command {
     fn cmd_view(&mut self) -> &mut Self {
         self.arg("view")
     }
+
+    fn cmd_approve_program_data_hash(&mut self) -> &mut Self {
+        self.arg("approve-program-data-hash")
+    }
+
+    fn cmd_permissionless_update_last_upgrade_slot(&mut self) -> &mut Self {
+        self.arg("permissionless-update-last-upgrade-slot")
+    }
+
+    fn cmd_set_valuation_mode(&mut self) -> &mut Self {
+        self.arg("set-valuation-mode")
+    }
+
+    fn cmd_set_delayed_unstake_fallback(&mut self) -> &mut Self {
+        self.arg("set-delayed-unstake-fallback")
+    }
+
+    fn cmd_set_withdrawal_fee_guard(&mut self) -> &mut Self {
+        self.arg("set-withdrawal-fee-guard")
+    }
+
+    fn cmd_record_withdrawal_fees(&mut self) -> &mut Self {
+        self.arg("record-withdrawal-fees")
+    }
 }
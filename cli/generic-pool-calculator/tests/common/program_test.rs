@@ -8,6 +8,12 @@ use solana_program_test::{processor, ProgramTest};
 pub trait GpcSplProgramTest {
     fn add_spl_programs(self) -> Self;
     fn add_mock_spl_calculator_state(self, last_upgrade_slot: u64, manager: Pubkey) -> Self;
+    fn add_mock_spl_calculator_state_with_approved_hash(
+        self,
+        last_upgrade_slot: u64,
+        manager: Pubkey,
+        approved_program_data_hash: [u8; 32],
+    ) -> Self;
 }
 
 impl GpcSplProgramTest for ProgramTest {
@@ -25,9 +31,24 @@ impl GpcSplProgramTest for ProgramTest {
     }
 
     fn add_mock_spl_calculator_state(self, last_upgrade_slot: u64, manager: Pubkey) -> Self {
+        self.add_mock_spl_calculator_state_with_approved_hash(
+            last_upgrade_slot,
+            manager,
+            Default::default(),
+        )
+    }
+
+    fn add_mock_spl_calculator_state_with_approved_hash(
+        self,
+        last_upgrade_slot: u64,
+        manager: Pubkey,
+        approved_program_data_hash: [u8; 32],
+    ) -> Self {
         self.add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager,
             last_upgrade_slot,
+            stale_discount_bps: Default::default(),
+            approved_program_data_hash,
             owner: spl_calculator_lib::program::ID,
         })
     }
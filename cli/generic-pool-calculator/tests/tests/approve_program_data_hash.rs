@@ -0,0 +1,42 @@
+use generic_pool_calculator_lib::utils::{read_stake_pool_progdata_hash, try_calculator_state};
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, ExtendedCommand},
+    ExtendedBanksClient,
+};
+use solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
+use solana_sdk::pubkey::Pubkey;
+use spl_stake_pool_keys::spl_stake_pool_program_progdata;
+
+use crate::common::{setup_with_payer_as_manager, TestGpcCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn approve_program_data_hash_success_payer_as_manager() {
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup_with_payer_as_manager(0).await;
+
+    // write out the deployed spl stake pool program as a local .so file
+    let progdata = bc
+        .get_account_unwrapped(spl_stake_pool_program_progdata::ID)
+        .await;
+    let so_file = std::env::temp_dir().join(format!("{}.so", Pubkey::new_unique()));
+    std::fs::write(
+        &so_file,
+        &progdata.data[UpgradeableLoaderState::size_of_programdata_metadata()..],
+    )
+    .unwrap();
+
+    cmd.with_spl_calculator()
+        .cmd_approve_program_data_hash()
+        .arg(&so_file);
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    std::fs::remove_file(so_file).unwrap();
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let state_data = bc
+        .get_account_data(spl_calculator_lib::program::SPL_CALCULATOR_STATE_ID)
+        .await;
+    let state = try_calculator_state(&state_data).unwrap();
+    assert_eq!(
+        state.approved_program_data_hash,
+        read_stake_pool_progdata_hash(&progdata).unwrap()
+    );
+}
@@ -1,5 +1,11 @@
+mod approve_program_data_hash;
 mod init;
+mod permissionless_update_last_upgrade_slot;
+mod set_delayed_unstake_fallback;
 mod set_manager;
+mod set_stale_discount;
+mod set_valuation_mode;
+mod set_withdrawal_fee_guard;
 mod update_last_upgrade_slot;
 mod view;
 
@@ -0,0 +1,39 @@
+use generic_pool_calculator_lib::utils::{read_stake_pool_progdata_hash, try_calculator_state};
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, ExtendedCommand},
+    ExtendedBanksClient, KeyedUiAccount,
+};
+use solana_program_test::ProgramTest;
+use solana_sdk::pubkey::Pubkey;
+use spl_stake_pool_keys::spl_stake_pool_program;
+use test_utils::SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT;
+
+use crate::common::{setup, GpcSplProgramTest, TestGpcCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn permissionless_update_last_upgrade_slot_success_approved_hash() {
+    let progdata =
+        KeyedUiAccount::from_test_fixtures_file("spl-stake-pool-prog-data.json").to_keyed_account();
+    let approved_program_data_hash = read_stake_pool_progdata_hash(&progdata).unwrap();
+
+    let pt = ProgramTest::default().add_mock_spl_calculator_state_with_approved_hash(
+        0,
+        Pubkey::new_unique(),
+        approved_program_data_hash,
+    );
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(pt).await;
+    cmd.with_spl_calculator()
+        .cmd_permissionless_update_last_upgrade_slot()
+        .arg(spl_stake_pool_program::ID_STR);
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let state_data = bc
+        .get_account_data(spl_calculator_lib::program::SPL_CALCULATOR_STATE_ID)
+        .await;
+    let state = try_calculator_state(&state_data).unwrap();
+    assert_eq!(
+        state.last_upgrade_slot,
+        SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT
+    );
+}
@@ -0,0 +1,51 @@
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use marinade_calculator_lib::{program::MARINADE_CALCULATOR_CONFIG_ID, try_marinade_calc_config};
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, temp_keypair_file, ExtendedCommand},
+    ExtendedBanksClient,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, TestGpcCmd};
+
+fn marinade_calculator_program_test(manager: Pubkey) -> ProgramTest {
+    let mut pt = ProgramTest::default().add_mock_calculator_state(MockCalculatorStateAccountArgs {
+        manager,
+        last_upgrade_slot: 0,
+        stale_discount_bps: Default::default(),
+        approved_program_data_hash: Default::default(),
+        owner: marinade_calculator_lib::program::ID,
+    });
+    pt.add_program(
+        "marinade_calculator",
+        marinade_calculator_lib::program::ID,
+        processor!(marinade_calculator::entrypoint::process_instruction),
+    );
+    pt
+}
+
+async fn assert_delayed_unstake_fallback(bc: &mut BanksClient, expected: bool) {
+    let calc_config_data = bc.get_account_data(MARINADE_CALCULATOR_CONFIG_ID).await;
+    let calc_config = try_marinade_calc_config(&calc_config_data).unwrap();
+    assert_eq!(calc_config.delayed_unstake_fallback, u8::from(expected));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_delayed_unstake_fallback_success_separate_manager() {
+    let manager = Keypair::new();
+    let manager_keyfile = temp_keypair_file(&manager);
+    let pt = marinade_calculator_program_test(manager.pubkey());
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(pt).await;
+    cmd.arg("marinade")
+        .cmd_set_delayed_unstake_fallback()
+        .arg("-m")
+        .arg(manager_keyfile.path())
+        .arg("true");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_delayed_unstake_fallback(&mut bc, true).await;
+}
@@ -0,0 +1,48 @@
+use generic_pool_calculator_lib::utils::try_calculator_state;
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, temp_keypair_file, ExtendedCommand},
+    ExtendedBanksClient,
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, setup_with_payer_as_manager, GpcSplProgramTest, TestGpcCmd};
+
+async fn assert_stale_discount_bps(bc: &mut BanksClient, expected_stale_discount_bps: u16) {
+    let state_data = bc
+        .get_account_data(spl_calculator_lib::program::SPL_CALCULATOR_STATE_ID)
+        .await;
+    let state = try_calculator_state(&state_data).unwrap();
+    assert_eq!(state.stale_discount_bps, expected_stale_discount_bps);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_stale_discount_success_payer_as_manager() {
+    const STALE_DISCOUNT_BPS: u16 = 50;
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup_with_payer_as_manager(0).await;
+    cmd.with_spl_calculator()
+        .cmd_set_stale_discount()
+        .arg(STALE_DISCOUNT_BPS.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_stale_discount_bps(&mut bc, STALE_DISCOUNT_BPS).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_stale_discount_success_separate_manager() {
+    const STALE_DISCOUNT_BPS: u16 = 50;
+
+    let manager = Keypair::new();
+    let manager_keyfile = temp_keypair_file(&manager);
+    let pt = ProgramTest::default().add_mock_spl_calculator_state(0, manager.pubkey());
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(pt).await;
+    cmd.with_spl_calculator()
+        .cmd_set_stale_discount()
+        .arg("-m")
+        .arg(manager_keyfile.path())
+        .arg(STALE_DISCOUNT_BPS.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_stale_discount_bps(&mut bc, STALE_DISCOUNT_BPS).await;
+}
@@ -0,0 +1,44 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, temp_keypair_file, ExtendedCommand},
+    ExtendedBanksClient, ExtendedProgramTest,
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{signature::Keypair, signer::Signer};
+use spl_calculator_lib::{
+    pda::SplPoolConfigFindPdaArgs, try_spl_pool_config, SplSolValCalc, SplValuationMode,
+};
+use test_utils::jito_stake_pool;
+
+use crate::common::{setup, GpcSplProgramTest, TestGpcCmd};
+
+async fn assert_valuation_mode(bc: &mut BanksClient, expected: SplValuationMode) {
+    let pool_config = SplPoolConfigFindPdaArgs {
+        program_id: SplSolValCalc::ID,
+        pool_state: jito_stake_pool::ID,
+    }
+    .get_spl_pool_config_address_and_bump_seed()
+    .0;
+    let pool_config_data = bc.get_account_data(pool_config).await;
+    let config = try_spl_pool_config(&pool_config_data).unwrap();
+    assert_eq!(config.valuation_mode, u8::from(expected));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_valuation_mode_success_separate_manager() {
+    let manager = Keypair::new();
+    let manager_keyfile = temp_keypair_file(&manager);
+    let pt = ProgramTest::default()
+        .add_mock_spl_calculator_state(0, manager.pubkey())
+        .add_test_fixtures_account("jito-stake-pool.json");
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(pt).await;
+    cmd.with_spl_calculator()
+        .cmd_set_valuation_mode()
+        .arg("-m")
+        .arg(manager_keyfile.path())
+        .arg(jito_stake_pool::ID.to_string())
+        .arg("min-of-both");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_valuation_mode(&mut bc, SplValuationMode::MinOfBoth).await;
+}
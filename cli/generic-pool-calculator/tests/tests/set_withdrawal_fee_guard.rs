@@ -0,0 +1,77 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, temp_keypair_file, ExtendedCommand},
+    ExtendedBanksClient, ExtendedProgramTest,
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use spl_calculator_interface::Fee;
+use spl_calculator_lib::{
+    deserialize_stake_pool_checked, pda::SplPoolConfigFindPdaArgs, try_spl_pool_config,
+    SplSolValCalc, StakeWithdrawalFees, WithdrawalFeeGuard,
+};
+use test_utils::jito_stake_pool;
+
+use crate::common::{base_cmd, setup, GpcSplProgramTest, TestGpcCmd};
+
+fn jito_pool_config() -> Pubkey {
+    SplPoolConfigFindPdaArgs {
+        program_id: SplSolValCalc::ID,
+        pool_state: jito_stake_pool::ID,
+    }
+    .get_spl_pool_config_address_and_bump_seed()
+    .0
+}
+
+async fn jito_withdrawal_fee_guard(bc: &mut BanksClient) -> WithdrawalFeeGuard {
+    let pool_config_data = bc.get_account_data(jito_pool_config()).await;
+    try_spl_pool_config(&pool_config_data).unwrap().into()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_withdrawal_fee_guard_then_record_success() {
+    let manager = Keypair::new();
+    let manager_keyfile = temp_keypair_file(&manager);
+    let pt = ProgramTest::default()
+        .add_mock_spl_calculator_state(0, manager.pubkey())
+        .add_test_fixtures_account("jito-stake-pool.json");
+    let (mut cmd, cfg, mut bc, _payer, _rbh) = setup(pt).await;
+    cmd.with_spl_calculator()
+        .cmd_set_withdrawal_fee_guard()
+        .arg("-m")
+        .arg(manager_keyfile.path())
+        .arg("--fee-change-window-epochs")
+        .arg("3")
+        .arg("--max-fee-bps")
+        .arg("50")
+        .arg(jito_stake_pool::ID.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let jito_stake_pool_acc = bc.get_account_unwrapped(jito_stake_pool::ID).await;
+    let stake_pool = deserialize_stake_pool_checked(Keyed {
+        pubkey: jito_stake_pool::ID,
+        account: jito_stake_pool_acc,
+    })
+    .unwrap();
+    let expected = WithdrawalFeeGuard {
+        fee_change_window_epochs: 3,
+        last_fee_change_epoch: 0,
+        max_stake_withdrawal_fee: Some(Fee {
+            denominator: 10_000,
+            numerator: 50,
+        }),
+        recorded_fees: StakeWithdrawalFees::from(&stake_pool),
+    };
+    assert_eq!(jito_withdrawal_fee_guard(&mut bc).await, expected);
+
+    // unchanged fees should not start a new fee change window
+    let mut cmd = base_cmd(&cfg);
+    cmd.with_spl_calculator()
+        .cmd_record_withdrawal_fees()
+        .arg(jito_stake_pool::ID.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_eq!(jito_withdrawal_fee_guard(&mut bc).await, expected);
+}
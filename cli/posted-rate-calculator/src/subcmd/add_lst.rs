@@ -0,0 +1,113 @@
+use clap::Args;
+use posted_rate_calculator_interface::{add_lst_ix_with_program_id, AddLstIxArgs};
+use posted_rate_calculator_lib::{
+    account_resolvers::AddLstFreeArgs, pda::ProgramStateFindPdaArgs, utils::try_program_state,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Enable an LST to be supported by the posted-rate SOL value calculator program"
+)]
+pub struct AddLstArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        help = "Mint of the new LST to add. Can either be a pubkey or case-sensitive symbol of a token on sanctum-lst-list. e.g. 'bSOL'"
+    )]
+    pub lst_mint: String,
+
+    #[arg(help = "Lamports per `rate_denom` LST atomics")]
+    pub rate_num: u64,
+
+    #[arg(help = "LST atomics per `rate_num` lamports")]
+    pub rate_denom: u64,
+
+    #[arg(help = "Max change in the rate allowed per update, in bips of the current rate")]
+    pub max_rate_change_bps: u16,
+
+    #[arg(long, help = "Only allow the rate to increase on subsequent updates")]
+    pub is_monotonic: bool,
+
+    #[arg(
+        help = "Number of slots after the last update beyond which the rate is considered stale"
+    )]
+    pub max_staleness_slots: u64,
+}
+
+impl AddLstArgs {
+    pub async fn run(args: crate::Args) {
+        let slsts = args.load_slst_list();
+        let Self {
+            manager,
+            lst_mint,
+            rate_num,
+            rate_denom,
+            max_rate_change_bps,
+            is_monotonic,
+            max_staleness_slots,
+        } = match args.subcmd {
+            Subcmd::AddLst(a) => a,
+            _ => unreachable!(),
+        };
+        let lst_mint = LstArg::parse_arg(&lst_mint, &slsts).unwrap();
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let ix = add_lst_ix_with_program_id(
+            program_id,
+            AddLstFreeArgs {
+                payer: payer.pubkey(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+                lst_mint: lst_mint.mint(),
+            }
+            .resolve_for_prog(program_id)
+            .unwrap()
+            .0,
+            AddLstIxArgs {
+                rate_num,
+                rate_denom,
+                max_rate_change_bps,
+                is_monotonic,
+                max_staleness_slots,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,47 @@
+use clap::Subcommand;
+
+mod add_lst;
+mod common;
+mod initialize;
+mod remove_lst;
+mod set_manager;
+mod set_rate;
+mod set_rate_guardrails;
+mod view;
+mod view_lst;
+
+use add_lst::AddLstArgs;
+use initialize::InitializeArgs;
+use remove_lst::RemoveLstArgs;
+use set_manager::SetManagerArgs;
+use set_rate::SetRateArgs;
+use set_rate_guardrails::SetRateGuardrailsArgs;
+use view::ViewArgs;
+use view_lst::ViewLstArgs;
+
+#[derive(Debug, Subcommand)]
+pub enum Subcmd {
+    Initialize,
+    SetManager(SetManagerArgs),
+    AddLst(AddLstArgs),
+    RemoveLst(RemoveLstArgs),
+    SetRate(SetRateArgs),
+    SetRateGuardrails(SetRateGuardrailsArgs),
+    View(ViewArgs),
+    ViewLst(ViewLstArgs),
+}
+
+impl Subcmd {
+    pub async fn run(args: crate::Args) {
+        match &args.subcmd {
+            Self::Initialize => InitializeArgs::run(args).await,
+            Self::SetManager(_) => SetManagerArgs::run(args).await,
+            Self::AddLst(_) => AddLstArgs::run(args).await,
+            Self::RemoveLst(_) => RemoveLstArgs::run(args).await,
+            Self::SetRate(_) => SetRateArgs::run(args).await,
+            Self::SetRateGuardrails(_) => SetRateGuardrailsArgs::run(args).await,
+            Self::View(_) => ViewArgs::run(args).await,
+            Self::ViewLst(_) => ViewLstArgs::run(args).await,
+        }
+    }
+}
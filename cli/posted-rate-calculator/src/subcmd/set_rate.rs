@@ -0,0 +1,93 @@
+use clap::Args;
+use posted_rate_calculator_interface::{set_rate_ix_with_program_id, SetRateIxArgs};
+use posted_rate_calculator_lib::{
+    account_resolvers::SetRateByMintFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(long_about = "Post a new rate for given LST")]
+pub struct SetRateArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        help = "Mint of the LST to post the rate for. Can either be a pubkey or case-sensitive symbol of a token on sanctum-lst-list. e.g. 'bSOL'"
+    )]
+    pub lst_mint: String,
+
+    #[arg(help = "Lamports per `rate_denom` LST atomics")]
+    pub rate_num: u64,
+
+    #[arg(help = "LST atomics per `rate_num` lamports")]
+    pub rate_denom: u64,
+}
+
+impl SetRateArgs {
+    pub async fn run(args: crate::Args) {
+        let slsts = args.load_slst_list();
+        let Self {
+            manager,
+            lst_mint,
+            rate_num,
+            rate_denom,
+        } = match args.subcmd {
+            Subcmd::SetRate(a) => a,
+            _ => unreachable!(),
+        };
+        let lst_mint = LstArg::parse_arg(&lst_mint, &slsts).unwrap();
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let ix = set_rate_ix_with_program_id(
+            program_id,
+            SetRateByMintFreeArgs {
+                lst_mint: lst_mint.mint(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+            SetRateIxArgs {
+                rate_num,
+                rate_denom,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,38 @@
+use clap::Args;
+use posted_rate_calculator_lib::{pda::RateAccountFindPdaArgs, utils::try_rate_account};
+
+use crate::{lst_arg::LstArg, subcmd::Subcmd};
+
+#[derive(Args, Debug)]
+#[command(long_about = "View the current posted rate for a given LST")]
+pub struct ViewLstArgs {
+    #[arg(
+        help = "Mint of the LST to view the posted rate for. Can either be a pubkey or case-sensitive symbol of a token on sanctum-lst-list. e.g. 'bSOL'"
+    )]
+    pub lst_mint: String,
+}
+
+impl ViewLstArgs {
+    pub async fn run(args: crate::Args) {
+        let slsts = args.load_slst_list();
+        let Self { lst_mint } = match args.subcmd {
+            Subcmd::ViewLst(a) => a,
+            _ => unreachable!(),
+        };
+        let lst_mint = LstArg::parse_arg(&lst_mint, &slsts).unwrap();
+
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let rate_account_pda = RateAccountFindPdaArgs {
+            program_id,
+            lst_mint: lst_mint.mint(),
+        }
+        .get_rate_account_address_and_bump_seed()
+        .0;
+        let rate_account_data = rpc.get_account_data(&rate_account_pda).await.unwrap();
+        let rate_account = try_rate_account(&rate_account_data).unwrap();
+
+        println!("{rate_account:#?}");
+    }
+}
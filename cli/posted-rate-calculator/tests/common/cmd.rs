@@ -0,0 +1,115 @@
+use assert_cmd::Command;
+use cli_test_utils::TestCliCmd;
+use posted_rate_calculator_interface::ProgramState;
+use posted_rate_calculator_test_utils::{
+    MockRateAccount, MockRateAccountArgs, PostedRateCalculatorProgramTest,
+};
+use sanctum_solana_test_utils::{
+    banks_rpc_server::BanksRpcServer,
+    cli::TempCliConfig,
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs},
+    ExtendedProgramTest, IntoAccount,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::{hash::Hash, signature::Keypair, signer::Signer};
+
+fn add_posted_rate_calculator_program(mut pt: ProgramTest) -> ProgramTest {
+    pt.add_program(
+        "posted_rate_calculator",
+        posted_rate_calculator_lib::program::ID,
+        processor!(posted_rate_calculator::entrypoint::process_instruction),
+    );
+    pt
+}
+
+// setup program test
+// - `program_state`       sets posted rate calculator program state up
+// - `mock_mints`          sets token mint up
+// - `mock_rate_accounts`  sets token mint up and set mock rate account
+// - funds given payer and set it as default cli keypair in temporary config
+pub async fn setup(
+    pt: ProgramTest,
+    payer: Keypair,
+    program_state: Option<ProgramState>,
+    mock_mints: &[Pubkey],
+    mock_rate_accounts: &[MockRateAccountArgs],
+) -> (Command, TempCliConfig, BanksClient, Keypair, Hash) {
+    let mut pt =
+        add_posted_rate_calculator_program(pt).add_system_account(payer.pubkey(), 1_000_000_000);
+
+    if let Some(program_state) = program_state {
+        pt.add_mock_program_state(program_state);
+    }
+
+    for &mint in mock_mints {
+        pt = pt.add_tokenkeg_mint_from_args(
+            mint,
+            MockMintArgs {
+                mint_authority: None,
+                freeze_authority: None,
+                supply: 0,
+                decimals: 9,
+            },
+        );
+    }
+
+    for mra in mock_rate_accounts {
+        pt = pt.add_tokenkeg_mint_from_args(
+            mra.lst_mint,
+            MockMintArgs {
+                mint_authority: None,
+                freeze_authority: None,
+                supply: 0,
+                decimals: 9,
+            },
+        );
+        let (acc, addr) = mra.to_rate_account_and_addr(posted_rate_calculator_lib::program::ID);
+        pt.add_account(addr, MockRateAccount(acc).into_account())
+    }
+
+    let (bc, _rng_payer, rbh) = pt.start().await;
+
+    let (port, _jh) = BanksRpcServer::spawn_random_unused(bc.clone()).await;
+    let cfg = TempCliConfig::from_keypair_and_local_port(&payer, port);
+    let cmd = base_cmd(&cfg);
+    (cmd, cfg, bc, payer, rbh)
+}
+
+pub fn cargo_bin() -> Command {
+    Command::cargo_bin("prc").unwrap()
+}
+
+fn base_cmd(cfg: &TempCliConfig) -> Command {
+    let mut cmd = cargo_bin();
+    cmd.with_send_mode_dump_msg().with_cfg_temp_cli(cfg);
+    cmd
+}
+
+pub trait TestCmd {
+    fn with_posted_rate_calculator_program(&mut self) -> &mut Self;
+
+    fn cmd_add_lst(&mut self) -> &mut Self;
+
+    fn cmd_set_rate(&mut self) -> &mut Self;
+
+    fn cmd_view_lst(&mut self) -> &mut Self;
+}
+
+impl TestCmd for Command {
+    fn with_posted_rate_calculator_program(&mut self) -> &mut Self {
+        self.arg(posted_rate_calculator_lib::program::ID_STR)
+    }
+
+    fn cmd_add_lst(&mut self) -> &mut Self {
+        self.arg("add-lst")
+    }
+
+    fn cmd_set_rate(&mut self) -> &mut Self {
+        self.arg("set-rate")
+    }
+
+    fn cmd_view_lst(&mut self) -> &mut Self {
+        self.arg("view-lst")
+    }
+}
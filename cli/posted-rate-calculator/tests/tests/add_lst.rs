@@ -0,0 +1,43 @@
+use posted_rate_calculator_interface::ProgramState;
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn add_lst_success() {
+    const RATE_NUM: u64 = 1_050_000_000;
+    const RATE_DENOM: u64 = 1_000_000_000;
+    const MAX_RATE_CHANGE_BPS: u16 = 100;
+    const MAX_STALENESS_SLOTS: u64 = 432_000;
+
+    let lst_mint: Pubkey = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let program_state = ProgramState {
+        manager: payer.pubkey(),
+    };
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        payer,
+        Some(program_state),
+        &[lst_mint],
+        &[],
+    )
+    .await;
+
+    cmd.with_posted_rate_calculator_program()
+        .cmd_add_lst()
+        .arg(lst_mint.to_string())
+        .arg(RATE_NUM.to_string())
+        .arg(RATE_DENOM.to_string())
+        .arg(MAX_RATE_CHANGE_BPS.to_string())
+        .arg(MAX_STALENESS_SLOTS.to_string())
+        .arg("--is-monotonic");
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+}
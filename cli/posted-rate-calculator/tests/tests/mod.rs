@@ -0,0 +1,3 @@
+mod add_lst;
+mod set_rate;
+mod view_lst;
@@ -0,0 +1,47 @@
+use posted_rate_calculator_interface::ProgramState;
+use posted_rate_calculator_test_utils::MockRateAccountArgs;
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_rate_success() {
+    const RATE_NUM: u64 = 1_055_000_000;
+    const RATE_DENOM: u64 = 1_000_000_000;
+
+    let lst_mint: Pubkey = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let program_state = ProgramState {
+        manager: payer.pubkey(),
+    };
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        payer,
+        Some(program_state),
+        &[],
+        &[MockRateAccountArgs {
+            rate_num: 1_050_000_000,
+            rate_denom: 1_000_000_000,
+            max_rate_change_bps: 100,
+            is_monotonic: true,
+            max_staleness_slots: u64::MAX,
+            last_updated_slot: 0,
+            lst_mint,
+        }],
+    )
+    .await;
+
+    cmd.with_posted_rate_calculator_program()
+        .cmd_set_rate()
+        .arg(lst_mint.to_string())
+        .arg(RATE_NUM.to_string())
+        .arg(RATE_DENOM.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+}
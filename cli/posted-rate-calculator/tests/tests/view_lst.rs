@@ -0,0 +1,43 @@
+use std::process::Output;
+
+use posted_rate_calculator_interface::ProgramState;
+use posted_rate_calculator_test_utils::MockRateAccountArgs;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_sdk::signature::Keypair;
+
+use crate::common::{setup, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn view_lst_success() {
+    let lst_mint: Pubkey = Pubkey::new_unique();
+
+    let program_state = ProgramState {
+        manager: Pubkey::default(),
+    };
+
+    let (mut cmd, _cfg, _bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        Keypair::new(),
+        Some(program_state),
+        &[],
+        &[MockRateAccountArgs {
+            rate_num: 1,
+            rate_denom: 1,
+            max_rate_change_bps: Default::default(),
+            is_monotonic: Default::default(),
+            max_staleness_slots: Default::default(),
+            last_updated_slot: Default::default(),
+            lst_mint,
+        }],
+    )
+    .await;
+
+    cmd.with_posted_rate_calculator_program()
+        .cmd_view_lst()
+        .arg(lst_mint.to_string());
+
+    let Output { status, stdout, .. } = cmd.output().unwrap();
+    assert!(status.success());
+    eprintln!("{}", std::str::from_utf8(&stdout).unwrap());
+}
@@ -0,0 +1,3 @@
+mod cmd;
+
+pub use cmd::*;
@@ -0,0 +1,11 @@
+mod add_lst;
+mod initialize;
+mod remove_lst;
+mod set_lp_withdrawal_fee;
+mod set_lst_fee;
+mod set_manager;
+mod view;
+mod view_lst;
+
+// TODO: tests for price-exact-in, price-exact-out, price-lp-tokens-to-mint, price-lp-tokens-to-redeem
+// if we can figure out how to get BanksClientRpcServer to simulate transactions with full data
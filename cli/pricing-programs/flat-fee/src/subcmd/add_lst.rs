@@ -1,7 +1,8 @@
 use clap::Args;
 use flat_fee_interface::{add_lst_ix_with_program_id, AddLstIxArgs};
 use flat_fee_lib::{
-    account_resolvers::AddLstFreeArgs, pda::ProgramStateFindPdaArgs, utils::try_program_state,
+    account_resolvers::AddLstFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state_allow_legacy,
 };
 use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
 use sanctum_solana_cli_utils::PubkeySrc;
@@ -58,8 +59,8 @@ impl AddLstArgs {
             .get_program_state_address_and_bump_seed()
             .0;
         let state_acc = rpc.get_account(&state_pda).await.unwrap();
-        let state = try_program_state(&state_acc.data).unwrap();
-        verify_manager(state, manager.pubkey()).unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
 
         let ix = add_lst_ix_with_program_id(
             program_id,
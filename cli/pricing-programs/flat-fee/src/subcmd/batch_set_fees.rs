@@ -2,7 +2,8 @@ use clap::Args;
 use flat_fee_interface::{set_lst_fee_ix_with_program_id, SetLstFeeIxArgs, SetLstFeeKeys};
 use flat_fee_lib::{
     pda::ProgramStateFindPdaArgs,
-    utils::{try_fee_account, try_program_state},
+    program::FEE_ACCOUNT_SIZE,
+    utils::{try_fee_account, try_program_state_allow_legacy},
 };
 use sanctum_solana_cli_utils::PubkeySrc;
 use solana_sdk::{
@@ -19,6 +20,7 @@ use super::Subcmd;
 #[derive(Args, Debug)]
 #[command(
     long_about = "Set the input fee and output fee for all LSTs under the program in one shot.
+Fees can only be decreased immediately, the transaction fails if any LST's fees would increase. Use schedule-lst-fee to increase fees.
 Assumes manager is a squads multisig and outputs a base58-encoded VersionedTransaction that can be imported into the squads UI.
 IMPORTANT: Due to the size of the tx, it is likely the created proposal will throw  `Access violation in heap section at address 0x30000804a of size 1` if executed via the squads UI.
 You will need to programmatically prefix the transaction with a top-level ComputeBudgetInstruction::request_heap_frame(MAX_HEAP_FRAME_BYTES) to execute it."
@@ -75,9 +77,14 @@ impl BatchSetFeesArgs {
             .position(|(pk, _acc)| *pk == state_pda)
             .unwrap();
         let (_state_pk, state_acc) = program_accs.remove(state_i);
-        let manager = try_program_state(&state_acc.data).unwrap().manager;
+        let manager = try_program_state_allow_legacy(&state_acc.data)
+            .unwrap()
+            .manager;
 
         program_accs.retain(|(_pk, acc)| {
+            if acc.data.len() != FEE_ACCOUNT_SIZE {
+                return false;
+            }
             let fee_acc = try_fee_account(&acc.data).unwrap();
             fee_acc.input_fee_bps != input_fee_bps || fee_acc.output_fee_bps != output_fee_bps
         });
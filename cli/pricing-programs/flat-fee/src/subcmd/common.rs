@@ -1,11 +1,19 @@
 use data_encoding::BASE64;
 use flat_fee_interface::ProgramState;
+use flat_fee_lib::{
+    pda::{LstSurchargeFindPdaArgs, PairFeeAccountFindPdaArgs},
+    utils::{try_lst_surcharge, try_program_state_admin},
+};
+use s_controller_lib::{find_pool_reserves_address, FindLstPdaAtaKeys};
 use sanctum_solana_client_utils::{to_est_cu_sim_tx, EST_CU_SIM_TX_CONFIG};
 use solana_client::{
     nonblocking::rpc_client::RpcClient, rpc_response::RpcSimulateTransactionResult,
 };
 use solana_sdk::{
-    instruction::Instruction, native_token::lamports_to_sol, pubkey::Pubkey, signer::Signer,
+    instruction::{AccountMeta, Instruction},
+    native_token::lamports_to_sol,
+    pubkey::Pubkey,
+    signer::Signer,
 };
 use solana_transaction_status::{UiReturnDataEncoding, UiTransactionReturnData};
 use std::convert::Infallible;
@@ -21,6 +29,76 @@ pub fn verify_manager(state: &ProgramState, curr_manager: Pubkey) -> Result<(),
     Ok(())
 }
 
+pub fn verify_admin(state: &ProgramState, curr_admin: Pubkey) -> Result<(), Infallible> {
+    let admin = match try_program_state_admin(state) {
+        Ok(admin) => admin,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(-1);
+        }
+    };
+    if admin != curr_admin {
+        eprintln!("Wrong admin. Expected: {}. Got: {}", admin, curr_admin);
+        std::process::exit(-1);
+    }
+    Ok(())
+}
+
+/// Finds the S pool's reserves account for `lst_mint`.
+/// Fetches the mint to determine its token program.
+pub async fn find_s_pool_reserves(rpc: &RpcClient, lst_mint: Pubkey) -> Pubkey {
+    let token_program = rpc.get_account(&lst_mint).await.unwrap().owner;
+    find_pool_reserves_address(FindLstPdaAtaKeys {
+        lst_mint,
+        token_program,
+    })
+    .0
+}
+
+/// Finds the stake pool to pass for `lst_mint` to PriceExactIn/PriceExactOut.
+/// Returns the stake pool recorded in the LST's LstSurcharge account,
+/// or the LST mint if the LST has no LstSurcharge account.
+pub async fn find_lst_surcharge_stake_pool(
+    rpc: &RpcClient,
+    program_id: Pubkey,
+    lst_mint: Pubkey,
+) -> Pubkey {
+    let surcharge_acc = LstSurchargeFindPdaArgs {
+        program_id,
+        lst_mint,
+    }
+    .get_lst_surcharge_address_and_bump_seed()
+    .0;
+    let accs = rpc.get_multiple_accounts(&[surcharge_acc]).await.unwrap();
+    match accs.into_iter().next().flatten() {
+        Some(acc) if !acc.data.is_empty() => try_lst_surcharge(&acc.data).unwrap().stake_pool,
+        _ => lst_mint,
+    }
+}
+
+/// Returns the PairFeeAccount to pass as remaining account to PriceExactIn/PriceExactOut
+/// if the LST pair has a fee override
+pub async fn find_pair_fee_account_meta(
+    rpc: &RpcClient,
+    program_id: Pubkey,
+    input_lst_mint: Pubkey,
+    output_lst_mint: Pubkey,
+) -> Option<AccountMeta> {
+    let pair_fee_acc = PairFeeAccountFindPdaArgs {
+        program_id,
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .get_pair_fee_account_address_and_bump_seed()
+    .0;
+    let accs = rpc.get_multiple_accounts(&[pair_fee_acc]).await.unwrap();
+    accs.into_iter()
+        .next()
+        .flatten()
+        .filter(|acc| !acc.data.is_empty())
+        .map(|_acc| AccountMeta::new_readonly(pair_fee_acc, false))
+}
+
 pub async fn handle_pricing_ix(rpc: &RpcClient, ix: Instruction, payer: &dyn Signer) {
     let tx = to_est_cu_sim_tx(&payer.pubkey(), &[ix], &[]).unwrap();
     let RpcSimulateTransactionResult {
@@ -1,7 +1,8 @@
 use clap::Args;
 use flat_fee_interface::initialize_ix_with_program_id;
 use flat_fee_lib::{
-    account_resolvers::InitializeFreeArgs, pda::ProgramStateFindPdaArgs, utils::try_program_state,
+    account_resolvers::InitializeFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state_allow_legacy,
 };
 use s_cli_utils::handle_tx_full;
 use solana_sdk::commitment_config::CommitmentConfig;
@@ -28,7 +29,7 @@ impl InitializeArgs {
             .unwrap();
         if let Some(state) = state.value {
             eprintln!("State PDA {state_pda} already initialized:");
-            let state = try_program_state(&state.data).unwrap();
+            let state = try_program_state_allow_legacy(&state.data).unwrap();
             eprintln!("{state:#?}");
             return;
         }
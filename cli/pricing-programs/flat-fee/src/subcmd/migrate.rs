@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use clap::Args;
+use flat_fee_interface::migrate_account_ix_with_program_id;
+use flat_fee_lib::{
+    account_resolvers::MigrateAccountFreeArgs,
+    pda::{FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
+    program::{LEGACY_FEE_ACCOUNT_SIZE, LEGACY_STATE_SIZE},
+};
+use s_cli_utils::handle_tx_full;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use super::Subcmd;
+
+const MIGRATE_IXS_PER_TX: usize = 10;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Migrates the program state and all FeeAccounts with the legacy layout to the current layout. Permissionless, the config wallet pays for the additional rent.
+FeeAccounts of LSTs not in the sanctum-lst-list are skipped since their LST mint cannot be determined."
+)]
+pub struct MigrateArgs;
+
+impl MigrateArgs {
+    pub async fn run(args: crate::Args) {
+        let slsts = args.load_slst_list();
+        let Self = match args.subcmd {
+            Subcmd::Migrate(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        let pda_to_mint: HashMap<Pubkey, Pubkey> = slsts
+            .iter()
+            .map(|lst| {
+                (
+                    FeeAccountFindPdaArgs {
+                        program_id,
+                        lst_mint: lst.mint,
+                    }
+                    .get_fee_account_address_and_bump_seed()
+                    .0,
+                    lst.mint,
+                )
+            })
+            .collect();
+
+        let program_accs = rpc.get_program_accounts(&program_id).await.unwrap();
+        let ixs: Vec<Instruction> = program_accs
+            .iter()
+            .filter(|(pk, acc)| {
+                if *pk == state_pda {
+                    acc.data.len() == LEGACY_STATE_SIZE
+                } else {
+                    acc.data.len() == LEGACY_FEE_ACCOUNT_SIZE
+                }
+            })
+            .filter_map(|(pk, _acc)| {
+                let lst_mint = if *pk == state_pda {
+                    None
+                } else {
+                    match pda_to_mint.get(pk) {
+                        Some(mint) => Some(*mint),
+                        None => {
+                            eprintln!("Skipping FeeAccount {pk} of unknown LST");
+                            return None;
+                        }
+                    }
+                };
+                Some(
+                    migrate_account_ix_with_program_id(
+                        program_id,
+                        MigrateAccountFreeArgs {
+                            payer: payer.pubkey(),
+                            migrate_acc: *pk,
+                            lst_mint,
+                        }
+                        .resolve(),
+                    )
+                    .unwrap(),
+                )
+            })
+            .collect();
+
+        if ixs.is_empty() {
+            eprintln!("No accounts to migrate");
+            return;
+        }
+        eprintln!("Migrating {} accounts", ixs.len());
+
+        for chunk in ixs.chunks(MIGRATE_IXS_PER_TX) {
+            handle_tx_full(
+                &rpc,
+                args.fee_limit_cb,
+                args.send_mode,
+                chunk.to_vec(),
+                &[],
+                &mut [payer.as_ref()],
+            )
+            .await;
+        }
+    }
+}
@@ -6,24 +6,48 @@ mod batch_set_fees;
 mod common;
 mod create_lut;
 mod initialize;
+mod migrate;
 mod price_exact_in;
 mod price_exact_out;
 mod price_lp_tokens_to_mint;
 mod price_lp_tokens_to_redeem;
 mod remove_lst;
+mod remove_pair_fee;
+mod schedule_lp_withdrawal_fee;
+mod schedule_lst_fee;
+mod set_admin;
+mod set_fee_bounds;
+mod set_lp_deposit_fee;
 mod set_lp_withdrawal_fee;
 mod set_lst_fee;
+mod set_lst_lp_deposit_fee;
+mod set_lst_lp_withdrawal_fee;
+mod set_lst_surcharge;
 mod set_manager;
+mod set_pair_fee;
+mod set_surge_fee;
 mod view;
 mod view_lst;
 
 use add_lst::AddLstArgs;
 use create_lut::CreateLutArgs;
 use initialize::InitializeArgs;
+use migrate::MigrateArgs;
 use remove_lst::RemoveLstArgs;
+use remove_pair_fee::RemovePairFeeArgs;
+use schedule_lp_withdrawal_fee::ScheduleLpWithdrawalFeeArgs;
+use schedule_lst_fee::ScheduleLstFeeArgs;
+use set_admin::SetAdminArgs;
+use set_fee_bounds::SetFeeBoundsArgs;
+use set_lp_deposit_fee::SetLpDepositFeeArgs;
 use set_lp_withdrawal_fee::SetLpWithdrawalFeeArgs;
 use set_lst_fee::SetLstFeeArgs;
+use set_lst_lp_deposit_fee::SetLstLpDepositFeeArgs;
+use set_lst_lp_withdrawal_fee::SetLstLpWithdrawalFeeArgs;
+use set_lst_surcharge::SetLstSurchargeArgs;
 use set_manager::SetManagerArgs;
+use set_pair_fee::SetPairFeeArgs;
+use set_surge_fee::SetSurgeFeeArgs;
 
 use self::{
     price_exact_in::PriceExactInArgs, price_exact_out::PriceExactOutArgs,
@@ -35,10 +59,21 @@ use self::{
 pub enum Subcmd {
     Initialize,
     SetManager(SetManagerArgs),
+    SetAdmin(SetAdminArgs),
+    SetFeeBounds(SetFeeBoundsArgs),
     AddLst(AddLstArgs),
     RemoveLst(RemoveLstArgs),
     SetLstFee(SetLstFeeArgs),
     SetLpWithdrawalFee(SetLpWithdrawalFeeArgs),
+    ScheduleLstFee(ScheduleLstFeeArgs),
+    ScheduleLpWithdrawalFee(ScheduleLpWithdrawalFeeArgs),
+    SetLpDepositFee(SetLpDepositFeeArgs),
+    SetLstLpDepositFee(SetLstLpDepositFeeArgs),
+    SetLstLpWithdrawalFee(SetLstLpWithdrawalFeeArgs),
+    SetSurgeFee(SetSurgeFeeArgs),
+    SetLstSurcharge(SetLstSurchargeArgs),
+    SetPairFee(SetPairFeeArgs),
+    RemovePairFee(RemovePairFeeArgs),
     View(ViewArgs),
     ViewLst(ViewLstArgs),
     PriceExactIn(PriceExactInArgs),
@@ -47,6 +82,7 @@ pub enum Subcmd {
     PriceLpTokensToRedeem(PriceLpTokensToRedeemArgs),
     CreateLut(CreateLutArgs),
     BatchSetFees(BatchSetFeesArgs),
+    Migrate(MigrateArgs),
 }
 
 impl Subcmd {
@@ -54,10 +90,21 @@ impl Subcmd {
         match &args.subcmd {
             Self::Initialize => InitializeArgs::run(args).await,
             Self::SetManager(_) => SetManagerArgs::run(args).await,
+            Self::SetAdmin(_) => SetAdminArgs::run(args).await,
+            Self::SetFeeBounds(_) => SetFeeBoundsArgs::run(args).await,
             Self::AddLst(_) => AddLstArgs::run(args).await,
             Self::RemoveLst(_) => RemoveLstArgs::run(args).await,
             Self::SetLstFee(_) => SetLstFeeArgs::run(args).await,
             Self::SetLpWithdrawalFee(_) => SetLpWithdrawalFeeArgs::run(args).await,
+            Self::ScheduleLstFee(_) => ScheduleLstFeeArgs::run(args).await,
+            Self::ScheduleLpWithdrawalFee(_) => ScheduleLpWithdrawalFeeArgs::run(args).await,
+            Self::SetLpDepositFee(_) => SetLpDepositFeeArgs::run(args).await,
+            Self::SetLstLpDepositFee(_) => SetLstLpDepositFeeArgs::run(args).await,
+            Self::SetLstLpWithdrawalFee(_) => SetLstLpWithdrawalFeeArgs::run(args).await,
+            Self::SetSurgeFee(_) => SetSurgeFeeArgs::run(args).await,
+            Self::SetLstSurcharge(_) => SetLstSurchargeArgs::run(args).await,
+            Self::SetPairFee(_) => SetPairFeeArgs::run(args).await,
+            Self::RemovePairFee(_) => RemovePairFeeArgs::run(args).await,
             Self::View(_) => ViewArgs::run(args).await,
             Self::ViewLst(_) => ViewLstArgs::run(args).await,
             Self::PriceExactIn(_) => PriceExactInArgs::run(args).await,
@@ -66,6 +113,7 @@ impl Subcmd {
             Self::PriceLpTokensToRedeem(_) => PriceLpTokensToRedeemArgs::run(args).await,
             Self::CreateLut(_) => CreateLutArgs::run(args).await,
             Self::BatchSetFees(_) => BatchSetFeesArgs::run(args).await,
+            Self::Migrate(_) => MigrateArgs::run(args).await,
         }
     }
 }
@@ -1,11 +1,18 @@
 use clap::Args;
-use flat_fee_interface::{price_exact_in_ix_with_program_id, PriceExactInIxArgs, PriceExactInKeys};
-use flat_fee_lib::pda::FeeAccountFindPdaArgs;
+use flat_fee_interface::{price_exact_in_ix_with_program_id, PriceExactInIxArgs};
+use flat_fee_lib::account_resolvers::PriceExactInFreeArgs;
+use s_controller_lib::program::{LST_STATE_LIST_ID, POOL_STATE_ID};
 use solana_sdk::native_token::sol_to_lamports;
 
 use crate::lst_arg::LstArg;
 
-use super::{common::handle_pricing_ix, Subcmd};
+use super::{
+    common::{
+        find_lst_surcharge_stake_pool, find_pair_fee_account_meta, find_s_pool_reserves,
+        handle_pricing_ix,
+    },
+    Subcmd,
+};
 
 #[derive(Args, Debug)]
 #[command(
@@ -57,33 +64,35 @@ impl PriceExactInArgs {
         let program_id = args.program;
 
         let input_lst_mint = input.mint();
-        let input_fee_acc = FeeAccountFindPdaArgs {
-            program_id,
-            lst_mint: input_lst_mint,
-        }
-        .get_fee_account_address_and_bump_seed()
-        .0;
         let output_lst_mint = output.mint();
-        let output_fee_acc = FeeAccountFindPdaArgs {
-            program_id,
-            lst_mint: output_lst_mint,
-        }
-        .get_fee_account_address_and_bump_seed()
-        .0;
-        let ix = price_exact_in_ix_with_program_id(
+        let input_pool_reserves = find_s_pool_reserves(&rpc, input_lst_mint).await;
+        let output_pool_reserves = find_s_pool_reserves(&rpc, output_lst_mint).await;
+        let input_stake_pool =
+            find_lst_surcharge_stake_pool(&rpc, program_id, input_lst_mint).await;
+        let output_stake_pool =
+            find_lst_surcharge_stake_pool(&rpc, program_id, output_lst_mint).await;
+        let mut ix = price_exact_in_ix_with_program_id(
             program_id,
-            PriceExactInKeys {
+            PriceExactInFreeArgs {
                 input_lst_mint,
                 output_lst_mint,
-                input_fee_acc,
-                output_fee_acc,
-            },
+                pool_state: POOL_STATE_ID,
+                lst_state_list: LST_STATE_LIST_ID,
+                input_pool_reserves,
+                output_pool_reserves,
+                input_stake_pool,
+                output_stake_pool,
+            }
+            .resolve_for_prog(program_id),
             PriceExactInIxArgs {
                 amount: sol_to_lamports(amount),
                 sol_value: sol_to_lamports(sol_value),
             },
         )
         .unwrap();
+        ix.accounts.extend(
+            find_pair_fee_account_meta(&rpc, program_id, input_lst_mint, output_lst_mint).await,
+        );
 
         handle_pricing_ix(&rpc, ix, payer.as_ref()).await;
     }
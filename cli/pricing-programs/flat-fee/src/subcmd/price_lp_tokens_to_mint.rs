@@ -1,12 +1,15 @@
 use clap::Args;
-use flat_fee_interface::{
-    price_lp_tokens_to_mint_ix_with_program_id, PriceLpTokensToMintIxArgs, PriceLpTokensToMintKeys,
-};
+use flat_fee_interface::{price_lp_tokens_to_mint_ix_with_program_id, PriceLpTokensToMintIxArgs};
+use flat_fee_lib::account_resolvers::PriceLpTokensToMintFreeArgs;
+use s_controller_lib::program::{LST_STATE_LIST_ID, POOL_STATE_ID};
 use solana_sdk::native_token::sol_to_lamports;
 
 use crate::lst_arg::LstArg;
 
-use super::{common::handle_pricing_ix, Subcmd};
+use super::{
+    common::{find_s_pool_reserves, handle_pricing_ix},
+    Subcmd,
+};
 
 #[derive(Args, Debug)]
 #[command(
@@ -51,11 +54,17 @@ impl PriceLpTokensToMintArgs {
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
 
+        let input_lst_mint = lst_mint.mint();
+        let pool_reserves = find_s_pool_reserves(&rpc, input_lst_mint).await;
         let ix = price_lp_tokens_to_mint_ix_with_program_id(
             program_id,
-            PriceLpTokensToMintKeys {
-                input_lst_mint: lst_mint.mint(),
-            },
+            PriceLpTokensToMintFreeArgs {
+                input_lst_mint,
+                pool_state: POOL_STATE_ID,
+                lst_state_list: LST_STATE_LIST_ID,
+                pool_reserves,
+            }
+            .resolve_for_prog(program_id),
             PriceLpTokensToMintIxArgs {
                 amount: sol_to_lamports(amount),
                 sol_value: sol_to_lamports(sol_value),
@@ -1,14 +1,17 @@
 use clap::Args;
 use flat_fee_interface::{
     price_lp_tokens_to_redeem_ix_with_program_id, PriceLpTokensToRedeemIxArgs,
-    PriceLpTokensToRedeemKeys,
 };
-use flat_fee_lib::pda::ProgramStateFindPdaArgs;
+use flat_fee_lib::account_resolvers::PriceLpTokensToRedeemFreeArgs;
+use s_controller_lib::program::{LST_STATE_LIST_ID, POOL_STATE_ID};
 use solana_sdk::native_token::sol_to_lamports;
 
 use crate::lst_arg::LstArg;
 
-use super::{common::handle_pricing_ix, Subcmd};
+use super::{
+    common::{find_s_pool_reserves, handle_pricing_ix},
+    Subcmd,
+};
 
 #[derive(Args, Debug)]
 #[command(
@@ -53,15 +56,17 @@ impl PriceLpTokensToRedeemArgs {
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
 
-        let state = ProgramStateFindPdaArgs { program_id }
-            .get_program_state_address_and_bump_seed()
-            .0;
+        let output_lst_mint = lst_mint.mint();
+        let pool_reserves = find_s_pool_reserves(&rpc, output_lst_mint).await;
         let ix = price_lp_tokens_to_redeem_ix_with_program_id(
             program_id,
-            PriceLpTokensToRedeemKeys {
-                output_lst_mint: lst_mint.mint(),
-                state,
-            },
+            PriceLpTokensToRedeemFreeArgs {
+                output_lst_mint,
+                pool_state: POOL_STATE_ID,
+                lst_state_list: LST_STATE_LIST_ID,
+                pool_reserves,
+            }
+            .resolve_for_prog(program_id),
             PriceLpTokensToRedeemIxArgs {
                 amount: sol_to_lamports(amount),
                 sol_value: sol_to_lamports(sol_value),
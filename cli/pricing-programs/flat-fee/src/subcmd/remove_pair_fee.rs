@@ -0,0 +1,95 @@
+use clap::Args;
+use flat_fee_interface::remove_pair_fee_ix_with_program_id;
+use flat_fee_lib::{
+    account_resolvers::RemovePairFeeFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state_allow_legacy,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(long_about = "Remove the fee override for swapping from input LST to output LST")]
+pub struct RemovePairFeeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        help = "Input LST. Can either be a pubkey or case-sensitive symbol of a token on sanctum-lst-list. e.g. 'bSOL'"
+    )]
+    pub input: String,
+
+    #[arg(
+        help = "Output LST. Can either be a pubkey or case-sensitive symbol of a token on sanctum-lst-list. e.g. 'bSOL'"
+    )]
+    pub output: String,
+
+    #[arg(help = "Account to refund SOL rent to")]
+    pub refund_rent_to: String,
+}
+
+impl RemovePairFeeArgs {
+    pub async fn run(args: crate::Args) {
+        let slsts = args.load_slst_list();
+        let Self {
+            manager,
+            input,
+            output,
+            refund_rent_to,
+        } = match args.subcmd {
+            Subcmd::RemovePairFee(a) => a,
+            _ => unreachable!(),
+        };
+        let [input, output] = [input, output].map(|a| LstArg::parse_arg(&a, &slsts).unwrap());
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let refund_rent_to = PubkeySrc::parse(&refund_rent_to).unwrap();
+
+        let ix = remove_pair_fee_ix_with_program_id(
+            program_id,
+            RemovePairFeeFreeArgs {
+                refund_rent_to: refund_rent_to.pubkey(),
+                input_lst_mint: input.mint(),
+                output_lst_mint: output.mint(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,102 @@
+use clap::Args;
+use flat_fee_interface::{schedule_lst_fee_ix_with_program_id, ScheduleLstFeeIxArgs};
+use flat_fee_lib::{
+    account_resolvers::ScheduleLstFeeByMintFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state_allow_legacy,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Schedule a change to the fees for given LST, taking effect from the start of the given epoch"
+)]
+pub struct ScheduleLstFeeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        help = "Mint of the LST to schedule fees for. Can either be a pubkey or case-sensitive symbol of a token on sanctum-lst-list. e.g. 'bSOL'"
+    )]
+    pub lst_mint: String,
+
+    #[arg(help = "Fee in bips to impose when the LST is used as input")]
+    pub input_fee_bps: i16,
+
+    #[arg(help = "Fee in bips to impose when the LST is used as output")]
+    pub output_fee_bps: i16,
+
+    #[arg(
+        help = "Epoch from which the new fees take effect. Must be at least 2 epochs after the current epoch"
+    )]
+    pub activation_epoch: u64,
+}
+
+impl ScheduleLstFeeArgs {
+    pub async fn run(args: crate::Args) {
+        let slsts = args.load_slst_list();
+        let Self {
+            manager,
+            lst_mint,
+            input_fee_bps,
+            output_fee_bps,
+            activation_epoch,
+        } = match args.subcmd {
+            Subcmd::ScheduleLstFee(a) => a,
+            _ => unreachable!(),
+        };
+        let lst_mint = LstArg::parse_arg(&lst_mint, &slsts).unwrap();
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let ix = schedule_lst_fee_ix_with_program_id(
+            program_id,
+            ScheduleLstFeeByMintFreeArgs {
+                lst_mint: lst_mint.mint(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+            ScheduleLstFeeIxArgs {
+                input_fee_bps,
+                output_fee_bps,
+                activation_epoch,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,76 @@
+use clap::Args;
+use flat_fee_interface::set_admin_ix_with_program_id;
+use flat_fee_lib::{
+    account_resolvers::SetAdminFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state_allow_legacy,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use super::{common::verify_admin, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(long_about = "Sets the flat-fee pricing program's admin, the authority over fee bounds")]
+pub struct SetAdminArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current admin signer. Defaults to config wallet if not set."
+    )]
+    pub curr_admin: Option<String>,
+
+    #[arg(help = "The new program's admin to set. Can be a pubkey or signer.")]
+    pub new_admin: String,
+}
+
+impl SetAdminArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            curr_admin,
+            new_admin,
+        } = match args.subcmd {
+            Subcmd::SetAdmin(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let curr_admin_signer =
+            curr_admin.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let curr_admin = curr_admin_signer.as_ref().unwrap_or(&payer);
+
+        let new_admin = PubkeySrc::parse(&new_admin).unwrap();
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_admin(&state, curr_admin.pubkey()).unwrap();
+
+        let ix = set_admin_ix_with_program_id(
+            program_id,
+            SetAdminFreeArgs {
+                new_admin: new_admin.pubkey(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), curr_admin.as_ref()],
+        )
+        .await;
+    }
+}
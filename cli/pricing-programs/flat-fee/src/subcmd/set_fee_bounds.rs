@@ -0,0 +1,116 @@
+use clap::Args;
+use flat_fee_interface::{set_fee_bounds_ix_with_program_id, SetFeeBoundsIxArgs};
+use flat_fee_lib::{
+    account_resolvers::SetFeeBoundsFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state_allow_legacy,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use super::{common::verify_admin, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(long_about = "Sets the bounds that the manager's fee updates must stay within")]
+pub struct SetFeeBoundsArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current admin signer. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        long,
+        allow_negative_numbers = true,
+        help = "Minimum input and output fee in bips that can be set for an LST"
+    )]
+    pub min_lst_fee_bps: i16,
+
+    #[arg(
+        long,
+        allow_negative_numbers = true,
+        help = "Maximum input and output fee in bips that can be set for an LST"
+    )]
+    pub max_lst_fee_bps: i16,
+
+    #[arg(long, help = "Maximum LP withdrawal fee in bips that can be set")]
+    pub max_lp_withdrawal_fee_bps: u16,
+
+    #[arg(
+        long,
+        help = "Maximum change in bips that fee updates can make to a fee within an epoch"
+    )]
+    pub max_fee_change_bps: u16,
+
+    #[arg(long, help = "Maximum LP deposit fee in bips that can be set")]
+    pub max_lp_deposit_fee_bps: u16,
+
+    #[arg(
+        long,
+        help = "Maximum surge fee and LST surcharge in bips that can be set"
+    )]
+    pub max_surge_fee_bps: u16,
+}
+
+impl SetFeeBoundsArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            min_lst_fee_bps,
+            max_lst_fee_bps,
+            max_lp_withdrawal_fee_bps,
+            max_fee_change_bps,
+            max_lp_deposit_fee_bps,
+            max_surge_fee_bps,
+        } = match args.subcmd {
+            Subcmd::SetFeeBounds(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let admin_signer =
+            admin.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_admin(&state, admin.pubkey()).unwrap();
+
+        let ix = set_fee_bounds_ix_with_program_id(
+            program_id,
+            SetFeeBoundsFreeArgs {
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+            SetFeeBoundsIxArgs {
+                min_lst_fee_bps,
+                max_lst_fee_bps,
+                max_lp_withdrawal_fee_bps,
+                max_fee_change_bps,
+                max_lp_deposit_fee_bps,
+                max_surge_fee_bps,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,75 @@
+use clap::Args;
+use flat_fee_interface::{set_lp_deposit_fee_ix_with_program_id, SetLpDepositFeeIxArgs};
+use flat_fee_lib::{
+    account_resolvers::SetLpDepositFeeFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state_allow_legacy,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(long_about = "Update the global fee imposed for minting LP token with LST")]
+pub struct SetLpDepositFeeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(help = "Fee in bips to impose when minting LP token with any LST")]
+    pub lp_deposit_fee_bps: u16,
+}
+
+impl SetLpDepositFeeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            lp_deposit_fee_bps,
+        } = match args.subcmd {
+            Subcmd::SetLpDepositFee(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let ix = set_lp_deposit_fee_ix_with_program_id(
+            program_id,
+            SetLpDepositFeeFreeArgs {
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+            SetLpDepositFeeIxArgs { lp_deposit_fee_bps },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
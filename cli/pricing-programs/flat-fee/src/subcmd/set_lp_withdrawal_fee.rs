@@ -2,7 +2,7 @@ use clap::Args;
 use flat_fee_interface::{set_lp_withdrawal_fee_ix_with_program_id, SetLpWithdrawalFeeIxArgs};
 use flat_fee_lib::{
     account_resolvers::SetLpWithdrawalFeeFreeArgs, pda::ProgramStateFindPdaArgs,
-    utils::try_program_state,
+    utils::try_program_state_allow_legacy,
 };
 use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
 use sanctum_solana_cli_utils::PubkeySrc;
@@ -11,7 +11,9 @@ use solana_readonly_account::sdk::KeyedAccount;
 use super::{common::verify_manager, Subcmd};
 
 #[derive(Args, Debug)]
-#[command(long_about = "Update the fees imposed for redeeming LP token for LST")]
+#[command(
+    long_about = "Decrease the fees imposed for redeeming LP token for LST immediately. Use schedule-lp-withdrawal-fee to increase them"
+)]
 pub struct SetLpWithdrawalFeeArgs {
     #[arg(
         long,
@@ -45,8 +47,8 @@ impl SetLpWithdrawalFeeArgs {
             .get_program_state_address_and_bump_seed()
             .0;
         let state_acc = rpc.get_account(&state_pda).await.unwrap();
-        let state = try_program_state(&state_acc.data).unwrap();
-        verify_manager(state, manager.pubkey()).unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
 
         let ix = set_lp_withdrawal_fee_ix_with_program_id(
             program_id,
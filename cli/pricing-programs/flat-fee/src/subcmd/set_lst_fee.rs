@@ -2,7 +2,7 @@ use clap::Args;
 use flat_fee_interface::{set_lst_fee_ix_with_program_id, SetLstFeeIxArgs};
 use flat_fee_lib::{
     account_resolvers::SetLstFeeByMintFreeArgs, pda::ProgramStateFindPdaArgs,
-    utils::try_program_state,
+    utils::try_program_state_allow_legacy,
 };
 use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
 use sanctum_solana_cli_utils::PubkeySrc;
@@ -13,7 +13,9 @@ use crate::lst_arg::LstArg;
 use super::{common::verify_manager, Subcmd};
 
 #[derive(Args, Debug)]
-#[command(long_about = "Update the fees for given LST")]
+#[command(
+    long_about = "Decrease the fees for given LST immediately. Use schedule-lst-fee to increase them"
+)]
 pub struct SetLstFeeArgs {
     #[arg(
         long,
@@ -59,8 +61,8 @@ impl SetLstFeeArgs {
             .get_program_state_address_and_bump_seed()
             .0;
         let state_acc = rpc.get_account(&state_pda).await.unwrap();
-        let state = try_program_state(&state_acc.data).unwrap();
-        verify_manager(state, manager.pubkey()).unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
 
         let ix = set_lst_fee_ix_with_program_id(
             program_id,
@@ -0,0 +1,112 @@
+use clap::Args;
+use flat_fee_interface::{set_lst_surcharge_ix_with_program_id, SetLstSurchargeIxArgs};
+use flat_fee_lib::{
+    account_resolvers::SetLstSurchargeFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state_allow_legacy,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+/// Offset of `last_update_epoch` in SPL stake pool accounts
+const SPL_STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET: u16 = 274;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Update the surcharge imposed on swaps involving the given LST while its stake pool has not been updated for the current epoch"
+)]
+pub struct SetLstSurchargeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        long,
+        short,
+        default_value_t = SPL_STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET,
+        help = "Byte offset of the little-endian u64 last update epoch in the stake pool account. Defaults to the SPL stake pool offset if not set."
+    )]
+    pub last_update_epoch_offset: u16,
+
+    #[arg(
+        help = "Mint of the LST to set the surcharge for. Can either be a pubkey or case-sensitive symbol of a token on sanctum-lst-list. e.g. 'bSOL'"
+    )]
+    pub lst_mint: String,
+
+    #[arg(help = "The LST's stake pool account")]
+    pub stake_pool: String,
+
+    #[arg(help = "Surcharge in bips to impose while the stake pool is not yet updated")]
+    pub surcharge_bps: u16,
+}
+
+impl SetLstSurchargeArgs {
+    pub async fn run(args: crate::Args) {
+        let slsts = args.load_slst_list();
+        let Self {
+            manager,
+            last_update_epoch_offset,
+            lst_mint,
+            stake_pool,
+            surcharge_bps,
+        } = match args.subcmd {
+            Subcmd::SetLstSurcharge(a) => a,
+            _ => unreachable!(),
+        };
+        let lst_mint = LstArg::parse_arg(&lst_mint, &slsts).unwrap();
+        let stake_pool: Pubkey = stake_pool.parse().unwrap();
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let (keys, _create_pda_args) = SetLstSurchargeFreeArgs {
+            payer: payer.pubkey(),
+            state_acc: KeyedAccount {
+                pubkey: state_pda,
+                account: state_acc,
+            },
+            lst_mint: lst_mint.mint(),
+            stake_pool,
+        }
+        .resolve_for_prog(program_id)
+        .unwrap();
+        let ix = set_lst_surcharge_ix_with_program_id(
+            program_id,
+            keys,
+            SetLstSurchargeIxArgs {
+                surcharge_bps,
+                last_update_epoch_offset,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -1,6 +1,6 @@
 use clap::Args;
 use flat_fee_interface::{set_manager_ix_with_program_id, SetManagerKeys};
-use flat_fee_lib::{pda::ProgramStateFindPdaArgs, utils::try_program_state};
+use flat_fee_lib::{pda::ProgramStateFindPdaArgs, utils::try_program_state_allow_legacy};
 use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
 use sanctum_solana_cli_utils::PubkeySrc;
 
@@ -42,8 +42,8 @@ impl SetManagerArgs {
             .get_program_state_address_and_bump_seed()
             .0;
         let state_data = rpc.get_account_data(&state_pda).await.unwrap();
-        let state = try_program_state(&state_data).unwrap();
-        verify_manager(state, curr_manager.pubkey()).unwrap();
+        let state = try_program_state_allow_legacy(&state_data).unwrap();
+        verify_manager(&state, curr_manager.pubkey()).unwrap();
 
         let ix = set_manager_ix_with_program_id(
             program_id,
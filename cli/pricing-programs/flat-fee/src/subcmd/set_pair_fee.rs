@@ -0,0 +1,137 @@
+use clap::Args;
+use flat_fee_interface::{
+    add_pair_fee_ix_with_program_id, set_pair_fee_ix_with_program_id, AddPairFeeIxArgs,
+    SetPairFeeIxArgs,
+};
+use flat_fee_lib::{
+    account_resolvers::{AddPairFeeFreeArgs, SetPairFeeByMintsFreeArgs},
+    pda::{PairFeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
+    utils::try_program_state_allow_legacy,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Set the fee override for swapping from input LST to output LST, creating the PairFeeAccount if it does not exist"
+)]
+pub struct SetPairFeeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        help = "Input LST. Can either be a pubkey or case-sensitive symbol of a token on sanctum-lst-list. e.g. 'bSOL'"
+    )]
+    pub input: String,
+
+    #[arg(
+        help = "Output LST. Can either be a pubkey or case-sensitive symbol of a token on sanctum-lst-list. e.g. 'bSOL'"
+    )]
+    pub output: String,
+
+    #[arg(
+        help = "Fee in bips to impose when swapping from input LST to output LST, replacing the sum of the LSTs' input and output fees"
+    )]
+    pub fee_bps: i16,
+}
+
+impl SetPairFeeArgs {
+    pub async fn run(args: crate::Args) {
+        let slsts = args.load_slst_list();
+        let Self {
+            manager,
+            input,
+            output,
+            fee_bps,
+        } = match args.subcmd {
+            Subcmd::SetPairFee(a) => a,
+            _ => unreachable!(),
+        };
+        let [input, output] = [input, output].map(|a| LstArg::parse_arg(&a, &slsts).unwrap());
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let input_lst_mint = input.mint();
+        let output_lst_mint = output.mint();
+        let pair_fee_acc = PairFeeAccountFindPdaArgs {
+            program_id,
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .get_pair_fee_account_address_and_bump_seed()
+        .0;
+        let pair_fee_acc_exists = rpc
+            .get_multiple_accounts(&[pair_fee_acc])
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .flatten()
+            .is_some();
+        let state_acc = KeyedAccount {
+            pubkey: state_pda,
+            account: state_acc,
+        };
+
+        let ix = if pair_fee_acc_exists {
+            set_pair_fee_ix_with_program_id(
+                program_id,
+                SetPairFeeByMintsFreeArgs {
+                    input_lst_mint,
+                    output_lst_mint,
+                    state_acc,
+                }
+                .resolve_for_prog(program_id)
+                .unwrap(),
+                SetPairFeeIxArgs { fee_bps },
+            )
+            .unwrap()
+        } else {
+            add_pair_fee_ix_with_program_id(
+                program_id,
+                AddPairFeeFreeArgs {
+                    payer: payer.pubkey(),
+                    state_acc,
+                    input_lst_mint,
+                    output_lst_mint,
+                }
+                .resolve_for_prog(program_id)
+                .unwrap()
+                .0,
+                AddPairFeeIxArgs { fee_bps },
+            )
+            .unwrap()
+        };
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
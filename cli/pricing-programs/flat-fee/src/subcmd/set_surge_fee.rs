@@ -0,0 +1,87 @@
+use clap::Args;
+use flat_fee_interface::{set_surge_fee_ix_with_program_id, SetSurgeFeeIxArgs};
+use flat_fee_lib::{
+    account_resolvers::SetSurgeFeeFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state_allow_legacy,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Update the surge fee imposed on swaps and LP redemptions at the start of every epoch"
+)]
+pub struct SetSurgeFeeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(help = "Surge fee in bips to impose at the first slot of the epoch")]
+    pub max_surge_fee_bps: u16,
+
+    #[arg(
+        help = "Number of slots after the start of the epoch over which the surge fee decays linearly to 0. 0 disables the surge fee."
+    )]
+    pub decay_slots: u32,
+}
+
+impl SetSurgeFeeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            max_surge_fee_bps,
+            decay_slots,
+        } = match args.subcmd {
+            Subcmd::SetSurgeFee(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state_allow_legacy(&state_acc.data).unwrap();
+        verify_manager(&state, manager.pubkey()).unwrap();
+
+        let ix = set_surge_fee_ix_with_program_id(
+            program_id,
+            SetSurgeFeeFreeArgs {
+                payer: payer.pubkey(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+            SetSurgeFeeIxArgs {
+                max_surge_fee_bps,
+                decay_slots,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -2,8 +2,15 @@ use std::collections::HashMap;
 
 use clap::Args;
 use flat_fee_lib::{
-    pda::{FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
-    utils::{try_fee_account, try_program_state},
+    pda::{
+        FeeAccountFindPdaArgs, LstSurchargeFindPdaArgs, ProgramStateFindPdaArgs,
+        SurgeConfigFindPdaArgs,
+    },
+    program::{LST_SURCHARGE_SIZE, PAIR_FEE_ACCOUNT_SIZE},
+    utils::{
+        try_fee_account_allow_legacy, try_lst_surcharge, try_pair_fee_account,
+        try_program_state_allow_legacy, try_surge_config,
+    },
 };
 use sanctum_lst_list::SanctumLst;
 use solana_sdk::pubkey::Pubkey;
@@ -28,6 +35,9 @@ impl ViewArgs {
         let state_pda = ProgramStateFindPdaArgs { program_id }
             .get_program_state_address_and_bump_seed()
             .0;
+        let surge_config_pda = SurgeConfigFindPdaArgs { program_id }
+            .get_surge_config_address_and_bump_seed()
+            .0;
 
         let pda_to_lst: HashMap<Pubkey, &SanctumLst> = slsts
             .iter()
@@ -43,29 +53,73 @@ impl ViewArgs {
                 )
             })
             .collect();
+        let surcharge_pda_to_lst: HashMap<Pubkey, &SanctumLst> = slsts
+            .iter()
+            .map(|lst| {
+                (
+                    LstSurchargeFindPdaArgs {
+                        program_id,
+                        lst_mint: lst.mint,
+                    }
+                    .get_lst_surcharge_address_and_bump_seed()
+                    .0,
+                    lst,
+                )
+            })
+            .collect();
 
         let mut program_accs = rpc.get_program_accounts(&program_id).await.unwrap();
         program_accs.retain(|(pk, acc)| {
             if *pk == state_pda {
-                let state = try_program_state(&acc.data).unwrap();
+                let state = try_program_state_allow_legacy(&acc.data).unwrap();
                 println!("{state:#?}");
                 println!();
                 false
+            } else if *pk == surge_config_pda {
+                let surge_config = try_surge_config(&acc.data).unwrap();
+                println!("{surge_config:#?}");
+                println!();
+                false
             } else {
                 true
             }
         });
+        let (surcharge_accs, program_accs): (Vec<_>, Vec<_>) = program_accs
+            .into_iter()
+            .partition(|(_pk, acc)| acc.data.len() == LST_SURCHARGE_SIZE);
+        let (pair_fee_accs, program_accs): (Vec<_>, Vec<_>) = program_accs
+            .into_iter()
+            .partition(|(_pk, acc)| acc.data.len() == PAIR_FEE_ACCOUNT_SIZE);
 
         for (pk, acc) in program_accs.iter() {
             let symbol = pda_to_lst
                 .get(pk)
                 .map_or_else(|| "Unknown LST", |SanctumLst { symbol, .. }| symbol);
             println!("{symbol} (PDA {pk}):");
-            let fee = try_fee_account(&acc.data).unwrap();
+            let fee = try_fee_account_allow_legacy(&acc.data).unwrap();
             println!("{fee:#?}");
             println!();
         }
 
+        for (pk, acc) in surcharge_accs.iter() {
+            let symbol = surcharge_pda_to_lst
+                .get(pk)
+                .map_or_else(|| "Unknown LST", |SanctumLst { symbol, .. }| symbol);
+            println!("{symbol} surcharge (PDA {pk}):");
+            let surcharge = try_lst_surcharge(&acc.data).unwrap();
+            println!("{surcharge:#?}");
+            println!();
+        }
+
+        // PairFeeAccount PDAs cannot be mapped back to their LST pair without
+        // deriving every pair's PDA, so only their addresses are printed
+        for (pk, acc) in pair_fee_accs.iter() {
+            println!("Pair fee override (PDA {pk}):");
+            let pair_fee = try_pair_fee_account(&acc.data).unwrap();
+            println!("{pair_fee:#?}");
+            println!();
+        }
+
         println!("{} LSTs total", program_accs.len());
     }
 }
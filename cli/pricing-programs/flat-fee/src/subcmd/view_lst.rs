@@ -1,5 +1,12 @@
 use clap::Args;
-use flat_fee_lib::{pda::FeeAccountFindPdaArgs, utils::try_fee_account};
+use flat_fee_lib::{
+    calc::{
+        effective_lp_withdrawal_fee_bps, fee_account_lst_fees, fee_account_upcoming_lst_fees,
+        program_state_lp_withdrawal_fee_bps, LstFees, PendingFee,
+    },
+    pda::{FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
+    utils::{try_fee_account_allow_legacy, try_program_state_allow_legacy},
+};
 
 use crate::{lst_arg::LstArg, subcmd::Subcmd};
 
@@ -31,8 +38,45 @@ impl ViewLstArgs {
         .get_fee_account_address_and_bump_seed()
         .0;
         let fee_account_data = rpc.get_account_data(&fee_account_pda).await.unwrap();
-        let fee_account = try_fee_account(&fee_account_data).unwrap();
+        let fee_account = &try_fee_account_allow_legacy(&fee_account_data).unwrap();
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_data = rpc.get_account_data(&state_pda).await.unwrap();
+        let state = &try_program_state_allow_legacy(&state_data).unwrap();
+        let epoch = rpc.get_epoch_info().await.unwrap().epoch;
+        let lp_withdrawal_fee_bps = effective_lp_withdrawal_fee_bps(
+            program_state_lp_withdrawal_fee_bps(state, epoch),
+            fee_account,
+        );
+        let lp_withdrawal_fee_src = if fee_account.has_lp_withdrawal_fee == 0 {
+            "global"
+        } else {
+            "LST"
+        };
+        let LstFees {
+            input_fee_bps,
+            output_fee_bps,
+        } = fee_account_lst_fees(fee_account, epoch);
 
         println!("{fee_account:#?}");
+        println!("Effective input fee: {input_fee_bps} bps");
+        println!("Effective output fee: {output_fee_bps} bps");
+        if let Some(PendingFee {
+            fee:
+                LstFees {
+                    input_fee_bps,
+                    output_fee_bps,
+                },
+            activation_epoch,
+        }) = fee_account_upcoming_lst_fees(fee_account, epoch)
+        {
+            println!("Upcoming input fee: {input_fee_bps} bps from epoch {activation_epoch}");
+            println!("Upcoming output fee: {output_fee_bps} bps from epoch {activation_epoch}");
+        }
+        println!(
+            "Effective LP withdrawal fee: {lp_withdrawal_fee_bps} bps ({lp_withdrawal_fee_src})"
+        );
     }
 }
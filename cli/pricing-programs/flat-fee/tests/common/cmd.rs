@@ -101,6 +101,8 @@ pub trait TestCmd {
 
     fn cmd_set_lp_withdrawal_fee(&mut self) -> &mut Self;
 
+    fn cmd_set_lp_deposit_fee(&mut self) -> &mut Self;
+
     fn cmd_add_lst(&mut self) -> &mut Self;
 
     fn cmd_remove_lst(&mut self) -> &mut Self;
@@ -127,6 +129,10 @@ impl TestCmd for Command {
         self.arg("set-lp-withdrawal-fee")
     }
 
+    fn cmd_set_lp_deposit_fee(&mut self) -> &mut Self {
+        self.arg("set-lp-deposit-fee")
+    }
+
     fn cmd_add_lst(&mut self) -> &mut Self {
         self.arg("add-lst")
     }
@@ -1,6 +1,7 @@
 mod add_lst;
 mod initialize;
 mod remove_lst;
+mod set_lp_deposit_fee;
 mod set_lp_withdrawal_fee;
 mod set_lst_fee;
 mod set_manager;
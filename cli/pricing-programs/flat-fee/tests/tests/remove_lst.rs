@@ -16,6 +16,25 @@ async fn remove_lst_success() {
     let program_state = ProgramState {
         manager: payer.pubkey(),
         lp_withdrawal_fee_bps: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        pending_lp_withdrawal_fee_bps: Default::default(),
+        has_pending_lp_withdrawal_fee: Default::default(),
+        padding: Default::default(),
+        pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+        admin: Default::default(),
+        min_lst_fee_bps: Default::default(),
+        max_lst_fee_bps: Default::default(),
+        max_lp_withdrawal_fee_bps: Default::default(),
+        max_fee_change_bps: Default::default(),
+        max_lp_deposit_fee_bps: Default::default(),
+        max_surge_fee_bps: Default::default(),
+        has_fee_bounds: Default::default(),
+        fee_bounds_padding: Default::default(),
+        baseline_lp_withdrawal_fee_bps: Default::default(),
+        baseline_lp_deposit_fee_bps: Default::default(),
+        has_fee_baseline: Default::default(),
+        fee_baseline_padding: Default::default(),
+        fee_baseline_epoch: Default::default(),
     };
 
     let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(
@@ -26,6 +45,8 @@ async fn remove_lst_success() {
         &[MockFeeAccountArgs {
             input_fee_bps: Default::default(),
             output_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
             lst_mint,
         }],
     )
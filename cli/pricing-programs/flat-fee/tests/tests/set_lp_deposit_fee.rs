@@ -0,0 +1,59 @@
+use flat_fee_interface::ProgramState;
+use flat_fee_lib::utils::try_program_state;
+use sanctum_solana_test_utils::{
+    cli::{assert_all_txs_success_nonempty, ExtendedCommand},
+    ExtendedBanksClient,
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{setup, TestCmd};
+
+async fn assert_lp_deposit_fee_bps(bc: &mut BanksClient, expected_lp_deposit_fee_bps: u16) {
+    let state_data = bc.get_account_data(flat_fee_lib::program::STATE_ID).await;
+    let state = try_program_state(&state_data).unwrap();
+    assert_eq!(state.lp_deposit_fee_bps, expected_lp_deposit_fee_bps);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_lp_deposit_fee_success() {
+    const NEW_LP_DEPOSIT_FEE_BPS: u16 = 7;
+
+    let payer = Keypair::new();
+
+    let program_state = ProgramState {
+        manager: payer.pubkey(),
+        lp_withdrawal_fee_bps: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        pending_lp_withdrawal_fee_bps: Default::default(),
+        has_pending_lp_withdrawal_fee: Default::default(),
+        padding: Default::default(),
+        pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+        admin: Default::default(),
+        min_lst_fee_bps: Default::default(),
+        max_lst_fee_bps: Default::default(),
+        max_lp_withdrawal_fee_bps: Default::default(),
+        max_fee_change_bps: Default::default(),
+        max_lp_deposit_fee_bps: Default::default(),
+        max_surge_fee_bps: Default::default(),
+        has_fee_bounds: Default::default(),
+        fee_bounds_padding: Default::default(),
+        baseline_lp_withdrawal_fee_bps: Default::default(),
+        baseline_lp_deposit_fee_bps: Default::default(),
+        has_fee_baseline: Default::default(),
+        fee_baseline_padding: Default::default(),
+        fee_baseline_epoch: Default::default(),
+    };
+    let pt = ProgramTest::default();
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) =
+        setup(pt, payer, Some(program_state), &[], &[]).await;
+
+    cmd.with_flat_fee_program()
+        .cmd_set_lp_deposit_fee()
+        .arg(NEW_LP_DEPOSIT_FEE_BPS.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_lp_deposit_fee_bps(&mut bc, NEW_LP_DEPOSIT_FEE_BPS).await;
+}
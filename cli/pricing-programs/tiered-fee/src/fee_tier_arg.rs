@@ -0,0 +1,36 @@
+use std::error::Error;
+
+use clap::ValueEnum;
+use tiered_fee_interface::FeeTier;
+use tiered_fee_lib::calc::TierBasis;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum TierBasisArg {
+    OutputReserves,
+    PoolTotal,
+}
+
+impl From<TierBasisArg> for TierBasis {
+    fn from(value: TierBasisArg) -> Self {
+        match value {
+            TierBasisArg::OutputReserves => Self::OutputReserves,
+            TierBasisArg::PoolTotal => Self::PoolTotal,
+        }
+    }
+}
+
+/// `<threshold_bps>:<fee_bps>`
+#[derive(Clone, Copy, Debug)]
+pub struct FeeTierArg(pub FeeTier);
+
+impl FeeTierArg {
+    pub fn parse_arg(arg: &str) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        let (threshold_bps, fee_bps) = arg
+            .split_once(':')
+            .ok_or_else(|| format!("Expected <threshold_bps>:<fee_bps>, got {arg}"))?;
+        Ok(Self(FeeTier {
+            threshold_bps: threshold_bps.parse()?,
+            fee_bps: fee_bps.parse()?,
+        }))
+    }
+}
@@ -0,0 +1,110 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
+use sanctum_solana_cli_utils::PubkeySrc;
+use solana_readonly_account::sdk::KeyedAccount;
+use tiered_fee_interface::{set_lst_fee_tiers_ix_with_program_id, SetLstFeeTiersIxArgs};
+use tiered_fee_lib::{
+    account_resolvers::SetLstFeeTiersByMintFreeArgs, calc::TierBasis, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state,
+};
+
+use crate::{
+    fee_tier_arg::{FeeTierArg, TierBasisArg},
+    lst_arg::LstArg,
+};
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Replace the swap size fee tiers charged when the given LST is used as output"
+)]
+pub struct SetLstFeeTiersArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        long,
+        short,
+        help = "What the tier thresholds are relative to",
+        default_value = "output-reserves",
+        value_enum
+    )]
+    pub basis: TierBasisArg,
+
+    #[arg(
+        help = "Mint of the LST to set fee tiers for. Can either be a pubkey or case-sensitive symbol of a token on sanctum-lst-list. e.g. 'bSOL'"
+    )]
+    pub lst_mint: String,
+
+    #[arg(
+        help = "Fee tiers in increasing threshold order, each formatted <threshold_bps>:<fee_bps>. e.g. '1000:10 5000:50'. Leave empty to remove all tiers",
+        value_parser = StringValueParser::new().try_map(|s| FeeTierArg::parse_arg(&s)),
+    )]
+    pub tiers: Vec<FeeTierArg>,
+}
+
+impl SetLstFeeTiersArgs {
+    pub async fn run(args: crate::Args) {
+        let slsts = args.load_slst_list();
+        let Self {
+            manager,
+            basis,
+            lst_mint,
+            tiers,
+        } = match args.subcmd {
+            Subcmd::SetLstFeeTiers(a) => a,
+            _ => unreachable!(),
+        };
+        let lst_mint = LstArg::parse_arg(&lst_mint, &slsts).unwrap();
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer =
+            manager.map(|s| pubkey_src_to_box_dyn_signer(PubkeySrc::parse(&s).unwrap()));
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let ix = set_lst_fee_tiers_ix_with_program_id(
+            program_id,
+            SetLstFeeTiersByMintFreeArgs {
+                lst_mint: lst_mint.mint(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+            SetLstFeeTiersIxArgs {
+                tier_basis: TierBasis::from(basis).into(),
+                tiers: tiers.into_iter().map(|FeeTierArg(tier)| tier).collect(),
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), manager.as_ref()],
+        )
+        .await;
+    }
+}
@@ -13,7 +13,7 @@ use solana_sdk::{
     pubkey::Pubkey,
 };
 use spl_calculator_lib::{
-    resolve_to_account_metas_for_calc, SanctumSplMultiSolValCalc, SanctumSplSolValCalc,
+    resolve_spl_to_account_metas_for_calc, SanctumSplMultiSolValCalc, SanctumSplSolValCalc,
     SplSolValCalc,
 };
 use wsol_calculator_lib::WSOL_LST_SOL_COMMON_METAS;
@@ -78,7 +78,7 @@ pub fn sol_value_calculator_accounts_of_sanctum_lst(
         PoolInfo::Marinade => marinade_sol_val_calc_account_metas().to_vec(),
         PoolInfo::ReservePool => WSOL_LST_SOL_COMMON_METAS.to_vec(),
         PoolInfo::SanctumSpl(SplPoolAccounts { pool, .. }) => {
-            resolve_to_account_metas_for_calc::<SanctumSplSolValCalc>(
+            resolve_spl_to_account_metas_for_calc::<SanctumSplSolValCalc>(
                 LstSolCommonIntermediateKeys {
                     lst_mint: *mint,
                     pool_state: *pool,
@@ -87,14 +87,14 @@ pub fn sol_value_calculator_accounts_of_sanctum_lst(
             .to_vec()
         }
         PoolInfo::Spl(SplPoolAccounts { pool, .. }) => {
-            resolve_to_account_metas_for_calc::<SplSolValCalc>(LstSolCommonIntermediateKeys {
+            resolve_spl_to_account_metas_for_calc::<SplSolValCalc>(LstSolCommonIntermediateKeys {
                 lst_mint: *mint,
                 pool_state: *pool,
             })
             .to_vec()
         }
         PoolInfo::SanctumSplMulti(SplPoolAccounts { pool, .. }) => {
-            resolve_to_account_metas_for_calc::<SanctumSplMultiSolValCalc>(
+            resolve_spl_to_account_metas_for_calc::<SanctumSplMultiSolValCalc>(
                 LstSolCommonIntermediateKeys {
                     lst_mint: *mint,
                     pool_state: *pool,
@@ -129,6 +129,7 @@ impl RebalSolArgs {
             },
             &slsts,
             &Arc::new(AtomicU64::new(clock.epoch)),
+            None,
         )
         .unwrap();
         let mut deposit_sol = DepositSolStakedex::from_sanctum_lst(sanctum_lst);
@@ -140,6 +140,7 @@ impl RebalStakeArgs {
             },
             &slsts,
             &Arc::new(AtomicU64::new(clock.epoch)),
+            None,
         )
         .unwrap();
         let mut withdraw_stake = WithdrawStakeStakedex::from_sanctum_lst(from);
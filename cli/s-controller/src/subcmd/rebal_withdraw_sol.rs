@@ -137,6 +137,7 @@ impl RebalWithdrawSolArgs {
             },
             &slsts,
             &Arc::new(AtomicU64::new(clock.epoch)),
+            None,
         )
         .unwrap();
         let mut withdraw_sol = WithdrawSolStakedex::from_sanctum_lst(sanctum_lst);
@@ -3,7 +3,10 @@ use clap::{
     Args,
 };
 use s_cli_utils::{handle_tx_full, pubkey_src_to_box_dyn_signer};
-use s_controller_interface::set_pricing_program_ix_with_program_id;
+use s_controller_interface::{
+    set_pricing_program_ix_with_program_id, set_pricing_program_v2_ix_with_program_id,
+    SetPricingProgramV2IxArgs,
+};
 use s_controller_lib::{try_pool_state, SetPricingProgramFreeArgs};
 use sanctum_solana_cli_utils::PubkeySrc;
 
@@ -26,6 +29,12 @@ pub struct SetPricingProgArgs {
 - flat-fee",
     value_parser = StringValueParser::new().try_map(|s| PricingProgArg::parse_arg(&s)))]
     pub pricing_prog: PricingProgArg,
+
+    #[arg(
+        long,
+        help = "Set if the new pricing program only takes the LST mints, not the pool state, LST state list and reserves accounts, in its pricing CPIs"
+    )]
+    pub no_pool_context: bool,
 }
 
 impl SetPricingProgArgs {
@@ -33,6 +42,7 @@ impl SetPricingProgArgs {
         let Self {
             admin,
             pricing_prog,
+            no_pool_context,
         } = match args.subcmd {
             Subcmd::SetPricingProg(a) => a,
             _ => unreachable!(),
@@ -50,15 +60,24 @@ impl SetPricingProgArgs {
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_admin(pool_state, admin.pubkey()).unwrap();
 
-        let ix = set_pricing_program_ix_with_program_id(
-            program_id,
-            SetPricingProgramFreeArgs {
-                new_pricing_program: pricing_prog.program_id(),
-                pool_state_acc,
-            }
-            .resolve_for_prog(program_id)
-            .unwrap(),
-        )
+        let free_args = SetPricingProgramFreeArgs {
+            new_pricing_program: pricing_prog.program_id(),
+            pool_state_acc,
+        };
+        let ix = if no_pool_context {
+            set_pricing_program_ix_with_program_id(
+                program_id,
+                free_args.resolve_for_prog(program_id).unwrap(),
+            )
+        } else {
+            set_pricing_program_v2_ix_with_program_id(
+                program_id,
+                free_args.resolve_v2_for_prog(program_id).unwrap(),
+                SetPricingProgramV2IxArgs {
+                    is_pool_context_enabled: 1,
+                },
+            )
+        }
         .unwrap();
 
         handle_tx_full(
@@ -70,6 +70,10 @@ impl ViewArgs {
             println!("    version: {}", pool_state.version);
             println!("    is_disabled: {}", pool_state.is_disabled);
             println!("    is_rebalancing: {}", pool_state.is_rebalancing);
+            println!(
+                "    is_pricing_pool_context_enabled: {}",
+                pool_state.is_pricing_pool_context_enabled
+            );
             println!("    admin: {}", pool_state.admin);
             println!(
                 "    rebalance_authority: {}",
@@ -94,6 +98,7 @@ impl ViewArgs {
                 let LstState {
                     mint,
                     is_input_disabled,
+                    is_batch_calc_enabled,
                     sol_value,
                     sol_value_calculator,
                     ..
@@ -107,6 +112,10 @@ impl ViewArgs {
                     "      is_input_disabled: {}",
                     U8Bool(*is_input_disabled).is_true()
                 );
+                println!(
+                    "      is_batch_calc_enabled: {}",
+                    U8Bool(*is_batch_calc_enabled).is_true()
+                );
                 println!("      sol_value: {}", lamports_to_sol(*sol_value));
                 println!("      sol_value_calculator: {sol_value_calculator}");
                 let token_program = match sanctum_lst_opt {
@@ -38,16 +38,39 @@ async fn rebal_all_sol_to_jitosol_rebal_auth_payer() {
         flat_fee_interface::ProgramState {
             manager: Pubkey::default(),
             lp_withdrawal_fee_bps: 0,
+            lp_deposit_fee_bps: 0,
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         [
             MockFeeAccountArgs {
                 input_fee_bps: 0,
                 output_fee_bps: 0,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
                 lst_mint: native_mint::ID,
             },
             MockFeeAccountArgs {
                 input_fee_bps: 0,
                 output_fee_bps: 0,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
                 lst_mint: jitosol::ID,
             },
         ],
@@ -1,5 +1,6 @@
 use s_controller_test_utils::{
-    assert_pricing_prog_set, jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs,
+    assert_pricing_pool_context_enabled, assert_pricing_prog_set,
+    jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs,
 };
 use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
 use solana_program_test::ProgramTest;
@@ -33,6 +34,7 @@ async fn set_pricing_prog_flat_fee_success_payer_admin() {
     let exec_res = cmd.exec_b64_txs(&mut bc).await;
     assert_all_txs_success_nonempty(&exec_res);
     assert_pricing_prog_set(&mut bc, flat_fee_lib::program::ID).await;
+    assert_pricing_pool_context_enabled(&mut bc, true).await;
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -46,4 +48,20 @@ async fn set_pricing_prog_flat_fee_pubkey_arg_success_payer_admin() {
     let exec_res = cmd.exec_b64_txs(&mut bc).await;
     assert_all_txs_success_nonempty(&exec_res);
     assert_pricing_prog_set(&mut bc, flat_fee_lib::program::ID).await;
+    assert_pricing_pool_context_enabled(&mut bc, true).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_pricing_prog_no_pool_context_success_payer_admin() {
+    let pt = jito_marinade_no_fee_pt_with_flat_fee_prog();
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_set_pricing_prog()
+        .arg("--no-pool-context")
+        .arg("flat-fee");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_pricing_prog_set(&mut bc, flat_fee_lib::program::ID).await;
+    assert_pricing_pool_context_enabled(&mut bc, false).await;
 }
@@ -0,0 +1,49 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum DynamicFeeError {
+    #[error("Invalid program state data")]
+    InvalidProgramStateData = 0,
+    #[error("Incorrect program state account")]
+    IncorrectProgramState = 1,
+    #[error("FeeAccount is not initialized for the given LST mint")]
+    UnsupportedLstMint = 2,
+    #[error("Given signed fee value is out of bound")]
+    SignedFeeOutOfBound = 3,
+    #[error("Given unsigned fee value is out of bound")]
+    UnsignedFeeOutOfBound = 4,
+    #[error("Math error")]
+    MathError = 5,
+    #[error("LST is not on the S controller's LST state list")]
+    LstNotInPool = 6,
+    #[error("Given target weight is out of bound")]
+    TargetWeightOutOfBound = 7,
+    #[error("PriceExactOut did not converge within the iteration limit")]
+    PriceExactOutNotConverged = 8,
+}
+impl From<DynamicFeeError> for ProgramError {
+    fn from(e: DynamicFeeError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for DynamicFeeError {
+    fn type_of() -> &'static str {
+        "DynamicFeeError"
+    }
+}
+impl PrintProgramError for DynamicFeeError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
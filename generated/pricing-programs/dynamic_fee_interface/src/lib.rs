@@ -0,0 +1,9 @@
+#![allow(non_local_definitions)]
+
+solana_program::declare_id!("F6cSovKR9KficSkohucRZ7Nz9SWAH7Mdt6jBuhmaMWpU");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
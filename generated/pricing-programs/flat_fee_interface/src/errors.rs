@@ -18,6 +18,36 @@ pub enum FlatFeeError {
     UnsignedFeeOutOfBound = 4,
     #[error("Math error")]
     MathError = 5,
+    #[error("Invalid surge config data")]
+    InvalidSurgeConfigData = 6,
+    #[error("Invalid LST surcharge data")]
+    InvalidLstSurchargeData = 7,
+    #[error("Stake pool account does not match the LST's surcharge config")]
+    IncorrectStakePool = 8,
+    #[error("Failed to read last update epoch from stake pool account")]
+    InvalidStakePoolData = 9,
+    #[error("Invalid pair fee account data")]
+    InvalidPairFeeAccountData = 10,
+    #[error("Pair fee account does not match the input and output LSTs")]
+    IncorrectPairFeeAccount = 11,
+    #[error("Account is not a ProgramState or FeeAccount with the legacy layout")]
+    AccountNotMigratable = 12,
+    #[error("Scheduled fee activation epoch must be at least 2 epochs after the current epoch")]
+    InvalidActivationEpoch = 13,
+    #[error("Fee is outside the bounds configured by the admin")]
+    FeeOutOfConfiguredBound = 14,
+    #[error("Fee change exceeds the maximum change per update configured by the admin")]
+    FeeChangeTooLarge = 15,
+    #[error("Invalid fee bounds")]
+    InvalidFeeBounds = 16,
+    #[error("Fee increases must be scheduled with an activation epoch")]
+    FeeIncreaseNotScheduled = 17,
+    #[error("LST is the input LST of pair fee overrides that must be removed first")]
+    LstHasPairFees = 18,
+    #[error("FeeAccount has a legacy layout and must be migrated first")]
+    FeeAccountNeedsMigration = 19,
+    #[error("ProgramState admin has not been set, the ProgramState must be migrated first")]
+    AdminNotSet = 20,
 }
 impl From<FlatFeeError> for ProgramError {
     fn from(e: FlatFeeError) -> Self {
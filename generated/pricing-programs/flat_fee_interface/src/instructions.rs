@@ -14,6 +14,19 @@ pub enum FlatFeeProgramIx {
     PriceExactOut(PriceExactOutIxArgs),
     PriceLpTokensToMint(PriceLpTokensToMintIxArgs),
     PriceLpTokensToRedeem(PriceLpTokensToRedeemIxArgs),
+    SetAdmin,
+    SetFeeBounds(SetFeeBoundsIxArgs),
+    ScheduleLpWithdrawalFee(ScheduleLpWithdrawalFeeIxArgs),
+    ScheduleLstFee(ScheduleLstFeeIxArgs),
+    SetLstLpWithdrawalFee(SetLstLpWithdrawalFeeIxArgs),
+    MigrateAccount,
+    SetLstLpDepositFee(SetLstLpDepositFeeIxArgs),
+    SetLpDepositFee(SetLpDepositFeeIxArgs),
+    RemovePairFee,
+    SetPairFee(SetPairFeeIxArgs),
+    AddPairFee(AddPairFeeIxArgs),
+    SetLstSurcharge(SetLstSurchargeIxArgs),
+    SetSurgeFee(SetSurgeFeeIxArgs),
     SetLpWithdrawalFee(SetLpWithdrawalFeeIxArgs),
     SetLstFee(SetLstFeeIxArgs),
     RemoveLst,
@@ -40,6 +53,39 @@ impl FlatFeeProgramIx {
             PRICE_LP_TOKENS_TO_REDEEM_IX_DISCM => Ok(Self::PriceLpTokensToRedeem(
                 PriceLpTokensToRedeemIxArgs::deserialize(&mut reader)?,
             )),
+            SET_ADMIN_IX_DISCM => Ok(Self::SetAdmin),
+            SET_FEE_BOUNDS_IX_DISCM => Ok(Self::SetFeeBounds(SetFeeBoundsIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            SCHEDULE_LP_WITHDRAWAL_FEE_IX_DISCM => Ok(Self::ScheduleLpWithdrawalFee(
+                ScheduleLpWithdrawalFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            SCHEDULE_LST_FEE_IX_DISCM => Ok(Self::ScheduleLstFee(
+                ScheduleLstFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_LST_LP_WITHDRAWAL_FEE_IX_DISCM => Ok(Self::SetLstLpWithdrawalFee(
+                SetLstLpWithdrawalFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            MIGRATE_ACCOUNT_IX_DISCM => Ok(Self::MigrateAccount),
+            SET_LST_LP_DEPOSIT_FEE_IX_DISCM => Ok(Self::SetLstLpDepositFee(
+                SetLstLpDepositFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_LP_DEPOSIT_FEE_IX_DISCM => Ok(Self::SetLpDepositFee(
+                SetLpDepositFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            REMOVE_PAIR_FEE_IX_DISCM => Ok(Self::RemovePairFee),
+            SET_PAIR_FEE_IX_DISCM => Ok(Self::SetPairFee(SetPairFeeIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            ADD_PAIR_FEE_IX_DISCM => Ok(Self::AddPairFee(AddPairFeeIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            SET_LST_SURCHARGE_IX_DISCM => Ok(Self::SetLstSurcharge(
+                SetLstSurchargeIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_SURGE_FEE_IX_DISCM => Ok(Self::SetSurgeFee(SetSurgeFeeIxArgs::deserialize(
+                &mut reader,
+            )?)),
             SET_LP_WITHDRAWAL_FEE_IX_DISCM => Ok(Self::SetLpWithdrawalFee(
                 SetLpWithdrawalFeeIxArgs::deserialize(&mut reader)?,
             )),
@@ -72,6 +118,49 @@ impl FlatFeeProgramIx {
                 writer.write_all(&[PRICE_LP_TOKENS_TO_REDEEM_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
+            Self::SetAdmin => writer.write_all(&[SET_ADMIN_IX_DISCM]),
+            Self::SetFeeBounds(args) => {
+                writer.write_all(&[SET_FEE_BOUNDS_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::ScheduleLpWithdrawalFee(args) => {
+                writer.write_all(&[SCHEDULE_LP_WITHDRAWAL_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::ScheduleLstFee(args) => {
+                writer.write_all(&[SCHEDULE_LST_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLstLpWithdrawalFee(args) => {
+                writer.write_all(&[SET_LST_LP_WITHDRAWAL_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::MigrateAccount => writer.write_all(&[MIGRATE_ACCOUNT_IX_DISCM]),
+            Self::SetLstLpDepositFee(args) => {
+                writer.write_all(&[SET_LST_LP_DEPOSIT_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLpDepositFee(args) => {
+                writer.write_all(&[SET_LP_DEPOSIT_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RemovePairFee => writer.write_all(&[REMOVE_PAIR_FEE_IX_DISCM]),
+            Self::SetPairFee(args) => {
+                writer.write_all(&[SET_PAIR_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddPairFee(args) => {
+                writer.write_all(&[ADD_PAIR_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLstSurcharge(args) => {
+                writer.write_all(&[SET_LST_SURCHARGE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetSurgeFee(args) => {
+                writer.write_all(&[SET_SURGE_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
             Self::SetLpWithdrawalFee(args) => {
                 writer.write_all(&[SET_LP_WITHDRAWAL_FEE_IX_DISCM])?;
                 args.serialize(&mut writer)
@@ -110,17 +199,35 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke_signed(ix, &account_info, seeds)
 }
-pub const PRICE_EXACT_IN_IX_ACCOUNTS_LEN: usize = 4;
+pub const PRICE_EXACT_IN_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactInAccounts<'me, 'info> {
     ///Mint of the input LST
     pub input_lst_mint: &'me AccountInfo<'info>,
     ///Mint of the output LST
     pub output_lst_mint: &'me AccountInfo<'info>,
+    ///The S controller pool state PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S controller LST state list PDA
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the input LST
+    pub input_pool_reserves: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the output LST
+    pub output_pool_reserves: &'me AccountInfo<'info>,
     ///FeeAccount PDA for the input LST
     pub input_fee_acc: &'me AccountInfo<'info>,
     ///FeeAccount PDA for the output LST
     pub output_fee_acc: &'me AccountInfo<'info>,
+    ///SurgeConfig PDA. May be uninitialized, in which case no epoch surge fee is levied
+    pub surge_config: &'me AccountInfo<'info>,
+    ///LstSurcharge PDA for the input LST. May be uninitialized, in which case no surcharge is levied for the input LST
+    pub input_surcharge_acc: &'me AccountInfo<'info>,
+    ///LstSurcharge PDA for the output LST. May be uninitialized, in which case no surcharge is levied for the output LST
+    pub output_surcharge_acc: &'me AccountInfo<'info>,
+    ///The input LST's stake pool as recorded in input_surcharge_acc. Ignored if input_surcharge_acc is uninitialized
+    pub input_stake_pool: &'me AccountInfo<'info>,
+    ///The output LST's stake pool as recorded in output_surcharge_acc. Ignored if output_surcharge_acc is uninitialized
+    pub output_stake_pool: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactInKeys {
@@ -128,18 +235,45 @@ pub struct PriceExactInKeys {
     pub input_lst_mint: Pubkey,
     ///Mint of the output LST
     pub output_lst_mint: Pubkey,
+    ///The S controller pool state PDA
+    pub pool_state: Pubkey,
+    ///The S controller LST state list PDA
+    pub lst_state_list: Pubkey,
+    ///The pool's reserves token account for the input LST
+    pub input_pool_reserves: Pubkey,
+    ///The pool's reserves token account for the output LST
+    pub output_pool_reserves: Pubkey,
     ///FeeAccount PDA for the input LST
     pub input_fee_acc: Pubkey,
     ///FeeAccount PDA for the output LST
     pub output_fee_acc: Pubkey,
+    ///SurgeConfig PDA. May be uninitialized, in which case no epoch surge fee is levied
+    pub surge_config: Pubkey,
+    ///LstSurcharge PDA for the input LST. May be uninitialized, in which case no surcharge is levied for the input LST
+    pub input_surcharge_acc: Pubkey,
+    ///LstSurcharge PDA for the output LST. May be uninitialized, in which case no surcharge is levied for the output LST
+    pub output_surcharge_acc: Pubkey,
+    ///The input LST's stake pool as recorded in input_surcharge_acc. Ignored if input_surcharge_acc is uninitialized
+    pub input_stake_pool: Pubkey,
+    ///The output LST's stake pool as recorded in output_surcharge_acc. Ignored if output_surcharge_acc is uninitialized
+    pub output_stake_pool: Pubkey,
 }
 impl From<PriceExactInAccounts<'_, '_>> for PriceExactInKeys {
     fn from(accounts: PriceExactInAccounts) -> Self {
         Self {
             input_lst_mint: *accounts.input_lst_mint.key,
             output_lst_mint: *accounts.output_lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            input_pool_reserves: *accounts.input_pool_reserves.key,
+            output_pool_reserves: *accounts.output_pool_reserves.key,
             input_fee_acc: *accounts.input_fee_acc.key,
             output_fee_acc: *accounts.output_fee_acc.key,
+            surge_config: *accounts.surge_config.key,
+            input_surcharge_acc: *accounts.input_surcharge_acc.key,
+            output_surcharge_acc: *accounts.output_surcharge_acc.key,
+            input_stake_pool: *accounts.input_stake_pool.key,
+            output_stake_pool: *accounts.output_stake_pool.key,
         }
     }
 }
@@ -156,6 +290,26 @@ impl From<PriceExactInKeys> for [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.input_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.input_fee_acc,
                 is_signer: false,
@@ -166,6 +320,31 @@ impl From<PriceExactInKeys> for [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.surge_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.input_surcharge_acc,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_surcharge_acc,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.input_stake_pool,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_stake_pool,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -174,8 +353,17 @@ impl From<[Pubkey; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]> for PriceExactInKeys {
         Self {
             input_lst_mint: pubkeys[0],
             output_lst_mint: pubkeys[1],
-            input_fee_acc: pubkeys[2],
-            output_fee_acc: pubkeys[3],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+            input_pool_reserves: pubkeys[4],
+            output_pool_reserves: pubkeys[5],
+            input_fee_acc: pubkeys[6],
+            output_fee_acc: pubkeys[7],
+            surge_config: pubkeys[8],
+            input_surcharge_acc: pubkeys[9],
+            output_surcharge_acc: pubkeys[10],
+            input_stake_pool: pubkeys[11],
+            output_stake_pool: pubkeys[12],
         }
     }
 }
@@ -186,8 +374,17 @@ impl<'info> From<PriceExactInAccounts<'_, 'info>>
         [
             accounts.input_lst_mint.clone(),
             accounts.output_lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.input_pool_reserves.clone(),
+            accounts.output_pool_reserves.clone(),
             accounts.input_fee_acc.clone(),
             accounts.output_fee_acc.clone(),
+            accounts.surge_config.clone(),
+            accounts.input_surcharge_acc.clone(),
+            accounts.output_surcharge_acc.clone(),
+            accounts.input_stake_pool.clone(),
+            accounts.output_stake_pool.clone(),
         ]
     }
 }
@@ -198,8 +395,17 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]>
         Self {
             input_lst_mint: &arr[0],
             output_lst_mint: &arr[1],
-            input_fee_acc: &arr[2],
-            output_fee_acc: &arr[3],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+            input_pool_reserves: &arr[4],
+            output_pool_reserves: &arr[5],
+            input_fee_acc: &arr[6],
+            output_fee_acc: &arr[7],
+            surge_config: &arr[8],
+            input_surcharge_acc: &arr[9],
+            output_surcharge_acc: &arr[10],
+            input_stake_pool: &arr[11],
+            output_stake_pool: &arr[12],
         }
     }
 }
@@ -302,8 +508,23 @@ pub fn price_exact_in_verify_account_keys(
     for (actual, expected) in [
         (accounts.input_lst_mint.key, &keys.input_lst_mint),
         (accounts.output_lst_mint.key, &keys.output_lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.input_pool_reserves.key, &keys.input_pool_reserves),
+        (
+            accounts.output_pool_reserves.key,
+            &keys.output_pool_reserves,
+        ),
         (accounts.input_fee_acc.key, &keys.input_fee_acc),
         (accounts.output_fee_acc.key, &keys.output_fee_acc),
+        (accounts.surge_config.key, &keys.surge_config),
+        (accounts.input_surcharge_acc.key, &keys.input_surcharge_acc),
+        (
+            accounts.output_surcharge_acc.key,
+            &keys.output_surcharge_acc,
+        ),
+        (accounts.input_stake_pool.key, &keys.input_stake_pool),
+        (accounts.output_stake_pool.key, &keys.output_stake_pool),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -311,17 +532,35 @@ pub fn price_exact_in_verify_account_keys(
     }
     Ok(())
 }
-pub const PRICE_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 4;
+pub const PRICE_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactOutAccounts<'me, 'info> {
     ///Mint of the input LST
     pub input_lst_mint: &'me AccountInfo<'info>,
     ///Mint of the output LST
     pub output_lst_mint: &'me AccountInfo<'info>,
+    ///The S controller pool state PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S controller LST state list PDA
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the input LST
+    pub input_pool_reserves: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the output LST
+    pub output_pool_reserves: &'me AccountInfo<'info>,
     ///FeeAccount PDA for the input LST
     pub input_fee_acc: &'me AccountInfo<'info>,
     ///FeeAccount PDA for the output LST
     pub output_fee_acc: &'me AccountInfo<'info>,
+    ///SurgeConfig PDA. May be uninitialized, in which case no epoch surge fee is levied
+    pub surge_config: &'me AccountInfo<'info>,
+    ///LstSurcharge PDA for the input LST. May be uninitialized, in which case no surcharge is levied for the input LST
+    pub input_surcharge_acc: &'me AccountInfo<'info>,
+    ///LstSurcharge PDA for the output LST. May be uninitialized, in which case no surcharge is levied for the output LST
+    pub output_surcharge_acc: &'me AccountInfo<'info>,
+    ///The input LST's stake pool as recorded in input_surcharge_acc. Ignored if input_surcharge_acc is uninitialized
+    pub input_stake_pool: &'me AccountInfo<'info>,
+    ///The output LST's stake pool as recorded in output_surcharge_acc. Ignored if output_surcharge_acc is uninitialized
+    pub output_stake_pool: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactOutKeys {
@@ -329,18 +568,45 @@ pub struct PriceExactOutKeys {
     pub input_lst_mint: Pubkey,
     ///Mint of the output LST
     pub output_lst_mint: Pubkey,
+    ///The S controller pool state PDA
+    pub pool_state: Pubkey,
+    ///The S controller LST state list PDA
+    pub lst_state_list: Pubkey,
+    ///The pool's reserves token account for the input LST
+    pub input_pool_reserves: Pubkey,
+    ///The pool's reserves token account for the output LST
+    pub output_pool_reserves: Pubkey,
     ///FeeAccount PDA for the input LST
     pub input_fee_acc: Pubkey,
     ///FeeAccount PDA for the output LST
     pub output_fee_acc: Pubkey,
+    ///SurgeConfig PDA. May be uninitialized, in which case no epoch surge fee is levied
+    pub surge_config: Pubkey,
+    ///LstSurcharge PDA for the input LST. May be uninitialized, in which case no surcharge is levied for the input LST
+    pub input_surcharge_acc: Pubkey,
+    ///LstSurcharge PDA for the output LST. May be uninitialized, in which case no surcharge is levied for the output LST
+    pub output_surcharge_acc: Pubkey,
+    ///The input LST's stake pool as recorded in input_surcharge_acc. Ignored if input_surcharge_acc is uninitialized
+    pub input_stake_pool: Pubkey,
+    ///The output LST's stake pool as recorded in output_surcharge_acc. Ignored if output_surcharge_acc is uninitialized
+    pub output_stake_pool: Pubkey,
 }
 impl From<PriceExactOutAccounts<'_, '_>> for PriceExactOutKeys {
     fn from(accounts: PriceExactOutAccounts) -> Self {
         Self {
             input_lst_mint: *accounts.input_lst_mint.key,
             output_lst_mint: *accounts.output_lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            input_pool_reserves: *accounts.input_pool_reserves.key,
+            output_pool_reserves: *accounts.output_pool_reserves.key,
             input_fee_acc: *accounts.input_fee_acc.key,
             output_fee_acc: *accounts.output_fee_acc.key,
+            surge_config: *accounts.surge_config.key,
+            input_surcharge_acc: *accounts.input_surcharge_acc.key,
+            output_surcharge_acc: *accounts.output_surcharge_acc.key,
+            input_stake_pool: *accounts.input_stake_pool.key,
+            output_stake_pool: *accounts.output_stake_pool.key,
         }
     }
 }
@@ -357,6 +623,26 @@ impl From<PriceExactOutKeys> for [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.input_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.input_fee_acc,
                 is_signer: false,
@@ -367,6 +653,31 @@ impl From<PriceExactOutKeys> for [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.surge_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.input_surcharge_acc,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_surcharge_acc,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.input_stake_pool,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_stake_pool,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -375,8 +686,17 @@ impl From<[Pubkey; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]> for PriceExactOutKeys {
         Self {
             input_lst_mint: pubkeys[0],
             output_lst_mint: pubkeys[1],
-            input_fee_acc: pubkeys[2],
-            output_fee_acc: pubkeys[3],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+            input_pool_reserves: pubkeys[4],
+            output_pool_reserves: pubkeys[5],
+            input_fee_acc: pubkeys[6],
+            output_fee_acc: pubkeys[7],
+            surge_config: pubkeys[8],
+            input_surcharge_acc: pubkeys[9],
+            output_surcharge_acc: pubkeys[10],
+            input_stake_pool: pubkeys[11],
+            output_stake_pool: pubkeys[12],
         }
     }
 }
@@ -387,8 +707,17 @@ impl<'info> From<PriceExactOutAccounts<'_, 'info>>
         [
             accounts.input_lst_mint.clone(),
             accounts.output_lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.input_pool_reserves.clone(),
+            accounts.output_pool_reserves.clone(),
             accounts.input_fee_acc.clone(),
             accounts.output_fee_acc.clone(),
+            accounts.surge_config.clone(),
+            accounts.input_surcharge_acc.clone(),
+            accounts.output_surcharge_acc.clone(),
+            accounts.input_stake_pool.clone(),
+            accounts.output_stake_pool.clone(),
         ]
     }
 }
@@ -399,8 +728,17 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]
         Self {
             input_lst_mint: &arr[0],
             output_lst_mint: &arr[1],
-            input_fee_acc: &arr[2],
-            output_fee_acc: &arr[3],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+            input_pool_reserves: &arr[4],
+            output_pool_reserves: &arr[5],
+            input_fee_acc: &arr[6],
+            output_fee_acc: &arr[7],
+            surge_config: &arr[8],
+            input_surcharge_acc: &arr[9],
+            output_surcharge_acc: &arr[10],
+            input_stake_pool: &arr[11],
+            output_stake_pool: &arr[12],
         }
     }
 }
@@ -503,8 +841,23 @@ pub fn price_exact_out_verify_account_keys(
     for (actual, expected) in [
         (accounts.input_lst_mint.key, &keys.input_lst_mint),
         (accounts.output_lst_mint.key, &keys.output_lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.input_pool_reserves.key, &keys.input_pool_reserves),
+        (
+            accounts.output_pool_reserves.key,
+            &keys.output_pool_reserves,
+        ),
         (accounts.input_fee_acc.key, &keys.input_fee_acc),
         (accounts.output_fee_acc.key, &keys.output_fee_acc),
+        (accounts.surge_config.key, &keys.surge_config),
+        (accounts.input_surcharge_acc.key, &keys.input_surcharge_acc),
+        (
+            accounts.output_surcharge_acc.key,
+            &keys.output_surcharge_acc,
+        ),
+        (accounts.input_stake_pool.key, &keys.input_stake_pool),
+        (accounts.output_stake_pool.key, &keys.output_stake_pool),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -512,37 +865,94 @@ pub fn price_exact_out_verify_account_keys(
     }
     Ok(())
 }
-pub const PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN: usize = 1;
+pub const PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceLpTokensToMintAccounts<'me, 'info> {
     ///Mint of the input LST
     pub input_lst_mint: &'me AccountInfo<'info>,
+    ///The S controller pool state PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S controller LST state list PDA
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the input LST
+    pub pool_reserves: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
+    ///FeeAccount PDA for the input LST
+    pub fee_acc: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceLpTokensToMintKeys {
     ///Mint of the input LST
     pub input_lst_mint: Pubkey,
+    ///The S controller pool state PDA
+    pub pool_state: Pubkey,
+    ///The S controller LST state list PDA
+    pub lst_state_list: Pubkey,
+    ///The pool's reserves token account for the input LST
+    pub pool_reserves: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
+    ///FeeAccount PDA for the input LST
+    pub fee_acc: Pubkey,
 }
 impl From<PriceLpTokensToMintAccounts<'_, '_>> for PriceLpTokensToMintKeys {
     fn from(accounts: PriceLpTokensToMintAccounts) -> Self {
         Self {
             input_lst_mint: *accounts.input_lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+            state: *accounts.state.key,
+            fee_acc: *accounts.fee_acc.key,
         }
     }
 }
 impl From<PriceLpTokensToMintKeys> for [AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN] {
     fn from(keys: PriceLpTokensToMintKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.input_lst_mint,
-            is_signer: false,
-            is_writable: false,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.input_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.fee_acc,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
     }
 }
 impl From<[Pubkey; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]> for PriceLpTokensToMintKeys {
     fn from(pubkeys: [Pubkey; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             input_lst_mint: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            pool_reserves: pubkeys[3],
+            state: pubkeys[4],
+            fee_acc: pubkeys[5],
         }
     }
 }
@@ -550,7 +960,14 @@ impl<'info> From<PriceLpTokensToMintAccounts<'_, 'info>>
     for [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]
 {
     fn from(accounts: PriceLpTokensToMintAccounts<'_, 'info>) -> Self {
-        [accounts.input_lst_mint.clone()]
+        [
+            accounts.input_lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+            accounts.state.clone(),
+            accounts.fee_acc.clone(),
+        ]
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>
@@ -559,6 +976,11 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOU
     fn from(arr: &'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             input_lst_mint: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            pool_reserves: &arr[3],
+            state: &arr[4],
+            fee_acc: &arr[5],
         }
     }
 }
@@ -658,33 +1080,65 @@ pub fn price_lp_tokens_to_mint_verify_account_keys(
     accounts: PriceLpTokensToMintAccounts<'_, '_>,
     keys: PriceLpTokensToMintKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
-    for (actual, expected) in [(accounts.input_lst_mint.key, &keys.input_lst_mint)] {
+    for (actual, expected) in [
+        (accounts.input_lst_mint.key, &keys.input_lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+        (accounts.state.key, &keys.state),
+        (accounts.fee_acc.key, &keys.fee_acc),
+    ] {
         if actual != expected {
             return Err((*actual, *expected));
         }
     }
     Ok(())
 }
-pub const PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN: usize = 2;
+pub const PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceLpTokensToRedeemAccounts<'me, 'info> {
     ///Mint of the output LST
     pub output_lst_mint: &'me AccountInfo<'info>,
+    ///The S controller pool state PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S controller LST state list PDA
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the output LST
+    pub pool_reserves: &'me AccountInfo<'info>,
     ///Program state PDA
     pub state: &'me AccountInfo<'info>,
+    ///SurgeConfig PDA. May be uninitialized, in which case no epoch surge fee is levied
+    pub surge_config: &'me AccountInfo<'info>,
+    ///FeeAccount PDA for the output LST
+    pub fee_acc: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceLpTokensToRedeemKeys {
     ///Mint of the output LST
     pub output_lst_mint: Pubkey,
+    ///The S controller pool state PDA
+    pub pool_state: Pubkey,
+    ///The S controller LST state list PDA
+    pub lst_state_list: Pubkey,
+    ///The pool's reserves token account for the output LST
+    pub pool_reserves: Pubkey,
     ///Program state PDA
     pub state: Pubkey,
+    ///SurgeConfig PDA. May be uninitialized, in which case no epoch surge fee is levied
+    pub surge_config: Pubkey,
+    ///FeeAccount PDA for the output LST
+    pub fee_acc: Pubkey,
 }
 impl From<PriceLpTokensToRedeemAccounts<'_, '_>> for PriceLpTokensToRedeemKeys {
     fn from(accounts: PriceLpTokensToRedeemAccounts) -> Self {
         Self {
             output_lst_mint: *accounts.output_lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
             state: *accounts.state.key,
+            surge_config: *accounts.surge_config.key,
+            fee_acc: *accounts.fee_acc.key,
         }
     }
 }
@@ -696,11 +1150,36 @@ impl From<PriceLpTokensToRedeemKeys> for [AccountMeta; PRICE_LP_TOKENS_TO_REDEEM
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.state,
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.surge_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.fee_acc,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -708,7 +1187,12 @@ impl From<[Pubkey; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]> for PriceLpTokens
     fn from(pubkeys: [Pubkey; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             output_lst_mint: pubkeys[0],
-            state: pubkeys[1],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            pool_reserves: pubkeys[3],
+            state: pubkeys[4],
+            surge_config: pubkeys[5],
+            fee_acc: pubkeys[6],
         }
     }
 }
@@ -716,7 +1200,15 @@ impl<'info> From<PriceLpTokensToRedeemAccounts<'_, 'info>>
     for [AccountInfo<'info>; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]
 {
     fn from(accounts: PriceLpTokensToRedeemAccounts<'_, 'info>) -> Self {
-        [accounts.output_lst_mint.clone(), accounts.state.clone()]
+        [
+            accounts.output_lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+            accounts.state.clone(),
+            accounts.surge_config.clone(),
+            accounts.fee_acc.clone(),
+        ]
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]>
@@ -725,7 +1217,12 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_REDEEM_IX_ACC
     fn from(arr: &'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             output_lst_mint: &arr[0],
-            state: &arr[1],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            pool_reserves: &arr[3],
+            state: &arr[4],
+            surge_config: &arr[5],
+            fee_acc: &arr[6],
         }
     }
 }
@@ -827,7 +1324,12 @@ pub fn price_lp_tokens_to_redeem_verify_account_keys(
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.output_lst_mint.key, &keys.output_lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
         (accounts.state.key, &keys.state),
+        (accounts.surge_config.key, &keys.surge_config),
+        (accounts.fee_acc.key, &keys.fee_acc),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -835,6 +1337,2902 @@ pub fn price_lp_tokens_to_redeem_verify_account_keys(
     }
     Ok(())
 }
+pub const SET_ADMIN_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetAdminAccounts<'me, 'info> {
+    ///The current program admin, or the program manager if the admin has not been set
+    pub current_admin: &'me AccountInfo<'info>,
+    ///The new program admin to set to
+    pub new_admin: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetAdminKeys {
+    ///The current program admin, or the program manager if the admin has not been set
+    pub current_admin: Pubkey,
+    ///The new program admin to set to
+    pub new_admin: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetAdminAccounts<'_, '_>> for SetAdminKeys {
+    fn from(accounts: SetAdminAccounts) -> Self {
+        Self {
+            current_admin: *accounts.current_admin.key,
+            new_admin: *accounts.new_admin.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetAdminKeys> for [AccountMeta; SET_ADMIN_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetAdminKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.current_admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_admin,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_ADMIN_IX_ACCOUNTS_LEN]> for SetAdminKeys {
+    fn from(pubkeys: [Pubkey; SET_ADMIN_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            current_admin: pubkeys[0],
+            new_admin: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetAdminAccounts<'_, 'info>> for [AccountInfo<'info>; SET_ADMIN_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetAdminAccounts<'_, 'info>) -> Self {
+        [
+            accounts.current_admin.clone(),
+            accounts.new_admin.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_ADMIN_IX_ACCOUNTS_LEN]>
+    for SetAdminAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_ADMIN_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            current_admin: &arr[0],
+            new_admin: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SET_ADMIN_IX_DISCM: u8 = 237u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetAdminIxData;
+impl SetAdminIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_ADMIN_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_ADMIN_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_ADMIN_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_admin_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetAdminKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_ADMIN_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SetAdminIxData.try_to_vec()?,
+    })
+}
+pub fn set_admin_ix(keys: SetAdminKeys) -> std::io::Result<Instruction> {
+    set_admin_ix_with_program_id(crate::ID, keys)
+}
+pub fn set_admin_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetAdminAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: SetAdminKeys = accounts.into();
+    let ix = set_admin_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_admin_invoke(accounts: SetAdminAccounts<'_, '_>) -> ProgramResult {
+    set_admin_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn set_admin_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetAdminAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetAdminKeys = accounts.into();
+    let ix = set_admin_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_admin_invoke_signed(
+    accounts: SetAdminAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_admin_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn set_admin_verify_account_keys(
+    accounts: SetAdminAccounts<'_, '_>,
+    keys: SetAdminKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.current_admin.key, &keys.current_admin),
+        (accounts.new_admin.key, &keys.new_admin),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_admin_verify_writable_privileges<'me, 'info>(
+    accounts: SetAdminAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_admin_verify_signer_privileges<'me, 'info>(
+    accounts: SetAdminAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.current_admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_admin_verify_account_privileges<'me, 'info>(
+    accounts: SetAdminAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_admin_verify_writable_privileges(accounts)?;
+    set_admin_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_FEE_BOUNDS_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetFeeBoundsAccounts<'me, 'info> {
+    ///The program admin, or the program manager if the admin has not been set
+    pub admin: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetFeeBoundsKeys {
+    ///The program admin, or the program manager if the admin has not been set
+    pub admin: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetFeeBoundsAccounts<'_, '_>> for SetFeeBoundsKeys {
+    fn from(accounts: SetFeeBoundsAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetFeeBoundsKeys> for [AccountMeta; SET_FEE_BOUNDS_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetFeeBoundsKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_FEE_BOUNDS_IX_ACCOUNTS_LEN]> for SetFeeBoundsKeys {
+    fn from(pubkeys: [Pubkey; SET_FEE_BOUNDS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            state: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetFeeBoundsAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_FEE_BOUNDS_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetFeeBoundsAccounts<'_, 'info>) -> Self {
+        [accounts.admin.clone(), accounts.state.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FEE_BOUNDS_IX_ACCOUNTS_LEN]>
+    for SetFeeBoundsAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_FEE_BOUNDS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            state: &arr[1],
+        }
+    }
+}
+pub const SET_FEE_BOUNDS_IX_DISCM: u8 = 238u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetFeeBoundsIxArgs {
+    pub min_lst_fee_bps: i16,
+    pub max_lst_fee_bps: i16,
+    pub max_lp_withdrawal_fee_bps: u16,
+    pub max_fee_change_bps: u16,
+    pub max_lp_deposit_fee_bps: u16,
+    pub max_surge_fee_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetFeeBoundsIxData(pub SetFeeBoundsIxArgs);
+impl From<SetFeeBoundsIxArgs> for SetFeeBoundsIxData {
+    fn from(args: SetFeeBoundsIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetFeeBoundsIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_FEE_BOUNDS_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_FEE_BOUNDS_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetFeeBoundsIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_FEE_BOUNDS_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_fee_bounds_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetFeeBoundsKeys,
+    args: SetFeeBoundsIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_FEE_BOUNDS_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetFeeBoundsIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_fee_bounds_ix(
+    keys: SetFeeBoundsKeys,
+    args: SetFeeBoundsIxArgs,
+) -> std::io::Result<Instruction> {
+    set_fee_bounds_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_fee_bounds_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetFeeBoundsAccounts<'_, '_>,
+    args: SetFeeBoundsIxArgs,
+) -> ProgramResult {
+    let keys: SetFeeBoundsKeys = accounts.into();
+    let ix = set_fee_bounds_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_fee_bounds_invoke(
+    accounts: SetFeeBoundsAccounts<'_, '_>,
+    args: SetFeeBoundsIxArgs,
+) -> ProgramResult {
+    set_fee_bounds_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_fee_bounds_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetFeeBoundsAccounts<'_, '_>,
+    args: SetFeeBoundsIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetFeeBoundsKeys = accounts.into();
+    let ix = set_fee_bounds_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_fee_bounds_invoke_signed(
+    accounts: SetFeeBoundsAccounts<'_, '_>,
+    args: SetFeeBoundsIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_fee_bounds_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_fee_bounds_verify_account_keys(
+    accounts: SetFeeBoundsAccounts<'_, '_>,
+    keys: SetFeeBoundsKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_fee_bounds_verify_writable_privileges<'me, 'info>(
+    accounts: SetFeeBoundsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_fee_bounds_verify_signer_privileges<'me, 'info>(
+    accounts: SetFeeBoundsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_fee_bounds_verify_account_privileges<'me, 'info>(
+    accounts: SetFeeBoundsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_fee_bounds_verify_writable_privileges(accounts)?;
+    set_fee_bounds_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SCHEDULE_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct ScheduleLpWithdrawalFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ScheduleLpWithdrawalFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
+}
+impl From<ScheduleLpWithdrawalFeeAccounts<'_, '_>> for ScheduleLpWithdrawalFeeKeys {
+    fn from(accounts: ScheduleLpWithdrawalFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<ScheduleLpWithdrawalFeeKeys>
+    for [AccountMeta; SCHEDULE_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: ScheduleLpWithdrawalFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SCHEDULE_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]> for ScheduleLpWithdrawalFeeKeys {
+    fn from(pubkeys: [Pubkey; SCHEDULE_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            state: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<ScheduleLpWithdrawalFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SCHEDULE_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: ScheduleLpWithdrawalFeeAccounts<'_, 'info>) -> Self {
+        [accounts.manager.clone(), accounts.state.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SCHEDULE_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]>
+    for ScheduleLpWithdrawalFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SCHEDULE_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            state: &arr[1],
+        }
+    }
+}
+pub const SCHEDULE_LP_WITHDRAWAL_FEE_IX_DISCM: u8 = 239u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleLpWithdrawalFeeIxArgs {
+    pub lp_withdrawal_fee_bps: u16,
+    pub activation_epoch: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduleLpWithdrawalFeeIxData(pub ScheduleLpWithdrawalFeeIxArgs);
+impl From<ScheduleLpWithdrawalFeeIxArgs> for ScheduleLpWithdrawalFeeIxData {
+    fn from(args: ScheduleLpWithdrawalFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl ScheduleLpWithdrawalFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SCHEDULE_LP_WITHDRAWAL_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SCHEDULE_LP_WITHDRAWAL_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(ScheduleLpWithdrawalFeeIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SCHEDULE_LP_WITHDRAWAL_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn schedule_lp_withdrawal_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ScheduleLpWithdrawalFeeKeys,
+    args: ScheduleLpWithdrawalFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SCHEDULE_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: ScheduleLpWithdrawalFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn schedule_lp_withdrawal_fee_ix(
+    keys: ScheduleLpWithdrawalFeeKeys,
+    args: ScheduleLpWithdrawalFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    schedule_lp_withdrawal_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn schedule_lp_withdrawal_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ScheduleLpWithdrawalFeeAccounts<'_, '_>,
+    args: ScheduleLpWithdrawalFeeIxArgs,
+) -> ProgramResult {
+    let keys: ScheduleLpWithdrawalFeeKeys = accounts.into();
+    let ix = schedule_lp_withdrawal_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn schedule_lp_withdrawal_fee_invoke(
+    accounts: ScheduleLpWithdrawalFeeAccounts<'_, '_>,
+    args: ScheduleLpWithdrawalFeeIxArgs,
+) -> ProgramResult {
+    schedule_lp_withdrawal_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn schedule_lp_withdrawal_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ScheduleLpWithdrawalFeeAccounts<'_, '_>,
+    args: ScheduleLpWithdrawalFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ScheduleLpWithdrawalFeeKeys = accounts.into();
+    let ix = schedule_lp_withdrawal_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn schedule_lp_withdrawal_fee_invoke_signed(
+    accounts: ScheduleLpWithdrawalFeeAccounts<'_, '_>,
+    args: ScheduleLpWithdrawalFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    schedule_lp_withdrawal_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn schedule_lp_withdrawal_fee_verify_account_keys(
+    accounts: ScheduleLpWithdrawalFeeAccounts<'_, '_>,
+    keys: ScheduleLpWithdrawalFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn schedule_lp_withdrawal_fee_verify_writable_privileges<'me, 'info>(
+    accounts: ScheduleLpWithdrawalFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn schedule_lp_withdrawal_fee_verify_signer_privileges<'me, 'info>(
+    accounts: ScheduleLpWithdrawalFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn schedule_lp_withdrawal_fee_verify_account_privileges<'me, 'info>(
+    accounts: ScheduleLpWithdrawalFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    schedule_lp_withdrawal_fee_verify_writable_privileges(accounts)?;
+    schedule_lp_withdrawal_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SCHEDULE_LST_FEE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct ScheduleLstFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///FeeAccount PDA to modify
+    pub fee_acc: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ScheduleLstFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///FeeAccount PDA to modify
+    pub fee_acc: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<ScheduleLstFeeAccounts<'_, '_>> for ScheduleLstFeeKeys {
+    fn from(accounts: ScheduleLstFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            fee_acc: *accounts.fee_acc.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<ScheduleLstFeeKeys> for [AccountMeta; SCHEDULE_LST_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: ScheduleLstFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SCHEDULE_LST_FEE_IX_ACCOUNTS_LEN]> for ScheduleLstFeeKeys {
+    fn from(pubkeys: [Pubkey; SCHEDULE_LST_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            fee_acc: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<ScheduleLstFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SCHEDULE_LST_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: ScheduleLstFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.fee_acc.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SCHEDULE_LST_FEE_IX_ACCOUNTS_LEN]>
+    for ScheduleLstFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SCHEDULE_LST_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            fee_acc: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SCHEDULE_LST_FEE_IX_DISCM: u8 = 240u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleLstFeeIxArgs {
+    pub input_fee_bps: i16,
+    pub output_fee_bps: i16,
+    pub activation_epoch: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduleLstFeeIxData(pub ScheduleLstFeeIxArgs);
+impl From<ScheduleLstFeeIxArgs> for ScheduleLstFeeIxData {
+    fn from(args: ScheduleLstFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl ScheduleLstFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SCHEDULE_LST_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SCHEDULE_LST_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(ScheduleLstFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SCHEDULE_LST_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn schedule_lst_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ScheduleLstFeeKeys,
+    args: ScheduleLstFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SCHEDULE_LST_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: ScheduleLstFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn schedule_lst_fee_ix(
+    keys: ScheduleLstFeeKeys,
+    args: ScheduleLstFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    schedule_lst_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn schedule_lst_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ScheduleLstFeeAccounts<'_, '_>,
+    args: ScheduleLstFeeIxArgs,
+) -> ProgramResult {
+    let keys: ScheduleLstFeeKeys = accounts.into();
+    let ix = schedule_lst_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn schedule_lst_fee_invoke(
+    accounts: ScheduleLstFeeAccounts<'_, '_>,
+    args: ScheduleLstFeeIxArgs,
+) -> ProgramResult {
+    schedule_lst_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn schedule_lst_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ScheduleLstFeeAccounts<'_, '_>,
+    args: ScheduleLstFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ScheduleLstFeeKeys = accounts.into();
+    let ix = schedule_lst_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn schedule_lst_fee_invoke_signed(
+    accounts: ScheduleLstFeeAccounts<'_, '_>,
+    args: ScheduleLstFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    schedule_lst_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn schedule_lst_fee_verify_account_keys(
+    accounts: ScheduleLstFeeAccounts<'_, '_>,
+    keys: ScheduleLstFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.fee_acc.key, &keys.fee_acc),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn schedule_lst_fee_verify_writable_privileges<'me, 'info>(
+    accounts: ScheduleLstFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.fee_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn schedule_lst_fee_verify_signer_privileges<'me, 'info>(
+    accounts: ScheduleLstFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn schedule_lst_fee_verify_account_privileges<'me, 'info>(
+    accounts: ScheduleLstFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    schedule_lst_fee_verify_writable_privileges(accounts)?;
+    schedule_lst_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LST_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstLpWithdrawalFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///FeeAccount PDA to modify
+    pub fee_acc: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstLpWithdrawalFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///FeeAccount PDA to modify
+    pub fee_acc: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetLstLpWithdrawalFeeAccounts<'_, '_>> for SetLstLpWithdrawalFeeKeys {
+    fn from(accounts: SetLstLpWithdrawalFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            fee_acc: *accounts.fee_acc.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetLstLpWithdrawalFeeKeys> for [AccountMeta; SET_LST_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLstLpWithdrawalFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LST_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]> for SetLstLpWithdrawalFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_LST_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            fee_acc: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetLstLpWithdrawalFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LST_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLstLpWithdrawalFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.fee_acc.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LST_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]>
+    for SetLstLpWithdrawalFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LST_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            fee_acc: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SET_LST_LP_WITHDRAWAL_FEE_IX_DISCM: u8 = 241u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLstLpWithdrawalFeeIxArgs {
+    pub lp_withdrawal_fee_bps: Option<u16>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLstLpWithdrawalFeeIxData(pub SetLstLpWithdrawalFeeIxArgs);
+impl From<SetLstLpWithdrawalFeeIxArgs> for SetLstLpWithdrawalFeeIxData {
+    fn from(args: SetLstLpWithdrawalFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLstLpWithdrawalFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LST_LP_WITHDRAWAL_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LST_LP_WITHDRAWAL_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLstLpWithdrawalFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LST_LP_WITHDRAWAL_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lst_lp_withdrawal_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLstLpWithdrawalFeeKeys,
+    args: SetLstLpWithdrawalFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LST_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLstLpWithdrawalFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lst_lp_withdrawal_fee_ix(
+    keys: SetLstLpWithdrawalFeeKeys,
+    args: SetLstLpWithdrawalFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lst_lp_withdrawal_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lst_lp_withdrawal_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstLpWithdrawalFeeAccounts<'_, '_>,
+    args: SetLstLpWithdrawalFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetLstLpWithdrawalFeeKeys = accounts.into();
+    let ix = set_lst_lp_withdrawal_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lst_lp_withdrawal_fee_invoke(
+    accounts: SetLstLpWithdrawalFeeAccounts<'_, '_>,
+    args: SetLstLpWithdrawalFeeIxArgs,
+) -> ProgramResult {
+    set_lst_lp_withdrawal_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lst_lp_withdrawal_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstLpWithdrawalFeeAccounts<'_, '_>,
+    args: SetLstLpWithdrawalFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLstLpWithdrawalFeeKeys = accounts.into();
+    let ix = set_lst_lp_withdrawal_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lst_lp_withdrawal_fee_invoke_signed(
+    accounts: SetLstLpWithdrawalFeeAccounts<'_, '_>,
+    args: SetLstLpWithdrawalFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lst_lp_withdrawal_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lst_lp_withdrawal_fee_verify_account_keys(
+    accounts: SetLstLpWithdrawalFeeAccounts<'_, '_>,
+    keys: SetLstLpWithdrawalFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.fee_acc.key, &keys.fee_acc),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_lp_withdrawal_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetLstLpWithdrawalFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.fee_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_lp_withdrawal_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetLstLpWithdrawalFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_lp_withdrawal_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetLstLpWithdrawalFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lst_lp_withdrawal_fee_verify_writable_privileges(accounts)?;
+    set_lst_lp_withdrawal_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const MIGRATE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateAccountAccounts<'me, 'info> {
+    ///Account paying for the additional rent of the migrated account
+    pub payer: &'me AccountInfo<'info>,
+    ///ProgramState or FeeAccount with the legacy layout to migrate
+    pub migrate_acc: &'me AccountInfo<'info>,
+    ///Mint of the LST whose FeeAccount is migrated. Any account, by convention the ProgramState, when migrating the ProgramState
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateAccountKeys {
+    ///Account paying for the additional rent of the migrated account
+    pub payer: Pubkey,
+    ///ProgramState or FeeAccount with the legacy layout to migrate
+    pub migrate_acc: Pubkey,
+    ///Mint of the LST whose FeeAccount is migrated. Any account, by convention the ProgramState, when migrating the ProgramState
+    pub lst_mint: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<MigrateAccountAccounts<'_, '_>> for MigrateAccountKeys {
+    fn from(accounts: MigrateAccountAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            migrate_acc: *accounts.migrate_acc.key,
+            lst_mint: *accounts.lst_mint.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<MigrateAccountKeys> for [AccountMeta; MIGRATE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: MigrateAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.migrate_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MIGRATE_ACCOUNT_IX_ACCOUNTS_LEN]> for MigrateAccountKeys {
+    fn from(pubkeys: [Pubkey; MIGRATE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            migrate_acc: pubkeys[1],
+            lst_mint: pubkeys[2],
+            system_program: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<MigrateAccountAccounts<'_, 'info>>
+    for [AccountInfo<'info>; MIGRATE_ACCOUNT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: MigrateAccountAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.migrate_acc.clone(),
+            accounts.lst_mint.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; MIGRATE_ACCOUNT_IX_ACCOUNTS_LEN]>
+    for MigrateAccountAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; MIGRATE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            migrate_acc: &arr[1],
+            lst_mint: &arr[2],
+            system_program: &arr[3],
+        }
+    }
+}
+pub const MIGRATE_ACCOUNT_IX_DISCM: u8 = 242u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrateAccountIxData;
+impl MigrateAccountIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != MIGRATE_ACCOUNT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    MIGRATE_ACCOUNT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[MIGRATE_ACCOUNT_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn migrate_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: MigrateAccountKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; MIGRATE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: MigrateAccountIxData.try_to_vec()?,
+    })
+}
+pub fn migrate_account_ix(keys: MigrateAccountKeys) -> std::io::Result<Instruction> {
+    migrate_account_ix_with_program_id(crate::ID, keys)
+}
+pub fn migrate_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateAccountAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: MigrateAccountKeys = accounts.into();
+    let ix = migrate_account_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn migrate_account_invoke(accounts: MigrateAccountAccounts<'_, '_>) -> ProgramResult {
+    migrate_account_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn migrate_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateAccountAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: MigrateAccountKeys = accounts.into();
+    let ix = migrate_account_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn migrate_account_invoke_signed(
+    accounts: MigrateAccountAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    migrate_account_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn migrate_account_verify_account_keys(
+    accounts: MigrateAccountAccounts<'_, '_>,
+    keys: MigrateAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.migrate_acc.key, &keys.migrate_acc),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_account_verify_writable_privileges<'me, 'info>(
+    accounts: MigrateAccountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.migrate_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_account_verify_signer_privileges<'me, 'info>(
+    accounts: MigrateAccountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_account_verify_account_privileges<'me, 'info>(
+    accounts: MigrateAccountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    migrate_account_verify_writable_privileges(accounts)?;
+    migrate_account_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstLpDepositFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///FeeAccount PDA to modify
+    pub fee_acc: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstLpDepositFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///FeeAccount PDA to modify
+    pub fee_acc: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetLstLpDepositFeeAccounts<'_, '_>> for SetLstLpDepositFeeKeys {
+    fn from(accounts: SetLstLpDepositFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            fee_acc: *accounts.fee_acc.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetLstLpDepositFeeKeys> for [AccountMeta; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLstLpDepositFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]> for SetLstLpDepositFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            fee_acc: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetLstLpDepositFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLstLpDepositFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.fee_acc.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]>
+    for SetLstLpDepositFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            fee_acc: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SET_LST_LP_DEPOSIT_FEE_IX_DISCM: u8 = 243u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLstLpDepositFeeIxArgs {
+    pub lp_deposit_fee_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLstLpDepositFeeIxData(pub SetLstLpDepositFeeIxArgs);
+impl From<SetLstLpDepositFeeIxArgs> for SetLstLpDepositFeeIxData {
+    fn from(args: SetLstLpDepositFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLstLpDepositFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LST_LP_DEPOSIT_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LST_LP_DEPOSIT_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLstLpDepositFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LST_LP_DEPOSIT_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lst_lp_deposit_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLstLpDepositFeeKeys,
+    args: SetLstLpDepositFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LST_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLstLpDepositFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lst_lp_deposit_fee_ix(
+    keys: SetLstLpDepositFeeKeys,
+    args: SetLstLpDepositFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lst_lp_deposit_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lst_lp_deposit_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstLpDepositFeeAccounts<'_, '_>,
+    args: SetLstLpDepositFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetLstLpDepositFeeKeys = accounts.into();
+    let ix = set_lst_lp_deposit_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lst_lp_deposit_fee_invoke(
+    accounts: SetLstLpDepositFeeAccounts<'_, '_>,
+    args: SetLstLpDepositFeeIxArgs,
+) -> ProgramResult {
+    set_lst_lp_deposit_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lst_lp_deposit_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstLpDepositFeeAccounts<'_, '_>,
+    args: SetLstLpDepositFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLstLpDepositFeeKeys = accounts.into();
+    let ix = set_lst_lp_deposit_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lst_lp_deposit_fee_invoke_signed(
+    accounts: SetLstLpDepositFeeAccounts<'_, '_>,
+    args: SetLstLpDepositFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lst_lp_deposit_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lst_lp_deposit_fee_verify_account_keys(
+    accounts: SetLstLpDepositFeeAccounts<'_, '_>,
+    keys: SetLstLpDepositFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.fee_acc.key, &keys.fee_acc),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_lp_deposit_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetLstLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.fee_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_lp_deposit_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetLstLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_lp_deposit_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetLstLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lst_lp_deposit_fee_verify_writable_privileges(accounts)?;
+    set_lst_lp_deposit_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpDepositFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpDepositFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
+}
+impl From<SetLpDepositFeeAccounts<'_, '_>> for SetLpDepositFeeKeys {
+    fn from(accounts: SetLpDepositFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetLpDepositFeeKeys> for [AccountMeta; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLpDepositFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]> for SetLpDepositFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            state: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetLpDepositFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLpDepositFeeAccounts<'_, 'info>) -> Self {
+        [accounts.manager.clone(), accounts.state.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]>
+    for SetLpDepositFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            state: &arr[1],
+        }
+    }
+}
+pub const SET_LP_DEPOSIT_FEE_IX_DISCM: u8 = 244u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLpDepositFeeIxArgs {
+    pub lp_deposit_fee_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLpDepositFeeIxData(pub SetLpDepositFeeIxArgs);
+impl From<SetLpDepositFeeIxArgs> for SetLpDepositFeeIxData {
+    fn from(args: SetLpDepositFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLpDepositFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LP_DEPOSIT_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LP_DEPOSIT_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLpDepositFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LP_DEPOSIT_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lp_deposit_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLpDepositFeeKeys,
+    args: SetLpDepositFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LP_DEPOSIT_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLpDepositFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lp_deposit_fee_ix(
+    keys: SetLpDepositFeeKeys,
+    args: SetLpDepositFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lp_deposit_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lp_deposit_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLpDepositFeeAccounts<'_, '_>,
+    args: SetLpDepositFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetLpDepositFeeKeys = accounts.into();
+    let ix = set_lp_deposit_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lp_deposit_fee_invoke(
+    accounts: SetLpDepositFeeAccounts<'_, '_>,
+    args: SetLpDepositFeeIxArgs,
+) -> ProgramResult {
+    set_lp_deposit_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lp_deposit_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLpDepositFeeAccounts<'_, '_>,
+    args: SetLpDepositFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLpDepositFeeKeys = accounts.into();
+    let ix = set_lp_deposit_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lp_deposit_fee_invoke_signed(
+    accounts: SetLpDepositFeeAccounts<'_, '_>,
+    args: SetLpDepositFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lp_deposit_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lp_deposit_fee_verify_account_keys(
+    accounts: SetLpDepositFeeAccounts<'_, '_>,
+    keys: SetLpDepositFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_deposit_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_deposit_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_deposit_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetLpDepositFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lp_deposit_fee_verify_writable_privileges(accounts)?;
+    set_lp_deposit_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone, Debug)]
+pub struct RemovePairFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Account to refund SOL rent to
+    pub refund_rent_to: &'me AccountInfo<'info>,
+    ///PairFeeAccount PDA to close
+    pub pair_fee_acc: &'me AccountInfo<'info>,
+    ///The input LST's FeeAccount PDA
+    pub input_fee_acc: &'me AccountInfo<'info>,
+    ///Mint of the input LST
+    pub input_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the output LST
+    pub output_lst_mint: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemovePairFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Account to refund SOL rent to
+    pub refund_rent_to: Pubkey,
+    ///PairFeeAccount PDA to close
+    pub pair_fee_acc: Pubkey,
+    ///The input LST's FeeAccount PDA
+    pub input_fee_acc: Pubkey,
+    ///Mint of the input LST
+    pub input_lst_mint: Pubkey,
+    ///Mint of the output LST
+    pub output_lst_mint: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<RemovePairFeeAccounts<'_, '_>> for RemovePairFeeKeys {
+    fn from(accounts: RemovePairFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            refund_rent_to: *accounts.refund_rent_to.key,
+            pair_fee_acc: *accounts.pair_fee_acc.key,
+            input_fee_acc: *accounts.input_fee_acc.key,
+            input_lst_mint: *accounts.input_lst_mint.key,
+            output_lst_mint: *accounts.output_lst_mint.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<RemovePairFeeKeys> for [AccountMeta; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: RemovePairFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pair_fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.input_fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.input_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN]> for RemovePairFeeKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            refund_rent_to: pubkeys[1],
+            pair_fee_acc: pubkeys[2],
+            input_fee_acc: pubkeys[3],
+            input_lst_mint: pubkeys[4],
+            output_lst_mint: pubkeys[5],
+            state: pubkeys[6],
+        }
+    }
+}
+impl<'info> From<RemovePairFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemovePairFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.refund_rent_to.clone(),
+            accounts.pair_fee_acc.clone(),
+            accounts.input_fee_acc.clone(),
+            accounts.input_lst_mint.clone(),
+            accounts.output_lst_mint.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN]>
+    for RemovePairFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            refund_rent_to: &arr[1],
+            pair_fee_acc: &arr[2],
+            input_fee_acc: &arr[3],
+            input_lst_mint: &arr[4],
+            output_lst_mint: &arr[5],
+            state: &arr[6],
+        }
+    }
+}
+pub const REMOVE_PAIR_FEE_IX_DISCM: u8 = 245u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemovePairFeeIxData;
+impl RemovePairFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_PAIR_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_PAIR_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_PAIR_FEE_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_pair_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemovePairFeeKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_PAIR_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: RemovePairFeeIxData.try_to_vec()?,
+    })
+}
+pub fn remove_pair_fee_ix(keys: RemovePairFeeKeys) -> std::io::Result<Instruction> {
+    remove_pair_fee_ix_with_program_id(crate::ID, keys)
+}
+pub fn remove_pair_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemovePairFeeAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: RemovePairFeeKeys = accounts.into();
+    let ix = remove_pair_fee_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_pair_fee_invoke(accounts: RemovePairFeeAccounts<'_, '_>) -> ProgramResult {
+    remove_pair_fee_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn remove_pair_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemovePairFeeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemovePairFeeKeys = accounts.into();
+    let ix = remove_pair_fee_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_pair_fee_invoke_signed(
+    accounts: RemovePairFeeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_pair_fee_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn remove_pair_fee_verify_account_keys(
+    accounts: RemovePairFeeAccounts<'_, '_>,
+    keys: RemovePairFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+        (accounts.pair_fee_acc.key, &keys.pair_fee_acc),
+        (accounts.input_fee_acc.key, &keys.input_fee_acc),
+        (accounts.input_lst_mint.key, &keys.input_lst_mint),
+        (accounts.output_lst_mint.key, &keys.output_lst_mint),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_pair_fee_verify_writable_privileges<'me, 'info>(
+    accounts: RemovePairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.refund_rent_to,
+        accounts.pair_fee_acc,
+        accounts.input_fee_acc,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_pair_fee_verify_signer_privileges<'me, 'info>(
+    accounts: RemovePairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_pair_fee_verify_account_privileges<'me, 'info>(
+    accounts: RemovePairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_pair_fee_verify_writable_privileges(accounts)?;
+    remove_pair_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_PAIR_FEE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetPairFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///PairFeeAccount PDA to modify
+    pub pair_fee_acc: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetPairFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///PairFeeAccount PDA to modify
+    pub pair_fee_acc: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetPairFeeAccounts<'_, '_>> for SetPairFeeKeys {
+    fn from(accounts: SetPairFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            pair_fee_acc: *accounts.pair_fee_acc.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetPairFeeKeys> for [AccountMeta; SET_PAIR_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetPairFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pair_fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_PAIR_FEE_IX_ACCOUNTS_LEN]> for SetPairFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            pair_fee_acc: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetPairFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_PAIR_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetPairFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.pair_fee_acc.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PAIR_FEE_IX_ACCOUNTS_LEN]>
+    for SetPairFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            pair_fee_acc: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SET_PAIR_FEE_IX_DISCM: u8 = 246u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPairFeeIxArgs {
+    pub fee_bps: i16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetPairFeeIxData(pub SetPairFeeIxArgs);
+impl From<SetPairFeeIxArgs> for SetPairFeeIxData {
+    fn from(args: SetPairFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetPairFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_PAIR_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_PAIR_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetPairFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_PAIR_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_pair_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetPairFeeKeys,
+    args: SetPairFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_PAIR_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetPairFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_pair_fee_ix(
+    keys: SetPairFeeKeys,
+    args: SetPairFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_pair_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_pair_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetPairFeeAccounts<'_, '_>,
+    args: SetPairFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetPairFeeKeys = accounts.into();
+    let ix = set_pair_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_pair_fee_invoke(
+    accounts: SetPairFeeAccounts<'_, '_>,
+    args: SetPairFeeIxArgs,
+) -> ProgramResult {
+    set_pair_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_pair_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetPairFeeAccounts<'_, '_>,
+    args: SetPairFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetPairFeeKeys = accounts.into();
+    let ix = set_pair_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_pair_fee_invoke_signed(
+    accounts: SetPairFeeAccounts<'_, '_>,
+    args: SetPairFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_pair_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_pair_fee_verify_account_keys(
+    accounts: SetPairFeeAccounts<'_, '_>,
+    keys: SetPairFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.pair_fee_acc.key, &keys.pair_fee_acc),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_pair_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pair_fee_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_pair_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_pair_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_pair_fee_verify_writable_privileges(accounts)?;
+    set_pair_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_PAIR_FEE_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone, Debug)]
+pub struct AddPairFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Account paying for PairFeeAccount's rent
+    pub payer: &'me AccountInfo<'info>,
+    ///PairFeeAccount PDA to be created
+    pub pair_fee_acc: &'me AccountInfo<'info>,
+    ///The input LST's FeeAccount PDA
+    pub input_fee_acc: &'me AccountInfo<'info>,
+    ///Mint of the input LST
+    pub input_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the output LST
+    pub output_lst_mint: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddPairFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Account paying for PairFeeAccount's rent
+    pub payer: Pubkey,
+    ///PairFeeAccount PDA to be created
+    pub pair_fee_acc: Pubkey,
+    ///The input LST's FeeAccount PDA
+    pub input_fee_acc: Pubkey,
+    ///Mint of the input LST
+    pub input_lst_mint: Pubkey,
+    ///Mint of the output LST
+    pub output_lst_mint: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<AddPairFeeAccounts<'_, '_>> for AddPairFeeKeys {
+    fn from(accounts: AddPairFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            payer: *accounts.payer.key,
+            pair_fee_acc: *accounts.pair_fee_acc.key,
+            input_fee_acc: *accounts.input_fee_acc.key,
+            input_lst_mint: *accounts.input_lst_mint.key,
+            output_lst_mint: *accounts.output_lst_mint.key,
+            state: *accounts.state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<AddPairFeeKeys> for [AccountMeta; ADD_PAIR_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddPairFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pair_fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.input_fee_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.input_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_PAIR_FEE_IX_ACCOUNTS_LEN]> for AddPairFeeKeys {
+    fn from(pubkeys: [Pubkey; ADD_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            payer: pubkeys[1],
+            pair_fee_acc: pubkeys[2],
+            input_fee_acc: pubkeys[3],
+            input_lst_mint: pubkeys[4],
+            output_lst_mint: pubkeys[5],
+            state: pubkeys[6],
+            system_program: pubkeys[7],
+        }
+    }
+}
+impl<'info> From<AddPairFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ADD_PAIR_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AddPairFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.payer.clone(),
+            accounts.pair_fee_acc.clone(),
+            accounts.input_fee_acc.clone(),
+            accounts.input_lst_mint.clone(),
+            accounts.output_lst_mint.clone(),
+            accounts.state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_PAIR_FEE_IX_ACCOUNTS_LEN]>
+    for AddPairFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_PAIR_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            payer: &arr[1],
+            pair_fee_acc: &arr[2],
+            input_fee_acc: &arr[3],
+            input_lst_mint: &arr[4],
+            output_lst_mint: &arr[5],
+            state: &arr[6],
+            system_program: &arr[7],
+        }
+    }
+}
+pub const ADD_PAIR_FEE_IX_DISCM: u8 = 247u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddPairFeeIxArgs {
+    pub fee_bps: i16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddPairFeeIxData(pub AddPairFeeIxArgs);
+impl From<AddPairFeeIxArgs> for AddPairFeeIxData {
+    fn from(args: AddPairFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddPairFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_PAIR_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_PAIR_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddPairFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_PAIR_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_pair_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddPairFeeKeys,
+    args: AddPairFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_PAIR_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddPairFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_pair_fee_ix(
+    keys: AddPairFeeKeys,
+    args: AddPairFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    add_pair_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_pair_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddPairFeeAccounts<'_, '_>,
+    args: AddPairFeeIxArgs,
+) -> ProgramResult {
+    let keys: AddPairFeeKeys = accounts.into();
+    let ix = add_pair_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_pair_fee_invoke(
+    accounts: AddPairFeeAccounts<'_, '_>,
+    args: AddPairFeeIxArgs,
+) -> ProgramResult {
+    add_pair_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_pair_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddPairFeeAccounts<'_, '_>,
+    args: AddPairFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddPairFeeKeys = accounts.into();
+    let ix = add_pair_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_pair_fee_invoke_signed(
+    accounts: AddPairFeeAccounts<'_, '_>,
+    args: AddPairFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_pair_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_pair_fee_verify_account_keys(
+    accounts: AddPairFeeAccounts<'_, '_>,
+    keys: AddPairFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pair_fee_acc.key, &keys.pair_fee_acc),
+        (accounts.input_fee_acc.key, &keys.input_fee_acc),
+        (accounts.input_lst_mint.key, &keys.input_lst_mint),
+        (accounts.output_lst_mint.key, &keys.output_lst_mint),
+        (accounts.state.key, &keys.state),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_pair_fee_verify_writable_privileges<'me, 'info>(
+    accounts: AddPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.payer,
+        accounts.pair_fee_acc,
+        accounts.input_fee_acc,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_pair_fee_verify_signer_privileges<'me, 'info>(
+    accounts: AddPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_pair_fee_verify_account_privileges<'me, 'info>(
+    accounts: AddPairFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_pair_fee_verify_writable_privileges(accounts)?;
+    add_pair_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LST_SURCHARGE_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstSurchargeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Account paying for LstSurcharge's rent if it needs to be created
+    pub payer: &'me AccountInfo<'info>,
+    ///LstSurcharge PDA to create or modify
+    pub surcharge_acc: &'me AccountInfo<'info>,
+    ///Mint of the LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The LST's stake pool account
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstSurchargeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Account paying for LstSurcharge's rent if it needs to be created
+    pub payer: Pubkey,
+    ///LstSurcharge PDA to create or modify
+    pub surcharge_acc: Pubkey,
+    ///Mint of the LST
+    pub lst_mint: Pubkey,
+    ///The LST's stake pool account
+    pub stake_pool: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetLstSurchargeAccounts<'_, '_>> for SetLstSurchargeKeys {
+    fn from(accounts: SetLstSurchargeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            payer: *accounts.payer.key,
+            surcharge_acc: *accounts.surcharge_acc.key,
+            lst_mint: *accounts.lst_mint.key,
+            stake_pool: *accounts.stake_pool.key,
+            state: *accounts.state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetLstSurchargeKeys> for [AccountMeta; SET_LST_SURCHARGE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLstSurchargeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.surcharge_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LST_SURCHARGE_IX_ACCOUNTS_LEN]> for SetLstSurchargeKeys {
+    fn from(pubkeys: [Pubkey; SET_LST_SURCHARGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            payer: pubkeys[1],
+            surcharge_acc: pubkeys[2],
+            lst_mint: pubkeys[3],
+            stake_pool: pubkeys[4],
+            state: pubkeys[5],
+            system_program: pubkeys[6],
+        }
+    }
+}
+impl<'info> From<SetLstSurchargeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LST_SURCHARGE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLstSurchargeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.payer.clone(),
+            accounts.surcharge_acc.clone(),
+            accounts.lst_mint.clone(),
+            accounts.stake_pool.clone(),
+            accounts.state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LST_SURCHARGE_IX_ACCOUNTS_LEN]>
+    for SetLstSurchargeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LST_SURCHARGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            payer: &arr[1],
+            surcharge_acc: &arr[2],
+            lst_mint: &arr[3],
+            stake_pool: &arr[4],
+            state: &arr[5],
+            system_program: &arr[6],
+        }
+    }
+}
+pub const SET_LST_SURCHARGE_IX_DISCM: u8 = 248u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLstSurchargeIxArgs {
+    pub surcharge_bps: u16,
+    pub last_update_epoch_offset: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLstSurchargeIxData(pub SetLstSurchargeIxArgs);
+impl From<SetLstSurchargeIxArgs> for SetLstSurchargeIxData {
+    fn from(args: SetLstSurchargeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLstSurchargeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LST_SURCHARGE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LST_SURCHARGE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLstSurchargeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LST_SURCHARGE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lst_surcharge_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLstSurchargeKeys,
+    args: SetLstSurchargeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LST_SURCHARGE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLstSurchargeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lst_surcharge_ix(
+    keys: SetLstSurchargeKeys,
+    args: SetLstSurchargeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lst_surcharge_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lst_surcharge_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstSurchargeAccounts<'_, '_>,
+    args: SetLstSurchargeIxArgs,
+) -> ProgramResult {
+    let keys: SetLstSurchargeKeys = accounts.into();
+    let ix = set_lst_surcharge_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lst_surcharge_invoke(
+    accounts: SetLstSurchargeAccounts<'_, '_>,
+    args: SetLstSurchargeIxArgs,
+) -> ProgramResult {
+    set_lst_surcharge_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lst_surcharge_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstSurchargeAccounts<'_, '_>,
+    args: SetLstSurchargeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLstSurchargeKeys = accounts.into();
+    let ix = set_lst_surcharge_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lst_surcharge_invoke_signed(
+    accounts: SetLstSurchargeAccounts<'_, '_>,
+    args: SetLstSurchargeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lst_surcharge_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lst_surcharge_verify_account_keys(
+    accounts: SetLstSurchargeAccounts<'_, '_>,
+    keys: SetLstSurchargeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.payer.key, &keys.payer),
+        (accounts.surcharge_acc.key, &keys.surcharge_acc),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.state.key, &keys.state),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_surcharge_verify_writable_privileges<'me, 'info>(
+    accounts: SetLstSurchargeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.surcharge_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_surcharge_verify_signer_privileges<'me, 'info>(
+    accounts: SetLstSurchargeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_surcharge_verify_account_privileges<'me, 'info>(
+    accounts: SetLstSurchargeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lst_surcharge_verify_writable_privileges(accounts)?;
+    set_lst_surcharge_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_SURGE_FEE_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetSurgeFeeAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Account paying for SurgeConfig's rent if it needs to be created
+    pub payer: &'me AccountInfo<'info>,
+    ///SurgeConfig PDA to create or modify
+    pub surge_config: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetSurgeFeeKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Account paying for SurgeConfig's rent if it needs to be created
+    pub payer: Pubkey,
+    ///SurgeConfig PDA to create or modify
+    pub surge_config: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetSurgeFeeAccounts<'_, '_>> for SetSurgeFeeKeys {
+    fn from(accounts: SetSurgeFeeAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            payer: *accounts.payer.key,
+            surge_config: *accounts.surge_config.key,
+            state: *accounts.state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetSurgeFeeKeys> for [AccountMeta; SET_SURGE_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetSurgeFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.surge_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_SURGE_FEE_IX_ACCOUNTS_LEN]> for SetSurgeFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_SURGE_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            payer: pubkeys[1],
+            surge_config: pubkeys[2],
+            state: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetSurgeFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_SURGE_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetSurgeFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.payer.clone(),
+            accounts.surge_config.clone(),
+            accounts.state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_SURGE_FEE_IX_ACCOUNTS_LEN]>
+    for SetSurgeFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_SURGE_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            payer: &arr[1],
+            surge_config: &arr[2],
+            state: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_SURGE_FEE_IX_DISCM: u8 = 249u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetSurgeFeeIxArgs {
+    pub max_surge_fee_bps: u16,
+    pub decay_slots: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetSurgeFeeIxData(pub SetSurgeFeeIxArgs);
+impl From<SetSurgeFeeIxArgs> for SetSurgeFeeIxData {
+    fn from(args: SetSurgeFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetSurgeFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_SURGE_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_SURGE_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetSurgeFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_SURGE_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_surge_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetSurgeFeeKeys,
+    args: SetSurgeFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_SURGE_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetSurgeFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_surge_fee_ix(
+    keys: SetSurgeFeeKeys,
+    args: SetSurgeFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_surge_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_surge_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetSurgeFeeAccounts<'_, '_>,
+    args: SetSurgeFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetSurgeFeeKeys = accounts.into();
+    let ix = set_surge_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_surge_fee_invoke(
+    accounts: SetSurgeFeeAccounts<'_, '_>,
+    args: SetSurgeFeeIxArgs,
+) -> ProgramResult {
+    set_surge_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_surge_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetSurgeFeeAccounts<'_, '_>,
+    args: SetSurgeFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetSurgeFeeKeys = accounts.into();
+    let ix = set_surge_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_surge_fee_invoke_signed(
+    accounts: SetSurgeFeeAccounts<'_, '_>,
+    args: SetSurgeFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_surge_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_surge_fee_verify_account_keys(
+    accounts: SetSurgeFeeAccounts<'_, '_>,
+    keys: SetSurgeFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.payer.key, &keys.payer),
+        (accounts.surge_config.key, &keys.surge_config),
+        (accounts.state.key, &keys.state),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_surge_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetSurgeFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.surge_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_surge_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetSurgeFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_surge_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetSurgeFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_surge_fee_verify_writable_privileges(accounts)?;
+    set_surge_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
 pub const SET_LP_WITHDRAWAL_FEE_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct SetLpWithdrawalFeeAccounts<'me, 'info> {
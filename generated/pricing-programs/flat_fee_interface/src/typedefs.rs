@@ -7,13 +7,84 @@ use solana_program::pubkey::Pubkey;
 pub struct ProgramState {
     pub manager: Pubkey,
     pub lp_withdrawal_fee_bps: u16,
+    pub lp_deposit_fee_bps: u16,
+    pub pending_lp_withdrawal_fee_bps: u16,
+    pub has_pending_lp_withdrawal_fee: u8,
+    pub padding: u8,
+    pub pending_lp_withdrawal_fee_activation_epoch: u64,
+    pub admin: Pubkey,
+    pub min_lst_fee_bps: i16,
+    pub max_lst_fee_bps: i16,
+    pub max_lp_withdrawal_fee_bps: u16,
+    pub max_fee_change_bps: u16,
+    pub max_lp_deposit_fee_bps: u16,
+    pub max_surge_fee_bps: u16,
+    pub has_fee_bounds: u8,
+    pub fee_bounds_padding: [u8; 3],
+    pub baseline_lp_withdrawal_fee_bps: u16,
+    pub baseline_lp_deposit_fee_bps: u16,
+    pub has_fee_baseline: u8,
+    pub fee_baseline_padding: [u8; 3],
+    pub fee_baseline_epoch: u64,
 }
 #[repr(C)]
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeeAccount {
     pub bump: u8,
-    pub padding: u8,
+    pub has_lp_withdrawal_fee: u8,
     pub input_fee_bps: i16,
     pub output_fee_bps: i16,
+    pub lp_deposit_fee_bps: u16,
+    pub lp_withdrawal_fee_bps: u16,
+    pub pending_input_fee_bps: i16,
+    pub pending_output_fee_bps: i16,
+    pub has_pending_fees: u8,
+    pub num_pair_fees: u8,
+    pub pending_fees_activation_epoch: u64,
+    pub baseline_input_fee_bps: i16,
+    pub baseline_output_fee_bps: i16,
+    pub baseline_lp_deposit_fee_bps: u16,
+    pub baseline_lp_withdrawal_fee_bps: u16,
+    pub has_fee_baseline: u8,
+    pub fee_baseline_padding: [u8; 7],
+    pub fee_baseline_epoch: u64,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SurgeConfig {
+    pub max_surge_fee_bps: u16,
+    pub padding: [u8; 2],
+    pub decay_slots: u32,
+    pub baseline_max_surge_fee_bps: u16,
+    pub has_fee_baseline: u8,
+    pub fee_baseline_padding: [u8; 5],
+    pub fee_baseline_epoch: u64,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LstSurcharge {
+    pub stake_pool: Pubkey,
+    pub bump: u8,
+    pub padding: u8,
+    pub surcharge_bps: u16,
+    pub last_update_epoch_offset: u16,
+    pub baseline_surcharge_bps: u16,
+    pub has_fee_baseline: u8,
+    pub fee_baseline_padding: [u8; 7],
+    pub fee_baseline_epoch: u64,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PairFeeAccount {
+    pub bump: u8,
+    pub padding: u8,
+    pub fee_bps: i16,
+    pub baseline_fee_bps: i16,
+    pub has_fee_baseline: u8,
+    pub fee_baseline_padding: u8,
+    pub fee_baseline_epoch: u64,
 }
@@ -80,13 +80,21 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke_signed(ix, &account_info, seeds)
 }
-pub const PRICE_EXACT_IN_IX_ACCOUNTS_LEN: usize = 2;
+pub const PRICE_EXACT_IN_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactInAccounts<'me, 'info> {
     ///Mint of the input LST
     pub input_lst_mint: &'me AccountInfo<'info>,
     ///Mint of the output LST
     pub output_lst_mint: &'me AccountInfo<'info>,
+    ///The S controller pool state PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S controller LST state list PDA
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the input LST
+    pub input_pool_reserves: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the output LST
+    pub output_pool_reserves: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactInKeys {
@@ -94,12 +102,24 @@ pub struct PriceExactInKeys {
     pub input_lst_mint: Pubkey,
     ///Mint of the output LST
     pub output_lst_mint: Pubkey,
+    ///The S controller pool state PDA
+    pub pool_state: Pubkey,
+    ///The S controller LST state list PDA
+    pub lst_state_list: Pubkey,
+    ///The pool's reserves token account for the input LST
+    pub input_pool_reserves: Pubkey,
+    ///The pool's reserves token account for the output LST
+    pub output_pool_reserves: Pubkey,
 }
 impl From<PriceExactInAccounts<'_, '_>> for PriceExactInKeys {
     fn from(accounts: PriceExactInAccounts) -> Self {
         Self {
             input_lst_mint: *accounts.input_lst_mint.key,
             output_lst_mint: *accounts.output_lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            input_pool_reserves: *accounts.input_pool_reserves.key,
+            output_pool_reserves: *accounts.output_pool_reserves.key,
         }
     }
 }
@@ -116,6 +136,26 @@ impl From<PriceExactInKeys> for [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.input_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -124,6 +164,10 @@ impl From<[Pubkey; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]> for PriceExactInKeys {
         Self {
             input_lst_mint: pubkeys[0],
             output_lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+            input_pool_reserves: pubkeys[4],
+            output_pool_reserves: pubkeys[5],
         }
     }
 }
@@ -134,6 +178,10 @@ impl<'info> From<PriceExactInAccounts<'_, 'info>>
         [
             accounts.input_lst_mint.clone(),
             accounts.output_lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.input_pool_reserves.clone(),
+            accounts.output_pool_reserves.clone(),
         ]
     }
 }
@@ -144,6 +192,10 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]>
         Self {
             input_lst_mint: &arr[0],
             output_lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+            input_pool_reserves: &arr[4],
+            output_pool_reserves: &arr[5],
         }
     }
 }
@@ -246,6 +298,13 @@ pub fn price_exact_in_verify_account_keys(
     for (actual, expected) in [
         (accounts.input_lst_mint.key, &keys.input_lst_mint),
         (accounts.output_lst_mint.key, &keys.output_lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.input_pool_reserves.key, &keys.input_pool_reserves),
+        (
+            accounts.output_pool_reserves.key,
+            &keys.output_pool_reserves,
+        ),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -253,13 +312,21 @@ pub fn price_exact_in_verify_account_keys(
     }
     Ok(())
 }
-pub const PRICE_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 2;
+pub const PRICE_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactOutAccounts<'me, 'info> {
     ///Mint of the input LST
     pub input_lst_mint: &'me AccountInfo<'info>,
     ///Mint of the output LST
     pub output_lst_mint: &'me AccountInfo<'info>,
+    ///The S controller pool state PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S controller LST state list PDA
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the input LST
+    pub input_pool_reserves: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the output LST
+    pub output_pool_reserves: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceExactOutKeys {
@@ -267,12 +334,24 @@ pub struct PriceExactOutKeys {
     pub input_lst_mint: Pubkey,
     ///Mint of the output LST
     pub output_lst_mint: Pubkey,
+    ///The S controller pool state PDA
+    pub pool_state: Pubkey,
+    ///The S controller LST state list PDA
+    pub lst_state_list: Pubkey,
+    ///The pool's reserves token account for the input LST
+    pub input_pool_reserves: Pubkey,
+    ///The pool's reserves token account for the output LST
+    pub output_pool_reserves: Pubkey,
 }
 impl From<PriceExactOutAccounts<'_, '_>> for PriceExactOutKeys {
     fn from(accounts: PriceExactOutAccounts) -> Self {
         Self {
             input_lst_mint: *accounts.input_lst_mint.key,
             output_lst_mint: *accounts.output_lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            input_pool_reserves: *accounts.input_pool_reserves.key,
+            output_pool_reserves: *accounts.output_pool_reserves.key,
         }
     }
 }
@@ -289,6 +368,26 @@ impl From<PriceExactOutKeys> for [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.input_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.output_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -297,6 +396,10 @@ impl From<[Pubkey; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]> for PriceExactOutKeys {
         Self {
             input_lst_mint: pubkeys[0],
             output_lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+            input_pool_reserves: pubkeys[4],
+            output_pool_reserves: pubkeys[5],
         }
     }
 }
@@ -307,6 +410,10 @@ impl<'info> From<PriceExactOutAccounts<'_, 'info>>
         [
             accounts.input_lst_mint.clone(),
             accounts.output_lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.input_pool_reserves.clone(),
+            accounts.output_pool_reserves.clone(),
         ]
     }
 }
@@ -317,6 +424,10 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]
         Self {
             input_lst_mint: &arr[0],
             output_lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+            input_pool_reserves: &arr[4],
+            output_pool_reserves: &arr[5],
         }
     }
 }
@@ -419,6 +530,13 @@ pub fn price_exact_out_verify_account_keys(
     for (actual, expected) in [
         (accounts.input_lst_mint.key, &keys.input_lst_mint),
         (accounts.output_lst_mint.key, &keys.output_lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.input_pool_reserves.key, &keys.input_pool_reserves),
+        (
+            accounts.output_pool_reserves.key,
+            &keys.output_pool_reserves,
+        ),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -426,37 +544,72 @@ pub fn price_exact_out_verify_account_keys(
     }
     Ok(())
 }
-pub const PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN: usize = 1;
+pub const PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceLpTokensToMintAccounts<'me, 'info> {
     ///Mint of the input LST
     pub input_lst_mint: &'me AccountInfo<'info>,
+    ///The S controller pool state PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S controller LST state list PDA
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the input LST
+    pub pool_reserves: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceLpTokensToMintKeys {
     ///Mint of the input LST
     pub input_lst_mint: Pubkey,
+    ///The S controller pool state PDA
+    pub pool_state: Pubkey,
+    ///The S controller LST state list PDA
+    pub lst_state_list: Pubkey,
+    ///The pool's reserves token account for the input LST
+    pub pool_reserves: Pubkey,
 }
 impl From<PriceLpTokensToMintAccounts<'_, '_>> for PriceLpTokensToMintKeys {
     fn from(accounts: PriceLpTokensToMintAccounts) -> Self {
         Self {
             input_lst_mint: *accounts.input_lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
         }
     }
 }
 impl From<PriceLpTokensToMintKeys> for [AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN] {
     fn from(keys: PriceLpTokensToMintKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.input_lst_mint,
-            is_signer: false,
-            is_writable: false,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.input_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
     }
 }
 impl From<[Pubkey; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]> for PriceLpTokensToMintKeys {
     fn from(pubkeys: [Pubkey; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             input_lst_mint: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            pool_reserves: pubkeys[3],
         }
     }
 }
@@ -464,7 +617,12 @@ impl<'info> From<PriceLpTokensToMintAccounts<'_, 'info>>
     for [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]
 {
     fn from(accounts: PriceLpTokensToMintAccounts<'_, 'info>) -> Self {
-        [accounts.input_lst_mint.clone()]
+        [
+            accounts.input_lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+        ]
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>
@@ -473,6 +631,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOU
     fn from(arr: &'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             input_lst_mint: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            pool_reserves: &arr[3],
         }
     }
 }
@@ -572,44 +733,84 @@ pub fn price_lp_tokens_to_mint_verify_account_keys(
     accounts: PriceLpTokensToMintAccounts<'_, '_>,
     keys: PriceLpTokensToMintKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
-    for (actual, expected) in [(accounts.input_lst_mint.key, &keys.input_lst_mint)] {
+    for (actual, expected) in [
+        (accounts.input_lst_mint.key, &keys.input_lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+    ] {
         if actual != expected {
             return Err((*actual, *expected));
         }
     }
     Ok(())
 }
-pub const PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN: usize = 1;
+pub const PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct PriceLpTokensToRedeemAccounts<'me, 'info> {
     ///Mint of the output LST
     pub output_lst_mint: &'me AccountInfo<'info>,
+    ///The S controller pool state PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S controller LST state list PDA
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The pool's reserves token account for the output LST
+    pub pool_reserves: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PriceLpTokensToRedeemKeys {
     ///Mint of the output LST
     pub output_lst_mint: Pubkey,
+    ///The S controller pool state PDA
+    pub pool_state: Pubkey,
+    ///The S controller LST state list PDA
+    pub lst_state_list: Pubkey,
+    ///The pool's reserves token account for the output LST
+    pub pool_reserves: Pubkey,
 }
 impl From<PriceLpTokensToRedeemAccounts<'_, '_>> for PriceLpTokensToRedeemKeys {
     fn from(accounts: PriceLpTokensToRedeemAccounts) -> Self {
         Self {
             output_lst_mint: *accounts.output_lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
         }
     }
 }
 impl From<PriceLpTokensToRedeemKeys> for [AccountMeta; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN] {
     fn from(keys: PriceLpTokensToRedeemKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.output_lst_mint,
-            is_signer: false,
-            is_writable: false,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.output_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
     }
 }
 impl From<[Pubkey; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]> for PriceLpTokensToRedeemKeys {
     fn from(pubkeys: [Pubkey; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             output_lst_mint: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            pool_reserves: pubkeys[3],
         }
     }
 }
@@ -617,7 +818,12 @@ impl<'info> From<PriceLpTokensToRedeemAccounts<'_, 'info>>
     for [AccountInfo<'info>; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]
 {
     fn from(accounts: PriceLpTokensToRedeemAccounts<'_, 'info>) -> Self {
-        [accounts.output_lst_mint.clone()]
+        [
+            accounts.output_lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+        ]
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]>
@@ -626,6 +832,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_REDEEM_IX_ACC
     fn from(arr: &'me [AccountInfo<'info>; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             output_lst_mint: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            pool_reserves: &arr[3],
         }
     }
 }
@@ -725,7 +934,12 @@ pub fn price_lp_tokens_to_redeem_verify_account_keys(
     accounts: PriceLpTokensToRedeemAccounts<'_, '_>,
     keys: PriceLpTokensToRedeemKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
-    for (actual, expected) in [(accounts.output_lst_mint.key, &keys.output_lst_mint)] {
+    for (actual, expected) in [
+        (accounts.output_lst_mint.key, &keys.output_lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+    ] {
         if actual != expected {
             return Err((*actual, *expected));
         }
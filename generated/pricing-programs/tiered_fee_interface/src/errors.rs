@@ -0,0 +1,51 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum TieredFeeError {
+    #[error("Invalid program state data")]
+    InvalidProgramStateData = 0,
+    #[error("Incorrect program state account")]
+    IncorrectProgramState = 1,
+    #[error("FeeAccount is not initialized for the given LST mint")]
+    UnsupportedLstMint = 2,
+    #[error("Given signed fee value is out of bound")]
+    SignedFeeOutOfBound = 3,
+    #[error("Given unsigned fee value is out of bound")]
+    UnsignedFeeOutOfBound = 4,
+    #[error("Math error")]
+    MathError = 5,
+    #[error("LST is not on the S controller's LST state list")]
+    LstNotInPool = 6,
+    #[error(
+        "Fee tiers must number at most 4 and have strictly increasing thresholds of at most 10_000 bps"
+    )]
+    InvalidFeeTiers = 7,
+    #[error("Invalid fee tier basis")]
+    InvalidTierBasis = 8,
+}
+impl From<TieredFeeError> for ProgramError {
+    fn from(e: TieredFeeError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for TieredFeeError {
+    fn type_of() -> &'static str {
+        "TieredFeeError"
+    }
+}
+impl PrintProgramError for TieredFeeError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
@@ -0,0 +1,9 @@
+#![allow(non_local_definitions)]
+
+solana_program::declare_id!("78ykD6FdAN52dW2ffL26yyiidEkfPE1hYvBCiXWXphPa");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
@@ -84,6 +84,32 @@ pub enum SControllerError {
     SwapSameLst = 35,
     #[error("Cannot add the same authority that has already been authorized")]
     DuplicateDisablePoolAuthority = 36,
+    #[error("Invalid donor allowlist data")]
+    InvalidDonorAllowlistData = 37,
+    #[error("Incorrect donor allowlist account")]
+    IncorrectDonorAllowlist = 38,
+    #[error("Donor with given index does not exist")]
+    InvalidDonorIndex = 39,
+    #[error("Donor is not on the donor allowlist")]
+    UnauthorizedDonor = 40,
+    #[error("Cannot add a donor that is already on the allowlist")]
+    DuplicateDonor = 41,
+    #[error("Change in pool SOL value from rebalance is below the minimum set in StartRebalance")]
+    RebalanceSolValueDeltaTooLow = 42,
+    #[error("Too many additional destination LSTs for a single rebalance")]
+    TooManyRebalanceDsts = 43,
+    #[error("Destination LSTs do not match the ones recorded at the start of the rebalance")]
+    IncorrectRebalanceDsts = 44,
+    #[error(
+        "Pool instructions between StartRebalance and EndRebalance must only be signed by the rebalance authority"
+    )]
+    IxNotAllowedDuringRebalance = 45,
+    #[error(
+        "min_sol_value_delta allows a rebalance loss greater than the pool's max_rebalance_loss"
+    )]
+    RebalanceLossTooHigh = 46,
+    #[error("Invalid rebalance config data")]
+    InvalidRebalanceConfigData = 47,
 }
 impl From<SControllerError> for ProgramError {
     fn from(e: SControllerError) -> Self {
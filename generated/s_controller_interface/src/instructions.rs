@@ -1,3 +1,4 @@
+use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo,
@@ -33,6 +34,14 @@ pub enum SControllerProgramIx {
     EndRebalance,
     SetRebalanceAuthority,
     Initialize,
+    Donate(DonateIxArgs),
+    AddDonor,
+    RemoveDonor(RemoveDonorIxArgs),
+    SetLstBatchCalc(SetLstBatchCalcIxArgs),
+    SetMaxRebalanceLoss(SetMaxRebalanceLossIxArgs),
+    StartRebalanceV2(StartRebalanceV2IxArgs),
+    EndRebalanceV2(EndRebalanceV2IxArgs),
+    SetPricingProgramV2(SetPricingProgramV2IxArgs),
 }
 impl SControllerProgramIx {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -88,6 +97,26 @@ impl SControllerProgramIx {
             END_REBALANCE_IX_DISCM => Ok(Self::EndRebalance),
             SET_REBALANCE_AUTHORITY_IX_DISCM => Ok(Self::SetRebalanceAuthority),
             INITIALIZE_IX_DISCM => Ok(Self::Initialize),
+            DONATE_IX_DISCM => Ok(Self::Donate(DonateIxArgs::deserialize(&mut reader)?)),
+            ADD_DONOR_IX_DISCM => Ok(Self::AddDonor),
+            REMOVE_DONOR_IX_DISCM => Ok(Self::RemoveDonor(RemoveDonorIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            SET_LST_BATCH_CALC_IX_DISCM => Ok(Self::SetLstBatchCalc(
+                SetLstBatchCalcIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_MAX_REBALANCE_LOSS_IX_DISCM => Ok(Self::SetMaxRebalanceLoss(
+                SetMaxRebalanceLossIxArgs::deserialize(&mut reader)?,
+            )),
+            START_REBALANCE_V2_IX_DISCM => Ok(Self::StartRebalanceV2(
+                StartRebalanceV2IxArgs::deserialize(&mut reader)?,
+            )),
+            END_REBALANCE_V2_IX_DISCM => Ok(Self::EndRebalanceV2(
+                EndRebalanceV2IxArgs::deserialize(&mut reader)?,
+            )),
+            SET_PRICING_PROGRAM_V2_IX_DISCM => Ok(Self::SetPricingProgramV2(
+                SetPricingProgramV2IxArgs::deserialize(&mut reader)?,
+            )),
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!("discm {:?} not found", maybe_discm),
@@ -162,6 +191,35 @@ impl SControllerProgramIx {
             Self::EndRebalance => writer.write_all(&[END_REBALANCE_IX_DISCM]),
             Self::SetRebalanceAuthority => writer.write_all(&[SET_REBALANCE_AUTHORITY_IX_DISCM]),
             Self::Initialize => writer.write_all(&[INITIALIZE_IX_DISCM]),
+            Self::Donate(args) => {
+                writer.write_all(&[DONATE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddDonor => writer.write_all(&[ADD_DONOR_IX_DISCM]),
+            Self::RemoveDonor(args) => {
+                writer.write_all(&[REMOVE_DONOR_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLstBatchCalc(args) => {
+                writer.write_all(&[SET_LST_BATCH_CALC_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetMaxRebalanceLoss(args) => {
+                writer.write_all(&[SET_MAX_REBALANCE_LOSS_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::StartRebalanceV2(args) => {
+                writer.write_all(&[START_REBALANCE_V2_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::EndRebalanceV2(args) => {
+                writer.write_all(&[END_REBALANCE_V2_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetPricingProgramV2(args) => {
+                writer.write_all(&[SET_PRICING_PROGRAM_V2_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
         }
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -6073,3 +6131,2070 @@ pub fn initialize_verify_account_privileges<'me, 'info>(
     initialize_verify_signer_privileges(accounts)?;
     Ok(())
 }
+pub const DONATE_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone, Debug)]
+pub struct DonateAccounts<'me, 'info> {
+    ///Authority of src_lst_acc. Account donating the LST.
+    pub donor: &'me AccountInfo<'info>,
+    ///Mint of the LST to donate
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LST token account to donate from
+    pub src_lst_acc: &'me AccountInfo<'info>,
+    ///LST's token program
+    pub lst_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+    ///The pool's donor allowlist singleton PDA. Donations are permissionless if uninitialized.
+    pub donor_allowlist: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DonateKeys {
+    ///Authority of src_lst_acc. Account donating the LST.
+    pub donor: Pubkey,
+    ///Mint of the LST to donate
+    pub lst_mint: Pubkey,
+    ///LST token account to donate from
+    pub src_lst_acc: Pubkey,
+    ///LST's token program
+    pub lst_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+    ///The pool's donor allowlist singleton PDA. Donations are permissionless if uninitialized.
+    pub donor_allowlist: Pubkey,
+}
+impl From<DonateAccounts<'_, '_>> for DonateKeys {
+    fn from(accounts: DonateAccounts) -> Self {
+        Self {
+            donor: *accounts.donor.key,
+            lst_mint: *accounts.lst_mint.key,
+            src_lst_acc: *accounts.src_lst_acc.key,
+            lst_token_program: *accounts.lst_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+            donor_allowlist: *accounts.donor_allowlist.key,
+        }
+    }
+}
+impl From<DonateKeys> for [AccountMeta; DONATE_IX_ACCOUNTS_LEN] {
+    fn from(keys: DonateKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.donor,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.donor_allowlist,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DONATE_IX_ACCOUNTS_LEN]> for DonateKeys {
+    fn from(pubkeys: [Pubkey; DONATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            donor: pubkeys[0],
+            lst_mint: pubkeys[1],
+            src_lst_acc: pubkeys[2],
+            lst_token_program: pubkeys[3],
+            pool_state: pubkeys[4],
+            lst_state_list: pubkeys[5],
+            pool_reserves: pubkeys[6],
+            donor_allowlist: pubkeys[7],
+        }
+    }
+}
+impl<'info> From<DonateAccounts<'_, 'info>> for [AccountInfo<'info>; DONATE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DonateAccounts<'_, 'info>) -> Self {
+        [
+            accounts.donor.clone(),
+            accounts.lst_mint.clone(),
+            accounts.src_lst_acc.clone(),
+            accounts.lst_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+            accounts.donor_allowlist.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DONATE_IX_ACCOUNTS_LEN]>
+    for DonateAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; DONATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            donor: &arr[0],
+            lst_mint: &arr[1],
+            src_lst_acc: &arr[2],
+            lst_token_program: &arr[3],
+            pool_state: &arr[4],
+            lst_state_list: &arr[5],
+            pool_reserves: &arr[6],
+            donor_allowlist: &arr[7],
+        }
+    }
+}
+pub const DONATE_IX_DISCM: u8 = 23u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DonateIxArgs {
+    pub lst_value_calc_accs: u8,
+    pub lst_index: u32,
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DonateIxData(pub DonateIxArgs);
+impl From<DonateIxArgs> for DonateIxData {
+    fn from(args: DonateIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DonateIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != DONATE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    DONATE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(DonateIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[DONATE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn donate_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DonateKeys,
+    args: DonateIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; DONATE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: DonateIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn donate_ix(keys: DonateKeys, args: DonateIxArgs) -> std::io::Result<Instruction> {
+    donate_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn donate_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DonateAccounts<'_, '_>,
+    args: DonateIxArgs,
+) -> ProgramResult {
+    let keys: DonateKeys = accounts.into();
+    let ix = donate_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn donate_invoke(accounts: DonateAccounts<'_, '_>, args: DonateIxArgs) -> ProgramResult {
+    donate_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn donate_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DonateAccounts<'_, '_>,
+    args: DonateIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: DonateKeys = accounts.into();
+    let ix = donate_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn donate_invoke_signed(
+    accounts: DonateAccounts<'_, '_>,
+    args: DonateIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    donate_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn donate_verify_account_keys(
+    accounts: DonateAccounts<'_, '_>,
+    keys: DonateKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.donor.key, &keys.donor),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.src_lst_acc.key, &keys.src_lst_acc),
+        (accounts.lst_token_program.key, &keys.lst_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+        (accounts.donor_allowlist.key, &keys.donor_allowlist),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn donate_verify_writable_privileges<'me, 'info>(
+    accounts: DonateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lst_acc,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.pool_reserves,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn donate_verify_signer_privileges<'me, 'info>(
+    accounts: DonateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.donor] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn donate_verify_account_privileges<'me, 'info>(
+    accounts: DonateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    donate_verify_writable_privileges(accounts)?;
+    donate_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_DONOR_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct AddDonorAccounts<'me, 'info> {
+    ///Account paying for additional rent for realloc
+    pub payer: &'me AccountInfo<'info>,
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The new donor to add to the allowlist
+    pub new_donor: &'me AccountInfo<'info>,
+    ///The pool's donor allowlist singleton PDA
+    pub donor_allowlist: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddDonorKeys {
+    ///Account paying for additional rent for realloc
+    pub payer: Pubkey,
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The new donor to add to the allowlist
+    pub new_donor: Pubkey,
+    ///The pool's donor allowlist singleton PDA
+    pub donor_allowlist: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<AddDonorAccounts<'_, '_>> for AddDonorKeys {
+    fn from(accounts: AddDonorAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            new_donor: *accounts.new_donor.key,
+            donor_allowlist: *accounts.donor_allowlist.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<AddDonorKeys> for [AccountMeta; ADD_DONOR_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddDonorKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_donor,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.donor_allowlist,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_DONOR_IX_ACCOUNTS_LEN]> for AddDonorKeys {
+    fn from(pubkeys: [Pubkey; ADD_DONOR_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            admin: pubkeys[1],
+            pool_state: pubkeys[2],
+            new_donor: pubkeys[3],
+            donor_allowlist: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<AddDonorAccounts<'_, 'info>> for [AccountInfo<'info>; ADD_DONOR_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AddDonorAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.new_donor.clone(),
+            accounts.donor_allowlist.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_DONOR_IX_ACCOUNTS_LEN]>
+    for AddDonorAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_DONOR_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            admin: &arr[1],
+            pool_state: &arr[2],
+            new_donor: &arr[3],
+            donor_allowlist: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+pub const ADD_DONOR_IX_DISCM: u8 = 24u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddDonorIxData;
+impl AddDonorIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_DONOR_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_DONOR_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_DONOR_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_donor_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddDonorKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_DONOR_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: AddDonorIxData.try_to_vec()?,
+    })
+}
+pub fn add_donor_ix(keys: AddDonorKeys) -> std::io::Result<Instruction> {
+    add_donor_ix_with_program_id(crate::ID, keys)
+}
+pub fn add_donor_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddDonorAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: AddDonorKeys = accounts.into();
+    let ix = add_donor_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_donor_invoke(accounts: AddDonorAccounts<'_, '_>) -> ProgramResult {
+    add_donor_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn add_donor_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddDonorAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddDonorKeys = accounts.into();
+    let ix = add_donor_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_donor_invoke_signed(
+    accounts: AddDonorAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_donor_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn add_donor_verify_account_keys(
+    accounts: AddDonorAccounts<'_, '_>,
+    keys: AddDonorKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.new_donor.key, &keys.new_donor),
+        (accounts.donor_allowlist.key, &keys.donor_allowlist),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_donor_verify_writable_privileges<'me, 'info>(
+    accounts: AddDonorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.donor_allowlist] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_donor_verify_signer_privileges<'me, 'info>(
+    accounts: AddDonorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_donor_verify_account_privileges<'me, 'info>(
+    accounts: AddDonorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_donor_verify_writable_privileges(accounts)?;
+    add_donor_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_DONOR_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveDonorAccounts<'me, 'info> {
+    ///The account to refund SOL rent to after resizing
+    pub refund_rent_to: &'me AccountInfo<'info>,
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The donor to remove from the allowlist
+    pub donor: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's donor allowlist singleton PDA
+    pub donor_allowlist: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveDonorKeys {
+    ///The account to refund SOL rent to after resizing
+    pub refund_rent_to: Pubkey,
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The donor to remove from the allowlist
+    pub donor: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The pool's donor allowlist singleton PDA
+    pub donor_allowlist: Pubkey,
+}
+impl From<RemoveDonorAccounts<'_, '_>> for RemoveDonorKeys {
+    fn from(accounts: RemoveDonorAccounts) -> Self {
+        Self {
+            refund_rent_to: *accounts.refund_rent_to.key,
+            admin: *accounts.admin.key,
+            donor: *accounts.donor.key,
+            pool_state: *accounts.pool_state.key,
+            donor_allowlist: *accounts.donor_allowlist.key,
+        }
+    }
+}
+impl From<RemoveDonorKeys> for [AccountMeta; REMOVE_DONOR_IX_ACCOUNTS_LEN] {
+    fn from(keys: RemoveDonorKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.donor,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.donor_allowlist,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_DONOR_IX_ACCOUNTS_LEN]> for RemoveDonorKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_DONOR_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            refund_rent_to: pubkeys[0],
+            admin: pubkeys[1],
+            donor: pubkeys[2],
+            pool_state: pubkeys[3],
+            donor_allowlist: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<RemoveDonorAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_DONOR_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemoveDonorAccounts<'_, 'info>) -> Self {
+        [
+            accounts.refund_rent_to.clone(),
+            accounts.admin.clone(),
+            accounts.donor.clone(),
+            accounts.pool_state.clone(),
+            accounts.donor_allowlist.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_DONOR_IX_ACCOUNTS_LEN]>
+    for RemoveDonorAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REMOVE_DONOR_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            refund_rent_to: &arr[0],
+            admin: &arr[1],
+            donor: &arr[2],
+            pool_state: &arr[3],
+            donor_allowlist: &arr[4],
+        }
+    }
+}
+pub const REMOVE_DONOR_IX_DISCM: u8 = 25u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveDonorIxArgs {
+    pub index: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveDonorIxData(pub RemoveDonorIxArgs);
+impl From<RemoveDonorIxArgs> for RemoveDonorIxData {
+    fn from(args: RemoveDonorIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveDonorIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_DONOR_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_DONOR_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(RemoveDonorIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_DONOR_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_donor_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveDonorKeys,
+    args: RemoveDonorIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_DONOR_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveDonorIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_donor_ix(
+    keys: RemoveDonorKeys,
+    args: RemoveDonorIxArgs,
+) -> std::io::Result<Instruction> {
+    remove_donor_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn remove_donor_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveDonorAccounts<'_, '_>,
+    args: RemoveDonorIxArgs,
+) -> ProgramResult {
+    let keys: RemoveDonorKeys = accounts.into();
+    let ix = remove_donor_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_donor_invoke(
+    accounts: RemoveDonorAccounts<'_, '_>,
+    args: RemoveDonorIxArgs,
+) -> ProgramResult {
+    remove_donor_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_donor_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveDonorAccounts<'_, '_>,
+    args: RemoveDonorIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveDonorKeys = accounts.into();
+    let ix = remove_donor_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_donor_invoke_signed(
+    accounts: RemoveDonorAccounts<'_, '_>,
+    args: RemoveDonorIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_donor_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn remove_donor_verify_account_keys(
+    accounts: RemoveDonorAccounts<'_, '_>,
+    keys: RemoveDonorKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+        (accounts.admin.key, &keys.admin),
+        (accounts.donor.key, &keys.donor),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.donor_allowlist.key, &keys.donor_allowlist),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_donor_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveDonorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.refund_rent_to, accounts.donor_allowlist] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_donor_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveDonorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_donor_verify_account_privileges<'me, 'info>(
+    accounts: RemoveDonorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_donor_verify_writable_privileges(accounts)?;
+    remove_donor_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LST_BATCH_CALC_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstBatchCalcAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Mint of the LST to set the BatchCalc flag of
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstBatchCalcKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Mint of the LST to set the BatchCalc flag of
+    pub lst_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<SetLstBatchCalcAccounts<'_, '_>> for SetLstBatchCalcKeys {
+    fn from(accounts: SetLstBatchCalcAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<SetLstBatchCalcKeys> for [AccountMeta; SET_LST_BATCH_CALC_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLstBatchCalcKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LST_BATCH_CALC_IX_ACCOUNTS_LEN]> for SetLstBatchCalcKeys {
+    fn from(pubkeys: [Pubkey; SET_LST_BATCH_CALC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetLstBatchCalcAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LST_BATCH_CALC_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLstBatchCalcAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LST_BATCH_CALC_IX_ACCOUNTS_LEN]>
+    for SetLstBatchCalcAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LST_BATCH_CALC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+        }
+    }
+}
+pub const SET_LST_BATCH_CALC_IX_DISCM: u8 = 26u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLstBatchCalcIxArgs {
+    pub index: u32,
+    pub is_batch_calc_enabled: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLstBatchCalcIxData(pub SetLstBatchCalcIxArgs);
+impl From<SetLstBatchCalcIxArgs> for SetLstBatchCalcIxData {
+    fn from(args: SetLstBatchCalcIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLstBatchCalcIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LST_BATCH_CALC_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LST_BATCH_CALC_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLstBatchCalcIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LST_BATCH_CALC_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lst_batch_calc_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLstBatchCalcKeys,
+    args: SetLstBatchCalcIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LST_BATCH_CALC_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLstBatchCalcIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lst_batch_calc_ix(
+    keys: SetLstBatchCalcKeys,
+    args: SetLstBatchCalcIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lst_batch_calc_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lst_batch_calc_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstBatchCalcAccounts<'_, '_>,
+    args: SetLstBatchCalcIxArgs,
+) -> ProgramResult {
+    let keys: SetLstBatchCalcKeys = accounts.into();
+    let ix = set_lst_batch_calc_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lst_batch_calc_invoke(
+    accounts: SetLstBatchCalcAccounts<'_, '_>,
+    args: SetLstBatchCalcIxArgs,
+) -> ProgramResult {
+    set_lst_batch_calc_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lst_batch_calc_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstBatchCalcAccounts<'_, '_>,
+    args: SetLstBatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLstBatchCalcKeys = accounts.into();
+    let ix = set_lst_batch_calc_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lst_batch_calc_invoke_signed(
+    accounts: SetLstBatchCalcAccounts<'_, '_>,
+    args: SetLstBatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lst_batch_calc_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lst_batch_calc_verify_account_keys(
+    accounts: SetLstBatchCalcAccounts<'_, '_>,
+    keys: SetLstBatchCalcKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_batch_calc_verify_writable_privileges<'me, 'info>(
+    accounts: SetLstBatchCalcAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool_state, accounts.lst_state_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_batch_calc_verify_signer_privileges<'me, 'info>(
+    accounts: SetLstBatchCalcAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_batch_calc_verify_account_privileges<'me, 'info>(
+    accounts: SetLstBatchCalcAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lst_batch_calc_verify_writable_privileges(accounts)?;
+    set_lst_batch_calc_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetMaxRebalanceLossAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Account paying for the rent of the RebalanceConfig PDA if uninitialized
+    pub payer: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The RebalanceConfig PDA. Created if uninitialized
+    pub rebalance_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetMaxRebalanceLossKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Account paying for the rent of the RebalanceConfig PDA if uninitialized
+    pub payer: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The RebalanceConfig PDA. Created if uninitialized
+    pub rebalance_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetMaxRebalanceLossAccounts<'_, '_>> for SetMaxRebalanceLossKeys {
+    fn from(accounts: SetMaxRebalanceLossAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            payer: *accounts.payer.key,
+            pool_state: *accounts.pool_state.key,
+            rebalance_config: *accounts.rebalance_config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetMaxRebalanceLossKeys> for [AccountMeta; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetMaxRebalanceLossKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN]> for SetMaxRebalanceLossKeys {
+    fn from(pubkeys: [Pubkey; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            payer: pubkeys[1],
+            pool_state: pubkeys[2],
+            rebalance_config: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetMaxRebalanceLossAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetMaxRebalanceLossAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.payer.clone(),
+            accounts.pool_state.clone(),
+            accounts.rebalance_config.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN]>
+    for SetMaxRebalanceLossAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            payer: &arr[1],
+            pool_state: &arr[2],
+            rebalance_config: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_MAX_REBALANCE_LOSS_IX_DISCM: u8 = 27u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetMaxRebalanceLossIxArgs {
+    pub max_rebalance_loss: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetMaxRebalanceLossIxData(pub SetMaxRebalanceLossIxArgs);
+impl From<SetMaxRebalanceLossIxArgs> for SetMaxRebalanceLossIxData {
+    fn from(args: SetMaxRebalanceLossIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetMaxRebalanceLossIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_MAX_REBALANCE_LOSS_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_MAX_REBALANCE_LOSS_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetMaxRebalanceLossIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_MAX_REBALANCE_LOSS_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_max_rebalance_loss_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetMaxRebalanceLossKeys,
+    args: SetMaxRebalanceLossIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetMaxRebalanceLossIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_max_rebalance_loss_ix(
+    keys: SetMaxRebalanceLossKeys,
+    args: SetMaxRebalanceLossIxArgs,
+) -> std::io::Result<Instruction> {
+    set_max_rebalance_loss_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_max_rebalance_loss_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetMaxRebalanceLossAccounts<'_, '_>,
+    args: SetMaxRebalanceLossIxArgs,
+) -> ProgramResult {
+    let keys: SetMaxRebalanceLossKeys = accounts.into();
+    let ix = set_max_rebalance_loss_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_max_rebalance_loss_invoke(
+    accounts: SetMaxRebalanceLossAccounts<'_, '_>,
+    args: SetMaxRebalanceLossIxArgs,
+) -> ProgramResult {
+    set_max_rebalance_loss_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_max_rebalance_loss_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetMaxRebalanceLossAccounts<'_, '_>,
+    args: SetMaxRebalanceLossIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetMaxRebalanceLossKeys = accounts.into();
+    let ix = set_max_rebalance_loss_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_max_rebalance_loss_invoke_signed(
+    accounts: SetMaxRebalanceLossAccounts<'_, '_>,
+    args: SetMaxRebalanceLossIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_max_rebalance_loss_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_max_rebalance_loss_verify_account_keys(
+    accounts: SetMaxRebalanceLossAccounts<'_, '_>,
+    keys: SetMaxRebalanceLossKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.rebalance_config.key, &keys.rebalance_config),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_max_rebalance_loss_verify_writable_privileges<'me, 'info>(
+    accounts: SetMaxRebalanceLossAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.rebalance_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_max_rebalance_loss_verify_signer_privileges<'me, 'info>(
+    accounts: SetMaxRebalanceLossAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_max_rebalance_loss_verify_account_privileges<'me, 'info>(
+    accounts: SetMaxRebalanceLossAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_max_rebalance_loss_verify_writable_privileges(accounts)?;
+    set_max_rebalance_loss_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const START_REBALANCE_V2_IX_ACCOUNTS_LEN: usize = 13;
+#[derive(Copy, Clone, Debug)]
+pub struct StartRebalanceV2Accounts<'me, 'info> {
+    ///The pool's rebalance authority
+    pub rebalance_authority: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each lst in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The RebalanceRecord PDA
+    pub rebalance_record: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: &'me AccountInfo<'info>,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: &'me AccountInfo<'info>,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: &'me AccountInfo<'info>,
+    ///Source LST token account to withdraw to
+    pub withdraw_to: &'me AccountInfo<'info>,
+    ///Instructions sysvar
+    pub instructions: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+    ///Source LST token program
+    pub src_lst_token_program: &'me AccountInfo<'info>,
+    ///The RebalanceConfig PDA. May be uninitialized
+    pub rebalance_config: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct StartRebalanceV2Keys {
+    ///The pool's rebalance authority
+    pub rebalance_authority: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each lst in the pool
+    pub lst_state_list: Pubkey,
+    ///The RebalanceRecord PDA
+    pub rebalance_record: Pubkey,
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: Pubkey,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: Pubkey,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: Pubkey,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: Pubkey,
+    ///Source LST token account to withdraw to
+    pub withdraw_to: Pubkey,
+    ///Instructions sysvar
+    pub instructions: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Source LST token program
+    pub src_lst_token_program: Pubkey,
+    ///The RebalanceConfig PDA. May be uninitialized
+    pub rebalance_config: Pubkey,
+}
+impl From<StartRebalanceV2Accounts<'_, '_>> for StartRebalanceV2Keys {
+    fn from(accounts: StartRebalanceV2Accounts) -> Self {
+        Self {
+            rebalance_authority: *accounts.rebalance_authority.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            rebalance_record: *accounts.rebalance_record.key,
+            src_lst_mint: *accounts.src_lst_mint.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            src_pool_reserves: *accounts.src_pool_reserves.key,
+            dst_pool_reserves: *accounts.dst_pool_reserves.key,
+            withdraw_to: *accounts.withdraw_to.key,
+            instructions: *accounts.instructions.key,
+            system_program: *accounts.system_program.key,
+            src_lst_token_program: *accounts.src_lst_token_program.key,
+            rebalance_config: *accounts.rebalance_config.key,
+        }
+    }
+}
+impl From<StartRebalanceV2Keys> for [AccountMeta; START_REBALANCE_V2_IX_ACCOUNTS_LEN] {
+    fn from(keys: StartRebalanceV2Keys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.rebalance_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.instructions,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_config,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; START_REBALANCE_V2_IX_ACCOUNTS_LEN]> for StartRebalanceV2Keys {
+    fn from(pubkeys: [Pubkey; START_REBALANCE_V2_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            rebalance_authority: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            rebalance_record: pubkeys[3],
+            src_lst_mint: pubkeys[4],
+            dst_lst_mint: pubkeys[5],
+            src_pool_reserves: pubkeys[6],
+            dst_pool_reserves: pubkeys[7],
+            withdraw_to: pubkeys[8],
+            instructions: pubkeys[9],
+            system_program: pubkeys[10],
+            src_lst_token_program: pubkeys[11],
+            rebalance_config: pubkeys[12],
+        }
+    }
+}
+impl<'info> From<StartRebalanceV2Accounts<'_, 'info>>
+    for [AccountInfo<'info>; START_REBALANCE_V2_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: StartRebalanceV2Accounts<'_, 'info>) -> Self {
+        [
+            accounts.rebalance_authority.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.rebalance_record.clone(),
+            accounts.src_lst_mint.clone(),
+            accounts.dst_lst_mint.clone(),
+            accounts.src_pool_reserves.clone(),
+            accounts.dst_pool_reserves.clone(),
+            accounts.withdraw_to.clone(),
+            accounts.instructions.clone(),
+            accounts.system_program.clone(),
+            accounts.src_lst_token_program.clone(),
+            accounts.rebalance_config.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; START_REBALANCE_V2_IX_ACCOUNTS_LEN]>
+    for StartRebalanceV2Accounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; START_REBALANCE_V2_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            rebalance_authority: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            rebalance_record: &arr[3],
+            src_lst_mint: &arr[4],
+            dst_lst_mint: &arr[5],
+            src_pool_reserves: &arr[6],
+            dst_pool_reserves: &arr[7],
+            withdraw_to: &arr[8],
+            instructions: &arr[9],
+            system_program: &arr[10],
+            src_lst_token_program: &arr[11],
+            rebalance_config: &arr[12],
+        }
+    }
+}
+pub const START_REBALANCE_V2_IX_DISCM: u8 = 28u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StartRebalanceV2IxArgs {
+    pub src_lst_calc_accs: u8,
+    pub dst_lst_calc_accs: u8,
+    pub src_lst_index: u32,
+    pub dst_lst_index: u32,
+    pub amount: u64,
+    pub min_starting_src_lst: u64,
+    pub max_starting_dst_lst: u64,
+    pub min_sol_value_delta: i64,
+    pub additional_dsts: Vec<AdditionalRebalanceDst>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct StartRebalanceV2IxData(pub StartRebalanceV2IxArgs);
+impl From<StartRebalanceV2IxArgs> for StartRebalanceV2IxData {
+    fn from(args: StartRebalanceV2IxArgs) -> Self {
+        Self(args)
+    }
+}
+impl StartRebalanceV2IxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != START_REBALANCE_V2_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    START_REBALANCE_V2_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(StartRebalanceV2IxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[START_REBALANCE_V2_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn start_rebalance_v2_ix_with_program_id(
+    program_id: Pubkey,
+    keys: StartRebalanceV2Keys,
+    args: StartRebalanceV2IxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; START_REBALANCE_V2_IX_ACCOUNTS_LEN] = keys.into();
+    let data: StartRebalanceV2IxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn start_rebalance_v2_ix(
+    keys: StartRebalanceV2Keys,
+    args: StartRebalanceV2IxArgs,
+) -> std::io::Result<Instruction> {
+    start_rebalance_v2_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn start_rebalance_v2_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: StartRebalanceV2Accounts<'_, '_>,
+    args: StartRebalanceV2IxArgs,
+) -> ProgramResult {
+    let keys: StartRebalanceV2Keys = accounts.into();
+    let ix = start_rebalance_v2_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn start_rebalance_v2_invoke(
+    accounts: StartRebalanceV2Accounts<'_, '_>,
+    args: StartRebalanceV2IxArgs,
+) -> ProgramResult {
+    start_rebalance_v2_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn start_rebalance_v2_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: StartRebalanceV2Accounts<'_, '_>,
+    args: StartRebalanceV2IxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: StartRebalanceV2Keys = accounts.into();
+    let ix = start_rebalance_v2_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn start_rebalance_v2_invoke_signed(
+    accounts: StartRebalanceV2Accounts<'_, '_>,
+    args: StartRebalanceV2IxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    start_rebalance_v2_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn start_rebalance_v2_verify_account_keys(
+    accounts: StartRebalanceV2Accounts<'_, '_>,
+    keys: StartRebalanceV2Keys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.rebalance_authority.key, &keys.rebalance_authority),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.rebalance_record.key, &keys.rebalance_record),
+        (accounts.src_lst_mint.key, &keys.src_lst_mint),
+        (accounts.dst_lst_mint.key, &keys.dst_lst_mint),
+        (accounts.src_pool_reserves.key, &keys.src_pool_reserves),
+        (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+        (accounts.withdraw_to.key, &keys.withdraw_to),
+        (accounts.instructions.key, &keys.instructions),
+        (accounts.system_program.key, &keys.system_program),
+        (
+            accounts.src_lst_token_program.key,
+            &keys.src_lst_token_program,
+        ),
+        (accounts.rebalance_config.key, &keys.rebalance_config),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn start_rebalance_v2_verify_writable_privileges<'me, 'info>(
+    accounts: StartRebalanceV2Accounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.rebalance_record,
+        accounts.src_pool_reserves,
+        accounts.dst_pool_reserves,
+        accounts.withdraw_to,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn start_rebalance_v2_verify_signer_privileges<'me, 'info>(
+    accounts: StartRebalanceV2Accounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.rebalance_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn start_rebalance_v2_verify_account_privileges<'me, 'info>(
+    accounts: StartRebalanceV2Accounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    start_rebalance_v2_verify_writable_privileges(accounts)?;
+    start_rebalance_v2_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const END_REBALANCE_V2_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct EndRebalanceV2Accounts<'me, 'info> {
+    ///The pool's rebalance authority
+    pub rebalance_authority: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each lst in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The RebalanceRecord PDA
+    pub rebalance_record: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: &'me AccountInfo<'info>,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct EndRebalanceV2Keys {
+    ///The pool's rebalance authority
+    pub rebalance_authority: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each lst in the pool
+    pub lst_state_list: Pubkey,
+    ///The RebalanceRecord PDA
+    pub rebalance_record: Pubkey,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: Pubkey,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: Pubkey,
+}
+impl From<EndRebalanceV2Accounts<'_, '_>> for EndRebalanceV2Keys {
+    fn from(accounts: EndRebalanceV2Accounts) -> Self {
+        Self {
+            rebalance_authority: *accounts.rebalance_authority.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            rebalance_record: *accounts.rebalance_record.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            dst_pool_reserves: *accounts.dst_pool_reserves.key,
+        }
+    }
+}
+impl From<EndRebalanceV2Keys> for [AccountMeta; END_REBALANCE_V2_IX_ACCOUNTS_LEN] {
+    fn from(keys: EndRebalanceV2Keys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.rebalance_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; END_REBALANCE_V2_IX_ACCOUNTS_LEN]> for EndRebalanceV2Keys {
+    fn from(pubkeys: [Pubkey; END_REBALANCE_V2_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            rebalance_authority: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            rebalance_record: pubkeys[3],
+            dst_lst_mint: pubkeys[4],
+            dst_pool_reserves: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<EndRebalanceV2Accounts<'_, 'info>>
+    for [AccountInfo<'info>; END_REBALANCE_V2_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: EndRebalanceV2Accounts<'_, 'info>) -> Self {
+        [
+            accounts.rebalance_authority.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.rebalance_record.clone(),
+            accounts.dst_lst_mint.clone(),
+            accounts.dst_pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; END_REBALANCE_V2_IX_ACCOUNTS_LEN]>
+    for EndRebalanceV2Accounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; END_REBALANCE_V2_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            rebalance_authority: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            rebalance_record: &arr[3],
+            dst_lst_mint: &arr[4],
+            dst_pool_reserves: &arr[5],
+        }
+    }
+}
+pub const END_REBALANCE_V2_IX_DISCM: u8 = 29u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EndRebalanceV2IxArgs {
+    pub dst_lst_calc_accs: u8,
+    pub additional_dst_lst_calc_accs: Vec<u8>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct EndRebalanceV2IxData(pub EndRebalanceV2IxArgs);
+impl From<EndRebalanceV2IxArgs> for EndRebalanceV2IxData {
+    fn from(args: EndRebalanceV2IxArgs) -> Self {
+        Self(args)
+    }
+}
+impl EndRebalanceV2IxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != END_REBALANCE_V2_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    END_REBALANCE_V2_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(EndRebalanceV2IxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[END_REBALANCE_V2_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn end_rebalance_v2_ix_with_program_id(
+    program_id: Pubkey,
+    keys: EndRebalanceV2Keys,
+    args: EndRebalanceV2IxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; END_REBALANCE_V2_IX_ACCOUNTS_LEN] = keys.into();
+    let data: EndRebalanceV2IxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn end_rebalance_v2_ix(
+    keys: EndRebalanceV2Keys,
+    args: EndRebalanceV2IxArgs,
+) -> std::io::Result<Instruction> {
+    end_rebalance_v2_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn end_rebalance_v2_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: EndRebalanceV2Accounts<'_, '_>,
+    args: EndRebalanceV2IxArgs,
+) -> ProgramResult {
+    let keys: EndRebalanceV2Keys = accounts.into();
+    let ix = end_rebalance_v2_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn end_rebalance_v2_invoke(
+    accounts: EndRebalanceV2Accounts<'_, '_>,
+    args: EndRebalanceV2IxArgs,
+) -> ProgramResult {
+    end_rebalance_v2_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn end_rebalance_v2_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: EndRebalanceV2Accounts<'_, '_>,
+    args: EndRebalanceV2IxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: EndRebalanceV2Keys = accounts.into();
+    let ix = end_rebalance_v2_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn end_rebalance_v2_invoke_signed(
+    accounts: EndRebalanceV2Accounts<'_, '_>,
+    args: EndRebalanceV2IxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    end_rebalance_v2_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn end_rebalance_v2_verify_account_keys(
+    accounts: EndRebalanceV2Accounts<'_, '_>,
+    keys: EndRebalanceV2Keys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.rebalance_authority.key, &keys.rebalance_authority),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.rebalance_record.key, &keys.rebalance_record),
+        (accounts.dst_lst_mint.key, &keys.dst_lst_mint),
+        (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn end_rebalance_v2_verify_writable_privileges<'me, 'info>(
+    accounts: EndRebalanceV2Accounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.rebalance_record,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn end_rebalance_v2_verify_signer_privileges<'me, 'info>(
+    accounts: EndRebalanceV2Accounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.rebalance_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn end_rebalance_v2_verify_account_privileges<'me, 'info>(
+    accounts: EndRebalanceV2Accounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    end_rebalance_v2_verify_writable_privileges(accounts)?;
+    end_rebalance_v2_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_PRICING_PROGRAM_V2_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetPricingProgramV2Accounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's new pricing program
+    pub new_pricing_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetPricingProgramV2Keys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's new pricing program
+    pub new_pricing_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+}
+impl From<SetPricingProgramV2Accounts<'_, '_>> for SetPricingProgramV2Keys {
+    fn from(accounts: SetPricingProgramV2Accounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            new_pricing_program: *accounts.new_pricing_program.key,
+            pool_state: *accounts.pool_state.key,
+        }
+    }
+}
+impl From<SetPricingProgramV2Keys> for [AccountMeta; SET_PRICING_PROGRAM_V2_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetPricingProgramV2Keys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_pricing_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_PRICING_PROGRAM_V2_IX_ACCOUNTS_LEN]> for SetPricingProgramV2Keys {
+    fn from(pubkeys: [Pubkey; SET_PRICING_PROGRAM_V2_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            new_pricing_program: pubkeys[1],
+            pool_state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetPricingProgramV2Accounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_PRICING_PROGRAM_V2_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetPricingProgramV2Accounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.new_pricing_program.clone(),
+            accounts.pool_state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PRICING_PROGRAM_V2_IX_ACCOUNTS_LEN]>
+    for SetPricingProgramV2Accounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_PRICING_PROGRAM_V2_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            new_pricing_program: &arr[1],
+            pool_state: &arr[2],
+        }
+    }
+}
+pub const SET_PRICING_PROGRAM_V2_IX_DISCM: u8 = 30u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPricingProgramV2IxArgs {
+    pub is_pool_context_enabled: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetPricingProgramV2IxData(pub SetPricingProgramV2IxArgs);
+impl From<SetPricingProgramV2IxArgs> for SetPricingProgramV2IxData {
+    fn from(args: SetPricingProgramV2IxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetPricingProgramV2IxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_PRICING_PROGRAM_V2_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_PRICING_PROGRAM_V2_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetPricingProgramV2IxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_PRICING_PROGRAM_V2_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_pricing_program_v2_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetPricingProgramV2Keys,
+    args: SetPricingProgramV2IxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_PRICING_PROGRAM_V2_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetPricingProgramV2IxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_pricing_program_v2_ix(
+    keys: SetPricingProgramV2Keys,
+    args: SetPricingProgramV2IxArgs,
+) -> std::io::Result<Instruction> {
+    set_pricing_program_v2_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_pricing_program_v2_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetPricingProgramV2Accounts<'_, '_>,
+    args: SetPricingProgramV2IxArgs,
+) -> ProgramResult {
+    let keys: SetPricingProgramV2Keys = accounts.into();
+    let ix = set_pricing_program_v2_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_pricing_program_v2_invoke(
+    accounts: SetPricingProgramV2Accounts<'_, '_>,
+    args: SetPricingProgramV2IxArgs,
+) -> ProgramResult {
+    set_pricing_program_v2_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_pricing_program_v2_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetPricingProgramV2Accounts<'_, '_>,
+    args: SetPricingProgramV2IxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetPricingProgramV2Keys = accounts.into();
+    let ix = set_pricing_program_v2_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_pricing_program_v2_invoke_signed(
+    accounts: SetPricingProgramV2Accounts<'_, '_>,
+    args: SetPricingProgramV2IxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_pricing_program_v2_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_pricing_program_v2_verify_account_keys(
+    accounts: SetPricingProgramV2Accounts<'_, '_>,
+    keys: SetPricingProgramV2Keys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.new_pricing_program.key, &keys.new_pricing_program),
+        (accounts.pool_state.key, &keys.pool_state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_pricing_program_v2_verify_writable_privileges<'me, 'info>(
+    accounts: SetPricingProgramV2Accounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool_state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_pricing_program_v2_verify_signer_privileges<'me, 'info>(
+    accounts: SetPricingProgramV2Accounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_pricing_program_v2_verify_account_privileges<'me, 'info>(
+    accounts: SetPricingProgramV2Accounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_pricing_program_v2_verify_writable_privileges(accounts)?;
+    set_pricing_program_v2_verify_signer_privileges(accounts)?;
+    Ok(())
+}
@@ -11,7 +11,7 @@ pub struct PoolState {
     pub version: u8,
     pub is_disabled: u8,
     pub is_rebalancing: u8,
-    pub padding: [u8; 1],
+    pub is_pricing_pool_context_enabled: u8,
     pub admin: Pubkey,
     pub rebalance_authority: Pubkey,
     pub protocol_fee_beneficiary: Pubkey,
@@ -25,7 +25,8 @@ pub struct LstState {
     pub is_input_disabled: u8,
     pub pool_reserves_bump: u8,
     pub protocol_fee_accumulator_bump: u8,
-    pub padding: [u8; 5],
+    pub is_batch_calc_enabled: u8,
+    pub padding: [u8; 4],
     pub sol_value: u64,
     pub mint: Pubkey,
     pub sol_value_calculator: Pubkey,
@@ -35,6 +36,21 @@ pub struct LstState {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RebalanceRecord {
     pub old_total_sol_value: u64,
-    pub padding: [u8; 4],
+    pub min_sol_value_delta: i64,
     pub dst_lst_index: u32,
+    pub additional_dst_lst_indexes: [u32; 3],
+    pub additional_dst_lst_count: u8,
+    pub padding: [u8; 7],
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RebalanceConfig {
+    pub max_rebalance_loss: u64,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdditionalRebalanceDst {
+    pub lst_index: u32,
+    pub lst_calc_accs: u8,
 }
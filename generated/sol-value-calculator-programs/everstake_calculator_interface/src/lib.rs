@@ -0,0 +1,7 @@
+#![allow(non_local_definitions)]
+
+solana_program::declare_id!("TH1S1DLoNLYCoNTA1NSERRoRANDCoP1EDTYPES11111");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod errors;
+pub use errors::*;
@@ -22,6 +22,12 @@ pub enum GenericPoolCalculatorError {
     InvalidStakePoolProgramData = 1006,
     #[error("Math error")]
     MathError = 1007,
+    #[error("stale discount must be less than 10000 bps")]
+    InvalidStaleDiscount = 1008,
+    #[error("CalculatorState does not have a legacy layout")]
+    StateNotMigratable = 1009,
+    #[error("stake pool program data does not match the approved hash")]
+    ProgramDataHashMismatch = 1010,
 }
 impl From<GenericPoolCalculatorError> for ProgramError {
     fn from(e: GenericPoolCalculatorError) -> Self {
@@ -12,6 +12,11 @@ use std::io::Read;
 pub enum GenericPoolCalculatorProgramIx {
     LstToSol(LstToSolIxArgs),
     SolToLst(SolToLstIxArgs),
+    BatchCalc(BatchCalcIxArgs),
+    PermissionlessUpdateLastUpgradeSlot,
+    SetApprovedProgramDataHash(SetApprovedProgramDataHashIxArgs),
+    MigrateState,
+    SetStaleDiscount(SetStaleDiscountIxArgs),
     UpdateLastUpgradeSlot,
     SetManager,
     Init,
@@ -25,6 +30,17 @@ impl GenericPoolCalculatorProgramIx {
         match maybe_discm {
             LST_TO_SOL_IX_DISCM => Ok(Self::LstToSol(LstToSolIxArgs::deserialize(&mut reader)?)),
             SOL_TO_LST_IX_DISCM => Ok(Self::SolToLst(SolToLstIxArgs::deserialize(&mut reader)?)),
+            BATCH_CALC_IX_DISCM => Ok(Self::BatchCalc(BatchCalcIxArgs::deserialize(&mut reader)?)),
+            PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_DISCM => {
+                Ok(Self::PermissionlessUpdateLastUpgradeSlot)
+            }
+            SET_APPROVED_PROGRAM_DATA_HASH_IX_DISCM => Ok(Self::SetApprovedProgramDataHash(
+                SetApprovedProgramDataHashIxArgs::deserialize(&mut reader)?,
+            )),
+            MIGRATE_STATE_IX_DISCM => Ok(Self::MigrateState),
+            SET_STALE_DISCOUNT_IX_DISCM => Ok(Self::SetStaleDiscount(
+                SetStaleDiscountIxArgs::deserialize(&mut reader)?,
+            )),
             UPDATE_LAST_UPGRADE_SLOT_IX_DISCM => Ok(Self::UpdateLastUpgradeSlot),
             SET_MANAGER_IX_DISCM => Ok(Self::SetManager),
             INIT_IX_DISCM => Ok(Self::Init),
@@ -44,6 +60,22 @@ impl GenericPoolCalculatorProgramIx {
                 writer.write_all(&[SOL_TO_LST_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
+            Self::BatchCalc(args) => {
+                writer.write_all(&[BATCH_CALC_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::PermissionlessUpdateLastUpgradeSlot => {
+                writer.write_all(&[PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_DISCM])
+            }
+            Self::SetApprovedProgramDataHash(args) => {
+                writer.write_all(&[SET_APPROVED_PROGRAM_DATA_HASH_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::MigrateState => writer.write_all(&[MIGRATE_STATE_IX_DISCM]),
+            Self::SetStaleDiscount(args) => {
+                writer.write_all(&[SET_STALE_DISCOUNT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
             Self::UpdateLastUpgradeSlot => writer.write_all(&[UPDATE_LAST_UPGRADE_SLOT_IX_DISCM]),
             Self::SetManager => writer.write_all(&[SET_MANAGER_IX_DISCM]),
             Self::Init => writer.write_all(&[INIT_IX_DISCM]),
@@ -488,6 +520,1006 @@ pub fn sol_to_lst_verify_account_keys(
     }
     Ok(())
 }
+pub const BATCH_CALC_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct BatchCalcAccounts<'me, 'info> {
+    ///The LST mint
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The CalculatorState PDA
+    pub state: &'me AccountInfo<'info>,
+    ///The main stake pool state account
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The stake pool program
+    pub pool_program: &'me AccountInfo<'info>,
+    ///The stake pool program executable data
+    pub pool_program_data: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct BatchCalcKeys {
+    ///The LST mint
+    pub lst_mint: Pubkey,
+    ///The CalculatorState PDA
+    pub state: Pubkey,
+    ///The main stake pool state account
+    pub pool_state: Pubkey,
+    ///The stake pool program
+    pub pool_program: Pubkey,
+    ///The stake pool program executable data
+    pub pool_program_data: Pubkey,
+}
+impl From<BatchCalcAccounts<'_, '_>> for BatchCalcKeys {
+    fn from(accounts: BatchCalcAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            state: *accounts.state.key,
+            pool_state: *accounts.pool_state.key,
+            pool_program: *accounts.pool_program.key,
+            pool_program_data: *accounts.pool_program_data.key,
+        }
+    }
+}
+impl From<BatchCalcKeys> for [AccountMeta; BATCH_CALC_IX_ACCOUNTS_LEN] {
+    fn from(keys: BatchCalcKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_program_data,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; BATCH_CALC_IX_ACCOUNTS_LEN]> for BatchCalcKeys {
+    fn from(pubkeys: [Pubkey; BATCH_CALC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            state: pubkeys[1],
+            pool_state: pubkeys[2],
+            pool_program: pubkeys[3],
+            pool_program_data: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<BatchCalcAccounts<'_, 'info>>
+    for [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: BatchCalcAccounts<'_, 'info>) -> Self {
+        [
+            accounts.lst_mint.clone(),
+            accounts.state.clone(),
+            accounts.pool_state.clone(),
+            accounts.pool_program.clone(),
+            accounts.pool_program_data.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]>
+    for BatchCalcAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            state: &arr[1],
+            pool_state: &arr[2],
+            pool_program: &arr[3],
+            pool_program_data: &arr[4],
+        }
+    }
+}
+pub const BATCH_CALC_IX_DISCM: u8 = 2u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchCalcIxArgs {
+    pub lst_to_sol_amounts: Vec<u64>,
+    pub sol_to_lst_amounts: Vec<u64>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchCalcIxData(pub BatchCalcIxArgs);
+impl From<BatchCalcIxArgs> for BatchCalcIxData {
+    fn from(args: BatchCalcIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BatchCalcIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != BATCH_CALC_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    BATCH_CALC_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(BatchCalcIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[BATCH_CALC_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn batch_calc_ix_with_program_id(
+    program_id: Pubkey,
+    keys: BatchCalcKeys,
+    args: BatchCalcIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; BATCH_CALC_IX_ACCOUNTS_LEN] = keys.into();
+    let data: BatchCalcIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn batch_calc_ix(keys: BatchCalcKeys, args: BatchCalcIxArgs) -> std::io::Result<Instruction> {
+    batch_calc_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn batch_calc_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+) -> ProgramResult {
+    let keys: BatchCalcKeys = accounts.into();
+    let ix = batch_calc_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn batch_calc_invoke(
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+) -> ProgramResult {
+    batch_calc_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn batch_calc_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: BatchCalcKeys = accounts.into();
+    let ix = batch_calc_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn batch_calc_invoke_signed(
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    batch_calc_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn batch_calc_verify_account_keys(
+    accounts: BatchCalcAccounts<'_, '_>,
+    keys: BatchCalcKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.state.key, &keys.state),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pool_program.key, &keys.pool_program),
+        (accounts.pool_program_data.key, &keys.pool_program_data),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub const PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct PermissionlessUpdateLastUpgradeSlotAccounts<'me, 'info> {
+    ///The CalculatorState PDA
+    pub state: &'me AccountInfo<'info>,
+    ///The stake pool program
+    pub pool_program: &'me AccountInfo<'info>,
+    ///The stake pool program executable data
+    pub pool_program_data: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct PermissionlessUpdateLastUpgradeSlotKeys {
+    ///The CalculatorState PDA
+    pub state: Pubkey,
+    ///The stake pool program
+    pub pool_program: Pubkey,
+    ///The stake pool program executable data
+    pub pool_program_data: Pubkey,
+}
+impl From<PermissionlessUpdateLastUpgradeSlotAccounts<'_, '_>>
+    for PermissionlessUpdateLastUpgradeSlotKeys
+{
+    fn from(accounts: PermissionlessUpdateLastUpgradeSlotAccounts) -> Self {
+        Self {
+            state: *accounts.state.key,
+            pool_program: *accounts.pool_program.key,
+            pool_program_data: *accounts.pool_program_data.key,
+        }
+    }
+}
+impl From<PermissionlessUpdateLastUpgradeSlotKeys>
+    for [AccountMeta; PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: PermissionlessUpdateLastUpgradeSlotKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_program_data,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_ACCOUNTS_LEN]>
+    for PermissionlessUpdateLastUpgradeSlotKeys
+{
+    fn from(pubkeys: [Pubkey; PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            state: pubkeys[0],
+            pool_program: pubkeys[1],
+            pool_program_data: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<PermissionlessUpdateLastUpgradeSlotAccounts<'_, 'info>>
+    for [AccountInfo<'info>; PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: PermissionlessUpdateLastUpgradeSlotAccounts<'_, 'info>) -> Self {
+        [
+            accounts.state.clone(),
+            accounts.pool_program.clone(),
+            accounts.pool_program_data.clone(),
+        ]
+    }
+}
+impl<'me, 'info>
+    From<&'me [AccountInfo<'info>; PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_ACCOUNTS_LEN]>
+    for PermissionlessUpdateLastUpgradeSlotAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            state: &arr[0],
+            pool_program: &arr[1],
+            pool_program_data: &arr[2],
+        }
+    }
+}
+pub const PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_DISCM: u8 = 249u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct PermissionlessUpdateLastUpgradeSlotIxData;
+impl PermissionlessUpdateLastUpgradeSlotIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn permissionless_update_last_upgrade_slot_ix_with_program_id(
+    program_id: Pubkey,
+    keys: PermissionlessUpdateLastUpgradeSlotKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; PERMISSIONLESS_UPDATE_LAST_UPGRADE_SLOT_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: PermissionlessUpdateLastUpgradeSlotIxData.try_to_vec()?,
+    })
+}
+pub fn permissionless_update_last_upgrade_slot_ix(
+    keys: PermissionlessUpdateLastUpgradeSlotKeys,
+) -> std::io::Result<Instruction> {
+    permissionless_update_last_upgrade_slot_ix_with_program_id(crate::ID, keys)
+}
+pub fn permissionless_update_last_upgrade_slot_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: PermissionlessUpdateLastUpgradeSlotAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: PermissionlessUpdateLastUpgradeSlotKeys = accounts.into();
+    let ix = permissionless_update_last_upgrade_slot_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn permissionless_update_last_upgrade_slot_invoke(
+    accounts: PermissionlessUpdateLastUpgradeSlotAccounts<'_, '_>,
+) -> ProgramResult {
+    permissionless_update_last_upgrade_slot_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn permissionless_update_last_upgrade_slot_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: PermissionlessUpdateLastUpgradeSlotAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: PermissionlessUpdateLastUpgradeSlotKeys = accounts.into();
+    let ix = permissionless_update_last_upgrade_slot_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn permissionless_update_last_upgrade_slot_invoke_signed(
+    accounts: PermissionlessUpdateLastUpgradeSlotAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    permissionless_update_last_upgrade_slot_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        seeds,
+    )
+}
+pub fn permissionless_update_last_upgrade_slot_verify_account_keys(
+    accounts: PermissionlessUpdateLastUpgradeSlotAccounts<'_, '_>,
+    keys: PermissionlessUpdateLastUpgradeSlotKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.state.key, &keys.state),
+        (accounts.pool_program.key, &keys.pool_program),
+        (accounts.pool_program_data.key, &keys.pool_program_data),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn permissionless_update_last_upgrade_slot_verify_writable_privileges<'me, 'info>(
+    accounts: PermissionlessUpdateLastUpgradeSlotAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn permissionless_update_last_upgrade_slot_verify_account_privileges<'me, 'info>(
+    accounts: PermissionlessUpdateLastUpgradeSlotAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    permissionless_update_last_upgrade_slot_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_APPROVED_PROGRAM_DATA_HASH_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetApprovedProgramDataHashAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///The CalculatorState PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetApprovedProgramDataHashKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///The CalculatorState PDA
+    pub state: Pubkey,
+}
+impl From<SetApprovedProgramDataHashAccounts<'_, '_>> for SetApprovedProgramDataHashKeys {
+    fn from(accounts: SetApprovedProgramDataHashAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetApprovedProgramDataHashKeys>
+    for [AccountMeta; SET_APPROVED_PROGRAM_DATA_HASH_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: SetApprovedProgramDataHashKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_APPROVED_PROGRAM_DATA_HASH_IX_ACCOUNTS_LEN]>
+    for SetApprovedProgramDataHashKeys
+{
+    fn from(pubkeys: [Pubkey; SET_APPROVED_PROGRAM_DATA_HASH_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            state: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetApprovedProgramDataHashAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_APPROVED_PROGRAM_DATA_HASH_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetApprovedProgramDataHashAccounts<'_, 'info>) -> Self {
+        [accounts.manager.clone(), accounts.state.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_APPROVED_PROGRAM_DATA_HASH_IX_ACCOUNTS_LEN]>
+    for SetApprovedProgramDataHashAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; SET_APPROVED_PROGRAM_DATA_HASH_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            manager: &arr[0],
+            state: &arr[1],
+        }
+    }
+}
+pub const SET_APPROVED_PROGRAM_DATA_HASH_IX_DISCM: u8 = 250u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetApprovedProgramDataHashIxArgs {
+    pub approved_program_data_hash: [u8; 32],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetApprovedProgramDataHashIxData(pub SetApprovedProgramDataHashIxArgs);
+impl From<SetApprovedProgramDataHashIxArgs> for SetApprovedProgramDataHashIxData {
+    fn from(args: SetApprovedProgramDataHashIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetApprovedProgramDataHashIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_APPROVED_PROGRAM_DATA_HASH_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_APPROVED_PROGRAM_DATA_HASH_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetApprovedProgramDataHashIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_APPROVED_PROGRAM_DATA_HASH_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_approved_program_data_hash_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetApprovedProgramDataHashKeys,
+    args: SetApprovedProgramDataHashIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_APPROVED_PROGRAM_DATA_HASH_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetApprovedProgramDataHashIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_approved_program_data_hash_ix(
+    keys: SetApprovedProgramDataHashKeys,
+    args: SetApprovedProgramDataHashIxArgs,
+) -> std::io::Result<Instruction> {
+    set_approved_program_data_hash_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_approved_program_data_hash_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetApprovedProgramDataHashAccounts<'_, '_>,
+    args: SetApprovedProgramDataHashIxArgs,
+) -> ProgramResult {
+    let keys: SetApprovedProgramDataHashKeys = accounts.into();
+    let ix = set_approved_program_data_hash_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_approved_program_data_hash_invoke(
+    accounts: SetApprovedProgramDataHashAccounts<'_, '_>,
+    args: SetApprovedProgramDataHashIxArgs,
+) -> ProgramResult {
+    set_approved_program_data_hash_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_approved_program_data_hash_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetApprovedProgramDataHashAccounts<'_, '_>,
+    args: SetApprovedProgramDataHashIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetApprovedProgramDataHashKeys = accounts.into();
+    let ix = set_approved_program_data_hash_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_approved_program_data_hash_invoke_signed(
+    accounts: SetApprovedProgramDataHashAccounts<'_, '_>,
+    args: SetApprovedProgramDataHashIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_approved_program_data_hash_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_approved_program_data_hash_verify_account_keys(
+    accounts: SetApprovedProgramDataHashAccounts<'_, '_>,
+    keys: SetApprovedProgramDataHashKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_approved_program_data_hash_verify_writable_privileges<'me, 'info>(
+    accounts: SetApprovedProgramDataHashAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_approved_program_data_hash_verify_signer_privileges<'me, 'info>(
+    accounts: SetApprovedProgramDataHashAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_approved_program_data_hash_verify_account_privileges<'me, 'info>(
+    accounts: SetApprovedProgramDataHashAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_approved_program_data_hash_verify_writable_privileges(accounts)?;
+    set_approved_program_data_hash_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const MIGRATE_STATE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateStateAccounts<'me, 'info> {
+    ///Account paying for the additional rent of the migrated CalculatorState
+    pub payer: &'me AccountInfo<'info>,
+    ///The CalculatorState PDA with the legacy layout to migrate
+    pub state: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateStateKeys {
+    ///Account paying for the additional rent of the migrated CalculatorState
+    pub payer: Pubkey,
+    ///The CalculatorState PDA with the legacy layout to migrate
+    pub state: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<MigrateStateAccounts<'_, '_>> for MigrateStateKeys {
+    fn from(accounts: MigrateStateAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            state: *accounts.state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<MigrateStateKeys> for [AccountMeta; MIGRATE_STATE_IX_ACCOUNTS_LEN] {
+    fn from(keys: MigrateStateKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MIGRATE_STATE_IX_ACCOUNTS_LEN]> for MigrateStateKeys {
+    fn from(pubkeys: [Pubkey; MIGRATE_STATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            state: pubkeys[1],
+            system_program: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<MigrateStateAccounts<'_, 'info>>
+    for [AccountInfo<'info>; MIGRATE_STATE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: MigrateStateAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; MIGRATE_STATE_IX_ACCOUNTS_LEN]>
+    for MigrateStateAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; MIGRATE_STATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            state: &arr[1],
+            system_program: &arr[2],
+        }
+    }
+}
+pub const MIGRATE_STATE_IX_DISCM: u8 = 251u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrateStateIxData;
+impl MigrateStateIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != MIGRATE_STATE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    MIGRATE_STATE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[MIGRATE_STATE_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn migrate_state_ix_with_program_id(
+    program_id: Pubkey,
+    keys: MigrateStateKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; MIGRATE_STATE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: MigrateStateIxData.try_to_vec()?,
+    })
+}
+pub fn migrate_state_ix(keys: MigrateStateKeys) -> std::io::Result<Instruction> {
+    migrate_state_ix_with_program_id(crate::ID, keys)
+}
+pub fn migrate_state_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateStateAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: MigrateStateKeys = accounts.into();
+    let ix = migrate_state_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn migrate_state_invoke(accounts: MigrateStateAccounts<'_, '_>) -> ProgramResult {
+    migrate_state_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn migrate_state_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateStateAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: MigrateStateKeys = accounts.into();
+    let ix = migrate_state_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn migrate_state_invoke_signed(
+    accounts: MigrateStateAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    migrate_state_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn migrate_state_verify_account_keys(
+    accounts: MigrateStateAccounts<'_, '_>,
+    keys: MigrateStateKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.state.key, &keys.state),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_state_verify_writable_privileges<'me, 'info>(
+    accounts: MigrateStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_state_verify_signer_privileges<'me, 'info>(
+    accounts: MigrateStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_state_verify_account_privileges<'me, 'info>(
+    accounts: MigrateStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    migrate_state_verify_writable_privileges(accounts)?;
+    migrate_state_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_STALE_DISCOUNT_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetStaleDiscountAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///The CalculatorState PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetStaleDiscountKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///The CalculatorState PDA
+    pub state: Pubkey,
+}
+impl From<SetStaleDiscountAccounts<'_, '_>> for SetStaleDiscountKeys {
+    fn from(accounts: SetStaleDiscountAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetStaleDiscountKeys> for [AccountMeta; SET_STALE_DISCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetStaleDiscountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_STALE_DISCOUNT_IX_ACCOUNTS_LEN]> for SetStaleDiscountKeys {
+    fn from(pubkeys: [Pubkey; SET_STALE_DISCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            state: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetStaleDiscountAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_STALE_DISCOUNT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetStaleDiscountAccounts<'_, 'info>) -> Self {
+        [accounts.manager.clone(), accounts.state.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_STALE_DISCOUNT_IX_ACCOUNTS_LEN]>
+    for SetStaleDiscountAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_STALE_DISCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            state: &arr[1],
+        }
+    }
+}
+pub const SET_STALE_DISCOUNT_IX_DISCM: u8 = 252u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetStaleDiscountIxArgs {
+    pub stale_discount_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetStaleDiscountIxData(pub SetStaleDiscountIxArgs);
+impl From<SetStaleDiscountIxArgs> for SetStaleDiscountIxData {
+    fn from(args: SetStaleDiscountIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetStaleDiscountIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_STALE_DISCOUNT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_STALE_DISCOUNT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetStaleDiscountIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_STALE_DISCOUNT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_stale_discount_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetStaleDiscountKeys,
+    args: SetStaleDiscountIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_STALE_DISCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetStaleDiscountIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_stale_discount_ix(
+    keys: SetStaleDiscountKeys,
+    args: SetStaleDiscountIxArgs,
+) -> std::io::Result<Instruction> {
+    set_stale_discount_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_stale_discount_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetStaleDiscountAccounts<'_, '_>,
+    args: SetStaleDiscountIxArgs,
+) -> ProgramResult {
+    let keys: SetStaleDiscountKeys = accounts.into();
+    let ix = set_stale_discount_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_stale_discount_invoke(
+    accounts: SetStaleDiscountAccounts<'_, '_>,
+    args: SetStaleDiscountIxArgs,
+) -> ProgramResult {
+    set_stale_discount_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_stale_discount_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetStaleDiscountAccounts<'_, '_>,
+    args: SetStaleDiscountIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetStaleDiscountKeys = accounts.into();
+    let ix = set_stale_discount_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_stale_discount_invoke_signed(
+    accounts: SetStaleDiscountAccounts<'_, '_>,
+    args: SetStaleDiscountIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_stale_discount_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_stale_discount_verify_account_keys(
+    accounts: SetStaleDiscountAccounts<'_, '_>,
+    keys: SetStaleDiscountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_stale_discount_verify_writable_privileges<'me, 'info>(
+    accounts: SetStaleDiscountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_stale_discount_verify_signer_privileges<'me, 'info>(
+    accounts: SetStaleDiscountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_stale_discount_verify_account_privileges<'me, 'info>(
+    accounts: SetStaleDiscountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_stale_discount_verify_writable_privileges(accounts)?;
+    set_stale_discount_verify_signer_privileges(accounts)?;
+    Ok(())
+}
 pub const UPDATE_LAST_UPGRADE_SLOT_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct UpdateLastUpgradeSlotAccounts<'me, 'info> {
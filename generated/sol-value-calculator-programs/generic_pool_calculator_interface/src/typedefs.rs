@@ -7,4 +7,7 @@ use solana_program::pubkey::Pubkey;
 pub struct CalculatorState {
     pub manager: Pubkey,
     pub last_upgrade_slot: u64,
+    pub stale_discount_bps: u16,
+    pub padding: [u8; 6],
+    pub approved_program_data_hash: [u8; 32],
 }
@@ -10,6 +10,8 @@ pub enum MarinadeCalculatorError {
     MarinadePaused = 0,
     #[error("Marinade currently has stake withdrawal disabled")]
     MarinadeStakeWithdrawDisabled = 1,
+    #[error("Incorrect calculator config account")]
+    IncorrectCalcConfig = 2,
 }
 impl From<MarinadeCalculatorError> for ProgramError {
     fn from(e: MarinadeCalculatorError) -> Self {
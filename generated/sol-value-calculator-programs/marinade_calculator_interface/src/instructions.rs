@@ -0,0 +1,306 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarinadeCalculatorProgramIx {
+    SetDelayedUnstakeFallback(SetDelayedUnstakeFallbackIxArgs),
+}
+impl MarinadeCalculatorProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        match maybe_discm {
+            SET_DELAYED_UNSTAKE_FALLBACK_IX_DISCM => Ok(Self::SetDelayedUnstakeFallback(
+                SetDelayedUnstakeFallbackIxArgs::deserialize(&mut reader)?,
+            )),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::SetDelayedUnstakeFallback(args) => {
+                writer.write_all(&[SET_DELAYED_UNSTAKE_FALLBACK_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const SET_DELAYED_UNSTAKE_FALLBACK_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetDelayedUnstakeFallbackAccounts<'me, 'info> {
+    ///Account paying for MarinadeCalcConfig's rent if it is not yet initialized
+    pub payer: &'me AccountInfo<'info>,
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///The CalculatorState PDA
+    pub state: &'me AccountInfo<'info>,
+    ///The MarinadeCalcConfig PDA
+    pub calc_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetDelayedUnstakeFallbackKeys {
+    ///Account paying for MarinadeCalcConfig's rent if it is not yet initialized
+    pub payer: Pubkey,
+    ///The program manager
+    pub manager: Pubkey,
+    ///The CalculatorState PDA
+    pub state: Pubkey,
+    ///The MarinadeCalcConfig PDA
+    pub calc_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetDelayedUnstakeFallbackAccounts<'_, '_>> for SetDelayedUnstakeFallbackKeys {
+    fn from(accounts: SetDelayedUnstakeFallbackAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            manager: *accounts.manager.key,
+            state: *accounts.state.key,
+            calc_config: *accounts.calc_config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetDelayedUnstakeFallbackKeys>
+    for [AccountMeta; SET_DELAYED_UNSTAKE_FALLBACK_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: SetDelayedUnstakeFallbackKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.calc_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_DELAYED_UNSTAKE_FALLBACK_IX_ACCOUNTS_LEN]>
+    for SetDelayedUnstakeFallbackKeys
+{
+    fn from(pubkeys: [Pubkey; SET_DELAYED_UNSTAKE_FALLBACK_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            manager: pubkeys[1],
+            state: pubkeys[2],
+            calc_config: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetDelayedUnstakeFallbackAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_DELAYED_UNSTAKE_FALLBACK_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetDelayedUnstakeFallbackAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.manager.clone(),
+            accounts.state.clone(),
+            accounts.calc_config.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_DELAYED_UNSTAKE_FALLBACK_IX_ACCOUNTS_LEN]>
+    for SetDelayedUnstakeFallbackAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_DELAYED_UNSTAKE_FALLBACK_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            manager: &arr[1],
+            state: &arr[2],
+            calc_config: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_DELAYED_UNSTAKE_FALLBACK_IX_DISCM: u8 = 248u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetDelayedUnstakeFallbackIxArgs {
+    pub enabled: bool,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetDelayedUnstakeFallbackIxData(pub SetDelayedUnstakeFallbackIxArgs);
+impl From<SetDelayedUnstakeFallbackIxArgs> for SetDelayedUnstakeFallbackIxData {
+    fn from(args: SetDelayedUnstakeFallbackIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetDelayedUnstakeFallbackIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_DELAYED_UNSTAKE_FALLBACK_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_DELAYED_UNSTAKE_FALLBACK_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetDelayedUnstakeFallbackIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_DELAYED_UNSTAKE_FALLBACK_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_delayed_unstake_fallback_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetDelayedUnstakeFallbackKeys,
+    args: SetDelayedUnstakeFallbackIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_DELAYED_UNSTAKE_FALLBACK_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetDelayedUnstakeFallbackIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_delayed_unstake_fallback_ix(
+    keys: SetDelayedUnstakeFallbackKeys,
+    args: SetDelayedUnstakeFallbackIxArgs,
+) -> std::io::Result<Instruction> {
+    set_delayed_unstake_fallback_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_delayed_unstake_fallback_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetDelayedUnstakeFallbackAccounts<'_, '_>,
+    args: SetDelayedUnstakeFallbackIxArgs,
+) -> ProgramResult {
+    let keys: SetDelayedUnstakeFallbackKeys = accounts.into();
+    let ix = set_delayed_unstake_fallback_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_delayed_unstake_fallback_invoke(
+    accounts: SetDelayedUnstakeFallbackAccounts<'_, '_>,
+    args: SetDelayedUnstakeFallbackIxArgs,
+) -> ProgramResult {
+    set_delayed_unstake_fallback_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_delayed_unstake_fallback_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetDelayedUnstakeFallbackAccounts<'_, '_>,
+    args: SetDelayedUnstakeFallbackIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetDelayedUnstakeFallbackKeys = accounts.into();
+    let ix = set_delayed_unstake_fallback_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_delayed_unstake_fallback_invoke_signed(
+    accounts: SetDelayedUnstakeFallbackAccounts<'_, '_>,
+    args: SetDelayedUnstakeFallbackIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_delayed_unstake_fallback_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_delayed_unstake_fallback_verify_account_keys(
+    accounts: SetDelayedUnstakeFallbackAccounts<'_, '_>,
+    keys: SetDelayedUnstakeFallbackKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.manager.key, &keys.manager),
+        (accounts.state.key, &keys.state),
+        (accounts.calc_config.key, &keys.calc_config),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_delayed_unstake_fallback_verify_writable_privileges<'me, 'info>(
+    accounts: SetDelayedUnstakeFallbackAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.calc_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_delayed_unstake_fallback_verify_signer_privileges<'me, 'info>(
+    accounts: SetDelayedUnstakeFallbackAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_delayed_unstake_fallback_verify_account_privileges<'me, 'info>(
+    accounts: SetDelayedUnstakeFallbackAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_delayed_unstake_fallback_verify_writable_privileges(accounts)?;
+    set_delayed_unstake_fallback_verify_signer_privileges(accounts)?;
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum PostedRateCalculatorError {
+    #[error("Invalid program state data")]
+    InvalidProgramStateData = 0,
+    #[error("Incorrect program state account")]
+    IncorrectProgramState = 1,
+    #[error("RateAccount is not initialized for the given LST mint")]
+    UnsupportedLstMint = 2,
+    #[error("Rate numerator and denominator must be nonzero")]
+    InvalidRate = 3,
+    #[error("Rate change exceeds the max rate change per update")]
+    RateChangeExceedsMax = 4,
+    #[error("Rate cannot decrease in monotonic mode")]
+    RateDecreased = 5,
+    #[error("Rate has not been updated within the max staleness limit")]
+    StaleRate = 6,
+    #[error("Math error")]
+    MathError = 7,
+}
+impl From<PostedRateCalculatorError> for ProgramError {
+    fn from(e: PostedRateCalculatorError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for PostedRateCalculatorError {
+    fn type_of() -> &'static str {
+        "PostedRateCalculatorError"
+    }
+}
+impl PrintProgramError for PostedRateCalculatorError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
@@ -0,0 +1,1866 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum PostedRateCalculatorProgramIx {
+    LstToSol(LstToSolIxArgs),
+    SolToLst(SolToLstIxArgs),
+    BatchCalc(BatchCalcIxArgs),
+    SetRateGuardrails(SetRateGuardrailsIxArgs),
+    SetRate(SetRateIxArgs),
+    RemoveLst,
+    AddLst(AddLstIxArgs),
+    SetManager,
+    Initialize,
+}
+impl PostedRateCalculatorProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        match maybe_discm {
+            LST_TO_SOL_IX_DISCM => Ok(Self::LstToSol(LstToSolIxArgs::deserialize(&mut reader)?)),
+            SOL_TO_LST_IX_DISCM => Ok(Self::SolToLst(SolToLstIxArgs::deserialize(&mut reader)?)),
+            BATCH_CALC_IX_DISCM => Ok(Self::BatchCalc(BatchCalcIxArgs::deserialize(&mut reader)?)),
+            SET_RATE_GUARDRAILS_IX_DISCM => Ok(Self::SetRateGuardrails(
+                SetRateGuardrailsIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_RATE_IX_DISCM => Ok(Self::SetRate(SetRateIxArgs::deserialize(&mut reader)?)),
+            REMOVE_LST_IX_DISCM => Ok(Self::RemoveLst),
+            ADD_LST_IX_DISCM => Ok(Self::AddLst(AddLstIxArgs::deserialize(&mut reader)?)),
+            SET_MANAGER_IX_DISCM => Ok(Self::SetManager),
+            INITIALIZE_IX_DISCM => Ok(Self::Initialize),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::LstToSol(args) => {
+                writer.write_all(&[LST_TO_SOL_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SolToLst(args) => {
+                writer.write_all(&[SOL_TO_LST_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::BatchCalc(args) => {
+                writer.write_all(&[BATCH_CALC_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetRateGuardrails(args) => {
+                writer.write_all(&[SET_RATE_GUARDRAILS_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetRate(args) => {
+                writer.write_all(&[SET_RATE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveLst => writer.write_all(&[REMOVE_LST_IX_DISCM]),
+            Self::AddLst(args) => {
+                writer.write_all(&[ADD_LST_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetManager => writer.write_all(&[SET_MANAGER_IX_DISCM]),
+            Self::Initialize => writer.write_all(&[INITIALIZE_IX_DISCM]),
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const LST_TO_SOL_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct LstToSolAccounts<'me, 'info> {
+    ///The LST mint
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The RateAccount PDA of the LST mint
+    pub rate_acc: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct LstToSolKeys {
+    ///The LST mint
+    pub lst_mint: Pubkey,
+    ///The RateAccount PDA of the LST mint
+    pub rate_acc: Pubkey,
+}
+impl From<LstToSolAccounts<'_, '_>> for LstToSolKeys {
+    fn from(accounts: LstToSolAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            rate_acc: *accounts.rate_acc.key,
+        }
+    }
+}
+impl From<LstToSolKeys> for [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: LstToSolKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rate_acc,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; LST_TO_SOL_IX_ACCOUNTS_LEN]> for LstToSolKeys {
+    fn from(pubkeys: [Pubkey; LST_TO_SOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            rate_acc: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<LstToSolAccounts<'_, 'info>> for [AccountInfo<'info>; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+    fn from(accounts: LstToSolAccounts<'_, 'info>) -> Self {
+        [accounts.lst_mint.clone(), accounts.rate_acc.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; LST_TO_SOL_IX_ACCOUNTS_LEN]>
+    for LstToSolAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; LST_TO_SOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            rate_acc: &arr[1],
+        }
+    }
+}
+pub const LST_TO_SOL_IX_DISCM: u8 = 0u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LstToSolIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct LstToSolIxData(pub LstToSolIxArgs);
+impl From<LstToSolIxArgs> for LstToSolIxData {
+    fn from(args: LstToSolIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl LstToSolIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != LST_TO_SOL_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    LST_TO_SOL_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(LstToSolIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[LST_TO_SOL_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn lst_to_sol_ix_with_program_id(
+    program_id: Pubkey,
+    keys: LstToSolKeys,
+    args: LstToSolIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] = keys.into();
+    let data: LstToSolIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn lst_to_sol_ix(keys: LstToSolKeys, args: LstToSolIxArgs) -> std::io::Result<Instruction> {
+    lst_to_sol_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn lst_to_sol_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: LstToSolAccounts<'_, '_>,
+    args: LstToSolIxArgs,
+) -> ProgramResult {
+    let keys: LstToSolKeys = accounts.into();
+    let ix = lst_to_sol_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn lst_to_sol_invoke(
+    accounts: LstToSolAccounts<'_, '_>,
+    args: LstToSolIxArgs,
+) -> ProgramResult {
+    lst_to_sol_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn lst_to_sol_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: LstToSolAccounts<'_, '_>,
+    args: LstToSolIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: LstToSolKeys = accounts.into();
+    let ix = lst_to_sol_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn lst_to_sol_invoke_signed(
+    accounts: LstToSolAccounts<'_, '_>,
+    args: LstToSolIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    lst_to_sol_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn lst_to_sol_verify_account_keys(
+    accounts: LstToSolAccounts<'_, '_>,
+    keys: LstToSolKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.rate_acc.key, &keys.rate_acc),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub const SOL_TO_LST_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SolToLstAccounts<'me, 'info> {
+    ///The LST mint
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The RateAccount PDA of the LST mint
+    pub rate_acc: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SolToLstKeys {
+    ///The LST mint
+    pub lst_mint: Pubkey,
+    ///The RateAccount PDA of the LST mint
+    pub rate_acc: Pubkey,
+}
+impl From<SolToLstAccounts<'_, '_>> for SolToLstKeys {
+    fn from(accounts: SolToLstAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            rate_acc: *accounts.rate_acc.key,
+        }
+    }
+}
+impl From<SolToLstKeys> for [AccountMeta; SOL_TO_LST_IX_ACCOUNTS_LEN] {
+    fn from(keys: SolToLstKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rate_acc,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SOL_TO_LST_IX_ACCOUNTS_LEN]> for SolToLstKeys {
+    fn from(pubkeys: [Pubkey; SOL_TO_LST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            rate_acc: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SolToLstAccounts<'_, 'info>> for [AccountInfo<'info>; SOL_TO_LST_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SolToLstAccounts<'_, 'info>) -> Self {
+        [accounts.lst_mint.clone(), accounts.rate_acc.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SOL_TO_LST_IX_ACCOUNTS_LEN]>
+    for SolToLstAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SOL_TO_LST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            rate_acc: &arr[1],
+        }
+    }
+}
+pub const SOL_TO_LST_IX_DISCM: u8 = 1u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolToLstIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolToLstIxData(pub SolToLstIxArgs);
+impl From<SolToLstIxArgs> for SolToLstIxData {
+    fn from(args: SolToLstIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SolToLstIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SOL_TO_LST_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SOL_TO_LST_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SolToLstIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SOL_TO_LST_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn sol_to_lst_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SolToLstKeys,
+    args: SolToLstIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SOL_TO_LST_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SolToLstIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn sol_to_lst_ix(keys: SolToLstKeys, args: SolToLstIxArgs) -> std::io::Result<Instruction> {
+    sol_to_lst_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn sol_to_lst_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SolToLstAccounts<'_, '_>,
+    args: SolToLstIxArgs,
+) -> ProgramResult {
+    let keys: SolToLstKeys = accounts.into();
+    let ix = sol_to_lst_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn sol_to_lst_invoke(
+    accounts: SolToLstAccounts<'_, '_>,
+    args: SolToLstIxArgs,
+) -> ProgramResult {
+    sol_to_lst_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn sol_to_lst_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SolToLstAccounts<'_, '_>,
+    args: SolToLstIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SolToLstKeys = accounts.into();
+    let ix = sol_to_lst_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn sol_to_lst_invoke_signed(
+    accounts: SolToLstAccounts<'_, '_>,
+    args: SolToLstIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    sol_to_lst_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn sol_to_lst_verify_account_keys(
+    accounts: SolToLstAccounts<'_, '_>,
+    keys: SolToLstKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.rate_acc.key, &keys.rate_acc),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub const BATCH_CALC_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct BatchCalcAccounts<'me, 'info> {
+    ///The LST mint
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The RateAccount PDA of the LST mint
+    pub rate_acc: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct BatchCalcKeys {
+    ///The LST mint
+    pub lst_mint: Pubkey,
+    ///The RateAccount PDA of the LST mint
+    pub rate_acc: Pubkey,
+}
+impl From<BatchCalcAccounts<'_, '_>> for BatchCalcKeys {
+    fn from(accounts: BatchCalcAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            rate_acc: *accounts.rate_acc.key,
+        }
+    }
+}
+impl From<BatchCalcKeys> for [AccountMeta; BATCH_CALC_IX_ACCOUNTS_LEN] {
+    fn from(keys: BatchCalcKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rate_acc,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; BATCH_CALC_IX_ACCOUNTS_LEN]> for BatchCalcKeys {
+    fn from(pubkeys: [Pubkey; BATCH_CALC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            rate_acc: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<BatchCalcAccounts<'_, 'info>>
+    for [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: BatchCalcAccounts<'_, 'info>) -> Self {
+        [accounts.lst_mint.clone(), accounts.rate_acc.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]>
+    for BatchCalcAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            rate_acc: &arr[1],
+        }
+    }
+}
+pub const BATCH_CALC_IX_DISCM: u8 = 2u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchCalcIxArgs {
+    pub lst_to_sol_amounts: Vec<u64>,
+    pub sol_to_lst_amounts: Vec<u64>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchCalcIxData(pub BatchCalcIxArgs);
+impl From<BatchCalcIxArgs> for BatchCalcIxData {
+    fn from(args: BatchCalcIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BatchCalcIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != BATCH_CALC_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    BATCH_CALC_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(BatchCalcIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[BATCH_CALC_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn batch_calc_ix_with_program_id(
+    program_id: Pubkey,
+    keys: BatchCalcKeys,
+    args: BatchCalcIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; BATCH_CALC_IX_ACCOUNTS_LEN] = keys.into();
+    let data: BatchCalcIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn batch_calc_ix(keys: BatchCalcKeys, args: BatchCalcIxArgs) -> std::io::Result<Instruction> {
+    batch_calc_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn batch_calc_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+) -> ProgramResult {
+    let keys: BatchCalcKeys = accounts.into();
+    let ix = batch_calc_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn batch_calc_invoke(
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+) -> ProgramResult {
+    batch_calc_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn batch_calc_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: BatchCalcKeys = accounts.into();
+    let ix = batch_calc_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn batch_calc_invoke_signed(
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    batch_calc_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn batch_calc_verify_account_keys(
+    accounts: BatchCalcAccounts<'_, '_>,
+    keys: BatchCalcKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.rate_acc.key, &keys.rate_acc),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub const SET_RATE_GUARDRAILS_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetRateGuardrailsAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///RateAccount PDA to modify
+    pub rate_acc: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetRateGuardrailsKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///RateAccount PDA to modify
+    pub rate_acc: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetRateGuardrailsAccounts<'_, '_>> for SetRateGuardrailsKeys {
+    fn from(accounts: SetRateGuardrailsAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            rate_acc: *accounts.rate_acc.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetRateGuardrailsKeys> for [AccountMeta; SET_RATE_GUARDRAILS_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetRateGuardrailsKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rate_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_RATE_GUARDRAILS_IX_ACCOUNTS_LEN]> for SetRateGuardrailsKeys {
+    fn from(pubkeys: [Pubkey; SET_RATE_GUARDRAILS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            rate_acc: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetRateGuardrailsAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_RATE_GUARDRAILS_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetRateGuardrailsAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.rate_acc.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_RATE_GUARDRAILS_IX_ACCOUNTS_LEN]>
+    for SetRateGuardrailsAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_RATE_GUARDRAILS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            rate_acc: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SET_RATE_GUARDRAILS_IX_DISCM: u8 = 250u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetRateGuardrailsIxArgs {
+    pub max_rate_change_bps: u16,
+    pub is_monotonic: bool,
+    pub max_staleness_slots: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetRateGuardrailsIxData(pub SetRateGuardrailsIxArgs);
+impl From<SetRateGuardrailsIxArgs> for SetRateGuardrailsIxData {
+    fn from(args: SetRateGuardrailsIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetRateGuardrailsIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_RATE_GUARDRAILS_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_RATE_GUARDRAILS_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetRateGuardrailsIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_RATE_GUARDRAILS_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_rate_guardrails_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetRateGuardrailsKeys,
+    args: SetRateGuardrailsIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_RATE_GUARDRAILS_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetRateGuardrailsIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_rate_guardrails_ix(
+    keys: SetRateGuardrailsKeys,
+    args: SetRateGuardrailsIxArgs,
+) -> std::io::Result<Instruction> {
+    set_rate_guardrails_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_rate_guardrails_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetRateGuardrailsAccounts<'_, '_>,
+    args: SetRateGuardrailsIxArgs,
+) -> ProgramResult {
+    let keys: SetRateGuardrailsKeys = accounts.into();
+    let ix = set_rate_guardrails_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_rate_guardrails_invoke(
+    accounts: SetRateGuardrailsAccounts<'_, '_>,
+    args: SetRateGuardrailsIxArgs,
+) -> ProgramResult {
+    set_rate_guardrails_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_rate_guardrails_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetRateGuardrailsAccounts<'_, '_>,
+    args: SetRateGuardrailsIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetRateGuardrailsKeys = accounts.into();
+    let ix = set_rate_guardrails_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_rate_guardrails_invoke_signed(
+    accounts: SetRateGuardrailsAccounts<'_, '_>,
+    args: SetRateGuardrailsIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_rate_guardrails_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_rate_guardrails_verify_account_keys(
+    accounts: SetRateGuardrailsAccounts<'_, '_>,
+    keys: SetRateGuardrailsKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.rate_acc.key, &keys.rate_acc),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_rate_guardrails_verify_writable_privileges<'me, 'info>(
+    accounts: SetRateGuardrailsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.rate_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_rate_guardrails_verify_signer_privileges<'me, 'info>(
+    accounts: SetRateGuardrailsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_rate_guardrails_verify_account_privileges<'me, 'info>(
+    accounts: SetRateGuardrailsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_rate_guardrails_verify_writable_privileges(accounts)?;
+    set_rate_guardrails_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_RATE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetRateAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///RateAccount PDA to modify
+    pub rate_acc: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetRateKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///RateAccount PDA to modify
+    pub rate_acc: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetRateAccounts<'_, '_>> for SetRateKeys {
+    fn from(accounts: SetRateAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            rate_acc: *accounts.rate_acc.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetRateKeys> for [AccountMeta; SET_RATE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetRateKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rate_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_RATE_IX_ACCOUNTS_LEN]> for SetRateKeys {
+    fn from(pubkeys: [Pubkey; SET_RATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            rate_acc: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetRateAccounts<'_, 'info>> for [AccountInfo<'info>; SET_RATE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetRateAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.rate_acc.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_RATE_IX_ACCOUNTS_LEN]>
+    for SetRateAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_RATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            rate_acc: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SET_RATE_IX_DISCM: u8 = 251u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetRateIxArgs {
+    pub rate_num: u64,
+    pub rate_denom: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetRateIxData(pub SetRateIxArgs);
+impl From<SetRateIxArgs> for SetRateIxData {
+    fn from(args: SetRateIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetRateIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_RATE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_RATE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetRateIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_RATE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_rate_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetRateKeys,
+    args: SetRateIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_RATE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetRateIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_rate_ix(keys: SetRateKeys, args: SetRateIxArgs) -> std::io::Result<Instruction> {
+    set_rate_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_rate_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetRateAccounts<'_, '_>,
+    args: SetRateIxArgs,
+) -> ProgramResult {
+    let keys: SetRateKeys = accounts.into();
+    let ix = set_rate_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_rate_invoke(accounts: SetRateAccounts<'_, '_>, args: SetRateIxArgs) -> ProgramResult {
+    set_rate_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_rate_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetRateAccounts<'_, '_>,
+    args: SetRateIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetRateKeys = accounts.into();
+    let ix = set_rate_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_rate_invoke_signed(
+    accounts: SetRateAccounts<'_, '_>,
+    args: SetRateIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_rate_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_rate_verify_account_keys(
+    accounts: SetRateAccounts<'_, '_>,
+    keys: SetRateKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.rate_acc.key, &keys.rate_acc),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_rate_verify_writable_privileges<'me, 'info>(
+    accounts: SetRateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.rate_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_rate_verify_signer_privileges<'me, 'info>(
+    accounts: SetRateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_rate_verify_account_privileges<'me, 'info>(
+    accounts: SetRateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_rate_verify_writable_privileges(accounts)?;
+    set_rate_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_LST_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLstAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Account to refund SOL rent to
+    pub refund_rent_to: &'me AccountInfo<'info>,
+    ///RateAccount PDA to remove
+    pub rate_acc: &'me AccountInfo<'info>,
+    ///Mint of the LST to remove
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLstKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Account to refund SOL rent to
+    pub refund_rent_to: Pubkey,
+    ///RateAccount PDA to remove
+    pub rate_acc: Pubkey,
+    ///Mint of the LST to remove
+    pub lst_mint: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<RemoveLstAccounts<'_, '_>> for RemoveLstKeys {
+    fn from(accounts: RemoveLstAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            refund_rent_to: *accounts.refund_rent_to.key,
+            rate_acc: *accounts.rate_acc.key,
+            lst_mint: *accounts.lst_mint.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<RemoveLstKeys> for [AccountMeta; REMOVE_LST_IX_ACCOUNTS_LEN] {
+    fn from(keys: RemoveLstKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.rate_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_LST_IX_ACCOUNTS_LEN]> for RemoveLstKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_LST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            refund_rent_to: pubkeys[1],
+            rate_acc: pubkeys[2],
+            lst_mint: pubkeys[3],
+            state: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<RemoveLstAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_LST_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemoveLstAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.refund_rent_to.clone(),
+            accounts.rate_acc.clone(),
+            accounts.lst_mint.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LST_IX_ACCOUNTS_LEN]>
+    for RemoveLstAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REMOVE_LST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            refund_rent_to: &arr[1],
+            rate_acc: &arr[2],
+            lst_mint: &arr[3],
+            state: &arr[4],
+        }
+    }
+}
+pub const REMOVE_LST_IX_DISCM: u8 = 252u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveLstIxData;
+impl RemoveLstIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_LST_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_LST_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_LST_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_lst_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveLstKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_LST_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: RemoveLstIxData.try_to_vec()?,
+    })
+}
+pub fn remove_lst_ix(keys: RemoveLstKeys) -> std::io::Result<Instruction> {
+    remove_lst_ix_with_program_id(crate::ID, keys)
+}
+pub fn remove_lst_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLstAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: RemoveLstKeys = accounts.into();
+    let ix = remove_lst_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_lst_invoke(accounts: RemoveLstAccounts<'_, '_>) -> ProgramResult {
+    remove_lst_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn remove_lst_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLstAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveLstKeys = accounts.into();
+    let ix = remove_lst_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_lst_invoke_signed(
+    accounts: RemoveLstAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_lst_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn remove_lst_verify_account_keys(
+    accounts: RemoveLstAccounts<'_, '_>,
+    keys: RemoveLstKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+        (accounts.rate_acc.key, &keys.rate_acc),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_lst_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveLstAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.refund_rent_to, accounts.rate_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_lst_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveLstAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_lst_verify_account_privileges<'me, 'info>(
+    accounts: RemoveLstAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_lst_verify_writable_privileges(accounts)?;
+    remove_lst_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_LST_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct AddLstAccounts<'me, 'info> {
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Account paying for RateAccount's rent
+    pub payer: &'me AccountInfo<'info>,
+    ///RateAccount PDA to create
+    pub rate_acc: &'me AccountInfo<'info>,
+    ///Mint of the LST to add
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLstKeys {
+    ///The program manager
+    pub manager: Pubkey,
+    ///Account paying for RateAccount's rent
+    pub payer: Pubkey,
+    ///RateAccount PDA to create
+    pub rate_acc: Pubkey,
+    ///Mint of the LST to add
+    pub lst_mint: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<AddLstAccounts<'_, '_>> for AddLstKeys {
+    fn from(accounts: AddLstAccounts) -> Self {
+        Self {
+            manager: *accounts.manager.key,
+            payer: *accounts.payer.key,
+            rate_acc: *accounts.rate_acc.key,
+            lst_mint: *accounts.lst_mint.key,
+            state: *accounts.state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<AddLstKeys> for [AccountMeta; ADD_LST_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddLstKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.rate_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_LST_IX_ACCOUNTS_LEN]> for AddLstKeys {
+    fn from(pubkeys: [Pubkey; ADD_LST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: pubkeys[0],
+            payer: pubkeys[1],
+            rate_acc: pubkeys[2],
+            lst_mint: pubkeys[3],
+            state: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<AddLstAccounts<'_, 'info>> for [AccountInfo<'info>; ADD_LST_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AddLstAccounts<'_, 'info>) -> Self {
+        [
+            accounts.manager.clone(),
+            accounts.payer.clone(),
+            accounts.rate_acc.clone(),
+            accounts.lst_mint.clone(),
+            accounts.state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LST_IX_ACCOUNTS_LEN]>
+    for AddLstAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_LST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            manager: &arr[0],
+            payer: &arr[1],
+            rate_acc: &arr[2],
+            lst_mint: &arr[3],
+            state: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+pub const ADD_LST_IX_DISCM: u8 = 253u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLstIxArgs {
+    pub rate_num: u64,
+    pub rate_denom: u64,
+    pub max_rate_change_bps: u16,
+    pub is_monotonic: bool,
+    pub max_staleness_slots: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddLstIxData(pub AddLstIxArgs);
+impl From<AddLstIxArgs> for AddLstIxData {
+    fn from(args: AddLstIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddLstIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_LST_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_LST_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddLstIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_LST_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_lst_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddLstKeys,
+    args: AddLstIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_LST_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddLstIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_lst_ix(keys: AddLstKeys, args: AddLstIxArgs) -> std::io::Result<Instruction> {
+    add_lst_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_lst_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLstAccounts<'_, '_>,
+    args: AddLstIxArgs,
+) -> ProgramResult {
+    let keys: AddLstKeys = accounts.into();
+    let ix = add_lst_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_lst_invoke(accounts: AddLstAccounts<'_, '_>, args: AddLstIxArgs) -> ProgramResult {
+    add_lst_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_lst_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLstAccounts<'_, '_>,
+    args: AddLstIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddLstKeys = accounts.into();
+    let ix = add_lst_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_lst_invoke_signed(
+    accounts: AddLstAccounts<'_, '_>,
+    args: AddLstIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_lst_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_lst_verify_account_keys(
+    accounts: AddLstAccounts<'_, '_>,
+    keys: AddLstKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.manager.key, &keys.manager),
+        (accounts.payer.key, &keys.payer),
+        (accounts.rate_acc.key, &keys.rate_acc),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.state.key, &keys.state),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_lst_verify_writable_privileges<'me, 'info>(
+    accounts: AddLstAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.rate_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_lst_verify_signer_privileges<'me, 'info>(
+    accounts: AddLstAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_lst_verify_account_privileges<'me, 'info>(
+    accounts: AddLstAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_lst_verify_writable_privileges(accounts)?;
+    add_lst_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_MANAGER_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetManagerAccounts<'me, 'info> {
+    ///The current program manager
+    pub current_manager: &'me AccountInfo<'info>,
+    ///The new program manager to set to
+    pub new_manager: &'me AccountInfo<'info>,
+    ///The program state PDA
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetManagerKeys {
+    ///The current program manager
+    pub current_manager: Pubkey,
+    ///The new program manager to set to
+    pub new_manager: Pubkey,
+    ///The program state PDA
+    pub state: Pubkey,
+}
+impl From<SetManagerAccounts<'_, '_>> for SetManagerKeys {
+    fn from(accounts: SetManagerAccounts) -> Self {
+        Self {
+            current_manager: *accounts.current_manager.key,
+            new_manager: *accounts.new_manager.key,
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetManagerKeys> for [AccountMeta; SET_MANAGER_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetManagerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.current_manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_manager,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_MANAGER_IX_ACCOUNTS_LEN]> for SetManagerKeys {
+    fn from(pubkeys: [Pubkey; SET_MANAGER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            current_manager: pubkeys[0],
+            new_manager: pubkeys[1],
+            state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetManagerAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_MANAGER_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetManagerAccounts<'_, 'info>) -> Self {
+        [
+            accounts.current_manager.clone(),
+            accounts.new_manager.clone(),
+            accounts.state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_MANAGER_IX_ACCOUNTS_LEN]>
+    for SetManagerAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_MANAGER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            current_manager: &arr[0],
+            new_manager: &arr[1],
+            state: &arr[2],
+        }
+    }
+}
+pub const SET_MANAGER_IX_DISCM: u8 = 254u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetManagerIxData;
+impl SetManagerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_MANAGER_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_MANAGER_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_MANAGER_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_manager_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetManagerKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_MANAGER_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SetManagerIxData.try_to_vec()?,
+    })
+}
+pub fn set_manager_ix(keys: SetManagerKeys) -> std::io::Result<Instruction> {
+    set_manager_ix_with_program_id(crate::ID, keys)
+}
+pub fn set_manager_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetManagerAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: SetManagerKeys = accounts.into();
+    let ix = set_manager_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_manager_invoke(accounts: SetManagerAccounts<'_, '_>) -> ProgramResult {
+    set_manager_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn set_manager_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetManagerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetManagerKeys = accounts.into();
+    let ix = set_manager_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_manager_invoke_signed(
+    accounts: SetManagerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_manager_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn set_manager_verify_account_keys(
+    accounts: SetManagerAccounts<'_, '_>,
+    keys: SetManagerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.current_manager.key, &keys.current_manager),
+        (accounts.new_manager.key, &keys.new_manager),
+        (accounts.state.key, &keys.state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_manager_verify_writable_privileges<'me, 'info>(
+    accounts: SetManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_manager_verify_signer_privileges<'me, 'info>(
+    accounts: SetManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.current_manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_manager_verify_account_privileges<'me, 'info>(
+    accounts: SetManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_manager_verify_writable_privileges(accounts)?;
+    set_manager_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const INITIALIZE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeAccounts<'me, 'info> {
+    ///Account paying for ProgramState's rent
+    pub payer: &'me AccountInfo<'info>,
+    ///Program state PDA
+    pub state: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeKeys {
+    ///Account paying for ProgramState's rent
+    pub payer: Pubkey,
+    ///Program state PDA
+    pub state: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<InitializeAccounts<'_, '_>> for InitializeKeys {
+    fn from(accounts: InitializeAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            state: *accounts.state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<InitializeKeys> for [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]> for InitializeKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            state: pubkeys[1],
+            system_program: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<InitializeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
+    for InitializeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            state: &arr[1],
+            system_program: &arr[2],
+        }
+    }
+}
+pub const INITIALIZE_IX_DISCM: u8 = 255u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializeIxData;
+impl InitializeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != INITIALIZE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INITIALIZE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[INITIALIZE_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn initialize_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: InitializeIxData.try_to_vec()?,
+    })
+}
+pub fn initialize_ix(keys: InitializeKeys) -> std::io::Result<Instruction> {
+    initialize_ix_with_program_id(crate::ID, keys)
+}
+pub fn initialize_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: InitializeKeys = accounts.into();
+    let ix = initialize_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_invoke(accounts: InitializeAccounts<'_, '_>) -> ProgramResult {
+    initialize_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn initialize_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializeKeys = accounts.into();
+    let ix = initialize_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_invoke_signed(
+    accounts: InitializeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn initialize_verify_account_keys(
+    accounts: InitializeAccounts<'_, '_>,
+    keys: InitializeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.state.key, &keys.state),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_verify_writable_privileges<'me, 'info>(
+    accounts: InitializeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_verify_signer_privileges<'me, 'info>(
+    accounts: InitializeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_verify_account_privileges<'me, 'info>(
+    accounts: InitializeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_verify_writable_privileges(accounts)?;
+    initialize_verify_signer_privileges(accounts)?;
+    Ok(())
+}
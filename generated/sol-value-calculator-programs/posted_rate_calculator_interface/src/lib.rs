@@ -0,0 +1,9 @@
+#![allow(non_local_definitions)]
+
+solana_program::declare_id!("rate2pJGF9p7kpzb6eU326EFZf2cDnimbTFVeJtx1qt");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
@@ -0,0 +1,22 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProgramState {
+    pub manager: Pubkey,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateAccount {
+    pub bump: u8,
+    pub is_monotonic: u8,
+    pub max_rate_change_bps: u16,
+    pub padding: [u8; 4],
+    pub max_staleness_slots: u64,
+    pub last_updated_slot: u64,
+    pub rate_num: u64,
+    pub rate_denom: u64,
+}
@@ -0,0 +1,43 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum SLpCalculatorError {
+    #[error("Pool state is not the pool state PDA of an S program")]
+    InvalidPoolState = 0,
+    #[error("LstStateList is not the S pool's LstStateList PDA")]
+    InvalidLstStateList = 1,
+    #[error("LST mint is not the S pool's LP token mint")]
+    IncorrectLpTokenMint = 2,
+    #[error("S pool is in the middle of a rebalance")]
+    PoolRebalancing = 3,
+    #[error("Not every LST of the S pool was synced earlier in this transaction")]
+    PoolNotSynced = 4,
+    #[error("S pool's LP token mint is one of its own LSTs")]
+    LpTokenMintIsPoolLst = 5,
+}
+impl From<SLpCalculatorError> for ProgramError {
+    fn from(e: SLpCalculatorError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for SLpCalculatorError {
+    fn type_of() -> &'static str {
+        "SLpCalculatorError"
+    }
+}
+impl PrintProgramError for SLpCalculatorError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
@@ -0,0 +1,656 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum SLpCalculatorProgramIx {
+    LstToSol(LstToSolIxArgs),
+    SolToLst(SolToLstIxArgs),
+    BatchCalc(BatchCalcIxArgs),
+}
+impl SLpCalculatorProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        match maybe_discm {
+            LST_TO_SOL_IX_DISCM => Ok(Self::LstToSol(LstToSolIxArgs::deserialize(&mut reader)?)),
+            SOL_TO_LST_IX_DISCM => Ok(Self::SolToLst(SolToLstIxArgs::deserialize(&mut reader)?)),
+            BATCH_CALC_IX_DISCM => Ok(Self::BatchCalc(BatchCalcIxArgs::deserialize(&mut reader)?)),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::LstToSol(args) => {
+                writer.write_all(&[LST_TO_SOL_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SolToLst(args) => {
+                writer.write_all(&[SOL_TO_LST_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::BatchCalc(args) => {
+                writer.write_all(&[BATCH_CALC_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const LST_TO_SOL_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct LstToSolAccounts<'me, 'info> {
+    ///LP token mint of the S pool
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The S pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S pool's dynamic list PDA of LstStates
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///Instructions sysvar
+    pub instructions: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct LstToSolKeys {
+    ///LP token mint of the S pool
+    pub lst_mint: Pubkey,
+    ///The S pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The S pool's dynamic list PDA of LstStates
+    pub lst_state_list: Pubkey,
+    ///Instructions sysvar
+    pub instructions: Pubkey,
+}
+impl From<LstToSolAccounts<'_, '_>> for LstToSolKeys {
+    fn from(accounts: LstToSolAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            instructions: *accounts.instructions.key,
+        }
+    }
+}
+impl From<LstToSolKeys> for [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: LstToSolKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.instructions,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; LST_TO_SOL_IX_ACCOUNTS_LEN]> for LstToSolKeys {
+    fn from(pubkeys: [Pubkey; LST_TO_SOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            instructions: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<LstToSolAccounts<'_, 'info>> for [AccountInfo<'info>; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+    fn from(accounts: LstToSolAccounts<'_, 'info>) -> Self {
+        [
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.instructions.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; LST_TO_SOL_IX_ACCOUNTS_LEN]>
+    for LstToSolAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; LST_TO_SOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            instructions: &arr[3],
+        }
+    }
+}
+pub const LST_TO_SOL_IX_DISCM: u8 = 0u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LstToSolIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct LstToSolIxData(pub LstToSolIxArgs);
+impl From<LstToSolIxArgs> for LstToSolIxData {
+    fn from(args: LstToSolIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl LstToSolIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != LST_TO_SOL_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    LST_TO_SOL_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(LstToSolIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[LST_TO_SOL_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn lst_to_sol_ix_with_program_id(
+    program_id: Pubkey,
+    keys: LstToSolKeys,
+    args: LstToSolIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] = keys.into();
+    let data: LstToSolIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn lst_to_sol_ix(keys: LstToSolKeys, args: LstToSolIxArgs) -> std::io::Result<Instruction> {
+    lst_to_sol_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn lst_to_sol_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: LstToSolAccounts<'_, '_>,
+    args: LstToSolIxArgs,
+) -> ProgramResult {
+    let keys: LstToSolKeys = accounts.into();
+    let ix = lst_to_sol_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn lst_to_sol_invoke(
+    accounts: LstToSolAccounts<'_, '_>,
+    args: LstToSolIxArgs,
+) -> ProgramResult {
+    lst_to_sol_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn lst_to_sol_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: LstToSolAccounts<'_, '_>,
+    args: LstToSolIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: LstToSolKeys = accounts.into();
+    let ix = lst_to_sol_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn lst_to_sol_invoke_signed(
+    accounts: LstToSolAccounts<'_, '_>,
+    args: LstToSolIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    lst_to_sol_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn lst_to_sol_verify_account_keys(
+    accounts: LstToSolAccounts<'_, '_>,
+    keys: LstToSolKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.instructions.key, &keys.instructions),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub const SOL_TO_LST_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SolToLstAccounts<'me, 'info> {
+    ///LP token mint of the S pool
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The S pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S pool's dynamic list PDA of LstStates
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///Instructions sysvar
+    pub instructions: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SolToLstKeys {
+    ///LP token mint of the S pool
+    pub lst_mint: Pubkey,
+    ///The S pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The S pool's dynamic list PDA of LstStates
+    pub lst_state_list: Pubkey,
+    ///Instructions sysvar
+    pub instructions: Pubkey,
+}
+impl From<SolToLstAccounts<'_, '_>> for SolToLstKeys {
+    fn from(accounts: SolToLstAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            instructions: *accounts.instructions.key,
+        }
+    }
+}
+impl From<SolToLstKeys> for [AccountMeta; SOL_TO_LST_IX_ACCOUNTS_LEN] {
+    fn from(keys: SolToLstKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.instructions,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SOL_TO_LST_IX_ACCOUNTS_LEN]> for SolToLstKeys {
+    fn from(pubkeys: [Pubkey; SOL_TO_LST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            instructions: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SolToLstAccounts<'_, 'info>> for [AccountInfo<'info>; SOL_TO_LST_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SolToLstAccounts<'_, 'info>) -> Self {
+        [
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.instructions.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SOL_TO_LST_IX_ACCOUNTS_LEN]>
+    for SolToLstAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SOL_TO_LST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            instructions: &arr[3],
+        }
+    }
+}
+pub const SOL_TO_LST_IX_DISCM: u8 = 1u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolToLstIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolToLstIxData(pub SolToLstIxArgs);
+impl From<SolToLstIxArgs> for SolToLstIxData {
+    fn from(args: SolToLstIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SolToLstIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SOL_TO_LST_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SOL_TO_LST_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SolToLstIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SOL_TO_LST_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn sol_to_lst_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SolToLstKeys,
+    args: SolToLstIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SOL_TO_LST_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SolToLstIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn sol_to_lst_ix(keys: SolToLstKeys, args: SolToLstIxArgs) -> std::io::Result<Instruction> {
+    sol_to_lst_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn sol_to_lst_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SolToLstAccounts<'_, '_>,
+    args: SolToLstIxArgs,
+) -> ProgramResult {
+    let keys: SolToLstKeys = accounts.into();
+    let ix = sol_to_lst_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn sol_to_lst_invoke(
+    accounts: SolToLstAccounts<'_, '_>,
+    args: SolToLstIxArgs,
+) -> ProgramResult {
+    sol_to_lst_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn sol_to_lst_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SolToLstAccounts<'_, '_>,
+    args: SolToLstIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SolToLstKeys = accounts.into();
+    let ix = sol_to_lst_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn sol_to_lst_invoke_signed(
+    accounts: SolToLstAccounts<'_, '_>,
+    args: SolToLstIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    sol_to_lst_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn sol_to_lst_verify_account_keys(
+    accounts: SolToLstAccounts<'_, '_>,
+    keys: SolToLstKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.instructions.key, &keys.instructions),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub const BATCH_CALC_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct BatchCalcAccounts<'me, 'info> {
+    ///LP token mint of the S pool
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The S pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The S pool's dynamic list PDA of LstStates
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///Instructions sysvar
+    pub instructions: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct BatchCalcKeys {
+    ///LP token mint of the S pool
+    pub lst_mint: Pubkey,
+    ///The S pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The S pool's dynamic list PDA of LstStates
+    pub lst_state_list: Pubkey,
+    ///Instructions sysvar
+    pub instructions: Pubkey,
+}
+impl From<BatchCalcAccounts<'_, '_>> for BatchCalcKeys {
+    fn from(accounts: BatchCalcAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            instructions: *accounts.instructions.key,
+        }
+    }
+}
+impl From<BatchCalcKeys> for [AccountMeta; BATCH_CALC_IX_ACCOUNTS_LEN] {
+    fn from(keys: BatchCalcKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.instructions,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; BATCH_CALC_IX_ACCOUNTS_LEN]> for BatchCalcKeys {
+    fn from(pubkeys: [Pubkey; BATCH_CALC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            instructions: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<BatchCalcAccounts<'_, 'info>>
+    for [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: BatchCalcAccounts<'_, 'info>) -> Self {
+        [
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.instructions.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]>
+    for BatchCalcAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            instructions: &arr[3],
+        }
+    }
+}
+pub const BATCH_CALC_IX_DISCM: u8 = 2u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchCalcIxArgs {
+    pub lst_to_sol_amounts: Vec<u64>,
+    pub sol_to_lst_amounts: Vec<u64>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchCalcIxData(pub BatchCalcIxArgs);
+impl From<BatchCalcIxArgs> for BatchCalcIxData {
+    fn from(args: BatchCalcIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BatchCalcIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != BATCH_CALC_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    BATCH_CALC_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(BatchCalcIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[BATCH_CALC_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn batch_calc_ix_with_program_id(
+    program_id: Pubkey,
+    keys: BatchCalcKeys,
+    args: BatchCalcIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; BATCH_CALC_IX_ACCOUNTS_LEN] = keys.into();
+    let data: BatchCalcIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn batch_calc_ix(keys: BatchCalcKeys, args: BatchCalcIxArgs) -> std::io::Result<Instruction> {
+    batch_calc_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn batch_calc_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+) -> ProgramResult {
+    let keys: BatchCalcKeys = accounts.into();
+    let ix = batch_calc_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn batch_calc_invoke(
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+) -> ProgramResult {
+    batch_calc_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn batch_calc_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: BatchCalcKeys = accounts.into();
+    let ix = batch_calc_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn batch_calc_invoke_signed(
+    accounts: BatchCalcAccounts<'_, '_>,
+    args: BatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    batch_calc_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn batch_calc_verify_account_keys(
+    accounts: BatchCalcAccounts<'_, '_>,
+    keys: BatchCalcKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.instructions.key, &keys.instructions),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
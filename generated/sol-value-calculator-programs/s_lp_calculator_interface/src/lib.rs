@@ -0,0 +1,7 @@
+#![allow(non_local_definitions)]
+
+solana_program::declare_id!("sLpca1cSdxihusXo1XG46npkkYMfhT7beqXFazWK41o");
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
@@ -11,6 +11,7 @@ use std::io::Read;
 pub enum SolValueCalculatorProgramIx {
     LstToSol(LstToSolIxArgs),
     SolToLst(SolToLstIxArgs),
+    BatchCalc(BatchCalcIxArgs),
 }
 impl SolValueCalculatorProgramIx {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -21,6 +22,7 @@ impl SolValueCalculatorProgramIx {
         match maybe_discm {
             LST_TO_SOL_IX_DISCM => Ok(Self::LstToSol(LstToSolIxArgs::deserialize(&mut reader)?)),
             SOL_TO_LST_IX_DISCM => Ok(Self::SolToLst(SolToLstIxArgs::deserialize(&mut reader)?)),
+            BATCH_CALC_IX_DISCM => Ok(Self::BatchCalc(BatchCalcIxArgs::deserialize(&mut reader)?)),
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!("discm {:?} not found", maybe_discm),
@@ -37,6 +39,10 @@ impl SolValueCalculatorProgramIx {
                 writer.write_all(&[SOL_TO_LST_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
+            Self::BatchCalc(args) => {
+                writer.write_all(&[BATCH_CALC_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
         }
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -301,3 +307,134 @@ pub fn sol_to_lst_verify_account_keys(
     }
     Ok(())
 }
+pub const BATCH_CALC_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone, Debug)]
+pub struct BatchCalcAccounts<'me, 'info> {
+    ///The LST mint
+    pub lst_mint: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct BatchCalcKeys {
+    ///The LST mint
+    pub lst_mint: Pubkey,
+}
+impl From<BatchCalcAccounts<'_, '_>> for BatchCalcKeys {
+    fn from(accounts: BatchCalcAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+        }
+    }
+}
+impl From<BatchCalcKeys> for [AccountMeta; BATCH_CALC_IX_ACCOUNTS_LEN] {
+    fn from(keys: BatchCalcKeys) -> Self {
+        [AccountMeta {
+            pubkey: keys.lst_mint,
+            is_signer: false,
+            is_writable: false,
+        }]
+    }
+}
+impl From<[Pubkey; BATCH_CALC_IX_ACCOUNTS_LEN]> for BatchCalcKeys {
+    fn from(pubkeys: [Pubkey; BATCH_CALC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+        }
+    }
+}
+impl<'info> From<BatchCalcAccounts<'_, 'info>>
+    for [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: BatchCalcAccounts<'_, 'info>) -> Self {
+        [accounts.lst_mint.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]>
+    for BatchCalcAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN]) -> Self {
+        Self { lst_mint: &arr[0] }
+    }
+}
+pub const BATCH_CALC_IX_DISCM: u8 = 2u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchCalcIxArgs {
+    pub lst_to_sol_amounts: Vec<u64>,
+    pub sol_to_lst_amounts: Vec<u64>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchCalcIxData(pub BatchCalcIxArgs);
+impl From<BatchCalcIxArgs> for BatchCalcIxData {
+    fn from(args: BatchCalcIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BatchCalcIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != BATCH_CALC_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    BATCH_CALC_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(BatchCalcIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[BATCH_CALC_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn batch_calc_ix<K: Into<BatchCalcKeys>, A: Into<BatchCalcIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: BatchCalcKeys = accounts.into();
+    let metas: [AccountMeta; BATCH_CALC_IX_ACCOUNTS_LEN] = keys.into();
+    let args_full: BatchCalcIxArgs = args.into();
+    let data: BatchCalcIxData = args_full.into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn batch_calc_invoke<'info, A: Into<BatchCalcIxArgs>>(
+    accounts: BatchCalcAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = batch_calc_ix(accounts, args)?;
+    let account_info: [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn batch_calc_invoke_signed<'info, A: Into<BatchCalcIxArgs>>(
+    accounts: BatchCalcAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = batch_calc_ix(accounts, args)?;
+    let account_info: [AccountInfo<'info>; BATCH_CALC_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn batch_calc_verify_account_keys(
+    accounts: BatchCalcAccounts<'_, '_>,
+    keys: BatchCalcKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [(accounts.lst_mint.key, &keys.lst_mint)] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
@@ -8,6 +8,14 @@ use thiserror::Error;
 pub enum SplCalculatorError {
     #[error("SPL stake pool not yet updated for this epoch")]
     PoolNotUpdated = 0,
+    #[error("Invalid valuation mode")]
+    InvalidValuationMode = 1,
+    #[error("Incorrect pool config account for the stake pool")]
+    IncorrectPoolConfig = 2,
+    #[error("Stake withdrawal fee changed within the configured epoch window")]
+    WithdrawalFeeChanged = 3,
+    #[error("Stake withdrawal fee exceeds the configured ceiling")]
+    WithdrawalFeeAboveCeiling = 4,
 }
 impl From<SplCalculatorError> for ProgramError {
     fn from(e: SplCalculatorError) -> Self {
@@ -0,0 +1,747 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum SplCalculatorProgramIx {
+    SetValuationMode(SetValuationModeIxArgs),
+    SetWithdrawalFeeGuard(SetWithdrawalFeeGuardIxArgs),
+    RecordWithdrawalFees,
+}
+impl SplCalculatorProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        match maybe_discm {
+            SET_VALUATION_MODE_IX_DISCM => Ok(Self::SetValuationMode(
+                SetValuationModeIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_WITHDRAWAL_FEE_GUARD_IX_DISCM => Ok(Self::SetWithdrawalFeeGuard(
+                SetWithdrawalFeeGuardIxArgs::deserialize(&mut reader)?,
+            )),
+            RECORD_WITHDRAWAL_FEES_IX_DISCM => Ok(Self::RecordWithdrawalFees),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::SetValuationMode(args) => {
+                writer.write_all(&[SET_VALUATION_MODE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetWithdrawalFeeGuard(args) => {
+                writer.write_all(&[SET_WITHDRAWAL_FEE_GUARD_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RecordWithdrawalFees => writer.write_all(&[RECORD_WITHDRAWAL_FEES_IX_DISCM]),
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const SET_VALUATION_MODE_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct SetValuationModeAccounts<'me, 'info> {
+    ///Account paying for SplPoolConfig's rent if it is not yet initialized
+    pub payer: &'me AccountInfo<'info>,
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///The CalculatorState PDA
+    pub state: &'me AccountInfo<'info>,
+    ///The stake pool to configure
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The SplPoolConfig PDA of the stake pool
+    pub pool_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetValuationModeKeys {
+    ///Account paying for SplPoolConfig's rent if it is not yet initialized
+    pub payer: Pubkey,
+    ///The program manager
+    pub manager: Pubkey,
+    ///The CalculatorState PDA
+    pub state: Pubkey,
+    ///The stake pool to configure
+    pub pool_state: Pubkey,
+    ///The SplPoolConfig PDA of the stake pool
+    pub pool_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetValuationModeAccounts<'_, '_>> for SetValuationModeKeys {
+    fn from(accounts: SetValuationModeAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            manager: *accounts.manager.key,
+            state: *accounts.state.key,
+            pool_state: *accounts.pool_state.key,
+            pool_config: *accounts.pool_config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetValuationModeKeys> for [AccountMeta; SET_VALUATION_MODE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetValuationModeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_VALUATION_MODE_IX_ACCOUNTS_LEN]> for SetValuationModeKeys {
+    fn from(pubkeys: [Pubkey; SET_VALUATION_MODE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            manager: pubkeys[1],
+            state: pubkeys[2],
+            pool_state: pubkeys[3],
+            pool_config: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<SetValuationModeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_VALUATION_MODE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetValuationModeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.manager.clone(),
+            accounts.state.clone(),
+            accounts.pool_state.clone(),
+            accounts.pool_config.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_VALUATION_MODE_IX_ACCOUNTS_LEN]>
+    for SetValuationModeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_VALUATION_MODE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            manager: &arr[1],
+            state: &arr[2],
+            pool_state: &arr[3],
+            pool_config: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+pub const SET_VALUATION_MODE_IX_DISCM: u8 = 248u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetValuationModeIxArgs {
+    pub valuation_mode: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetValuationModeIxData(pub SetValuationModeIxArgs);
+impl From<SetValuationModeIxArgs> for SetValuationModeIxData {
+    fn from(args: SetValuationModeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetValuationModeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_VALUATION_MODE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_VALUATION_MODE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetValuationModeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_VALUATION_MODE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_valuation_mode_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetValuationModeKeys,
+    args: SetValuationModeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_VALUATION_MODE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetValuationModeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_valuation_mode_ix(
+    keys: SetValuationModeKeys,
+    args: SetValuationModeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_valuation_mode_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_valuation_mode_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetValuationModeAccounts<'_, '_>,
+    args: SetValuationModeIxArgs,
+) -> ProgramResult {
+    let keys: SetValuationModeKeys = accounts.into();
+    let ix = set_valuation_mode_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_valuation_mode_invoke(
+    accounts: SetValuationModeAccounts<'_, '_>,
+    args: SetValuationModeIxArgs,
+) -> ProgramResult {
+    set_valuation_mode_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_valuation_mode_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetValuationModeAccounts<'_, '_>,
+    args: SetValuationModeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetValuationModeKeys = accounts.into();
+    let ix = set_valuation_mode_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_valuation_mode_invoke_signed(
+    accounts: SetValuationModeAccounts<'_, '_>,
+    args: SetValuationModeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_valuation_mode_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_valuation_mode_verify_account_keys(
+    accounts: SetValuationModeAccounts<'_, '_>,
+    keys: SetValuationModeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.manager.key, &keys.manager),
+        (accounts.state.key, &keys.state),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_valuation_mode_verify_writable_privileges<'me, 'info>(
+    accounts: SetValuationModeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pool_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_valuation_mode_verify_signer_privileges<'me, 'info>(
+    accounts: SetValuationModeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_valuation_mode_verify_account_privileges<'me, 'info>(
+    accounts: SetValuationModeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_valuation_mode_verify_writable_privileges(accounts)?;
+    set_valuation_mode_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_WITHDRAWAL_FEE_GUARD_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct SetWithdrawalFeeGuardAccounts<'me, 'info> {
+    ///Account paying for SplPoolConfig's rent if it is not yet initialized
+    pub payer: &'me AccountInfo<'info>,
+    ///The program manager
+    pub manager: &'me AccountInfo<'info>,
+    ///The CalculatorState PDA
+    pub state: &'me AccountInfo<'info>,
+    ///The stake pool to configure
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The SplPoolConfig PDA of the stake pool
+    pub pool_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetWithdrawalFeeGuardKeys {
+    ///Account paying for SplPoolConfig's rent if it is not yet initialized
+    pub payer: Pubkey,
+    ///The program manager
+    pub manager: Pubkey,
+    ///The CalculatorState PDA
+    pub state: Pubkey,
+    ///The stake pool to configure
+    pub pool_state: Pubkey,
+    ///The SplPoolConfig PDA of the stake pool
+    pub pool_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetWithdrawalFeeGuardAccounts<'_, '_>> for SetWithdrawalFeeGuardKeys {
+    fn from(accounts: SetWithdrawalFeeGuardAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            manager: *accounts.manager.key,
+            state: *accounts.state.key,
+            pool_state: *accounts.pool_state.key,
+            pool_config: *accounts.pool_config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetWithdrawalFeeGuardKeys> for [AccountMeta; SET_WITHDRAWAL_FEE_GUARD_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetWithdrawalFeeGuardKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_WITHDRAWAL_FEE_GUARD_IX_ACCOUNTS_LEN]> for SetWithdrawalFeeGuardKeys {
+    fn from(pubkeys: [Pubkey; SET_WITHDRAWAL_FEE_GUARD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            manager: pubkeys[1],
+            state: pubkeys[2],
+            pool_state: pubkeys[3],
+            pool_config: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<SetWithdrawalFeeGuardAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_WITHDRAWAL_FEE_GUARD_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetWithdrawalFeeGuardAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.manager.clone(),
+            accounts.state.clone(),
+            accounts.pool_state.clone(),
+            accounts.pool_config.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_WITHDRAWAL_FEE_GUARD_IX_ACCOUNTS_LEN]>
+    for SetWithdrawalFeeGuardAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_WITHDRAWAL_FEE_GUARD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            manager: &arr[1],
+            state: &arr[2],
+            pool_state: &arr[3],
+            pool_config: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+pub const SET_WITHDRAWAL_FEE_GUARD_IX_DISCM: u8 = 247u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetWithdrawalFeeGuardIxArgs {
+    pub fee_change_window_epochs: u64,
+    pub max_stake_withdrawal_fee: Fee,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetWithdrawalFeeGuardIxData(pub SetWithdrawalFeeGuardIxArgs);
+impl From<SetWithdrawalFeeGuardIxArgs> for SetWithdrawalFeeGuardIxData {
+    fn from(args: SetWithdrawalFeeGuardIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetWithdrawalFeeGuardIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_WITHDRAWAL_FEE_GUARD_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_WITHDRAWAL_FEE_GUARD_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetWithdrawalFeeGuardIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_WITHDRAWAL_FEE_GUARD_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_withdrawal_fee_guard_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetWithdrawalFeeGuardKeys,
+    args: SetWithdrawalFeeGuardIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_WITHDRAWAL_FEE_GUARD_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetWithdrawalFeeGuardIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_withdrawal_fee_guard_ix(
+    keys: SetWithdrawalFeeGuardKeys,
+    args: SetWithdrawalFeeGuardIxArgs,
+) -> std::io::Result<Instruction> {
+    set_withdrawal_fee_guard_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_withdrawal_fee_guard_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetWithdrawalFeeGuardAccounts<'_, '_>,
+    args: SetWithdrawalFeeGuardIxArgs,
+) -> ProgramResult {
+    let keys: SetWithdrawalFeeGuardKeys = accounts.into();
+    let ix = set_withdrawal_fee_guard_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_withdrawal_fee_guard_invoke(
+    accounts: SetWithdrawalFeeGuardAccounts<'_, '_>,
+    args: SetWithdrawalFeeGuardIxArgs,
+) -> ProgramResult {
+    set_withdrawal_fee_guard_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_withdrawal_fee_guard_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetWithdrawalFeeGuardAccounts<'_, '_>,
+    args: SetWithdrawalFeeGuardIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetWithdrawalFeeGuardKeys = accounts.into();
+    let ix = set_withdrawal_fee_guard_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_withdrawal_fee_guard_invoke_signed(
+    accounts: SetWithdrawalFeeGuardAccounts<'_, '_>,
+    args: SetWithdrawalFeeGuardIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_withdrawal_fee_guard_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_withdrawal_fee_guard_verify_account_keys(
+    accounts: SetWithdrawalFeeGuardAccounts<'_, '_>,
+    keys: SetWithdrawalFeeGuardKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.manager.key, &keys.manager),
+        (accounts.state.key, &keys.state),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_withdrawal_fee_guard_verify_writable_privileges<'me, 'info>(
+    accounts: SetWithdrawalFeeGuardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pool_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_withdrawal_fee_guard_verify_signer_privileges<'me, 'info>(
+    accounts: SetWithdrawalFeeGuardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_withdrawal_fee_guard_verify_account_privileges<'me, 'info>(
+    accounts: SetWithdrawalFeeGuardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_withdrawal_fee_guard_verify_writable_privileges(accounts)?;
+    set_withdrawal_fee_guard_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const RECORD_WITHDRAWAL_FEES_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct RecordWithdrawalFeesAccounts<'me, 'info> {
+    ///The stake pool whose withdrawal fees to record
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The initialized SplPoolConfig PDA of the stake pool
+    pub pool_config: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RecordWithdrawalFeesKeys {
+    ///The stake pool whose withdrawal fees to record
+    pub pool_state: Pubkey,
+    ///The initialized SplPoolConfig PDA of the stake pool
+    pub pool_config: Pubkey,
+}
+impl From<RecordWithdrawalFeesAccounts<'_, '_>> for RecordWithdrawalFeesKeys {
+    fn from(accounts: RecordWithdrawalFeesAccounts) -> Self {
+        Self {
+            pool_state: *accounts.pool_state.key,
+            pool_config: *accounts.pool_config.key,
+        }
+    }
+}
+impl From<RecordWithdrawalFeesKeys> for [AccountMeta; RECORD_WITHDRAWAL_FEES_IX_ACCOUNTS_LEN] {
+    fn from(keys: RecordWithdrawalFeesKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; RECORD_WITHDRAWAL_FEES_IX_ACCOUNTS_LEN]> for RecordWithdrawalFeesKeys {
+    fn from(pubkeys: [Pubkey; RECORD_WITHDRAWAL_FEES_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pool_state: pubkeys[0],
+            pool_config: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<RecordWithdrawalFeesAccounts<'_, 'info>>
+    for [AccountInfo<'info>; RECORD_WITHDRAWAL_FEES_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RecordWithdrawalFeesAccounts<'_, 'info>) -> Self {
+        [accounts.pool_state.clone(), accounts.pool_config.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; RECORD_WITHDRAWAL_FEES_IX_ACCOUNTS_LEN]>
+    for RecordWithdrawalFeesAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; RECORD_WITHDRAWAL_FEES_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pool_state: &arr[0],
+            pool_config: &arr[1],
+        }
+    }
+}
+pub const RECORD_WITHDRAWAL_FEES_IX_DISCM: u8 = 246u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordWithdrawalFeesIxData;
+impl RecordWithdrawalFeesIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != RECORD_WITHDRAWAL_FEES_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    RECORD_WITHDRAWAL_FEES_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[RECORD_WITHDRAWAL_FEES_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn record_withdrawal_fees_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RecordWithdrawalFeesKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; RECORD_WITHDRAWAL_FEES_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: RecordWithdrawalFeesIxData.try_to_vec()?,
+    })
+}
+pub fn record_withdrawal_fees_ix(keys: RecordWithdrawalFeesKeys) -> std::io::Result<Instruction> {
+    record_withdrawal_fees_ix_with_program_id(crate::ID, keys)
+}
+pub fn record_withdrawal_fees_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RecordWithdrawalFeesAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: RecordWithdrawalFeesKeys = accounts.into();
+    let ix = record_withdrawal_fees_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn record_withdrawal_fees_invoke(
+    accounts: RecordWithdrawalFeesAccounts<'_, '_>,
+) -> ProgramResult {
+    record_withdrawal_fees_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn record_withdrawal_fees_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RecordWithdrawalFeesAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RecordWithdrawalFeesKeys = accounts.into();
+    let ix = record_withdrawal_fees_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn record_withdrawal_fees_invoke_signed(
+    accounts: RecordWithdrawalFeesAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    record_withdrawal_fees_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn record_withdrawal_fees_verify_account_keys(
+    accounts: RecordWithdrawalFeesAccounts<'_, '_>,
+    keys: RecordWithdrawalFeesKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pool_config.key, &keys.pool_config),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn record_withdrawal_fees_verify_writable_privileges<'me, 'info>(
+    accounts: RecordWithdrawalFeesAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn record_withdrawal_fees_verify_account_privileges<'me, 'info>(
+    accounts: RecordWithdrawalFeesAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    record_withdrawal_fees_verify_writable_privileges(accounts)?;
+    Ok(())
+}
@@ -3,5 +3,7 @@
 solana_program::declare_id!("TH1S1DLoNLYCoNTA1NSERRoRANDCoP1EDTYPES11111");
 pub mod typedefs;
 pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
 pub mod errors;
 pub use errors::*;
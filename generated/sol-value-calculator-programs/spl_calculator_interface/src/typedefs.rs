@@ -1,5 +1,20 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
 use solana_program::pubkey::Pubkey;
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplPoolConfig {
+    pub bump: u8,
+    pub valuation_mode: u8,
+    pub has_recorded_next_stake_withdrawal_fee: u8,
+    pub padding: [u8; 5],
+    pub fee_change_window_epochs: u64,
+    pub last_fee_change_epoch: u64,
+    pub max_stake_withdrawal_fee: Fee,
+    pub recorded_stake_withdrawal_fee: Fee,
+    pub recorded_next_stake_withdrawal_fee: Fee,
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SplStakePool {
@@ -48,7 +63,8 @@ pub struct Lockup {
     pub epoch: u64,
     pub custodian: Pubkey,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fee {
     pub denominator: u64,
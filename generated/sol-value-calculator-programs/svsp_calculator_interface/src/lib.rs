@@ -0,0 +1,9 @@
+#![allow(non_local_definitions)]
+
+solana_program::declare_id!("svspWeya3GKjhjE9qPJypVu2azp94iDgnWw4CsfM8HM");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
@@ -0,0 +1,7 @@
+pub mod everstake_stake_pool_program {
+    sanctum_macros::declare_program_keys!("EverSFw9uN5t1V8kS3ficHUcKffSjwpGzUSGd7mgmSks", []);
+}
+
+pub mod everstake_stake_pool_program_progdata {
+    sanctum_macros::declare_program_keys!("BmVHbbVQh2jDqZwZrq5jhyrJQTpQK1KNaLWh7QwbgBzZ", []);
+}
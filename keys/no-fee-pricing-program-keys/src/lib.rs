@@ -0,0 +1,3 @@
+pub mod no_fee_pricing_program {
+    sanctum_macros::declare_program_keys!("NoFEEPR1C1NGPRoGRAM111111111111111111111111", []);
+}
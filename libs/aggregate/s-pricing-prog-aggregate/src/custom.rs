@@ -0,0 +1,59 @@
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::ReadonlyAccountData;
+use std::{collections::HashMap, fmt::Debug};
+
+use crate::{PricingProg, PricingProgErr};
+
+/// Object-safe counterpart of [`crate::MutablePricingProg`] for pricing programs
+/// that are not known to this crate.
+///
+/// Implement this for your own pricing program and pass a [`CustomPricingProgFactory`]
+/// that creates it to [`crate::KnownPricingProg::try_new_with_custom`],
+/// or to `SPool`'s constructors, so that they can use it.
+pub trait CustomPricingProg: PricingProg + Debug + Send + Sync {
+    /// See [`crate::MutablePricingProg::get_accounts_to_update_for_liquidity`]
+    fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey>;
+
+    /// See [`crate::MutablePricingProg::get_accounts_to_update_for_all_lsts`]
+    fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey>;
+
+    /// See [`crate::MutablePricingProg::get_accounts_to_update_for_lsts`]
+    fn get_accounts_to_update_for_lsts(&self, lst_mints: &[Pubkey]) -> Vec<Pubkey>;
+
+    /// See [`crate::MutablePricingProg::update`].
+    /// `account_map` maps each fetched account's pubkey to its data.
+    fn update(&mut self, account_map: &HashMap<Pubkey, &[u8]>) -> anyhow::Result<()>;
+
+    fn clone_boxed(&self) -> Box<dyn CustomPricingProg>;
+}
+
+impl Clone for Box<dyn CustomPricingProg> {
+    fn clone(&self) -> Self {
+        self.clone_boxed()
+    }
+}
+
+/// Creates a custom pricing program given its program ID
+/// and the mints of all LSTs in the S pool.
+///
+/// Should return [`PricingProgErr::UnknownPricingProg`] for program IDs it does not handle
+pub type CustomPricingProgFactory =
+    fn(Pubkey, &[Pubkey]) -> Result<Box<dyn CustomPricingProg>, PricingProgErr>;
+
+pub(crate) fn update_custom_pricing_prog<D: ReadonlyAccountData>(
+    pricing_prog: &mut dyn CustomPricingProg,
+    account_map: &HashMap<Pubkey, D>,
+) -> anyhow::Result<()> {
+    let account_datas: Vec<(Pubkey, D::DataDeref<'_>)> = account_map
+        .iter()
+        .map(|(pubkey, account)| (*pubkey, account.data()))
+        .collect();
+    let account_map: HashMap<Pubkey, &[u8]> = account_datas
+        .iter()
+        .map(|(pubkey, data)| {
+            let data: &[u8] = data;
+            (*pubkey, data)
+        })
+        .collect();
+    pricing_prog.update(&account_map)
+}
@@ -1,21 +1,35 @@
 use flat_fee_interface::{
-    FeeAccount, FlatFeeError, PriceLpTokensToMintKeys, ProgramState,
+    FeeAccount, FlatFeeError, LstSurcharge, PairFeeAccount, ProgramState, SurgeConfig,
     PRICE_EXACT_IN_IX_ACCOUNTS_LEN, PRICE_EXACT_OUT_IX_ACCOUNTS_LEN,
     PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN, PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN,
 };
 use flat_fee_lib::{
     account_resolvers::{
         PriceExactInFreeArgs, PriceExactInWithBumpFreeArgs, PriceExactOutFreeArgs,
-        PriceExactOutWithBumpFreeArgs, PriceLpTokensToRedeemFreeArgs,
+        PriceExactOutWithBumpFreeArgs, PriceLpTokensToMintFreeArgs,
+        PriceLpTokensToMintWithBumpFreeArgs, PriceLpTokensToRedeemFreeArgs,
+        PriceLpTokensToRedeemWithBumpFreeArgs,
     },
     calc::{
-        calculate_price_exact_in, calculate_price_exact_out, calculate_price_lp_tokens_to_redeem,
-        CalculatePriceExactInArgs, CalculatePriceExactOutArgs,
+        calculate_epoch_surge_fee_bps, calculate_price_exact_in, calculate_price_exact_out,
+        calculate_price_lp_tokens_to_mint, calculate_price_lp_tokens_to_redeem,
+        effective_lp_withdrawal_fee_bps, fee_account_lst_fees, fee_account_upcoming_lst_fees,
+        program_state_lp_withdrawal_fee_bps, program_state_upcoming_lp_withdrawal_fee,
+        slots_elapsed_in_epoch, CalculatePriceExactInArgs, CalculatePriceExactOutArgs, LstFees,
+        PendingFee,
     },
-    pda::{FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
-    utils::{try_fee_account, try_program_state},
+    pda::{
+        FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs, LstSurchargeFindPdaArgs,
+        PairFeeAccountFindPdaArgs, ProgramStateFindPdaArgs, SurgeConfigFindPdaArgs,
+    },
+    utils::{
+        try_fee_account_allow_legacy, try_lst_surcharge, try_pair_fee_account,
+        try_program_state_allow_legacy, try_stake_pool_last_update_epoch, try_surge_config,
+    },
+};
+use solana_program::{
+    clock::Clock, epoch_schedule::EpochSchedule, instruction::AccountMeta, pubkey::Pubkey, sysvar,
 };
-use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_readonly_account::ReadonlyAccountData;
 use std::collections::HashMap;
 
@@ -26,6 +40,12 @@ pub struct FlatFeePricingProg {
     program_id: Pubkey,
     program_state: Option<ProgramState>, // value = None means ProgramState not yet fetched
     mints_to_fee_accounts: HashMap<Pubkey, Option<FeeAccount>>, // value = None means FeeAccount not yet fetched
+    surge_config: Option<SurgeConfig>, // value = None means SurgeConfig not yet fetched or not initialized
+    mints_to_lst_surcharges: HashMap<Pubkey, Option<LstSurcharge>>, // value = None means LstSurcharge not yet fetched or not initialized
+    stake_pools_last_update_epochs: HashMap<Pubkey, u64>,
+    pairs_to_pair_fee_accounts: HashMap<(Pubkey, Pubkey), (Pubkey, Option<PairFeeAccount>)>, // key = (input_lst_mint, output_lst_mint), value = (PairFeeAccount PDA, None if not yet fetched or not initialized)
+    clock: Clock,
+    epoch_schedule: EpochSchedule,
 }
 
 impl FlatFeePricingProg {
@@ -50,6 +70,194 @@ impl FlatFeePricingProg {
         self.program_state.as_ref()
     }
 
+    pub fn find_surge_config_addr(&self) -> Pubkey {
+        SurgeConfigFindPdaArgs {
+            program_id: self.program_id,
+        }
+        .get_surge_config_address_and_bump_seed()
+        .0
+    }
+
+    pub fn find_lst_surcharge_addr(&self, lst_mint: Pubkey) -> Pubkey {
+        LstSurchargeFindPdaArgs {
+            program_id: self.program_id,
+            lst_mint,
+        }
+        .get_lst_surcharge_address_and_bump_seed()
+        .0
+    }
+
+    #[inline]
+    pub const fn surge_config(&self) -> Option<&SurgeConfig> {
+        self.surge_config.as_ref()
+    }
+
+    #[inline]
+    pub fn lst_surcharge(&self, lst_mint: &Pubkey) -> Option<&LstSurcharge> {
+        self.mints_to_lst_surcharges
+            .get(lst_mint)
+            .and_then(|opt| opt.as_ref())
+    }
+
+    /// Overrides the cached clock, for use if the caller
+    /// does not fetch the clock sysvar via [`MutablePricingProg::update`]
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+
+    /// The LST's input and output fees in effect at the cached clock's epoch
+    pub fn lst_fees(&self, lst_mint: &Pubkey) -> Result<LstFees, FlatFeeError> {
+        Ok(fee_account_lst_fees(
+            self.get_fee_account_checked(lst_mint)?,
+            self.clock.epoch,
+        ))
+    }
+
+    /// The LST's scheduled fee change that is not yet in effect at the cached clock's epoch, if any
+    pub fn upcoming_lst_fees(
+        &self,
+        lst_mint: &Pubkey,
+    ) -> Result<Option<PendingFee<LstFees>>, FlatFeeError> {
+        Ok(fee_account_upcoming_lst_fees(
+            self.get_fee_account_checked(lst_mint)?,
+            self.clock.epoch,
+        ))
+    }
+
+    /// The global LP withdrawal fee in effect at the cached clock's epoch
+    pub fn lp_withdrawal_fee_bps(&self) -> Result<u16, FlatFeeError> {
+        let state = self
+            .program_state
+            .as_ref()
+            .ok_or(FlatFeeError::InvalidProgramStateData)?;
+        Ok(program_state_lp_withdrawal_fee_bps(state, self.clock.epoch))
+    }
+
+    /// The scheduled global LP withdrawal fee change that is not yet in effect
+    /// at the cached clock's epoch, if any
+    pub fn upcoming_lp_withdrawal_fee(&self) -> Result<Option<PendingFee<u16>>, FlatFeeError> {
+        let state = self
+            .program_state
+            .as_ref()
+            .ok_or(FlatFeeError::InvalidProgramStateData)?;
+        Ok(program_state_upcoming_lp_withdrawal_fee(
+            state,
+            self.clock.epoch,
+        ))
+    }
+
+    /// Sum of the current epoch surge fee and both LSTs' surcharges.
+    ///
+    /// LSTs whose stake pool has not been fetched yet are assumed
+    /// to not have been updated for the current epoch.
+    pub fn surge_fee_bps(
+        &self,
+        input_lst_mint: &Pubkey,
+        output_lst_mint: &Pubkey,
+    ) -> Result<u16, FlatFeeError> {
+        let epoch_surge_fee_bps = self.surge_config.as_ref().map_or(0, |surge_config| {
+            calculate_epoch_surge_fee_bps(
+                surge_config,
+                slots_elapsed_in_epoch(&self.clock, &self.epoch_schedule),
+            )
+        });
+        [
+            epoch_surge_fee_bps,
+            self.lst_surcharge_bps(input_lst_mint)?,
+            self.lst_surcharge_bps(output_lst_mint)?,
+        ]
+        .into_iter()
+        .try_fold(0u16, |acc, bps| acc.checked_add(bps))
+        .ok_or(FlatFeeError::MathError)
+    }
+
+    fn lst_surcharge_bps(&self, lst_mint: &Pubkey) -> Result<u16, FlatFeeError> {
+        let lst_surcharge = match self.lst_surcharge(lst_mint) {
+            Some(s) => s,
+            None => return Ok(0),
+        };
+        let last_update_epoch = match self
+            .stake_pools_last_update_epochs
+            .get(&lst_surcharge.stake_pool)
+        {
+            Some(e) => *e,
+            None => return Ok(lst_surcharge.surcharge_bps),
+        };
+        Ok(if last_update_epoch < self.clock.epoch {
+            lst_surcharge.surcharge_bps
+        } else {
+            0
+        })
+    }
+
+    /// Returns the stake pools to pass for (input, output),
+    /// defaulting to the LST mint for LSTs without a LstSurcharge account
+    fn stake_pools(&self, input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> (Pubkey, Pubkey) {
+        let stake_pool = |lst_mint: Pubkey| {
+            self.lst_surcharge(&lst_mint)
+                .map_or(lst_mint, |s| s.stake_pool)
+        };
+        (stake_pool(input_lst_mint), stake_pool(output_lst_mint))
+    }
+
+    /// Returns (input_bump, output_bump), None if the LST's LstSurcharge account is not initialized
+    fn cached_lst_surcharge_bumps(
+        &self,
+        input_lst_mint: &Pubkey,
+        output_lst_mint: &Pubkey,
+    ) -> (Option<u8>, Option<u8>) {
+        (
+            self.lst_surcharge(input_lst_mint).map(|s| s.bump),
+            self.lst_surcharge(output_lst_mint).map(|s| s.bump),
+        )
+    }
+
+    /// Returns the cached PairFeeAccount override for the given swap direction, if any
+    #[inline]
+    pub fn pair_fee_account(
+        &self,
+        input_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
+    ) -> Option<&PairFeeAccount> {
+        self.pairs_to_pair_fee_accounts
+            .get(&(input_lst_mint, output_lst_mint))
+            .and_then(|(_addr, opt)| opt.as_ref())
+    }
+
+    /// The PairFeeAccount to pass as remaining account to PriceExactIn/PriceExactOut
+    /// if the LST pair has a fee override
+    fn pair_fee_account_meta(
+        &self,
+        input_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
+    ) -> Option<AccountMeta> {
+        match self
+            .pairs_to_pair_fee_accounts
+            .get(&(input_lst_mint, output_lst_mint))
+        {
+            Some((addr, Some(_))) => Some(AccountMeta::new_readonly(*addr, false)),
+            _ => None,
+        }
+    }
+
+    fn pair_fee_accounts_for_mints(&self, lst_mints: &[Pubkey]) -> Vec<Pubkey> {
+        self.pairs_to_pair_fee_accounts
+            .iter()
+            .filter(|((input_lst_mint, output_lst_mint), _)| {
+                lst_mints.contains(input_lst_mint) || lst_mints.contains(output_lst_mint)
+            })
+            .map(|(_, (addr, _))| *addr)
+            .collect()
+    }
+
+    fn lst_surcharge_accounts_for_mint(&self, lst_mint: Pubkey) -> Vec<Pubkey> {
+        let mut res = vec![self.find_lst_surcharge_addr(lst_mint)];
+        if let Some(LstSurcharge { stake_pool, .. }) = self.lst_surcharge(&lst_mint) {
+            res.push(*stake_pool);
+        }
+        res
+    }
+
     /// Returns (input_bump, output_bump)
     fn get_cached_fee_account_bumps(
         &self,
@@ -102,21 +310,68 @@ impl MutablePricingProg for FlatFeePricingProg {
     where
         Self: Sized,
     {
+        let mints: Vec<Pubkey> = mints.collect();
+        let pairs_to_pair_fee_accounts = mints
+            .iter()
+            .flat_map(|input_lst_mint| {
+                mints
+                    .iter()
+                    .filter(move |output_lst_mint| *output_lst_mint != input_lst_mint)
+                    .map(move |output_lst_mint| {
+                        let (addr, _bump) = PairFeeAccountFindPdaArgs {
+                            program_id,
+                            input_lst_mint: *input_lst_mint,
+                            output_lst_mint: *output_lst_mint,
+                        }
+                        .get_pair_fee_account_address_and_bump_seed();
+                        ((*input_lst_mint, *output_lst_mint), (addr, None))
+                    })
+            })
+            .collect();
         Ok(Self {
             program_id,
             program_state: None,
-            mints_to_fee_accounts: mints.map(|pk| (pk, None)).collect(),
+            mints_to_fee_accounts: mints.into_iter().map(|pk| (pk, None)).collect(),
+            surge_config: None,
+            mints_to_lst_surcharges: HashMap::new(),
+            stake_pools_last_update_epochs: HashMap::new(),
+            pairs_to_pair_fee_accounts,
+            clock: Clock::default(),
+            epoch_schedule: EpochSchedule::without_warmup(),
         })
     }
 
     fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
-        vec![self.find_program_state_addr()]
+        self.mints_to_fee_accounts
+            .iter()
+            .map(|(lst_mint, fee_account_opt)| self.fee_account_for_mint(lst_mint, fee_account_opt))
+            .chain([
+                self.find_program_state_addr(),
+                self.find_surge_config_addr(),
+                sysvar::clock::ID,
+                sysvar::epoch_schedule::ID,
+            ])
+            .collect()
     }
 
     fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
         self.mints_to_fee_accounts
             .iter()
-            .map(|(lst_mint, fee_account_opt)| self.fee_account_for_mint(lst_mint, fee_account_opt))
+            .flat_map(|(lst_mint, fee_account_opt)| {
+                let mut res = vec![self.fee_account_for_mint(lst_mint, fee_account_opt)];
+                res.extend(self.lst_surcharge_accounts_for_mint(*lst_mint));
+                res
+            })
+            .chain(
+                self.pairs_to_pair_fee_accounts
+                    .values()
+                    .map(|(addr, _)| *addr),
+            )
+            .chain([
+                self.find_surge_config_addr(),
+                sysvar::clock::ID,
+                sysvar::epoch_schedule::ID,
+            ])
             .collect()
     }
 
@@ -124,14 +379,25 @@ impl MutablePricingProg for FlatFeePricingProg {
         &self,
         lst_mints: I,
     ) -> Vec<Pubkey> {
+        let lst_mints: Vec<Pubkey> = lst_mints.collect();
+        let pair_fee_accounts = self.pair_fee_accounts_for_mints(&lst_mints);
         lst_mints
-            .map(|lst_mint| {
+            .into_iter()
+            .flat_map(|lst_mint| {
                 let fee_account_opt = self
                     .mints_to_fee_accounts
                     .get(&lst_mint)
                     .map_or_else(|| &None, |opt| opt);
-                self.fee_account_for_mint(&lst_mint, fee_account_opt)
+                let mut res = vec![self.fee_account_for_mint(&lst_mint, fee_account_opt)];
+                res.extend(self.lst_surcharge_accounts_for_mint(lst_mint));
+                res
             })
+            .chain(pair_fee_accounts)
+            .chain([
+                self.find_surge_config_addr(),
+                sysvar::clock::ID,
+                sysvar::epoch_schedule::ID,
+            ])
             .collect()
     }
 
@@ -141,7 +407,7 @@ impl MutablePricingProg for FlatFeePricingProg {
     ) -> anyhow::Result<()> {
         let psa = self.find_program_state_addr();
         if let Some(acc) = account_map.get(&psa) {
-            self.program_state = Some(*try_program_state(&acc.data())?);
+            self.program_state = Some(try_program_state_allow_legacy(&acc.data())?);
         }
 
         for (lst_mint, fee_account_opt) in self.mints_to_fee_accounts.iter_mut() {
@@ -158,7 +424,59 @@ impl MutablePricingProg for FlatFeePricingProg {
                 None => find_pda_args.get_fee_account_address_and_bump_seed().0,
             };
             if let Some(acc) = account_map.get(&faa) {
-                *fee_account_opt = Some(*try_fee_account(&acc.data())?);
+                *fee_account_opt = Some(try_fee_account_allow_legacy(&acc.data())?);
+            }
+        }
+
+        if let Some(acc) = account_map.get(&sysvar::clock::ID) {
+            self.clock = bincode::deserialize(&acc.data())?;
+        }
+        if let Some(acc) = account_map.get(&sysvar::epoch_schedule::ID) {
+            self.epoch_schedule = bincode::deserialize(&acc.data())?;
+        }
+
+        if let Some(acc) = account_map.get(&self.find_surge_config_addr()) {
+            let data = acc.data();
+            self.surge_config = if data.is_empty() {
+                None
+            } else {
+                Some(*try_surge_config(&data)?)
+            };
+        }
+
+        let lst_mints: Vec<Pubkey> = self.mints_to_fee_accounts.keys().copied().collect();
+        for lst_mint in lst_mints {
+            if let Some(acc) = account_map.get(&self.find_lst_surcharge_addr(lst_mint)) {
+                let data = acc.data();
+                let lst_surcharge = if data.is_empty() {
+                    None
+                } else {
+                    Some(*try_lst_surcharge(&data)?)
+                };
+                self.mints_to_lst_surcharges.insert(lst_mint, lst_surcharge);
+            }
+            let lst_surcharge = match self.lst_surcharge(&lst_mint) {
+                Some(s) => *s,
+                None => continue,
+            };
+            if let Some(acc) = account_map.get(&lst_surcharge.stake_pool) {
+                let last_update_epoch = try_stake_pool_last_update_epoch(
+                    &acc.data(),
+                    lst_surcharge.last_update_epoch_offset,
+                )?;
+                self.stake_pools_last_update_epochs
+                    .insert(lst_surcharge.stake_pool, last_update_epoch);
+            }
+        }
+
+        for (addr, pair_fee_account_opt) in self.pairs_to_pair_fee_accounts.values_mut() {
+            if let Some(acc) = account_map.get(addr) {
+                let data = acc.data();
+                *pair_fee_account_opt = if data.is_empty() {
+                    None
+                } else {
+                    Some(*try_pair_fee_account(&data)?)
+                };
             }
         }
 
@@ -173,49 +491,76 @@ impl PricingProg for FlatFeePricingProg {
 
     fn quote_lp_tokens_to_redeem(
         &self,
-        _output_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
         pricing_programs_interface::PriceLpTokensToRedeemIxArgs { sol_value, .. }: &pricing_programs_interface::PriceLpTokensToRedeemIxArgs,
     ) -> anyhow::Result<u64> {
-        let lp_withdrawal_fee_bps = self
-            .program_state
-            .ok_or(FlatFeeError::InvalidProgramStateData)?
-            .lp_withdrawal_fee_bps;
+        let lp_withdrawal_fee_bps = effective_lp_withdrawal_fee_bps(
+            self.lp_withdrawal_fee_bps()?,
+            self.get_fee_account_checked(&output_lst_mint)?,
+        );
+        let surge_fee_bps = self.surge_config.as_ref().map_or(0, |surge_config| {
+            calculate_epoch_surge_fee_bps(
+                surge_config,
+                slots_elapsed_in_epoch(&self.clock, &self.epoch_schedule),
+            )
+        });
         Ok(calculate_price_lp_tokens_to_redeem(
             lp_withdrawal_fee_bps,
+            surge_fee_bps,
             *sol_value,
         )?)
     }
 
     fn price_lp_tokens_to_redeem_accounts(
         &self,
-        output_lst_mint: Pubkey,
+        keys: pricing_programs_interface::PriceLpTokensToRedeemKeys,
     ) -> anyhow::Result<Vec<AccountMeta>> {
-        Ok(
-            <[AccountMeta; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]>::from(
-                PriceLpTokensToRedeemFreeArgs { output_lst_mint }.resolve_for_prog(self.program_id),
-            )
-            .into(),
-        )
+        let args = PriceLpTokensToRedeemFreeArgs::from(keys);
+        let keys = match self.get_fee_account_checked(&args.output_lst_mint) {
+            Ok(FeeAccount { bump, .. }) => PriceLpTokensToRedeemWithBumpFreeArgs {
+                args,
+                fee_acc_bump: *bump,
+            }
+            .resolve_for_prog(self.program_id)?,
+            Err(_) => args.resolve_for_prog(self.program_id),
+        };
+        Ok(<[AccountMeta; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]>::from(keys).into())
     }
 
     fn quote_lp_tokens_to_mint(
         &self,
-        _input_lst_mint: Pubkey,
+        input_lst_mint: Pubkey,
         pricing_programs_interface::PriceLpTokensToMintIxArgs { sol_value, .. }: &pricing_programs_interface::PriceLpTokensToMintIxArgs,
     ) -> anyhow::Result<u64> {
-        Ok(*sol_value)
+        let lp_deposit_fee_bps = self
+            .program_state
+            .ok_or(FlatFeeError::InvalidProgramStateData)?
+            .lp_deposit_fee_bps;
+        let FeeAccount {
+            lp_deposit_fee_bps: lst_lp_deposit_fee_bps,
+            ..
+        } = self.get_fee_account_checked(&input_lst_mint)?;
+        Ok(calculate_price_lp_tokens_to_mint(
+            lp_deposit_fee_bps,
+            *lst_lp_deposit_fee_bps,
+            *sol_value,
+        )?)
     }
 
     fn price_lp_tokens_to_mint_accounts(
         &self,
-        input_lst_mint: Pubkey,
+        keys: pricing_programs_interface::PriceLpTokensToMintKeys,
     ) -> anyhow::Result<Vec<AccountMeta>> {
-        Ok(
-            <[AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>::from(
-                PriceLpTokensToMintKeys { input_lst_mint },
-            )
-            .into(),
-        )
+        let args = PriceLpTokensToMintFreeArgs::from(keys);
+        let keys = match self.get_fee_account_checked(&args.input_lst_mint) {
+            Ok(FeeAccount { bump, .. }) => PriceLpTokensToMintWithBumpFreeArgs {
+                args,
+                fee_acc_bump: *bump,
+            }
+            .resolve_for_prog(self.program_id)?,
+            Err(_) => args.resolve_for_prog(self.program_id),
+        };
+        Ok(<[AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>::from(keys).into())
     }
 
     fn quote_exact_in(
@@ -223,39 +568,58 @@ impl PricingProg for FlatFeePricingProg {
         pricing_programs_interface::PriceExactInKeys {
             input_lst_mint,
             output_lst_mint,
+            ..
         }: pricing_programs_interface::PriceExactInKeys,
         pricing_programs_interface::PriceExactInIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactInIxArgs,
     ) -> anyhow::Result<u64> {
-        let FeeAccount { input_fee_bps, .. } = self.get_fee_account_checked(&input_lst_mint)?;
-        let FeeAccount { output_fee_bps, .. } = self.get_fee_account_checked(&output_lst_mint)?;
+        let LstFees { input_fee_bps, .. } = self.lst_fees(&input_lst_mint)?;
+        let LstFees { output_fee_bps, .. } = self.lst_fees(&output_lst_mint)?;
         Ok(calculate_price_exact_in(CalculatePriceExactInArgs {
-            input_fee_bps: *input_fee_bps,
-            output_fee_bps: *output_fee_bps,
+            input_fee_bps,
+            output_fee_bps,
+            pair_fee_bps: self
+                .pair_fee_account(input_lst_mint, output_lst_mint)
+                .map(|p| p.fee_bps),
+            surge_fee_bps: self.surge_fee_bps(&input_lst_mint, &output_lst_mint)?,
             in_sol_value: *sol_value,
         })?)
     }
 
     fn price_exact_in_accounts(
         &self,
-        pricing_programs_interface::PriceExactInKeys {
-            input_lst_mint,
-            output_lst_mint,
-        }: pricing_programs_interface::PriceExactInKeys,
+        keys: pricing_programs_interface::PriceExactInKeys,
     ) -> anyhow::Result<Vec<AccountMeta>> {
-        let args = PriceExactInFreeArgs {
+        let pricing_programs_interface::PriceExactInKeys {
             input_lst_mint,
             output_lst_mint,
+            ..
+        } = keys;
+        let (input_stake_pool, output_stake_pool) =
+            self.stake_pools(input_lst_mint, output_lst_mint);
+        let args = PriceExactInFreeArgs {
+            input_stake_pool,
+            output_stake_pool,
+            ..PriceExactInFreeArgs::from(keys)
         };
         let keys = match self.get_cached_fee_account_bumps(input_lst_mint, output_lst_mint) {
-            Some((input_fee_acc_bump, output_fee_acc_bump)) => PriceExactInWithBumpFreeArgs {
-                args,
-                input_fee_acc_bump,
-                output_fee_acc_bump,
+            Some((input_fee_acc_bump, output_fee_acc_bump)) => {
+                let (input_surcharge_acc_bump, output_surcharge_acc_bump) =
+                    self.cached_lst_surcharge_bumps(&input_lst_mint, &output_lst_mint);
+                PriceExactInWithBumpFreeArgs {
+                    args,
+                    input_fee_acc_bump,
+                    output_fee_acc_bump,
+                    input_surcharge_acc_bump,
+                    output_surcharge_acc_bump,
+                }
+                .resolve_for_prog(self.program_id)?
             }
-            .resolve()?,
-            None => args.resolve(),
+            None => args.resolve_for_prog(self.program_id),
         };
-        Ok(<[AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]>::from(keys).into())
+        let mut metas: Vec<AccountMeta> =
+            <[AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]>::from(keys).into();
+        metas.extend(self.pair_fee_account_meta(input_lst_mint, output_lst_mint));
+        Ok(metas)
     }
 
     fn quote_exact_out(
@@ -263,39 +627,58 @@ impl PricingProg for FlatFeePricingProg {
         pricing_programs_interface::PriceExactOutKeys {
             input_lst_mint,
             output_lst_mint,
+            ..
         }: pricing_programs_interface::PriceExactOutKeys,
         pricing_programs_interface::PriceExactOutIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactOutIxArgs,
     ) -> anyhow::Result<u64> {
-        let FeeAccount { input_fee_bps, .. } = self.get_fee_account_checked(&input_lst_mint)?;
-        let FeeAccount { output_fee_bps, .. } = self.get_fee_account_checked(&output_lst_mint)?;
+        let LstFees { input_fee_bps, .. } = self.lst_fees(&input_lst_mint)?;
+        let LstFees { output_fee_bps, .. } = self.lst_fees(&output_lst_mint)?;
         Ok(calculate_price_exact_out(CalculatePriceExactOutArgs {
-            input_fee_bps: *input_fee_bps,
-            output_fee_bps: *output_fee_bps,
+            input_fee_bps,
+            output_fee_bps,
+            pair_fee_bps: self
+                .pair_fee_account(input_lst_mint, output_lst_mint)
+                .map(|p| p.fee_bps),
+            surge_fee_bps: self.surge_fee_bps(&input_lst_mint, &output_lst_mint)?,
             out_sol_value: *sol_value,
         })?)
     }
 
     fn price_exact_out_accounts(
         &self,
-        pricing_programs_interface::PriceExactOutKeys {
-            input_lst_mint,
-            output_lst_mint,
-        }: pricing_programs_interface::PriceExactOutKeys,
+        keys: pricing_programs_interface::PriceExactOutKeys,
     ) -> anyhow::Result<Vec<AccountMeta>> {
-        let args = PriceExactOutFreeArgs {
+        let pricing_programs_interface::PriceExactOutKeys {
             input_lst_mint,
             output_lst_mint,
+            ..
+        } = keys;
+        let (input_stake_pool, output_stake_pool) =
+            self.stake_pools(input_lst_mint, output_lst_mint);
+        let args = PriceExactOutFreeArgs {
+            input_stake_pool,
+            output_stake_pool,
+            ..PriceExactOutFreeArgs::from(keys)
         };
         let keys = match self.get_cached_fee_account_bumps(input_lst_mint, output_lst_mint) {
-            Some((input_fee_acc_bump, output_fee_acc_bump)) => PriceExactOutWithBumpFreeArgs {
-                args,
-                input_fee_acc_bump,
-                output_fee_acc_bump,
+            Some((input_fee_acc_bump, output_fee_acc_bump)) => {
+                let (input_surcharge_acc_bump, output_surcharge_acc_bump) =
+                    self.cached_lst_surcharge_bumps(&input_lst_mint, &output_lst_mint);
+                PriceExactOutWithBumpFreeArgs {
+                    args,
+                    input_fee_acc_bump,
+                    output_fee_acc_bump,
+                    input_surcharge_acc_bump,
+                    output_surcharge_acc_bump,
+                }
+                .resolve_for_prog(self.program_id)?
             }
-            .resolve()?,
-            None => args.resolve(),
+            None => args.resolve_for_prog(self.program_id),
         };
-        Ok(<[AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]>::from(keys).into())
+        let mut metas: Vec<AccountMeta> =
+            <[AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]>::from(keys).into();
+        metas.extend(self.pair_fee_account_meta(input_lst_mint, output_lst_mint));
+        Ok(metas)
     }
 }
 
@@ -305,8 +688,7 @@ impl TryFrom<KnownPricingProg> for FlatFeePricingProg {
     fn try_from(value: KnownPricingProg) -> Result<Self, Self::Error> {
         match value {
             KnownPricingProg::FlatFee(f) => Ok(f),
-            // TODO: uncomment when we add more variants
-            // _ => Err(PricingProgErr::WrongPricingProg),
+            _ => Err(PricingProgErr::WrongPricingProg),
         }
     }
 }
@@ -1,46 +1,95 @@
+use no_fee_pricing_program_keys::no_fee_pricing_program;
 use pricing_programs_interface::{
     PriceExactInIxArgs, PriceExactInKeys, PriceExactOutIxArgs, PriceExactOutKeys,
-    PriceLpTokensToMintIxArgs, PriceLpTokensToRedeemIxArgs,
+    PriceLpTokensToMintIxArgs, PriceLpTokensToMintKeys, PriceLpTokensToRedeemIxArgs,
+    PriceLpTokensToRedeemKeys,
 };
 use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_readonly_account::ReadonlyAccountData;
 use std::collections::HashMap;
 
+mod custom;
+mod dynamic_fee;
 mod err;
 mod flat_fee;
+mod no_fee;
+mod tiered_fee;
 mod traits;
 
+pub use custom::*;
+pub use dynamic_fee::*;
 pub use err::*;
 pub use flat_fee::*;
+pub use no_fee::*;
+pub use tiered_fee::*;
 pub use traits::*;
 
 #[derive(Clone, Debug)]
 pub enum KnownPricingProg {
-    FlatFee(FlatFeePricingProg), // only variant for now
+    DynamicFee(DynamicFeePricingProg),
+    FlatFee(FlatFeePricingProg),
+    NoFee(NoFeePricingProg),
+    TieredFee(TieredFeePricingProg),
+    /// A pricing program not known to this crate,
+    /// created by the [`CustomPricingProgFactory`] passed to [`Self::try_new_with_custom`]
+    Custom(Box<dyn CustomPricingProg>),
 }
 
-impl MutablePricingProg for KnownPricingProg {
-    fn try_new<I: Iterator<Item = Pubkey>>(
+impl KnownPricingProg {
+    /// Like [`MutablePricingProg::try_new`], but falls back to `custom_factory`, if any,
+    /// for pricing programs not known to this crate
+    pub fn try_new_with_custom<I: Iterator<Item = Pubkey>>(
         program_id: Pubkey,
         mints: I,
+        custom_factory: Option<CustomPricingProgFactory>,
     ) -> Result<Self, PricingProgErr> {
         Ok(match program_id {
+            dynamic_fee_lib::program::ID => {
+                Self::DynamicFee(DynamicFeePricingProg::try_new(program_id, mints)?)
+            }
             flat_fee_lib::program::ID => {
                 Self::FlatFee(FlatFeePricingProg::try_new(program_id, mints)?)
             }
-            _ => Err(PricingProgErr::UnknownPricingProg)?,
+            no_fee_pricing_program::ID => {
+                Self::NoFee(NoFeePricingProg::try_new(program_id, mints)?)
+            }
+            tiered_fee_lib::program::ID => {
+                Self::TieredFee(TieredFeePricingProg::try_new(program_id, mints)?)
+            }
+            _ => {
+                let factory = custom_factory.ok_or(PricingProgErr::UnknownPricingProg)?;
+                let mints: Vec<Pubkey> = mints.collect();
+                Self::Custom(factory(program_id, &mints)?)
+            }
         })
     }
+}
+
+impl MutablePricingProg for KnownPricingProg {
+    fn try_new<I: Iterator<Item = Pubkey>>(
+        program_id: Pubkey,
+        mints: I,
+    ) -> Result<Self, PricingProgErr> {
+        Self::try_new_with_custom(program_id, mints, None)
+    }
 
     fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
         match self {
+            Self::DynamicFee(p) => p.get_accounts_to_update_for_all_lsts(),
             Self::FlatFee(p) => p.get_accounts_to_update_for_all_lsts(),
+            Self::NoFee(p) => p.get_accounts_to_update_for_all_lsts(),
+            Self::TieredFee(p) => p.get_accounts_to_update_for_all_lsts(),
+            Self::Custom(p) => p.get_accounts_to_update_for_all_lsts(),
         }
     }
 
     fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
         match self {
+            Self::DynamicFee(p) => p.get_accounts_to_update_for_liquidity(),
             Self::FlatFee(p) => p.get_accounts_to_update_for_liquidity(),
+            Self::NoFee(p) => p.get_accounts_to_update_for_liquidity(),
+            Self::TieredFee(p) => p.get_accounts_to_update_for_liquidity(),
+            Self::Custom(p) => p.get_accounts_to_update_for_liquidity(),
         }
     }
 
@@ -49,7 +98,11 @@ impl MutablePricingProg for KnownPricingProg {
         lst_mints: I,
     ) -> Vec<Pubkey> {
         match self {
+            Self::DynamicFee(p) => p.get_accounts_to_update_for_lsts(lst_mints),
             Self::FlatFee(p) => p.get_accounts_to_update_for_lsts(lst_mints),
+            Self::NoFee(p) => p.get_accounts_to_update_for_lsts(lst_mints),
+            Self::TieredFee(p) => p.get_accounts_to_update_for_lsts(lst_mints),
+            Self::Custom(p) => p.get_accounts_to_update_for_lsts(&lst_mints.collect::<Vec<_>>()),
         }
     }
 
@@ -58,7 +111,11 @@ impl MutablePricingProg for KnownPricingProg {
         account_map: &HashMap<Pubkey, D>,
     ) -> anyhow::Result<()> {
         match self {
+            Self::DynamicFee(p) => p.update(account_map),
             Self::FlatFee(p) => p.update(account_map),
+            Self::NoFee(p) => p.update(account_map),
+            Self::TieredFee(p) => p.update(account_map),
+            Self::Custom(p) => update_custom_pricing_prog(p.as_mut(), account_map),
         }
     }
 }
@@ -66,7 +123,11 @@ impl MutablePricingProg for KnownPricingProg {
 impl PricingProg for KnownPricingProg {
     fn pricing_program_id(&self) -> Pubkey {
         match self {
+            Self::DynamicFee(p) => p.pricing_program_id(),
             Self::FlatFee(p) => p.pricing_program_id(),
+            Self::NoFee(p) => p.pricing_program_id(),
+            Self::TieredFee(p) => p.pricing_program_id(),
+            Self::Custom(p) => p.pricing_program_id(),
         }
     }
 
@@ -76,16 +137,24 @@ impl PricingProg for KnownPricingProg {
         args: &PriceLpTokensToRedeemIxArgs,
     ) -> anyhow::Result<u64> {
         match self {
+            Self::DynamicFee(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
             Self::FlatFee(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
+            Self::NoFee(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
+            Self::TieredFee(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
+            Self::Custom(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
         }
     }
 
     fn price_lp_tokens_to_redeem_accounts(
         &self,
-        output_lst_mint: Pubkey,
+        keys: PriceLpTokensToRedeemKeys,
     ) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
-            Self::FlatFee(p) => p.price_lp_tokens_to_redeem_accounts(output_lst_mint),
+            Self::DynamicFee(p) => p.price_lp_tokens_to_redeem_accounts(keys),
+            Self::FlatFee(p) => p.price_lp_tokens_to_redeem_accounts(keys),
+            Self::NoFee(p) => p.price_lp_tokens_to_redeem_accounts(keys),
+            Self::TieredFee(p) => p.price_lp_tokens_to_redeem_accounts(keys),
+            Self::Custom(p) => p.price_lp_tokens_to_redeem_accounts(keys),
         }
     }
 
@@ -95,16 +164,24 @@ impl PricingProg for KnownPricingProg {
         args: &PriceLpTokensToMintIxArgs,
     ) -> anyhow::Result<u64> {
         match self {
+            Self::DynamicFee(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
             Self::FlatFee(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
+            Self::NoFee(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
+            Self::TieredFee(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
+            Self::Custom(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
         }
     }
 
     fn price_lp_tokens_to_mint_accounts(
         &self,
-        input_lst_mint: Pubkey,
+        keys: PriceLpTokensToMintKeys,
     ) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
-            Self::FlatFee(p) => p.price_lp_tokens_to_mint_accounts(input_lst_mint),
+            Self::DynamicFee(p) => p.price_lp_tokens_to_mint_accounts(keys),
+            Self::FlatFee(p) => p.price_lp_tokens_to_mint_accounts(keys),
+            Self::NoFee(p) => p.price_lp_tokens_to_mint_accounts(keys),
+            Self::TieredFee(p) => p.price_lp_tokens_to_mint_accounts(keys),
+            Self::Custom(p) => p.price_lp_tokens_to_mint_accounts(keys),
         }
     }
 
@@ -114,13 +191,21 @@ impl PricingProg for KnownPricingProg {
         args: &PriceExactInIxArgs,
     ) -> anyhow::Result<u64> {
         match self {
+            Self::DynamicFee(p) => p.quote_exact_in(keys, args),
             Self::FlatFee(p) => p.quote_exact_in(keys, args),
+            Self::NoFee(p) => p.quote_exact_in(keys, args),
+            Self::TieredFee(p) => p.quote_exact_in(keys, args),
+            Self::Custom(p) => p.quote_exact_in(keys, args),
         }
     }
 
     fn price_exact_in_accounts(&self, keys: PriceExactInKeys) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
+            Self::DynamicFee(p) => p.price_exact_in_accounts(keys),
             Self::FlatFee(p) => p.price_exact_in_accounts(keys),
+            Self::NoFee(p) => p.price_exact_in_accounts(keys),
+            Self::TieredFee(p) => p.price_exact_in_accounts(keys),
+            Self::Custom(p) => p.price_exact_in_accounts(keys),
         }
     }
 
@@ -130,7 +215,11 @@ impl PricingProg for KnownPricingProg {
         args: &PriceExactOutIxArgs,
     ) -> anyhow::Result<u64> {
         match self {
+            Self::DynamicFee(p) => p.quote_exact_out(keys, args),
             Self::FlatFee(p) => p.quote_exact_out(keys, args),
+            Self::NoFee(p) => p.quote_exact_out(keys, args),
+            Self::TieredFee(p) => p.quote_exact_out(keys, args),
+            Self::Custom(p) => p.quote_exact_out(keys, args),
         }
     }
 
@@ -139,7 +228,11 @@ impl PricingProg for KnownPricingProg {
         keys: PriceExactOutKeys,
     ) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
+            Self::DynamicFee(p) => p.price_exact_out_accounts(keys),
             Self::FlatFee(p) => p.price_exact_out_accounts(keys),
+            Self::NoFee(p) => p.price_exact_out_accounts(keys),
+            Self::TieredFee(p) => p.price_exact_out_accounts(keys),
+            Self::Custom(p) => p.price_exact_out_accounts(keys),
         }
     }
 }
@@ -149,3 +242,27 @@ impl From<FlatFeePricingProg> for KnownPricingProg {
         Self::FlatFee(value)
     }
 }
+
+impl From<NoFeePricingProg> for KnownPricingProg {
+    fn from(value: NoFeePricingProg) -> Self {
+        Self::NoFee(value)
+    }
+}
+
+impl From<Box<dyn CustomPricingProg>> for KnownPricingProg {
+    fn from(value: Box<dyn CustomPricingProg>) -> Self {
+        Self::Custom(value)
+    }
+}
+
+impl From<DynamicFeePricingProg> for KnownPricingProg {
+    fn from(value: DynamicFeePricingProg) -> Self {
+        Self::DynamicFee(value)
+    }
+}
+
+impl From<TieredFeePricingProg> for KnownPricingProg {
+    fn from(value: TieredFeePricingProg) -> Self {
+        Self::TieredFee(value)
+    }
+}
@@ -2,7 +2,8 @@ use std::collections::HashMap;
 
 use pricing_programs_interface::{
     PriceExactInIxArgs, PriceExactInKeys, PriceExactOutIxArgs, PriceExactOutKeys,
-    PriceLpTokensToMintIxArgs, PriceLpTokensToRedeemIxArgs,
+    PriceLpTokensToMintIxArgs, PriceLpTokensToMintKeys, PriceLpTokensToRedeemIxArgs,
+    PriceLpTokensToRedeemKeys,
 };
 use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_readonly_account::ReadonlyAccountData;
@@ -90,7 +91,7 @@ pub trait PricingProg {
     /// This should exclude the program_id and include the common interface account prefixes
     fn price_lp_tokens_to_redeem_accounts(
         &self,
-        output_lst_mint: Pubkey,
+        keys: PriceLpTokensToRedeemKeys,
     ) -> anyhow::Result<Vec<AccountMeta>>;
 
     /// Returns SOL value of the LP tokens to mint
@@ -106,7 +107,7 @@ pub trait PricingProg {
     /// This should exclude the program_id and include the common interface account prefixes
     fn price_lp_tokens_to_mint_accounts(
         &self,
-        input_lst_mint: Pubkey,
+        keys: PriceLpTokensToMintKeys,
     ) -> anyhow::Result<Vec<AccountMeta>>;
 
     /// Returns SOL value of the output LST
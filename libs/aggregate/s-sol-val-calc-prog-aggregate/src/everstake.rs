@@ -0,0 +1,178 @@
+use everstake_calculator_lib::{
+    deserialize_everstake_stake_pool, deserialize_everstake_stake_pool_checked,
+    EverstakeSolValCalc, EverstakeStakePoolCalc,
+};
+use generic_pool_calculator_interface::GenericPoolCalculatorError;
+use generic_pool_calculator_lib::{
+    account_resolvers::LstSolCommonIntermediateKeys, utils::read_stale_discount_bps,
+    GenericPoolSolValCalc,
+};
+use sanctum_token_ratio::U64ValueRange;
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+use spl_calculator_lib::resolve_to_account_metas_for_calc;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+
+#[derive(Clone, Debug, Default)]
+pub struct EverstakeLstSolValCalc {
+    pub lst_mint: Pubkey,
+    pub stake_pool_addr: Pubkey,
+    pub calc: Option<EverstakeStakePoolCalc>,
+    /// Read from the calculator program's CalculatorState, 0 until fetched
+    pub stale_discount_bps: u16,
+    pub shared_current_epoch: Arc<AtomicU64>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EverstakeLstSolValCalcInitKeys {
+    pub lst_mint: Pubkey,
+    pub stake_pool_addr: Pubkey,
+}
+
+impl EverstakeLstSolValCalc {
+    #[inline]
+    pub const fn from_keys(
+        EverstakeLstSolValCalcInitKeys {
+            lst_mint,
+            stake_pool_addr,
+        }: EverstakeLstSolValCalcInitKeys,
+        shared_current_epoch: Arc<AtomicU64>,
+    ) -> Self {
+        Self {
+            lst_mint,
+            stake_pool_addr,
+            calc: None,
+            stale_discount_bps: 0,
+            shared_current_epoch,
+        }
+    }
+
+    #[inline]
+    pub fn from_pool<P: ReadonlyAccountData + ReadonlyAccountPubkey + ReadonlyAccountOwner>(
+        pool_acc: P,
+        shared_current_epoch: Arc<AtomicU64>,
+    ) -> Result<Self, GenericPoolCalculatorError> {
+        let stake_pool_addr = *pool_acc.pubkey();
+        let pool = deserialize_everstake_stake_pool_checked(pool_acc)?;
+        Ok(Self {
+            lst_mint: pool.pool_mint,
+            stake_pool_addr,
+            calc: Some(EverstakeStakePoolCalc::from(pool)),
+            stale_discount_bps: 0,
+            shared_current_epoch,
+        })
+    }
+
+    #[inline]
+    pub fn current_epoch(&self) -> u64 {
+        self.shared_current_epoch.load(Ordering::Relaxed)
+    }
+}
+
+impl MutableLstSolValCalc for EverstakeLstSolValCalc {
+    #[inline]
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        vec![
+            self.stake_pool_addr,
+            EverstakeSolValCalc::CALCULATOR_STATE_PDA,
+        ]
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        if let Some(acc) = account_map.get(&self.stake_pool_addr) {
+            let pool = deserialize_everstake_stake_pool(acc)?;
+            if pool.pool_mint != self.lst_mint {
+                return Err(EverstakeLstSolValCalcErr::WrongLstMint.into());
+            }
+            self.calc = Some(EverstakeStakePoolCalc::from(pool));
+        }
+        if let Some(acc) = account_map.get(&EverstakeSolValCalc::CALCULATOR_STATE_PDA) {
+            self.stale_discount_bps = read_stale_discount_bps(acc)?;
+        }
+        Ok(())
+    }
+}
+
+impl LstSolValCalc for EverstakeLstSolValCalc {
+    #[inline]
+    fn sol_value_calculator_program_id(&self) -> Pubkey {
+        everstake_calculator_lib::program::ID
+    }
+
+    #[inline]
+    fn lst_mint(&self) -> Pubkey {
+        self.lst_mint
+    }
+
+    fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self
+            .calc
+            .ok_or(EverstakeLstSolValCalcErr::StakePoolNotFetched)?
+            .verify_pool_updated_or_stale_bounded(self.current_epoch(), self.stale_discount_bps)?;
+        Ok(calc.calc_lst_to_sol(lst_amount)?)
+    }
+
+    fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self
+            .calc
+            .ok_or(EverstakeLstSolValCalcErr::StakePoolNotFetched)?
+            .verify_pool_updated_or_stale_bounded(self.current_epoch(), self.stale_discount_bps)?;
+        Ok(calc.calc_sol_to_lst(lamports)?)
+    }
+
+    fn ix_accounts(&self) -> Vec<AccountMeta> {
+        Vec::from(resolve_to_account_metas_for_calc::<EverstakeSolValCalc>(
+            LstSolCommonIntermediateKeys {
+                lst_mint: self.lst_mint,
+                pool_state: self.stake_pool_addr,
+            },
+        ))
+    }
+
+    #[inline]
+    fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
+        self.calc.as_ref().map(|c| c as &dyn SolValueCalculator)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum EverstakeLstSolValCalcErr {
+    WrongLstMint,
+    StakePoolNotFetched,
+}
+
+impl Display for EverstakeLstSolValCalcErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WrongLstMint => f.write_str("LST mint and stake pool does not match"),
+            Self::StakePoolNotFetched => f.write_str("stake pool not yet fetched"),
+        }
+    }
+}
+
+impl Error for EverstakeLstSolValCalcErr {}
+
+impl TryFrom<KnownLstSolValCalc> for EverstakeLstSolValCalc {
+    type Error = LstSolValCalcErr;
+
+    fn try_from(value: KnownLstSolValCalc) -> Result<Self, Self::Error> {
+        match value {
+            KnownLstSolValCalc::Everstake(s) => Ok(s),
+            _ => Err(LstSolValCalcErr::WrongLstSolValCalc),
+        }
+    }
+}
@@ -6,20 +6,28 @@ use solana_readonly_account::ReadonlyAccountData;
 use std::collections::HashMap;
 
 mod err;
+mod everstake;
 mod lido;
 mod marinade;
+mod posted_rate;
+mod s_lp;
 mod sanctum_spl;
 mod sanctum_spl_multi;
 mod spl;
+mod svsp;
 mod traits;
 mod wsol;
 
 pub use err::*;
+pub use everstake::*;
 pub use lido::*;
 pub use marinade::*;
+pub use posted_rate::*;
+pub use s_lp::*;
 pub use sanctum_spl::*;
 pub use sanctum_spl_multi::*;
 pub use spl::*;
+pub use svsp::*;
 pub use traits::*;
 pub use wsol::*;
 
@@ -31,6 +39,10 @@ pub enum KnownLstSolValCalc {
     SanctumSpl(SanctumSplLstSolValCalc),
     Wsol(WsolLstSolValCalc),
     SanctumSplMulti(SanctumSplMultiLstSolValCalc),
+    Svsp(SvspLstSolValCalc),
+    Everstake(EverstakeLstSolValCalc),
+    PostedRate(PostedRateLstSolValCalc),
+    SLp(SLpLstSolValCalc),
 }
 
 impl MutableLstSolValCalc for KnownLstSolValCalc {
@@ -42,6 +54,10 @@ impl MutableLstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.get_accounts_to_update(),
             Self::Wsol(s) => s.get_accounts_to_update(),
             Self::SanctumSplMulti(s) => s.get_accounts_to_update(),
+            Self::Svsp(s) => s.get_accounts_to_update(),
+            Self::Everstake(s) => s.get_accounts_to_update(),
+            Self::PostedRate(s) => s.get_accounts_to_update(),
+            Self::SLp(s) => s.get_accounts_to_update(),
         }
     }
 
@@ -56,6 +72,10 @@ impl MutableLstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.update(account_map),
             Self::Wsol(s) => s.update(account_map),
             Self::SanctumSplMulti(s) => s.update(account_map),
+            Self::Svsp(s) => s.update(account_map),
+            Self::Everstake(s) => s.update(account_map),
+            Self::PostedRate(s) => s.update(account_map),
+            Self::SLp(s) => s.update(account_map),
         }
     }
 }
@@ -69,6 +89,10 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.sol_value_calculator_program_id(),
             Self::Wsol(s) => s.sol_value_calculator_program_id(),
             Self::SanctumSplMulti(s) => s.sol_value_calculator_program_id(),
+            Self::Svsp(s) => s.sol_value_calculator_program_id(),
+            Self::Everstake(s) => s.sol_value_calculator_program_id(),
+            Self::PostedRate(s) => s.sol_value_calculator_program_id(),
+            Self::SLp(s) => s.sol_value_calculator_program_id(),
         }
     }
 
@@ -80,6 +104,10 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.lst_mint(),
             Self::Wsol(s) => s.lst_mint(),
             Self::SanctumSplMulti(s) => s.lst_mint(),
+            Self::Svsp(s) => s.lst_mint(),
+            Self::Everstake(s) => s.lst_mint(),
+            Self::PostedRate(s) => s.lst_mint(),
+            Self::SLp(s) => s.lst_mint(),
         }
     }
 
@@ -91,6 +119,10 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.lst_to_sol(lst_amount),
             Self::Wsol(s) => s.lst_to_sol(lst_amount),
             Self::SanctumSplMulti(s) => s.lst_to_sol(lst_amount),
+            Self::Svsp(s) => s.lst_to_sol(lst_amount),
+            Self::Everstake(s) => s.lst_to_sol(lst_amount),
+            Self::PostedRate(s) => s.lst_to_sol(lst_amount),
+            Self::SLp(s) => s.lst_to_sol(lst_amount),
         }
     }
 
@@ -102,6 +134,10 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.sol_to_lst(lamports),
             Self::Wsol(s) => s.sol_to_lst(lamports),
             Self::SanctumSplMulti(s) => s.sol_to_lst(lamports),
+            Self::Svsp(s) => s.sol_to_lst(lamports),
+            Self::Everstake(s) => s.sol_to_lst(lamports),
+            Self::PostedRate(s) => s.sol_to_lst(lamports),
+            Self::SLp(s) => s.sol_to_lst(lamports),
         }
     }
 
@@ -113,6 +149,10 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.ix_accounts(),
             Self::Wsol(s) => s.ix_accounts(),
             Self::SanctumSplMulti(s) => s.ix_accounts(),
+            Self::Svsp(s) => s.ix_accounts(),
+            Self::Everstake(s) => s.ix_accounts(),
+            Self::PostedRate(s) => s.ix_accounts(),
+            Self::SLp(s) => s.ix_accounts(),
         }
     }
 
@@ -124,10 +164,20 @@ impl LstSolValCalc for KnownLstSolValCalc {
             Self::SanctumSpl(s) => s.sol_value_calculator(),
             Self::Wsol(s) => s.sol_value_calculator(),
             Self::SanctumSplMulti(s) => s.sol_value_calculator(),
+            Self::Svsp(s) => s.sol_value_calculator(),
+            Self::Everstake(s) => s.sol_value_calculator(),
+            Self::PostedRate(s) => s.sol_value_calculator(),
+            Self::SLp(s) => s.sol_value_calculator(),
         }
     }
 }
 
+impl From<EverstakeLstSolValCalc> for KnownLstSolValCalc {
+    fn from(value: EverstakeLstSolValCalc) -> Self {
+        Self::Everstake(value)
+    }
+}
+
 impl From<LidoLstSolValCalc> for KnownLstSolValCalc {
     fn from(value: LidoLstSolValCalc) -> Self {
         Self::Lido(value)
@@ -140,6 +190,18 @@ impl From<MarinadeLstSolValCalc> for KnownLstSolValCalc {
     }
 }
 
+impl From<PostedRateLstSolValCalc> for KnownLstSolValCalc {
+    fn from(value: PostedRateLstSolValCalc) -> Self {
+        Self::PostedRate(value)
+    }
+}
+
+impl From<SLpLstSolValCalc> for KnownLstSolValCalc {
+    fn from(value: SLpLstSolValCalc) -> Self {
+        Self::SLp(value)
+    }
+}
+
 impl From<SplLstSolValCalc> for KnownLstSolValCalc {
     fn from(value: SplLstSolValCalc) -> Self {
         Self::Spl(value)
@@ -158,6 +220,12 @@ impl From<SanctumSplMultiLstSolValCalc> for KnownLstSolValCalc {
     }
 }
 
+impl From<SvspLstSolValCalc> for KnownLstSolValCalc {
+    fn from(value: SvspLstSolValCalc) -> Self {
+        Self::Svsp(value)
+    }
+}
+
 impl From<WsolLstSolValCalc> for KnownLstSolValCalc {
     fn from(value: WsolLstSolValCalc) -> Self {
         Self::Wsol(value)
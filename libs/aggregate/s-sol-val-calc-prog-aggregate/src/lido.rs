@@ -1,4 +1,5 @@
 use borsh::BorshDeserialize;
+use generic_pool_calculator_lib::{utils::read_stale_discount_bps, GenericPoolSolValCalc};
 use lido_calculator_interface::Lido;
 use lido_calculator_lib::{LidoCalc, LidoSolValCalc, LIDO_LST_SOL_COMMON_INTERMEDIATE_KEYS};
 use lido_keys::{lido_state, stsol};
@@ -22,6 +23,8 @@ use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolVa
 #[derive(Clone, Debug)]
 pub struct LidoLstSolValCalc {
     pub calc: Option<LidoCalc>,
+    /// Read from the calculator program's CalculatorState, 0 until fetched
+    pub stale_discount_bps: u16,
     pub shared_current_epoch: Arc<AtomicU64>,
 }
 
@@ -30,6 +33,7 @@ impl LidoLstSolValCalc {
     pub const fn new(shared_current_epoch: Arc<AtomicU64>) -> Self {
         Self {
             calc: None,
+            stale_discount_bps: 0,
             shared_current_epoch,
         }
     }
@@ -42,7 +46,7 @@ impl LidoLstSolValCalc {
 
 impl MutableLstSolValCalc for LidoLstSolValCalc {
     fn get_accounts_to_update(&self) -> Vec<Pubkey> {
-        vec![lido_state::ID]
+        vec![lido_state::ID, LidoSolValCalc::CALCULATOR_STATE_PDA]
     }
 
     fn update<D: ReadonlyAccountData>(
@@ -52,6 +56,9 @@ impl MutableLstSolValCalc for LidoLstSolValCalc {
         if let Some(acc) = account_map.get(&lido_state::ID) {
             self.calc = Some(LidoCalc::from(Lido::deserialize(&mut acc.data().as_ref())?));
         }
+        if let Some(acc) = account_map.get(&LidoSolValCalc::CALCULATOR_STATE_PDA) {
+            self.stale_discount_bps = read_stale_discount_bps(acc)?;
+        }
         Ok(())
     }
 }
@@ -66,14 +73,18 @@ impl LstSolValCalc for LidoLstSolValCalc {
     }
 
     fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
-        let calc = self.calc.ok_or(LidoLstSolValCalcErr::StateNotFetched)?;
-        calc.verify_pool_updated_for_this_epoch(self.current_epoch())?;
+        let calc = self
+            .calc
+            .ok_or(LidoLstSolValCalcErr::StateNotFetched)?
+            .verify_pool_updated_or_stale_bounded(self.current_epoch(), self.stale_discount_bps)?;
         Ok(calc.calc_lst_to_sol(lst_amount)?)
     }
 
     fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
-        let calc = self.calc.ok_or(LidoLstSolValCalcErr::StateNotFetched)?;
-        calc.verify_pool_updated_for_this_epoch(self.current_epoch())?;
+        let calc = self
+            .calc
+            .ok_or(LidoLstSolValCalcErr::StateNotFetched)?
+            .verify_pool_updated_or_stale_bounded(self.current_epoch(), self.stale_discount_bps)?;
         Ok(calc.calc_sol_to_lst(lamports)?)
     }
 
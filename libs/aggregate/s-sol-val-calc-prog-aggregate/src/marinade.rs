@@ -1,14 +1,14 @@
 use borsh::BorshDeserialize;
 use marinade_calculator_interface::MarinadeState;
 use marinade_calculator_lib::{
-    MarinadeSolValCalc, MarinadeStateCalc, MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
+    marinade_sol_val_calc_account_metas, program::MARINADE_CALCULATOR_CONFIG_ID,
+    try_marinade_calc_config, MarinadeStateCalc,
 };
 use marinade_keys::{marinade_state, msol};
 use sanctum_token_ratio::U64ValueRange;
 use sol_value_calculator_lib::SolValueCalculator;
 use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_readonly_account::ReadonlyAccountData;
-use spl_calculator_lib::resolve_to_account_metas_for_calc;
 use std::{collections::HashMap, error::Error, fmt::Display};
 
 use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
@@ -16,22 +16,37 @@ use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolVa
 #[derive(Clone, Copy, Debug, Default)]
 pub struct MarinadeLstSolValCalc {
     pub calc: Option<MarinadeStateCalc>,
+
+    /// Mirrors the calculator program's MarinadeCalcConfig,
+    /// false if it has not been initialized
+    pub delayed_unstake_fallback_enabled: bool,
 }
 
 impl MutableLstSolValCalc for MarinadeLstSolValCalc {
     fn get_accounts_to_update(&self) -> Vec<Pubkey> {
-        vec![marinade_state::ID]
+        vec![marinade_state::ID, MARINADE_CALCULATOR_CONFIG_ID]
     }
 
     fn update<D: ReadonlyAccountData>(
         &mut self,
         account_map: &HashMap<Pubkey, D>,
     ) -> anyhow::Result<()> {
+        if let Some(acc) = account_map.get(&MARINADE_CALCULATOR_CONFIG_ID) {
+            let data = acc.data();
+            // PDA data can only be allocated by the calculator program
+            if !data.is_empty() {
+                self.delayed_unstake_fallback_enabled =
+                    try_marinade_calc_config(&data)?.delayed_unstake_fallback != 0;
+            }
+        }
         if let Some(acc) = account_map.get(&marinade_state::ID) {
             self.calc = Some(MarinadeStateCalc::from(MarinadeState::deserialize(
                 &mut acc.data().as_ref(),
             )?));
         }
+        self.calc = self
+            .calc
+            .map(|calc| calc.with_delayed_unstake_fallback(self.delayed_unstake_fallback_enabled));
         Ok(())
     }
 }
@@ -47,20 +62,18 @@ impl LstSolValCalc for MarinadeLstSolValCalc {
 
     fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
         let calc = self.calc.ok_or(MarinadeLstSolValCalcErr::StateNotFetched)?;
-        calc.verify_can_withdraw_stake()?;
+        calc.verify_can_unstake()?;
         Ok(calc.calc_lst_to_sol(lst_amount)?)
     }
 
     fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
         let calc = self.calc.ok_or(MarinadeLstSolValCalcErr::StateNotFetched)?;
-        calc.verify_can_withdraw_stake()?;
+        calc.verify_can_unstake()?;
         Ok(calc.calc_sol_to_lst(lamports)?)
     }
 
     fn ix_accounts(&self) -> Vec<AccountMeta> {
-        Vec::from(resolve_to_account_metas_for_calc::<MarinadeSolValCalc>(
-            MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
-        ))
+        Vec::from(marinade_sol_val_calc_account_metas())
     }
 
     fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
@@ -0,0 +1,127 @@
+use posted_rate_calculator_lib::{
+    account_resolvers::PostedRateLstSolCommonFreeArgs, calc::PostedRateCalc,
+    utils::try_rate_account,
+};
+use sanctum_token_ratio::U64ValueRange;
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+
+#[derive(Clone, Debug)]
+pub struct PostedRateLstSolValCalc {
+    pub lst_mint: Pubkey,
+    pub rate_acc: Pubkey,
+    pub calc: Option<PostedRateCalc>,
+    pub shared_current_slot: Arc<AtomicU64>,
+}
+
+impl PostedRateLstSolValCalc {
+    pub fn new(lst_mint: Pubkey, shared_current_slot: Arc<AtomicU64>) -> Self {
+        let rate_acc = PostedRateLstSolCommonFreeArgs { lst_mint }
+            .resolve()
+            .rate_acc;
+        Self {
+            lst_mint,
+            rate_acc,
+            calc: None,
+            shared_current_slot,
+        }
+    }
+
+    #[inline]
+    pub fn current_slot(&self) -> u64 {
+        self.shared_current_slot.load(Ordering::Relaxed)
+    }
+}
+
+impl MutableLstSolValCalc for PostedRateLstSolValCalc {
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        vec![self.rate_acc]
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        if let Some(acc) = account_map.get(&self.rate_acc) {
+            self.calc = Some(try_rate_account(&acc.data())?.into());
+        }
+        Ok(())
+    }
+}
+
+impl LstSolValCalc for PostedRateLstSolValCalc {
+    fn sol_value_calculator_program_id(&self) -> Pubkey {
+        posted_rate_calculator_lib::program::ID
+    }
+
+    fn lst_mint(&self) -> Pubkey {
+        self.lst_mint
+    }
+
+    fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self
+            .calc
+            .ok_or(PostedRateLstSolValCalcErr::RateAccountNotFetched)?;
+        calc.verify_rate_not_stale(self.current_slot())?;
+        Ok(calc.calc_lst_to_sol(lst_amount)?)
+    }
+
+    fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self
+            .calc
+            .ok_or(PostedRateLstSolValCalcErr::RateAccountNotFetched)?;
+        calc.verify_rate_not_stale(self.current_slot())?;
+        Ok(calc.calc_sol_to_lst(lamports)?)
+    }
+
+    fn ix_accounts(&self) -> Vec<AccountMeta> {
+        Vec::from(
+            PostedRateLstSolCommonFreeArgs {
+                lst_mint: self.lst_mint,
+            }
+            .resolve_to_account_metas(),
+        )
+    }
+
+    fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
+        self.calc.as_ref().map(|c| c as &dyn SolValueCalculator)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PostedRateLstSolValCalcErr {
+    RateAccountNotFetched,
+}
+
+impl Display for PostedRateLstSolValCalcErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RateAccountNotFetched => f.write_str("posted rate account not yet fetched"),
+        }
+    }
+}
+
+impl Error for PostedRateLstSolValCalcErr {}
+
+impl TryFrom<KnownLstSolValCalc> for PostedRateLstSolValCalc {
+    type Error = LstSolValCalcErr;
+
+    fn try_from(value: KnownLstSolValCalc) -> Result<Self, Self::Error> {
+        match value {
+            KnownLstSolValCalc::PostedRate(s) => Ok(s),
+            _ => Err(LstSolValCalcErr::WrongLstSolValCalc),
+        }
+    }
+}
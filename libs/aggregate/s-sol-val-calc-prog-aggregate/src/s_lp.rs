@@ -0,0 +1,145 @@
+use s_controller_interface::PoolState;
+use s_controller_lib::{find_pool_state_address, try_pool_state};
+use s_lp_calculator_lib::{
+    account_resolvers::SLpLstSolCommonFreeArgsConst,
+    calc::SLpSolValCalc,
+    utils::{try_lp_token_supply, verify_pool_not_rebalancing},
+};
+use sanctum_token_ratio::U64ValueRange;
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+
+/// Values the LP token of a (possibly different) S pool.
+///
+/// Quotes use the source pool's last synced `total_sol_value`,
+/// while the on-chain program additionally requires
+/// every LST in the source pool to be synced earlier in the same transaction.
+#[derive(Clone, Debug)]
+pub struct SLpLstSolValCalc {
+    pub s_program_id: Pubkey,
+    pub pool_state_addr: Pubkey,
+    pub lp_token_mint: Pubkey,
+    pub pool_state: Option<PoolState>,
+    pub lp_token_supply: Option<u64>,
+    pub calc: Option<SLpSolValCalc>,
+}
+
+impl SLpLstSolValCalc {
+    pub fn new(s_program_id: Pubkey, lp_token_mint: Pubkey) -> Self {
+        Self {
+            s_program_id,
+            pool_state_addr: find_pool_state_address(s_program_id).0,
+            lp_token_mint,
+            pool_state: None,
+            lp_token_supply: None,
+            calc: None,
+        }
+    }
+
+    fn verified_calc(&self) -> anyhow::Result<SLpSolValCalc> {
+        let (pool_state, calc) = self
+            .pool_state
+            .as_ref()
+            .zip(self.calc)
+            .ok_or(SLpLstSolValCalcErr::AccountsNotFetched)?;
+        verify_pool_not_rebalancing(pool_state)?;
+        Ok(calc)
+    }
+}
+
+impl MutableLstSolValCalc for SLpLstSolValCalc {
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        vec![self.pool_state_addr, self.lp_token_mint]
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        if let Some(acc) = account_map.get(&self.pool_state_addr) {
+            let pool_state = *try_pool_state(&acc.data())?;
+            if pool_state.lp_token_mint != self.lp_token_mint {
+                return Err(SLpLstSolValCalcErr::IncorrectLpTokenMint.into());
+            }
+            self.pool_state = Some(pool_state);
+        }
+        if let Some(acc) = account_map.get(&self.lp_token_mint) {
+            self.lp_token_supply = Some(try_lp_token_supply(&acc.data(), &self.pool_state_addr)?);
+        }
+        if let (Some(pool_state), Some(lp_token_supply)) = (self.pool_state, self.lp_token_supply) {
+            self.calc = Some(SLpSolValCalc {
+                lp_token_supply,
+                pool_total_sol_value: pool_state.total_sol_value,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl LstSolValCalc for SLpLstSolValCalc {
+    fn sol_value_calculator_program_id(&self) -> Pubkey {
+        s_lp_calculator_lib::program::ID
+    }
+
+    fn lst_mint(&self) -> Pubkey {
+        self.lp_token_mint
+    }
+
+    fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
+        Ok(self.verified_calc()?.calc_lst_to_sol(lst_amount)?)
+    }
+
+    fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
+        Ok(self.verified_calc()?.calc_sol_to_lst(lamports)?)
+    }
+
+    fn ix_accounts(&self) -> Vec<AccountMeta> {
+        Vec::from(
+            SLpLstSolCommonFreeArgsConst {
+                s_program_id: self.s_program_id,
+                lp_token_mint: self.lp_token_mint,
+            }
+            .resolve_to_account_metas(),
+        )
+    }
+
+    fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
+        self.calc.as_ref().map(|c| c as &dyn SolValueCalculator)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SLpLstSolValCalcErr {
+    AccountsNotFetched,
+    IncorrectLpTokenMint,
+}
+
+impl Display for SLpLstSolValCalcErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AccountsNotFetched => {
+                f.write_str("S pool state or LP token mint not yet fetched")
+            }
+            Self::IncorrectLpTokenMint => {
+                f.write_str("S pool state's LP token mint does not match")
+            }
+        }
+    }
+}
+
+impl Error for SLpLstSolValCalcErr {}
+
+impl TryFrom<KnownLstSolValCalc> for SLpLstSolValCalc {
+    type Error = LstSolValCalcErr;
+
+    fn try_from(value: KnownLstSolValCalc) -> Result<Self, Self::Error> {
+        match value {
+            KnownLstSolValCalc::SLp(s) => Ok(s),
+            _ => Err(LstSolValCalcErr::WrongLstSolValCalc),
+        }
+    }
+}
@@ -1,12 +1,12 @@
 use generic_pool_calculator_interface::GenericPoolCalculatorError;
-use generic_pool_calculator_lib::account_resolvers::LstSolCommonIntermediateKeys;
 use sanctum_token_ratio::U64ValueRange;
 use sol_value_calculator_lib::SolValueCalculator;
 use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 use spl_calculator_lib::{
-    deserialize_sanctum_spl_stake_pool_checked, resolve_to_account_metas_for_calc,
-    sanctum_spl_sol_val_calc_program, SanctumSplSolValCalc, SplStakePoolCalc,
+    deserialize_sanctum_spl_stake_pool_checked, sanctum_spl_sol_val_calc_program,
+    SanctumSplSolValCalc, SplStakePoolCalc, SplValuationMode, StakeWithdrawalFees,
+    WithdrawalFeeGuard,
 };
 use std::{
     collections::HashMap,
@@ -36,7 +36,11 @@ impl SanctumSplLstSolValCalc {
         Ok(Self(SplLstSolValCalc {
             lst_mint: pool.pool_mint,
             stake_pool_addr,
-            calc: Some(SplStakePoolCalc::from(pool)),
+            calc: Some(SplStakePoolCalc::from(&pool)),
+            stake_withdrawal_fees: Some(StakeWithdrawalFees::from(&pool)),
+            stale_discount_bps: 0,
+            valuation_mode: SplValuationMode::StakeWithdrawal,
+            withdrawal_fee_guard: WithdrawalFeeGuard::DISABLED,
             shared_current_epoch,
         }))
     }
@@ -45,7 +49,8 @@ impl SanctumSplLstSolValCalc {
 impl MutableLstSolValCalc for SanctumSplLstSolValCalc {
     #[inline]
     fn get_accounts_to_update(&self) -> Vec<Pubkey> {
-        self.0.get_accounts_to_update()
+        self.0
+            .get_accounts_to_update_for_calc::<SanctumSplSolValCalc>()
     }
 
     #[inline]
@@ -53,7 +58,8 @@ impl MutableLstSolValCalc for SanctumSplLstSolValCalc {
         &mut self,
         account_map: &HashMap<Pubkey, D>,
     ) -> anyhow::Result<()> {
-        self.0.update(account_map)
+        self.0
+            .update_for_calc::<SanctumSplSolValCalc, D>(account_map)
     }
 }
 
@@ -63,12 +69,7 @@ impl LstSolValCalc for SanctumSplLstSolValCalc {
     }
 
     fn ix_accounts(&self) -> Vec<AccountMeta> {
-        Vec::from(resolve_to_account_metas_for_calc::<SanctumSplSolValCalc>(
-            LstSolCommonIntermediateKeys {
-                lst_mint: self.0.lst_mint,
-                pool_state: self.0.stake_pool_addr,
-            },
-        ))
+        self.0.ix_accounts_for_calc::<SanctumSplSolValCalc>()
     }
 
     fn lst_mint(&self) -> Pubkey {
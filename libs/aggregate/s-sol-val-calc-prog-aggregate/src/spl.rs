@@ -1,12 +1,17 @@
 use generic_pool_calculator_interface::GenericPoolCalculatorError;
-use generic_pool_calculator_lib::account_resolvers::LstSolCommonIntermediateKeys;
+use generic_pool_calculator_lib::{
+    account_resolvers::LstSolCommonIntermediateKeys, utils::read_stale_discount_bps,
+    GenericPoolSolValCalc,
+};
 use sanctum_token_ratio::U64ValueRange;
-use sol_value_calculator_lib::SolValueCalculator;
+use sol_value_calculator_lib::{SolValueCalculator, StaleDiscountCalc};
 use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 use spl_calculator_lib::{
     deserialize_spl_stake_pool_checked, deserialize_stake_pool_checked,
-    resolve_to_account_metas_for_calc, SplSolValCalc, SplStakePoolCalc,
+    pda::SplPoolConfigFindPdaArgs, resolve_spl_to_account_metas_for_calc, try_spl_pool_config,
+    SplPoolParams, SplSolValCalc, SplStakePoolCalc, SplValuationMode, StakeWithdrawalFees,
+    WithdrawalFeeGuard,
 };
 use std::{
     collections::HashMap,
@@ -25,6 +30,16 @@ pub struct SplLstSolValCalc {
     pub lst_mint: Pubkey,
     pub stake_pool_addr: Pubkey,
     pub calc: Option<SplStakePoolCalc>,
+    /// Read from the stake pool alongside `calc`
+    pub stake_withdrawal_fees: Option<StakeWithdrawalFees>,
+    /// Read from the calculator program's CalculatorState, 0 until fetched
+    pub stale_discount_bps: u16,
+    /// Read from the stake pool's SplPoolConfig, [`SplValuationMode::StakeWithdrawal`]
+    /// until fetched or if the config does not exist
+    pub valuation_mode: SplValuationMode,
+    /// Read from the stake pool's SplPoolConfig, [`WithdrawalFeeGuard::DISABLED`]
+    /// until fetched or if the config does not exist
+    pub withdrawal_fee_guard: WithdrawalFeeGuard,
     pub shared_current_epoch: Arc<AtomicU64>,
 }
 
@@ -47,6 +62,10 @@ impl SplLstSolValCalc {
             lst_mint,
             stake_pool_addr,
             calc: None,
+            stake_withdrawal_fees: None,
+            stale_discount_bps: 0,
+            valuation_mode: SplValuationMode::StakeWithdrawal,
+            withdrawal_fee_guard: WithdrawalFeeGuard::DISABLED,
             shared_current_epoch,
         }
     }
@@ -61,7 +80,11 @@ impl SplLstSolValCalc {
         Ok(Self {
             lst_mint: pool.pool_mint,
             stake_pool_addr,
-            calc: Some(SplStakePoolCalc::from(pool)),
+            calc: Some(SplStakePoolCalc::from(&pool)),
+            stake_withdrawal_fees: Some(StakeWithdrawalFees::from(&pool)),
+            stale_discount_bps: 0,
+            valuation_mode: SplValuationMode::StakeWithdrawal,
+            withdrawal_fee_guard: WithdrawalFeeGuard::DISABLED,
             shared_current_epoch,
         })
     }
@@ -70,15 +93,39 @@ impl SplLstSolValCalc {
     pub fn current_epoch(&self) -> u64 {
         self.shared_current_epoch.load(Ordering::Relaxed)
     }
-}
 
-impl MutableLstSolValCalc for SplLstSolValCalc {
     #[inline]
-    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
-        vec![self.stake_pool_addr]
+    pub fn pool_config_addr_for_calc<C: GenericPoolSolValCalc>(&self) -> Pubkey {
+        SplPoolConfigFindPdaArgs {
+            program_id: C::ID,
+            pool_state: self.stake_pool_addr,
+        }
+        .get_spl_pool_config_address_and_bump_seed()
+        .0
     }
 
-    fn update<D: ReadonlyAccountData>(
+    /// Shared by the SPL, sanctum SPL and sanctum SPL multi calculators,
+    /// which only differ in the calculator program's accounts to read
+    #[inline]
+    pub fn get_accounts_to_update_for_calc<C: GenericPoolSolValCalc>(&self) -> Vec<Pubkey> {
+        vec![
+            self.stake_pool_addr,
+            C::CALCULATOR_STATE_PDA,
+            self.pool_config_addr_for_calc::<C>(),
+        ]
+    }
+
+    /// Shared by the SPL, sanctum SPL and sanctum SPL multi calculators
+    pub fn ix_accounts_for_calc<C: GenericPoolSolValCalc>(&self) -> Vec<AccountMeta> {
+        Vec::from(resolve_spl_to_account_metas_for_calc::<C>(
+            LstSolCommonIntermediateKeys {
+                lst_mint: self.lst_mint,
+                pool_state: self.stake_pool_addr,
+            },
+        ))
+    }
+
+    pub fn update_for_calc<C: GenericPoolSolValCalc, D: ReadonlyAccountData>(
         &mut self,
         account_map: &HashMap<Pubkey, D>,
     ) -> anyhow::Result<()> {
@@ -87,10 +134,57 @@ impl MutableLstSolValCalc for SplLstSolValCalc {
             if pool.pool_mint != self.lst_mint {
                 return Err(SplLstSolValCalcErr::WrongLstMint.into());
             }
-            self.calc = Some(SplStakePoolCalc::from(pool));
+            self.calc = Some(SplStakePoolCalc::from(&pool));
+            self.stake_withdrawal_fees = Some(StakeWithdrawalFees::from(&pool));
+        }
+        if let Some(acc) = account_map.get(&C::CALCULATOR_STATE_PDA) {
+            self.stale_discount_bps = read_stale_discount_bps(acc)?;
         }
+        // config account is not returned if it was never initialized,
+        // in which case the default params apply
+        if let Some(acc) = account_map.get(&self.pool_config_addr_for_calc::<C>()) {
+            let data = acc.data();
+            if !data.is_empty() {
+                let SplPoolParams {
+                    valuation_mode,
+                    withdrawal_fee_guard,
+                } = try_spl_pool_config(&data)?.try_into()?;
+                self.valuation_mode = valuation_mode;
+                self.withdrawal_fee_guard = withdrawal_fee_guard;
+            }
+        }
+        self.calc = self
+            .calc
+            .map(|calc| calc.with_valuation_mode(self.valuation_mode));
         Ok(())
     }
+
+    /// Checks the stake pool against its withdrawal fee guard
+    /// and returns the calculator to value its LST with, as the calculator programs do
+    pub fn verified_calc(&self) -> anyhow::Result<StaleDiscountCalc<SplStakePoolCalc>> {
+        let (calc, fees) = self
+            .calc
+            .zip(self.stake_withdrawal_fees)
+            .ok_or(SplLstSolValCalcErr::StakePoolNotFetched)?;
+        let current_epoch = self.current_epoch();
+        self.withdrawal_fee_guard.verify(&fees, current_epoch)?;
+        Ok(calc.verify_pool_updated_or_stale_bounded(current_epoch, self.stale_discount_bps)?)
+    }
+}
+
+impl MutableLstSolValCalc for SplLstSolValCalc {
+    #[inline]
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        self.get_accounts_to_update_for_calc::<SplSolValCalc>()
+    }
+
+    #[inline]
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        self.update_for_calc::<SplSolValCalc, D>(account_map)
+    }
 }
 
 impl LstSolValCalc for SplLstSolValCalc {
@@ -105,24 +199,17 @@ impl LstSolValCalc for SplLstSolValCalc {
     }
 
     fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
-        let calc = self.calc.ok_or(SplLstSolValCalcErr::StakePoolNotFetched)?;
-        calc.verify_pool_updated_for_this_epoch(self.current_epoch())?;
+        let calc = self.verified_calc()?;
         Ok(calc.calc_lst_to_sol(lst_amount)?)
     }
 
     fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
-        let calc = self.calc.ok_or(SplLstSolValCalcErr::StakePoolNotFetched)?;
-        calc.verify_pool_updated_for_this_epoch(self.current_epoch())?;
+        let calc = self.verified_calc()?;
         Ok(calc.calc_sol_to_lst(lamports)?)
     }
 
     fn ix_accounts(&self) -> Vec<AccountMeta> {
-        Vec::from(resolve_to_account_metas_for_calc::<SplSolValCalc>(
-            LstSolCommonIntermediateKeys {
-                lst_mint: self.lst_mint,
-                pool_state: self.stake_pool_addr,
-            },
-        ))
+        self.ix_accounts_for_calc::<SplSolValCalc>()
     }
 
     #[inline]
@@ -0,0 +1,132 @@
+use generic_pool_calculator_lib::account_resolvers::LstSolCommonIntermediateKeys;
+use sanctum_token_lib::mint_supply;
+use sanctum_token_ratio::U64ValueRange;
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+use std::{collections::HashMap, error::Error, fmt::Display};
+use svsp_calculator_interface::{LstToSolKeys, LST_TO_SOL_IX_ACCOUNTS_LEN};
+use svsp_calculator_lib::{
+    find_svsp_pool_mint_address, find_svsp_pool_stake_address, read_pool_stake_amount,
+    SvspLstSolCommonKeys, SvspPoolCalc, SvspSolValCalc,
+};
+
+use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SvspLstSolValCalc {
+    pub lst_mint: Pubkey,
+    pub pool_addr: Pubkey,
+    pub pool_stake_addr: Pubkey,
+    pub calc: Option<SvspPoolCalc>,
+}
+
+impl SvspLstSolValCalc {
+    /// The LST mint and pool stake account are PDAs of the pool
+    /// so only the pool's address is required
+    #[inline]
+    pub fn from_pool_addr(pool_addr: Pubkey) -> Self {
+        Self {
+            lst_mint: find_svsp_pool_mint_address(pool_addr).0,
+            pool_addr,
+            pool_stake_addr: find_svsp_pool_stake_address(pool_addr).0,
+            calc: None,
+        }
+    }
+}
+
+impl MutableLstSolValCalc for SvspLstSolValCalc {
+    #[inline]
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        vec![self.pool_stake_addr, self.lst_mint]
+    }
+
+    /// SOL value depends on both the pool stake account and the LST mint,
+    /// so calc is only set once both have been fetched
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        let pool_stake = match account_map.get(&self.pool_stake_addr) {
+            Some(acc) => Some(read_pool_stake_amount(acc)?),
+            None => self.calc.map(|c| c.pool_stake),
+        };
+        let pool_token_supply = match account_map.get(&self.lst_mint) {
+            Some(acc) => Some(mint_supply(acc)?),
+            None => self.calc.map(|c| c.pool_token_supply),
+        };
+        if let (Some(pool_stake), Some(pool_token_supply)) = (pool_stake, pool_token_supply) {
+            self.calc = Some(SvspPoolCalc {
+                pool_stake,
+                pool_token_supply,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl LstSolValCalc for SvspLstSolValCalc {
+    #[inline]
+    fn sol_value_calculator_program_id(&self) -> Pubkey {
+        svsp_calculator_lib::program::ID
+    }
+
+    #[inline]
+    fn lst_mint(&self) -> Pubkey {
+        self.lst_mint
+    }
+
+    fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self.calc.ok_or(SvspLstSolValCalcErr::PoolNotFetched)?;
+        Ok(calc.calc_lst_to_sol(lst_amount)?)
+    }
+
+    fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self.calc.ok_or(SvspLstSolValCalcErr::PoolNotFetched)?;
+        Ok(calc.calc_sol_to_lst(lamports)?)
+    }
+
+    fn ix_accounts(&self) -> Vec<AccountMeta> {
+        let keys: LstToSolKeys = SvspLstSolCommonKeys::from_generic_keys(
+            LstSolCommonIntermediateKeys {
+                lst_mint: self.lst_mint,
+                pool_state: self.pool_addr,
+            }
+            .resolve::<SvspSolValCalc>(),
+            self.pool_stake_addr,
+        )
+        .into();
+        Vec::from(<[AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN]>::from(keys))
+    }
+
+    #[inline]
+    fn sol_value_calculator(&self) -> Option<&dyn SolValueCalculator> {
+        self.calc.as_ref().map(|c| c as &dyn SolValueCalculator)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SvspLstSolValCalcErr {
+    PoolNotFetched,
+}
+
+impl Display for SvspLstSolValCalcErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PoolNotFetched => f.write_str("pool stake account or LST mint not yet fetched"),
+        }
+    }
+}
+
+impl Error for SvspLstSolValCalcErr {}
+
+impl TryFrom<KnownLstSolValCalc> for SvspLstSolValCalc {
+    type Error = LstSolValCalcErr;
+
+    fn try_from(value: KnownLstSolValCalc) -> Result<Self, Self::Error> {
+        match value {
+            KnownLstSolValCalc::Svsp(s) => Ok(s),
+            _ => Err(LstSolValCalcErr::WrongLstSolValCalc),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use dynamic_fee_interface::DynamicFeeError;
+use sanctum_token_ratio::{FloorDiv, U64Ratio, BPS_DENOMINATOR};
+
+use super::{lst_fee_bps, LstFeeArgs, SwapSide, BPS_DENOMINATOR_I64};
+
+#[derive(Clone, Copy)]
+pub struct OutSolValueRatioArgs {
+    pub input: LstFeeArgs,
+    pub output: LstFeeArgs,
+    pub pool_total_sol_value: u64,
+    /// The swap's input SOL value
+    pub swap_sol_value: u64,
+}
+
+/// Returns the ratio that returns out_sol_value
+/// when applied to in_sol_value
+pub fn out_sol_value_ratio(
+    OutSolValueRatioArgs {
+        input,
+        output,
+        pool_total_sol_value,
+        swap_sol_value,
+    }: OutSolValueRatioArgs,
+) -> Result<FloorDiv<U64Ratio<u16, u16>>, DynamicFeeError> {
+    let input_fee_bps = lst_fee_bps(SwapSide::Input, input, pool_total_sol_value, swap_sol_value)?;
+    let output_fee_bps = lst_fee_bps(
+        SwapSide::Output,
+        output,
+        pool_total_sol_value,
+        swap_sol_value,
+    )?;
+    // The total may be negative, but a rebate is never larger than the swap's input SOL value.
+    // The S controller accepts the resulting decrease in the pool's SOL value
+    // of at most out_sol_value - in_sol_value
+    let fee_bps = input_fee_bps
+        .checked_add(output_fee_bps)
+        .ok_or(DynamicFeeError::MathError)?
+        .max(-BPS_DENOMINATOR_I64);
+    // post_fee_bps = 10_000 - fee_bps
+    // out_sol_value = floor(in_sol_value * post_fee_bps / 10_000)
+    // - rebates are allowed (10_000 < post_fee_bps <= 20_000)
+    // - however, >100% fees will error (post_fee_bps < 0)
+    let post_fee_bps: u16 = BPS_DENOMINATOR_I64
+        .checked_sub(fee_bps)
+        .and_then(|v| v.try_into().ok())
+        .ok_or(DynamicFeeError::MathError)?;
+    Ok(FloorDiv(U64Ratio {
+        num: post_fee_bps,
+        denom: BPS_DENOMINATOR,
+    }))
+}
@@ -0,0 +1,200 @@
+use dynamic_fee_interface::{DynamicFeeError, FeeAccount};
+use sanctum_token_ratio::BPS_DENOMINATOR;
+
+/// Which side of a swap an LST is on
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SwapSide {
+    Input,
+    Output,
+}
+
+/// An LST's fee params and its current allocation in the pool
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LstFeeArgs {
+    /// `input_fee_bps` if the LST is the swap's input,
+    /// `output_fee_bps` if the LST is the swap's output
+    pub base_fee_bps: i16,
+    pub target_weight_bps: u16,
+    pub imbalance_fee_bps: u16,
+    /// The LST's `LstState.sol_value` before the swap
+    pub lst_sol_value: u64,
+}
+
+impl LstFeeArgs {
+    pub const fn new(side: SwapSide, fee_acc: &FeeAccount, lst_sol_value: u64) -> Self {
+        let base_fee_bps = match side {
+            SwapSide::Input => fee_acc.input_fee_bps,
+            SwapSide::Output => fee_acc.output_fee_bps,
+        };
+        Self {
+            base_fee_bps,
+            target_weight_bps: fee_acc.target_weight_bps,
+            imbalance_fee_bps: fee_acc.imbalance_fee_bps,
+            lst_sol_value,
+        }
+    }
+}
+
+/// Returns the fee in bps charged for an LST on `side` of a swap of `swap_sol_value`:
+///
+/// `base_fee_bps + imbalance_fee_bps * mid_deviation / pool_total_sol_value`
+///
+/// where `mid_deviation` is how far, in SOL value, the LST is over its target weight
+/// (under its target weight if `side` is Output) halfway through the swap.
+///
+/// This is the average rate of a penalty that grows with the square of the LST's deviation
+/// from its target over the course of the swap, so the fee rises the further the swap moves
+/// the LST away from its target and falls, going negative, the closer it moves it back.
+/// The penalty only depends on the LST's allocation, so splitting a swap up does not reduce it.
+///
+/// The adjustment is rounded up in favour of the pool.
+/// No adjustment is made if the pool is empty.
+pub fn lst_fee_bps(
+    side: SwapSide,
+    LstFeeArgs {
+        base_fee_bps,
+        target_weight_bps,
+        imbalance_fee_bps,
+        lst_sol_value,
+    }: LstFeeArgs,
+    pool_total_sol_value: u64,
+    swap_sol_value: u64,
+) -> Result<i64, DynamicFeeError> {
+    let base_fee_bps = i64::from(base_fee_bps);
+    if pool_total_sol_value == 0 || imbalance_fee_bps == 0 {
+        return Ok(base_fee_bps);
+    }
+    // all values below are < 2^96 and cannot overflow i128
+    let bps_denom = i128::from(BPS_DENOMINATOR);
+    let pool_total_sol_value = i128::from(pool_total_sol_value);
+    // deviation from target before the swap, scaled by BPS_DENOMINATOR
+    let overweight = i128::from(lst_sol_value) * bps_denom
+        - i128::from(target_weight_bps) * pool_total_sol_value;
+    let deviation = match side {
+        SwapSide::Input => overweight,
+        SwapSide::Output => -overweight,
+    };
+    let mid_deviation_x2 = 2 * deviation + i128::from(swap_sol_value) * bps_denom;
+    let num = i128::from(imbalance_fee_bps) * mid_deviation_x2;
+    let denom = 2 * bps_denom * pool_total_sol_value;
+    // ceil div
+    let adj_bps = -(-num).div_euclid(denom);
+    let adj_bps = i64::try_from(adj_bps).map_err(|_e| DynamicFeeError::MathError)?;
+    base_fee_bps
+        .checked_add(adj_bps)
+        .ok_or(DynamicFeeError::MathError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const MAX_TOTAL_SOL_VALUE: u64 = 1_000_000_000_000_000_000;
+
+    #[test]
+    fn balanced_pool_charges_half_swap_deviation() {
+        // midpoint of the swap is 50 / 1000 = 5% over target
+        let args = LstFeeArgs {
+            base_fee_bps: 1,
+            target_weight_bps: 5_000,
+            imbalance_fee_bps: 100,
+            lst_sol_value: 500,
+        };
+        assert_eq!(lst_fee_bps(SwapSide::Input, args, 1_000, 100).unwrap(), 6);
+        assert_eq!(lst_fee_bps(SwapSide::Output, args, 1_000, 100).unwrap(), 6);
+    }
+
+    #[test]
+    fn swap_back_to_target_gets_rebate() {
+        // LST is 30% of the pool with 50% target
+        let args = LstFeeArgs {
+            base_fee_bps: 1,
+            target_weight_bps: 5_000,
+            imbalance_fee_bps: 100,
+            lst_sol_value: 300,
+        };
+        // midpoint is 10% under target
+        assert_eq!(lst_fee_bps(SwapSide::Input, args, 1_000, 200).unwrap(), -9);
+        // midpoint is 30% under target
+        assert_eq!(lst_fee_bps(SwapSide::Output, args, 1_000, 200).unwrap(), 31);
+    }
+
+    prop_compose! {
+        fn pool_lst_and_swap()
+            (pool_total_sol_value in 1..=MAX_TOTAL_SOL_VALUE)
+            (
+                lst_sol_value in 0..=pool_total_sol_value,
+                swap_sol_value in 0..=pool_total_sol_value,
+                pool_total_sol_value in Just(pool_total_sol_value),
+            ) -> (u64, u64, u64) {
+                (pool_total_sol_value, lst_sol_value, swap_sol_value)
+            }
+    }
+
+    prop_compose! {
+        fn fee_params()
+            (
+                base_fee_bps in -10_000..=10_000i16,
+                target_weight_bps in 0..=10_000u16,
+                imbalance_fee_bps in 0..=10_000u16,
+            ) -> (i16, u16, u16) {
+                (base_fee_bps, target_weight_bps, imbalance_fee_bps)
+            }
+    }
+
+    /// Returns (lst_sol_value, target_sol_value, swap_sol_value), all scaled by BPS_DENOMINATOR
+    fn scaled(
+        target_weight_bps: u16,
+        pool_total_sol_value: u64,
+        lst_sol_value: u64,
+        swap_sol_value: u64,
+    ) -> (u128, u128, u128) {
+        let bps_denom = u128::from(BPS_DENOMINATOR);
+        (
+            u128::from(lst_sol_value) * bps_denom,
+            u128::from(target_weight_bps) * u128::from(pool_total_sol_value),
+            u128::from(swap_sol_value) * bps_denom,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn moving_away_from_target_never_cheaper_than_base(
+            (pool_total_sol_value, lst_sol_value, swap_sol_value) in pool_lst_and_swap(),
+            (base_fee_bps, target_weight_bps, imbalance_fee_bps) in fee_params(),
+        ) {
+            let args = LstFeeArgs { base_fee_bps, target_weight_bps, imbalance_fee_bps, lst_sol_value };
+            let (lst, target, _swap) =
+                scaled(target_weight_bps, pool_total_sol_value, lst_sol_value, swap_sol_value);
+            if lst >= target {
+                let fee = lst_fee_bps(SwapSide::Input, args, pool_total_sol_value, swap_sol_value).unwrap();
+                prop_assert!(fee >= i64::from(base_fee_bps));
+            }
+            if lst <= target {
+                let fee = lst_fee_bps(SwapSide::Output, args, pool_total_sol_value, swap_sol_value).unwrap();
+                prop_assert!(fee >= i64::from(base_fee_bps));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn moving_towards_target_never_pricier_than_base(
+            (pool_total_sol_value, lst_sol_value, swap_sol_value) in pool_lst_and_swap(),
+            (base_fee_bps, target_weight_bps, imbalance_fee_bps) in fee_params(),
+        ) {
+            let args = LstFeeArgs { base_fee_bps, target_weight_bps, imbalance_fee_bps, lst_sol_value };
+            let (lst, target, swap) =
+                scaled(target_weight_bps, pool_total_sol_value, lst_sol_value, swap_sol_value);
+            if lst + swap <= target {
+                let fee = lst_fee_bps(SwapSide::Input, args, pool_total_sol_value, swap_sol_value).unwrap();
+                prop_assert!(fee <= i64::from(base_fee_bps));
+            }
+            if lst >= target + swap {
+                let fee = lst_fee_bps(SwapSide::Output, args, pool_total_sol_value, swap_sol_value).unwrap();
+                prop_assert!(fee <= i64::from(base_fee_bps));
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use dynamic_fee_interface::DynamicFeeError;
+use sanctum_token_ratio::ReversibleRatio;
+
+use super::{
+    common::{out_sol_value_ratio, OutSolValueRatioArgs},
+    LstFeeArgs,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CalculatePriceExactInArgs {
+    pub input: LstFeeArgs,
+    pub output: LstFeeArgs,
+    pub pool_total_sol_value: u64,
+    pub in_sol_value: u64,
+}
+
+/// Returns `out_sol_value`
+pub fn calculate_price_exact_in(
+    CalculatePriceExactInArgs {
+        input,
+        output,
+        pool_total_sol_value,
+        in_sol_value,
+    }: CalculatePriceExactInArgs,
+) -> Result<u64, DynamicFeeError> {
+    out_sol_value_ratio(OutSolValueRatioArgs {
+        input,
+        output,
+        pool_total_sol_value,
+        swap_sol_value: in_sol_value,
+    })?
+    .apply(in_sol_value)
+    .map_err(|_e| DynamicFeeError::MathError)
+}
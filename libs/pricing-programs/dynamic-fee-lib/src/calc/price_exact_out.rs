@@ -0,0 +1,262 @@
+use dynamic_fee_interface::DynamicFeeError;
+use sanctum_token_ratio::BPS_DENOMINATOR;
+
+use super::{
+    common::{out_sol_value_ratio, OutSolValueRatioArgs},
+    LstFeeArgs,
+};
+
+/// Each iteration that does not return raises the fee by at least 1 bps,
+/// so this only errors for extreme fee curves, typically when `out_sol_value`
+/// is close to the most the swap can output. Bounds compute usage.
+pub const MAX_PRICE_EXACT_OUT_ITERATIONS: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CalculatePriceExactOutArgs {
+    pub input: LstFeeArgs,
+    pub output: LstFeeArgs,
+    pub pool_total_sol_value: u64,
+    pub out_sol_value: u64,
+}
+
+/// Returns `in_sol_value`, the smallest input SOL value that
+/// [`calculate_price_exact_in`](super::calculate_price_exact_in) prices at `out_sol_value` or more.
+///
+/// Fees are charged on the swap's input SOL value, which is what is being solved for.
+/// Since the fee never decreases as the input SOL value grows, starting from 0 and repeatedly
+/// taking the input SOL value required to cover `out_sol_value` at the previous one's fee
+/// converges to the smallest one, taking one step per fee rate crossed.
+///
+/// Errors with [`DynamicFeeError::PriceExactOutNotConverged`] if it has not converged
+/// after [`MAX_PRICE_EXACT_OUT_ITERATIONS`] steps.
+pub fn calculate_price_exact_out(
+    CalculatePriceExactOutArgs {
+        input,
+        output,
+        pool_total_sol_value,
+        out_sol_value,
+    }: CalculatePriceExactOutArgs,
+) -> Result<u64, DynamicFeeError> {
+    if out_sol_value == 0 {
+        return Ok(0);
+    }
+    let mut in_sol_value = 0;
+    for _ in 0..MAX_PRICE_EXACT_OUT_ITERATIONS {
+        let post_fee_bps = out_sol_value_ratio(OutSolValueRatioArgs {
+            input,
+            output,
+            pool_total_sol_value,
+            swap_sol_value: in_sol_value,
+        })?
+        .0
+        .num;
+        if post_fee_bps == 0 {
+            return Err(DynamicFeeError::MathError);
+        }
+        // smallest in_sol_value with floor(in_sol_value * post_fee_bps / 10_000) >= out_sol_value,
+        // ceil(out_sol_value * 10_000 / post_fee_bps)
+        let num = u128::from(out_sol_value) * u128::from(BPS_DENOMINATOR);
+        let denom = u128::from(post_fee_bps);
+        let required: u64 = ((num + denom - 1) / denom)
+            .try_into()
+            .map_err(|_e| DynamicFeeError::MathError)?;
+        if required == in_sol_value {
+            return Ok(in_sol_value);
+        }
+        in_sol_value = required;
+    }
+    Err(DynamicFeeError::PriceExactOutNotConverged)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::calc::{calculate_price_exact_in, CalculatePriceExactInArgs};
+
+    use super::*;
+
+    const MAX_TOTAL_SOL_VALUE: u64 = 1_000_000_000_000_000_000;
+
+    prop_compose! {
+        fn lst_fee_args(pool_total_sol_value: u64)
+            (
+                base_fee_bps in -3_000..=3_000i16,
+                target_weight_bps in 0..=10_000u16,
+                imbalance_fee_bps in 0..=3_000u16,
+                lst_sol_value in 0..=pool_total_sol_value,
+            ) -> LstFeeArgs {
+                LstFeeArgs { base_fee_bps, target_weight_bps, imbalance_fee_bps, lst_sol_value }
+            }
+    }
+
+    prop_compose! {
+        fn pool_and_lsts()
+            (pool_total_sol_value in 0..=MAX_TOTAL_SOL_VALUE)
+            (
+                input in lst_fee_args(pool_total_sol_value),
+                output in lst_fee_args(pool_total_sol_value),
+                pool_total_sol_value in Just(pool_total_sol_value),
+            ) -> (u64, LstFeeArgs, LstFeeArgs) {
+                (pool_total_sol_value, input, output)
+            }
+    }
+
+    fn price_exact_in(
+        (pool_total_sol_value, input, output): (u64, LstFeeArgs, LstFeeArgs),
+        in_sol_value: u64,
+    ) -> Result<u64, DynamicFeeError> {
+        calculate_price_exact_in(CalculatePriceExactInArgs {
+            input,
+            output,
+            pool_total_sol_value,
+            in_sol_value,
+        })
+    }
+
+    fn price_exact_out(
+        (pool_total_sol_value, input, output): (u64, LstFeeArgs, LstFeeArgs),
+        out_sol_value: u64,
+    ) -> Result<u64, DynamicFeeError> {
+        calculate_price_exact_out(CalculatePriceExactOutArgs {
+            input,
+            output,
+            pool_total_sol_value,
+            out_sol_value,
+        })
+    }
+
+    #[test]
+    fn exact_out_charges_fee_on_input() {
+        // balanced pool, 10% imbalance fee on either side, no base fees
+        let lst = LstFeeArgs {
+            base_fee_bps: 0,
+            target_weight_bps: 5_000,
+            imbalance_fee_bps: 1_000,
+            lst_sol_value: 5_000,
+        };
+        let pool = (10_000, lst, lst);
+        // 1000 in moves each LST 10% of the pool off target, midpoint 5%: 50 bps on each side
+        assert_eq!(price_exact_in(pool, 1_000).unwrap(), 990);
+        assert_eq!(price_exact_out(pool, 990).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn rebate_bounded_by_input() {
+        let lst = LstFeeArgs {
+            base_fee_bps: -7_000,
+            target_weight_bps: 0,
+            imbalance_fee_bps: 0,
+            lst_sol_value: 0,
+        };
+        let pool = (10_000, lst, lst);
+        assert_eq!(price_exact_in(pool, 1_000).unwrap(), 2_000);
+        assert_eq!(price_exact_out(pool, 2_000).unwrap(), 1_000);
+    }
+
+    proptest! {
+        #[test]
+        fn exact_in_of_exact_out_covers_out(
+            pool in pool_and_lsts(),
+            out_sol_value in 0..=MAX_TOTAL_SOL_VALUE,
+        ) {
+            let in_sol_value = match price_exact_out(pool, out_sol_value) {
+                Ok(v) => v,
+                // out_sol_value is unreachable, fee goes over 100% first
+                Err(_e) => return Ok(()),
+            };
+            prop_assert!(price_exact_in(pool, in_sol_value).unwrap() >= out_sol_value);
+            if in_sol_value > 0 {
+                prop_assert!(price_exact_in(pool, in_sol_value - 1).unwrap() < out_sol_value);
+            }
+        }
+    }
+
+    prop_compose! {
+        /// An LST that is either entirely absent from the pool or makes up all of it,
+        /// with a target weight of 0 or 100%
+        fn extreme_lst_fee_args(pool_total_sol_value: u64)
+            (
+                base_fee_bps in -3_000..=3_000i16,
+                target_weight_bps in prop_oneof![Just(0), Just(10_000u16)],
+                imbalance_fee_bps in 0..=10_000u16,
+                lst_sol_value in prop_oneof![Just(0), Just(pool_total_sol_value)],
+            ) -> LstFeeArgs {
+                LstFeeArgs { base_fee_bps, target_weight_bps, imbalance_fee_bps, lst_sol_value }
+            }
+    }
+
+    prop_compose! {
+        fn pool_and_extreme_lsts()
+            (pool_total_sol_value in 1..=MAX_TOTAL_SOL_VALUE)
+            (
+                input in extreme_lst_fee_args(pool_total_sol_value),
+                output in extreme_lst_fee_args(pool_total_sol_value),
+                pool_total_sol_value in Just(pool_total_sol_value),
+            ) -> (u64, LstFeeArgs, LstFeeArgs) {
+                (pool_total_sol_value, input, output)
+            }
+    }
+
+    #[test]
+    fn exact_out_errors_if_not_converged() {
+        // the swap's output peaks at a total fee of 50%,
+        // approaching which each step raises the fee by only a few bps
+        let input = LstFeeArgs {
+            base_fee_bps: 0,
+            target_weight_bps: 0,
+            imbalance_fee_bps: 10_000,
+            lst_sol_value: 0,
+        };
+        let output = LstFeeArgs {
+            target_weight_bps: 10_000,
+            lst_sol_value: 1_000_000_000,
+            ..input
+        };
+        let pool = (1_000_000_000, input, output);
+        let max_out_sol_value = (0..=1_000u64)
+            .map(|i| price_exact_in(pool, i * 1_000_000).unwrap_or_default())
+            .max()
+            .unwrap();
+        assert_eq!(
+            price_exact_out(pool, max_out_sol_value),
+            Err(DynamicFeeError::PriceExactOutNotConverged)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn exact_out_at_extreme_deviations_is_exact_or_errors(
+            pool in pool_and_extreme_lsts(),
+            out_sol_value in 0..=MAX_TOTAL_SOL_VALUE,
+        ) {
+            let in_sol_value = match price_exact_out(pool, out_sol_value) {
+                Ok(v) => v,
+                // fee goes over 100% or does not converge within the iteration limit
+                Err(_e) => return Ok(()),
+            };
+            prop_assert!(price_exact_in(pool, in_sol_value).unwrap() >= out_sol_value);
+            if in_sol_value > 0 {
+                prop_assert!(price_exact_in(pool, in_sol_value - 1).unwrap() < out_sol_value);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn exact_out_of_exact_in_never_costs_more(
+            pool in pool_and_lsts(),
+            in_sol_value in 0..=MAX_TOTAL_SOL_VALUE,
+        ) {
+            let out_sol_value = match price_exact_in(pool, in_sol_value) {
+                Ok(v) => v,
+                // fee over 100%
+                Err(_e) => return Ok(()),
+            };
+            match price_exact_out(pool, out_sol_value) {
+                Ok(v) => prop_assert!(v <= in_sol_value),
+                Err(e) => prop_assert_eq!(e, DynamicFeeError::PriceExactOutNotConverged),
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use dynamic_fee_interface::DynamicFeeError;
+
+const MAX_SIGNED_FEE_BPS: i16 = 10_000;
+const MAX_UNSIGNED_FEE_BPS: u16 = 10_000;
+const MAX_TARGET_WEIGHT_BPS: u16 = 10_000;
+
+pub fn verify_signed_fee_bps_bound(fee_bps_i16: i16) -> Result<(), DynamicFeeError> {
+    if !(-MAX_SIGNED_FEE_BPS..=MAX_SIGNED_FEE_BPS).contains(&fee_bps_i16) {
+        return Err(DynamicFeeError::SignedFeeOutOfBound);
+    }
+    Ok(())
+}
+
+pub fn verify_unsigned_fee_bps_bound(fee_bps_u16: u16) -> Result<(), DynamicFeeError> {
+    if !(0..=MAX_UNSIGNED_FEE_BPS).contains(&fee_bps_u16) {
+        return Err(DynamicFeeError::UnsignedFeeOutOfBound);
+    }
+    Ok(())
+}
+
+pub fn verify_target_weight_bps_bound(target_weight_bps: u16) -> Result<(), DynamicFeeError> {
+    if target_weight_bps > MAX_TARGET_WEIGHT_BPS {
+        return Err(DynamicFeeError::TargetWeightOutOfBound);
+    }
+    Ok(())
+}
+
+/// Verifies all the manager-set fields of a FeeAccount
+pub fn verify_lst_fee_bounds(
+    input_fee_bps: i16,
+    output_fee_bps: i16,
+    target_weight_bps: u16,
+    imbalance_fee_bps: u16,
+) -> Result<(), DynamicFeeError> {
+    verify_signed_fee_bps_bound(input_fee_bps)?;
+    verify_signed_fee_bps_bound(output_fee_bps)?;
+    verify_target_weight_bps_bound(target_weight_bps)?;
+    verify_unsigned_fee_bps_bound(imbalance_fee_bps)
+}
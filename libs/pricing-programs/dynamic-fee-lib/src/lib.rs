@@ -0,0 +1,37 @@
+pub mod account_resolvers;
+pub mod calc;
+pub mod fee_bound;
+pub mod pda;
+pub mod utils;
+
+pub mod program {
+    pub const STATE_SIZE: usize = 34;
+    pub const FEE_ACCOUNT_SIZE: usize = 10;
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<dynamic_fee_interface::ProgramState>(),
+        STATE_SIZE,
+    );
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<dynamic_fee_interface::FeeAccount>(),
+        FEE_ACCOUNT_SIZE,
+    );
+
+    sanctum_macros::declare_program_keys!(
+        "F6cSovKR9KficSkohucRZ7Nz9SWAH7Mdt6jBuhmaMWpU",
+        [("state", b"state")]
+    );
+}
+
+pub mod initial_constants {
+    pub mod initial_manager {
+        #[cfg(feature = "testing")]
+        sanctum_macros::declare_program_keys!("J5aMuYiKNHUzMTpUS85413DxxvDVjNXs63EXW5twG1Mx", []);
+
+        #[cfg(not(feature = "testing"))]
+        sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
+    }
+
+    pub const INITIAL_LP_WITHDRAWAL_FEE_BPS: u16 = 5;
+}
@@ -0,0 +1,47 @@
+use bytemuck::{try_from_bytes, try_from_bytes_mut};
+
+use dynamic_fee_interface::{DynamicFeeError, FeeAccount, ProgramState};
+use s_controller_interface::LstState;
+use s_controller_lib::{create_pool_reserves_address, try_find_lst_mint_on_list};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+pub fn try_program_state(program_state_acc_data: &[u8]) -> Result<&ProgramState, DynamicFeeError> {
+    try_from_bytes(program_state_acc_data).map_err(|_e| DynamicFeeError::InvalidProgramStateData)
+}
+
+pub fn try_program_state_mut(
+    program_state_acc_data: &mut [u8],
+) -> Result<&mut ProgramState, DynamicFeeError> {
+    try_from_bytes_mut(program_state_acc_data)
+        .map_err(|_e| DynamicFeeError::InvalidProgramStateData)
+}
+
+pub fn try_fee_account(fee_acc_data: &[u8]) -> Result<&FeeAccount, DynamicFeeError> {
+    try_from_bytes(fee_acc_data).map_err(|_e| DynamicFeeError::UnsupportedLstMint)
+}
+
+pub fn try_fee_account_mut(fee_acc_data: &mut [u8]) -> Result<&mut FeeAccount, DynamicFeeError> {
+    try_from_bytes_mut(fee_acc_data).map_err(|_e| DynamicFeeError::UnsupportedLstMint)
+}
+
+/// Returns the `sol_value` of `lst_mint` on the S controller's LST state list
+pub fn try_lst_sol_value(
+    lst_mint: &Pubkey,
+    lst_state_list: &[LstState],
+) -> Result<u64, DynamicFeeError> {
+    try_find_lst_mint_on_list(*lst_mint, lst_state_list)
+        .map(|(_i, lst_state)| lst_state.sol_value)
+        .map_err(|_e| DynamicFeeError::LstNotInPool)
+}
+
+/// Returns the address of the pool's reserves token account for `lst_mint`,
+/// created from its `LstState` on the S controller's LST state list and `token_program`
+pub fn try_pool_reserves_address(
+    lst_mint: &Pubkey,
+    lst_state_list: &[LstState],
+    token_program: Pubkey,
+) -> Result<Pubkey, ProgramError> {
+    let (_i, lst_state) = try_find_lst_mint_on_list(*lst_mint, lst_state_list)
+        .map_err(|_e| DynamicFeeError::LstNotInPool)?;
+    Ok(create_pool_reserves_address(lst_state, token_program)?)
+}
@@ -0,0 +1,7 @@
+mod dynamic_fee_program_test;
+mod fee_account;
+mod state;
+
+pub use dynamic_fee_program_test::*;
+pub use fee_account::*;
+pub use state::*;
@@ -5,7 +5,7 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 use crate::{
     pda::{FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
     program::{self, STATE_ID},
-    utils::try_program_state,
+    utils::try_program_state_allow_legacy,
 };
 
 pub struct AddLstFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
@@ -46,7 +46,7 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> AddLstFreeArgs<S> {
         }
 
         let bytes = &state_acc.data();
-        let state: &ProgramState = try_program_state(bytes)?;
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
 
         let find_pda_args = FeeAccountFindPdaArgs {
             lst_mint,
@@ -0,0 +1,86 @@
+use flat_fee_interface::{AddPairFeeKeys, FlatFeeError, ProgramState};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::{
+        FeeAccountFindPdaArgs, PairFeeAccountCreatePdaArgs, PairFeeAccountFindPdaArgs,
+        ProgramStateFindPdaArgs,
+    },
+    program::{self, STATE_ID},
+    utils::try_program_state_allow_legacy,
+};
+
+pub struct AddPairFeeFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub payer: Pubkey,
+    pub state_acc: S,
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> AddPairFeeFreeArgs<S> {
+    pub fn resolve(self) -> Result<(AddPairFeeKeys, PairFeeAccountCreatePdaArgs), FlatFeeError> {
+        self.resolve_inner(STATE_ID, program::ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<(AddPairFeeKeys, PairFeeAccountCreatePdaArgs), FlatFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<(AddPairFeeKeys, PairFeeAccountCreatePdaArgs), FlatFeeError> {
+        let Self {
+            payer,
+            state_acc,
+            input_lst_mint,
+            output_lst_mint,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(FlatFeeError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
+
+        let find_pda_args = PairFeeAccountFindPdaArgs {
+            program_id,
+            input_lst_mint,
+            output_lst_mint,
+        };
+        let (pair_fee_acc, bump) = find_pda_args.get_pair_fee_account_address_and_bump_seed();
+
+        let (input_fee_acc, _bump) = FeeAccountFindPdaArgs {
+            program_id,
+            lst_mint: input_lst_mint,
+        }
+        .get_fee_account_address_and_bump_seed();
+
+        Ok((
+            AddPairFeeKeys {
+                manager: state.manager,
+                payer,
+                pair_fee_acc,
+                input_fee_acc,
+                input_lst_mint,
+                output_lst_mint,
+                state: state_id,
+                system_program: system_program::ID,
+            },
+            PairFeeAccountCreatePdaArgs {
+                find_pda_args,
+                bump,
+            },
+        ))
+    }
+}
@@ -0,0 +1,21 @@
+use flat_fee_interface::MigrateAccountKeys;
+use solana_program::{pubkey::Pubkey, system_program};
+
+pub struct MigrateAccountFreeArgs {
+    pub payer: Pubkey,
+    pub migrate_acc: Pubkey,
+    /// Mint of the LST whose FeeAccount is migrated.
+    /// None when migrating the ProgramState, which is then passed by convention
+    pub lst_mint: Option<Pubkey>,
+}
+
+impl MigrateAccountFreeArgs {
+    pub fn resolve(&self) -> MigrateAccountKeys {
+        MigrateAccountKeys {
+            payer: self.payer,
+            migrate_acc: self.migrate_acc,
+            lst_mint: self.lst_mint.unwrap_or(self.migrate_acc),
+            system_program: system_program::ID,
+        }
+    }
+}
@@ -5,28 +5,46 @@ use solana_program::{
 };
 
 use crate::{
-    pda::{FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs},
+    pda::{
+        FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs, LstSurchargeFindPdaArgs,
+        SurgeConfigFindPdaArgs,
+    },
     program as flat_fee_program,
 };
 
 /// Uses find_program_address, for use with
 /// - initial creation
 /// - client side
+///
+/// `input_stake_pool` and `output_stake_pool` should be the stake pools
+/// recorded in the LSTs' LstSurcharge accounts.
+/// For LSTs without a LstSurcharge account, any account may be passed,
+/// the LST mint is used by convention.
 pub struct PriceExactInFreeArgs {
     pub input_lst_mint: Pubkey,
     pub output_lst_mint: Pubkey,
+    pub pool_state: Pubkey,
+    pub lst_state_list: Pubkey,
+    pub input_pool_reserves: Pubkey,
+    pub output_pool_reserves: Pubkey,
+    pub input_stake_pool: Pubkey,
+    pub output_stake_pool: Pubkey,
 }
 
 impl PriceExactInFreeArgs {
     pub fn resolve(self) -> PriceExactInKeys {
-        self.resolve_inner(flat_fee_program::ID)
+        self.resolve_inner(flat_fee_program::ID, flat_fee_program::SURGE_CONFIG_ID)
     }
 
     pub fn resolve_for_prog(self, program_id: Pubkey) -> PriceExactInKeys {
-        self.resolve_inner(program_id)
+        let surge_config_id = SurgeConfigFindPdaArgs { program_id }
+            .get_surge_config_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(program_id, surge_config_id)
     }
 
-    fn resolve_inner(self, program_id: Pubkey) -> PriceExactInKeys {
+    fn resolve_inner(self, program_id: Pubkey, surge_config_id: Pubkey) -> PriceExactInKeys {
         let input_find_pda_args = FeeAccountFindPdaArgs {
             lst_mint: self.input_lst_mint,
             program_id,
@@ -39,11 +57,31 @@ impl PriceExactInFreeArgs {
         };
         let (output_fee_acc, _bump) = output_find_pda_args.get_fee_account_address_and_bump_seed();
 
+        let (input_surcharge_acc, _bump) = LstSurchargeFindPdaArgs {
+            lst_mint: self.input_lst_mint,
+            program_id,
+        }
+        .get_lst_surcharge_address_and_bump_seed();
+        let (output_surcharge_acc, _bump) = LstSurchargeFindPdaArgs {
+            lst_mint: self.output_lst_mint,
+            program_id,
+        }
+        .get_lst_surcharge_address_and_bump_seed();
+
         PriceExactInKeys {
             input_lst_mint: self.input_lst_mint,
             output_lst_mint: self.output_lst_mint,
+            pool_state: self.pool_state,
+            lst_state_list: self.lst_state_list,
+            input_pool_reserves: self.input_pool_reserves,
+            output_pool_reserves: self.output_pool_reserves,
             input_fee_acc,
             output_fee_acc,
+            surge_config: surge_config_id,
+            input_surcharge_acc,
+            output_surcharge_acc,
+            input_stake_pool: self.input_stake_pool,
+            output_stake_pool: self.output_stake_pool,
         }
     }
 
@@ -53,22 +91,60 @@ impl PriceExactInFreeArgs {
     }
 }
 
+impl From<pricing_programs_interface::PriceExactInKeys> for PriceExactInFreeArgs {
+    /// Stake pools are set to the LST mints, override them
+    /// for LSTs that have a LstSurcharge account
+    fn from(
+        pricing_programs_interface::PriceExactInKeys {
+            input_lst_mint,
+            output_lst_mint,
+            pool_state,
+            lst_state_list,
+            input_pool_reserves,
+            output_pool_reserves,
+        }: pricing_programs_interface::PriceExactInKeys,
+    ) -> Self {
+        Self {
+            input_lst_mint,
+            output_lst_mint,
+            pool_state,
+            lst_state_list,
+            input_pool_reserves,
+            output_pool_reserves,
+            input_stake_pool: input_lst_mint,
+            output_stake_pool: output_lst_mint,
+        }
+    }
+}
+
 pub struct PriceExactInWithBumpFreeArgs {
     pub args: PriceExactInFreeArgs,
     pub input_fee_acc_bump: u8,
     pub output_fee_acc_bump: u8,
+    /// None if the input LST's LstSurcharge account is not initialized
+    pub input_surcharge_acc_bump: Option<u8>,
+    /// None if the output LST's LstSurcharge account is not initialized
+    pub output_surcharge_acc_bump: Option<u8>,
 }
 
 impl PriceExactInWithBumpFreeArgs {
     pub fn resolve(self) -> Result<PriceExactInKeys, PubkeyError> {
-        self.resolve_inner(flat_fee_program::ID)
+        self.resolve_inner(flat_fee_program::ID, flat_fee_program::SURGE_CONFIG_ID)
     }
 
     pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<PriceExactInKeys, PubkeyError> {
-        self.resolve_inner(program_id)
+        let surge_config_id = SurgeConfigFindPdaArgs { program_id }
+            .get_surge_config_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(program_id, surge_config_id)
     }
 
-    fn resolve_inner(self, program_id: Pubkey) -> Result<PriceExactInKeys, PubkeyError> {
+    fn resolve_inner(
+        self,
+        program_id: Pubkey,
+        surge_config_id: Pubkey,
+    ) -> Result<PriceExactInKeys, PubkeyError> {
         let input_create_pda_args = FeeAccountCreatePdaArgs {
             find_pda_args: FeeAccountFindPdaArgs {
                 lst_mint: self.args.input_lst_mint,
@@ -87,11 +163,31 @@ impl PriceExactInWithBumpFreeArgs {
         };
         let output_fee_acc = output_create_pda_args.get_fee_account_address()?;
 
+        let input_surcharge_acc = LstSurchargeFindPdaArgs {
+            lst_mint: self.args.input_lst_mint,
+            program_id,
+        }
+        .get_lst_surcharge_address_with_bump_opt(self.input_surcharge_acc_bump)?;
+        let output_surcharge_acc = LstSurchargeFindPdaArgs {
+            lst_mint: self.args.output_lst_mint,
+            program_id,
+        }
+        .get_lst_surcharge_address_with_bump_opt(self.output_surcharge_acc_bump)?;
+
         Ok(PriceExactInKeys {
             input_lst_mint: self.args.input_lst_mint,
             output_lst_mint: self.args.output_lst_mint,
+            pool_state: self.args.pool_state,
+            lst_state_list: self.args.lst_state_list,
+            input_pool_reserves: self.args.input_pool_reserves,
+            output_pool_reserves: self.args.output_pool_reserves,
             input_fee_acc,
             output_fee_acc,
+            surge_config: surge_config_id,
+            input_surcharge_acc,
+            output_surcharge_acc,
+            input_stake_pool: self.args.input_stake_pool,
+            output_stake_pool: self.args.output_stake_pool,
         })
     }
 }
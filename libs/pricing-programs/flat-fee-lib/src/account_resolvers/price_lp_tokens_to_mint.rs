@@ -0,0 +1,122 @@
+use flat_fee_interface::{PriceLpTokensToMintKeys, PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN};
+use solana_program::{
+    instruction::AccountMeta,
+    pubkey::{Pubkey, PubkeyError},
+};
+
+use crate::{
+    pda::{FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
+    program as flat_fee_program,
+};
+
+/// Uses find_program_address, for use with
+/// - initial creation
+/// - client side
+pub struct PriceLpTokensToMintFreeArgs {
+    pub input_lst_mint: Pubkey,
+    pub pool_state: Pubkey,
+    pub lst_state_list: Pubkey,
+    pub pool_reserves: Pubkey,
+}
+
+impl PriceLpTokensToMintFreeArgs {
+    pub fn resolve(&self) -> PriceLpTokensToMintKeys {
+        self.resolve_inner(flat_fee_program::ID, flat_fee_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(&self, program_id: Pubkey) -> PriceLpTokensToMintKeys {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(program_id, state_id)
+    }
+
+    fn resolve_inner(&self, program_id: Pubkey, state_id: Pubkey) -> PriceLpTokensToMintKeys {
+        let (fee_acc, _bump) = FeeAccountFindPdaArgs {
+            lst_mint: self.input_lst_mint,
+            program_id,
+        }
+        .get_fee_account_address_and_bump_seed();
+
+        PriceLpTokensToMintKeys {
+            input_lst_mint: self.input_lst_mint,
+            pool_state: self.pool_state,
+            lst_state_list: self.lst_state_list,
+            pool_reserves: self.pool_reserves,
+            state: state_id,
+            fee_acc,
+        }
+    }
+
+    pub fn resolve_to_account_metas(
+        &self,
+    ) -> [AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN] {
+        let keys = self.resolve();
+        keys.into()
+    }
+}
+
+impl From<pricing_programs_interface::PriceLpTokensToMintKeys> for PriceLpTokensToMintFreeArgs {
+    fn from(
+        pricing_programs_interface::PriceLpTokensToMintKeys {
+            input_lst_mint,
+            pool_state,
+            lst_state_list,
+            pool_reserves,
+        }: pricing_programs_interface::PriceLpTokensToMintKeys,
+    ) -> Self {
+        Self {
+            input_lst_mint,
+            pool_state,
+            lst_state_list,
+            pool_reserves,
+        }
+    }
+}
+
+pub struct PriceLpTokensToMintWithBumpFreeArgs {
+    pub args: PriceLpTokensToMintFreeArgs,
+    pub fee_acc_bump: u8,
+}
+
+impl PriceLpTokensToMintWithBumpFreeArgs {
+    pub fn resolve(&self) -> Result<PriceLpTokensToMintKeys, PubkeyError> {
+        self.resolve_inner(flat_fee_program::ID, flat_fee_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<PriceLpTokensToMintKeys, PubkeyError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(program_id, state_id)
+    }
+
+    fn resolve_inner(
+        &self,
+        program_id: Pubkey,
+        state_id: Pubkey,
+    ) -> Result<PriceLpTokensToMintKeys, PubkeyError> {
+        let fee_acc = FeeAccountCreatePdaArgs {
+            find_pda_args: FeeAccountFindPdaArgs {
+                lst_mint: self.args.input_lst_mint,
+                program_id,
+            },
+            bump: self.fee_acc_bump,
+        }
+        .get_fee_account_address()?;
+
+        Ok(PriceLpTokensToMintKeys {
+            input_lst_mint: self.args.input_lst_mint,
+            pool_state: self.args.pool_state,
+            lst_state_list: self.args.lst_state_list,
+            pool_reserves: self.args.pool_reserves,
+            state: state_id,
+            fee_acc,
+        })
+    }
+}
@@ -1,29 +1,67 @@
 use flat_fee_interface::{PriceLpTokensToRedeemKeys, PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN};
-use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program::{
+    instruction::AccountMeta,
+    pubkey::{Pubkey, PubkeyError},
+};
 
-use crate::{pda::ProgramStateFindPdaArgs, program as flat_fee_program};
+use crate::{
+    pda::{
+        FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs, ProgramStateFindPdaArgs,
+        SurgeConfigFindPdaArgs,
+    },
+    program as flat_fee_program,
+};
 
+/// Uses find_program_address, for use with
+/// - initial creation
+/// - client side
 pub struct PriceLpTokensToRedeemFreeArgs {
     pub output_lst_mint: Pubkey,
+    pub pool_state: Pubkey,
+    pub lst_state_list: Pubkey,
+    pub pool_reserves: Pubkey,
 }
 
 impl PriceLpTokensToRedeemFreeArgs {
     pub fn resolve(&self) -> PriceLpTokensToRedeemKeys {
-        self.resolve_inner(flat_fee_program::STATE_ID)
+        self.resolve_inner(
+            flat_fee_program::ID,
+            flat_fee_program::STATE_ID,
+            flat_fee_program::SURGE_CONFIG_ID,
+        )
     }
 
     pub fn resolve_for_prog(&self, program_id: Pubkey) -> PriceLpTokensToRedeemKeys {
         let state_id = ProgramStateFindPdaArgs { program_id }
             .get_program_state_address_and_bump_seed()
             .0;
+        let surge_config_id = SurgeConfigFindPdaArgs { program_id }
+            .get_surge_config_address_and_bump_seed()
+            .0;
 
-        self.resolve_inner(state_id)
+        self.resolve_inner(program_id, state_id, surge_config_id)
     }
 
-    fn resolve_inner(&self, state_id: Pubkey) -> PriceLpTokensToRedeemKeys {
+    fn resolve_inner(
+        &self,
+        program_id: Pubkey,
+        state_id: Pubkey,
+        surge_config_id: Pubkey,
+    ) -> PriceLpTokensToRedeemKeys {
+        let (fee_acc, _bump) = FeeAccountFindPdaArgs {
+            lst_mint: self.output_lst_mint,
+            program_id,
+        }
+        .get_fee_account_address_and_bump_seed();
+
         PriceLpTokensToRedeemKeys {
             output_lst_mint: self.output_lst_mint,
+            pool_state: self.pool_state,
+            lst_state_list: self.lst_state_list,
+            pool_reserves: self.pool_reserves,
             state: state_id,
+            surge_config: surge_config_id,
+            fee_acc,
         }
     }
 
@@ -34,3 +72,76 @@ impl PriceLpTokensToRedeemFreeArgs {
         keys.into()
     }
 }
+
+impl From<pricing_programs_interface::PriceLpTokensToRedeemKeys> for PriceLpTokensToRedeemFreeArgs {
+    fn from(
+        pricing_programs_interface::PriceLpTokensToRedeemKeys {
+            output_lst_mint,
+            pool_state,
+            lst_state_list,
+            pool_reserves,
+        }: pricing_programs_interface::PriceLpTokensToRedeemKeys,
+    ) -> Self {
+        Self {
+            output_lst_mint,
+            pool_state,
+            lst_state_list,
+            pool_reserves,
+        }
+    }
+}
+
+pub struct PriceLpTokensToRedeemWithBumpFreeArgs {
+    pub args: PriceLpTokensToRedeemFreeArgs,
+    pub fee_acc_bump: u8,
+}
+
+impl PriceLpTokensToRedeemWithBumpFreeArgs {
+    pub fn resolve(&self) -> Result<PriceLpTokensToRedeemKeys, PubkeyError> {
+        self.resolve_inner(
+            flat_fee_program::ID,
+            flat_fee_program::STATE_ID,
+            flat_fee_program::SURGE_CONFIG_ID,
+        )
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<PriceLpTokensToRedeemKeys, PubkeyError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let surge_config_id = SurgeConfigFindPdaArgs { program_id }
+            .get_surge_config_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(program_id, state_id, surge_config_id)
+    }
+
+    fn resolve_inner(
+        &self,
+        program_id: Pubkey,
+        state_id: Pubkey,
+        surge_config_id: Pubkey,
+    ) -> Result<PriceLpTokensToRedeemKeys, PubkeyError> {
+        let fee_acc = FeeAccountCreatePdaArgs {
+            find_pda_args: FeeAccountFindPdaArgs {
+                lst_mint: self.args.output_lst_mint,
+                program_id,
+            },
+            bump: self.fee_acc_bump,
+        }
+        .get_fee_account_address()?;
+
+        Ok(PriceLpTokensToRedeemKeys {
+            output_lst_mint: self.args.output_lst_mint,
+            pool_state: self.args.pool_state,
+            lst_state_list: self.args.lst_state_list,
+            pool_reserves: self.args.pool_reserves,
+            state: state_id,
+            surge_config: surge_config_id,
+            fee_acc,
+        })
+    }
+}
@@ -5,7 +5,7 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 use crate::{
     pda::{FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
     program::{self, STATE_ID},
-    utils::try_program_state,
+    utils::try_program_state_allow_legacy,
 };
 
 pub struct RemoveLstFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
@@ -51,7 +51,7 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> RemoveLstFreeArgs<S> {
         let (fee_acc, _bump) = find_pda_args.get_fee_account_address_and_bump_seed();
 
         let bytes = &state_acc.data();
-        let state: &ProgramState = try_program_state(bytes)?;
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
 
         Ok(RemoveLstKeys {
             manager: state.manager,
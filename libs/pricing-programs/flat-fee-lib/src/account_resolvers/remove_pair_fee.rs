@@ -0,0 +1,75 @@
+use flat_fee_interface::{FlatFeeError, ProgramState, RemovePairFeeKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::{FeeAccountFindPdaArgs, PairFeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
+    program::{self, STATE_ID},
+    utils::try_program_state_allow_legacy,
+};
+
+pub struct RemovePairFeeFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub refund_rent_to: Pubkey,
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> RemovePairFeeFreeArgs<S> {
+    /// Uses find_program_address().
+    /// Ok to be inefficient since this is admin-facing
+    pub fn resolve(self) -> Result<RemovePairFeeKeys, FlatFeeError> {
+        self.resolve_inner(STATE_ID, program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<RemovePairFeeKeys, FlatFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<RemovePairFeeKeys, FlatFeeError> {
+        let RemovePairFeeFreeArgs {
+            refund_rent_to,
+            input_lst_mint,
+            output_lst_mint,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(FlatFeeError::IncorrectProgramState);
+        }
+
+        let (pair_fee_acc, _bump) = PairFeeAccountFindPdaArgs {
+            program_id,
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .get_pair_fee_account_address_and_bump_seed();
+
+        let (input_fee_acc, _bump) = FeeAccountFindPdaArgs {
+            program_id,
+            lst_mint: input_lst_mint,
+        }
+        .get_fee_account_address_and_bump_seed();
+
+        let bytes = &state_acc.data();
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
+
+        Ok(RemovePairFeeKeys {
+            manager: state.manager,
+            refund_rent_to,
+            pair_fee_acc,
+            input_fee_acc,
+            input_lst_mint,
+            output_lst_mint,
+            state: state_id,
+        })
+    }
+}
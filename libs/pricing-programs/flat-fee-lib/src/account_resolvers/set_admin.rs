@@ -0,0 +1,48 @@
+use flat_fee_interface::{FlatFeeError, ProgramState, SetAdminKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::ProgramStateFindPdaArgs,
+    program as flat_fee_program,
+    utils::{try_program_state_admin, try_program_state_allow_legacy},
+};
+
+pub struct SetAdminFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub new_admin: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetAdminFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetAdminKeys, FlatFeeError> {
+        self.resolve_inner(flat_fee_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<SetAdminKeys, FlatFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<SetAdminKeys, FlatFeeError> {
+        let SetAdminFreeArgs {
+            new_admin,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(FlatFeeError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
+
+        Ok(SetAdminKeys {
+            current_admin: try_program_state_admin(&state)?,
+            new_admin,
+            state: state_id,
+        })
+    }
+}
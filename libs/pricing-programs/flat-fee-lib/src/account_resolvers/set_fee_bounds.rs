@@ -0,0 +1,43 @@
+use flat_fee_interface::{FlatFeeError, ProgramState, SetFeeBoundsKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::ProgramStateFindPdaArgs,
+    program as flat_fee_program,
+    utils::{try_program_state_admin, try_program_state_allow_legacy},
+};
+
+pub struct SetFeeBoundsFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetFeeBoundsFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetFeeBoundsKeys, FlatFeeError> {
+        self.resolve_inner(flat_fee_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<SetFeeBoundsKeys, FlatFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<SetFeeBoundsKeys, FlatFeeError> {
+        let SetFeeBoundsFreeArgs { state_acc } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(FlatFeeError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
+
+        Ok(SetFeeBoundsKeys {
+            admin: try_program_state_admin(&state)?,
+            state: state_id,
+        })
+    }
+}
@@ -2,7 +2,10 @@ use flat_fee_interface::{FlatFeeError, ProgramState, SetLpWithdrawalFeeKeys};
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{pda::ProgramStateFindPdaArgs, program as flat_fee_program, utils::try_program_state};
+use crate::{
+    pda::ProgramStateFindPdaArgs, program as flat_fee_program,
+    utils::try_program_state_allow_legacy,
+};
 
 pub struct SetLpWithdrawalFeeFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
     pub state_acc: S,
@@ -32,7 +35,7 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetLpWithdrawalFeeFreeArgs<
         }
 
         let bytes = &state_acc.data();
-        let state: &ProgramState = try_program_state(bytes)?;
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
 
         Ok(SetLpWithdrawalFeeKeys {
             manager: state.manager,
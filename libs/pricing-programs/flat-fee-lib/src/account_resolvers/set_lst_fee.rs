@@ -5,7 +5,7 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 use crate::{
     pda::{FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
     program as flat_fee_program,
-    utils::try_program_state,
+    utils::try_program_state_allow_legacy,
 };
 
 pub struct SetLstFeeByMintFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
@@ -47,7 +47,7 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetLstFeeByMintFreeArgs<S>
         let (fee_acc, _bump) = find_pda_args.get_fee_account_address_and_bump_seed();
 
         let bytes = &state_acc.data();
-        let state: &ProgramState = try_program_state(bytes)?;
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
 
         Ok(SetLstFeeKeys {
             manager: state.manager,
@@ -86,7 +86,7 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetLstFeeFreeArgs<S> {
         }
 
         let bytes = &state_acc.data();
-        let state: &ProgramState = try_program_state(bytes)?;
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
 
         Ok(SetLstFeeKeys {
             manager: state.manager,
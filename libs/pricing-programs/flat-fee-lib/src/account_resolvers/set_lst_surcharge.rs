@@ -0,0 +1,75 @@
+use flat_fee_interface::{FlatFeeError, ProgramState, SetLstSurchargeKeys};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::{LstSurchargeCreatePdaArgs, LstSurchargeFindPdaArgs, ProgramStateFindPdaArgs},
+    program::{self, STATE_ID},
+    utils::try_program_state_allow_legacy,
+};
+
+pub struct SetLstSurchargeFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub payer: Pubkey,
+    pub state_acc: S,
+    pub lst_mint: Pubkey,
+    pub stake_pool: Pubkey,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetLstSurchargeFreeArgs<S> {
+    pub fn resolve(self) -> Result<(SetLstSurchargeKeys, LstSurchargeCreatePdaArgs), FlatFeeError> {
+        self.resolve_inner(STATE_ID, program::ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<(SetLstSurchargeKeys, LstSurchargeCreatePdaArgs), FlatFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<(SetLstSurchargeKeys, LstSurchargeCreatePdaArgs), FlatFeeError> {
+        let Self {
+            payer,
+            state_acc,
+            lst_mint,
+            stake_pool,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(FlatFeeError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
+
+        let find_pda_args = LstSurchargeFindPdaArgs {
+            lst_mint,
+            program_id,
+        };
+        let (surcharge_acc, bump) = find_pda_args.get_lst_surcharge_address_and_bump_seed();
+
+        Ok((
+            SetLstSurchargeKeys {
+                manager: state.manager,
+                payer,
+                surcharge_acc,
+                lst_mint,
+                stake_pool,
+                state: state_id,
+                system_program: system_program::ID,
+            },
+            LstSurchargeCreatePdaArgs {
+                find_pda_args,
+                bump,
+            },
+        ))
+    }
+}
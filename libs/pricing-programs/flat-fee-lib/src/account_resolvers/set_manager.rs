@@ -2,7 +2,10 @@ use flat_fee_interface::{FlatFeeError, ProgramState, SetManagerKeys};
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{pda::ProgramStateFindPdaArgs, program as flat_fee_program, utils::try_program_state};
+use crate::{
+    pda::ProgramStateFindPdaArgs, program as flat_fee_program,
+    utils::try_program_state_allow_legacy,
+};
 
 pub struct SetManagerFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
     pub new_manager: Pubkey,
@@ -33,7 +36,7 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetManagerFreeArgs<S> {
         }
 
         let bytes = &state_acc.data();
-        let state: &ProgramState = try_program_state(bytes)?;
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
 
         Ok(SetManagerKeys {
             current_manager: state.manager,
@@ -0,0 +1,100 @@
+use flat_fee_interface::{FlatFeeError, ProgramState, SetPairFeeKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::{PairFeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
+    program as flat_fee_program,
+    utils::try_program_state_allow_legacy,
+};
+
+pub struct SetPairFeeByMintsFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetPairFeeByMintsFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetPairFeeKeys, FlatFeeError> {
+        self.resolve_inner(flat_fee_program::STATE_ID, flat_fee_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<SetPairFeeKeys, FlatFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<SetPairFeeKeys, FlatFeeError> {
+        let SetPairFeeByMintsFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(FlatFeeError::IncorrectProgramState);
+        }
+
+        let (pair_fee_acc, _bump) = PairFeeAccountFindPdaArgs {
+            program_id,
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .get_pair_fee_account_address_and_bump_seed();
+
+        let bytes = &state_acc.data();
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
+
+        Ok(SetPairFeeKeys {
+            manager: state.manager,
+            pair_fee_acc,
+            state: state_id,
+        })
+    }
+}
+
+pub struct SetPairFeeFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub pair_fee_acc: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetPairFeeFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetPairFeeKeys, FlatFeeError> {
+        self.resolve_inner(flat_fee_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<SetPairFeeKeys, FlatFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<SetPairFeeKeys, FlatFeeError> {
+        let SetPairFeeFreeArgs {
+            pair_fee_acc,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(FlatFeeError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
+
+        Ok(SetPairFeeKeys {
+            manager: state.manager,
+            pair_fee_acc,
+            state: state_id,
+        })
+    }
+}
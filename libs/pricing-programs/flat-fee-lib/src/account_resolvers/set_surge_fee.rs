@@ -0,0 +1,57 @@
+use flat_fee_interface::{FlatFeeError, ProgramState, SetSurgeFeeKeys};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::{ProgramStateFindPdaArgs, SurgeConfigFindPdaArgs},
+    program as flat_fee_program,
+    utils::try_program_state_allow_legacy,
+};
+
+pub struct SetSurgeFeeFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub payer: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetSurgeFeeFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetSurgeFeeKeys, FlatFeeError> {
+        self.resolve_inner(
+            flat_fee_program::STATE_ID,
+            flat_fee_program::SURGE_CONFIG_ID,
+        )
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<SetSurgeFeeKeys, FlatFeeError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let surge_config_id = SurgeConfigFindPdaArgs { program_id }
+            .get_surge_config_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, surge_config_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        surge_config_id: Pubkey,
+    ) -> Result<SetSurgeFeeKeys, FlatFeeError> {
+        let Self { payer, state_acc } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(FlatFeeError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: ProgramState = try_program_state_allow_legacy(bytes)?;
+
+        Ok(SetSurgeFeeKeys {
+            manager: state.manager,
+            payer,
+            surge_config: surge_config_id,
+            state: state_id,
+            system_program: system_program::ID,
+        })
+    }
+}
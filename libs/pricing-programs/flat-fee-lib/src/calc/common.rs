@@ -7,6 +7,9 @@ use super::BPS_DENOMINATOR_I16;
 pub struct OutSolValueRatioArgs {
     pub input_fee_bps: i16,
     pub output_fee_bps: i16,
+    /// Replaces `input_fee_bps + output_fee_bps` if set
+    pub pair_fee_bps: Option<i16>,
+    pub surge_fee_bps: u16,
 }
 
 /// Returns the ratio that returns out_sol_value
@@ -15,11 +18,19 @@ pub fn out_sol_value_ratio(
     OutSolValueRatioArgs {
         input_fee_bps,
         output_fee_bps,
+        pair_fee_bps,
+        surge_fee_bps,
     }: OutSolValueRatioArgs,
 ) -> Result<FloorDiv<U64Ratio<u16, u16>>, FlatFeeError> {
-    let fee_bps = input_fee_bps
-        .checked_add(output_fee_bps)
-        .ok_or(FlatFeeError::MathError)?;
+    let surge_fee_bps: i16 = surge_fee_bps
+        .try_into()
+        .map_err(|_e| FlatFeeError::MathError)?;
+    let fee_bps = match pair_fee_bps {
+        Some(pair_fee_bps) => Some(pair_fee_bps),
+        None => input_fee_bps.checked_add(output_fee_bps),
+    }
+    .and_then(|v| v.checked_add(surge_fee_bps))
+    .ok_or(FlatFeeError::MathError)?;
     // post_fee_bps = 10_000 - fee_bps
     // out_sol_value = floor(in_sol_value * post_fee_bps / 10_000)
     // i16 signed subtraction:
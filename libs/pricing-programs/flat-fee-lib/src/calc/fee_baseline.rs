@@ -0,0 +1,260 @@
+//! Fee change limits are measured against the fees in effect at the start of the current epoch,
+//! not against the previous value, so that repeated updates within an epoch
+//! cannot move a fee by more than the limit in total.
+//!
+//! The baseline is recorded on the first fee update of each epoch.
+//! Until then, the fees currently in effect are the baseline.
+
+use flat_fee_interface::{FeeAccount, LstSurcharge, PairFeeAccount, ProgramState, SurgeConfig};
+
+use super::{
+    effective_lp_withdrawal_fee_bps, fee_account_lst_fees, program_state_lp_withdrawal_fee_bps,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProgramStateFeeBaseline {
+    pub lp_withdrawal_fee_bps: u16,
+    pub lp_deposit_fee_bps: u16,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeAccountFeeBaseline {
+    pub input_fee_bps: i16,
+    pub output_fee_bps: i16,
+    pub lp_deposit_fee_bps: u16,
+    /// The LST's effective LP withdrawal fee, which is the global LP withdrawal fee baseline
+    /// if the LST has no LP withdrawal fee of its own
+    pub lp_withdrawal_fee_bps: u16,
+}
+
+pub fn is_fee_baseline_current(
+    has_fee_baseline: u8,
+    fee_baseline_epoch: u64,
+    current_epoch: u64,
+) -> bool {
+    has_fee_baseline != 0 && fee_baseline_epoch == current_epoch
+}
+
+pub fn program_state_fee_baseline(
+    state: &ProgramState,
+    current_epoch: u64,
+) -> ProgramStateFeeBaseline {
+    if is_fee_baseline_current(
+        state.has_fee_baseline,
+        state.fee_baseline_epoch,
+        current_epoch,
+    ) {
+        return ProgramStateFeeBaseline {
+            lp_withdrawal_fee_bps: state.baseline_lp_withdrawal_fee_bps,
+            lp_deposit_fee_bps: state.baseline_lp_deposit_fee_bps,
+        };
+    }
+    ProgramStateFeeBaseline {
+        lp_withdrawal_fee_bps: program_state_lp_withdrawal_fee_bps(state, current_epoch),
+        lp_deposit_fee_bps: state.lp_deposit_fee_bps,
+    }
+}
+
+/// Records the baseline for `current_epoch` if it has not yet been recorded.
+/// Must be called before the fees are modified.
+pub fn record_program_state_fee_baseline(
+    state: &mut ProgramState,
+    current_epoch: u64,
+) -> ProgramStateFeeBaseline {
+    let baseline = program_state_fee_baseline(state, current_epoch);
+    state.baseline_lp_withdrawal_fee_bps = baseline.lp_withdrawal_fee_bps;
+    state.baseline_lp_deposit_fee_bps = baseline.lp_deposit_fee_bps;
+    state.has_fee_baseline = 1;
+    state.fee_baseline_epoch = current_epoch;
+    baseline
+}
+
+pub fn fee_account_fee_baseline(
+    fee_acc: &FeeAccount,
+    state: &ProgramState,
+    current_epoch: u64,
+) -> FeeAccountFeeBaseline {
+    if is_fee_baseline_current(
+        fee_acc.has_fee_baseline,
+        fee_acc.fee_baseline_epoch,
+        current_epoch,
+    ) {
+        return FeeAccountFeeBaseline {
+            input_fee_bps: fee_acc.baseline_input_fee_bps,
+            output_fee_bps: fee_acc.baseline_output_fee_bps,
+            lp_deposit_fee_bps: fee_acc.baseline_lp_deposit_fee_bps,
+            lp_withdrawal_fee_bps: fee_acc.baseline_lp_withdrawal_fee_bps,
+        };
+    }
+    let lst_fees = fee_account_lst_fees(fee_acc, current_epoch);
+    FeeAccountFeeBaseline {
+        input_fee_bps: lst_fees.input_fee_bps,
+        output_fee_bps: lst_fees.output_fee_bps,
+        lp_deposit_fee_bps: fee_acc.lp_deposit_fee_bps,
+        lp_withdrawal_fee_bps: effective_lp_withdrawal_fee_bps(
+            program_state_fee_baseline(state, current_epoch).lp_withdrawal_fee_bps,
+            fee_acc,
+        ),
+    }
+}
+
+/// Records the baseline for `current_epoch` if it has not yet been recorded.
+/// Must be called before the fees are modified.
+pub fn record_fee_account_fee_baseline(
+    fee_acc: &mut FeeAccount,
+    state: &ProgramState,
+    current_epoch: u64,
+) -> FeeAccountFeeBaseline {
+    let baseline = fee_account_fee_baseline(fee_acc, state, current_epoch);
+    fee_acc.baseline_input_fee_bps = baseline.input_fee_bps;
+    fee_acc.baseline_output_fee_bps = baseline.output_fee_bps;
+    fee_acc.baseline_lp_deposit_fee_bps = baseline.lp_deposit_fee_bps;
+    fee_acc.baseline_lp_withdrawal_fee_bps = baseline.lp_withdrawal_fee_bps;
+    fee_acc.has_fee_baseline = 1;
+    fee_acc.fee_baseline_epoch = current_epoch;
+    baseline
+}
+
+/// Records the baseline for `current_epoch` if it has not yet been recorded.
+/// Must be called before the fee is modified.
+pub fn record_pair_fee_account_fee_baseline(
+    pair_fee_acc: &mut PairFeeAccount,
+    current_epoch: u64,
+) -> i16 {
+    if !is_fee_baseline_current(
+        pair_fee_acc.has_fee_baseline,
+        pair_fee_acc.fee_baseline_epoch,
+        current_epoch,
+    ) {
+        pair_fee_acc.baseline_fee_bps = pair_fee_acc.fee_bps;
+        pair_fee_acc.has_fee_baseline = 1;
+        pair_fee_acc.fee_baseline_epoch = current_epoch;
+    }
+    pair_fee_acc.baseline_fee_bps
+}
+
+/// Records the baseline for `current_epoch` if it has not yet been recorded.
+/// Must be called before the fee is modified.
+pub fn record_surge_config_fee_baseline(surge_config: &mut SurgeConfig, current_epoch: u64) -> u16 {
+    if !is_fee_baseline_current(
+        surge_config.has_fee_baseline,
+        surge_config.fee_baseline_epoch,
+        current_epoch,
+    ) {
+        surge_config.baseline_max_surge_fee_bps = surge_config.max_surge_fee_bps;
+        surge_config.has_fee_baseline = 1;
+        surge_config.fee_baseline_epoch = current_epoch;
+    }
+    surge_config.baseline_max_surge_fee_bps
+}
+
+/// Records the baseline for `current_epoch` if it has not yet been recorded.
+/// Must be called before the fee is modified.
+pub fn record_lst_surcharge_fee_baseline(
+    surcharge_acc: &mut LstSurcharge,
+    current_epoch: u64,
+) -> u16 {
+    if !is_fee_baseline_current(
+        surcharge_acc.has_fee_baseline,
+        surcharge_acc.fee_baseline_epoch,
+        current_epoch,
+    ) {
+        surcharge_acc.baseline_surcharge_bps = surcharge_acc.surcharge_bps;
+        surcharge_acc.has_fee_baseline = 1;
+        surcharge_acc.fee_baseline_epoch = current_epoch;
+    }
+    surcharge_acc.baseline_surcharge_bps
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use crate::calc::{set_program_state_pending_lp_withdrawal_fee, PendingFee};
+
+    use super::*;
+
+    #[test]
+    fn program_state_baseline_fixed_within_epoch() {
+        let mut state = ProgramState::zeroed();
+        state.lp_withdrawal_fee_bps = 5;
+        state.lp_deposit_fee_bps = 3;
+
+        let baseline = record_program_state_fee_baseline(&mut state, 7);
+        state.lp_withdrawal_fee_bps = 9;
+        state.lp_deposit_fee_bps = 0;
+        assert_eq!(record_program_state_fee_baseline(&mut state, 7), baseline);
+        assert_eq!(
+            program_state_fee_baseline(&state, 8),
+            ProgramStateFeeBaseline {
+                lp_withdrawal_fee_bps: 9,
+                lp_deposit_fee_bps: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn program_state_baseline_includes_active_pending_fee() {
+        let mut state = ProgramState::zeroed();
+        state.lp_withdrawal_fee_bps = 5;
+        set_program_state_pending_lp_withdrawal_fee(
+            &mut state,
+            Some(PendingFee {
+                fee: 8,
+                activation_epoch: 10,
+            }),
+        );
+        assert_eq!(
+            program_state_fee_baseline(&state, 9).lp_withdrawal_fee_bps,
+            5
+        );
+        assert_eq!(
+            program_state_fee_baseline(&state, 10).lp_withdrawal_fee_bps,
+            8
+        );
+    }
+
+    #[test]
+    fn fee_account_lp_withdrawal_baseline_uses_global_baseline() {
+        let mut state = ProgramState::zeroed();
+        state.lp_withdrawal_fee_bps = 5;
+        record_program_state_fee_baseline(&mut state, 7);
+        state.lp_withdrawal_fee_bps = 9;
+
+        let mut fee_acc = FeeAccount::zeroed();
+        assert_eq!(
+            record_fee_account_fee_baseline(&mut fee_acc, &state, 7).lp_withdrawal_fee_bps,
+            5
+        );
+
+        fee_acc.has_lp_withdrawal_fee = 1;
+        fee_acc.lp_withdrawal_fee_bps = 20;
+        assert_eq!(
+            fee_account_fee_baseline(&fee_acc, &state, 7).lp_withdrawal_fee_bps,
+            5
+        );
+        assert_eq!(
+            fee_account_fee_baseline(&fee_acc, &state, 8).lp_withdrawal_fee_bps,
+            20
+        );
+    }
+
+    #[test]
+    fn pair_fee_baseline_fixed_within_epoch() {
+        let mut pair_fee_acc = PairFeeAccount::zeroed();
+        pair_fee_acc.fee_bps = -4;
+        assert_eq!(
+            record_pair_fee_account_fee_baseline(&mut pair_fee_acc, 0),
+            -4
+        );
+        pair_fee_acc.fee_bps = 6;
+        assert_eq!(
+            record_pair_fee_account_fee_baseline(&mut pair_fee_acc, 0),
+            -4
+        );
+        assert_eq!(
+            record_pair_fee_account_fee_baseline(&mut pair_fee_acc, 1),
+            6
+        );
+    }
+}
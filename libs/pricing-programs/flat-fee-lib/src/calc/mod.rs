@@ -1,10 +1,18 @@
 mod common;
+mod fee_baseline;
 mod price_exact_in;
 mod price_exact_out;
+mod price_lp_tokens_to_mint;
 mod price_lp_tokens_to_redeem;
+mod scheduled_fee;
+mod surge;
 
+pub use fee_baseline::*;
 pub use price_exact_in::*;
 pub use price_exact_out::*;
+pub use price_lp_tokens_to_mint::*;
 pub use price_lp_tokens_to_redeem::*;
+pub use scheduled_fee::*;
+pub use surge::*;
 
 const BPS_DENOMINATOR_I16: i16 = 10_000;
@@ -7,6 +7,10 @@ use super::common::{out_sol_value_ratio, OutSolValueRatioArgs};
 pub struct CalculatePriceExactInArgs {
     pub input_fee_bps: i16,
     pub output_fee_bps: i16,
+    /// Fee from the LST pair's PairFeeAccount, replaces `input_fee_bps + output_fee_bps` if set
+    pub pair_fee_bps: Option<i16>,
+    /// Sum of the epoch surge fee and both LSTs' surcharges
+    pub surge_fee_bps: u16,
     pub in_sol_value: u64,
 }
 
@@ -15,12 +19,16 @@ pub fn calculate_price_exact_in(
     CalculatePriceExactInArgs {
         input_fee_bps,
         output_fee_bps,
+        pair_fee_bps,
+        surge_fee_bps,
         in_sol_value,
     }: CalculatePriceExactInArgs,
 ) -> Result<u64, FlatFeeError> {
     out_sol_value_ratio(OutSolValueRatioArgs {
         input_fee_bps,
         output_fee_bps,
+        pair_fee_bps,
+        surge_fee_bps,
     })?
     .apply(in_sol_value)
     .map_err(|_e| FlatFeeError::MathError)
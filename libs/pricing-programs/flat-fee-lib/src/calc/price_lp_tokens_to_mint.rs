@@ -0,0 +1,18 @@
+use flat_fee_interface::FlatFeeError;
+use sanctum_token_ratio::{CeilDiv, ReversibleFee, U64BpsFee};
+
+/// The per-LST LP deposit fee is levied on top of the global LP deposit fee
+pub fn calculate_price_lp_tokens_to_mint(
+    lp_deposit_fee_bps: u16,
+    lst_lp_deposit_fee_bps: u16,
+    sol_value: u64,
+) -> Result<u64, FlatFeeError> {
+    let fee_bps = lp_deposit_fee_bps
+        .checked_add(lst_lp_deposit_fee_bps)
+        .ok_or(FlatFeeError::MathError)?;
+    U64BpsFee::try_new(fee_bps)
+        .map(CeilDiv)
+        .and_then(|f| f.apply(sol_value))
+        .map(|aaf| aaf.amt_after_fee())
+        .map_err(|_e| FlatFeeError::MathError)
+}
@@ -1,13 +1,35 @@
-use flat_fee_interface::FlatFeeError;
+use flat_fee_interface::{FeeAccount, FlatFeeError};
 use sanctum_token_ratio::{CeilDiv, ReversibleFee, U64BpsFee};
 
+/// The epoch surge fee is levied on top of the LP withdrawal fee
 pub fn calculate_price_lp_tokens_to_redeem(
     lp_withdrawal_fee_bps: u16,
+    surge_fee_bps: u16,
     sol_value: u64,
 ) -> Result<u64, FlatFeeError> {
-    U64BpsFee::try_new(lp_withdrawal_fee_bps)
+    let fee_bps = lp_withdrawal_fee_bps
+        .checked_add(surge_fee_bps)
+        .ok_or(FlatFeeError::MathError)?;
+    U64BpsFee::try_new(fee_bps)
         .map(CeilDiv)
         .and_then(|f| f.apply(sol_value))
         .map(|aaf| aaf.amt_after_fee())
         .map_err(|_e| FlatFeeError::MathError)
 }
+
+/// Returns the output LST's LP withdrawal fee if it has one set,
+/// otherwise the global LP withdrawal fee
+pub fn effective_lp_withdrawal_fee_bps(
+    global_lp_withdrawal_fee_bps: u16,
+    FeeAccount {
+        has_lp_withdrawal_fee,
+        lp_withdrawal_fee_bps,
+        ..
+    }: &FeeAccount,
+) -> u16 {
+    if *has_lp_withdrawal_fee == 0 {
+        global_lp_withdrawal_fee_bps
+    } else {
+        *lp_withdrawal_fee_bps
+    }
+}
@@ -0,0 +1,281 @@
+use flat_fee_interface::{FeeAccount, FlatFeeError, ProgramState};
+
+/// A fee change that takes effect from the start of `activation_epoch` onwards
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PendingFee<T> {
+    pub fee: T,
+    pub activation_epoch: u64,
+}
+
+impl<T> PendingFee<T> {
+    pub fn is_active(&self, current_epoch: u64) -> bool {
+        current_epoch >= self.activation_epoch
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LstFees {
+    pub input_fee_bps: i16,
+    pub output_fee_bps: i16,
+}
+
+/// Minimum number of epochs between scheduling a fee change and its activation.
+///
+/// A change scheduled late in an epoch and activating in the next one could take effect
+/// within minutes, so a full epoch is always left for it to be observed
+pub const MIN_ACTIVATION_EPOCH_DELAY: u64 = 2;
+
+/// Scheduled fee changes must activate at least [`MIN_ACTIVATION_EPOCH_DELAY`]
+/// epochs after the current epoch so that they can be observed before they take effect
+pub fn verify_activation_epoch(
+    activation_epoch: u64,
+    current_epoch: u64,
+) -> Result<(), FlatFeeError> {
+    let earliest = current_epoch.saturating_add(MIN_ACTIVATION_EPOCH_DELAY);
+    if activation_epoch < earliest {
+        return Err(FlatFeeError::InvalidActivationEpoch);
+    }
+    Ok(())
+}
+
+/// Fee changes that take effect immediately may only decrease the fee in effect.
+/// Increases must be scheduled so that they can be observed before they take effect
+pub fn verify_immediate_fee_change<T: PartialOrd>(
+    curr_fee_bps: T,
+    new_fee_bps: T,
+) -> Result<(), FlatFeeError> {
+    if new_fee_bps > curr_fee_bps {
+        return Err(FlatFeeError::FeeIncreaseNotScheduled);
+    }
+    Ok(())
+}
+
+pub fn fee_account_pending_fees(fee_acc: &FeeAccount) -> Option<PendingFee<LstFees>> {
+    if fee_acc.has_pending_fees == 0 {
+        return None;
+    }
+    Some(PendingFee {
+        fee: LstFees {
+            input_fee_bps: fee_acc.pending_input_fee_bps,
+            output_fee_bps: fee_acc.pending_output_fee_bps,
+        },
+        activation_epoch: fee_acc.pending_fees_activation_epoch,
+    })
+}
+
+/// The LST's input and output fees in effect at `current_epoch`,
+/// taking into account any pending fee change that has not yet been promoted
+pub fn fee_account_lst_fees(fee_acc: &FeeAccount, current_epoch: u64) -> LstFees {
+    match fee_account_pending_fees(fee_acc) {
+        Some(pending) if pending.is_active(current_epoch) => pending.fee,
+        _ => LstFees {
+            input_fee_bps: fee_acc.input_fee_bps,
+            output_fee_bps: fee_acc.output_fee_bps,
+        },
+    }
+}
+
+/// The LST's scheduled fee change, if any, that is not yet in effect at `current_epoch`
+pub fn fee_account_upcoming_lst_fees(
+    fee_acc: &FeeAccount,
+    current_epoch: u64,
+) -> Option<PendingFee<LstFees>> {
+    fee_account_pending_fees(fee_acc).filter(|pending| !pending.is_active(current_epoch))
+}
+
+pub fn set_fee_account_pending_fees(
+    fee_acc: &mut FeeAccount,
+    pending: Option<PendingFee<LstFees>>,
+) {
+    let PendingFee {
+        fee: LstFees {
+            input_fee_bps,
+            output_fee_bps,
+        },
+        activation_epoch,
+    } = pending.unwrap_or(PendingFee {
+        fee: LstFees {
+            input_fee_bps: 0,
+            output_fee_bps: 0,
+        },
+        activation_epoch: 0,
+    });
+    fee_acc.has_pending_fees = pending.is_some().into();
+    fee_acc.pending_input_fee_bps = input_fee_bps;
+    fee_acc.pending_output_fee_bps = output_fee_bps;
+    fee_acc.pending_fees_activation_epoch = activation_epoch;
+}
+
+/// Writes the pending fee change into the current fees if it is in effect at `current_epoch`
+pub fn promote_fee_account_pending_fees(fee_acc: &mut FeeAccount, current_epoch: u64) {
+    if let Some(pending) = fee_account_pending_fees(fee_acc) {
+        if pending.is_active(current_epoch) {
+            fee_acc.input_fee_bps = pending.fee.input_fee_bps;
+            fee_acc.output_fee_bps = pending.fee.output_fee_bps;
+            set_fee_account_pending_fees(fee_acc, None);
+        }
+    }
+}
+
+pub fn program_state_pending_lp_withdrawal_fee(state: &ProgramState) -> Option<PendingFee<u16>> {
+    if state.has_pending_lp_withdrawal_fee == 0 {
+        return None;
+    }
+    Some(PendingFee {
+        fee: state.pending_lp_withdrawal_fee_bps,
+        activation_epoch: state.pending_lp_withdrawal_fee_activation_epoch,
+    })
+}
+
+/// The global LP withdrawal fee in effect at `current_epoch`,
+/// taking into account any pending fee change that has not yet been promoted
+pub fn program_state_lp_withdrawal_fee_bps(state: &ProgramState, current_epoch: u64) -> u16 {
+    match program_state_pending_lp_withdrawal_fee(state) {
+        Some(pending) if pending.is_active(current_epoch) => pending.fee,
+        _ => state.lp_withdrawal_fee_bps,
+    }
+}
+
+/// The global LP withdrawal fee change, if any, that is not yet in effect at `current_epoch`
+pub fn program_state_upcoming_lp_withdrawal_fee(
+    state: &ProgramState,
+    current_epoch: u64,
+) -> Option<PendingFee<u16>> {
+    program_state_pending_lp_withdrawal_fee(state)
+        .filter(|pending| !pending.is_active(current_epoch))
+}
+
+pub fn set_program_state_pending_lp_withdrawal_fee(
+    state: &mut ProgramState,
+    pending: Option<PendingFee<u16>>,
+) {
+    let PendingFee {
+        fee,
+        activation_epoch,
+    } = pending.unwrap_or(PendingFee {
+        fee: 0,
+        activation_epoch: 0,
+    });
+    state.has_pending_lp_withdrawal_fee = pending.is_some().into();
+    state.pending_lp_withdrawal_fee_bps = fee;
+    state.pending_lp_withdrawal_fee_activation_epoch = activation_epoch;
+}
+
+/// Writes the pending LP withdrawal fee change into the current fee if it is in effect at `current_epoch`
+pub fn promote_program_state_pending_lp_withdrawal_fee(
+    state: &mut ProgramState,
+    current_epoch: u64,
+) {
+    if let Some(pending) = program_state_pending_lp_withdrawal_fee(state) {
+        if pending.is_active(current_epoch) {
+            state.lp_withdrawal_fee_bps = pending.fee;
+            set_program_state_pending_lp_withdrawal_fee(state, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    fn scheduled_fee_account() -> FeeAccount {
+        let mut fee_acc = FeeAccount::zeroed();
+        fee_acc.input_fee_bps = 1;
+        fee_acc.output_fee_bps = 2;
+        set_fee_account_pending_fees(
+            &mut fee_acc,
+            Some(PendingFee {
+                fee: LstFees {
+                    input_fee_bps: 3,
+                    output_fee_bps: 4,
+                },
+                activation_epoch: 10,
+            }),
+        );
+        fee_acc
+    }
+
+    #[test]
+    fn pending_lst_fees_used_once_active() {
+        let fee_acc = scheduled_fee_account();
+        let current = LstFees {
+            input_fee_bps: 1,
+            output_fee_bps: 2,
+        };
+        let pending = LstFees {
+            input_fee_bps: 3,
+            output_fee_bps: 4,
+        };
+        assert_eq!(fee_account_lst_fees(&fee_acc, 9), current);
+        assert_eq!(
+            fee_account_upcoming_lst_fees(&fee_acc, 9),
+            Some(PendingFee {
+                fee: pending,
+                activation_epoch: 10
+            })
+        );
+        assert_eq!(fee_account_lst_fees(&fee_acc, 10), pending);
+        assert_eq!(fee_account_upcoming_lst_fees(&fee_acc, 10), None);
+    }
+
+    #[test]
+    fn promotion_only_once_active() {
+        let mut fee_acc = scheduled_fee_account();
+        promote_fee_account_pending_fees(&mut fee_acc, 9);
+        assert_eq!(fee_acc, scheduled_fee_account());
+
+        promote_fee_account_pending_fees(&mut fee_acc, 10);
+        assert_eq!(fee_acc.input_fee_bps, 3);
+        assert_eq!(fee_acc.output_fee_bps, 4);
+        assert_eq!(fee_account_pending_fees(&fee_acc), None);
+        assert_eq!(fee_acc.pending_fees_activation_epoch, 0);
+    }
+
+    #[test]
+    fn pending_lp_withdrawal_fee_used_once_active() {
+        let mut state = ProgramState::zeroed();
+        state.lp_withdrawal_fee_bps = 5;
+        set_program_state_pending_lp_withdrawal_fee(
+            &mut state,
+            Some(PendingFee {
+                fee: 8,
+                activation_epoch: 10,
+            }),
+        );
+        assert_eq!(program_state_lp_withdrawal_fee_bps(&state, 9), 5);
+        assert_eq!(program_state_lp_withdrawal_fee_bps(&state, 10), 8);
+
+        promote_program_state_pending_lp_withdrawal_fee(&mut state, 11);
+        assert_eq!(state.lp_withdrawal_fee_bps, 8);
+        assert_eq!(program_state_pending_lp_withdrawal_fee(&state), None);
+    }
+
+    #[test]
+    fn activation_epoch_must_be_at_least_two_epochs_ahead() {
+        assert_eq!(
+            verify_activation_epoch(5, 5),
+            Err(FlatFeeError::InvalidActivationEpoch)
+        );
+        assert_eq!(
+            verify_activation_epoch(6, 5),
+            Err(FlatFeeError::InvalidActivationEpoch)
+        );
+        assert_eq!(verify_activation_epoch(7, 5), Ok(()));
+        assert_eq!(
+            verify_activation_epoch(u64::MAX, u64::MAX - 1),
+            Err(FlatFeeError::InvalidActivationEpoch)
+        );
+    }
+
+    #[test]
+    fn immediate_fee_change_only_decreases() {
+        assert_eq!(
+            verify_immediate_fee_change(-3i16, -2),
+            Err(FlatFeeError::FeeIncreaseNotScheduled)
+        );
+        assert_eq!(verify_immediate_fee_change(-3i16, -3), Ok(()));
+        assert_eq!(verify_immediate_fee_change(5u16, 0), Ok(()));
+    }
+}
@@ -0,0 +1,180 @@
+use flat_fee_interface::{FlatFeeError, LstSurcharge, SurgeConfig};
+use solana_program::{clock::Clock, epoch_schedule::EpochSchedule};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::utils::{try_lst_surcharge, try_stake_pool_last_update_epoch, try_surge_config};
+
+/// Returns the number of slots that have elapsed since the first slot of the current epoch
+pub fn slots_elapsed_in_epoch(clock: &Clock, epoch_schedule: &EpochSchedule) -> u64 {
+    clock
+        .slot
+        .saturating_sub(epoch_schedule.get_first_slot_in_epoch(clock.epoch))
+}
+
+/// Returns the epoch surge fee in bps.
+///
+/// Decays linearly from `max_surge_fee_bps` at the first slot of the epoch
+/// to 0 once `decay_slots` slots have elapsed, rounded up.
+pub fn calculate_epoch_surge_fee_bps(
+    SurgeConfig {
+        max_surge_fee_bps,
+        decay_slots,
+        ..
+    }: &SurgeConfig,
+    slots_elapsed: u64,
+) -> u16 {
+    let decay_slots = u64::from(*decay_slots);
+    let slots_remaining = match decay_slots.checked_sub(slots_elapsed) {
+        Some(r) if r > 0 => r,
+        _ => return 0,
+    };
+    // max_surge_fee_bps * slots_remaining / decay_slots <= max_surge_fee_bps
+    // since slots_remaining <= decay_slots, so the cast back to u16 never truncates
+    let num = u128::from(*max_surge_fee_bps) * u128::from(slots_remaining);
+    let denom = u128::from(decay_slots);
+    num.div_ceil(denom) as u16
+}
+
+/// Returns the LST's surcharge in bps:
+/// `surcharge_bps` if its stake pool has not yet been updated for `current_epoch`, 0 otherwise.
+pub fn calculate_lst_surcharge_bps(
+    LstSurcharge {
+        surcharge_bps,
+        last_update_epoch_offset,
+        ..
+    }: &LstSurcharge,
+    stake_pool_data: &[u8],
+    current_epoch: u64,
+) -> Result<u16, FlatFeeError> {
+    let last_update_epoch =
+        try_stake_pool_last_update_epoch(stake_pool_data, *last_update_epoch_offset)?;
+    Ok(if last_update_epoch < current_epoch {
+        *surcharge_bps
+    } else {
+        0
+    })
+}
+
+/// Returns the epoch surge fee in bps from a SurgeConfig account.
+/// Uninitialized SurgeConfig accounts levy no surge fee.
+pub fn surge_config_acc_fee_bps<D: ReadonlyAccountData>(
+    surge_config_acc: D,
+    clock: &Clock,
+    epoch_schedule: &EpochSchedule,
+) -> Result<u16, FlatFeeError> {
+    let bytes = surge_config_acc.data();
+    if bytes.is_empty() {
+        return Ok(0);
+    }
+    let surge_config = try_surge_config(&bytes)?;
+    Ok(calculate_epoch_surge_fee_bps(
+        surge_config,
+        slots_elapsed_in_epoch(clock, epoch_schedule),
+    ))
+}
+
+/// Returns the LST's surcharge in bps from a LstSurcharge account.
+/// Uninitialized LstSurcharge accounts levy no surcharge and `stake_pool_acc` is ignored.
+pub fn lst_surcharge_acc_fee_bps<
+    D: ReadonlyAccountData,
+    P: ReadonlyAccountPubkey + ReadonlyAccountData,
+>(
+    surcharge_acc: D,
+    stake_pool_acc: P,
+    current_epoch: u64,
+) -> Result<u16, FlatFeeError> {
+    let bytes = surcharge_acc.data();
+    if bytes.is_empty() {
+        return Ok(0);
+    }
+    let lst_surcharge = try_lst_surcharge(&bytes)?;
+    if *stake_pool_acc.pubkey() != lst_surcharge.stake_pool {
+        return Err(FlatFeeError::IncorrectStakePool);
+    }
+    calculate_lst_surcharge_bps(lst_surcharge, &stake_pool_acc.data(), current_epoch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn surge_config(max_surge_fee_bps: u16, decay_slots: u32) -> SurgeConfig {
+        SurgeConfig {
+            max_surge_fee_bps,
+            padding: [0; 2],
+            decay_slots,
+            baseline_max_surge_fee_bps: 0,
+            has_fee_baseline: 0,
+            fee_baseline_padding: [0; 5],
+            fee_baseline_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn surge_decays_linearly() {
+        let cfg = surge_config(100, 1_000);
+        assert_eq!(calculate_epoch_surge_fee_bps(&cfg, 0), 100);
+        assert_eq!(calculate_epoch_surge_fee_bps(&cfg, 500), 50);
+        // rounded up
+        assert_eq!(calculate_epoch_surge_fee_bps(&cfg, 999), 1);
+        assert_eq!(calculate_epoch_surge_fee_bps(&cfg, 1_000), 0);
+        assert_eq!(calculate_epoch_surge_fee_bps(&cfg, u64::MAX), 0);
+    }
+
+    #[test]
+    fn zero_decay_slots_disables_surge() {
+        let cfg = surge_config(100, 0);
+        assert_eq!(calculate_epoch_surge_fee_bps(&cfg, 0), 0);
+    }
+
+    #[test]
+    fn slots_elapsed_without_warmup() {
+        let epoch_schedule = EpochSchedule::without_warmup();
+        let clock = Clock {
+            slot: 432_000 * 5 + 123,
+            epoch: 5,
+            ..Default::default()
+        };
+        assert_eq!(slots_elapsed_in_epoch(&clock, &epoch_schedule), 123);
+    }
+
+    #[test]
+    fn surcharge_until_stake_pool_updated() {
+        let surcharge = LstSurcharge {
+            stake_pool: Default::default(),
+            bump: 0,
+            padding: 0,
+            surcharge_bps: 7,
+            last_update_epoch_offset: 2,
+            baseline_surcharge_bps: 0,
+            has_fee_baseline: 0,
+            fee_baseline_padding: [0; 7],
+            fee_baseline_epoch: 0,
+        };
+        let mut data = [0u8; 10];
+        data[2..].copy_from_slice(&500u64.to_le_bytes());
+        assert_eq!(calculate_lst_surcharge_bps(&surcharge, &data, 501), Ok(7));
+        assert_eq!(calculate_lst_surcharge_bps(&surcharge, &data, 500), Ok(0));
+        assert_eq!(
+            calculate_lst_surcharge_bps(&surcharge, &data[..9], 501),
+            Err(FlatFeeError::InvalidStakePoolData)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn surge_bounded_and_non_increasing(
+            max_surge_fee_bps: u16,
+            decay_slots: u32,
+            slots_elapsed in 0..=u64::from(u32::MAX) + 1,
+            more_slots in 0..=u64::from(u32::MAX),
+        ) {
+            let cfg = surge_config(max_surge_fee_bps, decay_slots);
+            let now = calculate_epoch_surge_fee_bps(&cfg, slots_elapsed);
+            let later = calculate_epoch_surge_fee_bps(&cfg, slots_elapsed + more_slots);
+            prop_assert!(now <= max_surge_fee_bps);
+            prop_assert!(later <= now);
+        }
+    }
+}
@@ -1,8 +1,12 @@
-use flat_fee_interface::FlatFeeError;
+use flat_fee_interface::{FlatFeeError, ProgramState};
 
 const MAX_SIGNED_FEE_BPS: i16 = 10_000;
 const MAX_UNSIGNED_FEE_BPS: u16 = 10_000;
 
+/// Cap on both the global and the per-LST LP deposit fee,
+/// so that their sum levied on an LP deposit can never exceed 100%
+pub const MAX_LP_DEPOSIT_FEE_BPS: u16 = 5_000;
+
 pub fn verify_signed_fee_bps_bound(fee_bps_i16: i16) -> Result<(), FlatFeeError> {
     if !(-MAX_SIGNED_FEE_BPS..=MAX_SIGNED_FEE_BPS).contains(&fee_bps_i16) {
         return Err(FlatFeeError::SignedFeeOutOfBound);
@@ -16,3 +20,182 @@ pub fn verify_unsigned_fee_bps_bound(fee_bps_u16: u16) -> Result<(), FlatFeeErro
     }
     Ok(())
 }
+
+pub fn verify_lp_deposit_fee_bps_bound(fee_bps_u16: u16) -> Result<(), FlatFeeError> {
+    if fee_bps_u16 > MAX_LP_DEPOSIT_FEE_BPS {
+        return Err(FlatFeeError::UnsignedFeeOutOfBound);
+    }
+    Ok(())
+}
+
+/// Verifies that the global and per-LST LP deposit fees levied together on an LP deposit
+/// do not exceed 100%
+pub fn verify_combined_lp_deposit_fee_bps(
+    lp_deposit_fee_bps: u16,
+    lst_lp_deposit_fee_bps: u16,
+) -> Result<(), FlatFeeError> {
+    let combined_fee_bps = lp_deposit_fee_bps
+        .checked_add(lst_lp_deposit_fee_bps)
+        .ok_or(FlatFeeError::UnsignedFeeOutOfBound)?;
+    verify_unsigned_fee_bps_bound(combined_fee_bps)
+}
+
+/// Verifies that admin-configured fee bounds are themselves valid
+pub fn verify_fee_bounds(
+    min_lst_fee_bps: i16,
+    max_lst_fee_bps: i16,
+    max_lp_withdrawal_fee_bps: u16,
+    max_lp_deposit_fee_bps: u16,
+    max_surge_fee_bps: u16,
+) -> Result<(), FlatFeeError> {
+    verify_signed_fee_bps_bound(min_lst_fee_bps)?;
+    verify_signed_fee_bps_bound(max_lst_fee_bps)?;
+    verify_unsigned_fee_bps_bound(max_lp_withdrawal_fee_bps)?;
+    verify_unsigned_fee_bps_bound(max_lp_deposit_fee_bps)?;
+    verify_unsigned_fee_bps_bound(max_surge_fee_bps)?;
+    if min_lst_fee_bps > max_lst_fee_bps {
+        return Err(FlatFeeError::InvalidFeeBounds);
+    }
+    Ok(())
+}
+
+/// Enforces the admin-configured range for LST input and output fees and pair fees, if any
+pub fn verify_lst_fee_bps_configured_bound(
+    state: &ProgramState,
+    fee_bps: i16,
+) -> Result<(), FlatFeeError> {
+    if state.has_fee_bounds == 0 {
+        return Ok(());
+    }
+    if !(state.min_lst_fee_bps..=state.max_lst_fee_bps).contains(&fee_bps) {
+        return Err(FlatFeeError::FeeOutOfConfiguredBound);
+    }
+    Ok(())
+}
+
+/// Enforces the admin-configured range and maximum change per epoch
+/// for an update of an LST input or output fee or a pair fee to `new_fee_bps`, if any.
+///
+/// `baseline_fee_bps` is the fee in effect at the start of the current epoch
+pub fn verify_lst_fee_bps_update(
+    state: &ProgramState,
+    baseline_fee_bps: i16,
+    new_fee_bps: i16,
+) -> Result<(), FlatFeeError> {
+    verify_lst_fee_bps_configured_bound(state, new_fee_bps)?;
+    verify_fee_change(state, i32::from(baseline_fee_bps), i32::from(new_fee_bps))
+}
+
+/// Enforces the admin-configured maximum and maximum change per epoch
+/// for an update of an LP withdrawal fee to `new_fee_bps`, if any.
+///
+/// `baseline_fee_bps` is the fee in effect at the start of the current epoch
+pub fn verify_lp_withdrawal_fee_bps_update(
+    state: &ProgramState,
+    baseline_fee_bps: u16,
+    new_fee_bps: u16,
+) -> Result<(), FlatFeeError> {
+    verify_unsigned_fee_bps_update(
+        state,
+        state.max_lp_withdrawal_fee_bps,
+        baseline_fee_bps,
+        new_fee_bps,
+    )
+}
+
+/// Enforces the admin-configured maximum and maximum change per epoch
+/// for an update of an LP deposit fee to `new_fee_bps`, if any.
+///
+/// `baseline_fee_bps` is the fee in effect at the start of the current epoch
+pub fn verify_lp_deposit_fee_bps_update(
+    state: &ProgramState,
+    baseline_fee_bps: u16,
+    new_fee_bps: u16,
+) -> Result<(), FlatFeeError> {
+    verify_unsigned_fee_bps_update(
+        state,
+        state.max_lp_deposit_fee_bps,
+        baseline_fee_bps,
+        new_fee_bps,
+    )
+}
+
+/// Enforces the admin-configured maximum and maximum change per epoch
+/// for an update of the max surge fee or an LST surcharge to `new_fee_bps`, if any.
+///
+/// `baseline_fee_bps` is the fee in effect at the start of the current epoch
+pub fn verify_surge_fee_bps_update(
+    state: &ProgramState,
+    baseline_fee_bps: u16,
+    new_fee_bps: u16,
+) -> Result<(), FlatFeeError> {
+    verify_unsigned_fee_bps_update(
+        state,
+        state.max_surge_fee_bps,
+        baseline_fee_bps,
+        new_fee_bps,
+    )
+}
+
+/// Enforces the admin-configured maximum change per epoch for the step a scheduled change
+/// of an LST input or output fee to `scheduled_fee_bps` makes when it activates, if any.
+///
+/// `replaced_fee_bps` is the fee in effect until activation. Immediate fee changes cancel
+/// any scheduled change, so this is the fee in effect when the change is scheduled
+pub fn verify_scheduled_lst_fee_bps_activation(
+    state: &ProgramState,
+    replaced_fee_bps: i16,
+    scheduled_fee_bps: i16,
+) -> Result<(), FlatFeeError> {
+    verify_fee_change(
+        state,
+        i32::from(replaced_fee_bps),
+        i32::from(scheduled_fee_bps),
+    )
+}
+
+/// Enforces the admin-configured maximum change per epoch for the step a scheduled change
+/// of the global LP withdrawal fee to `scheduled_fee_bps` makes when it activates, if any.
+///
+/// `replaced_fee_bps` is the fee in effect until activation. Immediate fee changes cancel
+/// any scheduled change, so this is the fee in effect when the change is scheduled
+pub fn verify_scheduled_lp_withdrawal_fee_bps_activation(
+    state: &ProgramState,
+    replaced_fee_bps: u16,
+    scheduled_fee_bps: u16,
+) -> Result<(), FlatFeeError> {
+    verify_fee_change(
+        state,
+        i32::from(replaced_fee_bps),
+        i32::from(scheduled_fee_bps),
+    )
+}
+
+fn verify_unsigned_fee_bps_update(
+    state: &ProgramState,
+    max_fee_bps: u16,
+    baseline_fee_bps: u16,
+    new_fee_bps: u16,
+) -> Result<(), FlatFeeError> {
+    if state.has_fee_bounds == 0 {
+        return Ok(());
+    }
+    if new_fee_bps > max_fee_bps {
+        return Err(FlatFeeError::FeeOutOfConfiguredBound);
+    }
+    verify_fee_change(state, i32::from(baseline_fee_bps), i32::from(new_fee_bps))
+}
+
+fn verify_fee_change(
+    state: &ProgramState,
+    baseline_fee_bps: i32,
+    new_fee_bps: i32,
+) -> Result<(), FlatFeeError> {
+    if state.has_fee_bounds == 0 {
+        return Ok(());
+    }
+    if (new_fee_bps - baseline_fee_bps).unsigned_abs() > u32::from(state.max_fee_change_bps) {
+        return Err(FlatFeeError::FeeChangeTooLarge);
+    }
+    Ok(())
+}
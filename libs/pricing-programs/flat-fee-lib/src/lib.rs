@@ -5,8 +5,21 @@ pub mod pda;
 pub mod utils;
 
 pub mod program {
-    pub const STATE_SIZE: usize = 34;
-    pub const FEE_ACCOUNT_SIZE: usize = 6;
+    pub const STATE_SIZE: usize = 112;
+    pub const FEE_ACCOUNT_SIZE: usize = 48;
+    pub const SURGE_CONFIG_SIZE: usize = 24;
+    pub const LST_SURCHARGE_SIZE: usize = 56;
+    pub const PAIR_FEE_ACCOUNT_SIZE: usize = 16;
+
+    /// Size of the ProgramState as first deployed, before `lp_deposit_fee_bps`,
+    /// the pending LP withdrawal fee, `admin` and the fee bounds were added.
+    /// Readers zero-extend it; MigrateAccount grows it to [`STATE_SIZE`]
+    pub const LEGACY_STATE_SIZE: usize = 34;
+
+    /// Size of a FeeAccount as first deployed, before the LP deposit and withdrawal fees
+    /// and the pending input and output fees were added.
+    /// Readers zero-extend it; MigrateAccount grows it to [`FEE_ACCOUNT_SIZE`]
+    pub const LEGACY_FEE_ACCOUNT_SIZE: usize = 6;
 
     static_assertions::const_assert_eq!(
         std::mem::size_of::<flat_fee_interface::ProgramState>(),
@@ -18,9 +31,24 @@ pub mod program {
         FEE_ACCOUNT_SIZE,
     );
 
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<flat_fee_interface::SurgeConfig>(),
+        SURGE_CONFIG_SIZE,
+    );
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<flat_fee_interface::LstSurcharge>(),
+        LST_SURCHARGE_SIZE,
+    );
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<flat_fee_interface::PairFeeAccount>(),
+        PAIR_FEE_ACCOUNT_SIZE,
+    );
+
     sanctum_macros::declare_program_keys!(
         "f1tUoNEKrDp1oeGn4zxr7bh41eN6VcfHjfrL3ZqQday",
-        [("state", b"state")]
+        [("state", b"state"), ("surge_config", b"surge_config")]
     );
 }
 
@@ -34,4 +62,6 @@ pub mod initial_constants {
     }
 
     pub const INITIAL_LP_WITHDRAWAL_FEE_BPS: u16 = 5;
+
+    pub const INITIAL_LP_DEPOSIT_FEE_BPS: u16 = 0;
 }
@@ -3,6 +3,8 @@ use solana_program::pubkey::{Pubkey, PubkeyError};
 use crate::program;
 
 pub const FEE_ACCOUNT_SEED_PREFIX: &[u8] = b"fee";
+pub const LST_SURCHARGE_SEED_PREFIX: &[u8] = b"surcharge";
+pub const PAIR_FEE_ACCOUNT_SEED_PREFIX: &[u8] = b"pair_fee";
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ProgramStateFindPdaArgs {
@@ -67,3 +69,110 @@ impl FeeAccountCreatePdaArgs {
         Pubkey::create_program_address(&self.to_signer_seeds(), &self.find_pda_args.program_id)
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SurgeConfigFindPdaArgs {
+    pub program_id: Pubkey,
+}
+
+impl SurgeConfigFindPdaArgs {
+    pub const fn to_seed(&self) -> [&[u8]; 1] {
+        [program::SURGE_CONFIG_SEED]
+    }
+
+    pub fn get_surge_config_address_and_bump_seed(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&self.to_seed(), &self.program_id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LstSurchargeFindPdaArgs {
+    pub program_id: Pubkey,
+    pub lst_mint: Pubkey,
+}
+
+impl LstSurchargeFindPdaArgs {
+    pub fn to_seed(&self) -> [&[u8]; 2] {
+        [LST_SURCHARGE_SEED_PREFIX, self.lst_mint.as_ref()]
+    }
+
+    pub fn get_lst_surcharge_address_and_bump_seed(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&self.to_seed(), &self.program_id)
+    }
+
+    /// Uses create_program_address if `bump` is known i.e. the LstSurcharge account
+    /// has been initialized, find_program_address otherwise
+    pub fn get_lst_surcharge_address_with_bump_opt(
+        self,
+        bump: Option<u8>,
+    ) -> Result<Pubkey, PubkeyError> {
+        match bump {
+            Some(bump) => LstSurchargeCreatePdaArgs {
+                find_pda_args: self,
+                bump,
+            }
+            .get_lst_surcharge_address(),
+            None => Ok(self.get_lst_surcharge_address_and_bump_seed().0),
+        }
+    }
+}
+
+pub struct LstSurchargeCreatePdaArgs {
+    pub find_pda_args: LstSurchargeFindPdaArgs,
+    pub bump: u8,
+}
+
+impl LstSurchargeCreatePdaArgs {
+    pub fn to_signer_seeds(&self) -> [&[u8]; 3] {
+        let [prefix, lst_mint] = self.find_pda_args.to_seed();
+
+        [prefix, lst_mint, std::slice::from_ref(&self.bump)]
+    }
+
+    pub fn get_lst_surcharge_address(&self) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(&self.to_signer_seeds(), &self.find_pda_args.program_id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PairFeeAccountFindPdaArgs {
+    pub program_id: Pubkey,
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+}
+
+impl PairFeeAccountFindPdaArgs {
+    pub fn to_seed(&self) -> [&[u8]; 3] {
+        [
+            PAIR_FEE_ACCOUNT_SEED_PREFIX,
+            self.input_lst_mint.as_ref(),
+            self.output_lst_mint.as_ref(),
+        ]
+    }
+
+    pub fn get_pair_fee_account_address_and_bump_seed(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&self.to_seed(), &self.program_id)
+    }
+}
+
+pub struct PairFeeAccountCreatePdaArgs {
+    pub find_pda_args: PairFeeAccountFindPdaArgs,
+    pub bump: u8,
+}
+
+impl PairFeeAccountCreatePdaArgs {
+    pub fn to_signer_seeds(&self) -> [&[u8]; 4] {
+        let [prefix, input_lst_mint, output_lst_mint] = self.find_pda_args.to_seed();
+
+        [
+            prefix,
+            input_lst_mint,
+            output_lst_mint,
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+
+    pub fn get_pair_fee_account_address(&self) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(&self.to_signer_seeds(), &self.find_pda_args.program_id)
+    }
+}
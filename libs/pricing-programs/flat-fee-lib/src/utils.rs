@@ -1,21 +1,154 @@
-use bytemuck::{try_from_bytes, try_from_bytes_mut};
+use bytemuck::{bytes_of_mut, try_from_bytes, try_from_bytes_mut, Pod, Zeroable};
 
-use flat_fee_interface::{FeeAccount, FlatFeeError, ProgramState};
+use flat_fee_interface::{
+    FeeAccount, FlatFeeError, LstSurcharge, PairFeeAccount, ProgramState, SurgeConfig,
+};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::{PairFeeAccountCreatePdaArgs, PairFeeAccountFindPdaArgs},
+    program::{LEGACY_FEE_ACCOUNT_SIZE, LEGACY_STATE_SIZE},
+};
+
+/// Reads `data` as a `T`, zero-extending it first if it has the legacy layout's size
+fn try_from_bytes_allow_legacy<T: Pod>(data: &[u8], legacy_size: usize) -> Option<T> {
+    if data.len() == legacy_size {
+        let mut res = T::zeroed();
+        bytes_of_mut(&mut res)
+            .get_mut(..legacy_size)?
+            .copy_from_slice(data);
+        return Some(res);
+    }
+    try_from_bytes(data).ok().copied()
+}
 
 pub fn try_program_state(program_state_acc_data: &[u8]) -> Result<&ProgramState, FlatFeeError> {
     try_from_bytes(program_state_acc_data).map_err(|_e| FlatFeeError::InvalidProgramStateData)
 }
 
+/// Like [`try_program_state`], but also accepts a ProgramState
+/// that has not yet been migrated from the legacy layout.
+/// Use for read-only access
+pub fn try_program_state_allow_legacy(
+    program_state_acc_data: &[u8],
+) -> Result<ProgramState, FlatFeeError> {
+    try_from_bytes_allow_legacy(program_state_acc_data, LEGACY_STATE_SIZE)
+        .ok_or(FlatFeeError::InvalidProgramStateData)
+}
+
 pub fn try_program_state_mut(
     program_state_acc_data: &mut [u8],
 ) -> Result<&mut ProgramState, FlatFeeError> {
     try_from_bytes_mut(program_state_acc_data).map_err(|_e| FlatFeeError::InvalidProgramStateData)
 }
 
+/// The admin authorized to update the fee bounds and the admin itself.
+/// A legacy ProgramState has no admin until it is migrated
+pub fn try_program_state_admin(state: &ProgramState) -> Result<Pubkey, FlatFeeError> {
+    if state.admin == Pubkey::default() {
+        return Err(FlatFeeError::AdminNotSet);
+    }
+    Ok(state.admin)
+}
+
 pub fn try_fee_account(fee_acc_data: &[u8]) -> Result<&FeeAccount, FlatFeeError> {
     try_from_bytes(fee_acc_data).map_err(|_e| FlatFeeError::UnsupportedLstMint)
 }
 
+/// Like [`try_fee_account`], but also accepts a FeeAccount
+/// that has not yet been migrated from the legacy layout.
+/// Use for read-only access
+pub fn try_fee_account_allow_legacy(fee_acc_data: &[u8]) -> Result<FeeAccount, FlatFeeError> {
+    try_from_bytes_allow_legacy(fee_acc_data, LEGACY_FEE_ACCOUNT_SIZE)
+        .ok_or(FlatFeeError::UnsupportedLstMint)
+}
+
+/// Like [`try_fee_account`], but fails with [`FlatFeeError::FeeAccountNeedsMigration`]
+/// for a FeeAccount that has not yet been migrated from the legacy layout.
+/// Use to verify a FeeAccount that is written to
+pub fn try_fee_account_migrated(fee_acc_data: &[u8]) -> Result<&FeeAccount, FlatFeeError> {
+    if fee_acc_data.len() == LEGACY_FEE_ACCOUNT_SIZE {
+        return Err(FlatFeeError::FeeAccountNeedsMigration);
+    }
+    try_fee_account(fee_acc_data)
+}
+
 pub fn try_fee_account_mut(fee_acc_data: &mut [u8]) -> Result<&mut FeeAccount, FlatFeeError> {
     try_from_bytes_mut(fee_acc_data).map_err(|_e| FlatFeeError::UnsupportedLstMint)
 }
+
+pub fn try_surge_config(surge_config_data: &[u8]) -> Result<&SurgeConfig, FlatFeeError> {
+    try_from_bytes(surge_config_data).map_err(|_e| FlatFeeError::InvalidSurgeConfigData)
+}
+
+pub fn try_surge_config_mut(
+    surge_config_data: &mut [u8],
+) -> Result<&mut SurgeConfig, FlatFeeError> {
+    try_from_bytes_mut(surge_config_data).map_err(|_e| FlatFeeError::InvalidSurgeConfigData)
+}
+
+pub fn try_lst_surcharge(lst_surcharge_data: &[u8]) -> Result<&LstSurcharge, FlatFeeError> {
+    try_from_bytes(lst_surcharge_data).map_err(|_e| FlatFeeError::InvalidLstSurchargeData)
+}
+
+pub fn try_lst_surcharge_mut(
+    lst_surcharge_data: &mut [u8],
+) -> Result<&mut LstSurcharge, FlatFeeError> {
+    try_from_bytes_mut(lst_surcharge_data).map_err(|_e| FlatFeeError::InvalidLstSurchargeData)
+}
+
+pub fn try_pair_fee_account(pair_fee_acc_data: &[u8]) -> Result<&PairFeeAccount, FlatFeeError> {
+    try_from_bytes(pair_fee_acc_data).map_err(|_e| FlatFeeError::InvalidPairFeeAccountData)
+}
+
+pub fn try_pair_fee_account_mut(
+    pair_fee_acc_data: &mut [u8],
+) -> Result<&mut PairFeeAccount, FlatFeeError> {
+    try_from_bytes_mut(pair_fee_acc_data).map_err(|_e| FlatFeeError::InvalidPairFeeAccountData)
+}
+
+/// Reads the fee override from a PairFeeAccount passed as a remaining account,
+/// verifying that it is the PairFeeAccount PDA for the given LST pair
+pub fn try_pair_fee_override<D: ReadonlyAccountPubkey + ReadonlyAccountData>(
+    pair_fee_acc: D,
+    find_pda_args: PairFeeAccountFindPdaArgs,
+) -> Result<i16, FlatFeeError> {
+    let bytes = pair_fee_acc.data();
+    let PairFeeAccount { bump, fee_bps, .. } = try_pair_fee_account(&bytes)?;
+    let expected = PairFeeAccountCreatePdaArgs {
+        find_pda_args,
+        bump: *bump,
+    }
+    .get_pair_fee_account_address()
+    .map_err(|_e| FlatFeeError::IncorrectPairFeeAccount)?;
+    if *pair_fee_acc.pubkey() != expected {
+        return Err(FlatFeeError::IncorrectPairFeeAccount);
+    }
+    Ok(*fee_bps)
+}
+
+/// Reads the little-endian u64 epoch the stake pool was last updated for
+/// at `last_update_epoch_offset` in the stake pool account's data
+pub fn try_stake_pool_last_update_epoch(
+    stake_pool_data: &[u8],
+    last_update_epoch_offset: u16,
+) -> Result<u64, FlatFeeError> {
+    let start = usize::from(last_update_epoch_offset);
+    stake_pool_data
+        .get(start..start + 8)
+        .and_then(|s| s.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(FlatFeeError::InvalidStakePoolData)
+}
+
+/// Returns None if the LstSurcharge account is not initialized
+pub fn try_lst_surcharge_bump_opt<D: ReadonlyAccountData>(
+    surcharge_acc: D,
+) -> Result<Option<u8>, FlatFeeError> {
+    let bytes = surcharge_acc.data();
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(try_lst_surcharge(&bytes)?.bump))
+}
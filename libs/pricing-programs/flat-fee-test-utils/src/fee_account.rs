@@ -26,6 +26,8 @@ impl IntoAccount for MockFeeAccount {
 pub struct MockFeeAccountArgs {
     pub input_fee_bps: i16,
     pub output_fee_bps: i16,
+    pub lp_deposit_fee_bps: u16,
+    pub lp_withdrawal_fee_bps: Option<u16>,
     pub lst_mint: Pubkey,
 }
 
@@ -34,6 +36,8 @@ impl MockFeeAccountArgs {
         let Self {
             input_fee_bps,
             output_fee_bps,
+            lp_deposit_fee_bps,
+            lp_withdrawal_fee_bps,
             lst_mint,
         } = self;
         let (addr, bump) = FeeAccountFindPdaArgs {
@@ -45,8 +49,22 @@ impl MockFeeAccountArgs {
             FeeAccount {
                 input_fee_bps: *input_fee_bps,
                 output_fee_bps: *output_fee_bps,
+                lp_deposit_fee_bps: *lp_deposit_fee_bps,
                 bump,
-                padding: 0u8,
+                has_lp_withdrawal_fee: lp_withdrawal_fee_bps.is_some().into(),
+                lp_withdrawal_fee_bps: lp_withdrawal_fee_bps.unwrap_or_default(),
+                pending_input_fee_bps: 0,
+                pending_output_fee_bps: 0,
+                has_pending_fees: 0,
+                num_pair_fees: 0,
+                pending_fees_activation_epoch: 0,
+                baseline_input_fee_bps: 0,
+                baseline_output_fee_bps: 0,
+                baseline_lp_deposit_fee_bps: 0,
+                baseline_lp_withdrawal_fee_bps: 0,
+                has_fee_baseline: 0,
+                fee_baseline_padding: [0; 7],
+                fee_baseline_epoch: 0,
             },
             addr,
         )
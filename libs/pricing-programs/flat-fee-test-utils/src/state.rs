@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use flat_fee_interface::ProgramState;
 use flat_fee_lib::{
-    initial_constants::{initial_manager, INITIAL_LP_WITHDRAWAL_FEE_BPS},
+    initial_constants::{
+        initial_manager, INITIAL_LP_DEPOSIT_FEE_BPS, INITIAL_LP_WITHDRAWAL_FEE_BPS,
+    },
     program::STATE_SIZE,
     utils::try_program_state_mut,
 };
@@ -12,6 +14,25 @@ use solana_sdk::account::Account;
 pub const DEFAULT_PROGRAM_STATE: ProgramState = ProgramState {
     manager: initial_manager::ID,
     lp_withdrawal_fee_bps: INITIAL_LP_WITHDRAWAL_FEE_BPS,
+    lp_deposit_fee_bps: INITIAL_LP_DEPOSIT_FEE_BPS,
+    pending_lp_withdrawal_fee_bps: 0,
+    has_pending_lp_withdrawal_fee: 0,
+    padding: 0,
+    pending_lp_withdrawal_fee_activation_epoch: 0,
+    admin: initial_manager::ID,
+    min_lst_fee_bps: 0,
+    max_lst_fee_bps: 0,
+    max_lp_withdrawal_fee_bps: 0,
+    max_fee_change_bps: 0,
+    max_lp_deposit_fee_bps: 0,
+    max_surge_fee_bps: 0,
+    has_fee_bounds: 0,
+    fee_bounds_padding: [0; 3],
+    baseline_lp_withdrawal_fee_bps: 0,
+    baseline_lp_deposit_fee_bps: 0,
+    has_fee_baseline: 0,
+    fee_baseline_padding: [0; 3],
+    fee_baseline_epoch: 0,
 };
 
 pub struct MockProgramState(pub ProgramState);
@@ -0,0 +1,21 @@
+mod add_lst;
+mod initialize;
+mod price_exact_in;
+mod price_exact_out;
+mod price_lp_tokens_to_redeem;
+mod remove_lst;
+mod set_lp_withdrawal_fee;
+mod set_lst_fee;
+mod set_lst_fee_tiers;
+mod set_manager;
+
+pub use add_lst::*;
+pub use initialize::*;
+pub use price_exact_in::*;
+pub use price_exact_out::*;
+pub use price_lp_tokens_to_redeem::*;
+pub use remove_lst::*;
+pub use set_lp_withdrawal_fee::*;
+pub use set_lst_fee::*;
+pub use set_lst_fee_tiers::*;
+pub use set_manager::*;
@@ -0,0 +1,129 @@
+use s_controller_lib::program::{LST_STATE_LIST_ID, POOL_STATE_ID};
+use solana_program::{
+    instruction::AccountMeta,
+    pubkey::{Pubkey, PubkeyError},
+};
+use tiered_fee_interface::{PriceExactOutKeys, PRICE_EXACT_OUT_IX_ACCOUNTS_LEN};
+
+use crate::{
+    pda::{FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs},
+    program as tiered_fee_program,
+};
+
+/// Uses find_program_address, for use with
+/// - initial creation
+/// - client side
+pub struct PriceExactOutFreeArgs {
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+    pub input_pool_reserves: Pubkey,
+    pub output_pool_reserves: Pubkey,
+}
+
+impl PriceExactOutFreeArgs {
+    pub fn resolve(self) -> PriceExactOutKeys {
+        self.resolve_inner(tiered_fee_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> PriceExactOutKeys {
+        self.resolve_inner(program_id)
+    }
+
+    fn resolve_inner(self, program_id: Pubkey) -> PriceExactOutKeys {
+        let input_find_pda_args = FeeAccountFindPdaArgs {
+            lst_mint: self.input_lst_mint,
+            program_id,
+        };
+        let (input_fee_acc, _bump) = input_find_pda_args.get_fee_account_address_and_bump_seed();
+
+        let output_find_pda_args = FeeAccountFindPdaArgs {
+            lst_mint: self.output_lst_mint,
+            program_id,
+        };
+        let (output_fee_acc, _bump) = output_find_pda_args.get_fee_account_address_and_bump_seed();
+
+        PriceExactOutKeys {
+            input_lst_mint: self.input_lst_mint,
+            output_lst_mint: self.output_lst_mint,
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            input_pool_reserves: self.input_pool_reserves,
+            output_pool_reserves: self.output_pool_reserves,
+            input_fee_acc,
+            output_fee_acc,
+        }
+    }
+
+    pub fn resolve_to_account_metas(self) -> [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN] {
+        let keys = self.resolve();
+        keys.into()
+    }
+}
+
+impl From<pricing_programs_interface::PriceExactOutKeys> for PriceExactOutFreeArgs {
+    /// pool_state and lst_state_list are dropped since
+    /// they're always resolved to the S controller's PDAs
+    fn from(
+        pricing_programs_interface::PriceExactOutKeys {
+            input_lst_mint,
+            output_lst_mint,
+            input_pool_reserves,
+            output_pool_reserves,
+            ..
+        }: pricing_programs_interface::PriceExactOutKeys,
+    ) -> Self {
+        Self {
+            input_lst_mint,
+            output_lst_mint,
+            input_pool_reserves,
+            output_pool_reserves,
+        }
+    }
+}
+
+pub struct PriceExactOutWithBumpFreeArgs {
+    pub args: PriceExactOutFreeArgs,
+    pub input_fee_acc_bump: u8,
+    pub output_fee_acc_bump: u8,
+}
+
+impl PriceExactOutWithBumpFreeArgs {
+    pub fn resolve(self) -> Result<PriceExactOutKeys, PubkeyError> {
+        self.resolve_inner(tiered_fee_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<PriceExactOutKeys, PubkeyError> {
+        self.resolve_inner(program_id)
+    }
+
+    fn resolve_inner(self, program_id: Pubkey) -> Result<PriceExactOutKeys, PubkeyError> {
+        let input_create_pda_args = FeeAccountCreatePdaArgs {
+            find_pda_args: FeeAccountFindPdaArgs {
+                lst_mint: self.args.input_lst_mint,
+                program_id,
+            },
+            bump: self.input_fee_acc_bump,
+        };
+        let input_fee_acc = input_create_pda_args.get_fee_account_address()?;
+
+        let output_create_pda_args = FeeAccountCreatePdaArgs {
+            find_pda_args: FeeAccountFindPdaArgs {
+                lst_mint: self.args.output_lst_mint,
+                program_id,
+            },
+            bump: self.output_fee_acc_bump,
+        };
+        let output_fee_acc = output_create_pda_args.get_fee_account_address()?;
+
+        Ok(PriceExactOutKeys {
+            input_lst_mint: self.args.input_lst_mint,
+            output_lst_mint: self.args.output_lst_mint,
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            input_pool_reserves: self.args.input_pool_reserves,
+            output_pool_reserves: self.args.output_pool_reserves,
+            input_fee_acc,
+            output_fee_acc,
+        })
+    }
+}
@@ -0,0 +1,58 @@
+use s_controller_lib::program::{LST_STATE_LIST_ID, POOL_STATE_ID};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use tiered_fee_interface::{PriceLpTokensToRedeemKeys, PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN};
+
+use crate::{pda::ProgramStateFindPdaArgs, program as tiered_fee_program};
+
+pub struct PriceLpTokensToRedeemFreeArgs {
+    pub output_lst_mint: Pubkey,
+    pub pool_reserves: Pubkey,
+}
+
+impl PriceLpTokensToRedeemFreeArgs {
+    pub fn resolve(&self) -> PriceLpTokensToRedeemKeys {
+        self.resolve_inner(tiered_fee_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(&self, program_id: Pubkey) -> PriceLpTokensToRedeemKeys {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(&self, state_id: Pubkey) -> PriceLpTokensToRedeemKeys {
+        PriceLpTokensToRedeemKeys {
+            output_lst_mint: self.output_lst_mint,
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            pool_reserves: self.pool_reserves,
+            state: state_id,
+        }
+    }
+
+    pub fn resolve_to_account_metas(
+        self,
+    ) -> [AccountMeta; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN] {
+        let keys = self.resolve();
+        keys.into()
+    }
+}
+
+impl From<pricing_programs_interface::PriceLpTokensToRedeemKeys> for PriceLpTokensToRedeemFreeArgs {
+    /// pool_state and lst_state_list are dropped since
+    /// they're always resolved to the S controller's PDAs
+    fn from(
+        pricing_programs_interface::PriceLpTokensToRedeemKeys {
+            output_lst_mint,
+            pool_reserves,
+            ..
+        }: pricing_programs_interface::PriceLpTokensToRedeemKeys,
+    ) -> Self {
+        Self {
+            output_lst_mint,
+            pool_reserves,
+        }
+    }
+}
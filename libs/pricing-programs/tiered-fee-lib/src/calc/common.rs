@@ -0,0 +1,60 @@
+use sanctum_token_ratio::{FloorDiv, U64Ratio, BPS_DENOMINATOR};
+use tiered_fee_interface::{FeeAccount, FeeTier, TieredFeeError};
+
+use crate::utils::fee_account_tiers;
+
+use super::{TierBasis, TierBasisSolValues, BPS_DENOMINATOR_I64};
+
+/// The fee params of a swap between two LSTs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SwapFeeArgs<'a> {
+    /// The input LST's `input_fee_bps`
+    pub input_fee_bps: i16,
+    /// The output LST's `output_fee_bps`
+    pub output_fee_bps: i16,
+    /// The output LST's fee tiers
+    pub tiers: &'a [FeeTier],
+    /// The SOL value the output LST's fee tier thresholds are relative to
+    pub tier_basis_sol_value: u64,
+}
+
+impl<'a> SwapFeeArgs<'a> {
+    pub fn new(
+        input_fee_acc: &FeeAccount,
+        output_fee_acc: &'a FeeAccount,
+        basis_sol_values: TierBasisSolValues,
+    ) -> Result<Self, TieredFeeError> {
+        let tier_basis = TierBasis::try_from(output_fee_acc.tier_basis)?;
+        Ok(Self {
+            input_fee_bps: input_fee_acc.input_fee_bps,
+            output_fee_bps: output_fee_acc.output_fee_bps,
+            tiers: fee_account_tiers(output_fee_acc)?,
+            tier_basis_sol_value: basis_sol_values.get(tier_basis),
+        })
+    }
+
+    /// Returns the ratio that returns out_sol_value
+    /// when applied to an in_sol_value that falls in the fee tier with `tier_fee_bps`
+    pub fn out_sol_value_ratio(
+        &self,
+        tier_fee_bps: u16,
+    ) -> Result<FloorDiv<U64Ratio<u16, u16>>, TieredFeeError> {
+        // Base fees may be negative but the total is floored at 0:
+        // tiered fees never price a rebate
+        let fee_bps = (i64::from(self.input_fee_bps)
+            + i64::from(self.output_fee_bps)
+            + i64::from(tier_fee_bps))
+        .max(0);
+        // post_fee_bps = 10_000 - fee_bps
+        // out_sol_value = floor(in_sol_value * post_fee_bps / 10_000)
+        // >100% fees will error (post_fee_bps < 0)
+        let post_fee_bps: u16 = BPS_DENOMINATOR_I64
+            .checked_sub(fee_bps)
+            .and_then(|v| v.try_into().ok())
+            .ok_or(TieredFeeError::MathError)?;
+        Ok(FloorDiv(U64Ratio {
+            num: post_fee_bps,
+            denom: BPS_DENOMINATOR,
+        }))
+    }
+}
@@ -0,0 +1,117 @@
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use tiered_fee_interface::{FeeTier, TieredFeeError};
+
+/// What an LST's fee tier thresholds are relative to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum TierBasis {
+    /// The SOL value of the output LST's reserves, its `LstState.sol_value`
+    OutputReserves = 0,
+    /// The pool's total SOL value, `PoolState.total_sol_value`
+    PoolTotal = 1,
+}
+
+impl TryFrom<u8> for TierBasis {
+    type Error = TieredFeeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::OutputReserves),
+            1 => Ok(Self::PoolTotal),
+            _ => Err(TieredFeeError::InvalidTierBasis),
+        }
+    }
+}
+
+impl From<TierBasis> for u8 {
+    fn from(value: TierBasis) -> Self {
+        value as Self
+    }
+}
+
+/// The SOL values that fee tier thresholds can be relative to
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TierBasisSolValues {
+    /// The output LST's `LstState.sol_value`
+    pub output_reserves_sol_value: u64,
+    /// The S controller's `PoolState.total_sol_value`
+    pub pool_total_sol_value: u64,
+}
+
+impl TierBasisSolValues {
+    pub const fn get(&self, basis: TierBasis) -> u64 {
+        match basis {
+            TierBasis::OutputReserves => self.output_reserves_sol_value,
+            TierBasis::PoolTotal => self.pool_total_sol_value,
+        }
+    }
+}
+
+/// The smallest swap SOL value that reaches a fee tier's threshold:
+/// `ceil(threshold_bps * basis_sol_value / 10_000)`
+pub fn tier_min_sol_value(threshold_bps: u16, basis_sol_value: u64) -> u64 {
+    let num = u128::from(threshold_bps) * u128::from(basis_sol_value);
+    let denom = u128::from(BPS_DENOMINATOR);
+    // ceil div
+    let min = (num + denom - 1) / denom;
+    min.try_into().unwrap_or(u64::MAX)
+}
+
+/// Returns `(min_swap_sol_value, fee_bps)` of each fee tier in increasing order,
+/// starting with the implicit 0 bps tier for swaps that reach no threshold.
+///
+/// `tiers` must have strictly increasing thresholds.
+pub fn fee_tier_segments(
+    tiers: &[FeeTier],
+    basis_sol_value: u64,
+) -> impl Iterator<Item = (u64, u16)> + '_ {
+    let tier_segments = tiers.iter().map(move |tier| {
+        (
+            tier_min_sol_value(tier.threshold_bps, basis_sol_value),
+            tier.fee_bps,
+        )
+    });
+    std::iter::once((0, 0)).chain(tier_segments)
+}
+
+/// Returns the additional fee in bps levied on a swap of `swap_sol_value`:
+/// the `fee_bps` of the highest tier whose threshold the swap reaches, or 0 if it reaches none
+pub fn tier_fee_bps(tiers: &[FeeTier], basis_sol_value: u64, swap_sol_value: u64) -> u16 {
+    fee_tier_segments(tiers, basis_sol_value)
+        .take_while(|(min_sol_value, _fee_bps)| *min_sol_value <= swap_sol_value)
+        .last()
+        .map_or(0, |(_min_sol_value, fee_bps)| fee_bps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIERS: [FeeTier; 2] = [
+        FeeTier {
+            threshold_bps: 100,
+            fee_bps: 5,
+        },
+        FeeTier {
+            threshold_bps: 1_000,
+            fee_bps: 20,
+        },
+    ];
+
+    #[test]
+    fn tier_fee_by_swap_size() {
+        // thresholds at 1% and 10% of 10_001 are 101 and 1001 after rounding up
+        assert_eq!(tier_fee_bps(&TIERS, 10_001, 0), 0);
+        assert_eq!(tier_fee_bps(&TIERS, 10_001, 100), 0);
+        assert_eq!(tier_fee_bps(&TIERS, 10_001, 101), 5);
+        assert_eq!(tier_fee_bps(&TIERS, 10_001, 1_000), 5);
+        assert_eq!(tier_fee_bps(&TIERS, 10_001, 1_001), 20);
+        assert_eq!(tier_fee_bps(&TIERS, 10_001, u64::MAX), 20);
+    }
+
+    #[test]
+    fn empty_basis_charges_highest_tier() {
+        assert_eq!(tier_fee_bps(&TIERS, 0, 0), 20);
+        assert_eq!(tier_fee_bps(&[], 0, 1), 0);
+    }
+}
@@ -0,0 +1,13 @@
+mod common;
+mod fee_tiers;
+mod price_exact_in;
+mod price_exact_out;
+mod price_lp_tokens_to_redeem;
+
+pub use common::*;
+pub use fee_tiers::*;
+pub use price_exact_in::*;
+pub use price_exact_out::*;
+pub use price_lp_tokens_to_redeem::*;
+
+const BPS_DENOMINATOR_I64: i64 = 10_000;
@@ -0,0 +1,22 @@
+use sanctum_token_ratio::ReversibleRatio;
+use tiered_fee_interface::TieredFeeError;
+
+use super::{tier_fee_bps, SwapFeeArgs};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CalculatePriceExactInArgs<'a> {
+    pub fees: SwapFeeArgs<'a>,
+    pub in_sol_value: u64,
+}
+
+/// Returns `out_sol_value`.
+///
+/// The fee tier is selected by `in_sol_value`.
+pub fn calculate_price_exact_in(
+    CalculatePriceExactInArgs { fees, in_sol_value }: CalculatePriceExactInArgs,
+) -> Result<u64, TieredFeeError> {
+    let tier_fee_bps = tier_fee_bps(fees.tiers, fees.tier_basis_sol_value, in_sol_value);
+    fees.out_sol_value_ratio(tier_fee_bps)?
+        .apply(in_sol_value)
+        .map_err(|_e| TieredFeeError::MathError)
+}
@@ -0,0 +1,152 @@
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use tiered_fee_interface::TieredFeeError;
+
+use super::{fee_tier_segments, SwapFeeArgs};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CalculatePriceExactOutArgs<'a> {
+    pub fees: SwapFeeArgs<'a>,
+    pub out_sol_value: u64,
+}
+
+/// Returns `in_sol_value`, the smallest input SOL value that
+/// [`calculate_price_exact_in`](super::calculate_price_exact_in) prices at `out_sol_value` or more.
+///
+/// Since the fee tier is selected by `in_sol_value`, which is what is being solved for,
+/// this goes through the tiers in increasing order and returns the input SOL value required
+/// to cover `out_sol_value` after the first tier's fees that actually falls in that tier.
+pub fn calculate_price_exact_out(
+    CalculatePriceExactOutArgs {
+        fees,
+        out_sol_value,
+    }: CalculatePriceExactOutArgs,
+) -> Result<u64, TieredFeeError> {
+    let mut segments = fee_tier_segments(fees.tiers, fees.tier_basis_sol_value).peekable();
+    while let Some((min_sol_value, tier_fee_bps)) = segments.next() {
+        let post_fee_bps = fees.out_sol_value_ratio(tier_fee_bps)?.0.num;
+        if post_fee_bps == 0 {
+            continue;
+        }
+        // smallest in_sol_value with floor(in_sol_value * post_fee_bps / 10_000) >= out_sol_value,
+        // ceil(out_sol_value * 10_000 / post_fee_bps)
+        let num = u128::from(out_sol_value) * u128::from(BPS_DENOMINATOR);
+        let denom = u128::from(post_fee_bps);
+        let required: u64 = match ((num + denom - 1) / denom).try_into() {
+            Ok(r) => r,
+            Err(_e) => continue,
+        };
+        let in_sol_value = required.max(min_sol_value);
+        let is_in_tier = segments
+            .peek()
+            .map_or(true, |(next_min_sol_value, _fee_bps)| {
+                in_sol_value < *next_min_sol_value
+            });
+        if is_in_tier {
+            return Ok(in_sol_value);
+        }
+    }
+    Err(TieredFeeError::MathError)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use tiered_fee_interface::FeeTier;
+
+    use crate::{
+        calc::{calculate_price_exact_in, CalculatePriceExactInArgs},
+        program::MAX_FEE_TIERS,
+    };
+
+    use super::*;
+
+    const MAX_SOL_VALUE: u64 = 1_000_000_000_000_000_000;
+
+    prop_compose! {
+        fn fee_tiers()
+            (thresholds in proptest::collection::btree_set(0..=10_000u16, 0..=MAX_FEE_TIERS))
+            (
+                fees in proptest::collection::vec(0..=3_000u16, thresholds.len()),
+                thresholds in Just(thresholds),
+            ) -> Vec<FeeTier> {
+                thresholds
+                    .into_iter()
+                    .zip(fees)
+                    .map(|(threshold_bps, fee_bps)| FeeTier { threshold_bps, fee_bps })
+                    .collect()
+            }
+    }
+
+    prop_compose! {
+        fn base_fees()
+            (
+                input_fee_bps in -3_000..=3_000i16,
+                output_fee_bps in -3_000..=3_000i16,
+            ) -> (i16, i16) {
+                (input_fee_bps, output_fee_bps)
+            }
+    }
+
+    fn price_exact_in(fees: SwapFeeArgs, in_sol_value: u64) -> u64 {
+        calculate_price_exact_in(CalculatePriceExactInArgs { fees, in_sol_value }).unwrap()
+    }
+
+    fn price_exact_out(fees: SwapFeeArgs, out_sol_value: u64) -> u64 {
+        calculate_price_exact_out(CalculatePriceExactOutArgs {
+            fees,
+            out_sol_value,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn exact_out_skips_tier_it_would_fall_out_of() {
+        // 10% fee for swaps of at least 100
+        let tiers = [FeeTier {
+            threshold_bps: 100,
+            fee_bps: 1_000,
+        }];
+        let fees = SwapFeeArgs {
+            input_fee_bps: 0,
+            output_fee_bps: 0,
+            tiers: &tiers,
+            tier_basis_sol_value: 10_000,
+        };
+        assert_eq!(price_exact_out(fees, 99), 99);
+        // 100 in would fall in the 10% tier and only get 90 out
+        assert_eq!(price_exact_out(fees, 100), 112);
+        assert_eq!(price_exact_in(fees, 111), 99);
+        assert_eq!(price_exact_in(fees, 112), 100);
+    }
+
+    proptest! {
+        #[test]
+        fn exact_in_of_exact_out_covers_out(
+            tiers in fee_tiers(),
+            (input_fee_bps, output_fee_bps) in base_fees(),
+            tier_basis_sol_value in 0..=MAX_SOL_VALUE,
+            out_sol_value in 0..=MAX_SOL_VALUE,
+        ) {
+            let fees = SwapFeeArgs { input_fee_bps, output_fee_bps, tiers: &tiers, tier_basis_sol_value };
+            let in_sol_value = price_exact_out(fees, out_sol_value);
+            prop_assert!(price_exact_in(fees, in_sol_value) >= out_sol_value);
+            if in_sol_value > 0 {
+                prop_assert!(price_exact_in(fees, in_sol_value - 1) < out_sol_value);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn exact_out_of_exact_in_never_costs_more(
+            tiers in fee_tiers(),
+            (input_fee_bps, output_fee_bps) in base_fees(),
+            tier_basis_sol_value in 0..=MAX_SOL_VALUE,
+            in_sol_value in 0..=MAX_SOL_VALUE,
+        ) {
+            let fees = SwapFeeArgs { input_fee_bps, output_fee_bps, tiers: &tiers, tier_basis_sol_value };
+            let out_sol_value = price_exact_in(fees, in_sol_value);
+            prop_assert!(price_exact_out(fees, out_sol_value) <= in_sol_value);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use tiered_fee_interface::{FeeTier, TieredFeeError};
+
+use crate::program::MAX_FEE_TIERS;
+
+const MAX_SIGNED_FEE_BPS: i16 = 10_000;
+const MAX_UNSIGNED_FEE_BPS: u16 = 10_000;
+const MAX_TIER_THRESHOLD_BPS: u16 = 10_000;
+
+pub fn verify_signed_fee_bps_bound(fee_bps_i16: i16) -> Result<(), TieredFeeError> {
+    if !(-MAX_SIGNED_FEE_BPS..=MAX_SIGNED_FEE_BPS).contains(&fee_bps_i16) {
+        return Err(TieredFeeError::SignedFeeOutOfBound);
+    }
+    Ok(())
+}
+
+pub fn verify_unsigned_fee_bps_bound(fee_bps_u16: u16) -> Result<(), TieredFeeError> {
+    if !(0..=MAX_UNSIGNED_FEE_BPS).contains(&fee_bps_u16) {
+        return Err(TieredFeeError::UnsignedFeeOutOfBound);
+    }
+    Ok(())
+}
+
+/// Verifies the base fees of a FeeAccount
+pub fn verify_lst_fee_bounds(
+    input_fee_bps: i16,
+    output_fee_bps: i16,
+) -> Result<(), TieredFeeError> {
+    verify_signed_fee_bps_bound(input_fee_bps)?;
+    verify_signed_fee_bps_bound(output_fee_bps)
+}
+
+/// Verifies that there are at most [`MAX_FEE_TIERS`] fee tiers
+/// with strictly increasing thresholds of at most 10_000 bps
+pub fn verify_fee_tiers(tiers: &[FeeTier]) -> Result<(), TieredFeeError> {
+    if tiers.len() > MAX_FEE_TIERS
+        || tiers
+            .iter()
+            .any(|tier| tier.threshold_bps > MAX_TIER_THRESHOLD_BPS)
+        || tiers
+            .windows(2)
+            .any(|w| w[0].threshold_bps >= w[1].threshold_bps)
+    {
+        return Err(TieredFeeError::InvalidFeeTiers);
+    }
+    tiers
+        .iter()
+        .try_for_each(|tier| verify_unsigned_fee_bps_bound(tier.fee_bps))
+}
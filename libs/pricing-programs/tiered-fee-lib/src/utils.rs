@@ -0,0 +1,42 @@
+use bytemuck::{try_from_bytes, try_from_bytes_mut};
+
+use s_controller_interface::LstState;
+use s_controller_lib::try_find_lst_mint_on_list;
+use solana_program::pubkey::Pubkey;
+use tiered_fee_interface::{FeeAccount, FeeTier, ProgramState, TieredFeeError};
+
+pub fn try_program_state(program_state_acc_data: &[u8]) -> Result<&ProgramState, TieredFeeError> {
+    try_from_bytes(program_state_acc_data).map_err(|_e| TieredFeeError::InvalidProgramStateData)
+}
+
+pub fn try_program_state_mut(
+    program_state_acc_data: &mut [u8],
+) -> Result<&mut ProgramState, TieredFeeError> {
+    try_from_bytes_mut(program_state_acc_data).map_err(|_e| TieredFeeError::InvalidProgramStateData)
+}
+
+pub fn try_fee_account(fee_acc_data: &[u8]) -> Result<&FeeAccount, TieredFeeError> {
+    try_from_bytes(fee_acc_data).map_err(|_e| TieredFeeError::UnsupportedLstMint)
+}
+
+pub fn try_fee_account_mut(fee_acc_data: &mut [u8]) -> Result<&mut FeeAccount, TieredFeeError> {
+    try_from_bytes_mut(fee_acc_data).map_err(|_e| TieredFeeError::UnsupportedLstMint)
+}
+
+/// Returns the first `tier_count` tiers of a FeeAccount, the ones in use
+pub fn fee_account_tiers(fee_acc: &FeeAccount) -> Result<&[FeeTier], TieredFeeError> {
+    fee_acc
+        .tiers
+        .get(..usize::from(fee_acc.tier_count))
+        .ok_or(TieredFeeError::InvalidFeeTiers)
+}
+
+/// Returns the `sol_value` of `lst_mint` on the S controller's LST state list
+pub fn try_lst_sol_value(
+    lst_mint: &Pubkey,
+    lst_state_list: &[LstState],
+) -> Result<u64, TieredFeeError> {
+    try_find_lst_mint_on_list(*lst_mint, lst_state_list)
+        .map(|(_i, lst_state)| lst_state.sol_value)
+        .map_err(|_e| TieredFeeError::LstNotInPool)
+}
@@ -0,0 +1,71 @@
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, IntoAccount};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use tiered_fee_interface::{FeeAccount, FeeTier};
+use tiered_fee_lib::{
+    calc::TierBasis,
+    pda::FeeAccountFindPdaArgs,
+    program::{FEE_ACCOUNT_SIZE, MAX_FEE_TIERS},
+    utils::try_fee_account_mut,
+};
+
+pub struct MockFeeAccount(pub FeeAccount);
+
+impl IntoAccount for MockFeeAccount {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; FEE_ACCOUNT_SIZE];
+        let dst = try_fee_account_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(FEE_ACCOUNT_SIZE),
+            data,
+            owner: tiered_fee_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MockFeeAccountArgs {
+    pub input_fee_bps: i16,
+    pub output_fee_bps: i16,
+    pub tier_basis: TierBasis,
+    /// Must be at most [`MAX_FEE_TIERS`] long
+    pub tiers: &'static [FeeTier],
+    pub lst_mint: Pubkey,
+}
+
+impl MockFeeAccountArgs {
+    pub fn to_fee_account_and_addr(&self, program_id: Pubkey) -> (FeeAccount, Pubkey) {
+        let Self {
+            input_fee_bps,
+            output_fee_bps,
+            tier_basis,
+            tiers,
+            lst_mint,
+        } = self;
+        let (addr, bump) = FeeAccountFindPdaArgs {
+            lst_mint: *lst_mint,
+            program_id,
+        }
+        .get_fee_account_address_and_bump_seed();
+        let mut fee_tiers = [FeeTier {
+            threshold_bps: 0,
+            fee_bps: 0,
+        }; MAX_FEE_TIERS];
+        fee_tiers[..tiers.len()].copy_from_slice(tiers);
+        (
+            FeeAccount {
+                input_fee_bps: *input_fee_bps,
+                output_fee_bps: *output_fee_bps,
+                bump,
+                tier_basis: (*tier_basis).into(),
+                tier_count: tiers.len().try_into().unwrap(),
+                padding: 0u8,
+                tiers: fee_tiers,
+            },
+            addr,
+        )
+    }
+}
@@ -0,0 +1,7 @@
+mod fee_account;
+mod state;
+mod tiered_fee_program_test;
+
+pub use fee_account::*;
+pub use state::*;
+pub use tiered_fee_program_test::*;
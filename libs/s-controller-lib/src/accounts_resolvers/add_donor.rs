@@ -0,0 +1,50 @@
+use s_controller_interface::{AddDonorKeys, SControllerError};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    find_donor_allowlist_address, find_pool_state_address,
+    program::{DONOR_ALLOWLIST_ID, POOL_STATE_ID},
+    try_pool_state,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddDonorFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub payer: Pubkey,
+    pub new_donor: Pubkey,
+    pub pool_state_acc: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> AddDonorFreeArgs<S> {
+    pub fn resolve(&self) -> Result<AddDonorKeys, SControllerError> {
+        if *self.pool_state_acc.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(AddDonorKeys {
+            payer: self.payer,
+            admin: pool_state.admin,
+            pool_state: POOL_STATE_ID,
+            new_donor: self.new_donor,
+            donor_allowlist: DONOR_ALLOWLIST_ID,
+            system_program: system_program::ID,
+        })
+    }
+
+    pub fn resolve_for_prog(&self, program_id: Pubkey) -> Result<AddDonorKeys, SControllerError> {
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(AddDonorKeys {
+            payer: self.payer,
+            admin: pool_state.admin,
+            pool_state: find_pool_state_address(program_id).0,
+            new_donor: self.new_donor,
+            donor_allowlist: find_donor_allowlist_address(program_id).0,
+            system_program: system_program::ID,
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use s_controller_interface::SControllerError;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address, program::LST_STATE_LIST_ID, try_find_lst_mint_on_list,
+    try_lst_state_list, try_match_lst_mint_on_list,
+};
+
+/// Number of accounts of an additional rebalance destination LST
+/// that precede its SOL value calculator accounts
+pub const ADDITIONAL_REBALANCE_DST_ACCOUNTS_LEN: usize = 2;
+
+/// Accounts of an additional destination LST of a multi-destination rebalance.
+///
+/// These are appended to StartRebalanceV2 and EndRebalanceV2 after the primary
+/// destination LST's SOL value calculator accounts, each followed by the
+/// additional destination LST's own SOL value calculator accounts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdditionalRebalanceDstKeys {
+    pub lst_mint: Pubkey,
+    pub pool_reserves: Pubkey,
+}
+
+impl From<AdditionalRebalanceDstKeys> for [AccountMeta; ADDITIONAL_REBALANCE_DST_ACCOUNTS_LEN] {
+    fn from(
+        AdditionalRebalanceDstKeys {
+            lst_mint,
+            pool_reserves,
+        }: AdditionalRebalanceDstKeys,
+    ) -> Self {
+        [
+            AccountMeta::new_readonly(lst_mint, false),
+            AccountMeta::new_readonly(pool_reserves, false),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AdditionalRebalanceDstFreeArgs<
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub lst_index: usize,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+impl<
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > AdditionalRebalanceDstFreeArgs<L, M>
+{
+    pub fn resolve(self) -> Result<AdditionalRebalanceDstKeys, SControllerError> {
+        if *self.lst_state_list.pubkey() != LST_STATE_LIST_ID {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list(&lst_state_list_acc_data)?;
+
+        let lst_state = try_match_lst_mint_on_list(*self.lst_mint.pubkey(), list, self.lst_index)?;
+        let pool_reserves = create_pool_reserves_address(lst_state, *self.lst_mint.owner())?;
+
+        Ok(AdditionalRebalanceDstKeys {
+            lst_mint: lst_state.mint,
+            pool_reserves,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find lst_index.
+/// Suitable for use on client-side
+#[derive(Clone, Copy, Debug)]
+pub struct AdditionalRebalanceDstByMintFreeArgs<L, M> {
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+impl<L: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
+    AdditionalRebalanceDstByMintFreeArgs<L, M>
+{
+    /// Returns (keys, lst_index, lst_calculator_program_id)
+    pub fn resolve(self) -> Result<(AdditionalRebalanceDstKeys, usize, Pubkey), SControllerError> {
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list(&lst_state_list_acc_data)?;
+
+        let (lst_index, lst_state) = try_find_lst_mint_on_list(*self.lst_mint.pubkey(), list)?;
+        let pool_reserves = create_pool_reserves_address(lst_state, *self.lst_mint.owner())?;
+
+        Ok((
+            AdditionalRebalanceDstKeys {
+                lst_mint: lst_state.mint,
+                pool_reserves,
+            },
+            lst_index,
+            lst_state.sol_value_calculator,
+        ))
+    }
+}
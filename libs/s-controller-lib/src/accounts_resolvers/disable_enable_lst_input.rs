@@ -1,5 +1,6 @@
 use s_controller_interface::{
     DisableLstInputKeys, EnableLstInputKeys, LstState, PoolState, SControllerError,
+    SetLstBatchCalcKeys,
 };
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
@@ -77,6 +78,17 @@ impl<
             lst_state_list: LST_STATE_LIST_ID,
         })
     }
+
+    /// SetLstBatchCalc takes the same accounts as DisableLstInput and EnableLstInput
+    pub fn resolve_set_batch_calc(&self) -> Result<SetLstBatchCalcKeys, SControllerError> {
+        let DisableEnableLstInputComputedKeys { admin, lst_mint } = self.compute_keys()?;
+        Ok(SetLstBatchCalcKeys {
+            admin,
+            lst_mint,
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+        })
+    }
 }
 
 /// Iterates through lst_state_list to find lst_index.
@@ -184,4 +196,39 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
             lst_index,
         ))
     }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_set_batch_calc(&self) -> Result<(SetLstBatchCalcKeys, usize), SControllerError> {
+        let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
+            self.compute_keys_and_index()?;
+
+        Ok((
+            SetLstBatchCalcKeys {
+                admin,
+                lst_mint,
+                pool_state: POOL_STATE_ID,
+                lst_state_list: LST_STATE_LIST_ID,
+            },
+            lst_index,
+        ))
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_set_batch_calc_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(SetLstBatchCalcKeys, usize), SControllerError> {
+        let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
+            self.compute_keys_and_index()?;
+
+        Ok((
+            SetLstBatchCalcKeys {
+                admin,
+                lst_mint,
+                pool_state: find_pool_state_address(program_id).0,
+                lst_state_list: find_lst_state_list_address(program_id).0,
+            },
+            lst_index,
+        ))
+    }
 }
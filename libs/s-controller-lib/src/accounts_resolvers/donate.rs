@@ -0,0 +1,153 @@
+use s_controller_interface::{DonateKeys, SControllerError};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address, create_pool_reserves_address_with_pool_state_id,
+    find_donor_allowlist_address, find_lst_state_list_address, find_pool_state_address,
+    program::{DONOR_ALLOWLIST_ID, LST_STATE_LIST_ID, POOL_STATE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct DonateFreeArgs<
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub lst_index: usize,
+    pub donor: Pubkey,
+    pub src_lst_acc: Pubkey,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+impl<
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > DonateFreeArgs<L, M>
+{
+    pub fn resolve(self) -> Result<DonateKeys, SControllerError> {
+        let Self {
+            lst_index,
+            donor,
+            src_lst_acc,
+            lst_state_list: lst_state_list_account,
+            lst_mint,
+        } = self;
+        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, lst_index)?;
+        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
+
+        Ok(DonateKeys {
+            donor,
+            lst_mint: *lst_mint.pubkey(),
+            src_lst_acc,
+            lst_token_program: *lst_mint.owner(),
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            pool_reserves,
+            donor_allowlist: DONOR_ALLOWLIST_ID,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find lst_index.
+/// Suitable for use on client-side.
+/// Does not check identity of lst_state_list
+#[derive(Clone, Copy, Debug)]
+pub struct DonateByMintFreeArgs<
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub donor: Pubkey,
+    pub src_lst_acc: Pubkey,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DonatePdas {
+    pub pool_state: Pubkey,
+    pub lst_state_list: Pubkey,
+    pub donor_allowlist: Pubkey,
+}
+
+impl DonatePdas {
+    pub fn find_for_program_id(program_id: Pubkey) -> Self {
+        Self {
+            pool_state: find_pool_state_address(program_id).0,
+            lst_state_list: find_lst_state_list_address(program_id).0,
+            donor_allowlist: find_donor_allowlist_address(program_id).0,
+        }
+    }
+}
+
+impl<L: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
+    DonateByMintFreeArgs<L, M>
+{
+    /// Does not check identity of lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list, sol value calculator program ID)
+    pub fn resolve(self) -> Result<(DonateKeys, usize, Pubkey), SControllerError> {
+        self.resolve_with_pdas(DonatePdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            donor_allowlist: DONOR_ALLOWLIST_ID,
+        })
+    }
+
+    /// Does not check identity of lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list, sol value calculator program ID)
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<(DonateKeys, usize, Pubkey), SControllerError> {
+        self.resolve_with_pdas(DonatePdas::find_for_program_id(program_id))
+    }
+
+    /// Does not check identity of lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list, sol value calculator program ID)
+    pub fn resolve_with_pdas(
+        self,
+        DonatePdas {
+            pool_state,
+            lst_state_list,
+            donor_allowlist,
+        }: DonatePdas,
+    ) -> Result<(DonateKeys, usize, Pubkey), SControllerError> {
+        let Self {
+            donor,
+            src_lst_acc,
+            lst_state_list: lst_state_list_account,
+            lst_mint,
+        } = self;
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let list = try_lst_state_list(&lst_state_list_acc_data)?;
+
+        let (lst_index, lst_state) = try_find_lst_mint_on_list(*lst_mint.pubkey(), list)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
+
+        Ok((
+            DonateKeys {
+                donor,
+                lst_mint: *lst_mint.pubkey(),
+                src_lst_acc,
+                lst_token_program: *lst_mint.owner(),
+                pool_state,
+                lst_state_list,
+                pool_reserves,
+                donor_allowlist,
+            },
+            lst_index,
+            lst_state.sol_value_calculator,
+        ))
+    }
+}
@@ -1,16 +1,21 @@
 mod add_disable_pool_authority;
+mod add_donor;
 mod add_liquidity;
 mod add_lst;
+mod additional_rebalance_dst;
 mod common;
 mod disable_enable_lst_input;
 mod disable_pool;
+mod donate;
 mod enable_pool;
 mod end_rebalance;
 mod initialize;
 mod remove_disable_pool_authority;
+mod remove_donor;
 mod remove_liquidity;
 mod remove_lst;
 mod set_admin;
+mod set_max_rebalance_loss;
 mod set_pricing_program;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
@@ -22,18 +27,23 @@ mod sync_sol_value;
 mod withdraw_protocol_fees;
 
 pub use add_disable_pool_authority::*;
+pub use add_donor::*;
 pub use add_liquidity::*;
 pub use add_lst::*;
+pub use additional_rebalance_dst::*;
 pub use common::*;
 pub use disable_enable_lst_input::*;
 pub use disable_pool::*;
+pub use donate::*;
 pub use enable_pool::*;
 pub use end_rebalance::*;
 pub use initialize::*;
 pub use remove_disable_pool_authority::*;
+pub use remove_donor::*;
 pub use remove_liquidity::*;
 pub use remove_lst::*;
 pub use set_admin::*;
+pub use set_max_rebalance_loss::*;
 pub use set_pricing_program::*;
 pub use set_protocol_fee::*;
 pub use set_protocol_fee_beneficiary::*;
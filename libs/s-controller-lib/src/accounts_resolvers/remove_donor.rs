@@ -0,0 +1,116 @@
+use s_controller_interface::{RemoveDonorIxArgs, RemoveDonorKeys, SControllerError};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    find_donor_allowlist_address, find_pool_state_address, index_to_u32,
+    program::{DONOR_ALLOWLIST_ID, POOL_STATE_ID},
+    try_donor_allowlist, try_find_element_in_list, try_pool_state,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveDonorFreeArgs<
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub index: usize,
+    pub refund_rent_to: Pubkey,
+    pub pool_state_acc: S,
+    pub donor_allowlist: L,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > RemoveDonorFreeArgs<S, L>
+{
+    pub fn resolve(&self) -> Result<RemoveDonorKeys, SControllerError> {
+        if *self.pool_state_acc.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *self.donor_allowlist.pubkey() != DONOR_ALLOWLIST_ID {
+            return Err(SControllerError::IncorrectDonorAllowlist);
+        }
+
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let donor_allowlist_data = self.donor_allowlist.data();
+        let list = try_donor_allowlist(&donor_allowlist_data)?;
+        let donor = list
+            .get(self.index)
+            .ok_or(SControllerError::InvalidDonorIndex)?;
+
+        Ok(RemoveDonorKeys {
+            refund_rent_to: self.refund_rent_to,
+            admin: pool_state.admin,
+            donor: *donor,
+            pool_state: POOL_STATE_ID,
+            donor_allowlist: DONOR_ALLOWLIST_ID,
+        })
+    }
+}
+
+/// Iterates through donor_allowlist to find the index.
+/// Does not check identity of pool_state_account and donor_allowlist
+/// Suitable for use client-side.
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveDonorByPubkeyFreeArgs<S, L> {
+    pub refund_rent_to: Pubkey,
+    pub donor: Pubkey,
+    pub pool_state_acc: S,
+    pub donor_allowlist: L,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RemoveDonorPdas {
+    pub pool_state: Pubkey,
+    pub donor_allowlist: Pubkey,
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> RemoveDonorByPubkeyFreeArgs<S, L> {
+    pub fn resolve(&self) -> Result<(RemoveDonorKeys, RemoveDonorIxArgs), SControllerError> {
+        self.resolve_with_pdas(RemoveDonorPdas {
+            pool_state: POOL_STATE_ID,
+            donor_allowlist: DONOR_ALLOWLIST_ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(RemoveDonorKeys, RemoveDonorIxArgs), SControllerError> {
+        self.resolve_with_pdas(RemoveDonorPdas {
+            pool_state: find_pool_state_address(program_id).0,
+            donor_allowlist: find_donor_allowlist_address(program_id).0,
+        })
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        RemoveDonorPdas {
+            pool_state,
+            donor_allowlist,
+        }: RemoveDonorPdas,
+    ) -> Result<(RemoveDonorKeys, RemoveDonorIxArgs), SControllerError> {
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state_acc = try_pool_state(&pool_state_data)?;
+
+        let donor_allowlist_data = self.donor_allowlist.data();
+        let list = try_donor_allowlist(&donor_allowlist_data)?;
+        let (index, _donor) = try_find_element_in_list(self.donor, list)
+            .ok_or(SControllerError::UnauthorizedDonor)?;
+        Ok((
+            RemoveDonorKeys {
+                refund_rent_to: self.refund_rent_to,
+                admin: pool_state_acc.admin,
+                donor: self.donor,
+                pool_state,
+                donor_allowlist,
+            },
+            RemoveDonorIxArgs {
+                index: index_to_u32(index)?,
+            },
+        ))
+    }
+}
@@ -0,0 +1,50 @@
+use s_controller_interface::{SControllerError, SetMaxRebalanceLossKeys};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    find_pool_state_address, find_rebalance_config_address,
+    program::{POOL_STATE_ID, REBALANCE_CONFIG_ID},
+    try_pool_state,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetMaxRebalanceLossFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub payer: Pubkey,
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> SetMaxRebalanceLossFreeArgs<S> {
+    pub fn resolve(&self) -> Result<SetMaxRebalanceLossKeys, SControllerError> {
+        if *self.pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(SetMaxRebalanceLossKeys {
+            admin: pool_state.admin,
+            payer: self.payer,
+            pool_state: POOL_STATE_ID,
+            rebalance_config: REBALANCE_CONFIG_ID,
+            system_program: system_program::ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<SetMaxRebalanceLossKeys, SControllerError> {
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(SetMaxRebalanceLossKeys {
+            admin: pool_state.admin,
+            payer: self.payer,
+            pool_state: find_pool_state_address(program_id).0,
+            rebalance_config: find_rebalance_config_address(program_id).0,
+            system_program: system_program::ID,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use s_controller_interface::{SControllerError, SetPricingProgramKeys};
+use s_controller_interface::{SControllerError, SetPricingProgramKeys, SetPricingProgramV2Keys};
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
@@ -17,6 +17,10 @@ impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> SetPricingProgramFreeArgs<S
         }
         self.resolve_with_pool_state_id(POOL_STATE_ID)
     }
+
+    pub fn resolve_v2(&self) -> Result<SetPricingProgramV2Keys, SControllerError> {
+        self.resolve().map(to_v2_keys)
+    }
 }
 
 impl<S: ReadonlyAccountData> SetPricingProgramFreeArgs<S> {
@@ -27,6 +31,13 @@ impl<S: ReadonlyAccountData> SetPricingProgramFreeArgs<S> {
         self.resolve_with_pool_state_id(find_pool_state_address(program_id).0)
     }
 
+    pub fn resolve_v2_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<SetPricingProgramV2Keys, SControllerError> {
+        self.resolve_for_prog(program_id).map(to_v2_keys)
+    }
+
     pub fn resolve_with_pool_state_id(
         &self,
         pool_state_id: Pubkey,
@@ -41,3 +52,18 @@ impl<S: ReadonlyAccountData> SetPricingProgramFreeArgs<S> {
         })
     }
 }
+
+/// SetPricingProgramV2 takes the same accounts as SetPricingProgram
+fn to_v2_keys(
+    SetPricingProgramKeys {
+        admin,
+        new_pricing_program,
+        pool_state,
+    }: SetPricingProgramKeys,
+) -> SetPricingProgramV2Keys {
+    SetPricingProgramV2Keys {
+        admin,
+        new_pricing_program,
+        pool_state,
+    }
+}
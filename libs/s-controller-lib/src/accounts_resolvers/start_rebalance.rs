@@ -1,11 +1,13 @@
-use s_controller_interface::{PoolState, SControllerError, StartRebalanceKeys};
+use s_controller_interface::{
+    PoolState, SControllerError, StartRebalanceKeys, StartRebalanceV2Keys,
+};
 use solana_program::{pubkey::Pubkey, system_program, sysvar};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
     create_pool_reserves_address, find_lst_state_list_address, find_pool_state_address,
-    find_rebalance_record_address,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID},
+    find_rebalance_config_address, find_rebalance_record_address,
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_CONFIG_ID, REBALANCE_RECORD_ID},
     try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, try_pool_state,
     SrcDstLstIndexes, SrcDstLstSolValueCalcProgramIds,
 };
@@ -208,3 +210,50 @@ impl<
         ))
     }
 }
+
+/// Extends resolved StartRebalance keys with the rebalance config PDA
+/// to form the keys of StartRebalanceV2
+#[derive(Clone, Copy, Debug)]
+pub struct StartRebalanceV2FromStartRebalanceKeys<'a>(pub &'a StartRebalanceKeys);
+
+impl StartRebalanceV2FromStartRebalanceKeys<'_> {
+    pub fn resolve(&self) -> StartRebalanceV2Keys {
+        self.resolve_with_rebalance_config(REBALANCE_CONFIG_ID)
+    }
+
+    pub fn resolve_for_prog(&self, program_id: Pubkey) -> StartRebalanceV2Keys {
+        self.resolve_with_rebalance_config(find_rebalance_config_address(program_id).0)
+    }
+
+    pub fn resolve_with_rebalance_config(&self, rebalance_config: Pubkey) -> StartRebalanceV2Keys {
+        let StartRebalanceKeys {
+            rebalance_authority,
+            pool_state,
+            lst_state_list,
+            rebalance_record,
+            src_lst_mint,
+            dst_lst_mint,
+            src_pool_reserves,
+            dst_pool_reserves,
+            withdraw_to,
+            instructions,
+            system_program,
+            src_lst_token_program,
+        } = *self.0;
+        StartRebalanceV2Keys {
+            rebalance_authority,
+            pool_state,
+            lst_state_list,
+            rebalance_record,
+            src_lst_mint,
+            dst_lst_mint,
+            src_pool_reserves,
+            dst_pool_reserves,
+            withdraw_to,
+            instructions,
+            system_program,
+            src_lst_token_program,
+            rebalance_config,
+        }
+    }
+}
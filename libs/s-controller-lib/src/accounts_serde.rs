@@ -1,5 +1,7 @@
 use bytemuck::{try_from_bytes, try_from_bytes_mut, AnyBitPattern};
-use s_controller_interface::{LstState, PoolState, RebalanceRecord, SControllerError};
+use s_controller_interface::{
+    LstState, PoolState, RebalanceConfig, RebalanceRecord, SControllerError,
+};
 use solana_program::pubkey::Pubkey;
 
 /// Tries to reinterpret `pool_state_acc_data` bytes as a PoolState
@@ -40,6 +42,16 @@ pub fn try_disable_pool_authority_list_mut(
         .ok_or(SControllerError::InvalidDisablePoolAuthorityListData)
 }
 
+pub fn try_donor_allowlist(donor_allowlist_acc_data: &[u8]) -> Result<&[Pubkey], SControllerError> {
+    try_list(donor_allowlist_acc_data).ok_or(SControllerError::InvalidDonorAllowlistData)
+}
+
+pub fn try_donor_allowlist_mut(
+    donor_allowlist_acc_data: &mut [u8],
+) -> Result<&mut [Pubkey], SControllerError> {
+    try_list_mut(donor_allowlist_acc_data).ok_or(SControllerError::InvalidDonorAllowlistData)
+}
+
 pub fn try_rebalance_record(
     rebalance_record_acc_data: &[u8],
 ) -> Result<&RebalanceRecord, SControllerError> {
@@ -54,6 +66,33 @@ pub fn try_rebalance_record_mut(
         .map_err(|_e| SControllerError::InvalidRebalanceRecordData)
 }
 
+pub fn try_rebalance_config(
+    rebalance_config_acc_data: &[u8],
+) -> Result<&RebalanceConfig, SControllerError> {
+    try_from_bytes(rebalance_config_acc_data)
+        .map_err(|_e| SControllerError::InvalidRebalanceConfigData)
+}
+
+pub fn try_rebalance_config_mut(
+    rebalance_config_acc_data: &mut [u8],
+) -> Result<&mut RebalanceConfig, SControllerError> {
+    try_from_bytes_mut(rebalance_config_acc_data)
+        .map_err(|_e| SControllerError::InvalidRebalanceConfigData)
+}
+
+/// Reads the max_rebalance_loss of the rebalance config PDA.
+///
+/// The PDA is only created by the first SetMaxRebalanceLoss,
+/// before which no rebalance loss is tolerated
+pub fn rebalance_config_max_rebalance_loss(
+    rebalance_config_acc_data: &[u8],
+) -> Result<u64, SControllerError> {
+    if rebalance_config_acc_data.is_empty() {
+        return Ok(0);
+    }
+    try_rebalance_config(rebalance_config_acc_data).map(|c| c.max_rebalance_loss)
+}
+
 /// Tries to reinterpret `list_acc_data` bytes as a slice.
 ///
 /// `list_acc_data` should only contain data of the items, no headers etc
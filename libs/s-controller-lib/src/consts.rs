@@ -17,3 +17,8 @@ pub const DEFAULT_TRADING_PROTOCOL_FEE_BPS: u16 = 1_000;
 pub const DEFAULT_LP_PROTOCOL_FEE_BPS: u16 = 1_000;
 
 pub const DEFAULT_PRICING_PROGRAM: Pubkey = flat_fee_lib::program::ID;
+
+/// Max number of destination LSTs a single rebalance can have
+/// in addition to its primary destination LST.
+/// Must match the length of `RebalanceRecord.additional_dst_lst_indexes`
+pub const MAX_ADDITIONAL_REBALANCE_DSTS: usize = 3;
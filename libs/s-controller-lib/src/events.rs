@@ -0,0 +1,22 @@
+//! Events logged by the program with `sol_log_data()`.
+//!
+//! Each event is logged as 2 data slices: the event's tag followed by its borsh-serialized data.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+pub const DONATE_EVENT_TAG: &[u8] = b"donate";
+
+/// Logged by Donate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct DonateEvent {
+    pub donor: Pubkey,
+    pub lst_mint: Pubkey,
+
+    /// Amount of LST received by the pool's reserves,
+    /// after any Token-2022 transfer fee
+    pub amount: u64,
+
+    /// Increase in the pool's `total_sol_value` due to the donation
+    pub sol_value: u64,
+}
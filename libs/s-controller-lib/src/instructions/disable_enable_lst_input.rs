@@ -1,5 +1,6 @@
 use s_controller_interface::{
-    disable_lst_input_ix, enable_lst_input_ix, DisableLstInputIxArgs, EnableLstInputIxArgs,
+    disable_lst_input_ix, enable_lst_input_ix, set_lst_batch_calc_ix, DisableLstInputIxArgs,
+    EnableLstInputIxArgs, SetLstBatchCalcIxArgs,
 };
 use solana_program::{instruction::Instruction, program_error::ProgramError};
 use solana_readonly_account::ReadonlyAccountData;
@@ -23,3 +24,19 @@ pub fn enable_lst_input_ix_by_mint_full<S: ReadonlyAccountData, L: ReadonlyAccou
     let ix = enable_lst_input_ix(keys, EnableLstInputIxArgs { index })?;
     Ok(ix)
 }
+
+pub fn set_lst_batch_calc_ix_by_mint_full<S: ReadonlyAccountData, L: ReadonlyAccountData>(
+    free_args: &DisableEnableLstInputByMintFreeArgs<S, L>,
+    is_batch_calc_enabled: bool,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve_set_batch_calc()?;
+    let index = index_to_u32(lst_index)?;
+    let ix = set_lst_batch_calc_ix(
+        keys,
+        SetLstBatchCalcIxArgs {
+            index,
+            is_batch_calc_enabled: is_batch_calc_enabled.into(),
+        },
+    )?;
+    Ok(ix)
+}
@@ -0,0 +1,105 @@
+use s_controller_interface::{
+    donate_ix_with_program_id, DonateIxArgs, DonateIxData, DonateKeys, SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{index_to_u32, ix_extend_with_sol_value_calculator_accounts, DonateByMintFreeArgs};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DonateIxFullArgs {
+    pub lst_index: usize,
+    pub amount: u64,
+}
+
+pub fn donate_ix_full(
+    accounts: DonateKeys,
+    args: DonateIxFullArgs,
+    sol_value_calculator_accounts: &[AccountMeta],
+    sol_value_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    donate_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        args,
+        sol_value_calculator_accounts,
+        sol_value_calculator_program_id,
+    )
+}
+
+pub fn donate_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: DonateKeys,
+    DonateIxFullArgs { lst_index, amount }: DonateIxFullArgs,
+    sol_value_calculator_accounts: &[AccountMeta],
+    sol_value_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let lst_index = index_to_u32(lst_index)?;
+    let mut ix = donate_ix_with_program_id(
+        program_id,
+        accounts,
+        DonateIxArgs {
+            lst_value_calc_accs: 0,
+            lst_index,
+            amount,
+        },
+    )?;
+    let lst_value_calc_accs = ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        sol_value_calculator_accounts,
+        sol_value_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    // TODO: better way to update lst_value_calc_accs than double serialization here
+    let mut overwrite = &mut ix.data[..];
+    DonateIxData(DonateIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        amount,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn donate_ix_by_mint_full<
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: DonateByMintFreeArgs<L, M>,
+    amount: u64,
+    sol_value_calculator_accounts: &[AccountMeta],
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, sol_value_calculator_program_id) = free_args.resolve()?;
+    let ix = donate_ix_full(
+        keys,
+        DonateIxFullArgs { lst_index, amount },
+        sol_value_calculator_accounts,
+        sol_value_calculator_program_id,
+    )?;
+    Ok(ix)
+}
+
+pub fn donate_ix_by_mint_full_for_prog<
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: DonateByMintFreeArgs<L, M>,
+    amount: u64,
+    sol_value_calculator_accounts: &[AccountMeta],
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, sol_value_calculator_program_id) =
+        free_args.resolve_for_prog(program_id)?;
+    let ix = donate_ix_full_for_prog(
+        program_id,
+        keys,
+        DonateIxFullArgs { lst_index, amount },
+        sol_value_calculator_accounts,
+        sol_value_calculator_program_id,
+    )?;
+    Ok(ix)
+}
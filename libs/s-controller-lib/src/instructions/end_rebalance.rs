@@ -1,6 +1,8 @@
 use s_controller_interface::{
-    end_rebalance_ix_with_program_id, EndRebalanceKeys, SControllerError, SControllerProgramIx,
-    StartRebalanceIxArgs, StartRebalanceKeys, START_REBALANCE_IX_ACCOUNTS_LEN,
+    end_rebalance_ix_with_program_id, end_rebalance_v2_ix_with_program_id, EndRebalanceKeys,
+    EndRebalanceV2IxArgs, EndRebalanceV2IxData, EndRebalanceV2Keys, SControllerError,
+    SControllerProgramIx, StartRebalanceIxArgs, StartRebalanceKeys, StartRebalanceV2IxArgs,
+    START_REBALANCE_IX_ACCOUNTS_LEN, START_REBALANCE_V2_IX_ACCOUNTS_LEN,
 };
 use solana_program::{
     instruction::{AccountMeta, Instruction},
@@ -10,7 +12,10 @@ use solana_program::{
 
 use crate::EndRebalanceFromStartRebalanceKeys;
 
-use super::utils::ix_extend_with_sol_value_calculator_accounts;
+use super::{
+    account_metas_extend_with_additional_rebalance_dsts,
+    utils::ix_extend_with_sol_value_calculator_accounts, AdditionalRebalanceDstFullArgs,
+};
 
 pub fn end_rebalance_ix_full(
     accounts: EndRebalanceKeys,
@@ -41,22 +46,94 @@ pub fn end_rebalance_ix_full_for_prog(
     Ok(ix)
 }
 
+/// EndRebalanceV2 for a rebalance started by StartRebalanceV2 with `additional_dsts`.
+///
+/// `additional_dsts` must be the same, in the same order, as those passed to StartRebalanceV2
+pub fn end_rebalance_v2_ix_full(
+    accounts: EndRebalanceKeys,
+    dst_lst_calculator_accounts: &[AccountMeta],
+    dst_lst_calculator_program_id: Pubkey,
+    additional_dsts: &[AdditionalRebalanceDstFullArgs],
+) -> Result<Instruction, ProgramError> {
+    end_rebalance_v2_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        dst_lst_calculator_accounts,
+        dst_lst_calculator_program_id,
+        additional_dsts,
+    )
+}
+
+pub fn end_rebalance_v2_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: EndRebalanceKeys,
+    dst_lst_calculator_accounts: &[AccountMeta],
+    dst_lst_calculator_program_id: Pubkey,
+    additional_dsts: &[AdditionalRebalanceDstFullArgs],
+) -> Result<Instruction, ProgramError> {
+    let mut args = EndRebalanceV2IxArgs {
+        dst_lst_calc_accs: 0,
+        additional_dst_lst_calc_accs: Vec::new(),
+    };
+    let mut ix = end_rebalance_v2_ix_with_program_id(
+        program_id,
+        end_rebalance_v2_keys(accounts),
+        args.clone(),
+    )?;
+    args.dst_lst_calc_accs = ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        dst_lst_calculator_accounts,
+        dst_lst_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    args.additional_dst_lst_calc_accs =
+        account_metas_extend_with_additional_rebalance_dsts(&mut ix.accounts, additional_dsts)?
+            .iter()
+            .map(|dst| dst.lst_calc_accs)
+            .collect();
+    // Cant overwrite in-place since additional_dst_lst_calc_accs changes the data's length
+    ix.data = EndRebalanceV2IxData(args).try_to_vec()?;
+    Ok(ix)
+}
+
+/// Creates the EndRebalance instruction that completes the rebalance started by `start_rebalance_ix`.
+///
+/// Returns EndRebalance for StartRebalance and EndRebalanceV2,
+/// including any additional destination LSTs it fans out to, for StartRebalanceV2.
 pub fn end_rebalance_ix_from_start_rebalance_ix(
     start_rebalance_ix: &Instruction,
 ) -> Result<Instruction, ProgramError> {
     let ix_data = SControllerProgramIx::deserialize(&start_rebalance_ix.data)?;
-    let StartRebalanceIxArgs {
-        src_lst_calc_accs, ..
-    } = match ix_data {
-        SControllerProgramIx::StartRebalance(args) => args,
+    // (accounts len, src_lst_calc_accs, EndRebalanceV2 args if StartRebalanceV2)
+    let (accounts_len, src_lst_calc_accs, v2_args) = match ix_data {
+        SControllerProgramIx::StartRebalance(StartRebalanceIxArgs {
+            src_lst_calc_accs, ..
+        }) => (START_REBALANCE_IX_ACCOUNTS_LEN, src_lst_calc_accs, None),
+        SControllerProgramIx::StartRebalanceV2(StartRebalanceV2IxArgs {
+            src_lst_calc_accs,
+            dst_lst_calc_accs,
+            additional_dsts,
+            ..
+        }) => (
+            START_REBALANCE_V2_IX_ACCOUNTS_LEN,
+            src_lst_calc_accs,
+            Some(EndRebalanceV2IxArgs {
+                dst_lst_calc_accs,
+                additional_dst_lst_calc_accs: additional_dsts
+                    .iter()
+                    .map(|dst| dst.lst_calc_accs)
+                    .collect(),
+            }),
+        ),
         _ => return Err(ProgramError::InvalidInstructionData),
     };
-    let dst_lst_suffix_start = START_REBALANCE_IX_ACCOUNTS_LEN + usize::from(src_lst_calc_accs);
+    let dst_lst_suffix_start = accounts_len + usize::from(src_lst_calc_accs);
     let dst_lst_suffix = start_rebalance_ix
         .accounts
         .get(dst_lst_suffix_start..)
         .ok_or(ProgramError::NotEnoughAccountKeys)?;
 
+    // StartRebalanceV2's accounts are StartRebalance's with rebalance_config appended
     let start_rebalance_metas = start_rebalance_ix
         .accounts
         .get(..START_REBALANCE_IX_ACCOUNTS_LEN)
@@ -70,8 +147,38 @@ pub fn end_rebalance_ix_from_start_rebalance_ix(
         EndRebalanceFromStartRebalanceKeys(&StartRebalanceKeys::from(start_rebalance_keys))
             .resolve();
 
-    let mut ix =
-        end_rebalance_ix_with_program_id(start_rebalance_ix.program_id, end_rebalance_keys)?;
+    let mut ix = match v2_args {
+        None => {
+            end_rebalance_ix_with_program_id(start_rebalance_ix.program_id, end_rebalance_keys)?
+        }
+        Some(args) => end_rebalance_v2_ix_with_program_id(
+            start_rebalance_ix.program_id,
+            end_rebalance_v2_keys(end_rebalance_keys),
+            args,
+        )?,
+    };
+    // For StartRebalanceV2, dst_lst_suffix contains both the primary destination LST's
+    // and all additional destination LSTs' accounts
     ix.accounts.extend(dst_lst_suffix.iter().cloned());
     Ok(ix)
 }
+
+fn end_rebalance_v2_keys(
+    EndRebalanceKeys {
+        rebalance_authority,
+        pool_state,
+        lst_state_list,
+        rebalance_record,
+        dst_lst_mint,
+        dst_pool_reserves,
+    }: EndRebalanceKeys,
+) -> EndRebalanceV2Keys {
+    EndRebalanceV2Keys {
+        rebalance_authority,
+        pool_state,
+        lst_state_list,
+        rebalance_record,
+        dst_lst_mint,
+        dst_pool_reserves,
+    }
+}
@@ -3,7 +3,9 @@
 
 mod add_liquidity;
 mod disable_enable_lst_input;
+mod donate;
 mod end_rebalance;
+mod native_sol;
 mod remove_liquidity;
 mod set_sol_value_calculator;
 mod start_rebalance;
@@ -14,7 +16,9 @@ mod utils;
 
 pub use add_liquidity::*;
 pub use disable_enable_lst_input::*;
+pub use donate::*;
 pub use end_rebalance::*;
+pub use native_sol::*;
 pub use remove_liquidity::*;
 pub use set_sol_value_calculator::*;
 pub use start_rebalance::*;
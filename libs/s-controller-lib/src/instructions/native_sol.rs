@@ -0,0 +1,98 @@
+use solana_program::{
+    instruction::Instruction, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+    rent::Rent, system_instruction,
+};
+use spl_token::{
+    instruction::{close_account, initialize_account3},
+    native_mint,
+};
+
+/// Seed of [`native_sol_wsol_acc`]
+pub const NATIVE_SOL_WSOL_ACC_SEED: &str = "s-native-sol";
+
+/// The temporary wSOL account of `owner` that native SOL is wrapped into and unwrapped from
+/// by the native SOL instruction helpers.
+///
+/// It is derived from `owner` with [`NATIVE_SOL_WSOL_ACC_SEED`], and created and closed
+/// in the same transaction, so any wSOL `owner` holds in other token accounts,
+/// such as its wSOL ATA, is left untouched.
+///
+/// Use this as the `src_lst_acc` or `dst_lst_acc` of the wrapped instruction.
+pub fn native_sol_wsol_acc(owner: &Pubkey) -> Result<Pubkey, ProgramError> {
+    Ok(Pubkey::create_with_seed(
+        owner,
+        NATIVE_SOL_WSOL_ACC_SEED,
+        &spl_token::ID,
+    )?)
+}
+
+/// Instructions that create `owner`'s temporary wSOL account
+/// and wrap `lamports` of native SOL from `owner`'s system account into it.
+///
+/// Fails if the temporary wSOL account already exists.
+pub fn wrap_native_sol_ixs(
+    owner: &Pubkey,
+    lamports: u64,
+) -> Result<[Instruction; 2], ProgramError> {
+    let wsol_acc = native_sol_wsol_acc(owner)?;
+    let rent_exempt_lamports = Rent::default().minimum_balance(spl_token::state::Account::LEN);
+    let total_lamports = rent_exempt_lamports
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    Ok([
+        system_instruction::create_account_with_seed(
+            owner,
+            &wsol_acc,
+            owner,
+            NATIVE_SOL_WSOL_ACC_SEED,
+            total_lamports,
+            spl_token::state::Account::LEN as u64,
+            &spl_token::ID,
+        ),
+        initialize_account3(&spl_token::ID, &wsol_acc, &native_mint::ID, owner)?,
+    ])
+}
+
+/// Instruction that closes `owner`'s temporary wSOL account, unwrapping its entire balance
+/// and rent back into `owner`'s system account
+pub fn unwrap_native_sol_ix(owner: &Pubkey) -> Result<Instruction, ProgramError> {
+    close_account(
+        &spl_token::ID,
+        &native_sol_wsol_acc(owner)?,
+        owner,
+        owner,
+        &[],
+    )
+}
+
+/// Surrounds `ix`, a swap or add liquidity instruction whose input wSOL account is
+/// [`native_sol_wsol_acc`] of `signer`, with instructions to:
+/// - wrap `lamports` of native SOL from `signer` into the temporary wSOL account before it
+/// - unwrap any unused amount and close the temporary wSOL account after it
+///
+/// `lamports` should be the exact input amount for ExactIn and AddLiquidity
+/// and the max input amount for ExactOut.
+pub fn native_sol_in_ixs(
+    signer: &Pubkey,
+    lamports: u64,
+    ix: Instruction,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let mut res = Vec::from(wrap_native_sol_ixs(signer, lamports)?);
+    res.push(ix);
+    res.push(unwrap_native_sol_ix(signer)?);
+    Ok(res)
+}
+
+/// Surrounds `ix`, a swap or remove liquidity instruction whose output wSOL account is
+/// [`native_sol_wsol_acc`] of `signer`, with instructions to:
+/// - create the empty temporary wSOL account before it
+/// - unwrap the output into `signer`'s system account and close the temporary wSOL account after it
+pub fn native_sol_out_ixs(
+    signer: &Pubkey,
+    ix: Instruction,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let mut res = Vec::from(wrap_native_sol_ixs(signer, 0)?);
+    res.push(ix);
+    res.push(unwrap_native_sol_ix(signer)?);
+    Ok(res)
+}
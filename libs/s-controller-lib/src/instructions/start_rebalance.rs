@@ -1,16 +1,26 @@
 use s_controller_interface::{
-    start_rebalance_ix_with_program_id, SControllerError, StartRebalanceIxArgs,
-    StartRebalanceIxData, StartRebalanceKeys,
+    start_rebalance_ix_with_program_id, start_rebalance_v2_ix_with_program_id,
+    AdditionalRebalanceDst, SControllerError, StartRebalanceIxArgs, StartRebalanceIxData,
+    StartRebalanceKeys, StartRebalanceV2IxArgs, StartRebalanceV2IxData, StartRebalanceV2Keys,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
 };
-use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    index_to_u32, SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes,
-    StartRebalanceByMintsFreeArgs,
+    index_to_u32, AdditionalRebalanceDstKeys, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcAccountSuffixes, StartRebalanceByMintsFreeArgs,
+    StartRebalanceV2FromStartRebalanceKeys, ADDITIONAL_REBALANCE_DST_ACCOUNTS_LEN,
+    MAX_ADDITIONAL_REBALANCE_DSTS,
 };
 
-use super::{ix_extend_with_src_dst_sol_value_calculator_accounts, SrcDstLstSolValueCalcAccounts};
+use super::{
+    account_metas_extend_with_sol_value_calculator_accounts,
+    ix_extend_with_src_dst_sol_value_calculator_accounts, SrcDstLstSolValueCalcAccounts,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct StartRebalanceIxLstAmts {
@@ -139,3 +149,186 @@ pub fn start_rebalance_ix_by_mints_full_for_prog<
         SrcDstLstSolValueCalcAccounts::new(program_ids, sol_val_calc_account_suffixes),
     )
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StartRebalanceV2IxFullArgs {
+    pub src_lst_index: usize,
+    pub dst_lst_index: usize,
+    pub lst_amts: StartRebalanceIxLstAmts,
+    /// Minimum change in the pool's total SOL value that EndRebalanceV2 must observe.
+    /// Set to a negative value to tolerate a loss of up to that many lamports.
+    pub min_sol_value_delta: i64,
+}
+
+/// An additional destination LST of a multi-destination rebalance.
+///
+/// lst_calculator_accounts should include the common interface account prefixes
+/// but exclude the program ID
+#[derive(Clone, Copy, Debug)]
+pub struct AdditionalRebalanceDstFullArgs<'me> {
+    pub lst_index: usize,
+    pub keys: AdditionalRebalanceDstKeys,
+    pub lst_calculator_program_id: Pubkey,
+    pub lst_calculator_accounts: &'me [AccountMeta],
+}
+
+/// StartRebalanceV2 that fans out to `additional_dsts` in addition to
+/// the primary destination LST in `accounts`
+pub fn start_rebalance_v2_ix_full(
+    accounts: StartRebalanceV2Keys,
+    args: StartRebalanceV2IxFullArgs,
+    sol_val_calc_keys: SrcDstLstSolValueCalcAccounts,
+    additional_dsts: &[AdditionalRebalanceDstFullArgs],
+) -> Result<Instruction, ProgramError> {
+    start_rebalance_v2_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        args,
+        sol_val_calc_keys,
+        additional_dsts,
+    )
+}
+
+pub fn start_rebalance_v2_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: StartRebalanceV2Keys,
+    StartRebalanceV2IxFullArgs {
+        src_lst_index,
+        dst_lst_index,
+        lst_amts:
+            StartRebalanceIxLstAmts {
+                amount,
+                min_starting_src_lst,
+                max_starting_dst_lst,
+            },
+        min_sol_value_delta,
+    }: StartRebalanceV2IxFullArgs,
+    sol_val_calc_keys: SrcDstLstSolValueCalcAccounts,
+    additional_dsts: &[AdditionalRebalanceDstFullArgs],
+) -> Result<Instruction, ProgramError> {
+    if additional_dsts.len() > MAX_ADDITIONAL_REBALANCE_DSTS {
+        return Err(SControllerError::TooManyRebalanceDsts.into());
+    }
+    let mut args = StartRebalanceV2IxArgs {
+        src_lst_calc_accs: 0,
+        dst_lst_calc_accs: 0,
+        src_lst_index: index_to_u32(src_lst_index)?,
+        dst_lst_index: index_to_u32(dst_lst_index)?,
+        amount,
+        min_starting_src_lst,
+        max_starting_dst_lst,
+        min_sol_value_delta,
+        additional_dsts: Vec::new(),
+    };
+    let mut ix = start_rebalance_v2_ix_with_program_id(program_id, accounts, args.clone())?;
+    let extend_count =
+        ix_extend_with_src_dst_sol_value_calculator_accounts(&mut ix, sol_val_calc_keys)
+            .map_err(|_e| SControllerError::MathError)?;
+    args.src_lst_calc_accs = extend_count.src_lst;
+    args.dst_lst_calc_accs = extend_count.dst_lst;
+    args.additional_dsts =
+        account_metas_extend_with_additional_rebalance_dsts(&mut ix.accounts, additional_dsts)?;
+    // Cant overwrite in-place since additional_dsts changes the data's length
+    ix.data = StartRebalanceV2IxData(args).try_to_vec()?;
+    Ok(ix)
+}
+
+/// Appends each additional destination LST's accounts followed by
+/// its SOL value calculator accounts.
+///
+/// Returns the `additional_dsts` StartRebalanceV2 instruction arg
+pub fn account_metas_extend_with_additional_rebalance_dsts(
+    accounts: &mut Vec<AccountMeta>,
+    additional_dsts: &[AdditionalRebalanceDstFullArgs],
+) -> Result<Vec<AdditionalRebalanceDst>, SControllerError> {
+    additional_dsts
+        .iter()
+        .map(
+            |AdditionalRebalanceDstFullArgs {
+                 lst_index,
+                 keys,
+                 lst_calculator_program_id,
+                 lst_calculator_accounts,
+             }| {
+                let metas: [AccountMeta; ADDITIONAL_REBALANCE_DST_ACCOUNTS_LEN] = (*keys).into();
+                accounts.extend(metas);
+                let lst_calc_accs = account_metas_extend_with_sol_value_calculator_accounts(
+                    accounts,
+                    lst_calculator_accounts,
+                    *lst_calculator_program_id,
+                )
+                .map_err(|_e| SControllerError::MathError)?;
+                Ok(AdditionalRebalanceDst {
+                    lst_index: index_to_u32(*lst_index)?,
+                    lst_calc_accs,
+                })
+            },
+        )
+        .collect()
+}
+
+pub fn start_rebalance_v2_ix_by_mints_full<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+>(
+    free_args: StartRebalanceByMintsFreeArgs<SM, DM, S, L>,
+    lst_amts: StartRebalanceIxLstAmts,
+    min_sol_value_delta: i64,
+    sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (
+        start_rebalance_keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        program_ids,
+    ) = free_args.resolve()?;
+    start_rebalance_v2_ix_full(
+        StartRebalanceV2FromStartRebalanceKeys(&start_rebalance_keys).resolve(),
+        StartRebalanceV2IxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            lst_amts,
+            min_sol_value_delta,
+        },
+        SrcDstLstSolValueCalcAccounts::new(program_ids, sol_val_calc_account_suffixes),
+        &[],
+    )
+}
+
+pub fn start_rebalance_v2_ix_by_mints_full_for_prog<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: StartRebalanceByMintsFreeArgs<SM, DM, S, L>,
+    lst_amts: StartRebalanceIxLstAmts,
+    min_sol_value_delta: i64,
+    sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (
+        start_rebalance_keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        program_ids,
+    ) = free_args.resolve_for_prog(program_id)?;
+    start_rebalance_v2_ix_full_for_prog(
+        program_id,
+        StartRebalanceV2FromStartRebalanceKeys(&start_rebalance_keys).resolve_for_prog(program_id),
+        StartRebalanceV2IxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            lst_amts,
+            min_sol_value_delta,
+        },
+        SrcDstLstSolValueCalcAccounts::new(program_ids, sol_val_calc_account_suffixes),
+        &[],
+    )
+}
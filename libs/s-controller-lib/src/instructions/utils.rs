@@ -1,5 +1,8 @@
 use std::num::TryFromIntError;
 
+use pricing_programs_interface::{
+    PRICE_EXACT_IN_IX_ACCOUNTS_LEN, PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN,
+};
 use s_controller_interface::SControllerError;
 use solana_program::{
     instruction::{AccountMeta, Instruction},
@@ -165,8 +168,16 @@ pub fn account_metas_extend_with_src_dst_sol_value_calculator_accounts(
     Ok(SrcDstLstSolValueCalcExtendCount { src_lst, dst_lst })
 }
 
-// actually the same as ix_extend_with_sol_value_calculator_accounts
-// since this interface also takes a single lst_mint prefix account
+/// Number of common interface accounts the S controller prepends to
+/// PriceLpTokensToMint and PriceLpTokensToRedeem CPIs.
+/// Same for both instructions.
+pub const PRICING_PROGRAM_PRICE_LP_PREFIX_LEN: usize = PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN;
+
+/// Number of common interface accounts the S controller prepends to
+/// PriceExactIn and PriceExactOut CPIs.
+/// Same for both instructions.
+pub const PRICING_PROGRAM_PRICE_SWAP_PREFIX_LEN: usize = PRICE_EXACT_IN_IX_ACCOUNTS_LEN;
+
 /// pricing_program_price_lp_accounts should include common interface account prefixes
 /// but exclude pricing_program_id
 /// Returns number of accounts added to the instruction's accounts array
@@ -174,7 +185,7 @@ pub fn ix_extend_with_pricing_program_price_lp_accounts(
     ix: &mut Instruction,
     pricing_program_price_lp_accounts: &[AccountMeta],
     pricing_program_id: Pubkey,
-) -> Result<u8, TryFromIntError> {
+) -> Result<u8, SControllerError> {
     account_metas_extend_with_pricing_program_price_lp_accounts(
         &mut ix.accounts,
         pricing_program_price_lp_accounts,
@@ -186,16 +197,25 @@ pub fn account_metas_extend_with_pricing_program_price_lp_accounts(
     accounts: &mut Vec<AccountMeta>,
     pricing_program_price_lp_accounts: &[AccountMeta],
     pricing_program_id: Pubkey,
-) -> Result<u8, TryFromIntError> {
+) -> Result<u8, SControllerError> {
     accounts.push(AccountMeta {
         pubkey: pricing_program_id,
         is_signer: false,
         is_writable: false,
     });
-    // exclude first account since that should be LST mint
-    accounts.extend(pricing_program_price_lp_accounts.iter().skip(1).cloned());
-    // n_accounts = len() - 1 + 1
-    pricing_program_price_lp_accounts.len().try_into()
+    // exclude common interface account prefixes since the S controller passes those itself
+    accounts.extend(
+        pricing_program_price_lp_accounts
+            .iter()
+            .skip(PRICING_PROGRAM_PRICE_LP_PREFIX_LEN)
+            .cloned(),
+    );
+    // n_accounts = len() - PRICING_PROGRAM_PRICE_LP_PREFIX_LEN + 1
+    pricing_program_price_lp_accounts
+        .len()
+        .checked_sub(PRICING_PROGRAM_PRICE_LP_PREFIX_LEN - 1)
+        .and_then(|len| len.try_into().ok())
+        .ok_or(SControllerError::MathError)
 }
 
 /// pricing_program_price_swap_accounts should include common interface account prefixes
@@ -223,12 +243,17 @@ pub fn account_metas_extend_with_pricing_program_price_swap_accounts(
         is_signer: false,
         is_writable: false,
     });
-    // exclude first 2 accounts since that should be input_lst_mint and output_lst_mint
-    accounts.extend(pricing_program_price_swap_accounts.iter().skip(2).cloned());
-    // n_accounts = len() - 2 + 1
+    // exclude common interface account prefixes since the S controller passes those itself
+    accounts.extend(
+        pricing_program_price_swap_accounts
+            .iter()
+            .skip(PRICING_PROGRAM_PRICE_SWAP_PREFIX_LEN)
+            .cloned(),
+    );
+    // n_accounts = len() - PRICING_PROGRAM_PRICE_SWAP_PREFIX_LEN + 1
     pricing_program_price_swap_accounts
         .len()
-        .checked_sub(1)
+        .checked_sub(PRICING_PROGRAM_PRICE_SWAP_PREFIX_LEN - 1)
         .and_then(|len| len.try_into().ok())
         .ok_or(SControllerError::MathError)
 }
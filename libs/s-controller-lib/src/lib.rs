@@ -1,5 +1,5 @@
 use s_controller_interface::{
-    LstState, PoolState, RebalanceRecord, SWAP_EXACT_IN_IX_ACCOUNTS_LEN,
+    LstState, PoolState, RebalanceConfig, RebalanceRecord, SWAP_EXACT_IN_IX_ACCOUNTS_LEN,
     SWAP_EXACT_OUT_IX_ACCOUNTS_LEN,
 };
 use static_assertions::const_assert_eq;
@@ -9,10 +9,12 @@ mod accounts_serde;
 mod calc;
 mod consts;
 mod disable_pool_authority_list;
+mod events;
 mod instructions;
 mod lst_indexes;
 mod lst_state_list;
 mod pda;
+mod pricing_program_keys;
 mod state;
 mod u8bool;
 
@@ -21,10 +23,12 @@ pub use accounts_serde::*;
 pub use calc::*;
 pub use consts::*;
 pub use disable_pool_authority_list::*;
+pub use events::*;
 pub use instructions::*;
 pub use lst_indexes::*;
 pub use lst_state_list::*;
 pub use pda::*;
+pub use pricing_program_keys::*;
 pub use state::*;
 pub use u8bool::*;
 
@@ -46,7 +50,7 @@ const_assert_eq!(
     SWAP_EXACT_OUT_IX_ACCOUNTS_LEN
 );
 
-pub const REBALANCE_RECORD_SIZE: usize = 16;
+pub const REBALANCE_RECORD_SIZE: usize = 40;
 const_assert_eq!(
     std::mem::size_of::<RebalanceRecord>(),
     REBALANCE_RECORD_SIZE
@@ -57,6 +61,17 @@ const_assert_eq!(
     REBALANCE_RECORD_ALIGN
 );
 
+pub const REBALANCE_CONFIG_SIZE: usize = 8;
+const_assert_eq!(
+    std::mem::size_of::<RebalanceConfig>(),
+    REBALANCE_CONFIG_SIZE
+);
+pub const REBALANCE_CONFIG_ALIGN: usize = 8;
+const_assert_eq!(
+    std::mem::align_of::<RebalanceConfig>(),
+    REBALANCE_CONFIG_ALIGN
+);
+
 // putting these consts here instead of in consts.rs
 // so that we dont forget to update the declare_program_keys!()
 // macro below if we change them
@@ -68,6 +83,8 @@ pub const LST_STATE_LIST_PDA_SEED: &[u8] = b"lst-state-list";
 pub const DISABLE_POOL_AUTHORITY_LIST_PDA_SEED: &[u8] = b"disable-pool-authority-list";
 pub const REBALANCE_RECORD_PDA_SEED: &[u8] = b"rebalance-record";
 pub const PROTOCOL_FEE_PDA_SEED: &[u8] = b"protocol-fee";
+pub const DONOR_ALLOWLIST_PDA_SEED: &[u8] = b"donor-allowlist";
+pub const REBALANCE_CONFIG_PDA_SEED: &[u8] = b"rebalance-config";
 
 pub mod program {
     sanctum_macros::declare_program_keys!(
@@ -81,6 +98,8 @@ pub mod program {
             ),
             ("rebalance-record", b"rebalance-record"),
             ("protocol-fee", b"protocol-fee"),
+            ("donor-allowlist", b"donor-allowlist"),
+            ("rebalance-config", b"rebalance-config"),
         ]
     );
 }
@@ -4,8 +4,9 @@ use solana_program::pubkey::Pubkey;
 
 use crate::{
     program::{POOL_STATE_ID, PROTOCOL_FEE_ID},
-    DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, LST_STATE_LIST_PDA_SEED, POOL_STATE_PDA_SEED,
-    PROTOCOL_FEE_PDA_SEED, REBALANCE_RECORD_PDA_SEED,
+    DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, DONOR_ALLOWLIST_PDA_SEED, LST_STATE_LIST_PDA_SEED,
+    POOL_STATE_PDA_SEED, PROTOCOL_FEE_PDA_SEED, REBALANCE_CONFIG_PDA_SEED,
+    REBALANCE_RECORD_PDA_SEED,
 };
 
 pub fn create_pool_reserves_address(
@@ -147,3 +148,17 @@ pub fn find_rebalance_record_address(program_id: Pubkey) -> (Pubkey, u8) {
 pub fn find_protocol_fee_address(program_id: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[PROTOCOL_FEE_PDA_SEED], &program_id)
 }
+
+/// Finds the donor allowlist PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::DONOR_ALLOWLIST_ID`] directly
+pub fn find_donor_allowlist_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DONOR_ALLOWLIST_PDA_SEED], &program_id)
+}
+
+/// Finds the rebalance config PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::REBALANCE_CONFIG_ID`] directly
+pub fn find_rebalance_config_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REBALANCE_CONFIG_PDA_SEED], &program_id)
+}
@@ -0,0 +1,85 @@
+//! Common pricing program interface account prefixes that the S controller
+//! prepends to its pricing program CPIs, derived from the S controller instruction's keys.
+
+use pricing_programs_interface::{
+    PriceExactInKeys, PriceExactOutKeys, PriceLpTokensToMintKeys, PriceLpTokensToRedeemKeys,
+};
+use s_controller_interface::{
+    AddLiquidityKeys, RemoveLiquidityKeys, SwapExactInKeys, SwapExactOutKeys,
+};
+
+pub fn price_exact_in_keys(
+    SwapExactInKeys {
+        src_lst_mint,
+        dst_lst_mint,
+        pool_state,
+        lst_state_list,
+        src_pool_reserves,
+        dst_pool_reserves,
+        ..
+    }: &SwapExactInKeys,
+) -> PriceExactInKeys {
+    PriceExactInKeys {
+        input_lst_mint: *src_lst_mint,
+        output_lst_mint: *dst_lst_mint,
+        pool_state: *pool_state,
+        lst_state_list: *lst_state_list,
+        input_pool_reserves: *src_pool_reserves,
+        output_pool_reserves: *dst_pool_reserves,
+    }
+}
+
+pub fn price_exact_out_keys(
+    SwapExactOutKeys {
+        src_lst_mint,
+        dst_lst_mint,
+        pool_state,
+        lst_state_list,
+        src_pool_reserves,
+        dst_pool_reserves,
+        ..
+    }: &SwapExactOutKeys,
+) -> PriceExactOutKeys {
+    PriceExactOutKeys {
+        input_lst_mint: *src_lst_mint,
+        output_lst_mint: *dst_lst_mint,
+        pool_state: *pool_state,
+        lst_state_list: *lst_state_list,
+        input_pool_reserves: *src_pool_reserves,
+        output_pool_reserves: *dst_pool_reserves,
+    }
+}
+
+pub fn price_lp_tokens_to_mint_keys(
+    AddLiquidityKeys {
+        lst_mint,
+        pool_state,
+        lst_state_list,
+        pool_reserves,
+        ..
+    }: &AddLiquidityKeys,
+) -> PriceLpTokensToMintKeys {
+    PriceLpTokensToMintKeys {
+        input_lst_mint: *lst_mint,
+        pool_state: *pool_state,
+        lst_state_list: *lst_state_list,
+        pool_reserves: *pool_reserves,
+    }
+}
+
+pub fn price_lp_tokens_to_redeem_keys(
+    RemoveLiquidityKeys {
+        lst_mint,
+        pool_state,
+        lst_state_list,
+        pool_reserves,
+        ..
+    }: &RemoveLiquidityKeys,
+) -> PriceLpTokensToRedeemKeys {
+    PriceLpTokensToRedeemKeys {
+        output_lst_mint: *lst_mint,
+        pool_state: *pool_state,
+        lst_state_list: *lst_state_list,
+        pool_reserves: *pool_reserves,
+    }
+}
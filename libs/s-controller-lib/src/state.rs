@@ -1,4 +1,4 @@
-use s_controller_interface::{LstState, PoolState, SControllerError};
+use s_controller_interface::{LstState, PoolState, RebalanceRecord, SControllerError};
 use solana_readonly_account::ReadonlyAccountData;
 
 use crate::{try_pool_state, U8Bool};
@@ -23,6 +23,69 @@ pub fn sync_sol_value_with_retval(
     Ok(())
 }
 
+/// Checks that a swap did not decrease the pool's total SOL value
+/// by more than the rebate its pricing program priced, `out_sol_value - in_sol_value`.
+///
+/// Swaps without a rebate must not decrease the pool's total SOL value at all.
+pub fn verify_swap_sol_value_change(
+    start_total_sol_value: u64,
+    end_total_sol_value: u64,
+    in_sol_value: u64,
+    out_sol_value: u64,
+) -> Result<(), SControllerError> {
+    let rebate = out_sol_value.saturating_sub(in_sol_value);
+    if end_total_sol_value < start_total_sol_value.saturating_sub(rebate) {
+        Err(SControllerError::PoolWouldLoseSolValue)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that the change in the pool's total SOL value over a rebalance
+/// is at least the `min_sol_value_delta` recorded at the start of it.
+///
+/// A negative `min_sol_value_delta` tolerates a loss of up to that many lamports.
+pub fn verify_rebalance_sol_value_delta(
+    old_total_sol_value: u64,
+    new_total_sol_value: u64,
+    min_sol_value_delta: i64,
+) -> Result<(), SControllerError> {
+    // no overflow, i128 fits u64 - u64
+    let sol_value_delta = i128::from(new_total_sol_value) - i128::from(old_total_sol_value);
+    if sol_value_delta >= i128::from(min_sol_value_delta) {
+        return Ok(());
+    }
+    if sol_value_delta < 0 {
+        Err(SControllerError::PoolWouldLoseSolValue)
+    } else {
+        Err(SControllerError::RebalanceSolValueDeltaTooLow)
+    }
+}
+
+/// Checks that the loss tolerated by a rebalance's `min_sol_value_delta`
+/// does not exceed the pool's admin-set `max_rebalance_loss`
+pub fn verify_rebalance_loss_within_max(
+    min_sol_value_delta: i64,
+    max_rebalance_loss: u64,
+) -> Result<(), SControllerError> {
+    // no overflow, i128 fits -u64
+    if i128::from(min_sol_value_delta) < -i128::from(max_rebalance_loss) {
+        Err(SControllerError::RebalanceLossTooHigh)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the lst_indexes of the additional destination LSTs recorded in a RebalanceRecord
+pub fn rebalance_record_additional_dst_lst_indexes(
+    rebalance_record: &RebalanceRecord,
+) -> Result<&[u32], SControllerError> {
+    rebalance_record
+        .additional_dst_lst_indexes
+        .get(..usize::from(rebalance_record.additional_dst_lst_count))
+        .ok_or(SControllerError::InvalidRebalanceRecordData)
+}
+
 /// For nice method call syntax both onchain and offchain,
 /// and to reduce scope of borrowing AccountInfo.data
 /// to avoid CPI account data borrow failed errors.
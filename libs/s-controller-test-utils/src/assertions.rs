@@ -100,6 +100,15 @@ pub async fn assert_pricing_prog_set(
     assert_eq!(pool_state.pricing_program, expected_pricing_prog);
 }
 
+pub async fn assert_pricing_pool_context_enabled(banks_client: &mut BanksClient, expected: bool) {
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(
+        U8Bool(pool_state.is_pricing_pool_context_enabled).is_true(),
+        expected
+    );
+}
+
 pub async fn assert_admin(bc: &mut BanksClient, expected_admin: Pubkey) {
     let pool_state_acc = bc.get_pool_state_acc().await;
     let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
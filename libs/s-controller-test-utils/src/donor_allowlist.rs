@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use s_controller_lib::try_donor_allowlist_mut;
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports, ExtendedBanksClient, ExtendedProgramTest,
+};
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+
+#[async_trait]
+pub trait DonorAllowlistBanksClient {
+    async fn get_donor_allowlist_acc(&mut self) -> Account;
+}
+
+#[async_trait]
+impl DonorAllowlistBanksClient for BanksClient {
+    async fn get_donor_allowlist_acc(&mut self) -> Account {
+        self.get_account_unwrapped(s_controller_lib::program::DONOR_ALLOWLIST_ID)
+            .await
+    }
+}
+
+pub const fn donor_allowlist_rent_exempt_lamports(donor_allowlist: &[Pubkey]) -> u64 {
+    est_rent_exempt_lamports(donor_allowlist.len() * PUBKEY_BYTES)
+}
+
+pub trait DonorAllowlistProgramTest {
+    fn add_donor_allowlist(self, donors: &[Pubkey]) -> Self;
+}
+
+impl DonorAllowlistProgramTest for ProgramTest {
+    fn add_donor_allowlist(self, donors: &[Pubkey]) -> Self {
+        assert!(!donors.is_empty());
+        let mut data = vec![0u8; donors.len() * PUBKEY_BYTES];
+        let donor_allowlist = try_donor_allowlist_mut(&mut data).unwrap();
+        donor_allowlist.copy_from_slice(donors);
+
+        let account = Account {
+            data,
+            lamports: donor_allowlist_rent_exempt_lamports(donors),
+            owner: s_controller_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        };
+
+        self.add_account_chained(s_controller_lib::program::DONOR_ALLOWLIST_ID, account)
+    }
+}
@@ -1,11 +1,15 @@
 mod assertions;
 mod disable_pool_authority_list;
+mod donor_allowlist;
 mod lst_state;
 mod program_test;
 mod state;
+mod token_2022;
 
 pub use assertions::*;
 pub use disable_pool_authority_list::*;
+pub use donor_allowlist::*;
 pub use lst_state::*;
 pub use program_test::*;
 pub use state::*;
+pub use token_2022::*;
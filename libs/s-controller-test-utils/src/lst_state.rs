@@ -5,14 +5,14 @@ use s_controller_lib::{
     try_lst_state_list, try_lst_state_list_mut, FindLstPdaAtaKeys, LST_STATE_SIZE,
 };
 use sanctum_solana_test_utils::{
-    est_rent_exempt_lamports,
-    token::{tokenkeg::mock_tokenkeg_account, MockTokenAccountArgs},
-    ExtendedBanksClient, IntoAccount,
+    est_rent_exempt_lamports, token::MockTokenAccountArgs, ExtendedBanksClient,
 };
 use solana_program::pubkey::Pubkey;
 use solana_program_test::{BanksClient, ProgramTest};
 use solana_sdk::account::Account;
 
+use crate::mock_token_account;
+
 #[derive(Clone, Copy, Debug)]
 pub struct MockLstStateArgs {
     pub mint: Pubkey,
@@ -33,7 +33,8 @@ pub struct MockLstStateRet {
     pub protocol_fee_accumulator_account: Account,
 }
 
-/// Assumes LST uses original spl-token program
+/// Token-2022 reserves and protocol fee accumulator accounts
+/// have the TransferFeeAmount extension
 pub fn mock_lst_state(
     MockLstStateArgs {
         mint,
@@ -58,25 +59,32 @@ pub fn mock_lst_state(
         is_input_disabled: is_input_disabled.into(),
         pool_reserves_bump,
         protocol_fee_accumulator_bump,
+        is_batch_calc_enabled: 0,
         padding: Default::default(),
         sol_value_calculator,
     };
-    let reserves_account = mock_tokenkeg_account(MockTokenAccountArgs {
-        mint,
-        authority: s_controller_lib::program::POOL_STATE_ID,
-        amount: reserves_amt,
-    });
-    let protocol_fee_accumulator_account = mock_tokenkeg_account(MockTokenAccountArgs {
-        mint,
-        authority: s_controller_lib::program::PROTOCOL_FEE_ID,
-        amount: protocol_fee_accumulator_amt,
-    });
+    let reserves_account = mock_token_account(
+        token_program,
+        MockTokenAccountArgs {
+            mint,
+            authority: s_controller_lib::program::POOL_STATE_ID,
+            amount: reserves_amt,
+        },
+    );
+    let protocol_fee_accumulator_account = mock_token_account(
+        token_program,
+        MockTokenAccountArgs {
+            mint,
+            authority: s_controller_lib::program::PROTOCOL_FEE_ID,
+            amount: protocol_fee_accumulator_amt,
+        },
+    );
     MockLstStateRet {
         lst_state,
         reserves_address,
-        reserves_account: reserves_account.into_account(),
+        reserves_account,
         protocol_fee_accumulator_address,
-        protocol_fee_accumulator_account: protocol_fee_accumulator_account.into_account(),
+        protocol_fee_accumulator_account,
     }
 }
 
@@ -0,0 +1,7 @@
+mod base;
+mod flat_fee_pp;
+mod no_fee_pp;
+
+pub use base::*;
+pub use flat_fee_pp::*;
+pub use no_fee_pp::*;
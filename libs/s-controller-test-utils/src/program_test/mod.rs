@@ -1,8 +1,10 @@
+mod jito_22_wsol;
 mod jito_marinade;
 mod jito_wsol;
 mod lido_wsol;
 mod utils;
 
+pub use jito_22_wsol::*;
 pub use jito_marinade::*;
 pub use jito_wsol::*;
 pub use lido_wsol::*;
@@ -1,3 +1,4 @@
+use borsh::{BorshDeserialize, BorshSerialize};
 use generic_pool_calculator_lib::GenericPoolSolValCalc;
 use generic_pool_calculator_test_utils::{
     GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
@@ -6,20 +7,27 @@ use lido_calculator_lib::LidoSolValCalc;
 use marinade_calculator_lib::MarinadeSolValCalc;
 use sanctum_solana_test_utils::{
     token::{tokenkeg::mock_tokenkeg_account, MockTokenAccountArgs},
-    ExtendedProgramTest, IntoAccount,
+    ExtendedProgramTest, IntoAccount, KeyedUiAccount,
 };
 use solana_program::pubkey::Pubkey;
 use solana_program_test::{processor, ProgramTest};
+use spl_calculator_interface::SplStakePool;
 use spl_calculator_lib::SplSolValCalc;
 use test_utils::{
-    LIDO_PROG_LAST_UPDATED_SLOT, MARINADE_PROG_LAST_UPDATED_SLOT,
+    jito_22_stake_pool, jitosol_22, LIDO_PROG_LAST_UPDATED_SLOT, MARINADE_PROG_LAST_UPDATED_SLOT,
     SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
 };
 
+use crate::{mock_token_2022_account, mock_transfer_fee_mint, MockTransferFeeMintArgs};
+
 pub trait AddSplProgramTest {
     fn add_spl_progs(self) -> Self;
 
     fn add_jito_stake_pool(self) -> Self;
+
+    /// Adds the jito stake pool at [`jito_22_stake_pool::ID`]
+    /// with its pool mint replaced by the Token-2022 mint [`jitosol_22::ID`]
+    fn add_jito_22_stake_pool(self, mint_args: MockTransferFeeMintArgs) -> Self;
 }
 
 impl AddSplProgramTest for ProgramTest {
@@ -33,6 +41,8 @@ impl AddSplProgramTest for ProgramTest {
         self.add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
+            stale_discount_bps: Default::default(),
+            approved_program_data_hash: Default::default(),
             owner: SplSolValCalc::ID,
         })
         .add_test_fixtures_account("spl-stake-pool-prog.json")
@@ -43,6 +53,21 @@ impl AddSplProgramTest for ProgramTest {
         self.add_test_fixtures_account("jito-stake-pool.json")
             .add_test_fixtures_account("jitosol-mint.json")
     }
+
+    fn add_jito_22_stake_pool(mut self, mint_args: MockTransferFeeMintArgs) -> Self {
+        let mut stake_pool_acc = KeyedUiAccount::from_test_fixtures_file("jito-stake-pool.json")
+            .to_keyed_account()
+            .account;
+        let mut stake_pool =
+            SplStakePool::deserialize(&mut stake_pool_acc.data.as_slice()).unwrap();
+        stake_pool.pool_mint = jitosol_22::ID;
+        stake_pool.token_program_id = spl_token_2022::ID;
+        let mut data = stake_pool_acc.data.as_mut_slice();
+        stake_pool.serialize(&mut data).unwrap();
+        self.add_account(jito_22_stake_pool::ID, stake_pool_acc);
+        self.add_account(jitosol_22::ID, mock_transfer_fee_mint(mint_args));
+        self
+    }
 }
 
 pub trait AddMarinadeProgramTest {
@@ -62,6 +87,8 @@ impl AddMarinadeProgramTest for ProgramTest {
         self.add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: MARINADE_PROG_LAST_UPDATED_SLOT,
+            stale_discount_bps: Default::default(),
+            approved_program_data_hash: Default::default(),
             owner: MarinadeSolValCalc::ID,
         })
         .add_test_fixtures_account("marinade-prog.json")
@@ -91,6 +118,8 @@ impl AddLidoProgramTest for ProgramTest {
         self.add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: LIDO_PROG_LAST_UPDATED_SLOT,
+            stale_discount_bps: Default::default(),
+            approved_program_data_hash: Default::default(),
             owner: LidoSolValCalc::ID,
         })
         .add_test_fixtures_account("lido-prog.json")
@@ -105,6 +134,8 @@ impl AddLidoProgramTest for ProgramTest {
 
 pub trait GenAndAddTokenAccountProgramTest {
     fn gen_and_add_token_account(&mut self, args: MockTokenAccountArgs) -> Pubkey;
+
+    fn gen_and_add_token_2022_account(&mut self, args: MockTokenAccountArgs) -> Pubkey;
 }
 
 impl GenAndAddTokenAccountProgramTest for ProgramTest {
@@ -114,4 +145,10 @@ impl GenAndAddTokenAccountProgramTest for ProgramTest {
         self.add_account(addr, token_acc.into_account());
         addr
     }
+
+    fn gen_and_add_token_2022_account(&mut self, args: MockTokenAccountArgs) -> Pubkey {
+        let addr = Pubkey::new_unique();
+        self.add_account(addr, mock_token_2022_account(args));
+        addr
+    }
 }
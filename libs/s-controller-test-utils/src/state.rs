@@ -26,7 +26,7 @@ pub const DEFAULT_POOL_STATE: PoolState = PoolState {
     version: 0,
     is_disabled: 0,
     is_rebalancing: 0,
-    padding: [0u8; 1],
+    is_pricing_pool_context_enabled: 1,
     admin: initial_authority::ID,
     rebalance_authority: initial_authority::ID,
     protocol_fee_beneficiary: initial_authority::ID,
@@ -0,0 +1,108 @@
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports,
+    token::{tokenkeg::mock_tokenkeg_account, MockTokenAccountArgs},
+    IntoAccount,
+};
+use solana_program::{program_option::COption, pubkey::Pubkey};
+use solana_sdk::account::Account;
+use spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeAmount, TransferFeeConfig},
+        BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+    },
+    state::{AccountState, Mint},
+};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MockTransferFeeMintArgs {
+    pub supply: u64,
+    pub decimals: u8,
+    pub transfer_fee_basis_points: u16,
+    pub maximum_fee: u64,
+}
+
+impl MockTransferFeeMintArgs {
+    /// The transfer fee in effect for every epoch
+    pub fn transfer_fee(&self) -> TransferFee {
+        TransferFee {
+            epoch: 0.into(),
+            maximum_fee: self.maximum_fee.into(),
+            transfer_fee_basis_points: self.transfer_fee_basis_points.into(),
+        }
+    }
+}
+
+/// Token-2022 mint with no authorities and the TransferFeeConfig extension
+pub fn mock_transfer_fee_mint(args: MockTransferFeeMintArgs) -> Account {
+    let len = ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferFeeConfig])
+        .unwrap();
+    let mut data = vec![0u8; len];
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+    config.older_transfer_fee = args.transfer_fee();
+    config.newer_transfer_fee = args.transfer_fee();
+    state.base = Mint {
+        mint_authority: COption::None,
+        supply: args.supply,
+        decimals: args.decimals,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(len),
+        data,
+        owner: spl_token_2022::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+/// Token-2022 token account with the TransferFeeAmount extension
+/// required to receive tokens of mints with the TransferFeeConfig extension
+pub fn mock_token_2022_account(
+    MockTokenAccountArgs {
+        mint,
+        authority,
+        amount,
+    }: MockTokenAccountArgs,
+) -> Account {
+    let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[
+        ExtensionType::TransferFeeAmount,
+    ])
+    .unwrap();
+    let mut data = vec![0u8; len];
+    let mut state =
+        StateWithExtensionsMut::<spl_token_2022::state::Account>::unpack_uninitialized(&mut data)
+            .unwrap();
+    state.init_extension::<TransferFeeAmount>(true).unwrap();
+    state.base = spl_token_2022::state::Account {
+        mint,
+        owner: authority,
+        amount,
+        delegate: COption::None,
+        state: AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(len),
+        data,
+        owner: spl_token_2022::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+/// Mock token account owned by `token_program`, either tokenkeg or Token-2022
+pub fn mock_token_account(token_program: Pubkey, args: MockTokenAccountArgs) -> Account {
+    if token_program == spl_token_2022::ID {
+        mock_token_2022_account(args)
+    } else {
+        mock_tokenkeg_account(args).into_account()
+    }
+}
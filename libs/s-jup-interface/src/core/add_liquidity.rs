@@ -4,13 +4,15 @@ use pricing_programs_interface::PriceLpTokensToMintIxArgs;
 use s_controller_interface::{add_liquidity_ix, AddLiquidityIxArgs, SControllerError};
 use s_controller_lib::{
     account_metas_extend_with_pricing_program_price_lp_accounts,
-    account_metas_extend_with_sol_value_calculator_accounts,
-    add_liquidity_ix_by_mint_full_for_prog, calc_lp_tokens_to_mint, index_to_u32, try_pool_state,
-    AddLiquidityByMintFreeArgs, AddLiquidityIxAmts, AddRemoveLiquidityAccountSuffixes,
+    account_metas_extend_with_sol_value_calculator_accounts, add_liquidity_ix_full_for_prog,
+    calc_lp_tokens_to_mint, index_to_u32, price_lp_tokens_to_mint_keys, try_pool_state,
+    AddLiquidityByMintFreeArgs, AddLiquidityIxAmts, AddLiquidityIxFullArgs,
+    AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityExtraAccounts,
     AddRemoveLiquidityProgramIds, LpTokenRateArgs, U8Bool,
 };
 use s_pricing_prog_aggregate::PricingProg;
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_s_common::token::amount_after_transfer_fee;
 use sanctum_token_lib::MintWithTokenProgram;
 use sanctum_token_ratio::AmtsAfterFeeBuilder;
 use solana_readonly_account::ReadonlyAccountData;
@@ -47,12 +49,17 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         let (pool_state, _input_lst_state, _input_reserves_balance) =
             apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
 
-        let lst_amount_sol_value = input_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_min();
+        let lst_amount_received =
+            amount_after_transfer_fee(input_lst_data.current_transfer_fee().as_ref(), *amount)?;
+        let lst_amount_sol_value = input_lst_data
+            .sol_val_calc
+            .lst_to_sol(lst_amount_received)?
+            .get_min();
 
         let lst_amount_sol_value_after_fees = pricing_prog.quote_lp_tokens_to_mint(
             *input_mint,
             &PriceLpTokensToMintIxArgs {
-                amount: *amount,
+                amount: lst_amount_received,
                 sol_value: lst_amount_sol_value,
             },
         )?;
@@ -124,19 +131,29 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 ..
             },
         ) = self.find_ready_lst(*source_mint)?;
-        Ok(add_liquidity_ix_by_mint_full_for_prog(
+        let (keys, lst_index, program_ids) = self
+            .add_liquidity_free_args(src_token_program, swap_params)?
+            .resolve_for_prog(self.program_id)?;
+        let pricing_program_price_lp_accounts = self
+            .pricing_prog()?
+            .price_lp_tokens_to_mint_accounts(price_lp_tokens_to_mint_keys(&keys))?;
+        Ok(add_liquidity_ix_full_for_prog(
             self.program_id,
-            self.add_liquidity_free_args(src_token_program, swap_params)?,
-            AddLiquidityIxAmts {
-                lst_amount: *in_amount,
-                min_lp_out: *out_amount,
-            },
-            AddRemoveLiquidityAccountSuffixes {
-                lst_calculator_accounts: &src_sol_val_calc.ix_accounts(),
-                pricing_program_price_lp_accounts: &self
-                    .pricing_prog()?
-                    .price_lp_tokens_to_mint_accounts(*source_mint)?,
+            keys,
+            AddLiquidityIxFullArgs {
+                lst_index,
+                amts: AddLiquidityIxAmts {
+                    lst_amount: *in_amount,
+                    min_lp_out: *out_amount,
+                },
             },
+            AddRemoveLiquidityExtraAccounts::new(
+                program_ids,
+                AddRemoveLiquidityAccountSuffixes {
+                    lst_calculator_accounts: &src_sol_val_calc.ix_accounts(),
+                    pricing_program_price_lp_accounts: &pricing_program_price_lp_accounts,
+                },
+            ),
         )?)
     }
 
@@ -191,7 +208,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             &mut account_metas,
             &self
                 .pricing_prog()?
-                .price_lp_tokens_to_mint_accounts(swap_params.source_mint)?,
+                .price_lp_tokens_to_mint_accounts(price_lp_tokens_to_mint_keys(&keys))?,
             pricing_program_id,
         )?;
 
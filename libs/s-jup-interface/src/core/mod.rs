@@ -3,9 +3,11 @@
 use anyhow::anyhow;
 use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapMode, SwapParams};
 use s_controller_interface::LstState;
-use s_controller_lib::try_lst_state_list;
+use s_controller_lib::{
+    native_sol_in_ixs, native_sol_out_ixs, native_sol_wsol_acc, try_lst_state_list,
+};
 use solana_readonly_account::ReadonlyAccountData;
-use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, system_program};
 
 use crate::SPool;
 
@@ -17,13 +19,54 @@ mod swap_exact_out;
 
 pub use common::*;
 
+/// Alias for native SOL that can be used in place of the wSOL mint
+/// as the input or output mint for quoting and swapping.
+///
+/// Native SOL is always swapped as the wSOL LST, valued by the wSOL calculator.
+pub const NATIVE_SOL_MINT_ALIAS: Pubkey = system_program::ID;
+
+/// Maps [`NATIVE_SOL_MINT_ALIAS`] to the wSOL mint, leaving other mints unchanged
+pub fn resolve_native_sol_alias(mint: Pubkey) -> Pubkey {
+    if mint == NATIVE_SOL_MINT_ALIAS {
+        wsol_keys::wsol::ID
+    } else {
+        mint
+    }
+}
+
+/// [`resolve_native_sol_alias`] for both mints of a swap,
+/// rejecting native SOL on both sides
+fn resolve_native_sol_alias_mints(
+    source_mint: Pubkey,
+    destination_mint: Pubkey,
+) -> anyhow::Result<(Pubkey, Pubkey)> {
+    if source_mint == NATIVE_SOL_MINT_ALIAS && destination_mint == NATIVE_SOL_MINT_ALIAS {
+        return Err(anyhow!("Cannot swap native SOL for native SOL"));
+    }
+    Ok((
+        resolve_native_sol_alias(source_mint),
+        resolve_native_sol_alias(destination_mint),
+    ))
+}
+
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     // Allows for use with transactions without jup program
+    //
+    // Supports [`NATIVE_SOL_MINT_ALIAS`] as `source_mint` or `destination_mint`,
+    // in which case the corresponding token account in `swap_params` must be a wSOL account,
+    // such as the one jup wraps native SOL into. Use [`Self::swap_ixs`] to wrap and unwrap it.
     pub fn swap_ix(
         &self,
         swap_params: &SwapParams,
         swap_mode: SwapMode, // to make up for lack of swap_mode in swap_params
     ) -> anyhow::Result<Instruction> {
+        let (source_mint, destination_mint) =
+            resolve_native_sol_alias_mints(swap_params.source_mint, swap_params.destination_mint)?;
+        let swap_params = &SwapParams {
+            source_mint,
+            destination_mint,
+            ..*swap_params
+        };
         let lp_mint = self.lp_token_mint()?;
         if swap_params.source_mint == lp_mint {
             if let SwapMode::ExactOut = swap_mode {
@@ -43,7 +86,56 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         }
     }
 
+    /// Same as [`Self::swap_ix`], but wraps and unwraps native SOL
+    /// when [`NATIVE_SOL_MINT_ALIAS`] is `source_mint` or `destination_mint`.
+    ///
+    /// For native SOL, the corresponding token account in `swap_params` is ignored
+    /// and the swap instead goes through `token_transfer_authority`'s temporary
+    /// wSOL account [`native_sol_wsol_acc`], which is created, funded from
+    /// and closed back into its system account within the returned instructions.
+    pub fn swap_ixs(
+        &self,
+        swap_params: &SwapParams,
+        swap_mode: SwapMode,
+    ) -> anyhow::Result<Vec<Instruction>> {
+        let SwapParams {
+            source_mint,
+            destination_mint,
+            token_transfer_authority,
+            in_amount,
+            ..
+        } = swap_params;
+        resolve_native_sol_alias_mints(*source_mint, *destination_mint)?;
+        if *source_mint == NATIVE_SOL_MINT_ALIAS {
+            let ix = self.swap_ix(
+                &SwapParams {
+                    source_token_account: native_sol_wsol_acc(token_transfer_authority)?,
+                    ..*swap_params
+                },
+                swap_mode,
+            )?;
+            Ok(native_sol_in_ixs(token_transfer_authority, *in_amount, ix)?)
+        } else if *destination_mint == NATIVE_SOL_MINT_ALIAS {
+            let ix = self.swap_ix(
+                &SwapParams {
+                    destination_token_account: native_sol_wsol_acc(token_transfer_authority)?,
+                    ..*swap_params
+                },
+                swap_mode,
+            )?;
+            Ok(native_sol_out_ixs(token_transfer_authority, ix)?)
+        } else {
+            Ok(vec![self.swap_ix(swap_params, swap_mode)?])
+        }
+    }
+
+    /// Supports [`NATIVE_SOL_MINT_ALIAS`] as `input_mint` or `output_mint`
     pub fn quote_full(&self, quote_params: &QuoteParams) -> anyhow::Result<Quote> {
+        let quote_params = &QuoteParams {
+            input_mint: resolve_native_sol_alias(quote_params.input_mint),
+            output_mint: resolve_native_sol_alias(quote_params.output_mint),
+            ..quote_params.clone()
+        };
         let lp_mint = self.lp_token_mint()?;
         if quote_params.input_mint == lp_mint {
             if let SwapMode::ExactOut = quote_params.swap_mode {
@@ -63,10 +155,19 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         }
     }
 
+    /// Supports [`NATIVE_SOL_MINT_ALIAS`] as `source_mint` or `destination_mint`,
+    /// with the corresponding token account being the wSOL account jup wraps native SOL into
     pub fn get_swap_and_account_metas_full(
         &self,
         swap_params: &SwapParams,
     ) -> anyhow::Result<SwapAndAccountMetas> {
+        let (source_mint, destination_mint) =
+            resolve_native_sol_alias_mints(swap_params.source_mint, swap_params.destination_mint)?;
+        let swap_params = &SwapParams {
+            source_mint,
+            destination_mint,
+            ..*swap_params
+        };
         let lp_mint = self.lp_token_mint()?;
         if swap_params.source_mint == lp_mint {
             self.remove_liquidity_swap_and_account_metas(swap_params)
@@ -5,13 +5,15 @@ use s_controller_interface::{remove_liquidity_ix, RemoveLiquidityIxArgs, SContro
 use s_controller_lib::{
     account_metas_extend_with_pricing_program_price_lp_accounts,
     account_metas_extend_with_sol_value_calculator_accounts, calc_lp_tokens_sol_value,
-    calc_remove_liquidity_protocol_fees, index_to_u32, remove_liquidity_ix_by_mint_full_for_prog,
-    try_pool_state, AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityProgramIds,
+    calc_remove_liquidity_protocol_fees, index_to_u32, price_lp_tokens_to_redeem_keys,
+    remove_liquidity_ix_full_for_prog, try_pool_state, AddRemoveLiquidityAccountSuffixes,
+    AddRemoveLiquidityExtraAccounts, AddRemoveLiquidityProgramIds,
     CalcRemoveLiquidityProtocolFeesArgs, LpTokenRateArgs, RemoveLiquidityByMintFreeArgs,
-    RemoveLiquidityIxAmts,
+    RemoveLiquidityIxAmts, RemoveLiquidityIxFullArgs,
 };
 use s_pricing_prog_aggregate::PricingProg;
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_s_common::token::amount_after_transfer_fee;
 use sanctum_token_lib::MintWithTokenProgram;
 use sanctum_token_ratio::AmtsAfterFeeBuilder;
 use solana_readonly_account::ReadonlyAccountData;
@@ -83,6 +85,10 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         if total_dst_lst_out > output_reserves_balance {
             return Err(SControllerError::NotEnoughLiquidity.into());
         }
+        let to_user_lst_received = amount_after_transfer_fee(
+            output_lst_data.current_transfer_fee().as_ref(),
+            to_user_lst_amount,
+        )?;
         let (fee_amount, fee_pct) = calc_quote_fees(
             AmtsAfterFeeBuilder::new_amt_bef_fee(lp_tokens_sol_value)
                 .with_amt_aft_fee(lp_tokens_sol_value_after_fees)?,
@@ -92,7 +98,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             min_in_amount: None,
             min_out_amount: None,
             in_amount: *amount,
-            out_amount: to_user_lst_amount,
+            out_amount: to_user_lst_received,
             fee_mint: *output_mint,
             fee_amount,
             fee_pct,
@@ -144,19 +150,29 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 ..
             },
         ) = self.find_ready_lst(*destination_mint)?;
-        Ok(remove_liquidity_ix_by_mint_full_for_prog(
+        let (keys, lst_index, program_ids) = self
+            .remove_liquidity_free_args(src_token_program, swap_params)?
+            .resolve_for_prog(self.program_id)?;
+        let pricing_program_price_lp_accounts = self
+            .pricing_prog()?
+            .price_lp_tokens_to_redeem_accounts(price_lp_tokens_to_redeem_keys(&keys))?;
+        Ok(remove_liquidity_ix_full_for_prog(
             self.program_id,
-            self.remove_liquidity_free_args(src_token_program, swap_params)?,
-            RemoveLiquidityIxAmts {
-                lp_token_amount: *in_amount,
-                min_lst_out: *out_amount,
-            },
-            AddRemoveLiquidityAccountSuffixes {
-                lst_calculator_accounts: &src_sol_val_calc.ix_accounts(),
-                pricing_program_price_lp_accounts: &self
-                    .pricing_prog()?
-                    .price_lp_tokens_to_redeem_accounts(*destination_mint)?,
+            keys,
+            RemoveLiquidityIxFullArgs {
+                lst_index,
+                amts: RemoveLiquidityIxAmts {
+                    lp_token_amount: *in_amount,
+                    min_lst_out: *out_amount,
+                },
             },
+            AddRemoveLiquidityExtraAccounts::new(
+                program_ids,
+                AddRemoveLiquidityAccountSuffixes {
+                    lst_calculator_accounts: &src_sol_val_calc.ix_accounts(),
+                    pricing_program_price_lp_accounts: &pricing_program_price_lp_accounts,
+                },
+            ),
         )?)
     }
 
@@ -211,7 +227,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             &mut account_metas,
             &self
                 .pricing_prog()?
-                .price_lp_tokens_to_redeem_accounts(swap_params.source_mint)?,
+                .price_lp_tokens_to_redeem_accounts(price_lp_tokens_to_redeem_keys(&keys))?,
             pricing_program_id,
         )?;
 
@@ -5,13 +5,14 @@ use s_controller_interface::{swap_exact_in_ix, SControllerError, SwapExactInIxAr
 use s_controller_lib::{
     account_metas_extend_with_pricing_program_price_swap_accounts,
     account_metas_extend_with_src_dst_sol_value_calculator_accounts, calc_swap_protocol_fees,
-    index_to_u32, swap_exact_in_ix_by_mint_full_for_prog, try_pool_state, CalcSwapProtocolFeesArgs,
-    SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes, SrcDstLstSolValueCalcAccounts,
-    SrcDstLstSolValueCalcExtendCount, SrcDstLstSolValueCalcProgramIds, SwapByMintsFreeArgs,
-    SwapExactInAmounts, U8Bool,
+    index_to_u32, price_exact_in_keys, swap_exact_in_ix_full_for_prog, try_pool_state,
+    CalcSwapProtocolFeesArgs, SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes,
+    SrcDstLstSolValueCalcAccounts, SrcDstLstSolValueCalcExtendCount,
+    SrcDstLstSolValueCalcProgramIds, SwapByMintsFreeArgs, SwapExactInIxFullArgs, U8Bool,
 };
 use s_pricing_prog_aggregate::PricingProg;
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_s_common::token::amount_after_transfer_fee;
 use sanctum_token_lib::MintWithTokenProgram;
 use sanctum_token_ratio::AmtsAfterFeeBuilder;
 use solana_readonly_account::ReadonlyAccountData;
@@ -47,11 +48,19 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         }
         let (pool_state, _input_lst_state, _input_reserves_balance) =
             apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
+        let input_pool_reserves = self.pool_reserves_account(&input_lst_state, input_lst_data)?;
         let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
+        let output_pool_reserves =
+            self.pool_reserves_account(&output_lst_state, output_lst_data)?;
         let (pool_state, _output_lst_state, output_reserves_balance) =
             apply_sync_sol_value(pool_state, output_lst_state, output_lst_data)?;
 
-        let in_sol_value = input_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_min();
+        let src_lst_in =
+            amount_after_transfer_fee(input_lst_data.current_transfer_fee().as_ref(), *amount)?;
+        let in_sol_value = input_lst_data
+            .sol_val_calc
+            .lst_to_sol(src_lst_in)?
+            .get_min();
         if in_sol_value == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
@@ -59,20 +68,25 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             PriceExactInKeys {
                 input_lst_mint: *input_mint,
                 output_lst_mint: *output_mint,
+                pool_state: self.pool_state_addr,
+                lst_state_list: self.lst_state_list_addr,
+                input_pool_reserves,
+                output_pool_reserves,
             },
             &PriceExactInIxArgs {
-                amount: *amount,
+                amount: src_lst_in,
                 sol_value: in_sol_value,
             },
         )?;
-        if out_sol_value > in_sol_value {
-            return Err(SControllerError::PoolWouldLoseSolValue.into());
-        }
         let dst_lst_out = output_lst_data
             .sol_val_calc
             .sol_to_lst(out_sol_value)?
             .get_min();
-        if dst_lst_out == 0 {
+        let dst_lst_received = amount_after_transfer_fee(
+            output_lst_data.current_transfer_fee().as_ref(),
+            dst_lst_out,
+        )?;
+        if dst_lst_received == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
         let to_protocol_fees_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
@@ -95,7 +109,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             min_in_amount: None,
             min_out_amount: None,
             in_amount: *amount,
-            out_amount: dst_lst_out,
+            out_amount: dst_lst_received,
             fee_mint: *output_mint,
             fee_amount,
             fee_pct,
@@ -161,24 +175,37 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             let pool_state_data = self.pool_state_data()?;
             try_pool_state(&pool_state_data)?.pricing_program
         };
-        Ok(swap_exact_in_ix_by_mint_full_for_prog(
+        let (
+            keys,
+            SrcDstLstIndexes {
+                src_lst_index,
+                dst_lst_index,
+            },
+            src_dst_lst_sol_value_calc_program_ids,
+        ) = self
+            .swap_by_mints_free_args(*src_token_program, *dst_token_program, swap_params)?
+            .resolve_exact_in_for_prog(self.program_id)?;
+        let pricing_program_accounts = self
+            .pricing_prog()?
+            .price_exact_in_accounts(price_exact_in_keys(&keys))?;
+        Ok(swap_exact_in_ix_full_for_prog(
             self.program_id,
-            self.swap_by_mints_free_args(*src_token_program, *dst_token_program, swap_params)?,
-            SwapExactInAmounts {
+            keys,
+            SwapExactInIxFullArgs {
+                src_lst_index,
+                dst_lst_index,
                 // TODO: where did other_amount_threshold go?
                 min_amount_out: *out_amount,
                 amount: *in_amount,
             },
-            SrcDstLstSolValueCalcAccountSuffixes {
-                src_lst_calculator_accounts: &src_sol_val_calc.ix_accounts(),
-                dst_lst_calculator_accounts: &dst_sol_val_calc.ix_accounts(),
-            },
-            &self
-                .pricing_prog()?
-                .price_exact_in_accounts(PriceExactInKeys {
-                    input_lst_mint: *source_mint,
-                    output_lst_mint: *destination_mint,
-                })?,
+            SrcDstLstSolValueCalcAccounts::new(
+                src_dst_lst_sol_value_calc_program_ids,
+                SrcDstLstSolValueCalcAccountSuffixes {
+                    src_lst_calculator_accounts: &src_sol_val_calc.ix_accounts(),
+                    dst_lst_calculator_accounts: &dst_sol_val_calc.ix_accounts(),
+                },
+            ),
+            &pricing_program_accounts,
             pricing_program,
         )?)
     }
@@ -262,10 +289,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         let pricing_prog = self.pricing_prog()?;
         account_metas_extend_with_pricing_program_price_swap_accounts(
             &mut account_metas,
-            &pricing_prog.price_exact_in_accounts(PriceExactInKeys {
-                input_lst_mint: *source_mint,
-                output_lst_mint: *destination_mint,
-            })?,
+            &pricing_prog.price_exact_in_accounts(price_exact_in_keys(&keys))?,
             pricing_prog.pricing_program_id(),
         )?;
 
@@ -5,13 +5,14 @@ use s_controller_interface::{swap_exact_out_ix, SControllerError, SwapExactOutIx
 use s_controller_lib::{
     account_metas_extend_with_pricing_program_price_swap_accounts,
     account_metas_extend_with_src_dst_sol_value_calculator_accounts, calc_swap_protocol_fees,
-    index_to_u32, swap_exact_out_ix_by_mint_full_for_prog, try_pool_state,
+    index_to_u32, price_exact_out_keys, swap_exact_out_ix_full_for_prog, try_pool_state,
     CalcSwapProtocolFeesArgs, SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes,
     SrcDstLstSolValueCalcAccounts, SrcDstLstSolValueCalcExtendCount,
-    SrcDstLstSolValueCalcProgramIds, SwapExactOutAmounts, U8Bool,
+    SrcDstLstSolValueCalcProgramIds, SwapExactOutIxFullArgs, U8Bool,
 };
 use s_pricing_prog_aggregate::PricingProg;
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_s_common::token::amount_before_transfer_fee;
 use sanctum_token_ratio::AmtsAfterFeeBuilder;
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::instruction::{AccountMeta, Instruction};
@@ -43,11 +44,19 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         }
         let (pool_state, _input_lst_state, _input_reserves_balance) =
             apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
+        let input_pool_reserves = self.pool_reserves_account(&input_lst_state, input_lst_data)?;
         let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
+        let output_pool_reserves =
+            self.pool_reserves_account(&output_lst_state, output_lst_data)?;
         let (pool_state, _output_lst_state, output_reserves_balance) =
             apply_sync_sol_value(pool_state, output_lst_state, output_lst_data)?;
 
-        let out_sol_value = output_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_max();
+        let dst_lst_out =
+            amount_before_transfer_fee(output_lst_data.current_transfer_fee().as_ref(), *amount)?;
+        let out_sol_value = output_lst_data
+            .sol_val_calc
+            .lst_to_sol(dst_lst_out)?
+            .get_max();
         if out_sol_value == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
@@ -55,15 +64,16 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             PriceExactOutKeys {
                 input_lst_mint: *input_mint,
                 output_lst_mint: *output_mint,
+                pool_state: self.pool_state_addr,
+                lst_state_list: self.lst_state_list_addr,
+                input_pool_reserves,
+                output_pool_reserves,
             },
             &PriceExactOutIxArgs {
-                amount: *amount,
+                amount: dst_lst_out,
                 sol_value: out_sol_value,
             },
         )?;
-        if out_sol_value > in_sol_value {
-            return Err(SControllerError::PoolWouldLoseSolValue.into());
-        }
         let src_lst_in = input_lst_data
             .sol_val_calc
             .sol_to_lst(in_sol_value)?
@@ -71,13 +81,15 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         if src_lst_in == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
+        let src_lst_sent =
+            amount_before_transfer_fee(input_lst_data.current_transfer_fee().as_ref(), src_lst_in)?;
         let to_protocol_fees_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
             in_sol_value,
             out_sol_value,
-            dst_lst_out: *amount,
+            dst_lst_out,
             trading_protocol_fee_bps: pool_state.trading_protocol_fee_bps,
         })?;
-        let total_dst_lst_out = amount
+        let total_dst_lst_out = dst_lst_out
             .checked_add(to_protocol_fees_lst_amount)
             .ok_or(SControllerError::MathError)?;
         if total_dst_lst_out > output_reserves_balance {
@@ -90,7 +102,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         Ok(Quote {
             min_in_amount: None,
             min_out_amount: None,
-            in_amount: src_lst_in,
+            in_amount: src_lst_sent,
             out_amount: *amount,
             fee_mint: *output_mint,
             fee_amount,
@@ -131,24 +143,37 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             let pool_state_data = self.pool_state_data()?;
             try_pool_state(&pool_state_data)?.pricing_program
         };
-        Ok(swap_exact_out_ix_by_mint_full_for_prog(
+        let (
+            keys,
+            SrcDstLstIndexes {
+                src_lst_index,
+                dst_lst_index,
+            },
+            src_dst_lst_sol_value_calc_program_ids,
+        ) = self
+            .swap_by_mints_free_args(*src_token_program, *dst_token_program, swap_params)?
+            .resolve_exact_out_for_prog(self.program_id)?;
+        let pricing_program_accounts = self
+            .pricing_prog()?
+            .price_exact_out_accounts(price_exact_out_keys(&keys))?;
+        Ok(swap_exact_out_ix_full_for_prog(
             self.program_id,
-            self.swap_by_mints_free_args(*src_token_program, *dst_token_program, swap_params)?,
-            SwapExactOutAmounts {
+            keys,
+            SwapExactOutIxFullArgs {
+                src_lst_index,
+                dst_lst_index,
                 // TODO: where did other_amount_threshold go?
                 max_amount_in: *in_amount,
                 amount: *out_amount,
             },
-            SrcDstLstSolValueCalcAccountSuffixes {
-                src_lst_calculator_accounts: &src_sol_val_calc.ix_accounts(),
-                dst_lst_calculator_accounts: &dst_sol_val_calc.ix_accounts(),
-            },
-            &self
-                .pricing_prog()?
-                .price_exact_out_accounts(PriceExactOutKeys {
-                    input_lst_mint: *source_mint,
-                    output_lst_mint: *destination_mint,
-                })?,
+            SrcDstLstSolValueCalcAccounts::new(
+                src_dst_lst_sol_value_calc_program_ids,
+                SrcDstLstSolValueCalcAccountSuffixes {
+                    src_lst_calculator_accounts: &src_sol_val_calc.ix_accounts(),
+                    dst_lst_calculator_accounts: &dst_sol_val_calc.ix_accounts(),
+                },
+            ),
+            &pricing_program_accounts,
             pricing_program,
         )?)
     }
@@ -233,10 +258,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         let pricing_prog = self.pricing_prog()?;
         account_metas_extend_with_pricing_program_price_swap_accounts(
             &mut account_metas,
-            &pricing_prog.price_exact_out_accounts(PriceExactOutKeys {
-                input_lst_mint: *source_mint,
-                output_lst_mint: *destination_mint,
-            })?,
+            &pricing_prog.price_exact_out_accounts(price_exact_out_keys(&keys))?,
             pricing_prog.pricing_program_id(),
         )?;
 
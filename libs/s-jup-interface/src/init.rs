@@ -3,6 +3,7 @@ use std::sync::{atomic::AtomicU64, Arc};
 use s_controller_lib::{
     find_lst_state_list_address, find_pool_state_address, try_lst_state_list, try_pool_state,
 };
+use s_pricing_prog_aggregate::CustomPricingProgFactory;
 use sanctum_lst_list::SanctumLst;
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::ReadonlyAccountData;
@@ -59,11 +60,14 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
     /// `Self`s created from this fn must be update_full() 2 more times before they can be used
     /// - first update fetches pool_state, updates various sol value calculator programs and pricing program
     /// - second update fetches LP token mint read from fetched pool_state
+    ///
+    /// `custom_pricing_prog_factory` creates the pricing program if it is not one known to the aggregate
     pub fn from_lst_state_list_account(
         program_id: Pubkey,
         lst_state_list_account: L,
         lst_list: &[SanctumLst],
         shared_current_epoch: &Arc<AtomicU64>,
+        custom_pricing_prog_factory: Option<CustomPricingProgFactory>,
     ) -> anyhow::Result<Self> {
         let SPoolInitKeys {
             lst_state_list: lst_state_list_addr,
@@ -85,6 +89,7 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
             pool_state_addr,
             pool_state_account: None,
             pricing_prog: None,
+            custom_pricing_prog_factory,
             lp_mint_supply: None,
             lst_state_list_account,
             lst_data_list,
@@ -95,6 +100,8 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     /// `Self`s created from this fn must be update_full() 1 more time before they can be used.
     ///  - this update updates the various sol value calculator programs and pricing program
+    ///
+    /// `custom_pricing_prog_factory` creates the pricing program if it is not one known to the aggregate
     pub fn from_init_accounts(
         program_id: Pubkey,
         SPoolInitAccounts {
@@ -103,19 +110,21 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         }: SPoolInitAccounts<S, L>,
         lst_list: &[SanctumLst],
         shared_current_epoch: &Arc<AtomicU64>,
+        custom_pricing_prog_factory: Option<CustomPricingProgFactory>,
     ) -> anyhow::Result<Self> {
         let pricing_prog = {
             let lst_state_list_acc_data = lst_state_list_acc.data();
             let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
             let pool_state_acc_data = pool_state_acc.data();
             let pool_state = try_pool_state(&pool_state_acc_data)?;
-            try_pricing_prog(pool_state, lst_state_list)?
+            try_pricing_prog(pool_state, lst_state_list, custom_pricing_prog_factory)?
         };
         let mut res = Self::from_lst_state_list_account(
             program_id,
             lst_state_list_acc,
             lst_list,
             shared_current_epoch,
+            custom_pricing_prog_factory,
         )?;
         res.pool_state_account = Some(pool_state_acc);
         res.pricing_prog = Some(pricing_prog);
@@ -3,6 +3,7 @@ use jupiter_amm_interface::{
     AccountMap, Amm, AmmContext, ClockRef, KeyedAccount, Quote, QuoteParams, SwapAndAccountMetas,
     SwapParams,
 };
+use no_fee_pricing_program_keys::no_fee_pricing_program;
 use s_controller_lib::find_lst_state_list_address;
 use sanctum_lst_list::{
     lido_program, marinade_program, sanctum_spl_multi_stake_pool_program,
@@ -10,6 +11,7 @@ use sanctum_lst_list::{
 };
 use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
+use svsp_keys::svsp_program;
 
 use crate::SPoolJup;
 
@@ -55,7 +57,13 @@ impl Amm for SPoolJup {
             ));
         }
         let SanctumLstList { sanctum_lst_list } = SanctumLstList::load();
-        Self::from_lst_state_list_account(program_id, account.clone(), &sanctum_lst_list, epoch)
+        Self::from_lst_state_list_account(
+            program_id,
+            account.clone(),
+            &sanctum_lst_list,
+            epoch,
+            None,
+        )
     }
 
     fn label(&self) -> String {
@@ -145,16 +153,34 @@ impl Amm for SPoolJup {
                 lido_calculator_lib::program::ID,
                 "lido_calculator".to_owned(),
             ),
+            // SVSP
+            (svsp_program::ID, "svsp".to_owned()),
+            (
+                svsp_calculator_lib::program::ID,
+                "svsp_calculator".to_owned(),
+            ),
             // wSOL
             (
                 wsol_calculator_lib::program::ID,
                 "wsol_calculator".to_owned(),
             ),
             // pricing program
+            (
+                dynamic_fee_interface::ID,
+                "dynamic_fee_pricing_program".to_owned(),
+            ),
             (
                 flat_fee_interface::ID,
                 "flat_fee_pricing_program".to_owned(),
             ),
+            (
+                no_fee_pricing_program::ID,
+                "no_fee_pricing_program".to_owned(),
+            ),
+            (
+                tiered_fee_interface::ID,
+                "tiered_fee_pricing_program".to_owned(),
+            ),
         ]
     }
 }
@@ -1,12 +1,17 @@
 use anyhow::anyhow;
 use s_controller_interface::LstState;
 use s_controller_lib::{try_lst_state_list, try_pool_state};
-use s_pricing_prog_aggregate::KnownPricingProg;
+use s_pricing_prog_aggregate::{CustomPricingProgFactory, KnownPricingProg};
 use s_sol_val_calc_prog_aggregate::KnownLstSolValCalc;
 use sanctum_associated_token_lib::{CreateAtaAddressArgs, FindAtaAddressArgs};
 use solana_program::pubkey::{Pubkey, PubkeyError};
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::account::Account;
+use spl_token_2022::extension::transfer_fee::{TransferFee, TransferFeeConfig};
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
 
 pub(crate) mod utils;
 
@@ -15,7 +20,7 @@ mod init;
 mod jup_interface;
 mod update;
 
-pub use core::apply_sync_sol_value;
+pub use core::{apply_sync_sol_value, resolve_native_sol_alias, NATIVE_SOL_MINT_ALIAS};
 pub use init::*;
 pub use jup_interface::*;
 
@@ -24,6 +29,19 @@ pub struct LstData {
     pub sol_val_calc: KnownLstSolValCalc,
     pub reserves_balance: Option<u64>,
     pub token_program: Pubkey,
+    /// TransferFeeConfig extension of the LST's mint, fetched on update.
+    /// Always None for tokenkeg LSTs
+    pub transfer_fee_config: Option<TransferFeeConfig>,
+    pub shared_current_epoch: Arc<AtomicU64>,
+}
+
+impl LstData {
+    /// Returns the transfer fee charged on transfers of this LST this epoch, if any
+    pub fn current_transfer_fee(&self) -> Option<TransferFee> {
+        self.transfer_fee_config
+            .as_ref()
+            .map(|config| *config.get_epoch_fee(self.shared_current_epoch.load(Ordering::Relaxed)))
+    }
 }
 
 /// Convenience type alias for jupiter
@@ -39,6 +57,8 @@ pub struct SPool<S, L> {
     pub pool_state_account: Option<S>,
     pub lst_state_list_account: L,
     pub pricing_prog: Option<KnownPricingProg>,
+    /// Creates the pricing program if it is not one known to the aggregate
+    pub custom_pricing_prog_factory: Option<CustomPricingProgFactory>,
     // indices match that of lst_state_list.
     // None means we don't know how to handle the given lst
     // this could be due to incomplete data or unknown LST sol value calculator program
@@ -54,6 +74,7 @@ impl<S, L: Default> Default for SPool<S, L> {
             lp_mint_supply: None,
             pool_state_account: None,
             pricing_prog: None,
+            custom_pricing_prog_factory: None,
             lst_state_list_account: L::default(),
             lst_data_list: Vec::new(),
         }
@@ -4,6 +4,7 @@ use s_controller_interface::LstState;
 use s_controller_lib::{try_lst_state_list, try_pool_state};
 use s_pricing_prog_aggregate::MutablePricingProg;
 use s_sol_val_calc_prog_aggregate::{LstSolValCalc, MutableLstSolValCalc};
+use sanctum_s_common::token::mint_transfer_fee_config;
 use sanctum_token_lib::{mint_supply, token_account_balance};
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::pubkey::Pubkey;
@@ -96,6 +97,10 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
         if let Ok(ata) = self.pool_reserves_account(lst_state, lst_data) {
             res.push(ata);
         }
+        // only Token-2022 mints can have the TransferFeeConfig extension
+        if lst_data.token_program == spl_token_2022::ID {
+            res.push(lst_state.mint);
+        }
         res
     }
 
@@ -152,12 +157,25 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
                 let lst_state_list_acc_data = self.lst_state_list_account.data();
                 let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
                 let ata_res = self.pool_reserves_account(&lst_state_list[i], ld);
+                let lst_mint = lst_state_list[i].mint;
                 let ld = match &mut self.lst_data_list[i] {
                     Some(l) => l,
                     None => return Ok(()),
                 };
                 let r = ld.sol_val_calc.update(account_map);
-                r.and(ata_res.map_or_else(
+                let mint_res = match account_map.get(&lst_mint) {
+                    Some(fetched) if ld.token_program == spl_token_2022::ID => {
+                        mint_transfer_fee_config(&fetched.data()).map_or_else(
+                            |e| Err(e.into()),
+                            |config| {
+                                ld.transfer_fee_config = config;
+                                Ok(())
+                            },
+                        )
+                    }
+                    _ => Ok(()),
+                };
+                r.and(mint_res).and(ata_res.map_or_else(
                     |e| Err(e.into()),
                     |ata| {
                         if let Some(fetched) = account_map.get(&ata) {
@@ -299,12 +317,16 @@ impl<S: ReadonlyAccountData + Clone, L: ReadonlyAccountData> SPool<S, L> {
                 if should_reinitialize_pricing_program {
                     // None if unable to initialize new_pricing_prog, with error captured
                     // for return later
-                    let new_pricing_prog = try_pricing_prog(new_pool_state, lst_state_list)
-                        .map(|mut pp| {
-                            r = pp.update(account_map);
-                            pp
-                        })
-                        .ok();
+                    let new_pricing_prog = try_pricing_prog(
+                        new_pool_state,
+                        lst_state_list,
+                        self.custom_pricing_prog_factory,
+                    )
+                    .map(|mut pp| {
+                        r = pp.update(account_map);
+                        pp
+                    })
+                    .ok();
                     self.pricing_prog = new_pricing_prog;
                 }
                 self.pool_state_account = Some(new_pool_state_acc.clone());
@@ -1,7 +1,7 @@
 use std::sync::{atomic::AtomicU64, Arc};
 
 use s_controller_interface::{LstState, PoolState};
-use s_pricing_prog_aggregate::{KnownPricingProg, MutablePricingProg};
+use s_pricing_prog_aggregate::{CustomPricingProgFactory, KnownPricingProg};
 use s_sol_val_calc_prog_aggregate::{
     KnownLstSolValCalc, LidoLstSolValCalc, LstSolValCalc, MarinadeLstSolValCalc,
     SanctumSplLstSolValCalc, SanctumSplMultiLstSolValCalc, SplLstSolValCalc,
@@ -14,10 +14,12 @@ use crate::LstData;
 pub fn try_pricing_prog(
     pool_state: &PoolState,
     lst_state_list: &[LstState],
+    custom_pricing_prog_factory: Option<CustomPricingProgFactory>,
 ) -> anyhow::Result<KnownPricingProg> {
-    Ok(KnownPricingProg::try_new(
+    Ok(KnownPricingProg::try_new_with_custom(
         pool_state.pricing_program,
         lst_state_list.iter().map(|LstState { mint, .. }| *mint),
+        custom_pricing_prog_factory,
     )?)
 }
 
@@ -36,7 +38,9 @@ pub fn try_lst_data(
         ..
     } = lst_list.iter().find(|s| s.mint == *mint)?;
     let calc = match pool {
-        PoolInfo::Lido => KnownLstSolValCalc::Lido(LidoLstSolValCalc::new(shared_current_epoch)),
+        PoolInfo::Lido => {
+            KnownLstSolValCalc::Lido(LidoLstSolValCalc::new(Arc::clone(&shared_current_epoch)))
+        }
         PoolInfo::Marinade => KnownLstSolValCalc::Marinade(MarinadeLstSolValCalc::default()),
         PoolInfo::ReservePool => KnownLstSolValCalc::Wsol(WsolLstSolValCalc),
         PoolInfo::SanctumSpl(SplPoolAccounts { pool, .. }) => {
@@ -45,7 +49,7 @@ pub fn try_lst_data(
                     lst_mint: *mint,
                     stake_pool_addr: *pool,
                 },
-                shared_current_epoch,
+                Arc::clone(&shared_current_epoch),
             ))
         }
         PoolInfo::Spl(SplPoolAccounts { pool, .. }) => {
@@ -54,7 +58,7 @@ pub fn try_lst_data(
                     lst_mint: *mint,
                     stake_pool_addr: *pool,
                 },
-                shared_current_epoch,
+                Arc::clone(&shared_current_epoch),
             ))
         }
         PoolInfo::SanctumSplMulti(SplPoolAccounts { pool, .. }) => {
@@ -63,9 +67,11 @@ pub fn try_lst_data(
                     lst_mint: *mint,
                     stake_pool_addr: *pool,
                 },
-                shared_current_epoch,
+                Arc::clone(&shared_current_epoch),
             ))
         }
+        // SLpLstSolValCalc requires SyncSolValue instructions for every LST
+        // of the source pool earlier in the same tx, which jup routes do not include
         PoolInfo::SPool(_) => None?,
     };
     if *sol_value_calculator != calc.sol_value_calculator_program_id() {
@@ -75,6 +81,8 @@ pub fn try_lst_data(
             sol_val_calc: calc,
             reserves_balance: None,
             token_program: *token_program,
+            transfer_fee_config: None,
+            shared_current_epoch,
         })
     }
 }
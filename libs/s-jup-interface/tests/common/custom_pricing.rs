@@ -0,0 +1,111 @@
+use pricing_programs_interface::{
+    PriceExactInIxArgs, PriceExactInKeys, PriceExactOutIxArgs, PriceExactOutKeys,
+    PriceLpTokensToMintIxArgs, PriceLpTokensToMintKeys, PriceLpTokensToRedeemIxArgs,
+    PriceLpTokensToRedeemKeys,
+};
+use s_pricing_prog_aggregate::{
+    CustomPricingProg, MutablePricingProg, NoFeePricingProg, PricingProg, PricingProgErr,
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+use std::collections::HashMap;
+
+/// A pricing program not known to the aggregate that is priced like the no-fee pricing program,
+/// for testing custom pricing program support
+#[derive(Clone, Debug)]
+pub struct CustomNoFeePricingProg(NoFeePricingProg);
+
+/// [`s_pricing_prog_aggregate::CustomPricingProgFactory`] for [`CustomNoFeePricingProg`]
+pub fn custom_no_fee_pricing_prog(
+    program_id: Pubkey,
+    mints: &[Pubkey],
+) -> Result<Box<dyn CustomPricingProg>, PricingProgErr> {
+    Ok(Box::new(CustomNoFeePricingProg(NoFeePricingProg::try_new(
+        program_id,
+        mints.iter().copied(),
+    )?)))
+}
+
+impl CustomPricingProg for CustomNoFeePricingProg {
+    fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
+        Vec::new()
+    }
+
+    fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
+        Vec::new()
+    }
+
+    fn get_accounts_to_update_for_lsts(&self, _lst_mints: &[Pubkey]) -> Vec<Pubkey> {
+        Vec::new()
+    }
+
+    fn update(&mut self, _account_map: &HashMap<Pubkey, &[u8]>) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Box<dyn CustomPricingProg> {
+        Box::new(self.clone())
+    }
+}
+
+impl PricingProg for CustomNoFeePricingProg {
+    fn pricing_program_id(&self) -> Pubkey {
+        self.0.pricing_program_id()
+    }
+
+    fn quote_lp_tokens_to_redeem(
+        &self,
+        output_lst_mint: Pubkey,
+        args: &PriceLpTokensToRedeemIxArgs,
+    ) -> anyhow::Result<u64> {
+        self.0.quote_lp_tokens_to_redeem(output_lst_mint, args)
+    }
+
+    fn price_lp_tokens_to_redeem_accounts(
+        &self,
+        keys: PriceLpTokensToRedeemKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        self.0.price_lp_tokens_to_redeem_accounts(keys)
+    }
+
+    fn quote_lp_tokens_to_mint(
+        &self,
+        input_lst_mint: Pubkey,
+        args: &PriceLpTokensToMintIxArgs,
+    ) -> anyhow::Result<u64> {
+        self.0.quote_lp_tokens_to_mint(input_lst_mint, args)
+    }
+
+    fn price_lp_tokens_to_mint_accounts(
+        &self,
+        keys: PriceLpTokensToMintKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        self.0.price_lp_tokens_to_mint_accounts(keys)
+    }
+
+    fn quote_exact_in(
+        &self,
+        keys: PriceExactInKeys,
+        args: &PriceExactInIxArgs,
+    ) -> anyhow::Result<u64> {
+        self.0.quote_exact_in(keys, args)
+    }
+
+    fn price_exact_in_accounts(&self, keys: PriceExactInKeys) -> anyhow::Result<Vec<AccountMeta>> {
+        self.0.price_exact_in_accounts(keys)
+    }
+
+    fn quote_exact_out(
+        &self,
+        keys: PriceExactOutKeys,
+        args: &PriceExactOutIxArgs,
+    ) -> anyhow::Result<u64> {
+        self.0.quote_exact_out(keys, args)
+    }
+
+    fn price_exact_out_accounts(
+        &self,
+        keys: PriceExactOutKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        self.0.price_exact_out_accounts(keys)
+    }
+}
@@ -1,5 +1,6 @@
+use s_controller_test_utils::mock_token_account;
 use sanctum_associated_token_lib::FindAtaAddressArgs;
-use sanctum_solana_test_utils::token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs};
+use sanctum_solana_test_utils::token::MockTokenAccountArgs;
 use sanctum_token_lib::MintWithTokenProgram;
 use solana_program_test::{processor, ProgramTest};
 use solana_sdk::pubkey::Pubkey;
@@ -14,7 +15,7 @@ pub trait MiscProgramTest {
 
 impl MiscProgramTest for ProgramTest {
     fn add_ata(
-        self,
+        mut self,
         wallet: Pubkey,
         MintWithTokenProgram {
             pubkey: mint,
@@ -29,15 +30,18 @@ impl MiscProgramTest for ProgramTest {
         }
         .find_ata_address()
         .0;
-        // TODO: handle token-22, no LSTs use that yet
-        self.add_tokenkeg_account_from_args(
+        self.add_account(
             ata_addr,
-            MockTokenAccountArgs {
-                mint,
-                authority: wallet,
-                amount,
-            },
-        )
+            mock_token_account(
+                token_program,
+                MockTokenAccountArgs {
+                    mint,
+                    authority: wallet,
+                    amount,
+                },
+            ),
+        );
+        self
     }
 
     fn add_s_program(mut self) -> Self {
@@ -1,7 +1,9 @@
+mod custom_pricing;
 mod misc;
 mod quote_swap;
 mod update;
 
+pub use custom_pricing::*;
 pub use misc::*;
 pub use quote_swap::*;
 pub use update::*;
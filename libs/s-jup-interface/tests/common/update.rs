@@ -1,10 +1,15 @@
 use async_trait::async_trait;
 use jupiter_amm_interface::{Amm, AmmContext, ClockRef, KeyedAccount};
 use s_jup_interface::{SPoolInitKeys, SPoolJup};
+use s_pricing_prog_aggregate::CustomPricingProgFactory;
+use sanctum_lst_list::SanctumLstList;
 use sanctum_solana_test_utils::ExtendedBanksClient;
 use solana_program_test::BanksClient;
 use solana_sdk::{clock::Clock, pubkey::Pubkey, sysvar::clock};
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{atomic::AtomicU64, Arc},
+};
 
 pub async fn fully_init_amm(bc: &mut BanksClient, program_id: Pubkey) -> SPoolJup {
     let SPoolInitKeys { lst_state_list, .. } = SPoolJup::init_keys(program_id);
@@ -25,6 +30,27 @@ pub async fn fully_init_amm(bc: &mut BanksClient, program_id: Pubkey) -> SPoolJu
     .await
 }
 
+pub async fn fully_init_amm_with_custom_pricing_prog(
+    bc: &mut BanksClient,
+    program_id: Pubkey,
+    custom_pricing_prog_factory: CustomPricingProgFactory,
+) -> SPoolJup {
+    let SPoolInitKeys { lst_state_list, .. } = SPoolJup::init_keys(program_id);
+    let lst_state_list_acc = bc.get_account_unwrapped(lst_state_list).await;
+    let clock: Clock = bincode::deserialize(&bc.get_account_data(clock::ID).await).unwrap();
+    let SanctumLstList { sanctum_lst_list } = SanctumLstList::load();
+    SPoolJup::from_lst_state_list_account(
+        program_id,
+        lst_state_list_acc,
+        &sanctum_lst_list,
+        &Arc::new(AtomicU64::new(clock.epoch)),
+        Some(custom_pricing_prog_factory),
+    )
+    .unwrap()
+    .initial_update(bc)
+    .await
+}
+
 #[async_trait]
 pub trait UpdatingSPoolJup {
     async fn update_with_banks(self, bc: &mut BanksClient) -> Self;
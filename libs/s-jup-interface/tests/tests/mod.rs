@@ -2,6 +2,7 @@
 //! TODO: test lst state list changed
 
 mod add_liquidity;
+mod native_sol;
 mod remove_liquidity;
 mod swap_exact_in;
 mod swap_exact_out;
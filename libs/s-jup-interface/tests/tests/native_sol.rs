@@ -0,0 +1,568 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode, SwapParams};
+use lido_keys::stsol;
+use s_controller_lib::native_sol_wsol_acc;
+use s_controller_test_utils::{
+    lido_wsol_flat_fee_program_test, LidoWsolProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::{resolve_native_sol_alias, SPoolJup, NATIVE_SOL_MINT_ALIAS};
+use sanctum_associated_token_lib::FindAtaAddressArgs;
+use sanctum_solana_test_utils::{ExtendedBanksClient, ExtendedProgramTest};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    clock::Clock, instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+use spl_token::native_mint;
+use test_utils::LIDO_STATE_LAST_UPDATE_EPOCH;
+
+use crate::{fully_init_amm, MiscProgramTest};
+
+const AMT: u64 = 1_000_000_000;
+
+/// Upper bound on the lamports the wallet spends on tx fees
+const MAX_TX_FEES: u64 = 1_000_000;
+
+const WALLET_STARTING_LAMPORTS: u64 = 10_000_000_000;
+
+fn lido_wsol_native_sol_program_test(wallet: Pubkey, lp_token_mint: Pubkey) -> ProgramTest {
+    lido_wsol_flat_fee_program_test(
+        LidoWsolProgramTestArgs {
+            wsol_reserves: 10_000_000_000,
+            stsol_sol_value: 10_000_000_000,
+            stsol_reserves: 10_000_000_000,
+            wsol_protocol_fee_accumulator: 0,
+            stsol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: 20_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 0,
+            lp_deposit_fee_bps: 0,
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
+                lst_mint: stsol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
+                lst_mint: native_mint::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program()
+    .add_system_account(wallet, WALLET_STARTING_LAMPORTS)
+}
+
+async fn start_native_sol_program_test(pt: ProgramTest) -> (BanksClient, SPoolJup) {
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: LIDO_STATE_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    (bc, s)
+}
+
+fn tokenkeg_ata(wallet: Pubkey, mint: Pubkey) -> Pubkey {
+    FindAtaAddressArgs {
+        wallet,
+        mint,
+        token_program: spl_token::ID,
+    }
+    .find_ata_address()
+    .0
+}
+
+struct NativeSolSwapArgs {
+    in_amount: u64,
+    out_amount: u64,
+    input_mint: Pubkey,
+    output_mint: Pubkey,
+    swap_mode: SwapMode,
+}
+
+fn native_sol_swap_ixs(
+    s: &SPoolJup,
+    wallet: Pubkey,
+    NativeSolSwapArgs {
+        in_amount,
+        out_amount,
+        input_mint,
+        output_mint,
+        swap_mode,
+    }: NativeSolSwapArgs,
+) -> anyhow::Result<Vec<Instruction>> {
+    s.swap_ixs(
+        &SwapParams {
+            in_amount,
+            out_amount,
+            source_mint: input_mint,
+            destination_mint: output_mint,
+            // ignored for native SOL
+            source_token_account: tokenkeg_ata(wallet, input_mint),
+            destination_token_account: tokenkeg_ata(wallet, output_mint),
+            token_transfer_authority: wallet,
+            open_order_address: None,
+            quote_mint_to_referrer: None,
+            jupiter_program_id: &Pubkey::default(),
+            missing_dynamic_accounts_as_default: false,
+        },
+        swap_mode,
+    )
+}
+
+/// Processes `ixs` signed and paid for by `wallet`,
+/// returning the change in its system account's lamports
+async fn process_native_sol_ixs(
+    bc: &mut BanksClient,
+    wallet: &Keypair,
+    ixs: &[Instruction],
+) -> i128 {
+    let mut tx = Transaction::new_with_payer(ixs, Some(&wallet.pubkey()));
+    let last_blockhash = bc.get_latest_blockhash().await.unwrap();
+    tx.sign(&[wallet], last_blockhash);
+
+    let lamports_before = bc.get_account_unwrapped(wallet.pubkey()).await.lamports;
+    bc.process_transaction(tx).await.unwrap();
+    let lamports_after = bc.get_account_unwrapped(wallet.pubkey()).await.lamports;
+
+    // temporary wSOL account must always be closed
+    assert!(bc
+        .get_account(native_sol_wsol_acc(&wallet.pubkey()).unwrap())
+        .await
+        .unwrap()
+        .is_none());
+
+    i128::from(lamports_after) - i128::from(lamports_before)
+}
+
+/// Asserts the wallet spent exactly `expected` lamports, in addition to tx fees
+fn assert_lamports_spent(lamports_change: i128, expected: u64) {
+    let spent = -lamports_change;
+    assert!(spent >= i128::from(expected));
+    assert!(spent < i128::from(expected + MAX_TX_FEES));
+}
+
+/// Asserts the wallet received exactly `expected` lamports, less tx fees
+fn assert_lamports_received(lamports_change: i128, expected: u64) {
+    assert!(lamports_change <= i128::from(expected));
+    assert!(lamports_change > i128::from(expected) - i128::from(MAX_TX_FEES));
+}
+
+async fn token_balance(bc: &mut BanksClient, token_acc: Pubkey) -> u64 {
+    token_account_balance(bc.get_account_unwrapped(token_acc).await).unwrap()
+}
+
+#[tokio::test]
+async fn swap_exact_in_native_sol_to_stsol() {
+    let wallet = Keypair::new();
+    let pt = lido_wsol_native_sol_program_test(wallet.pubkey(), Pubkey::new_unique()).add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: stsol::ID,
+            token_program: spl_token::ID,
+        },
+        0,
+    );
+    let (mut bc, s) = start_native_sol_program_test(pt).await;
+
+    let quote_params = QuoteParams {
+        amount: AMT,
+        input_mint: NATIVE_SOL_MINT_ALIAS,
+        output_mint: stsol::ID,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let quote = s.quote(&quote_params).unwrap();
+    // alias must quote the same as wSOL
+    let wsol_quote = s
+        .quote(&QuoteParams {
+            input_mint: native_mint::ID,
+            ..quote_params.clone()
+        })
+        .unwrap();
+    assert_eq!(quote.in_amount, wsol_quote.in_amount);
+    assert_eq!(quote.out_amount, wsol_quote.out_amount);
+
+    let ixs = native_sol_swap_ixs(
+        &s,
+        wallet.pubkey(),
+        NativeSolSwapArgs {
+            in_amount: quote.in_amount,
+            out_amount: quote.out_amount,
+            input_mint: quote_params.input_mint,
+            output_mint: quote_params.output_mint,
+            swap_mode: quote_params.swap_mode,
+        },
+    )
+    .unwrap();
+    let lamports_change = process_native_sol_ixs(&mut bc, &wallet, &ixs).await;
+
+    let stsol_out = token_balance(&mut bc, tokenkeg_ata(wallet.pubkey(), stsol::ID)).await;
+    assert_eq!(stsol_out, quote.out_amount);
+    assert_lamports_spent(lamports_change, AMT);
+}
+
+#[tokio::test]
+async fn swap_exact_in_stsol_to_native_sol() {
+    let wallet = Keypair::new();
+    let pt = lido_wsol_native_sol_program_test(wallet.pubkey(), Pubkey::new_unique()).add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: stsol::ID,
+            token_program: spl_token::ID,
+        },
+        AMT,
+    );
+    let (mut bc, s) = start_native_sol_program_test(pt).await;
+
+    let quote_params = QuoteParams {
+        amount: AMT,
+        input_mint: stsol::ID,
+        output_mint: NATIVE_SOL_MINT_ALIAS,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let quote = s.quote(&quote_params).unwrap();
+    // alias must quote the same as wSOL
+    let wsol_quote = s
+        .quote(&QuoteParams {
+            output_mint: native_mint::ID,
+            ..quote_params.clone()
+        })
+        .unwrap();
+    assert_eq!(quote.in_amount, wsol_quote.in_amount);
+    assert_eq!(quote.out_amount, wsol_quote.out_amount);
+
+    let ixs = native_sol_swap_ixs(
+        &s,
+        wallet.pubkey(),
+        NativeSolSwapArgs {
+            in_amount: quote.in_amount,
+            out_amount: quote.out_amount,
+            input_mint: quote_params.input_mint,
+            output_mint: quote_params.output_mint,
+            swap_mode: quote_params.swap_mode,
+        },
+    )
+    .unwrap();
+    let lamports_change = process_native_sol_ixs(&mut bc, &wallet, &ixs).await;
+
+    let stsol_left = token_balance(&mut bc, tokenkeg_ata(wallet.pubkey(), stsol::ID)).await;
+    assert_eq!(stsol_left, 0);
+    // wSOL output unwrapped into the system account
+    assert_lamports_received(lamports_change, quote.out_amount);
+}
+
+#[tokio::test]
+async fn swap_exact_out_native_sol_to_stsol() {
+    // wrapped on top of the quoted input amount and must be unwrapped back
+    const MAX_IN_SLACK: u64 = 100_000_000;
+
+    let wallet = Keypair::new();
+    let pt = lido_wsol_native_sol_program_test(wallet.pubkey(), Pubkey::new_unique()).add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: stsol::ID,
+            token_program: spl_token::ID,
+        },
+        0,
+    );
+    let (mut bc, s) = start_native_sol_program_test(pt).await;
+
+    let quote_params = QuoteParams {
+        amount: AMT,
+        input_mint: NATIVE_SOL_MINT_ALIAS,
+        output_mint: stsol::ID,
+        swap_mode: SwapMode::ExactOut,
+    };
+    let quote = s.quote(&quote_params).unwrap();
+    assert_eq!(quote.out_amount, AMT);
+
+    let ixs = native_sol_swap_ixs(
+        &s,
+        wallet.pubkey(),
+        NativeSolSwapArgs {
+            in_amount: quote.in_amount + MAX_IN_SLACK,
+            out_amount: quote.out_amount,
+            input_mint: quote_params.input_mint,
+            output_mint: quote_params.output_mint,
+            swap_mode: quote_params.swap_mode,
+        },
+    )
+    .unwrap();
+    let lamports_change = process_native_sol_ixs(&mut bc, &wallet, &ixs).await;
+
+    let stsol_out = token_balance(&mut bc, tokenkeg_ata(wallet.pubkey(), stsol::ID)).await;
+    assert_eq!(stsol_out, AMT);
+    // unused max input unwrapped back
+    assert_lamports_spent(lamports_change, quote.in_amount);
+}
+
+#[tokio::test]
+async fn add_liquidity_native_sol() {
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let pt = lido_wsol_native_sol_program_test(wallet.pubkey(), lp_token_mint).add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: lp_token_mint,
+            token_program: spl_token::ID,
+        },
+        0,
+    );
+    let (mut bc, s) = start_native_sol_program_test(pt).await;
+
+    let quote_params = QuoteParams {
+        amount: AMT,
+        input_mint: NATIVE_SOL_MINT_ALIAS,
+        output_mint: lp_token_mint,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let quote = s.quote(&quote_params).unwrap();
+    // alias must quote the same as wSOL
+    let wsol_quote = s
+        .quote(&QuoteParams {
+            input_mint: resolve_native_sol_alias(NATIVE_SOL_MINT_ALIAS),
+            ..quote_params.clone()
+        })
+        .unwrap();
+    assert_eq!(quote.in_amount, wsol_quote.in_amount);
+    assert_eq!(quote.out_amount, wsol_quote.out_amount);
+
+    let ixs = native_sol_swap_ixs(
+        &s,
+        wallet.pubkey(),
+        NativeSolSwapArgs {
+            in_amount: quote.in_amount,
+            out_amount: quote.out_amount,
+            input_mint: quote_params.input_mint,
+            output_mint: quote_params.output_mint,
+            swap_mode: quote_params.swap_mode,
+        },
+    )
+    .unwrap();
+    let lamports_change = process_native_sol_ixs(&mut bc, &wallet, &ixs).await;
+
+    let lp_out = token_balance(&mut bc, tokenkeg_ata(wallet.pubkey(), lp_token_mint)).await;
+    assert_eq!(lp_out, quote.out_amount);
+    assert_lamports_spent(lamports_change, AMT);
+}
+
+#[tokio::test]
+async fn remove_liquidity_native_sol() {
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let pt = lido_wsol_native_sol_program_test(wallet.pubkey(), lp_token_mint).add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: lp_token_mint,
+            token_program: spl_token::ID,
+        },
+        AMT,
+    );
+    let (mut bc, s) = start_native_sol_program_test(pt).await;
+
+    let quote_params = QuoteParams {
+        amount: AMT,
+        input_mint: lp_token_mint,
+        output_mint: NATIVE_SOL_MINT_ALIAS,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let quote = s.quote(&quote_params).unwrap();
+    // alias must quote the same as wSOL
+    let wsol_quote = s
+        .quote(&QuoteParams {
+            output_mint: resolve_native_sol_alias(NATIVE_SOL_MINT_ALIAS),
+            ..quote_params.clone()
+        })
+        .unwrap();
+    assert_eq!(quote.in_amount, wsol_quote.in_amount);
+    assert_eq!(quote.out_amount, wsol_quote.out_amount);
+
+    let ixs = native_sol_swap_ixs(
+        &s,
+        wallet.pubkey(),
+        NativeSolSwapArgs {
+            in_amount: quote.in_amount,
+            out_amount: quote.out_amount,
+            input_mint: quote_params.input_mint,
+            output_mint: quote_params.output_mint,
+            swap_mode: quote_params.swap_mode,
+        },
+    )
+    .unwrap();
+    let lamports_change = process_native_sol_ixs(&mut bc, &wallet, &ixs).await;
+
+    let lp_left = token_balance(&mut bc, tokenkeg_ata(wallet.pubkey(), lp_token_mint)).await;
+    assert_eq!(lp_left, 0);
+    // wSOL output unwrapped into the system account
+    assert_lamports_received(lamports_change, quote.out_amount);
+}
+
+#[tokio::test]
+async fn native_sol_alias_both_sides_rejected() {
+    let wallet = Keypair::new();
+    let pt = lido_wsol_native_sol_program_test(wallet.pubkey(), Pubkey::new_unique());
+    let (_bc, s) = start_native_sol_program_test(pt).await;
+
+    for swap_mode in [SwapMode::ExactIn, SwapMode::ExactOut] {
+        let res = native_sol_swap_ixs(
+            &s,
+            wallet.pubkey(),
+            NativeSolSwapArgs {
+                in_amount: AMT,
+                out_amount: AMT,
+                input_mint: NATIVE_SOL_MINT_ALIAS,
+                output_mint: NATIVE_SOL_MINT_ALIAS,
+                swap_mode,
+            },
+        );
+        assert!(res.is_err());
+    }
+}
+
+#[tokio::test]
+async fn native_sol_leaves_wsol_ata_untouched() {
+    let wallet = Keypair::new();
+    let pt = lido_wsol_native_sol_program_test(wallet.pubkey(), Pubkey::new_unique())
+        .add_ata(
+            wallet.pubkey(),
+            MintWithTokenProgram {
+                pubkey: stsol::ID,
+                token_program: spl_token::ID,
+            },
+            AMT,
+        )
+        .add_ata(
+            wallet.pubkey(),
+            MintWithTokenProgram {
+                pubkey: native_mint::ID,
+                token_program: spl_token::ID,
+            },
+            AMT,
+        );
+    let (mut bc, s) = start_native_sol_program_test(pt).await;
+    let wsol_ata = tokenkeg_ata(wallet.pubkey(), native_mint::ID);
+
+    let quote_params = QuoteParams {
+        amount: AMT,
+        input_mint: stsol::ID,
+        output_mint: NATIVE_SOL_MINT_ALIAS,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let quote = s.quote(&quote_params).unwrap();
+    let ixs = native_sol_swap_ixs(
+        &s,
+        wallet.pubkey(),
+        NativeSolSwapArgs {
+            in_amount: quote.in_amount,
+            out_amount: quote.out_amount,
+            input_mint: quote_params.input_mint,
+            output_mint: quote_params.output_mint,
+            swap_mode: quote_params.swap_mode,
+        },
+    )
+    .unwrap();
+    let lamports_change = process_native_sol_ixs(&mut bc, &wallet, &ixs).await;
+
+    assert_lamports_received(lamports_change, quote.out_amount);
+    // existing wSOL is neither spent nor unwrapped
+    assert_eq!(token_balance(&mut bc, wsol_ata).await, AMT);
+}
+
+#[tokio::test]
+async fn native_sol_alias_single_ix_same_as_wsol() {
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let pt = lido_wsol_native_sol_program_test(wallet.pubkey(), lp_token_mint);
+    let (_bc, s) = start_native_sol_program_test(pt).await;
+    // the wSOL account jup wraps native SOL into
+    let jup_wsol_acc = Pubkey::new_unique();
+
+    for (input_mint, output_mint, swap_mode) in [
+        (NATIVE_SOL_MINT_ALIAS, stsol::ID, SwapMode::ExactIn),
+        (stsol::ID, NATIVE_SOL_MINT_ALIAS, SwapMode::ExactIn),
+        (NATIVE_SOL_MINT_ALIAS, stsol::ID, SwapMode::ExactOut),
+        (NATIVE_SOL_MINT_ALIAS, lp_token_mint, SwapMode::ExactIn),
+        (lp_token_mint, NATIVE_SOL_MINT_ALIAS, SwapMode::ExactIn),
+    ] {
+        let token_acc = |mint: Pubkey| {
+            if mint == NATIVE_SOL_MINT_ALIAS {
+                jup_wsol_acc
+            } else {
+                tokenkeg_ata(wallet.pubkey(), mint)
+            }
+        };
+        let in_amount = match swap_mode {
+            SwapMode::ExactIn => AMT,
+            // get_swap_and_account_metas() treats in_amount == 0 as ExactOut
+            SwapMode::ExactOut => 0,
+        };
+        let alias_params = SwapParams {
+            in_amount,
+            out_amount: AMT,
+            source_mint: input_mint,
+            destination_mint: output_mint,
+            source_token_account: token_acc(input_mint),
+            destination_token_account: token_acc(output_mint),
+            token_transfer_authority: wallet.pubkey(),
+            open_order_address: None,
+            quote_mint_to_referrer: None,
+            jupiter_program_id: &Pubkey::default(),
+            missing_dynamic_accounts_as_default: false,
+        };
+        let wsol_params = SwapParams {
+            source_mint: resolve_native_sol_alias(input_mint),
+            destination_mint: resolve_native_sol_alias(output_mint),
+            ..alias_params
+        };
+
+        assert_eq!(
+            s.swap_ix(&alias_params, swap_mode).unwrap(),
+            s.swap_ix(&wsol_params, swap_mode).unwrap()
+        );
+        assert_eq!(
+            s.get_swap_and_account_metas(&alias_params)
+                .unwrap()
+                .account_metas,
+            s.get_swap_and_account_metas(&wsol_params)
+                .unwrap()
+                .account_metas
+        );
+    }
+}
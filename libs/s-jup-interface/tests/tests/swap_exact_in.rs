@@ -2,15 +2,21 @@ use flat_fee_test_utils::MockFeeAccountArgs;
 use jupiter_amm_interface::{QuoteParams, SwapMode};
 use marinade_keys::msol;
 use s_controller_test_utils::{
-    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+    jito_marinade_base_program_test, jito_marinade_flat_fee_program_test,
+    jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs, MockPoolState,
+    MockProtocolFeeBps,
 };
-use sanctum_solana_test_utils::ExtendedProgramTest;
+use s_pricing_prog_aggregate::PricingProg;
+use sanctum_solana_test_utils::{ExtendedProgramTest, IntoAccount};
 use sanctum_token_lib::MintWithTokenProgram;
-use solana_program_test::ProgramTestContext;
+use solana_program_test::{processor, ProgramTestContext};
 use solana_sdk::{clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer};
 use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
 
-use crate::{assert_quote_swap_eq, fully_init_amm, MiscProgramTest};
+use crate::{
+    assert_quote_swap_eq, custom_no_fee_pricing_prog, fully_init_amm,
+    fully_init_amm_with_custom_pricing_prog, MiscProgramTest,
+};
 
 #[tokio::test]
 async fn swap_exact_in_jito_marinade_basic() {
@@ -32,16 +38,39 @@ async fn swap_exact_in_jito_marinade_basic() {
         flat_fee_interface::ProgramState {
             manager: Pubkey::default(),
             lp_withdrawal_fee_bps: 0,
+            lp_deposit_fee_bps: 0,
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         [
             MockFeeAccountArgs {
                 input_fee_bps: 4,
                 output_fee_bps: 4,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
                 lst_mint: jitosol::ID,
             },
             MockFeeAccountArgs {
                 input_fee_bps: 4,
                 output_fee_bps: 4,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
                 lst_mint: msol::ID,
             },
         ],
@@ -92,3 +121,143 @@ async fn swap_exact_in_jito_marinade_basic() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn swap_exact_in_jito_marinade_no_fee() {
+    const AMT: u64 = 1_000_000_000;
+
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let pt = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 10_000_000_000,
+        msol_sol_value: 10_000_000_000,
+        jitosol_reserves: 10_000_000_000,
+        msol_reserves: 10_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 0,
+    })
+    .add_s_program()
+    .add_system_account(wallet.pubkey(), 1_000_000_000)
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        AMT,
+    )
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        0,
+    );
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    assert_quote_swap_eq(
+        &mut bc,
+        &s,
+        &wallet,
+        &QuoteParams {
+            amount: AMT,
+            input_mint: jitosol::ID,
+            output_mint: msol::ID,
+            swap_mode: SwapMode::ExactIn,
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn swap_exact_in_jito_marinade_custom_pricing_prog() {
+    const AMT: u64 = 1_000_000_000;
+
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let custom_pricing_prog_id = Pubkey::new_unique();
+    let (mut pt, mut pool_state) = jito_marinade_base_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 10_000_000_000,
+        msol_sol_value: 10_000_000_000,
+        jitosol_reserves: 10_000_000_000,
+        msol_reserves: 10_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 0,
+    });
+    // a pricing program the aggregate does not know about
+    pt.add_program(
+        "no_fee_pricing_program",
+        custom_pricing_prog_id,
+        processor!(no_fee_pricing_program::process_instruction),
+    );
+    pool_state.pricing_program = custom_pricing_prog_id;
+    pt.add_account(
+        s_controller_lib::program::POOL_STATE_ID,
+        MockPoolState(pool_state).into_account(),
+    );
+    let pt = pt
+        .add_s_program()
+        .add_system_account(wallet.pubkey(), 1_000_000_000)
+        .add_ata(
+            wallet.pubkey(),
+            MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            AMT,
+        )
+        .add_ata(
+            wallet.pubkey(),
+            MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            0,
+        );
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let s = fully_init_amm_with_custom_pricing_prog(
+        &mut bc,
+        s_controller_lib::program::ID,
+        custom_no_fee_pricing_prog,
+    )
+    .await;
+    assert_eq!(
+        s.pricing_prog().unwrap().pricing_program_id(),
+        custom_pricing_prog_id
+    );
+    assert_quote_swap_eq(
+        &mut bc,
+        &s,
+        &wallet,
+        &QuoteParams {
+            amount: AMT,
+            input_mint: jitosol::ID,
+            output_mint: msol::ID,
+            swap_mode: SwapMode::ExactIn,
+        },
+    )
+    .await;
+}
@@ -1,8 +1,17 @@
 //! TODO: stuff in here should probably be moved to sanctum-token-lib
 
 use sanctum_token_lib::mint_supply;
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
-use spl_token_2022::extension::StateWithExtensions;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeConfig},
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::Mint,
+};
 
 pub fn verify_tokenkeg_or_22_mint(mint: &AccountInfo) -> Result<(), ProgramError> {
     if *mint.owner != spl_token::ID && *mint.owner != spl_token_2022::ID {
@@ -28,3 +37,55 @@ pub fn verify_token_account_authority(
     }
     Ok(())
 }
+
+/// Reads the TransferFeeConfig extension of a mint's account data.
+///
+/// Returns None for tokenkeg mints and Token-2022 mints without the extension.
+pub fn mint_transfer_fee_config(
+    mint_data: &[u8],
+) -> Result<Option<TransferFeeConfig>, ProgramError> {
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+    Ok(mint.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Returns the transfer fee that applies to transfers of `mint` this epoch,
+/// None if `mint` does not charge transfer fees
+pub fn current_epoch_transfer_fee(mint: &AccountInfo) -> Result<Option<TransferFee>, ProgramError> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+    let config = match mint_transfer_fee_config(&mint.try_borrow_data()?)? {
+        Some(c) => c,
+        None => return Ok(None),
+    };
+    let epoch = Clock::get()?.epoch;
+    Ok(Some(*config.get_epoch_fee(epoch)))
+}
+
+/// Returns the amount the destination token account receives
+/// when `amount` is transferred
+pub fn amount_after_transfer_fee(
+    transfer_fee: Option<&TransferFee>,
+    amount: u64,
+) -> Result<u64, ProgramError> {
+    match transfer_fee {
+        Some(fee) => fee
+            .calculate_post_fee_amount(amount)
+            .ok_or(ProgramError::ArithmeticOverflow),
+        None => Ok(amount),
+    }
+}
+
+/// Returns the amount that must be transferred
+/// for the destination token account to receive `amount`
+pub fn amount_before_transfer_fee(
+    transfer_fee: Option<&TransferFee>,
+    amount: u64,
+) -> Result<u64, ProgramError> {
+    match transfer_fee {
+        Some(fee) => fee
+            .calculate_pre_fee_amount(amount)
+            .ok_or(ProgramError::ArithmeticOverflow),
+        None => Ok(amount),
+    }
+}
@@ -0,0 +1,94 @@
+use borsh::BorshDeserialize;
+use everstake_calculator_interface::{AccountType, EverstakeStakePool};
+use generic_pool_calculator_interface::{GenericPoolCalculatorError, LST_TO_SOL_IX_ACCOUNTS_LEN};
+use generic_pool_calculator_lib::{
+    account_resolvers::{LstSolCommonIntermediateArgs, LstSolCommonIntermediateKeys},
+    GenericPoolSolValCalc,
+};
+use solana_program::instruction::AccountMeta;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::EverstakeSolValCalc;
+
+/// Deserializes an Everstake stake pool from account data,
+/// checking owner (if its owned by the Everstake stake pool program)
+/// and AccountType (if its an initialized stake pool)
+pub fn deserialize_everstake_stake_pool_checked<S: ReadonlyAccountData + ReadonlyAccountOwner>(
+    everstake_stake_pool: S,
+) -> Result<EverstakeStakePool, GenericPoolCalculatorError> {
+    if *everstake_stake_pool.owner() != EverstakeSolValCalc::POOL_PROGRAM_ID {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData);
+    }
+    deserialize_everstake_stake_pool(everstake_stake_pool)
+}
+
+/// Deserializes an Everstake stake pool from account data,
+/// checking AccountType (if its an initialized stake pool),
+/// but not owner (if its owned by the correct stake pool program)
+pub fn deserialize_everstake_stake_pool<D: ReadonlyAccountData>(
+    everstake_stake_pool: D,
+) -> Result<EverstakeStakePool, GenericPoolCalculatorError> {
+    let stake_pool = EverstakeStakePool::deserialize(&mut everstake_stake_pool.data().as_ref())
+        .map_err(|_e| GenericPoolCalculatorError::InvalidStakePoolProgramData)?;
+    if stake_pool.account_type != AccountType::StakePool {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData);
+    }
+    Ok(stake_pool)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EverstakeLstSolCommonFreeArgs<S, Q> {
+    pub everstake_stake_pool: S,
+    pub everstake_stake_pool_prog: Q,
+}
+
+impl<
+        S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+        Q: ReadonlyAccountPubkey + ReadonlyAccountData,
+    > EverstakeLstSolCommonFreeArgs<S, Q>
+{
+    pub fn resolve(
+        self,
+    ) -> Result<(LstSolCommonIntermediateArgs<Q>, EverstakeStakePool), GenericPoolCalculatorError>
+    {
+        if *self.everstake_stake_pool_prog.pubkey() != EverstakeSolValCalc::POOL_PROGRAM_ID {
+            return Err(GenericPoolCalculatorError::WrongPoolProgram);
+        }
+        let stake_pool = deserialize_everstake_stake_pool_checked(&self.everstake_stake_pool)?;
+        Ok((
+            LstSolCommonIntermediateArgs {
+                lst_mint: stake_pool.pool_mint,
+                pool_state: *self.everstake_stake_pool.pubkey(),
+                pool_program: self.everstake_stake_pool_prog,
+            },
+            stake_pool,
+        ))
+    }
+}
+
+/// Struct that uses defined const for POOL_PROGRAM_PROGDATA
+/// so that it can be used on client side without fetching POOL_PROGRAM
+#[derive(Clone, Copy, Debug)]
+pub struct EverstakeLstSolCommonFreeArgsConst<S> {
+    pub everstake_stake_pool: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner>
+    EverstakeLstSolCommonFreeArgsConst<S>
+{
+    pub fn resolve(self) -> Result<LstSolCommonIntermediateKeys, GenericPoolCalculatorError> {
+        let stake_pool = deserialize_everstake_stake_pool_checked(&self.everstake_stake_pool)?;
+        Ok(LstSolCommonIntermediateKeys {
+            lst_mint: stake_pool.pool_mint,
+            pool_state: *self.everstake_stake_pool.pubkey(),
+        })
+    }
+
+    pub fn resolve_to_account_metas(
+        self,
+    ) -> Result<[AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN], GenericPoolCalculatorError> {
+        let keys: generic_pool_calculator_interface::LstToSolKeys =
+            self.resolve()?.resolve::<EverstakeSolValCalc>().into();
+        Ok(keys.into())
+    }
+}
@@ -0,0 +1,32 @@
+use everstake_keys::{everstake_stake_pool_program, everstake_stake_pool_program_progdata};
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use solana_program::pubkey::Pubkey;
+
+mod account_resolvers;
+mod calc;
+mod instructions;
+
+pub use account_resolvers::*;
+pub use calc::*;
+pub use instructions::*;
+
+pub mod program {
+    sanctum_macros::declare_program_keys!(
+        "everRNFBzRdzmKcJRwoqd1rGjv2VAAoMBHimge48se3",
+        [("everstake_calculator_state", b"state")]
+    );
+}
+
+pub mod initial_manager {
+    sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
+}
+
+pub struct EverstakeSolValCalc;
+
+impl GenericPoolSolValCalc for EverstakeSolValCalc {
+    const POOL_PROGRAM_ID: Pubkey = everstake_stake_pool_program::ID;
+    const POOL_PROGRAM_PROGDATA_ID: Pubkey = everstake_stake_pool_program_progdata::ID;
+    const CALCULATOR_STATE_PDA: Pubkey = program::EVERSTAKE_CALCULATOR_STATE_ID;
+    const CALCULATOR_STATE_BUMP: u8 = program::EVERSTAKE_CALCULATOR_STATE_BUMP;
+    const ID: Pubkey = program::ID;
+}
@@ -0,0 +1,28 @@
+use generic_pool_calculator_interface::MigrateStateKeys;
+use solana_program::{pubkey::Pubkey, system_program};
+
+use crate::{pda::CalculatorStateFindPdaArgs, GenericPoolSolValCalc};
+
+pub struct MigrateStateFreeArgs {
+    pub payer: Pubkey,
+}
+
+impl MigrateStateFreeArgs {
+    pub fn resolve<P: GenericPoolSolValCalc>(self) -> MigrateStateKeys {
+        MigrateStateKeys {
+            payer: self.payer,
+            state: P::CALCULATOR_STATE_PDA,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> MigrateStateKeys {
+        MigrateStateKeys {
+            payer: self.payer,
+            state: CalculatorStateFindPdaArgs { program_id }
+                .get_calculator_state_address_and_bump_seed()
+                .0,
+            system_program: system_program::ID,
+        }
+    }
+}
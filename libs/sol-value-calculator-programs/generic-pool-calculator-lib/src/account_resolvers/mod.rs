@@ -1,9 +1,17 @@
 mod init;
 mod lst_sol_common;
+mod migrate_state;
+mod permissionless_update_last_upgrade_slot;
+mod set_approved_program_data_hash;
 mod set_manager;
+mod set_stale_discount;
 mod update_last_upgrade_slot;
 
 pub use init::*;
 pub use lst_sol_common::*;
+pub use migrate_state::*;
+pub use permissionless_update_last_upgrade_slot::*;
+pub use set_approved_program_data_hash::*;
 pub use set_manager::*;
+pub use set_stale_discount::*;
 pub use update_last_upgrade_slot::*;
@@ -0,0 +1,63 @@
+use generic_pool_calculator_interface::{
+    GenericPoolCalculatorError, PermissionlessUpdateLastUpgradeSlotKeys,
+};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{pda::CalculatorStateFindPdaArgs, utils::read_programdata_addr, GenericPoolSolValCalc};
+
+pub struct PermissionlessUpdateLastUpgradeSlotFreeArgs<
+    Q: ReadonlyAccountPubkey + ReadonlyAccountData,
+> {
+    pub pool_program: Q,
+}
+
+impl<Q: ReadonlyAccountPubkey + ReadonlyAccountData>
+    PermissionlessUpdateLastUpgradeSlotFreeArgs<Q>
+{
+    pub fn resolve<P: GenericPoolSolValCalc>(
+        self,
+    ) -> Result<PermissionlessUpdateLastUpgradeSlotKeys, GenericPoolCalculatorError> {
+        if *self.pool_program.pubkey() != P::POOL_PROGRAM_ID {
+            return Err(GenericPoolCalculatorError::WrongPoolProgram);
+        }
+        let pool_program_data = read_programdata_addr(&self.pool_program)?;
+        Ok(PermissionlessUpdateLastUpgradeSlotKeys {
+            state: P::CALCULATOR_STATE_PDA,
+            pool_program: P::POOL_PROGRAM_ID,
+            pool_program_data,
+        })
+    }
+
+    /// For use with calculator programs that do not have a [`GenericPoolSolValCalc`] impl.
+    /// Does not check that `pool_program` is the one the calculator program works for.
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<PermissionlessUpdateLastUpgradeSlotKeys, GenericPoolCalculatorError> {
+        let pool_program_data = read_programdata_addr(&self.pool_program)?;
+        Ok(PermissionlessUpdateLastUpgradeSlotKeys {
+            state: CalculatorStateFindPdaArgs { program_id }
+                .get_calculator_state_address_and_bump_seed()
+                .0,
+            pool_program: *self.pool_program.pubkey(),
+            pool_program_data,
+        })
+    }
+}
+
+/// Struct that uses defined const for POOL_PROGRAM_PROGDATA
+/// so that it can be used without fetching POOL_PROGRAM
+pub struct PermissionlessUpdateLastUpgradeSlotFreeArgsConst;
+
+impl PermissionlessUpdateLastUpgradeSlotFreeArgsConst {
+    pub const fn resolve<P: GenericPoolSolValCalc>(
+        self,
+    ) -> PermissionlessUpdateLastUpgradeSlotKeys {
+        PermissionlessUpdateLastUpgradeSlotKeys {
+            state: P::CALCULATOR_STATE_PDA,
+            pool_program: P::POOL_PROGRAM_ID,
+            pool_program_data: P::POOL_PROGRAM_PROGDATA_ID,
+        }
+    }
+}
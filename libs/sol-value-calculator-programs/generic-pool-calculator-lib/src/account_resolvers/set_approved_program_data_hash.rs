@@ -0,0 +1,44 @@
+use generic_pool_calculator_interface::{
+    GenericPoolCalculatorError, SetApprovedProgramDataHashKeys,
+};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{pda::CalculatorStateFindPdaArgs, utils::try_calculator_state, GenericPoolSolValCalc};
+
+pub struct SetApprovedProgramDataHashFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub state: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetApprovedProgramDataHashFreeArgs<S> {
+    pub fn resolve<P: GenericPoolSolValCalc>(
+        self,
+    ) -> Result<SetApprovedProgramDataHashKeys, GenericPoolCalculatorError> {
+        self.resolve_with_state_pda(P::CALCULATOR_STATE_PDA)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetApprovedProgramDataHashKeys, GenericPoolCalculatorError> {
+        let state_pda = CalculatorStateFindPdaArgs { program_id }
+            .get_calculator_state_address_and_bump_seed()
+            .0;
+        self.resolve_with_state_pda(state_pda)
+    }
+
+    fn resolve_with_state_pda(
+        self,
+        state_pda: Pubkey,
+    ) -> Result<SetApprovedProgramDataHashKeys, GenericPoolCalculatorError> {
+        if *self.state.pubkey() != state_pda {
+            return Err(GenericPoolCalculatorError::WrongCalculatorStatePda);
+        }
+        let bytes = &self.state.data();
+        let calc_state = try_calculator_state(bytes)?;
+        Ok(SetApprovedProgramDataHashKeys {
+            manager: calc_state.manager,
+            state: state_pda,
+        })
+    }
+}
@@ -14,7 +14,12 @@ pub const CALCULATOR_STATE_SEED: &[u8] = b"state";
 
 // std::mem::size_of is a const fn so we dont technically need this
 // but this assert helps guard against unexpected size changes
-pub const CALCULATOR_STATE_SIZE: usize = 40;
+pub const CALCULATOR_STATE_SIZE: usize = 80;
+
+/// Sizes of previous CalculatorState layouts that can be migrated to the current one:
+/// - 40: as first deployed, before `stale_discount_bps` was added
+/// - 48: before `approved_program_data_hash` was added
+pub const LEGACY_CALCULATOR_STATE_SIZES: [usize; 2] = [40, 48];
 const_assert_eq!(
     std::mem::size_of::<CalculatorState>(),
     CALCULATOR_STATE_SIZE
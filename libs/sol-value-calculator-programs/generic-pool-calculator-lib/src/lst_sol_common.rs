@@ -1,7 +1,8 @@
-//! Common types that unifies LstToSol and SolToLst Accounts and Keys
+//! Common types that unifies LstToSol, SolToLst and BatchCalc Accounts and Keys
 
 use generic_pool_calculator_interface::{
-    LstToSolAccounts, LstToSolKeys, SolToLstAccounts, SolToLstKeys,
+    BatchCalcAccounts, BatchCalcKeys, LstToSolAccounts, LstToSolKeys, SolToLstAccounts,
+    SolToLstKeys,
 };
 use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
@@ -58,6 +59,26 @@ impl<'me, 'info> From<LstSolCommonAccounts<'me, 'info>> for SolToLstAccounts<'me
     }
 }
 
+impl<'me, 'info> From<LstSolCommonAccounts<'me, 'info>> for BatchCalcAccounts<'me, 'info> {
+    fn from(
+        LstSolCommonAccounts {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+        }: LstSolCommonAccounts<'me, 'info>,
+    ) -> Self {
+        Self {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+        }
+    }
+}
+
 pub struct LstSolCommonKeys {
     ///The LST mint
     pub lst_mint: Pubkey,
@@ -110,3 +131,23 @@ impl From<LstSolCommonKeys> for SolToLstKeys {
         }
     }
 }
+
+impl From<LstSolCommonKeys> for BatchCalcKeys {
+    fn from(
+        LstSolCommonKeys {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+        }: LstSolCommonKeys,
+    ) -> Self {
+        Self {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+        }
+    }
+}
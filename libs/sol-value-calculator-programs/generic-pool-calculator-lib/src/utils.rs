@@ -1,6 +1,7 @@
 use bytemuck::{try_from_bytes, try_from_bytes_mut};
 use generic_pool_calculator_interface::{CalculatorState, GenericPoolCalculatorError};
-use solana_program::{bpf_loader_upgradeable::UpgradeableLoaderState, pubkey::Pubkey};
+use sol_value_calculator_lib::BPS_DENOM;
+use solana_program::{bpf_loader_upgradeable::UpgradeableLoaderState, hash::hash, pubkey::Pubkey};
 use solana_readonly_account::ReadonlyAccountData;
 
 /// Attempts to deserialize a program account and read the
@@ -44,6 +45,30 @@ pub fn read_stake_pool_progdata_meta<D: ReadonlyAccountData>(
     }
 }
 
+/// sha256 of a program's executable bytes with trailing zero bytes stripped.
+///
+/// Program data accounts are zero-padded past the end of the deployed executable,
+/// so stripping makes the hash of a deployed program match that of its `.so` file.
+pub fn executable_hash(executable: &[u8]) -> [u8; 32] {
+    let len = executable
+        .iter()
+        .rposition(|b| *b != 0)
+        .map_or(0, |last_nonzero| last_nonzero + 1);
+    hash(&executable[..len]).to_bytes()
+}
+
+/// Computes the [`executable_hash`] of the executable contained in
+/// a stake pool program's program data account
+pub fn read_stake_pool_progdata_hash<D: ReadonlyAccountData>(
+    stake_pool_prog_data_acc: D,
+) -> Result<[u8; 32], GenericPoolCalculatorError> {
+    let data = stake_pool_prog_data_acc.data();
+    let executable = data
+        .get(UpgradeableLoaderState::size_of_programdata_metadata()..)
+        .ok_or(GenericPoolCalculatorError::InvalidStakePoolProgramData)?;
+    Ok(executable_hash(executable))
+}
+
 /// Tries to reinterpret calculator_state_acc_data bytes as a CalculatorState
 pub fn try_calculator_state(
     calculator_state_acc_data: &[u8],
@@ -60,6 +85,28 @@ pub fn try_calculator_state_mut(
         .map_err(|_e| GenericPoolCalculatorError::InvalidCalculatorStateData)
 }
 
+/// Returns the discount in bips to apply when valuing an LST
+/// whose pool was last updated an epoch ago. 0 means stale pools are not valued.
+///
+/// NB: does not check pubkey of account input
+pub fn read_stale_discount_bps<S: ReadonlyAccountData>(
+    calculator_state: S,
+) -> Result<u16, GenericPoolCalculatorError> {
+    let calculator_state_acc_data = calculator_state.data();
+    let calculator_state = try_calculator_state(&calculator_state_acc_data)?;
+    Ok(calculator_state.stale_discount_bps)
+}
+
+pub fn verify_stale_discount_bps(
+    stale_discount_bps: u16,
+) -> Result<(), GenericPoolCalculatorError> {
+    if stale_discount_bps < BPS_DENOM {
+        Ok(())
+    } else {
+        Err(GenericPoolCalculatorError::InvalidStaleDiscount)
+    }
+}
+
 pub struct VerifyNoStakePoolProgUpgradeArgs<D: ReadonlyAccountData, S: ReadonlyAccountData> {
     pub stake_pool_prog_data: D,
     pub calculator_state: S,
@@ -0,0 +1,64 @@
+use generic_pool_calculator_interface::{
+    migrate_state_verify_account_keys, migrate_state_verify_account_privileges,
+    GenericPoolCalculatorError, MigrateStateAccounts, MigrateStateKeys,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::MigrateStateFreeArgs, GenericPoolSolValCalc, CALCULATOR_STATE_SIZE,
+    LEGACY_CALCULATOR_STATE_SIZES,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::ResizableAccount;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use system_program_interface::{transfer_invoke, TransferAccounts, TransferIxArgs};
+
+/// Call on resolved and checked MigrateStateAccounts.
+///
+/// Grows a CalculatorState of any legacy layout to its current size,
+/// zero-initializing the new fields
+pub fn process_migrate_state_unchecked(
+    MigrateStateAccounts {
+        payer,
+        state,
+        system_program: _,
+    }: MigrateStateAccounts,
+) -> Result<(), ProgramError> {
+    let lamports_short = state.extend_by(CALCULATOR_STATE_SIZE - state.data_len())?;
+
+    if lamports_short > 0 {
+        transfer_invoke(
+            TransferAccounts {
+                from: payer,
+                to: state,
+            },
+            TransferIxArgs {
+                lamports: lamports_short,
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn verify_migrate_state<'me, 'info, P: GenericPoolSolValCalc>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<MigrateStateAccounts<'me, 'info>, ProgramError> {
+    let actual: MigrateStateAccounts = load_accounts(accounts)?;
+
+    let root_keys = MigrateStateFreeArgs {
+        payer: *actual.payer.key,
+    };
+    let expected: MigrateStateKeys = root_keys.resolve::<P>();
+
+    migrate_state_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    migrate_state_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    if *actual.state.owner != P::ID
+        || !LEGACY_CALCULATOR_STATE_SIZES.contains(&actual.state.data_len())
+    {
+        return Err(GenericPoolCalculatorError::StateNotMigratable.into());
+    }
+
+    Ok(actual)
+}
@@ -1,7 +1,15 @@
 mod init;
+mod migrate_state;
+mod permissionless_update_last_upgrade_slot;
+mod set_approved_program_data_hash;
 mod set_manager;
+mod set_stale_discount;
 mod update_last_upgrade_slot;
 
 pub use init::*;
+pub use migrate_state::*;
+pub use permissionless_update_last_upgrade_slot::*;
+pub use set_approved_program_data_hash::*;
 pub use set_manager::*;
+pub use set_stale_discount::*;
 pub use update_last_upgrade_slot::*;
@@ -0,0 +1,59 @@
+use generic_pool_calculator_interface::{
+    permissionless_update_last_upgrade_slot_verify_account_keys,
+    permissionless_update_last_upgrade_slot_verify_account_privileges, GenericPoolCalculatorError,
+    PermissionlessUpdateLastUpgradeSlotAccounts, PermissionlessUpdateLastUpgradeSlotKeys,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::PermissionlessUpdateLastUpgradeSlotFreeArgs,
+    utils::{
+        read_stake_pool_progdata_hash, read_stake_pool_progdata_meta, try_calculator_state,
+        try_calculator_state_mut,
+    },
+    GenericPoolSolValCalc,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+/// Call on resolved and checked PermissionlessUpdateLastUpgradeSlotAccounts
+pub fn process_permissionless_update_last_upgrade_slot_unchecked(
+    PermissionlessUpdateLastUpgradeSlotAccounts {
+        state,
+        pool_program: _,
+        pool_program_data,
+    }: PermissionlessUpdateLastUpgradeSlotAccounts,
+) -> Result<(), ProgramError> {
+    let (last_upgrade_slot, _upgrade_auth) = read_stake_pool_progdata_meta(pool_program_data)?;
+    let mut bytes = state.try_borrow_mut_data()?;
+    let calc_state = try_calculator_state_mut(&mut bytes)?;
+    calc_state.last_upgrade_slot = last_upgrade_slot;
+    Ok(())
+}
+
+pub fn verify_permissionless_update_last_upgrade_slot<'me, 'info, P: GenericPoolSolValCalc>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<PermissionlessUpdateLastUpgradeSlotAccounts<'me, 'info>, ProgramError> {
+    let actual: PermissionlessUpdateLastUpgradeSlotAccounts = load_accounts(accounts)?;
+
+    let root_keys = PermissionlessUpdateLastUpgradeSlotFreeArgs {
+        pool_program: actual.pool_program,
+    };
+    let expected: PermissionlessUpdateLastUpgradeSlotKeys = root_keys.resolve::<P>()?;
+
+    permissionless_update_last_upgrade_slot_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    permissionless_update_last_upgrade_slot_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let state_bytes = actual.state.try_borrow_data()?;
+    let calc_state = try_calculator_state(&state_bytes)?;
+    // an unset (all zeros) approved_program_data_hash never matches any executable's hash
+    if read_stake_pool_progdata_hash(actual.pool_program_data)?
+        != calc_state.approved_program_data_hash
+    {
+        return Err(GenericPoolCalculatorError::ProgramDataHashMismatch.into());
+    }
+
+    Ok(actual)
+}
@@ -0,0 +1,42 @@
+use generic_pool_calculator_interface::{
+    set_approved_program_data_hash_verify_account_keys,
+    set_approved_program_data_hash_verify_account_privileges, SetApprovedProgramDataHashAccounts,
+    SetApprovedProgramDataHashKeys,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::SetApprovedProgramDataHashFreeArgs, utils::try_calculator_state_mut,
+    GenericPoolSolValCalc,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+/// Call on resolved and checked SetApprovedProgramDataHashAccounts
+pub fn process_set_approved_program_data_hash_unchecked(
+    SetApprovedProgramDataHashAccounts { manager: _, state }: SetApprovedProgramDataHashAccounts,
+    approved_program_data_hash: [u8; 32],
+) -> Result<(), ProgramError> {
+    let mut bytes = state.try_borrow_mut_data()?;
+    let calc_state = try_calculator_state_mut(&mut bytes)?;
+    calc_state.approved_program_data_hash = approved_program_data_hash;
+    Ok(())
+}
+
+pub fn verify_set_approved_program_data_hash<'me, 'info, P: GenericPoolSolValCalc>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<SetApprovedProgramDataHashAccounts<'me, 'info>, ProgramError> {
+    let actual: SetApprovedProgramDataHashAccounts = load_accounts(accounts)?;
+
+    let root_keys = SetApprovedProgramDataHashFreeArgs {
+        state: actual.state,
+    };
+    let expected: SetApprovedProgramDataHashKeys = root_keys.resolve::<P>()?;
+
+    set_approved_program_data_hash_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_approved_program_data_hash_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,45 @@
+use generic_pool_calculator_interface::{
+    set_stale_discount_verify_account_keys, set_stale_discount_verify_account_privileges,
+    SetStaleDiscountAccounts, SetStaleDiscountKeys,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::SetStaleDiscountFreeArgs,
+    utils::{try_calculator_state_mut, verify_stale_discount_bps},
+    GenericPoolSolValCalc,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+/// Call on resolved and checked SetStaleDiscountAccounts
+pub fn process_set_stale_discount_unchecked(
+    SetStaleDiscountAccounts { manager: _, state }: SetStaleDiscountAccounts,
+    stale_discount_bps: u16,
+) -> Result<(), ProgramError> {
+    let mut bytes = state.try_borrow_mut_data()?;
+    let calc_state = try_calculator_state_mut(&mut bytes)?;
+    calc_state.stale_discount_bps = stale_discount_bps;
+    Ok(())
+}
+
+pub fn verify_set_stale_discount<'me, 'info, P: GenericPoolSolValCalc>(
+    accounts: &'me [AccountInfo<'info>],
+    stale_discount_bps: u16,
+) -> Result<SetStaleDiscountAccounts<'me, 'info>, ProgramError> {
+    let actual: SetStaleDiscountAccounts = load_accounts(accounts)?;
+
+    let root_keys = SetStaleDiscountFreeArgs {
+        state: actual.state,
+    };
+    let expected: SetStaleDiscountKeys = root_keys.resolve::<P>()?;
+
+    set_stale_discount_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_stale_discount_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_stale_discount_bps(stale_discount_bps)?;
+
+    Ok(actual)
+}
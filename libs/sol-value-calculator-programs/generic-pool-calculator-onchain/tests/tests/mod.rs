@@ -1,3 +1,7 @@
 mod process_init;
+mod process_migrate_state;
+mod process_permissionless_update_last_upgrade_slot;
+mod process_set_approved_program_data_hash;
 mod process_set_manager;
+mod process_set_stale_discount;
 mod process_update_last_upgrade_slot;
@@ -0,0 +1,144 @@
+use generic_pool_calculator_interface::{migrate_state_ix, GenericPoolCalculatorError};
+use generic_pool_calculator_lib::{
+    account_resolvers::MigrateStateFreeArgs, utils::try_calculator_state, CALCULATOR_STATE_SIZE,
+    LEGACY_CALCULATOR_STATE_SIZES,
+};
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use sanctum_solana_test_utils::{assert_custom_err, est_rent_exempt_lamports, ExtendedBanksClient};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{account::Account, signer::Signer, transaction::Transaction};
+
+use mock_calculator_program::MockCalculatorProgram;
+
+mod mock_calculator_program {
+    use generic_pool_calculator_lib::GenericPoolSolValCalc;
+    use generic_pool_calculator_onchain::processor::{
+        process_migrate_state_unchecked, verify_migrate_state,
+    };
+    use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+    use spl_stake_pool_keys::{spl_stake_pool_program, spl_stake_pool_program_progdata};
+
+    sanctum_macros::declare_program_keys!(
+        "8kbLzKfKo5gjbGQf2HmULGGTXQx6hnfYGJ8inL1zvVeL",
+        [("state", b"state")]
+    );
+
+    pub struct MockCalculatorProgram;
+
+    impl GenericPoolSolValCalc for MockCalculatorProgram {
+        const POOL_PROGRAM_ID: Pubkey = spl_stake_pool_program::ID;
+        const POOL_PROGRAM_PROGDATA_ID: Pubkey = spl_stake_pool_program_progdata::ID;
+        const CALCULATOR_STATE_PDA: Pubkey = STATE_ID;
+        const CALCULATOR_STATE_BUMP: u8 = STATE_BUMP;
+        const ID: Pubkey = ID;
+    }
+
+    pub fn process_instruction(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        _instruction_data: &[u8],
+    ) -> ProgramResult {
+        let checked = verify_migrate_state::<MockCalculatorProgram>(accounts)?;
+        process_migrate_state_unchecked(checked)
+    }
+}
+
+fn mock_prog_program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.prefer_bpf(false);
+    program_test.add_program(
+        "mock_calculator_program",
+        mock_calculator_program::ID,
+        processor!(mock_calculator_program::process_instruction),
+    );
+    program_test
+}
+
+const MANAGER: Pubkey = Pubkey::new_from_array([1; 32]);
+const LAST_UPGRADE_SLOT: u64 = 69;
+
+/// Migrates a legacy CalculatorState of size `legacy_size`
+/// whose `stale_discount_bps` field, if present, is `stale_discount_bps`
+async fn migrate_legacy_state_and_verify(legacy_size: usize, stale_discount_bps: u16) {
+    let mut legacy_data = vec![0u8; legacy_size];
+    legacy_data[..32].copy_from_slice(MANAGER.as_ref());
+    legacy_data[32..40].copy_from_slice(&LAST_UPGRADE_SLOT.to_le_bytes());
+    if legacy_size > 40 {
+        legacy_data[40..42].copy_from_slice(&stale_discount_bps.to_le_bytes());
+    }
+
+    let mut program_test = mock_prog_program_test();
+    program_test.add_account(
+        mock_calculator_program::STATE_ID,
+        Account {
+            lamports: est_rent_exempt_lamports(legacy_size),
+            data: legacy_data,
+            owner: mock_calculator_program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let mut ix = migrate_state_ix(
+        MigrateStateFreeArgs {
+            payer: payer.pubkey(),
+        }
+        .resolve::<MockCalculatorProgram>(),
+    )
+    .unwrap();
+    ix.program_id = mock_calculator_program::ID;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_account = banks_client
+        .get_account_unwrapped(mock_calculator_program::STATE_ID)
+        .await;
+    assert_eq!(state_account.data.len(), CALCULATOR_STATE_SIZE);
+    assert!(state_account.lamports >= est_rent_exempt_lamports(CALCULATOR_STATE_SIZE));
+    let calc_state = try_calculator_state(&state_account.data).unwrap();
+    assert_eq!(calc_state.manager, MANAGER);
+    assert_eq!(calc_state.last_upgrade_slot, LAST_UPGRADE_SLOT);
+    assert_eq!(calc_state.stale_discount_bps, stale_discount_bps);
+    assert_eq!(calc_state.approved_program_data_hash, [0; 32]);
+}
+
+#[tokio::test]
+async fn migrate_state_first_deployed_layout() {
+    migrate_legacy_state_and_verify(LEGACY_CALCULATOR_STATE_SIZES[0], 0).await;
+}
+
+#[tokio::test]
+async fn migrate_state_stale_discount_layout() {
+    migrate_legacy_state_and_verify(LEGACY_CALCULATOR_STATE_SIZES[1], 50).await;
+}
+
+#[tokio::test]
+async fn fail_migrate_state_already_migrated() {
+    let program_test =
+        mock_prog_program_test().add_mock_calculator_state(MockCalculatorStateAccountArgs {
+            manager: Pubkey::new_unique(),
+            last_upgrade_slot: Default::default(),
+            stale_discount_bps: Default::default(),
+            approved_program_data_hash: Default::default(),
+            owner: mock_calculator_program::ID,
+        });
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let mut ix = migrate_state_ix(
+        MigrateStateFreeArgs {
+            payer: payer.pubkey(),
+        }
+        .resolve::<MockCalculatorProgram>(),
+    )
+    .unwrap();
+    ix.program_id = mock_calculator_program::ID;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], recent_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, GenericPoolCalculatorError::StateNotMigratable);
+}
@@ -0,0 +1,144 @@
+use generic_pool_calculator_interface::{
+    permissionless_update_last_upgrade_slot_ix, GenericPoolCalculatorError,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::PermissionlessUpdateLastUpgradeSlotFreeArgsConst,
+    utils::{read_stake_pool_progdata_hash, try_calculator_state},
+};
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, ExtendedBanksClient, ExtendedProgramTest, KeyedUiAccount,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, BanksClient, BanksClientError, ProgramTest};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use mock_calculator_program::MockCalculatorProgram;
+use test_utils::SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT;
+
+mod mock_calculator_program {
+    use generic_pool_calculator_lib::GenericPoolSolValCalc;
+    use generic_pool_calculator_onchain::processor::{
+        process_permissionless_update_last_upgrade_slot_unchecked,
+        verify_permissionless_update_last_upgrade_slot,
+    };
+    use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+    use spl_stake_pool_keys::{spl_stake_pool_program, spl_stake_pool_program_progdata};
+
+    sanctum_macros::declare_program_keys!(
+        "8kbLzKfKo5gjbGQf2HmULGGTXQx6hnfYGJ8inL1zvVeL",
+        [("state", b"state")]
+    );
+
+    pub struct MockCalculatorProgram;
+
+    impl GenericPoolSolValCalc for MockCalculatorProgram {
+        const POOL_PROGRAM_ID: Pubkey = spl_stake_pool_program::ID;
+        const POOL_PROGRAM_PROGDATA_ID: Pubkey = spl_stake_pool_program_progdata::ID;
+        const CALCULATOR_STATE_PDA: Pubkey = STATE_ID;
+        const CALCULATOR_STATE_BUMP: u8 = STATE_BUMP;
+        const ID: Pubkey = ID;
+    }
+
+    pub fn process_instruction(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        _instruction_data: &[u8],
+    ) -> ProgramResult {
+        let checked =
+            verify_permissionless_update_last_upgrade_slot::<MockCalculatorProgram>(accounts)?;
+        process_permissionless_update_last_upgrade_slot_unchecked(checked)
+    }
+}
+
+const INITIAL_LAST_UPGRADE_SLOT: u64 = 69;
+
+fn spl_stake_pool_prog_data_hash() -> [u8; 32] {
+    let prog_data =
+        KeyedUiAccount::from_test_fixtures_file("spl-stake-pool-prog-data.json").to_keyed_account();
+    read_stake_pool_progdata_hash(&prog_data).unwrap()
+}
+
+fn mock_prog_program_test_with_spl(approved_program_data_hash: [u8; 32]) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.prefer_bpf(false);
+    program_test.add_program(
+        "mock_calculator_program",
+        mock_calculator_program::ID,
+        processor!(mock_calculator_program::process_instruction),
+    );
+    program_test = program_test
+        .add_test_fixtures_account("spl-stake-pool-prog.json")
+        .add_test_fixtures_account("spl-stake-pool-prog-data.json");
+
+    program_test.add_mock_calculator_state(MockCalculatorStateAccountArgs {
+        manager: Pubkey::new_unique(),
+        last_upgrade_slot: INITIAL_LAST_UPGRADE_SLOT,
+        stale_discount_bps: Default::default(),
+        approved_program_data_hash,
+        owner: mock_calculator_program::ID,
+    })
+}
+
+async fn verify_last_upgrade_slot(banks_client: &mut BanksClient, expected_last_upgrade_slot: u64) {
+    let state_account = banks_client
+        .get_account_unwrapped(mock_calculator_program::STATE_ID)
+        .await;
+    let calc_state = try_calculator_state(&state_account.data).unwrap();
+    assert_eq!(calc_state.last_upgrade_slot, expected_last_upgrade_slot);
+}
+
+async fn exec_permissionless_update_last_upgrade_slot(
+    approved_program_data_hash: [u8; 32],
+) -> (BanksClient, Result<(), BanksClientError>) {
+    let program_test = mock_prog_program_test_with_spl(approved_program_data_hash);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    verify_last_upgrade_slot(&mut banks_client, INITIAL_LAST_UPGRADE_SLOT).await;
+
+    let mut ix = permissionless_update_last_upgrade_slot_ix(
+        PermissionlessUpdateLastUpgradeSlotFreeArgsConst.resolve::<MockCalculatorProgram>(),
+    )
+    .unwrap();
+    ix.program_id = mock_calculator_program::ID;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], recent_blockhash);
+    let res = banks_client.process_transaction(tx).await;
+    (banks_client, res)
+}
+
+#[tokio::test]
+async fn permissionless_update_last_upgrade_slot_approved_hash() {
+    let (mut banks_client, res) =
+        exec_permissionless_update_last_upgrade_slot(spl_stake_pool_prog_data_hash()).await;
+    res.unwrap();
+
+    verify_last_upgrade_slot(&mut banks_client, SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT).await;
+}
+
+#[tokio::test]
+async fn fail_permissionless_update_last_upgrade_slot_no_approved_hash() {
+    let (mut banks_client, res) = exec_permissionless_update_last_upgrade_slot([0; 32]).await;
+    assert_custom_err(
+        res.unwrap_err(),
+        GenericPoolCalculatorError::ProgramDataHashMismatch,
+    );
+
+    verify_last_upgrade_slot(&mut banks_client, INITIAL_LAST_UPGRADE_SLOT).await;
+}
+
+#[tokio::test]
+async fn fail_permissionless_update_last_upgrade_slot_wrong_hash() {
+    let mut wrong_hash = spl_stake_pool_prog_data_hash();
+    wrong_hash[0] ^= 1;
+    let (mut banks_client, res) = exec_permissionless_update_last_upgrade_slot(wrong_hash).await;
+    assert_custom_err(
+        res.unwrap_err(),
+        GenericPoolCalculatorError::ProgramDataHashMismatch,
+    );
+
+    verify_last_upgrade_slot(&mut banks_client, INITIAL_LAST_UPGRADE_SLOT).await;
+}
@@ -0,0 +1,151 @@
+use generic_pool_calculator_interface::{
+    set_approved_program_data_hash_ix, SetApprovedProgramDataHashIxArgs,
+    SetApprovedProgramDataHashKeys,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::SetApprovedProgramDataHashFreeArgs, utils::try_calculator_state,
+};
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use sanctum_solana_test_utils::{assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use mock_calculator_program::MockCalculatorProgram;
+
+mod mock_calculator_program {
+    use generic_pool_calculator_interface::{
+        GenericPoolCalculatorProgramIx, SetApprovedProgramDataHashIxArgs,
+    };
+    use generic_pool_calculator_lib::GenericPoolSolValCalc;
+    use generic_pool_calculator_onchain::processor::{
+        process_set_approved_program_data_hash_unchecked, verify_set_approved_program_data_hash,
+    };
+    use solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+    use spl_stake_pool_keys::{spl_stake_pool_program, spl_stake_pool_program_progdata};
+
+    sanctum_macros::declare_program_keys!(
+        "8kbLzKfKo5gjbGQf2HmULGGTXQx6hnfYGJ8inL1zvVeL",
+        [("state", b"state")]
+    );
+
+    pub struct MockCalculatorProgram;
+
+    impl GenericPoolSolValCalc for MockCalculatorProgram {
+        const POOL_PROGRAM_ID: Pubkey = spl_stake_pool_program::ID;
+        const POOL_PROGRAM_PROGDATA_ID: Pubkey = spl_stake_pool_program_progdata::ID;
+        const CALCULATOR_STATE_PDA: Pubkey = STATE_ID;
+        const CALCULATOR_STATE_BUMP: u8 = STATE_BUMP;
+        const ID: Pubkey = ID;
+    }
+
+    pub fn process_instruction(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        instruction_data: &[u8],
+    ) -> ProgramResult {
+        let approved_program_data_hash =
+            match GenericPoolCalculatorProgramIx::deserialize(instruction_data)? {
+                GenericPoolCalculatorProgramIx::SetApprovedProgramDataHash(
+                    SetApprovedProgramDataHashIxArgs {
+                        approved_program_data_hash,
+                    },
+                ) => approved_program_data_hash,
+                _ => return Err(ProgramError::InvalidInstructionData),
+            };
+        let checked = verify_set_approved_program_data_hash::<MockCalculatorProgram>(accounts)?;
+        process_set_approved_program_data_hash_unchecked(checked, approved_program_data_hash)
+    }
+}
+
+fn mock_prog_program_test(manager: Pubkey) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.prefer_bpf(false);
+    program_test.add_program(
+        "mock_calculator_program",
+        mock_calculator_program::ID,
+        processor!(mock_calculator_program::process_instruction),
+    );
+    program_test.add_mock_calculator_state(MockCalculatorStateAccountArgs {
+        manager,
+        last_upgrade_slot: Default::default(),
+        stale_discount_bps: Default::default(),
+        approved_program_data_hash: Default::default(),
+        owner: mock_calculator_program::ID,
+    })
+}
+
+async fn verify_approved_program_data_hash(banks_client: &mut BanksClient, expected: [u8; 32]) {
+    let state_account = banks_client
+        .get_account_unwrapped(mock_calculator_program::STATE_ID)
+        .await;
+    let calc_state = try_calculator_state(&state_account.data).unwrap();
+    assert_eq!(calc_state.approved_program_data_hash, expected);
+}
+
+#[tokio::test]
+async fn set_approved_program_data_hash_basic() {
+    const APPROVED_PROGRAM_DATA_HASH: [u8; 32] = [69; 32];
+
+    let manager = Keypair::new();
+
+    let program_test = mock_prog_program_test(manager.pubkey());
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let mock_state = banks_client
+        .get_account_unwrapped(mock_calculator_program::STATE_ID)
+        .await;
+    verify_approved_program_data_hash(&mut banks_client, [0; 32]).await;
+
+    let free_args = SetApprovedProgramDataHashFreeArgs {
+        state: KeyedAccount {
+            pubkey: mock_calculator_program::STATE_ID,
+            account: mock_state,
+        },
+    };
+    let mut ix = set_approved_program_data_hash_ix(
+        free_args.resolve::<MockCalculatorProgram>().unwrap(),
+        SetApprovedProgramDataHashIxArgs {
+            approved_program_data_hash: APPROVED_PROGRAM_DATA_HASH,
+        },
+    )
+    .unwrap();
+    ix.program_id = mock_calculator_program::ID;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], recent_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_approved_program_data_hash(&mut banks_client, APPROVED_PROGRAM_DATA_HASH).await;
+}
+
+#[tokio::test]
+async fn fail_set_approved_program_data_hash_unauthorized_manager() {
+    let manager = Pubkey::new_unique();
+
+    let program_test = mock_prog_program_test(manager);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let mut ix = set_approved_program_data_hash_ix(
+        SetApprovedProgramDataHashKeys {
+            manager: payer.pubkey(),
+            state: mock_calculator_program::STATE_ID,
+        },
+        SetApprovedProgramDataHashIxArgs {
+            approved_program_data_hash: [69; 32],
+        },
+    )
+    .unwrap();
+    ix.program_id = mock_calculator_program::ID;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], recent_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    verify_approved_program_data_hash(&mut banks_client, [0; 32]).await;
+}
@@ -57,6 +57,8 @@ fn mock_prog_program_test(manager: Pubkey) -> ProgramTest {
     program_test.add_mock_calculator_state(MockCalculatorStateAccountArgs {
         manager,
         last_upgrade_slot: Default::default(),
+        stale_discount_bps: Default::default(),
+        approved_program_data_hash: Default::default(),
         owner: mock_calculator_program::ID,
     })
 }
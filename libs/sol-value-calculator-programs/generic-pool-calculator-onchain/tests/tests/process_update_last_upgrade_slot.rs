@@ -68,6 +68,8 @@ fn mock_prog_program_test_with_spl(manager: Pubkey) -> ProgramTest {
     program_test.add_mock_calculator_state(MockCalculatorStateAccountArgs {
         manager,
         last_upgrade_slot: INITIAL_LAST_UPGRADE_SLOT,
+        stale_discount_bps: Default::default(),
+        approved_program_data_hash: Default::default(),
         owner: mock_calculator_program::ID,
     })
 }
@@ -8,6 +8,8 @@ use solana_sdk::{account::Account, pubkey::Pubkey};
 pub struct MockCalculatorStateAccountArgs {
     pub manager: Pubkey,
     pub last_upgrade_slot: u64,
+    pub stale_discount_bps: u16,
+    pub approved_program_data_hash: [u8; 32],
 
     /// GenericPoolCalculator program ID
     pub owner: Pubkey,
@@ -18,12 +20,16 @@ impl IntoAccount for MockCalculatorStateAccountArgs {
         let Self {
             manager,
             last_upgrade_slot,
+            stale_discount_bps,
+            approved_program_data_hash,
             owner,
         } = self;
         let mut data = vec![0u8; CALCULATOR_STATE_SIZE];
         let state = try_calculator_state_mut(&mut data).unwrap();
         state.manager = manager;
         state.last_upgrade_slot = last_upgrade_slot;
+        state.stale_discount_bps = stale_discount_bps;
+        state.approved_program_data_hash = approved_program_data_hash;
         Account {
             lamports: 1_000_000_000, // just do 1 SOL lol
             data,
@@ -1,6 +1,8 @@
 use lido_calculator_interface::{ExchangeRate, Lido, LidoCalculatorError};
 use sanctum_token_ratio::{FloorDiv, ReversibleRatio, U64Ratio, U64ValueRange};
-use sol_value_calculator_lib::SolValueCalculator;
+use sol_value_calculator_lib::{
+    stale_discount_bps_for_epoch, PoolNotUpdatedErr, SolValueCalculator, StaleDiscountCalc,
+};
 use solana_program::program_error::ProgramError;
 
 /// Parameters from Lido required to calculate SOL value.
@@ -63,6 +65,23 @@ impl LidoCalc {
         }
     }
 
+    /// Like [`Self::verify_pool_updated_for_this_epoch`], but also accepts a pool
+    /// last updated exactly one epoch ago if `stale_discount_bps` is nonzero,
+    /// returning a calculator that values it conservatively.
+    pub const fn verify_pool_updated_or_stale_bounded(
+        self,
+        this_epoch: u64,
+        stale_discount_bps: u16,
+    ) -> Result<StaleDiscountCalc<Self>, LidoCalculatorError> {
+        match stale_discount_bps_for_epoch(self.computed_in_epoch, this_epoch, stale_discount_bps) {
+            Some(stale_discount_bps) => Ok(StaleDiscountCalc {
+                calc: self,
+                stale_discount_bps,
+            }),
+            None => Err(LidoCalculatorError::ExchangeRateNotUpdatedInThisEpoch),
+        }
+    }
+
     pub const fn stlamports_to_lamports_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
         let Self {
             st_sol_supply,
@@ -94,6 +113,12 @@ impl SolValueCalculator for LidoCalc {
     }
 }
 
+impl PoolNotUpdatedErr for LidoCalc {
+    fn pool_not_updated_err() -> ProgramError {
+        LidoCalculatorError::ExchangeRateNotUpdatedInThisEpoch.into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
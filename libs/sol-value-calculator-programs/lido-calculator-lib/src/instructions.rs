@@ -36,6 +36,23 @@ pub fn lido_sol_to_lst_invoke_signed(
     sol_to_lst_invoke_signed_with_program_id(crate::program::ID, accounts, args, seeds)
 }
 
+pub fn lido_batch_calc_ix(
+    keys: BatchCalcKeys,
+    args: BatchCalcIxArgs,
+) -> std::io::Result<Instruction> {
+    batch_calc_ix_with_program_id(crate::program::ID, keys, args)
+}
+pub fn lido_batch_calc_invoke(accounts: BatchCalcAccounts, args: BatchCalcIxArgs) -> ProgramResult {
+    batch_calc_invoke_with_program_id(crate::program::ID, accounts, args)
+}
+pub fn lido_batch_calc_invoke_signed(
+    accounts: BatchCalcAccounts,
+    args: BatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    batch_calc_invoke_signed_with_program_id(crate::program::ID, accounts, args, seeds)
+}
+
 pub fn lido_update_last_upgrade_slot_ix(
     keys: UpdateLastUpgradeSlotKeys,
 ) -> std::io::Result<Instruction> {
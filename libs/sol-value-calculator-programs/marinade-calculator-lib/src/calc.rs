@@ -21,6 +21,13 @@ pub struct MarinadeStateCalc {
     pub msol_supply: u64,
     pub withdraw_stake_account_enabled: bool,
     pub withdraw_stake_account_fee_cents: u32,
+    pub delayed_unstake_fee_cents: u32,
+
+    /// Whether to value mSOL through the delayed unstake ticket path
+    /// when stake account withdrawal is disabled.
+    ///
+    /// Not part of MarinadeState, set by the calculator program's manager
+    pub delayed_unstake_fallback_enabled: bool,
 }
 
 impl From<&MarinadeState> for MarinadeStateCalc {
@@ -46,6 +53,10 @@ impl From<&MarinadeState> for MarinadeStateCalc {
                 FeeCents {
                     bp_cents: withdraw_stake_account_fee_cents,
                 },
+            delayed_unstake_fee:
+                FeeCents {
+                    bp_cents: delayed_unstake_fee_cents,
+                },
             ..
         }: &MarinadeState,
     ) -> Self {
@@ -59,6 +70,8 @@ impl From<&MarinadeState> for MarinadeStateCalc {
             msol_supply: *msol_supply,
             withdraw_stake_account_enabled: *withdraw_stake_account_enabled,
             withdraw_stake_account_fee_cents: *withdraw_stake_account_fee_cents,
+            delayed_unstake_fee_cents: *delayed_unstake_fee_cents,
+            delayed_unstake_fallback_enabled: false,
         }
     }
 }
@@ -72,6 +85,14 @@ impl From<MarinadeState> for MarinadeStateCalc {
 /// Reference
 /// https://github.com/marinade-finance/liquid-staking-program/blob/26147376b75d8c971963da458623e646f2795e15/programs/marinade-finance/src/state/mod.rs#L96
 impl MarinadeStateCalc {
+    pub const fn with_delayed_unstake_fallback(
+        mut self,
+        delayed_unstake_fallback_enabled: bool,
+    ) -> Self {
+        self.delayed_unstake_fallback_enabled = delayed_unstake_fallback_enabled;
+        self
+    }
+
     pub const fn verify_can_withdraw_stake(&self) -> Result<(), MarinadeCalculatorError> {
         if self.paused {
             Err(MarinadeCalculatorError::MarinadePaused)
@@ -82,6 +103,23 @@ impl MarinadeStateCalc {
         }
     }
 
+    /// Like [`Self::verify_can_withdraw_stake`], but also passes
+    /// if stake account withdrawal is disabled and the delayed unstake fallback is enabled
+    pub const fn verify_can_unstake(&self) -> Result<(), MarinadeCalculatorError> {
+        if self.paused {
+            Err(MarinadeCalculatorError::MarinadePaused)
+        } else if !self.withdraw_stake_account_enabled && !self.delayed_unstake_fallback_enabled {
+            Err(MarinadeCalculatorError::MarinadeStakeWithdrawDisabled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns true if mSOL should be valued through the delayed unstake ticket path
+    pub const fn is_delayed_unstake(&self) -> bool {
+        !self.withdraw_stake_account_enabled && self.delayed_unstake_fallback_enabled
+    }
+
     pub const fn total_cooling_down(&self) -> Option<u64> {
         self.delayed_unstake_cooling_down
             .checked_add(self.emergency_cooling_down)
@@ -112,15 +150,33 @@ impl MarinadeStateCalc {
         U64FeeRatio::try_from_fee_num_and_denom(self.withdraw_stake_account_fee_cents, MAX_BP_CENTS)
             .map(FloorDiv)
     }
+
+    pub fn delayed_unstake_fee(&self) -> Result<FloorDiv<U64FeeRatio<u32, u32>>, MathError> {
+        U64FeeRatio::try_from_fee_num_and_denom(self.delayed_unstake_fee_cents, MAX_BP_CENTS)
+            .map(FloorDiv)
+    }
+
+    /// The fee charged by the unstake path mSOL is valued through
+    pub fn unstake_fee(&self) -> Result<FloorDiv<U64FeeRatio<u32, u32>>, MathError> {
+        if self.is_delayed_unstake() {
+            self.delayed_unstake_fee()
+        } else {
+            self.withdraw_stake_account_fee()
+        }
+    }
 }
 
 impl SolValueCalculator for MarinadeStateCalc {
     // Reference:
     // https://github.com/marinade-finance/liquid-staking-program/blob/26147376b75d8c971963da458623e646f2795e15/programs/marinade-finance/src/instructions/user/withdraw_stake_account.rs#L166
+    //
+    // The delayed unstake path values mSOL at the same msol_to_sol ratio,
+    // with the resulting ticket's lamports taken at face value
+    // and delayed_unstake_fee charged instead of withdraw_stake_account_fee
     fn calc_lst_to_sol(&self, msol_amount: u64) -> Result<U64ValueRange, ProgramError> {
         let ratio = self.msol_to_sol_ratio().ok_or(MathError)?;
         let sol_value_of_msol_burned = ratio.apply(msol_amount)?;
-        let fee = self.withdraw_stake_account_fee()?;
+        let fee = self.unstake_fee()?;
         let aaf = fee.apply(sol_value_of_msol_burned)?;
         let lamports_for_user = aaf.amt_after_fee();
         Ok(U64ValueRange::single(lamports_for_user))
@@ -128,7 +184,7 @@ impl SolValueCalculator for MarinadeStateCalc {
 
     fn calc_sol_to_lst(&self, lamports_for_user: u64) -> Result<U64ValueRange, ProgramError> {
         let r = self
-            .withdraw_stake_account_fee()?
+            .unstake_fee()?
             .reverse_from_amt_after_fee(lamports_for_user)?;
         let ratio = self.msol_to_sol_ratio().ok_or(MathError)?;
         let min = ratio.reverse(r.get_min())?.get_min();
@@ -189,11 +245,13 @@ mod tests {
                 ) in total_lamports_under_control(),
                 circulating_ticket_balance: u64,
                 msol_supply: u64,
-                withdraw_stake_account_fee_cents in 0..=MAX_BP_CENTS
+                withdraw_stake_account_fee_cents in 0..=MAX_BP_CENTS,
+                delayed_unstake_fee_cents in 0..=MAX_BP_CENTS,
+                withdraw_stake_account_enabled: bool,
             ) -> MarinadeStateCalc {
                 MarinadeStateCalc {
                     paused: false,
-                    withdraw_stake_account_enabled: true,
+                    withdraw_stake_account_enabled,
                     delayed_unstake_cooling_down,
                     emergency_cooling_down,
                     total_active_balance,
@@ -201,6 +259,10 @@ mod tests {
                     circulating_ticket_balance,
                     msol_supply,
                     withdraw_stake_account_fee_cents,
+                    delayed_unstake_fee_cents,
+                    // only affects valuation if withdraw_stake_account_enabled = false,
+                    // so always enabled to test both unstake paths
+                    delayed_unstake_fallback_enabled: true,
                 }
             }
     }
@@ -239,4 +301,31 @@ mod tests {
             //test_utils::prop_assert_diff_at_most!(max_round_trip, sol_amt, 1_000);
         }
     }
+
+    proptest! {
+        #[test]
+        fn unstake_path_selection((msol_amount, calc) in marinade_state_and_lst_amount()) {
+            prop_assert!(calc.verify_can_unstake().is_ok());
+            let no_fallback = calc.with_delayed_unstake_fallback(false);
+            prop_assert_eq!(
+                no_fallback.verify_can_unstake().is_ok(),
+                calc.withdraw_stake_account_enabled
+            );
+
+            let expected_fee = if calc.withdraw_stake_account_enabled {
+                calc.withdraw_stake_account_fee_cents
+            } else {
+                calc.delayed_unstake_fee_cents
+            };
+            let expected_calc = MarinadeStateCalc {
+                withdraw_stake_account_enabled: true,
+                withdraw_stake_account_fee_cents: expected_fee,
+                ..calc
+            };
+            prop_assert_eq!(
+                calc.calc_lst_to_sol(msol_amount).unwrap().get_min(),
+                expected_calc.calc_lst_to_sol(msol_amount).unwrap().get_min()
+            );
+        }
+    }
 }
@@ -0,0 +1,33 @@
+use bytemuck::try_from_bytes;
+use marinade_calculator_interface::{MarinadeCalcConfig, MarinadeCalculatorError};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::program::{ID, MARINADE_CALCULATOR_CONFIG_ID};
+
+/// Tries to reinterpret marinade_calc_config_acc_data bytes as a MarinadeCalcConfig
+pub fn try_marinade_calc_config(
+    marinade_calc_config_acc_data: &[u8],
+) -> Result<&MarinadeCalcConfig, MarinadeCalculatorError> {
+    try_from_bytes(marinade_calc_config_acc_data)
+        .map_err(|_e| MarinadeCalculatorError::IncorrectCalcConfig)
+}
+
+/// Reads whether the delayed unstake fallback is enabled,
+/// returning false if the MarinadeCalcConfig PDA is not yet initialized.
+///
+/// Checks that `calc_config` is the MarinadeCalcConfig PDA either way.
+pub fn read_delayed_unstake_fallback<
+    C: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+>(
+    calc_config: C,
+) -> Result<bool, MarinadeCalculatorError> {
+    if *calc_config.pubkey() != MARINADE_CALCULATOR_CONFIG_ID {
+        return Err(MarinadeCalculatorError::IncorrectCalcConfig);
+    }
+    let data = calc_config.data();
+    if *calc_config.owner() != ID || data.is_empty() {
+        return Ok(false);
+    }
+    let config = try_marinade_calc_config(&data)?;
+    Ok(config.delayed_unstake_fallback != 0)
+}
@@ -1,45 +1,111 @@
 use generic_pool_calculator_interface::*;
-use solana_program::{entrypoint::ProgramResult, instruction::Instruction};
+use marinade_calculator_interface::{
+    set_delayed_unstake_fallback_invoke_signed_with_program_id,
+    set_delayed_unstake_fallback_invoke_with_program_id,
+    set_delayed_unstake_fallback_ix_with_program_id, SetDelayedUnstakeFallbackAccounts,
+    SetDelayedUnstakeFallbackIxArgs, SetDelayedUnstakeFallbackKeys,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+use crate::program::MARINADE_CALCULATOR_CONFIG_ID;
+
+/// The marinade calculator program expects the MarinadeCalcConfig PDA
+/// after the common LstToSol/SolToLst accounts
+fn ix_with_calc_config(mut ix: Instruction) -> Instruction {
+    ix.accounts.push(AccountMeta::new_readonly(
+        MARINADE_CALCULATOR_CONFIG_ID,
+        false,
+    ));
+    ix
+}
+
+fn invoke_signed_with_calc_config<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    calc_config: &AccountInfo<'info>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let mut account_infos = Vec::from(accounts.into());
+    account_infos.push(calc_config.clone());
+    invoke_signed(ix, &account_infos, seeds)
+}
 
 pub fn marinade_lst_to_sol_ix(
     keys: LstToSolKeys,
     args: LstToSolIxArgs,
 ) -> std::io::Result<Instruction> {
-    lst_to_sol_ix_with_program_id(crate::program::ID, keys, args)
+    let ix = lst_to_sol_ix_with_program_id(crate::program::ID, keys, args)?;
+    Ok(ix_with_calc_config(ix))
 }
 
-pub fn marinade_lst_to_sol_invoke(
-    accounts: LstToSolAccounts,
+pub fn marinade_lst_to_sol_invoke<'info>(
+    accounts: LstToSolAccounts<'_, 'info>,
+    calc_config: &AccountInfo<'info>,
     args: LstToSolIxArgs,
 ) -> ProgramResult {
-    lst_to_sol_invoke_with_program_id(crate::program::ID, accounts, args)
+    marinade_lst_to_sol_invoke_signed(accounts, calc_config, args, &[])
 }
-pub fn marinade_lst_to_sol_invoke_signed(
-    accounts: LstToSolAccounts,
+pub fn marinade_lst_to_sol_invoke_signed<'info>(
+    accounts: LstToSolAccounts<'_, 'info>,
+    calc_config: &AccountInfo<'info>,
     args: LstToSolIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    lst_to_sol_invoke_signed_with_program_id(crate::program::ID, accounts, args, seeds)
+    let ix = marinade_lst_to_sol_ix(accounts.into(), args)?;
+    invoke_signed_with_calc_config(&ix, accounts, calc_config, seeds)
 }
 
 pub fn marinade_sol_to_lst_ix(
     keys: SolToLstKeys,
     args: SolToLstIxArgs,
 ) -> std::io::Result<Instruction> {
-    sol_to_lst_ix_with_program_id(crate::program::ID, keys, args)
+    let ix = sol_to_lst_ix_with_program_id(crate::program::ID, keys, args)?;
+    Ok(ix_with_calc_config(ix))
 }
-pub fn marinade_sol_to_lst_invoke(
-    accounts: SolToLstAccounts,
+pub fn marinade_sol_to_lst_invoke<'info>(
+    accounts: SolToLstAccounts<'_, 'info>,
+    calc_config: &AccountInfo<'info>,
     args: SolToLstIxArgs,
 ) -> ProgramResult {
-    sol_to_lst_invoke_with_program_id(crate::program::ID, accounts, args)
+    marinade_sol_to_lst_invoke_signed(accounts, calc_config, args, &[])
 }
-pub fn marinade_sol_to_lst_invoke_signed(
-    accounts: SolToLstAccounts,
+pub fn marinade_sol_to_lst_invoke_signed<'info>(
+    accounts: SolToLstAccounts<'_, 'info>,
+    calc_config: &AccountInfo<'info>,
     args: SolToLstIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    sol_to_lst_invoke_signed_with_program_id(crate::program::ID, accounts, args, seeds)
+    let ix = marinade_sol_to_lst_ix(accounts.into(), args)?;
+    invoke_signed_with_calc_config(&ix, accounts, calc_config, seeds)
+}
+
+pub fn marinade_batch_calc_ix(
+    keys: BatchCalcKeys,
+    args: BatchCalcIxArgs,
+) -> std::io::Result<Instruction> {
+    let ix = batch_calc_ix_with_program_id(crate::program::ID, keys, args)?;
+    Ok(ix_with_calc_config(ix))
+}
+pub fn marinade_batch_calc_invoke<'info>(
+    accounts: BatchCalcAccounts<'_, 'info>,
+    calc_config: &AccountInfo<'info>,
+    args: BatchCalcIxArgs,
+) -> ProgramResult {
+    marinade_batch_calc_invoke_signed(accounts, calc_config, args, &[])
+}
+pub fn marinade_batch_calc_invoke_signed<'info>(
+    accounts: BatchCalcAccounts<'_, 'info>,
+    calc_config: &AccountInfo<'info>,
+    args: BatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = marinade_batch_calc_ix(accounts.into(), args)?;
+    invoke_signed_with_calc_config(&ix, accounts, calc_config, seeds)
 }
 
 pub fn marinade_update_last_upgrade_slot_ix(
@@ -81,3 +147,28 @@ pub fn marinade_init_invoke(accounts: InitAccounts) -> ProgramResult {
 pub fn marinade_init_invoke_signed(accounts: InitAccounts, seeds: &[&[&[u8]]]) -> ProgramResult {
     init_invoke_signed_with_program_id(crate::program::ID, accounts, seeds)
 }
+
+pub fn marinade_set_delayed_unstake_fallback_ix(
+    keys: SetDelayedUnstakeFallbackKeys,
+    args: SetDelayedUnstakeFallbackIxArgs,
+) -> std::io::Result<Instruction> {
+    set_delayed_unstake_fallback_ix_with_program_id(crate::program::ID, keys, args)
+}
+pub fn marinade_set_delayed_unstake_fallback_invoke(
+    accounts: SetDelayedUnstakeFallbackAccounts,
+    args: SetDelayedUnstakeFallbackIxArgs,
+) -> ProgramResult {
+    set_delayed_unstake_fallback_invoke_with_program_id(crate::program::ID, accounts, args)
+}
+pub fn marinade_set_delayed_unstake_fallback_invoke_signed(
+    accounts: SetDelayedUnstakeFallbackAccounts,
+    args: SetDelayedUnstakeFallbackIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_delayed_unstake_fallback_invoke_signed_with_program_id(
+        crate::program::ID,
+        accounts,
+        args,
+        seeds,
+    )
+}
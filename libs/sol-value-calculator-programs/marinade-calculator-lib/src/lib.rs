@@ -3,16 +3,29 @@ use marinade_keys::{marinade_program, marinade_program_progdata};
 use solana_program::pubkey::Pubkey;
 
 mod calc;
+mod calc_config;
 mod instructions;
 
 pub use account_resolvers::*;
 pub use calc::*;
+pub use calc_config::*;
 pub use instructions::*;
 
+// std::mem::size_of is a const fn so we dont technically need this
+// but this assert helps guard against unexpected size changes
+pub const MARINADE_CALC_CONFIG_SIZE: usize = 1;
+static_assertions::const_assert_eq!(
+    std::mem::size_of::<marinade_calculator_interface::MarinadeCalcConfig>(),
+    MARINADE_CALC_CONFIG_SIZE
+);
+
 pub mod program {
     sanctum_macros::declare_program_keys!(
         "mare3SCyfZkAndpBRBeonETmkCCB3TJTTrz8ZN2dnhP",
-        [("marinade_calculator_state", b"state")]
+        [
+            ("marinade_calculator_state", b"state"),
+            ("marinade_calculator_config", b"config"),
+        ]
     );
 }
 
@@ -31,12 +44,23 @@ impl GenericPoolSolValCalc for MarinadeSolValCalc {
 }
 
 mod account_resolvers {
-    use generic_pool_calculator_interface::LST_TO_SOL_IX_ACCOUNTS_LEN;
-    use generic_pool_calculator_lib::account_resolvers::LstSolCommonIntermediateKeys;
+    use generic_pool_calculator_interface::{
+        GenericPoolCalculatorError, LST_TO_SOL_IX_ACCOUNTS_LEN,
+    };
+    use generic_pool_calculator_lib::{
+        account_resolvers::LstSolCommonIntermediateKeys, utils::try_calculator_state,
+        GenericPoolSolValCalc,
+    };
+    use marinade_calculator_interface::SetDelayedUnstakeFallbackKeys;
     use marinade_keys::{marinade_state, msol};
-    use solana_program::instruction::AccountMeta;
+    use solana_program::{instruction::AccountMeta, pubkey::Pubkey, system_program};
+    use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+    use crate::{program::MARINADE_CALCULATOR_CONFIG_ID, MarinadeSolValCalc};
 
-    use crate::MarinadeSolValCalc;
+    /// The marinade calculator program takes the MarinadeCalcConfig PDA
+    /// as an additional account after the common LstToSol/SolToLst accounts
+    pub const MARINADE_LST_SOL_ACCOUNTS_LEN: usize = LST_TO_SOL_IX_ACCOUNTS_LEN + 1;
 
     pub const MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS: LstSolCommonIntermediateKeys =
         LstSolCommonIntermediateKeys {
@@ -44,11 +68,45 @@ mod account_resolvers {
             pool_state: marinade_state::ID,
         };
 
-    pub fn marinade_sol_val_calc_account_metas() -> [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+    pub fn marinade_sol_val_calc_account_metas() -> [AccountMeta; MARINADE_LST_SOL_ACCOUNTS_LEN] {
         let marinade_sol_val_calc_keys: generic_pool_calculator_interface::SolToLstKeys =
             MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS
                 .resolve::<MarinadeSolValCalc>()
                 .into();
-        marinade_sol_val_calc_keys.into()
+        let [lst_mint, state, pool_state, pool_program, pool_program_data]: [AccountMeta;
+            LST_TO_SOL_IX_ACCOUNTS_LEN] = marinade_sol_val_calc_keys.into();
+        [
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+            AccountMeta::new_readonly(MARINADE_CALCULATOR_CONFIG_ID, false),
+        ]
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct SetDelayedUnstakeFallbackFreeArgs<S> {
+        pub payer: Pubkey,
+        pub state: S,
+    }
+
+    impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetDelayedUnstakeFallbackFreeArgs<S> {
+        pub fn resolve(self) -> Result<SetDelayedUnstakeFallbackKeys, GenericPoolCalculatorError> {
+            if *self.state.pubkey() != MarinadeSolValCalc::CALCULATOR_STATE_PDA {
+                return Err(GenericPoolCalculatorError::WrongCalculatorStatePda);
+            }
+
+            let bytes = self.state.data();
+            let calc_state = try_calculator_state(&bytes)?;
+
+            Ok(SetDelayedUnstakeFallbackKeys {
+                payer: self.payer,
+                manager: calc_state.manager,
+                state: MarinadeSolValCalc::CALCULATOR_STATE_PDA,
+                calc_config: MARINADE_CALCULATOR_CONFIG_ID,
+                system_program: system_program::ID,
+            })
+        }
     }
 }
@@ -0,0 +1,90 @@
+use posted_rate_calculator_interface::{
+    BatchCalcKeys, LstToSolKeys, SolToLstKeys, LST_TO_SOL_IX_ACCOUNTS_LEN,
+};
+use solana_program::{
+    instruction::AccountMeta,
+    pubkey::{Pubkey, PubkeyError},
+};
+
+use crate::{
+    pda::{RateAccountCreatePdaArgs, RateAccountFindPdaArgs},
+    program as posted_rate_calculator_program,
+};
+
+/// Uses find_program_address, for use with
+/// - initial creation
+/// - client side
+#[derive(Clone, Copy, Debug)]
+pub struct PostedRateLstSolCommonFreeArgs {
+    pub lst_mint: Pubkey,
+}
+
+impl PostedRateLstSolCommonFreeArgs {
+    pub fn resolve(self) -> LstToSolKeys {
+        self.resolve_inner(posted_rate_calculator_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> LstToSolKeys {
+        self.resolve_inner(program_id)
+    }
+
+    fn resolve_inner(self, program_id: Pubkey) -> LstToSolKeys {
+        let (rate_acc, _bump) = RateAccountFindPdaArgs {
+            lst_mint: self.lst_mint,
+            program_id,
+        }
+        .get_rate_account_address_and_bump_seed();
+        LstToSolKeys {
+            lst_mint: self.lst_mint,
+            rate_acc,
+        }
+    }
+
+    pub fn resolve_sol_to_lst(self) -> SolToLstKeys {
+        let LstToSolKeys { lst_mint, rate_acc } = self.resolve();
+        SolToLstKeys { lst_mint, rate_acc }
+    }
+
+    pub fn resolve_batch_calc(self) -> BatchCalcKeys {
+        let LstToSolKeys { lst_mint, rate_acc } = self.resolve();
+        BatchCalcKeys { lst_mint, rate_acc }
+    }
+
+    pub fn resolve_to_account_metas(self) -> [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+        let keys = self.resolve();
+        keys.into()
+    }
+}
+
+/// Uses create_program_address with the bump stored in the RateAccount,
+/// for use on-chain
+#[derive(Clone, Copy, Debug)]
+pub struct PostedRateLstSolCommonWithBumpFreeArgs {
+    pub args: PostedRateLstSolCommonFreeArgs,
+    pub rate_acc_bump: u8,
+}
+
+impl PostedRateLstSolCommonWithBumpFreeArgs {
+    pub fn resolve(self) -> Result<LstToSolKeys, PubkeyError> {
+        self.resolve_inner(posted_rate_calculator_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<LstToSolKeys, PubkeyError> {
+        self.resolve_inner(program_id)
+    }
+
+    fn resolve_inner(self, program_id: Pubkey) -> Result<LstToSolKeys, PubkeyError> {
+        let rate_acc = RateAccountCreatePdaArgs {
+            find_pda_args: RateAccountFindPdaArgs {
+                lst_mint: self.args.lst_mint,
+                program_id,
+            },
+            bump: self.rate_acc_bump,
+        }
+        .get_rate_account_address()?;
+        Ok(LstToSolKeys {
+            lst_mint: self.args.lst_mint,
+            rate_acc,
+        })
+    }
+}
@@ -0,0 +1,15 @@
+mod add_lst;
+mod initialize;
+mod lst_sol_common;
+mod remove_lst;
+mod set_manager;
+mod set_rate;
+mod set_rate_guardrails;
+
+pub use add_lst::*;
+pub use initialize::*;
+pub use lst_sol_common::*;
+pub use remove_lst::*;
+pub use set_manager::*;
+pub use set_rate::*;
+pub use set_rate_guardrails::*;
@@ -0,0 +1,50 @@
+use posted_rate_calculator_interface::{PostedRateCalculatorError, ProgramState, SetManagerKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::ProgramStateFindPdaArgs, program as posted_rate_calculator_program,
+    utils::try_program_state,
+};
+
+pub struct SetManagerFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub new_manager: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetManagerFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetManagerKeys, PostedRateCalculatorError> {
+        self.resolve_inner(posted_rate_calculator_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetManagerKeys, PostedRateCalculatorError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<SetManagerKeys, PostedRateCalculatorError> {
+        let SetManagerFreeArgs {
+            new_manager,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(PostedRateCalculatorError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        Ok(SetManagerKeys {
+            current_manager: state.manager,
+            new_manager,
+            state: state_id,
+        })
+    }
+}
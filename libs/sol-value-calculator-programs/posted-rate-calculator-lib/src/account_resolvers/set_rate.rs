@@ -0,0 +1,106 @@
+use posted_rate_calculator_interface::{PostedRateCalculatorError, ProgramState, SetRateKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    pda::{ProgramStateFindPdaArgs, RateAccountFindPdaArgs},
+    program as posted_rate_calculator_program,
+    utils::try_program_state,
+};
+
+pub struct SetRateByMintFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub lst_mint: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetRateByMintFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetRateKeys, PostedRateCalculatorError> {
+        self.resolve_inner(
+            posted_rate_calculator_program::STATE_ID,
+            posted_rate_calculator_program::ID,
+        )
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetRateKeys, PostedRateCalculatorError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<SetRateKeys, PostedRateCalculatorError> {
+        let SetRateByMintFreeArgs {
+            lst_mint,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(PostedRateCalculatorError::IncorrectProgramState);
+        }
+
+        let find_pda_args = RateAccountFindPdaArgs {
+            lst_mint,
+            program_id,
+        };
+        let (rate_acc, _bump) = find_pda_args.get_rate_account_address_and_bump_seed();
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        Ok(SetRateKeys {
+            manager: state.manager,
+            rate_acc,
+            state: state_id,
+        })
+    }
+}
+
+pub struct SetRateFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub rate_acc: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetRateFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetRateKeys, PostedRateCalculatorError> {
+        self.resolve_inner(posted_rate_calculator_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetRateKeys, PostedRateCalculatorError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<SetRateKeys, PostedRateCalculatorError> {
+        let SetRateFreeArgs {
+            rate_acc: _,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(PostedRateCalculatorError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        Ok(SetRateKeys {
+            manager: state.manager,
+            rate_acc: self.rate_acc,
+            state: state_id,
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use posted_rate_calculator_interface::{PostedRateCalculatorError, RateAccount};
+use sanctum_token_ratio::{FloorDiv, ReversibleRatio, U64Ratio, U64ValueRange};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::program_error::ProgramError;
+
+/// Parameters from RateAccount required to calculate SOL value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PostedRateCalc {
+    /// lamports per `rate_denom` LST atomics
+    pub rate_num: u64,
+
+    /// LST atomics per `rate_num` lamports
+    pub rate_denom: u64,
+
+    pub last_updated_slot: u64,
+
+    pub max_staleness_slots: u64,
+}
+
+impl From<&RateAccount> for PostedRateCalc {
+    fn from(
+        RateAccount {
+            rate_num,
+            rate_denom,
+            last_updated_slot,
+            max_staleness_slots,
+            ..
+        }: &RateAccount,
+    ) -> Self {
+        Self {
+            rate_num: *rate_num,
+            rate_denom: *rate_denom,
+            last_updated_slot: *last_updated_slot,
+            max_staleness_slots: *max_staleness_slots,
+        }
+    }
+}
+
+impl From<RateAccount> for PostedRateCalc {
+    fn from(value: RateAccount) -> Self {
+        (&value).into()
+    }
+}
+
+impl PostedRateCalc {
+    /// Errors if more than `max_staleness_slots` slots have passed since the rate was last posted
+    pub const fn verify_rate_not_stale(
+        &self,
+        current_slot: u64,
+    ) -> Result<(), PostedRateCalculatorError> {
+        if current_slot.saturating_sub(self.last_updated_slot) > self.max_staleness_slots {
+            Err(PostedRateCalculatorError::StaleRate)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub const fn lst_to_lamports_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        let Self {
+            rate_num,
+            rate_denom,
+            ..
+        } = self;
+        FloorDiv(U64Ratio {
+            num: *rate_num,
+            denom: *rate_denom,
+        })
+    }
+}
+
+/// Assumes:
+/// - rate has been verified to not be stale
+impl SolValueCalculator for PostedRateCalc {
+    fn calc_lst_to_sol(&self, lst_amount: u64) -> Result<U64ValueRange, ProgramError> {
+        Ok(U64ValueRange::single(
+            self.lst_to_lamports_ratio().apply(lst_amount)?,
+        ))
+    }
+
+    fn calc_sol_to_lst(&self, lamports_amount: u64) -> Result<U64ValueRange, ProgramError> {
+        Ok(self.lst_to_lamports_ratio().reverse(lamports_amount)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    prop_compose! {
+        fn posted_rate_calc()
+            (rate_num in 1..=u64::MAX, rate_denom in 1..=u64::MAX) -> PostedRateCalc {
+                PostedRateCalc {
+                    rate_num,
+                    rate_denom,
+                    last_updated_slot: 0,
+                    max_staleness_slots: 0,
+                }
+            }
+    }
+
+    prop_compose! {
+        /// lst_amt is bounded so that its SOL value does not overflow u64
+        fn posted_rate_calc_and_lst_amt()
+            (calc in posted_rate_calc())
+            (
+                lst_amt in 0..=u64::try_from(
+                    u128::from(u64::MAX) * u128::from(calc.rate_denom) / u128::from(calc.rate_num)
+                ).unwrap_or(u64::MAX),
+                calc in Just(calc),
+            ) -> (u64, PostedRateCalc) {
+                (lst_amt, calc)
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn lst_sol_round_trip((lst_amt, calc) in posted_rate_calc_and_lst_amt()) {
+            let r = calc.calc_lst_to_sol(lst_amt).unwrap();
+            let sol_amt = r.get_min();
+            let max_sol_amt = r.get_max();
+            prop_assert_eq!(sol_amt, max_sol_amt);
+            let r = calc.calc_sol_to_lst(sol_amt).unwrap();
+            let min = r.get_min();
+            let max = r.get_max();
+
+            // round trip from min should not exceed original
+            let min_round_trip = calc.calc_lst_to_sol(min).unwrap();
+            prop_assert!(sol_amt >= min_round_trip.get_min(), "{sol_amt} {}", min_round_trip.get_min());
+            prop_assert!(sol_amt >= min_round_trip.get_max(), "{sol_amt} {}", min_round_trip.get_max());
+
+            // round trip from max should not be smaller than original
+            let max_round_trip = calc.calc_lst_to_sol(max).unwrap();
+            prop_assert!(sol_amt <= max_round_trip.get_min(), "{sol_amt} {}", max_round_trip.get_min());
+            prop_assert!(sol_amt <= max_round_trip.get_max(), "{sol_amt} {}", max_round_trip.get_max());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn staleness_limit_inclusive(last_updated_slot: u64, max_staleness_slots: u64, elapsed: u64) {
+            let calc = PostedRateCalc {
+                rate_num: 1,
+                rate_denom: 1,
+                last_updated_slot,
+                max_staleness_slots,
+            };
+            let current_slot = last_updated_slot.saturating_add(elapsed);
+            let res = calc.verify_rate_not_stale(current_slot);
+            if current_slot - last_updated_slot <= max_staleness_slots {
+                prop_assert_eq!(res, Ok(()));
+            } else {
+                prop_assert_eq!(res, Err(PostedRateCalculatorError::StaleRate));
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use posted_rate_calculator_interface::{PostedRateCalculatorError, RateAccount};
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+pub fn verify_rate(rate_num: u64, rate_denom: u64) -> Result<(), PostedRateCalculatorError> {
+    if rate_num == 0 || rate_denom == 0 {
+        return Err(PostedRateCalculatorError::InvalidRate);
+    }
+    Ok(())
+}
+
+/// Verifies that updating `rate_acc`'s rate to `new_rate_num / new_rate_denom`
+/// respects the guardrails configured on it:
+/// - the new rate must be valid
+/// - if `is_monotonic`, the new rate must not be lower than the current rate
+/// - the new rate must not differ from the current rate by more than `max_rate_change_bps`
+pub fn verify_rate_update(
+    rate_acc: &RateAccount,
+    new_rate_num: u64,
+    new_rate_denom: u64,
+) -> Result<(), PostedRateCalculatorError> {
+    verify_rate(new_rate_num, new_rate_denom)?;
+
+    // cross-multiply so that both rates are over the same denominator
+    let curr = u128::from(rate_acc.rate_num) * u128::from(new_rate_denom);
+    let new = u128::from(new_rate_num) * u128::from(rate_acc.rate_denom);
+
+    if rate_acc.is_monotonic != 0 && new < curr {
+        return Err(PostedRateCalculatorError::RateDecreased);
+    }
+
+    if new.abs_diff(curr) > max_rate_change(curr, rate_acc.max_rate_change_bps) {
+        return Err(PostedRateCalculatorError::RateChangeExceedsMax);
+    }
+
+    Ok(())
+}
+
+/// floor(curr * max_rate_change_bps / 10_000), saturating.
+///
+/// Since the change is an integer, `change <= floor(curr * bps / 10_000)`
+/// iff `change * 10_000 <= curr * bps`, but this avoids overflowing u128
+fn max_rate_change(curr: u128, max_rate_change_bps: u16) -> u128 {
+    let bps = u128::from(max_rate_change_bps);
+    let q = curr / BPS_DENOMINATOR;
+    let r = curr % BPS_DENOMINATOR;
+    q.saturating_mul(bps)
+        .saturating_add(r * bps / BPS_DENOMINATOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn rate_acc(
+        rate_num: u64,
+        rate_denom: u64,
+        max_rate_change_bps: u16,
+        is_monotonic: bool,
+    ) -> RateAccount {
+        RateAccount {
+            bump: 0,
+            is_monotonic: is_monotonic.into(),
+            max_rate_change_bps,
+            padding: Default::default(),
+            max_staleness_slots: 0,
+            last_updated_slot: 0,
+            rate_num,
+            rate_denom,
+        }
+    }
+
+    #[test]
+    fn max_rate_change_bound_inclusive() {
+        let acc = rate_acc(1_000_000, 1_000_000, 100, false);
+        assert_eq!(verify_rate_update(&acc, 1_010_000, 1_000_000), Ok(()));
+        assert_eq!(verify_rate_update(&acc, 990_000, 1_000_000), Ok(()));
+        assert_eq!(
+            verify_rate_update(&acc, 1_010_001, 1_000_000),
+            Err(PostedRateCalculatorError::RateChangeExceedsMax)
+        );
+        assert_eq!(
+            verify_rate_update(&acc, 989_999, 1_000_000),
+            Err(PostedRateCalculatorError::RateChangeExceedsMax)
+        );
+    }
+
+    #[test]
+    fn monotonic_rejects_decrease() {
+        let acc = rate_acc(1_000_000, 1_000_000, 100, true);
+        assert_eq!(verify_rate_update(&acc, 1_000_000, 1_000_000), Ok(()));
+        assert_eq!(verify_rate_update(&acc, 1_000_001, 1_000_000), Ok(()));
+        assert_eq!(
+            verify_rate_update(&acc, 999_999, 1_000_000),
+            Err(PostedRateCalculatorError::RateDecreased)
+        );
+    }
+
+    #[test]
+    fn zero_rate_rejected() {
+        let acc = rate_acc(1, 1, u16::MAX, false);
+        assert_eq!(
+            verify_rate_update(&acc, 0, 1),
+            Err(PostedRateCalculatorError::InvalidRate)
+        );
+        assert_eq!(
+            verify_rate_update(&acc, 1, 0),
+            Err(PostedRateCalculatorError::InvalidRate)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn max_rate_change_matches_u128_formula(curr: u64, bps: u16) {
+            let curr = u128::from(curr);
+            prop_assert_eq!(
+                max_rate_change(curr, bps),
+                curr * u128::from(bps) / BPS_DENOMINATOR
+            );
+        }
+
+        #[test]
+        fn equivalent_rate_always_allowed(
+            rate_num in 1..=u32::MAX as u64,
+            rate_denom in 1..=u32::MAX as u64,
+            scale in 1..=u32::MAX as u64,
+            is_monotonic: bool,
+        ) {
+            let acc = rate_acc(rate_num, rate_denom, 0, is_monotonic);
+            prop_assert_eq!(
+                verify_rate_update(&acc, rate_num * scale, rate_denom * scale),
+                Ok(())
+            );
+        }
+    }
+}
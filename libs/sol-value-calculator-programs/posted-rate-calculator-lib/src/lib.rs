@@ -0,0 +1,35 @@
+pub mod account_resolvers;
+pub mod calc;
+pub mod guardrails;
+pub mod pda;
+pub mod utils;
+
+pub mod program {
+    pub const STATE_SIZE: usize = 32;
+    pub const RATE_ACCOUNT_SIZE: usize = 40;
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<posted_rate_calculator_interface::ProgramState>(),
+        STATE_SIZE,
+    );
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<posted_rate_calculator_interface::RateAccount>(),
+        RATE_ACCOUNT_SIZE,
+    );
+
+    sanctum_macros::declare_program_keys!(
+        "rate2pJGF9p7kpzb6eU326EFZf2cDnimbTFVeJtx1qt",
+        [("state", b"state")]
+    );
+}
+
+pub mod initial_constants {
+    pub mod initial_manager {
+        #[cfg(feature = "testing")]
+        sanctum_macros::declare_program_keys!("J5aMuYiKNHUzMTpUS85413DxxvDVjNXs63EXW5twG1Mx", []);
+
+        #[cfg(not(feature = "testing"))]
+        sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
+    }
+}
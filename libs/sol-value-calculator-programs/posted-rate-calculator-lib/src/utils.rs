@@ -0,0 +1,26 @@
+use bytemuck::{try_from_bytes, try_from_bytes_mut};
+use posted_rate_calculator_interface::{PostedRateCalculatorError, ProgramState, RateAccount};
+
+pub fn try_program_state(
+    program_state_acc_data: &[u8],
+) -> Result<&ProgramState, PostedRateCalculatorError> {
+    try_from_bytes(program_state_acc_data)
+        .map_err(|_e| PostedRateCalculatorError::InvalidProgramStateData)
+}
+
+pub fn try_program_state_mut(
+    program_state_acc_data: &mut [u8],
+) -> Result<&mut ProgramState, PostedRateCalculatorError> {
+    try_from_bytes_mut(program_state_acc_data)
+        .map_err(|_e| PostedRateCalculatorError::InvalidProgramStateData)
+}
+
+pub fn try_rate_account(rate_acc_data: &[u8]) -> Result<&RateAccount, PostedRateCalculatorError> {
+    try_from_bytes(rate_acc_data).map_err(|_e| PostedRateCalculatorError::UnsupportedLstMint)
+}
+
+pub fn try_rate_account_mut(
+    rate_acc_data: &mut [u8],
+) -> Result<&mut RateAccount, PostedRateCalculatorError> {
+    try_from_bytes_mut(rate_acc_data).map_err(|_e| PostedRateCalculatorError::UnsupportedLstMint)
+}
@@ -0,0 +1,7 @@
+mod posted_rate_calculator_program_test;
+mod rate_account;
+mod state;
+
+pub use posted_rate_calculator_program_test::*;
+pub use rate_account::*;
+pub use state::*;
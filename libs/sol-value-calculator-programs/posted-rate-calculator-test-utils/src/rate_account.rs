@@ -0,0 +1,67 @@
+use posted_rate_calculator_interface::RateAccount;
+use posted_rate_calculator_lib::{
+    pda::RateAccountFindPdaArgs, program::RATE_ACCOUNT_SIZE, utils::try_rate_account_mut,
+};
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, IntoAccount};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+
+pub struct MockRateAccount(pub RateAccount);
+
+impl IntoAccount for MockRateAccount {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; RATE_ACCOUNT_SIZE];
+        let dst = try_rate_account_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(RATE_ACCOUNT_SIZE),
+            data,
+            owner: posted_rate_calculator_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MockRateAccountArgs {
+    pub rate_num: u64,
+    pub rate_denom: u64,
+    pub max_rate_change_bps: u16,
+    pub is_monotonic: bool,
+    pub max_staleness_slots: u64,
+    pub last_updated_slot: u64,
+    pub lst_mint: Pubkey,
+}
+
+impl MockRateAccountArgs {
+    pub fn to_rate_account_and_addr(&self, program_id: Pubkey) -> (RateAccount, Pubkey) {
+        let Self {
+            rate_num,
+            rate_denom,
+            max_rate_change_bps,
+            is_monotonic,
+            max_staleness_slots,
+            last_updated_slot,
+            lst_mint,
+        } = self;
+        let (addr, bump) = RateAccountFindPdaArgs {
+            lst_mint: *lst_mint,
+            program_id,
+        }
+        .get_rate_account_address_and_bump_seed();
+        (
+            RateAccount {
+                bump,
+                is_monotonic: (*is_monotonic).into(),
+                max_rate_change_bps: *max_rate_change_bps,
+                padding: Default::default(),
+                max_staleness_slots: *max_staleness_slots,
+                last_updated_slot: *last_updated_slot,
+                rate_num: *rate_num,
+                rate_denom: *rate_denom,
+            },
+            addr,
+        )
+    }
+}
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use posted_rate_calculator_interface::ProgramState;
+use posted_rate_calculator_lib::{
+    initial_constants::initial_manager, program::STATE_SIZE, utils::try_program_state_mut,
+};
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedBanksClient, IntoAccount};
+use solana_program_test::BanksClient;
+use solana_sdk::account::Account;
+
+pub const DEFAULT_PROGRAM_STATE: ProgramState = ProgramState {
+    manager: initial_manager::ID,
+};
+
+pub struct MockProgramState(pub ProgramState);
+
+impl IntoAccount for MockProgramState {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; STATE_SIZE];
+        let dst = try_program_state_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(STATE_SIZE),
+            data,
+            owner: posted_rate_calculator_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[async_trait]
+pub trait PostedRateCalculatorProgramTestBanksClient {
+    async fn get_posted_rate_calculator_program_state(&mut self) -> Account;
+}
+
+#[async_trait]
+impl PostedRateCalculatorProgramTestBanksClient for BanksClient {
+    async fn get_posted_rate_calculator_program_state(&mut self) -> Account {
+        self.get_account_unwrapped(posted_rate_calculator_lib::program::STATE_ID)
+            .await
+    }
+}
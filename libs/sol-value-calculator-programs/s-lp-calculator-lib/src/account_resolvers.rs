@@ -0,0 +1,98 @@
+use s_controller_lib::{find_lst_state_list_address, find_pool_state_address, try_pool_state};
+use s_lp_calculator_interface::{
+    BatchCalcKeys, LstToSolKeys, SLpCalculatorError, SolToLstKeys, LST_TO_SOL_IX_ACCOUNTS_LEN,
+};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey, sysvar};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+/// The S program that owns `pool_state` is the program whose LP token is being valued.
+///
+/// Verifies that `pool_state` is the pool state PDA of its owner program,
+/// suitable for use both on-chain and client side.
+#[derive(Clone, Copy, Debug)]
+pub struct SLpLstSolCommonFreeArgs<P> {
+    pub pool_state: P,
+}
+
+impl<P: ReadonlyAccountData + ReadonlyAccountOwner + ReadonlyAccountPubkey>
+    SLpLstSolCommonFreeArgs<P>
+{
+    pub fn resolve(&self) -> Result<LstToSolKeys, SLpCalculatorError> {
+        let s_program_id = *self.pool_state.owner();
+        let (expected_pool_state, _bump) = find_pool_state_address(s_program_id);
+        if *self.pool_state.pubkey() != expected_pool_state {
+            return Err(SLpCalculatorError::InvalidPoolState);
+        }
+        let lp_token_mint = {
+            let data = self.pool_state.data();
+            try_pool_state(&data)
+                .map_err(|_e| SLpCalculatorError::InvalidPoolState)?
+                .lp_token_mint
+        };
+        Ok(LstToSolKeys {
+            lst_mint: lp_token_mint,
+            pool_state: expected_pool_state,
+            lst_state_list: find_lst_state_list_address(s_program_id).0,
+            instructions: sysvar::instructions::ID,
+        })
+    }
+
+    pub fn resolve_sol_to_lst(&self) -> Result<SolToLstKeys, SLpCalculatorError> {
+        let LstToSolKeys {
+            lst_mint,
+            pool_state,
+            lst_state_list,
+            instructions,
+        } = self.resolve()?;
+        Ok(SolToLstKeys {
+            lst_mint,
+            pool_state,
+            lst_state_list,
+            instructions,
+        })
+    }
+
+    pub fn resolve_batch_calc(&self) -> Result<BatchCalcKeys, SLpCalculatorError> {
+        let LstToSolKeys {
+            lst_mint,
+            pool_state,
+            lst_state_list,
+            instructions,
+        } = self.resolve()?;
+        Ok(BatchCalcKeys {
+            lst_mint,
+            pool_state,
+            lst_state_list,
+            instructions,
+        })
+    }
+
+    pub fn resolve_to_account_metas(
+        &self,
+    ) -> Result<[AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN], SLpCalculatorError> {
+        let keys = self.resolve()?;
+        Ok(keys.into())
+    }
+}
+
+/// For when the S program and its LP token mint are already known
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SLpLstSolCommonFreeArgsConst {
+    pub s_program_id: Pubkey,
+    pub lp_token_mint: Pubkey,
+}
+
+impl SLpLstSolCommonFreeArgsConst {
+    pub fn resolve(&self) -> LstToSolKeys {
+        LstToSolKeys {
+            lst_mint: self.lp_token_mint,
+            pool_state: find_pool_state_address(self.s_program_id).0,
+            lst_state_list: find_lst_state_list_address(self.s_program_id).0,
+            instructions: sysvar::instructions::ID,
+        }
+    }
+
+    pub fn resolve_to_account_metas(&self) -> [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+        self.resolve().into()
+    }
+}
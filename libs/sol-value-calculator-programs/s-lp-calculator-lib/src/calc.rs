@@ -0,0 +1,109 @@
+use s_controller_lib::{calc_lp_tokens_sol_value, LpTokenRateArgs};
+use sanctum_token_ratio::{FloorDiv, ReversibleRatio, U64Ratio, U64ValueRange};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::program_error::ProgramError;
+
+/// Values the LP token of an S pool at `pool_total_sol_value / lp_token_supply`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SLpSolValCalc {
+    pub lp_token_supply: u64,
+    pub pool_total_sol_value: u64,
+}
+
+impl From<SLpSolValCalc> for LpTokenRateArgs {
+    fn from(
+        SLpSolValCalc {
+            lp_token_supply,
+            pool_total_sol_value,
+        }: SLpSolValCalc,
+    ) -> Self {
+        Self {
+            lp_token_supply,
+            pool_total_sol_value,
+        }
+    }
+}
+
+/// Assumes:
+/// - the pool has been verified to be synced and not rebalancing
+impl SolValueCalculator for SLpSolValCalc {
+    fn calc_lst_to_sol(&self, lst_amount: u64) -> Result<U64ValueRange, ProgramError> {
+        Ok(U64ValueRange::single(calc_lp_tokens_sol_value(
+            (*self).into(),
+            lst_amount,
+        )?))
+    }
+
+    /// Errors if the pool has no LP tokens or no SOL value
+    fn calc_sol_to_lst(&self, lamports_amount: u64) -> Result<U64ValueRange, ProgramError> {
+        Ok(FloorDiv(U64Ratio {
+            num: self.pool_total_sol_value,
+            denom: self.lp_token_supply,
+        })
+        .reverse(lamports_amount)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    prop_compose! {
+        fn s_lp_sol_val_calc()
+            (lp_token_supply in 1..=u64::MAX, pool_total_sol_value in 1..=u64::MAX) -> SLpSolValCalc {
+                SLpSolValCalc {
+                    lp_token_supply,
+                    pool_total_sol_value,
+                }
+            }
+    }
+
+    prop_compose! {
+        /// lp_amt is bounded so that its SOL value does not overflow u64
+        fn s_lp_sol_val_calc_and_lp_amt()
+            (calc in s_lp_sol_val_calc())
+            (
+                lp_amt in 0..=u64::try_from(
+                    u128::from(u64::MAX) * u128::from(calc.lp_token_supply) / u128::from(calc.pool_total_sol_value)
+                ).unwrap_or(u64::MAX),
+                calc in Just(calc),
+            ) -> (u64, SLpSolValCalc) {
+                (lp_amt, calc)
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn lp_sol_round_trip((lp_amt, calc) in s_lp_sol_val_calc_and_lp_amt()) {
+            let r = calc.calc_lst_to_sol(lp_amt).unwrap();
+            let sol_amt = r.get_min();
+            let max_sol_amt = r.get_max();
+            prop_assert_eq!(sol_amt, max_sol_amt);
+            let r = calc.calc_sol_to_lst(sol_amt).unwrap();
+            let min = r.get_min();
+            let max = r.get_max();
+
+            // round trip from min should not exceed original
+            let min_round_trip = calc.calc_lst_to_sol(min).unwrap();
+            prop_assert!(sol_amt >= min_round_trip.get_min(), "{sol_amt} {}", min_round_trip.get_min());
+            prop_assert!(sol_amt >= min_round_trip.get_max(), "{sol_amt} {}", min_round_trip.get_max());
+
+            // round trip from max should not be smaller than original
+            let max_round_trip = calc.calc_lst_to_sol(max).unwrap();
+            prop_assert!(sol_amt <= max_round_trip.get_min(), "{sol_amt} {}", max_round_trip.get_min());
+            prop_assert!(sol_amt <= max_round_trip.get_max(), "{sol_amt} {}", max_round_trip.get_max());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn empty_pool_lp_worthless(lp_amt: u64, lp_token_supply: u64) {
+            let calc = SLpSolValCalc {
+                lp_token_supply,
+                pool_total_sol_value: 0,
+            };
+            prop_assert_eq!(calc.calc_lst_to_sol(lp_amt).unwrap(), U64ValueRange::single(0));
+        }
+    }
+}
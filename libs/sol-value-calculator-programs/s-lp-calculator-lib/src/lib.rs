@@ -0,0 +1,8 @@
+pub mod account_resolvers;
+pub mod calc;
+pub mod sync;
+pub mod utils;
+
+pub mod program {
+    pub const ID: solana_program::pubkey::Pubkey = s_lp_calculator_interface::ID;
+}
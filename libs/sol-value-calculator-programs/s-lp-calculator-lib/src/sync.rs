@@ -0,0 +1,46 @@
+use s_controller_interface::{LstState, SyncSolValueIxArgs, SyncSolValueIxData};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+
+/// Index of `pool_state` in the SyncSolValue instruction's accounts
+const SYNC_SOL_VALUE_POOL_STATE_ACCOUNT_INDEX: usize = 1;
+
+/// The S pool whose LSTs' SyncSolValue instructions are being looked for
+#[derive(Clone, Copy, Debug)]
+pub struct SPoolSyncArgs<'a> {
+    pub s_program_id: &'a Pubkey,
+    pub pool_state: &'a Pubkey,
+    pub lst_state_list: &'a [LstState],
+}
+
+impl SPoolSyncArgs<'_> {
+    /// Returns the index on `lst_state_list` of the LST that `ix` syncs the SOL value of,
+    /// None if `ix` is not a SyncSolValue instruction for this S pool
+    pub fn synced_lst_index(&self, ix: &Instruction) -> Option<usize> {
+        if ix.program_id != *self.s_program_id {
+            return None;
+        }
+        let SyncSolValueIxData(SyncSolValueIxArgs { lst_index }) =
+            SyncSolValueIxData::deserialize(&ix.data).ok()?;
+        let pool_state = ix.accounts.get(SYNC_SOL_VALUE_POOL_STATE_ACCOUNT_INDEX)?;
+        if pool_state.pubkey != *self.pool_state {
+            return None;
+        }
+        let lst_index = usize::try_from(lst_index).ok()?;
+        let lst_state = self.lst_state_list.get(lst_index)?;
+        let lst_mint = ix.accounts.first()?;
+        if lst_mint.pubkey != lst_state.mint {
+            return None;
+        }
+        Some(lst_index)
+    }
+
+    /// Returns true if every LST on `lst_state_list`
+    /// has been synced by one of `ixs`
+    pub fn all_synced<'i>(&self, ixs: impl IntoIterator<Item = &'i Instruction>) -> bool {
+        let mut is_synced = vec![false; self.lst_state_list.len()];
+        ixs.into_iter()
+            .filter_map(|ix| self.synced_lst_index(ix))
+            .for_each(|i| is_synced[i] = true);
+        is_synced.into_iter().all(|b| b)
+    }
+}
@@ -0,0 +1,40 @@
+use s_controller_interface::PoolState;
+use s_controller_lib::U8Bool;
+use s_lp_calculator_interface::SLpCalculatorError;
+use solana_program::{program_error::ProgramError, program_option::COption, pubkey::Pubkey};
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
+
+use crate::calc::SLpSolValCalc;
+
+pub fn verify_pool_not_rebalancing(pool_state: &PoolState) -> Result<(), SLpCalculatorError> {
+    if U8Bool(pool_state.is_rebalancing).is_true() {
+        return Err(SLpCalculatorError::PoolRebalancing);
+    }
+    Ok(())
+}
+
+/// Reads the LP token supply from `lp_token_mint_data`, verifying that
+/// `pool_state_addr` is the mint's mint authority,
+/// so that the mint cannot be paired with another program's pool state.
+pub fn try_lp_token_supply(
+    lp_token_mint_data: &[u8],
+    pool_state_addr: &Pubkey,
+) -> Result<u64, ProgramError> {
+    let StateWithExtensions { base, .. } = StateWithExtensions::<Mint>::unpack(lp_token_mint_data)?;
+    if base.mint_authority != COption::Some(*pool_state_addr) {
+        return Err(SLpCalculatorError::IncorrectLpTokenMint.into());
+    }
+    Ok(base.supply)
+}
+
+/// Assumes `lp_token_mint_data` belongs to `pool_state.lp_token_mint`
+pub fn try_s_lp_sol_val_calc(
+    pool_state: &PoolState,
+    pool_state_addr: &Pubkey,
+    lp_token_mint_data: &[u8],
+) -> Result<SLpSolValCalc, ProgramError> {
+    Ok(SLpSolValCalc {
+        lp_token_supply: try_lp_token_supply(lp_token_mint_data, pool_state_addr)?,
+        pool_total_sol_value: pool_state.total_sol_value,
+    })
+}
@@ -1,7 +1,29 @@
 use sanctum_token_ratio::U64ValueRange;
 use solana_program::program_error::ProgramError;
 
+mod stale_discount;
+
+pub use stale_discount::*;
+
 pub trait SolValueCalculator {
     fn calc_lst_to_sol(&self, lst_amount: u64) -> Result<U64ValueRange, ProgramError>;
     fn calc_sol_to_lst(&self, lamports_amount: u64) -> Result<U64ValueRange, ProgramError>;
+
+    /// Results of `calc_lst_to_sol()` for each of `lst_to_sol_amounts`,
+    /// followed by results of `calc_sol_to_lst()` for each of `sol_to_lst_amounts`
+    fn calc_batch(
+        &self,
+        lst_to_sol_amounts: &[u64],
+        sol_to_lst_amounts: &[u64],
+    ) -> Result<Vec<U64ValueRange>, ProgramError> {
+        lst_to_sol_amounts
+            .iter()
+            .map(|amt| self.calc_lst_to_sol(*amt))
+            .chain(
+                sol_to_lst_amounts
+                    .iter()
+                    .map(|amt| self.calc_sol_to_lst(*amt)),
+            )
+            .collect()
+    }
 }
@@ -0,0 +1,173 @@
+use sanctum_token_ratio::U64ValueRange;
+use solana_program::program_error::ProgramError;
+
+use crate::SolValueCalculator;
+
+pub const BPS_DENOM: u16 = 10_000;
+
+/// Returns the discount in bips to value an LST at, given the epoch its pool was last updated in:
+/// - 0 if the pool was updated this epoch
+/// - `stale_discount_bps` if the pool was updated exactly one epoch ago and `stale_discount_bps` is nonzero
+/// - None otherwise, i.e. the pool is too stale to be valued
+pub const fn stale_discount_bps_for_epoch(
+    last_update_epoch: u64,
+    current_epoch: u64,
+    stale_discount_bps: u16,
+) -> Option<u16> {
+    if last_update_epoch == current_epoch {
+        Some(0)
+    } else if stale_discount_bps != 0 && last_update_epoch.wrapping_add(1) == current_epoch {
+        Some(stale_discount_bps)
+    } else {
+        None
+    }
+}
+
+/// A [`SolValueCalculator`] that can be wrapped in a [`StaleDiscountCalc`]
+pub trait PoolNotUpdatedErr {
+    /// The error to fail with when the pool is valued as an input while stale,
+    /// i.e. the same error as when the pool is too stale to be valued
+    fn pool_not_updated_err() -> ProgramError;
+}
+
+/// Wraps a [`SolValueCalculator`] whose pool was last updated an epoch ago.
+///
+/// The wrapped calculator's exchange rate lags the true one by an epoch of staking rewards,
+/// so a stale LST may only be valued as the output:
+/// - `calc_lst_to_sol()`, used to value the LST as an input, fails with [`PoolNotUpdatedErr::pool_not_updated_err`]
+/// - `calc_sol_to_lst()`'s min is discounted by `stale_discount_bps`, in the pool's favour
+///
+/// A `stale_discount_bps` of 0 returns the wrapped calculator's results unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaleDiscountCalc<C> {
+    pub calc: C,
+    pub stale_discount_bps: u16,
+}
+
+impl<C> StaleDiscountCalc<C> {
+    pub const fn fresh(calc: C) -> Self {
+        Self {
+            calc,
+            stale_discount_bps: 0,
+        }
+    }
+
+    /// floor(amt * (10_000 - stale_discount_bps) / 10_000)
+    pub const fn discount(&self, amt: u64) -> u64 {
+        if self.stale_discount_bps >= BPS_DENOM {
+            return 0;
+        }
+        let after_discount_bps = (BPS_DENOM - self.stale_discount_bps) as u128;
+        (amt as u128 * after_discount_bps / BPS_DENOM as u128) as u64
+    }
+}
+
+impl<C: SolValueCalculator + PoolNotUpdatedErr> SolValueCalculator for StaleDiscountCalc<C> {
+    fn calc_lst_to_sol(&self, lst_amount: u64) -> Result<U64ValueRange, ProgramError> {
+        if self.stale_discount_bps != 0 {
+            return Err(C::pool_not_updated_err());
+        }
+        self.calc.calc_lst_to_sol(lst_amount)
+    }
+
+    fn calc_sol_to_lst(&self, lamports_amount: u64) -> Result<U64ValueRange, ProgramError> {
+        let r = self.calc.calc_sol_to_lst(lamports_amount)?;
+        if self.stale_discount_bps == 0 {
+            return Ok(r);
+        }
+        Ok(U64ValueRange::try_from_min_max(
+            self.discount(r.get_min()),
+            r.get_max(),
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// 1 LST = 2 SOL, exact
+    struct DoubleCalc;
+
+    impl SolValueCalculator for DoubleCalc {
+        fn calc_lst_to_sol(&self, lst_amount: u64) -> Result<U64ValueRange, ProgramError> {
+            Ok(U64ValueRange::single(lst_amount / 2 * 2))
+        }
+
+        fn calc_sol_to_lst(&self, lamports_amount: u64) -> Result<U64ValueRange, ProgramError> {
+            Ok(U64ValueRange::single(lamports_amount / 2))
+        }
+    }
+
+    impl PoolNotUpdatedErr for DoubleCalc {
+        fn pool_not_updated_err() -> ProgramError {
+            ProgramError::Custom(0)
+        }
+    }
+
+    #[test]
+    fn stale_discount_bps_for_epoch_cases() {
+        assert_eq!(stale_discount_bps_for_epoch(5, 5, 0), Some(0));
+        assert_eq!(stale_discount_bps_for_epoch(5, 5, 10), Some(0));
+        assert_eq!(stale_discount_bps_for_epoch(4, 5, 10), Some(10));
+        assert_eq!(stale_discount_bps_for_epoch(4, 5, 0), None);
+        assert_eq!(stale_discount_bps_for_epoch(3, 5, 10), None);
+        assert_eq!(stale_discount_bps_for_epoch(6, 5, 10), None);
+    }
+
+    #[test]
+    fn discount_basic() {
+        let calc = StaleDiscountCalc {
+            calc: DoubleCalc,
+            stale_discount_bps: 100,
+        };
+        assert_eq!(calc.discount(10_000), 9_900);
+        assert_eq!(calc.discount(9_999), 9_899);
+    }
+
+    proptest! {
+        #[test]
+        fn zero_discount_unchanged(amt: u64) {
+            let calc = StaleDiscountCalc::fresh(DoubleCalc);
+            let [r, orig] = [calc.calc_lst_to_sol(amt).unwrap(), DoubleCalc.calc_lst_to_sol(amt).unwrap()];
+            prop_assert_eq!((r.get_min(), r.get_max()), (orig.get_min(), orig.get_max()));
+            let [r, orig] = [calc.calc_sol_to_lst(amt).unwrap(), DoubleCalc.calc_sol_to_lst(amt).unwrap()];
+            prop_assert_eq!((r.get_min(), r.get_max()), (orig.get_min(), orig.get_max()));
+        }
+
+        #[test]
+        fn calc_batch_matches_individual_calcs(
+            lst_amts in proptest::collection::vec(any::<u64>(), 0..8),
+            sol_amts in proptest::collection::vec(any::<u64>(), 0..8),
+            stale_discount_bps in 0..BPS_DENOM,
+        ) {
+            let calc = StaleDiscountCalc { calc: DoubleCalc, stale_discount_bps };
+            let res = calc.calc_batch(&lst_amts, &sol_amts);
+            if stale_discount_bps != 0 && !lst_amts.is_empty() {
+                prop_assert_eq!(res.unwrap_err(), ProgramError::Custom(0));
+                return Ok(());
+            }
+            let res = res.unwrap();
+            prop_assert_eq!(res.len(), lst_amts.len() + sol_amts.len());
+            for (amt, r) in lst_amts.iter().zip(res.iter()) {
+                prop_assert_eq!(*r, calc.calc_lst_to_sol(*amt).unwrap());
+            }
+            for (amt, r) in sol_amts.iter().zip(res[lst_amts.len()..].iter()) {
+                prop_assert_eq!(*r, calc.calc_sol_to_lst(*amt).unwrap());
+            }
+        }
+
+        #[test]
+        fn stale_input_fails_and_output_min_discounted(amt: u64, stale_discount_bps in 1..BPS_DENOM) {
+            let calc = StaleDiscountCalc { calc: DoubleCalc, stale_discount_bps };
+
+            prop_assert_eq!(calc.calc_lst_to_sol(amt).unwrap_err(), ProgramError::Custom(0));
+
+            let orig = DoubleCalc.calc_sol_to_lst(amt).unwrap();
+            let r = calc.calc_sol_to_lst(amt).unwrap();
+            prop_assert_eq!(r.get_max(), orig.get_max());
+            prop_assert_eq!(r.get_min(), calc.discount(orig.get_min()));
+        }
+    }
+}
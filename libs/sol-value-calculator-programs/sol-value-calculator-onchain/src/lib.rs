@@ -1,10 +1,21 @@
 use borsh::BorshSerialize;
 use sanctum_token_ratio::U64ValueRange;
 use sol_value_calculator_lib::SolValueCalculator;
-use solana_program::{program::set_return_data, program_error::ProgramError};
+use solana_program::{
+    program::{set_return_data, MAX_RETURN_DATA},
+    program_error::ProgramError,
+};
 
 const U64_VALUE_RANGE_SER_SIZE: usize = std::mem::size_of::<U64ValueRange>();
 
+/// Borsh vec length prefix
+const VEC_LEN_SER_SIZE: usize = std::mem::size_of::<u32>();
+
+/// Max number of amounts a single BatchCalc instruction can evaluate
+/// such that its results still fit in return data
+pub const MAX_BATCH_CALC_LEN: usize =
+    (MAX_RETURN_DATA - VEC_LEN_SER_SIZE) / U64_VALUE_RANGE_SER_SIZE;
+
 /// set_return_data() to result of calc_lst_to_sol()
 pub fn process_lst_to_sol_unchecked<S: SolValueCalculator>(
     calc: &S,
@@ -23,6 +34,22 @@ pub fn process_sol_to_lst_unchecked<S: SolValueCalculator>(
     set_u64_value_range_return_data(range)
 }
 
+/// set_return_data() to borsh-serialized `Vec<U64ValueRange>` result of calc_batch()
+pub fn process_batch_calc_unchecked<S: SolValueCalculator>(
+    calc: &S,
+    lst_to_sol_amounts: &[u64],
+    sol_to_lst_amounts: &[u64],
+) -> Result<(), ProgramError> {
+    if lst_to_sol_amounts.len() + sol_to_lst_amounts.len() > MAX_BATCH_CALC_LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let ranges = calc.calc_batch(lst_to_sol_amounts, sol_to_lst_amounts)?;
+    let mut buf = Vec::with_capacity(VEC_LEN_SER_SIZE + ranges.len() * U64_VALUE_RANGE_SER_SIZE);
+    ranges.serialize(&mut buf)?;
+    set_return_data(&buf);
+    Ok(())
+}
+
 fn set_u64_value_range_return_data(range: U64ValueRange) -> Result<(), ProgramError> {
     let mut buf = [0u8; U64_VALUE_RANGE_SER_SIZE];
     range.serialize(&mut buf.as_mut())?;
@@ -2,13 +2,23 @@ use borsh::BorshDeserialize;
 use generic_pool_calculator_interface::{GenericPoolCalculatorError, LST_TO_SOL_IX_ACCOUNTS_LEN};
 use generic_pool_calculator_lib::{
     account_resolvers::{LstSolCommonIntermediateArgs, LstSolCommonIntermediateKeys},
+    utils::try_calculator_state,
     GenericPoolSolValCalc,
 };
-use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey, system_program};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
-use spl_calculator_interface::{AccountType, SplStakePool};
+use spl_calculator_interface::{
+    AccountType, RecordWithdrawalFeesKeys, SetValuationModeKeys, SetWithdrawalFeeGuardKeys,
+    SplStakePool,
+};
+
+use crate::{
+    pda::SplPoolConfigFindPdaArgs, SanctumSplMultiSolValCalc, SanctumSplSolValCalc, SplSolValCalc,
+};
 
-use crate::{SanctumSplMultiSolValCalc, SanctumSplSolValCalc, SplSolValCalc};
+/// The SPL calculator programs take the pool's SplPoolConfig PDA
+/// as an additional account after the common LstToSol/SolToLst accounts
+pub const SPL_LST_SOL_ACCOUNTS_LEN: usize = LST_TO_SOL_IX_ACCOUNTS_LEN + 1;
 
 pub fn deserialize_spl_stake_pool_checked<S: ReadonlyAccountData + ReadonlyAccountOwner>(
     spl_stake_pool: S,
@@ -131,9 +141,9 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner>
 
     pub fn resolve_spl_to_account_metas(
         self,
-    ) -> Result<[AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN], GenericPoolCalculatorError> {
+    ) -> Result<[AccountMeta; SPL_LST_SOL_ACCOUNTS_LEN], GenericPoolCalculatorError> {
         let keys = self.resolve_spl()?;
-        Ok(resolve_to_account_metas_for_calc::<SplSolValCalc>(keys))
+        Ok(resolve_spl_to_account_metas_for_calc::<SplSolValCalc>(keys))
     }
 
     pub fn resolve_sanctum_spl(
@@ -148,11 +158,9 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner>
 
     pub fn resolve_sanctum_spl_to_account_metas(
         self,
-    ) -> Result<[AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN], GenericPoolCalculatorError> {
+    ) -> Result<[AccountMeta; SPL_LST_SOL_ACCOUNTS_LEN], GenericPoolCalculatorError> {
         let keys = self.resolve_sanctum_spl()?;
-        Ok(resolve_to_account_metas_for_calc::<SanctumSplSolValCalc>(
-            keys,
-        ))
+        Ok(resolve_spl_to_account_metas_for_calc::<SanctumSplSolValCalc>(keys))
     }
 
     pub fn resolve_sanctum_spl_multi(
@@ -167,9 +175,11 @@ impl<S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner>
 
     pub fn resolve_sanctum_spl_multi_to_account_metas(
         self,
-    ) -> Result<[AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN], GenericPoolCalculatorError> {
+    ) -> Result<[AccountMeta; SPL_LST_SOL_ACCOUNTS_LEN], GenericPoolCalculatorError> {
         let keys = self.resolve_sanctum_spl_multi()?;
-        Ok(resolve_to_account_metas_for_calc::<SanctumSplMultiSolValCalc>(keys))
+        Ok(resolve_spl_to_account_metas_for_calc::<
+            SanctumSplMultiSolValCalc,
+        >(keys))
     }
 }
 
@@ -179,3 +189,148 @@ pub fn resolve_to_account_metas_for_calc<T: GenericPoolSolValCalc>(
     let keys: generic_pool_calculator_interface::LstToSolKeys = keys.resolve::<T>().into();
     keys.into()
 }
+
+/// [`resolve_to_account_metas_for_calc`] followed by the stake pool's SplPoolConfig PDA,
+/// for the SPL calculator programs
+pub fn resolve_spl_to_account_metas_for_calc<T: GenericPoolSolValCalc>(
+    keys: LstSolCommonIntermediateKeys,
+) -> [AccountMeta; SPL_LST_SOL_ACCOUNTS_LEN] {
+    let pool_config = SplPoolConfigFindPdaArgs {
+        program_id: T::ID,
+        pool_state: keys.pool_state,
+    }
+    .get_spl_pool_config_address_and_bump_seed()
+    .0;
+    let [lst_mint, state, pool_state, pool_program, pool_program_data] =
+        resolve_to_account_metas_for_calc::<T>(keys);
+    [
+        lst_mint,
+        state,
+        pool_state,
+        pool_program,
+        pool_program_data,
+        AccountMeta::new_readonly(pool_config, false),
+    ]
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetValuationModeFreeArgs<S, P> {
+    pub payer: Pubkey,
+    pub state: S,
+    pub pool_state: P,
+}
+
+impl<
+        S: ReadonlyAccountPubkey + ReadonlyAccountData,
+        P: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+    > SetValuationModeFreeArgs<S, P>
+{
+    /// Also returns the bump of the resolved SplPoolConfig PDA
+    pub fn resolve<T: GenericPoolSolValCalc>(
+        self,
+    ) -> Result<(SetValuationModeKeys, u8), GenericPoolCalculatorError> {
+        if *self.state.pubkey() != T::CALCULATOR_STATE_PDA {
+            return Err(GenericPoolCalculatorError::WrongCalculatorStatePda);
+        }
+        deserialize_stake_pool_check_program_owner(&self.pool_state, T::POOL_PROGRAM_ID)?;
+
+        let manager = {
+            let bytes = self.state.data();
+            try_calculator_state(&bytes)?.manager
+        };
+        let pool_state = *self.pool_state.pubkey();
+        let (pool_config, bump) = SplPoolConfigFindPdaArgs {
+            program_id: T::ID,
+            pool_state,
+        }
+        .get_spl_pool_config_address_and_bump_seed();
+
+        Ok((
+            SetValuationModeKeys {
+                payer: self.payer,
+                manager,
+                state: T::CALCULATOR_STATE_PDA,
+                pool_state,
+                pool_config,
+                system_program: system_program::ID,
+            },
+            bump,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetWithdrawalFeeGuardFreeArgs<S, P> {
+    pub payer: Pubkey,
+    pub state: S,
+    pub pool_state: P,
+}
+
+impl<
+        S: ReadonlyAccountPubkey + ReadonlyAccountData,
+        P: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+    > SetWithdrawalFeeGuardFreeArgs<S, P>
+{
+    /// Also returns the bump of the resolved SplPoolConfig PDA
+    pub fn resolve<T: GenericPoolSolValCalc>(
+        self,
+    ) -> Result<(SetWithdrawalFeeGuardKeys, u8), GenericPoolCalculatorError> {
+        let (
+            SetValuationModeKeys {
+                payer,
+                manager,
+                state,
+                pool_state,
+                pool_config,
+                system_program,
+            },
+            bump,
+        ) = SetValuationModeFreeArgs {
+            payer: self.payer,
+            state: self.state,
+            pool_state: self.pool_state,
+        }
+        .resolve::<T>()?;
+        Ok((
+            SetWithdrawalFeeGuardKeys {
+                payer,
+                manager,
+                state,
+                pool_state,
+                pool_config,
+                system_program,
+            },
+            bump,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RecordWithdrawalFeesFreeArgs<P> {
+    pub pool_state: P,
+}
+
+impl<P: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner>
+    RecordWithdrawalFeesFreeArgs<P>
+{
+    /// Also returns the deserialized stake pool
+    pub fn resolve<T: GenericPoolSolValCalc>(
+        self,
+    ) -> Result<(RecordWithdrawalFeesKeys, SplStakePool), GenericPoolCalculatorError> {
+        let stake_pool =
+            deserialize_stake_pool_check_program_owner(&self.pool_state, T::POOL_PROGRAM_ID)?;
+        let pool_state = *self.pool_state.pubkey();
+        let (pool_config, _bump) = SplPoolConfigFindPdaArgs {
+            program_id: T::ID,
+            pool_state,
+        }
+        .get_spl_pool_config_address_and_bump_seed();
+        Ok((
+            RecordWithdrawalFeesKeys {
+                pool_state,
+                pool_config,
+            },
+            stake_pool,
+        ))
+    }
+}
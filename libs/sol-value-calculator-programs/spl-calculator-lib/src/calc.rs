@@ -2,10 +2,14 @@ use sanctum_token_ratio::{
     CeilDiv, FloorDiv, MathError, ReversibleFee, ReversibleRatio, U64FeeRatio, U64Ratio,
     U64ValueRange,
 };
-use sol_value_calculator_lib::SolValueCalculator;
+use sol_value_calculator_lib::{
+    stale_discount_bps_for_epoch, PoolNotUpdatedErr, SolValueCalculator, StaleDiscountCalc,
+};
 use solana_program::program_error::ProgramError;
 use spl_calculator_interface::{Fee, SplCalculatorError, SplStakePool};
 
+use crate::{is_fee_higher, SplValuationMode};
+
 /// Parameters from SplStakePool required to calculate SOL value
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SplStakePoolCalc {
@@ -14,6 +18,9 @@ pub struct SplStakePoolCalc {
     pub pool_token_supply: u64,
     pub stake_withdrawal_fee_numerator: u64,
     pub stake_withdrawal_fee_denominator: u64,
+    pub sol_withdrawal_fee_numerator: u64,
+    pub sol_withdrawal_fee_denominator: u64,
+    pub valuation_mode: SplValuationMode,
 }
 
 impl From<&SplStakePool> for SplStakePoolCalc {
@@ -22,11 +29,8 @@ impl From<&SplStakePool> for SplStakePoolCalc {
             total_lamports,
             pool_token_supply,
             last_update_epoch,
-            stake_withdrawal_fee:
-                Fee {
-                    denominator,
-                    numerator,
-                },
+            stake_withdrawal_fee,
+            sol_withdrawal_fee,
             ..
         }: &SplStakePool,
     ) -> Self {
@@ -34,8 +38,11 @@ impl From<&SplStakePool> for SplStakePoolCalc {
             last_update_epoch: *last_update_epoch,
             total_lamports: *total_lamports,
             pool_token_supply: *pool_token_supply,
-            stake_withdrawal_fee_numerator: *numerator,
-            stake_withdrawal_fee_denominator: *denominator,
+            stake_withdrawal_fee_numerator: stake_withdrawal_fee.numerator,
+            stake_withdrawal_fee_denominator: stake_withdrawal_fee.denominator,
+            sol_withdrawal_fee_numerator: sol_withdrawal_fee.numerator,
+            sol_withdrawal_fee_denominator: sol_withdrawal_fee.denominator,
+            valuation_mode: SplValuationMode::default(),
         }
     }
 }
@@ -47,6 +54,13 @@ impl From<SplStakePool> for SplStakePoolCalc {
 }
 
 impl SplStakePoolCalc {
+    pub const fn with_valuation_mode(self, valuation_mode: SplValuationMode) -> Self {
+        Self {
+            valuation_mode,
+            ..self
+        }
+    }
+
     pub const fn verify_pool_updated_for_this_epoch(
         &self,
         this_epoch: u64,
@@ -58,6 +72,23 @@ impl SplStakePoolCalc {
         }
     }
 
+    /// Like [`Self::verify_pool_updated_for_this_epoch`], but also accepts a pool
+    /// last updated exactly one epoch ago if `stale_discount_bps` is nonzero,
+    /// returning a calculator that values it conservatively.
+    pub const fn verify_pool_updated_or_stale_bounded(
+        self,
+        this_epoch: u64,
+        stale_discount_bps: u16,
+    ) -> Result<StaleDiscountCalc<Self>, SplCalculatorError> {
+        match stale_discount_bps_for_epoch(self.last_update_epoch, this_epoch, stale_discount_bps) {
+            Some(stale_discount_bps) => Ok(StaleDiscountCalc {
+                calc: self,
+                stale_discount_bps,
+            }),
+            None => Err(SplCalculatorError::PoolNotUpdated),
+        }
+    }
+
     pub const fn lst_to_lamports_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
         let Self {
             total_lamports,
@@ -77,18 +108,71 @@ impl SplStakePoolCalc {
         )
         .map(CeilDiv)
     }
+
+    pub fn sol_withdrawal_fee(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError> {
+        U64FeeRatio::try_from_fee_num_and_denom(
+            self.sol_withdrawal_fee_numerator,
+            self.sol_withdrawal_fee_denominator,
+        )
+        .map(CeilDiv)
+    }
+
+    /// The withdrawal fee charged under [`Self::valuation_mode`].
+    ///
+    /// Both withdrawal paths apply their fee on pool tokens before converting
+    /// to lamports at the same rate, so the lower valuation of
+    /// [`SplValuationMode::MinOfBoth`] is always the one with the higher fee.
+    pub fn withdrawal_fee(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError> {
+        match self.valuation_mode {
+            SplValuationMode::StakeWithdrawal => self.stake_withdrawal_fee(),
+            SplValuationMode::SolWithdrawal => self.sol_withdrawal_fee(),
+            SplValuationMode::MinOfBoth => {
+                if self.is_sol_withdrawal_fee_higher() {
+                    self.sol_withdrawal_fee()
+                } else {
+                    self.stake_withdrawal_fee()
+                }
+            }
+        }
+    }
+
+    fn is_sol_withdrawal_fee_higher(&self) -> bool {
+        is_fee_higher(
+            &Fee {
+                denominator: self.sol_withdrawal_fee_denominator,
+                numerator: self.sol_withdrawal_fee_numerator,
+            },
+            &Fee {
+                denominator: self.stake_withdrawal_fee_denominator,
+                numerator: self.stake_withdrawal_fee_numerator,
+            },
+        )
+    }
 }
 
+/// Values LSTs by withdrawing them from the stake pool via the path selected by
+/// [`SplStakePoolCalc::valuation_mode`]. [`SplValuationMode::MinOfBoth`] uses whichever
+/// path has the higher fee.
+///
 /// Assumes:
-/// - stake pool manager is always valid, so stake withdraw fee will always be charged
-/// - stake pool always has active and transient stake, so withdraw_source != StakeWithdrawSource::ValidatorRemoval
-/// - stake pool has been updated for this epoch
+/// - stake pool manager is always valid, so withdrawal fees will always be charged
+/// - for stake withdrawals ([`SplValuationMode::StakeWithdrawal`], or [`SplValuationMode::MinOfBoth`]
+///   with the higher stake withdrawal fee), stake pool always has active and transient stake,
+///   so withdraw_source != StakeWithdrawSource::ValidatorRemoval
+/// - for SOL withdrawals ([`SplValuationMode::SolWithdrawal`], or [`SplValuationMode::MinOfBoth`]
+///   with the higher SOL withdrawal fee), the reserve has enough lamports to service the withdrawal
+/// - stake pool has been updated for this epoch, checked with
+///   [`SplStakePoolCalc::verify_pool_updated_for_this_epoch`]. Otherwise, if stale valuation is enabled,
+///   it was updated exactly one epoch ago, checked with [`SplStakePoolCalc::verify_pool_updated_or_stale_bounded`],
+///   and the returned [`StaleDiscountCalc`] must be used instead of this so that
+///   the LST is only valued as an output, discounted by `stale_discount_bps`
 impl SolValueCalculator for SplStakePoolCalc {
     // Reference:
     // https://github.com/solana-labs/solana-program-library/blob/c225e8025f7dbf3134683ec387671b9251a4606c/stake-pool/program/src/processor.rs#L3169
-    // applies fees on pool_tokens first and then converts amt_after_fee to lamports equivalent
+    // applies fees on pool_tokens first and then converts amt_after_fee to lamports equivalent.
+    // process_withdraw_sol does the same with sol_withdrawal_fee
     fn calc_lst_to_sol(&self, pool_tokens: u64) -> Result<U64ValueRange, ProgramError> {
-        let aaf = self.stake_withdrawal_fee()?.apply(pool_tokens)?;
+        let aaf = self.withdrawal_fee()?.apply(pool_tokens)?;
         let pool_tokens_burnt = aaf.amt_after_fee();
         let withdraw_lamports = self.lst_to_lamports_ratio().apply(pool_tokens_burnt)?;
         Ok(U64ValueRange::single(withdraw_lamports))
@@ -96,13 +180,19 @@ impl SolValueCalculator for SplStakePoolCalc {
 
     fn calc_sol_to_lst(&self, withdraw_lamports: u64) -> Result<U64ValueRange, ProgramError> {
         let r = self.lst_to_lamports_ratio().reverse(withdraw_lamports)?;
-        let fee = self.stake_withdrawal_fee()?;
+        let fee = self.withdrawal_fee()?;
         let min = fee.reverse_from_amt_after_fee(r.get_min())?.get_min();
         let max = fee.reverse_from_amt_after_fee(r.get_max())?.get_max();
         Ok(U64ValueRange::try_from_min_max(min, max)?)
     }
 }
 
+impl PoolNotUpdatedErr for SplStakePoolCalc {
+    fn pool_not_updated_err() -> ProgramError {
+        SplCalculatorError::PoolNotUpdated.into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,15 +206,32 @@ mod tests {
             }
     }
 
+    fn valuation_mode() -> impl Strategy<Value = SplValuationMode> {
+        prop_oneof![
+            Just(SplValuationMode::StakeWithdrawal),
+            Just(SplValuationMode::SolWithdrawal),
+            Just(SplValuationMode::MinOfBoth),
+        ]
+    }
+
     prop_compose! {
         fn spl_stake_pool_calc()
-            (Fee { denominator, numerator } in fee_rate_lte_one(), total_lamports: u64, pool_token_supply: u64) -> SplStakePoolCalc {
+            (
+                stake_withdrawal_fee in fee_rate_lte_one(),
+                sol_withdrawal_fee in fee_rate_lte_one(),
+                valuation_mode in valuation_mode(),
+                total_lamports: u64,
+                pool_token_supply: u64,
+            ) -> SplStakePoolCalc {
                 SplStakePoolCalc {
                     last_update_epoch: 0,
                     total_lamports,
                     pool_token_supply,
-                    stake_withdrawal_fee_numerator: numerator,
-                    stake_withdrawal_fee_denominator: denominator,
+                    stake_withdrawal_fee_numerator: stake_withdrawal_fee.numerator,
+                    stake_withdrawal_fee_denominator: stake_withdrawal_fee.denominator,
+                    sol_withdrawal_fee_numerator: sol_withdrawal_fee.numerator,
+                    sol_withdrawal_fee_denominator: sol_withdrawal_fee.denominator,
+                    valuation_mode,
                 }
             }
     }
@@ -158,5 +265,25 @@ mod tests {
             prop_assert!(sol_amt <= max_round_trip.get_min(), "{sol_amt} {}", max_round_trip.get_min());
             prop_assert!(sol_amt <= max_round_trip.get_max(), "{sol_amt} {}", max_round_trip.get_max());
         }
+
+        #[test]
+        fn min_of_both_lte_either_mode((pool_tokens, calc) in spl_stake_pool_and_lst_amount()) {
+            let min_of_both = calc
+                .with_valuation_mode(SplValuationMode::MinOfBoth)
+                .calc_lst_to_sol(pool_tokens)
+                .unwrap()
+                .get_max();
+            let stake = calc
+                .with_valuation_mode(SplValuationMode::StakeWithdrawal)
+                .calc_lst_to_sol(pool_tokens)
+                .unwrap()
+                .get_max();
+            let sol = calc
+                .with_valuation_mode(SplValuationMode::SolWithdrawal)
+                .calc_lst_to_sol(pool_tokens)
+                .unwrap()
+                .get_max();
+            prop_assert_eq!(min_of_both, stake.min(sol));
+        }
     }
 }
@@ -1,3 +1,13 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    pubkey::Pubkey,
+};
+
+use crate::pda::SplPoolConfigFindPdaArgs;
+
 mod sanctum_spl;
 mod sanctum_spl_multi;
 mod spl;
@@ -5,3 +15,32 @@ mod spl;
 pub use sanctum_spl::*;
 pub use sanctum_spl_multi::*;
 pub use spl::*;
+
+/// The SPL calculator programs expect the stake pool's SplPoolConfig PDA
+/// after the common LstToSol/SolToLst accounts
+fn ix_with_spl_pool_config(
+    mut ix: Instruction,
+    program_id: Pubkey,
+    pool_state: Pubkey,
+) -> Instruction {
+    let pool_config = SplPoolConfigFindPdaArgs {
+        program_id,
+        pool_state,
+    }
+    .get_spl_pool_config_address_and_bump_seed()
+    .0;
+    ix.accounts
+        .push(AccountMeta::new_readonly(pool_config, false));
+    ix
+}
+
+fn invoke_signed_with_spl_pool_config<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    pool_config: &AccountInfo<'info>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let mut account_infos = Vec::from(accounts.into());
+    account_infos.push(pool_config.clone());
+    invoke_signed(ix, &account_infos, seeds)
+}
@@ -1,5 +1,19 @@
 use generic_pool_calculator_interface::*;
-use solana_program::{entrypoint::ProgramResult, instruction::Instruction, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction, pubkey::Pubkey,
+};
+use spl_calculator_interface::{
+    record_withdrawal_fees_invoke_signed_with_program_id,
+    record_withdrawal_fees_invoke_with_program_id, record_withdrawal_fees_ix_with_program_id,
+    set_valuation_mode_invoke_signed_with_program_id, set_valuation_mode_invoke_with_program_id,
+    set_valuation_mode_ix_with_program_id, set_withdrawal_fee_guard_invoke_signed_with_program_id,
+    set_withdrawal_fee_guard_invoke_with_program_id, set_withdrawal_fee_guard_ix_with_program_id,
+    RecordWithdrawalFeesAccounts, RecordWithdrawalFeesKeys, SetValuationModeAccounts,
+    SetValuationModeIxArgs, SetValuationModeKeys, SetWithdrawalFeeGuardAccounts,
+    SetWithdrawalFeeGuardIxArgs, SetWithdrawalFeeGuardKeys,
+};
+
+use super::{invoke_signed_with_spl_pool_config, ix_with_spl_pool_config};
 
 const SVC_PROGRAM_ID: Pubkey = crate::sanctum_spl_sol_val_calc_program::ID;
 
@@ -7,41 +21,75 @@ pub fn sanctum_spl_lst_to_sol_ix(
     keys: LstToSolKeys,
     args: LstToSolIxArgs,
 ) -> std::io::Result<Instruction> {
-    lst_to_sol_ix_with_program_id(SVC_PROGRAM_ID, keys, args)
+    let pool_state = keys.pool_state;
+    let ix = lst_to_sol_ix_with_program_id(SVC_PROGRAM_ID, keys, args)?;
+    Ok(ix_with_spl_pool_config(ix, SVC_PROGRAM_ID, pool_state))
 }
-
-pub fn sanctum_spl_lst_to_sol_invoke(
-    accounts: LstToSolAccounts,
+pub fn sanctum_spl_lst_to_sol_invoke<'info>(
+    accounts: LstToSolAccounts<'_, 'info>,
+    pool_config: &AccountInfo<'info>,
     args: LstToSolIxArgs,
 ) -> ProgramResult {
-    lst_to_sol_invoke_with_program_id(SVC_PROGRAM_ID, accounts, args)
+    sanctum_spl_lst_to_sol_invoke_signed(accounts, pool_config, args, &[])
 }
-pub fn sanctum_spl_lst_to_sol_invoke_signed(
-    accounts: LstToSolAccounts,
+pub fn sanctum_spl_lst_to_sol_invoke_signed<'info>(
+    accounts: LstToSolAccounts<'_, 'info>,
+    pool_config: &AccountInfo<'info>,
     args: LstToSolIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    lst_to_sol_invoke_signed_with_program_id(SVC_PROGRAM_ID, accounts, args, seeds)
+    let ix = sanctum_spl_lst_to_sol_ix(accounts.into(), args)?;
+    invoke_signed_with_spl_pool_config(&ix, accounts, pool_config, seeds)
 }
 
 pub fn sanctum_spl_sol_to_lst_ix(
     keys: SolToLstKeys,
     args: SolToLstIxArgs,
 ) -> std::io::Result<Instruction> {
-    sol_to_lst_ix_with_program_id(SVC_PROGRAM_ID, keys, args)
+    let pool_state = keys.pool_state;
+    let ix = sol_to_lst_ix_with_program_id(SVC_PROGRAM_ID, keys, args)?;
+    Ok(ix_with_spl_pool_config(ix, SVC_PROGRAM_ID, pool_state))
 }
-pub fn sanctum_spl_sol_to_lst_invoke(
-    accounts: SolToLstAccounts,
+pub fn sanctum_spl_sol_to_lst_invoke<'info>(
+    accounts: SolToLstAccounts<'_, 'info>,
+    pool_config: &AccountInfo<'info>,
     args: SolToLstIxArgs,
 ) -> ProgramResult {
-    sol_to_lst_invoke_with_program_id(SVC_PROGRAM_ID, accounts, args)
+    sanctum_spl_sol_to_lst_invoke_signed(accounts, pool_config, args, &[])
 }
-pub fn sanctum_spl_sol_to_lst_invoke_signed(
-    accounts: SolToLstAccounts,
+pub fn sanctum_spl_sol_to_lst_invoke_signed<'info>(
+    accounts: SolToLstAccounts<'_, 'info>,
+    pool_config: &AccountInfo<'info>,
     args: SolToLstIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    sol_to_lst_invoke_signed_with_program_id(SVC_PROGRAM_ID, accounts, args, seeds)
+    let ix = sanctum_spl_sol_to_lst_ix(accounts.into(), args)?;
+    invoke_signed_with_spl_pool_config(&ix, accounts, pool_config, seeds)
+}
+
+pub fn sanctum_spl_batch_calc_ix(
+    keys: BatchCalcKeys,
+    args: BatchCalcIxArgs,
+) -> std::io::Result<Instruction> {
+    let pool_state = keys.pool_state;
+    let ix = batch_calc_ix_with_program_id(SVC_PROGRAM_ID, keys, args)?;
+    Ok(ix_with_spl_pool_config(ix, SVC_PROGRAM_ID, pool_state))
+}
+pub fn sanctum_spl_batch_calc_invoke<'info>(
+    accounts: BatchCalcAccounts<'_, 'info>,
+    pool_config: &AccountInfo<'info>,
+    args: BatchCalcIxArgs,
+) -> ProgramResult {
+    sanctum_spl_batch_calc_invoke_signed(accounts, pool_config, args, &[])
+}
+pub fn sanctum_spl_batch_calc_invoke_signed<'info>(
+    accounts: BatchCalcAccounts<'_, 'info>,
+    pool_config: &AccountInfo<'info>,
+    args: BatchCalcIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = sanctum_spl_batch_calc_ix(accounts.into(), args)?;
+    invoke_signed_with_spl_pool_config(&ix, accounts, pool_config, seeds)
 }
 
 pub fn sanctum_spl_update_last_upgrade_slot_ix(
@@ -83,3 +131,60 @@ pub fn sanctum_spl_init_invoke(accounts: InitAccounts) -> ProgramResult {
 pub fn sanctum_spl_init_invoke_signed(accounts: InitAccounts, seeds: &[&[&[u8]]]) -> ProgramResult {
     init_invoke_signed_with_program_id(SVC_PROGRAM_ID, accounts, seeds)
 }
+
+pub fn sanctum_spl_set_valuation_mode_ix(
+    keys: SetValuationModeKeys,
+    args: SetValuationModeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_valuation_mode_ix_with_program_id(SVC_PROGRAM_ID, keys, args)
+}
+pub fn sanctum_spl_set_valuation_mode_invoke(
+    accounts: SetValuationModeAccounts,
+    args: SetValuationModeIxArgs,
+) -> ProgramResult {
+    set_valuation_mode_invoke_with_program_id(SVC_PROGRAM_ID, accounts, args)
+}
+pub fn sanctum_spl_set_valuation_mode_invoke_signed(
+    accounts: SetValuationModeAccounts,
+    args: SetValuationModeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_valuation_mode_invoke_signed_with_program_id(SVC_PROGRAM_ID, accounts, args, seeds)
+}
+
+pub fn sanctum_spl_set_withdrawal_fee_guard_ix(
+    keys: SetWithdrawalFeeGuardKeys,
+    args: SetWithdrawalFeeGuardIxArgs,
+) -> std::io::Result<Instruction> {
+    set_withdrawal_fee_guard_ix_with_program_id(SVC_PROGRAM_ID, keys, args)
+}
+pub fn sanctum_spl_set_withdrawal_fee_guard_invoke(
+    accounts: SetWithdrawalFeeGuardAccounts,
+    args: SetWithdrawalFeeGuardIxArgs,
+) -> ProgramResult {
+    set_withdrawal_fee_guard_invoke_with_program_id(SVC_PROGRAM_ID, accounts, args)
+}
+pub fn sanctum_spl_set_withdrawal_fee_guard_invoke_signed(
+    accounts: SetWithdrawalFeeGuardAccounts,
+    args: SetWithdrawalFeeGuardIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_withdrawal_fee_guard_invoke_signed_with_program_id(SVC_PROGRAM_ID, accounts, args, seeds)
+}
+
+pub fn sanctum_spl_record_withdrawal_fees_ix(
+    keys: RecordWithdrawalFeesKeys,
+) -> std::io::Result<Instruction> {
+    record_withdrawal_fees_ix_with_program_id(SVC_PROGRAM_ID, keys)
+}
+pub fn sanctum_spl_record_withdrawal_fees_invoke(
+    accounts: RecordWithdrawalFeesAccounts,
+) -> ProgramResult {
+    record_withdrawal_fees_invoke_with_program_id(SVC_PROGRAM_ID, accounts)
+}
+pub fn sanctum_spl_record_withdrawal_fees_invoke_signed(
+    accounts: RecordWithdrawalFeesAccounts,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    record_withdrawal_fees_invoke_signed_with_program_id(SVC_PROGRAM_ID, accounts, seeds)
+}
@@ -3,10 +3,26 @@ use solana_program::pubkey::Pubkey;
 mod account_resolvers;
 mod calc;
 mod instructions;
+mod pool_config;
+mod valuation_mode;
+mod withdrawal_fee_guard;
+
+pub mod pda;
 
 pub use account_resolvers::*;
 pub use calc::*;
 pub use instructions::*;
+pub use pool_config::*;
+pub use valuation_mode::*;
+pub use withdrawal_fee_guard::*;
+
+// std::mem::size_of is a const fn so we dont technically need this
+// but this assert helps guard against unexpected size changes
+pub const SPL_POOL_CONFIG_SIZE: usize = 72;
+static_assertions::const_assert_eq!(
+    std::mem::size_of::<spl_calculator_interface::SplPoolConfig>(),
+    SPL_POOL_CONFIG_SIZE
+);
 
 pub mod program {
     sanctum_macros::declare_program_keys!(
@@ -0,0 +1,36 @@
+use solana_program::pubkey::{Pubkey, PubkeyError};
+
+pub const SPL_POOL_CONFIG_SEED_PREFIX: &[u8] = b"pool_config";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SplPoolConfigFindPdaArgs {
+    pub program_id: Pubkey,
+    pub pool_state: Pubkey,
+}
+
+impl SplPoolConfigFindPdaArgs {
+    pub fn to_seed(&self) -> [&[u8]; 2] {
+        [SPL_POOL_CONFIG_SEED_PREFIX, self.pool_state.as_ref()]
+    }
+
+    pub fn get_spl_pool_config_address_and_bump_seed(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&self.to_seed(), &self.program_id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SplPoolConfigCreatePdaArgs {
+    pub find_pda_args: SplPoolConfigFindPdaArgs,
+    pub bump: u8,
+}
+
+impl SplPoolConfigCreatePdaArgs {
+    pub fn to_signer_seeds(&self) -> [&[u8]; 3] {
+        let [prefix, pool_state] = self.find_pda_args.to_seed();
+        [prefix, pool_state, std::slice::from_ref(&self.bump)]
+    }
+
+    pub fn get_spl_pool_config_address(&self) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(&self.to_signer_seeds(), &self.find_pda_args.program_id)
+    }
+}
@@ -0,0 +1,89 @@
+use bytemuck::{try_from_bytes, try_from_bytes_mut};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+use spl_calculator_interface::{SplCalculatorError, SplPoolConfig};
+
+use crate::{
+    pda::{SplPoolConfigCreatePdaArgs, SplPoolConfigFindPdaArgs},
+    SplValuationMode, WithdrawalFeeGuard,
+};
+
+/// Tries to reinterpret spl_pool_config_acc_data bytes as a SplPoolConfig
+pub fn try_spl_pool_config(
+    spl_pool_config_acc_data: &[u8],
+) -> Result<&SplPoolConfig, SplCalculatorError> {
+    try_from_bytes(spl_pool_config_acc_data).map_err(|_e| SplCalculatorError::IncorrectPoolConfig)
+}
+
+/// Tries to reinterpret spl_pool_config_acc_data bytes as a mutable SplPoolConfig
+pub fn try_spl_pool_config_mut(
+    spl_pool_config_acc_data: &mut [u8],
+) -> Result<&mut SplPoolConfig, SplCalculatorError> {
+    try_from_bytes_mut(spl_pool_config_acc_data)
+        .map_err(|_e| SplCalculatorError::IncorrectPoolConfig)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ReadSplPoolConfigArgs<C> {
+    /// The calculator program whose config is being read
+    pub program_id: Pubkey,
+    pub pool_state: Pubkey,
+    pub pool_config: C,
+}
+
+/// The parameters an SPL calculator program values a stake pool's LST with,
+/// read from its SplPoolConfig PDA
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SplPoolParams {
+    pub valuation_mode: SplValuationMode,
+    pub withdrawal_fee_guard: WithdrawalFeeGuard,
+}
+
+impl TryFrom<&SplPoolConfig> for SplPoolParams {
+    type Error = SplCalculatorError;
+
+    fn try_from(config: &SplPoolConfig) -> Result<Self, Self::Error> {
+        Ok(Self {
+            valuation_mode: config.valuation_mode.try_into()?,
+            withdrawal_fee_guard: config.into(),
+        })
+    }
+}
+
+/// Reads the parameters configured for `pool_state`,
+/// returning the defaults if its config PDA is not yet initialized.
+///
+/// Checks that `pool_config` is the correct PDA either way.
+pub fn read_spl_pool_params<
+    C: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+>(
+    ReadSplPoolConfigArgs {
+        program_id,
+        pool_state,
+        pool_config,
+    }: ReadSplPoolConfigArgs<C>,
+) -> Result<SplPoolParams, SplCalculatorError> {
+    let find_pda_args = SplPoolConfigFindPdaArgs {
+        program_id,
+        pool_state,
+    };
+    let data = pool_config.data();
+    if *pool_config.owner() != program_id || data.is_empty() {
+        let (expected, _bump) = find_pda_args.get_spl_pool_config_address_and_bump_seed();
+        if *pool_config.pubkey() != expected {
+            return Err(SplCalculatorError::IncorrectPoolConfig);
+        }
+        return Ok(SplPoolParams::default());
+    }
+    let config = try_spl_pool_config(&data)?;
+    let expected = SplPoolConfigCreatePdaArgs {
+        find_pda_args,
+        bump: config.bump,
+    }
+    .get_spl_pool_config_address()
+    .map_err(|_e| SplCalculatorError::IncorrectPoolConfig)?;
+    if *pool_config.pubkey() != expected {
+        return Err(SplCalculatorError::IncorrectPoolConfig);
+    }
+    config.try_into()
+}
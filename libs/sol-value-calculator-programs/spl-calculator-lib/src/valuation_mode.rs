@@ -0,0 +1,37 @@
+use spl_calculator_interface::SplCalculatorError;
+
+/// How an SPL stake pool's LST is valued, stored as
+/// `SplPoolConfig.valuation_mode` in the pool's config PDA.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SplValuationMode {
+    /// Value at the pool's stake withdrawal rate, charging `stake_withdrawal_fee`.
+    /// This is the mode used for pools without an initialized config.
+    #[default]
+    StakeWithdrawal = 0,
+
+    /// Value at the pool's SOL withdrawal rate, charging `sol_withdrawal_fee`.
+    SolWithdrawal = 1,
+
+    /// Value at the lower of the stake and SOL withdrawal rates.
+    MinOfBoth = 2,
+}
+
+impl TryFrom<u8> for SplValuationMode {
+    type Error = SplCalculatorError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::StakeWithdrawal),
+            1 => Ok(Self::SolWithdrawal),
+            2 => Ok(Self::MinOfBoth),
+            _ => Err(SplCalculatorError::InvalidValuationMode),
+        }
+    }
+}
+
+impl From<SplValuationMode> for u8 {
+    fn from(value: SplValuationMode) -> Self {
+        value as u8
+    }
+}
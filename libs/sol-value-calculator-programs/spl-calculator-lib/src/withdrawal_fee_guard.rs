@@ -0,0 +1,281 @@
+use spl_calculator_interface::{
+    Fee, FutureEpochFee, SplCalculatorError, SplPoolConfig, SplStakePool,
+};
+
+/// The stake withdrawal fees of a stake pool tracked by its SplPoolConfig
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StakeWithdrawalFees {
+    pub stake_withdrawal_fee: Fee,
+
+    /// Whether the scheduled fee takes effect in one or two epochs' time is ignored,
+    /// else every stake pool update would count as a fee change
+    pub next_stake_withdrawal_fee: Option<Fee>,
+}
+
+impl From<&SplStakePool> for StakeWithdrawalFees {
+    fn from(
+        SplStakePool {
+            stake_withdrawal_fee,
+            next_stake_withdrawal_fee,
+            ..
+        }: &SplStakePool,
+    ) -> Self {
+        Self {
+            stake_withdrawal_fee: *stake_withdrawal_fee,
+            next_stake_withdrawal_fee: match next_stake_withdrawal_fee {
+                FutureEpochFee::None => None,
+                FutureEpochFee::One(fee) | FutureEpochFee::Two(fee) => Some(*fee),
+            },
+        }
+    }
+}
+
+/// Manager-configured checks on a stake pool's stake withdrawal fees,
+/// parsed from its SplPoolConfig
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WithdrawalFeeGuard {
+    /// Number of epochs after a recorded fee change during which the LST is not valued.
+    /// 0 disables fee change detection.
+    pub fee_change_window_epochs: u64,
+
+    pub last_fee_change_epoch: u64,
+
+    /// `None` if there is no ceiling
+    pub max_stake_withdrawal_fee: Option<Fee>,
+
+    pub recorded_fees: StakeWithdrawalFees,
+}
+
+impl WithdrawalFeeGuard {
+    /// The guard of stake pools without an initialized SplPoolConfig
+    pub const DISABLED: Self = Self {
+        fee_change_window_epochs: 0,
+        last_fee_change_epoch: 0,
+        max_stake_withdrawal_fee: None,
+        recorded_fees: StakeWithdrawalFees {
+            stake_withdrawal_fee: Fee {
+                denominator: 0,
+                numerator: 0,
+            },
+            next_stake_withdrawal_fee: None,
+        },
+    };
+
+    /// Checks the stake pool's current `fees` against this guard.
+    ///
+    /// With fee change detection enabled, fees that differ from the recorded ones
+    /// are treated as a change that happened this epoch.
+    pub fn verify(
+        &self,
+        fees: &StakeWithdrawalFees,
+        current_epoch: u64,
+    ) -> Result<(), SplCalculatorError> {
+        if let Some(max) = &self.max_stake_withdrawal_fee {
+            let exceeds_max = |fee: &Fee| is_fee_higher(fee, max);
+            if exceeds_max(&fees.stake_withdrawal_fee)
+                || fees
+                    .next_stake_withdrawal_fee
+                    .as_ref()
+                    .is_some_and(exceeds_max)
+            {
+                return Err(SplCalculatorError::WithdrawalFeeAboveCeiling);
+            }
+        }
+        if self.fee_change_window_epochs == 0 {
+            return Ok(());
+        }
+        if *fees != self.recorded_fees
+            || current_epoch
+                < self
+                    .last_fee_change_epoch
+                    .saturating_add(self.fee_change_window_epochs)
+        {
+            return Err(SplCalculatorError::WithdrawalFeeChanged);
+        }
+        Ok(())
+    }
+}
+
+impl Default for WithdrawalFeeGuard {
+    fn default() -> Self {
+        Self::DISABLED
+    }
+}
+
+impl From<&SplPoolConfig> for WithdrawalFeeGuard {
+    fn from(
+        SplPoolConfig {
+            has_recorded_next_stake_withdrawal_fee,
+            fee_change_window_epochs,
+            last_fee_change_epoch,
+            max_stake_withdrawal_fee,
+            recorded_stake_withdrawal_fee,
+            recorded_next_stake_withdrawal_fee,
+            ..
+        }: &SplPoolConfig,
+    ) -> Self {
+        Self {
+            fee_change_window_epochs: *fee_change_window_epochs,
+            last_fee_change_epoch: *last_fee_change_epoch,
+            max_stake_withdrawal_fee: (max_stake_withdrawal_fee.denominator != 0)
+                .then_some(*max_stake_withdrawal_fee),
+            recorded_fees: StakeWithdrawalFees {
+                stake_withdrawal_fee: *recorded_stake_withdrawal_fee,
+                next_stake_withdrawal_fee: (*has_recorded_next_stake_withdrawal_fee != 0)
+                    .then_some(*recorded_next_stake_withdrawal_fee),
+            },
+        }
+    }
+}
+
+/// Overwrites `config`'s recorded fees without starting a new fee change window
+pub fn set_recorded_stake_withdrawal_fees(
+    config: &mut SplPoolConfig,
+    StakeWithdrawalFees {
+        stake_withdrawal_fee,
+        next_stake_withdrawal_fee,
+    }: &StakeWithdrawalFees,
+) {
+    config.recorded_stake_withdrawal_fee = *stake_withdrawal_fee;
+    config.has_recorded_next_stake_withdrawal_fee = u8::from(next_stake_withdrawal_fee.is_some());
+    config.recorded_next_stake_withdrawal_fee = next_stake_withdrawal_fee.unwrap_or(Fee {
+        denominator: 0,
+        numerator: 0,
+    });
+}
+
+/// Records `fees` as `config`'s last seen fees,
+/// starting a new fee change window at `current_epoch` if they differ from the recorded ones.
+///
+/// Returns whether the fees changed.
+pub fn record_stake_withdrawal_fees(
+    config: &mut SplPoolConfig,
+    fees: &StakeWithdrawalFees,
+    current_epoch: u64,
+) -> bool {
+    if WithdrawalFeeGuard::from(&*config).recorded_fees == *fees {
+        return false;
+    }
+    set_recorded_stake_withdrawal_fees(config, fees);
+    config.last_fee_change_epoch = current_epoch;
+    true
+}
+
+/// Returns whether fee rate `a` is strictly higher than fee rate `b`.
+///
+/// A zero denominator means a zero fee, as in the stake pool program
+pub fn is_fee_higher(a: &Fee, b: &Fee) -> bool {
+    let num = |fee: &Fee| {
+        if fee.denominator == 0 {
+            0
+        } else {
+            u128::from(fee.numerator)
+        }
+    };
+    num(a) * u128::from(b.denominator.max(1)) > num(b) * u128::from(a.denominator.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+    use proptest::prelude::*;
+
+    prop_compose! {
+        fn fee()
+            (denominator in any::<u64>(), numerator in any::<u64>()) -> Fee {
+                Fee { denominator, numerator }
+            }
+    }
+
+    prop_compose! {
+        fn stake_withdrawal_fees()
+            (stake_withdrawal_fee in fee(), next_stake_withdrawal_fee in proptest::option::of(fee()))
+            -> StakeWithdrawalFees {
+                StakeWithdrawalFees { stake_withdrawal_fee, next_stake_withdrawal_fee }
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn disabled_guard_accepts_any_fees(fees in stake_withdrawal_fees(), current_epoch: u64) {
+            prop_assert!(WithdrawalFeeGuard::DISABLED.verify(&fees, current_epoch).is_ok());
+        }
+
+        #[test]
+        fn recorded_fees_round_trip(
+            fees in stake_withdrawal_fees(),
+            other in stake_withdrawal_fees(),
+            current_epoch: u64,
+        ) {
+            let mut config = SplPoolConfig::zeroed();
+            set_recorded_stake_withdrawal_fees(&mut config, &other);
+            let changed = record_stake_withdrawal_fees(&mut config, &fees, current_epoch);
+            prop_assert_eq!(changed, fees != other);
+            let guard = WithdrawalFeeGuard::from(&config);
+            prop_assert_eq!(guard.recorded_fees, fees);
+            if changed {
+                prop_assert_eq!(guard.last_fee_change_epoch, current_epoch);
+            }
+            prop_assert!(!record_stake_withdrawal_fees(&mut config, &fees, current_epoch));
+        }
+
+        #[test]
+        fn fee_change_window(
+            fees in stake_withdrawal_fees(),
+            last_fee_change_epoch in 0..u64::MAX / 2,
+            fee_change_window_epochs in 1..u64::MAX / 2,
+            epochs_since_change in 0..u64::MAX / 2,
+        ) {
+            let guard = WithdrawalFeeGuard {
+                fee_change_window_epochs,
+                last_fee_change_epoch,
+                max_stake_withdrawal_fee: None,
+                recorded_fees: fees,
+            };
+            let res = guard.verify(&fees, last_fee_change_epoch + epochs_since_change);
+            if epochs_since_change < fee_change_window_epochs {
+                prop_assert_eq!(res, Err(SplCalculatorError::WithdrawalFeeChanged));
+            } else {
+                prop_assert_eq!(res, Ok(()));
+            }
+        }
+
+        #[test]
+        fn unrecorded_fee_change_rejected(
+            fees in stake_withdrawal_fees(),
+            recorded_fees in stake_withdrawal_fees(),
+            current_epoch: u64,
+        ) {
+            prop_assume!(fees != recorded_fees);
+            let guard = WithdrawalFeeGuard {
+                fee_change_window_epochs: 1,
+                last_fee_change_epoch: 0,
+                max_stake_withdrawal_fee: None,
+                recorded_fees,
+            };
+            prop_assert_eq!(
+                guard.verify(&fees, current_epoch),
+                Err(SplCalculatorError::WithdrawalFeeChanged)
+            );
+        }
+
+        #[test]
+        fn fee_ceiling(fees in stake_withdrawal_fees(), max in fee(), current_epoch: u64) {
+            let guard = WithdrawalFeeGuard {
+                max_stake_withdrawal_fee: Some(max),
+                ..WithdrawalFeeGuard::DISABLED
+            };
+            let above = is_fee_higher(&fees.stake_withdrawal_fee, &max)
+                || fees
+                    .next_stake_withdrawal_fee
+                    .is_some_and(|next| is_fee_higher(&next, &max));
+            let res = guard.verify(&fees, current_epoch);
+            if above {
+                prop_assert_eq!(res, Err(SplCalculatorError::WithdrawalFeeAboveCeiling));
+            } else {
+                prop_assert_eq!(res, Ok(()));
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use spl_calculator_lib::{
+    pda::{SplPoolConfigCreatePdaArgs, SplPoolConfigFindPdaArgs},
+    SPL_POOL_CONFIG_SIZE,
+};
+use system_program_interface::CreateAccountAccounts;
+
+mod record_withdrawal_fees;
+mod set_valuation_mode;
+mod set_withdrawal_fee_guard;
+
+pub use record_withdrawal_fees::*;
+pub use set_valuation_mode::*;
+pub use set_withdrawal_fee_guard::*;
+
+/// Creates the SplPoolConfig PDA of `pool_state` if it does not yet exist
+fn create_pool_config_if_empty<'info, P: GenericPoolSolValCalc>(
+    payer: &AccountInfo<'info>,
+    pool_state: &AccountInfo<'info>,
+    pool_config: &AccountInfo<'info>,
+    pool_config_bump: u8,
+) -> Result<(), ProgramError> {
+    if !pool_config.data_is_empty() {
+        return Ok(());
+    }
+    let create_pda_args = SplPoolConfigCreatePdaArgs {
+        find_pda_args: SplPoolConfigFindPdaArgs {
+            program_id: P::ID,
+            pool_state: *pool_state.key,
+        },
+        bump: pool_config_bump,
+    };
+    init_rent_exempt_account_invoke_signed(
+        CreateAccountAccounts {
+            from: payer,
+            to: pool_config,
+        },
+        InitRentExemptAccountArgs {
+            space: SPL_POOL_CONFIG_SIZE,
+            owner: P::ID,
+        },
+        &[create_pda_args.to_signer_seeds().as_slice()],
+    )
+}
@@ -0,0 +1,63 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError, sysvar::Sysvar,
+};
+use spl_calculator_interface::{
+    record_withdrawal_fees_verify_account_keys, record_withdrawal_fees_verify_account_privileges,
+    RecordWithdrawalFeesAccounts, SplCalculatorError,
+};
+use spl_calculator_lib::{
+    record_stake_withdrawal_fees, try_spl_pool_config_mut, RecordWithdrawalFeesFreeArgs,
+    StakeWithdrawalFees,
+};
+
+/// Call on resolved and checked RecordWithdrawalFeesAccounts.
+///
+/// Starts a new fee change window at the current epoch
+/// if `current_fees` differ from the previously recorded ones.
+pub fn process_record_withdrawal_fees_unchecked(
+    RecordWithdrawalFeesAccounts {
+        pool_state: _,
+        pool_config,
+    }: RecordWithdrawalFeesAccounts,
+    current_fees: &StakeWithdrawalFees,
+) -> Result<(), ProgramError> {
+    let current_epoch = Clock::get()?.epoch;
+    let mut bytes = pool_config.try_borrow_mut_data()?;
+    let config = try_spl_pool_config_mut(&mut bytes)?;
+    record_stake_withdrawal_fees(config, current_fees, current_epoch);
+    Ok(())
+}
+
+/// Returns the checked accounts and the stake pool's current stake withdrawal fees
+pub fn verify_record_withdrawal_fees<'me, 'info, P: GenericPoolSolValCalc>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<
+    (
+        RecordWithdrawalFeesAccounts<'me, 'info>,
+        StakeWithdrawalFees,
+    ),
+    ProgramError,
+> {
+    let actual: RecordWithdrawalFeesAccounts = load_accounts(accounts)?;
+
+    let root_keys = RecordWithdrawalFeesFreeArgs {
+        pool_state: actual.pool_state,
+    };
+    let (expected, stake_pool) = root_keys.resolve::<P>()?;
+
+    record_withdrawal_fees_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    record_withdrawal_fees_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    // fees can only be recorded once the manager has initialized the config
+    if *actual.pool_config.owner != P::ID || actual.pool_config.data_is_empty() {
+        return Err(SplCalculatorError::IncorrectPoolConfig.into());
+    }
+
+    Ok((actual, (&stake_pool).into()))
+}
@@ -0,0 +1,60 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use spl_calculator_interface::{
+    set_valuation_mode_verify_account_keys, set_valuation_mode_verify_account_privileges,
+    SetValuationModeAccounts,
+};
+use spl_calculator_lib::{try_spl_pool_config_mut, SetValuationModeFreeArgs, SplValuationMode};
+
+use super::create_pool_config_if_empty;
+
+/// Call on resolved and checked SetValuationModeAccounts.
+///
+/// Creates the SplPoolConfig PDA if it does not yet exist.
+pub fn process_set_valuation_mode_unchecked<P: GenericPoolSolValCalc>(
+    SetValuationModeAccounts {
+        payer,
+        manager: _,
+        state: _,
+        pool_state,
+        pool_config,
+        system_program: _,
+    }: SetValuationModeAccounts,
+    pool_config_bump: u8,
+    valuation_mode: SplValuationMode,
+) -> Result<(), ProgramError> {
+    create_pool_config_if_empty::<P>(payer, pool_state, pool_config, pool_config_bump)?;
+
+    let mut bytes = pool_config.try_borrow_mut_data()?;
+    let config = try_spl_pool_config_mut(&mut bytes)?;
+    config.bump = pool_config_bump;
+    config.valuation_mode = valuation_mode.into();
+    Ok(())
+}
+
+/// Returns the checked accounts, the SplPoolConfig PDA's bump and the parsed valuation mode
+pub fn verify_set_valuation_mode<'me, 'info, P: GenericPoolSolValCalc>(
+    accounts: &'me [AccountInfo<'info>],
+    valuation_mode: u8,
+) -> Result<(SetValuationModeAccounts<'me, 'info>, u8, SplValuationMode), ProgramError> {
+    let actual: SetValuationModeAccounts = load_accounts(accounts)?;
+
+    let root_keys = SetValuationModeFreeArgs {
+        payer: *actual.payer.key,
+        state: actual.state,
+        pool_state: actual.pool_state,
+    };
+    let (expected, pool_config_bump) = root_keys.resolve::<P>()?;
+
+    set_valuation_mode_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_valuation_mode_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let valuation_mode = SplValuationMode::try_from(valuation_mode)?;
+
+    Ok((actual, pool_config_bump, valuation_mode))
+}
@@ -0,0 +1,81 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use spl_calculator_interface::{
+    set_withdrawal_fee_guard_verify_account_keys,
+    set_withdrawal_fee_guard_verify_account_privileges, SetWithdrawalFeeGuardAccounts,
+    SetWithdrawalFeeGuardIxArgs,
+};
+use spl_calculator_lib::{
+    deserialize_stake_pool_checked, set_recorded_stake_withdrawal_fees, try_spl_pool_config_mut,
+    SetWithdrawalFeeGuardFreeArgs, StakeWithdrawalFees,
+};
+
+use super::create_pool_config_if_empty;
+
+/// Call on resolved and checked SetWithdrawalFeeGuardAccounts.
+///
+/// Creates the SplPoolConfig PDA if it does not yet exist.
+///
+/// Also records the stake pool's current stake withdrawal fees
+/// without starting a new fee change window, the manager having acknowledged them.
+pub fn process_set_withdrawal_fee_guard_unchecked<P: GenericPoolSolValCalc>(
+    SetWithdrawalFeeGuardAccounts {
+        payer,
+        manager: _,
+        state: _,
+        pool_state,
+        pool_config,
+        system_program: _,
+    }: SetWithdrawalFeeGuardAccounts,
+    pool_config_bump: u8,
+    current_fees: &StakeWithdrawalFees,
+    SetWithdrawalFeeGuardIxArgs {
+        fee_change_window_epochs,
+        max_stake_withdrawal_fee,
+    }: SetWithdrawalFeeGuardIxArgs,
+) -> Result<(), ProgramError> {
+    create_pool_config_if_empty::<P>(payer, pool_state, pool_config, pool_config_bump)?;
+
+    let mut bytes = pool_config.try_borrow_mut_data()?;
+    let config = try_spl_pool_config_mut(&mut bytes)?;
+    config.bump = pool_config_bump;
+    config.fee_change_window_epochs = fee_change_window_epochs;
+    config.max_stake_withdrawal_fee = max_stake_withdrawal_fee;
+    set_recorded_stake_withdrawal_fees(config, current_fees);
+    Ok(())
+}
+
+/// Returns the checked accounts, the SplPoolConfig PDA's bump
+/// and the stake pool's current stake withdrawal fees
+pub fn verify_set_withdrawal_fee_guard<'me, 'info, P: GenericPoolSolValCalc>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<
+    (
+        SetWithdrawalFeeGuardAccounts<'me, 'info>,
+        u8,
+        StakeWithdrawalFees,
+    ),
+    ProgramError,
+> {
+    let actual: SetWithdrawalFeeGuardAccounts = load_accounts(accounts)?;
+
+    let root_keys = SetWithdrawalFeeGuardFreeArgs {
+        payer: *actual.payer.key,
+        state: actual.state,
+        pool_state: actual.pool_state,
+    };
+    let (expected, pool_config_bump) = root_keys.resolve::<P>()?;
+
+    set_withdrawal_fee_guard_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_withdrawal_fee_guard_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    // pool_state's owner was checked by resolve()
+    let stake_pool = deserialize_stake_pool_checked(actual.pool_state)?;
+
+    Ok((actual, pool_config_bump, (&stake_pool).into()))
+}
@@ -0,0 +1,89 @@
+use borsh::BorshDeserialize;
+use generic_pool_calculator_interface::GenericPoolCalculatorError;
+use generic_pool_calculator_lib::{
+    account_resolvers::{LstSolCommonIntermediateArgs, LstSolCommonIntermediateKeys},
+    GenericPoolSolValCalc,
+};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+use svsp_calculator_interface::{
+    LstToSolKeys, SinglePoolAccountType, SvspPool, LST_TO_SOL_IX_ACCOUNTS_LEN,
+};
+
+use crate::{
+    find_svsp_pool_mint_address, find_svsp_pool_stake_address, SvspLstSolCommonKeys, SvspSolValCalc,
+};
+
+/// Deserializes a SVSP pool from account data,
+/// checking owner (if its owned by the SVSP program)
+/// and AccountType (if its an initialized pool)
+pub fn deserialize_svsp_pool_checked<S: ReadonlyAccountData + ReadonlyAccountOwner>(
+    svsp_pool: S,
+) -> Result<SvspPool, GenericPoolCalculatorError> {
+    if *svsp_pool.owner() != SvspSolValCalc::POOL_PROGRAM_ID {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData);
+    }
+    let pool = SvspPool::deserialize(&mut svsp_pool.data().as_ref())
+        .map_err(|_e| GenericPoolCalculatorError::InvalidStakePoolProgramData)?;
+    if pool.account_type != SinglePoolAccountType::Pool {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData);
+    }
+    Ok(pool)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SvspLstSolCommonFreeArgs<S, Q> {
+    pub svsp_pool: S,
+    pub svsp_prog: Q,
+}
+
+impl<
+        S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+        Q: ReadonlyAccountPubkey + ReadonlyAccountData,
+    > SvspLstSolCommonFreeArgs<S, Q>
+{
+    pub fn resolve(self) -> Result<SvspLstSolCommonKeys, GenericPoolCalculatorError> {
+        deserialize_svsp_pool_checked(&self.svsp_pool)?;
+        let pool = *self.svsp_pool.pubkey();
+        let keys = LstSolCommonIntermediateArgs {
+            lst_mint: find_svsp_pool_mint_address(pool).0,
+            pool_state: pool,
+            pool_program: self.svsp_prog,
+        }
+        .resolve::<SvspSolValCalc>()?;
+        Ok(SvspLstSolCommonKeys::from_generic_keys(
+            keys,
+            find_svsp_pool_stake_address(pool).0,
+        ))
+    }
+}
+
+/// Struct that uses defined const for POOL_PROGRAM_PROGDATA
+/// so that it can be used on client side without fetching POOL_PROGRAM.
+///
+/// SVSP pool accounts do not store any data that the other keys depend on,
+/// since the LST mint and pool stake account are PDAs of the pool,
+/// so only the pool's address is required.
+#[derive(Clone, Copy, Debug)]
+pub struct SvspLstSolCommonFreeArgsConst {
+    pub svsp_pool: Pubkey,
+}
+
+impl SvspLstSolCommonFreeArgsConst {
+    pub fn resolve(self) -> SvspLstSolCommonKeys {
+        let keys = LstSolCommonIntermediateKeys {
+            lst_mint: find_svsp_pool_mint_address(self.svsp_pool).0,
+            pool_state: self.svsp_pool,
+        }
+        .resolve::<SvspSolValCalc>();
+        SvspLstSolCommonKeys::from_generic_keys(
+            keys,
+            find_svsp_pool_stake_address(self.svsp_pool).0,
+        )
+    }
+
+    pub fn resolve_to_account_metas(self) -> [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+        let keys: LstToSolKeys = self.resolve().into();
+        keys.into()
+    }
+}
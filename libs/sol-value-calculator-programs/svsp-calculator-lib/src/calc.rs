@@ -0,0 +1,90 @@
+use sanctum_token_ratio::{FloorDiv, ReversibleRatio, U64Ratio, U64ValueRange};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::program_error::ProgramError;
+
+/// Parameters from the SVSP pool's stake account and LST mint required to calculate SOL value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SvspPoolCalc {
+    /// `delegation.stake` of the pool's stake account
+    pub pool_stake: u64,
+
+    /// Supply of the pool's LST mint
+    pub pool_token_supply: u64,
+}
+
+impl SvspPoolCalc {
+    pub const fn lst_to_lamports_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        let Self {
+            pool_stake,
+            pool_token_supply,
+        } = self;
+        FloorDiv(U64Ratio {
+            num: *pool_stake,
+            denom: *pool_token_supply,
+        })
+    }
+}
+
+/// SVSP charges no fees on withdrawal, withdrawn stake is simply
+/// the burnt tokens' pro-rata share of the pool's delegated stake.
+///
+/// Reference: `calculate_withdraw_amount()` and `process_withdraw_stake()` in
+/// https://github.com/solana-labs/solana-program-library/blob/master/single-pool/program/src/processor.rs
+impl SolValueCalculator for SvspPoolCalc {
+    fn calc_lst_to_sol(&self, pool_tokens: u64) -> Result<U64ValueRange, ProgramError> {
+        Ok(U64ValueRange::single(
+            self.lst_to_lamports_ratio().apply(pool_tokens)?,
+        ))
+    }
+
+    fn calc_sol_to_lst(&self, withdraw_lamports: u64) -> Result<U64ValueRange, ProgramError> {
+        Ok(self.lst_to_lamports_ratio().reverse(withdraw_lamports)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    prop_compose! {
+        fn svsp_pool_calc()
+            (pool_stake: u64, pool_token_supply: u64) -> SvspPoolCalc {
+                SvspPoolCalc {
+                    pool_stake,
+                    pool_token_supply,
+                }
+            }
+    }
+
+    prop_compose! {
+        fn svsp_pool_calc_and_lst_amt()
+            (calc in svsp_pool_calc())
+            (lst_amt in 0..=calc.pool_token_supply, calc in Just(calc)) -> (u64, SvspPoolCalc) {
+                (lst_amt, calc)
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn lst_sol_round_trip((lst_amt, calc) in svsp_pool_calc_and_lst_amt()) {
+            let r = calc.calc_lst_to_sol(lst_amt).unwrap();
+            let sol_amt = r.get_min();
+            let max_sol_amt = r.get_max();
+            prop_assert_eq!(sol_amt, max_sol_amt);
+            let r = calc.calc_sol_to_lst(sol_amt).unwrap();
+            let min = r.get_min();
+            let max = r.get_max();
+
+            // round trip from min should not exceed original
+            let min_round_trip = calc.calc_lst_to_sol(min).unwrap();
+            prop_assert!(sol_amt >= min_round_trip.get_min(), "{sol_amt} {}", min_round_trip.get_min());
+            prop_assert!(sol_amt >= min_round_trip.get_max(), "{sol_amt} {}", min_round_trip.get_max());
+
+            // round trip from max should not be smaller than original
+            let max_round_trip = calc.calc_lst_to_sol(max).unwrap();
+            prop_assert!(sol_amt <= max_round_trip.get_min(), "{sol_amt} {}", max_round_trip.get_min());
+            prop_assert!(sol_amt <= max_round_trip.get_max(), "{sol_amt} {}", max_round_trip.get_max());
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use solana_program::pubkey::Pubkey;
+use svsp_keys::{svsp_program, svsp_program_progdata};
+
+mod account_resolvers;
+mod calc;
+mod lst_sol_common;
+mod pda;
+mod utils;
+
+pub use account_resolvers::*;
+pub use calc::*;
+pub use lst_sol_common::*;
+pub use pda::*;
+pub use utils::*;
+
+pub mod program {
+    sanctum_macros::declare_program_keys!(
+        "svspWeya3GKjhjE9qPJypVu2azp94iDgnWw4CsfM8HM",
+        [("svsp_calculator_state", b"state")]
+    );
+}
+
+pub mod initial_manager {
+    sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
+}
+
+pub struct SvspSolValCalc;
+
+impl GenericPoolSolValCalc for SvspSolValCalc {
+    const POOL_PROGRAM_ID: Pubkey = svsp_program::ID;
+    const POOL_PROGRAM_PROGDATA_ID: Pubkey = svsp_program_progdata::ID;
+    const CALCULATOR_STATE_PDA: Pubkey = program::SVSP_CALCULATOR_STATE_ID;
+    const CALCULATOR_STATE_BUMP: u8 = program::SVSP_CALCULATOR_STATE_BUMP;
+    const ID: Pubkey = program::ID;
+}
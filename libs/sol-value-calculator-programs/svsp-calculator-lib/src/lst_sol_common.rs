@@ -0,0 +1,109 @@
+//! Common types that unifies LstToSol and SolToLst Keys
+
+use generic_pool_calculator_lib::LstSolCommonKeys;
+use solana_program::pubkey::Pubkey;
+use svsp_calculator_interface::{BatchCalcKeys, LstToSolKeys, SolToLstKeys};
+
+/// [`LstSolCommonKeys`] with the additional SVSP pool stake account suffix
+pub struct SvspLstSolCommonKeys {
+    ///The LST mint
+    pub lst_mint: Pubkey,
+    ///The CalculatorState PDA
+    pub state: Pubkey,
+    ///The SVSP pool account
+    pub pool_state: Pubkey,
+    ///The SVSP program
+    pub pool_program: Pubkey,
+    ///The SVSP program executable data
+    pub pool_program_data: Pubkey,
+    ///The SVSP pool's stake account
+    pub pool_stake: Pubkey,
+}
+
+impl SvspLstSolCommonKeys {
+    pub const fn from_generic_keys(
+        LstSolCommonKeys {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+        }: LstSolCommonKeys,
+        pool_stake: Pubkey,
+    ) -> Self {
+        Self {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+            pool_stake,
+        }
+    }
+}
+
+impl From<SvspLstSolCommonKeys> for LstToSolKeys {
+    fn from(
+        SvspLstSolCommonKeys {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+            pool_stake,
+        }: SvspLstSolCommonKeys,
+    ) -> Self {
+        Self {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+            pool_stake,
+        }
+    }
+}
+
+impl From<SvspLstSolCommonKeys> for SolToLstKeys {
+    fn from(
+        SvspLstSolCommonKeys {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+            pool_stake,
+        }: SvspLstSolCommonKeys,
+    ) -> Self {
+        Self {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+            pool_stake,
+        }
+    }
+}
+
+impl From<SvspLstSolCommonKeys> for BatchCalcKeys {
+    fn from(
+        SvspLstSolCommonKeys {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+            pool_stake,
+        }: SvspLstSolCommonKeys,
+    ) -> Self {
+        Self {
+            lst_mint,
+            state,
+            pool_state,
+            pool_program,
+            pool_program_data,
+            pool_stake,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use solana_program::pubkey::Pubkey;
+use svsp_keys::svsp_program;
+
+pub const SVSP_POOL_SEED: &[u8] = b"pool";
+
+pub const SVSP_POOL_MINT_SEED: &[u8] = b"mint";
+
+pub const SVSP_POOL_STAKE_SEED: &[u8] = b"stake";
+
+/// Finds the SVSP pool PDA of the given vote account
+pub fn find_svsp_pool_address(vote_account: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SVSP_POOL_SEED, vote_account.as_ref()], &svsp_program::ID)
+}
+
+/// Finds the LST mint PDA of the given SVSP pool
+pub fn find_svsp_pool_mint_address(pool: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SVSP_POOL_MINT_SEED, pool.as_ref()], &svsp_program::ID)
+}
+
+/// Finds the stake account PDA of the given SVSP pool
+/// that holds all of the pool's delegated stake
+pub fn find_svsp_pool_stake_address(pool: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SVSP_POOL_STAKE_SEED, pool.as_ref()], &svsp_program::ID)
+}
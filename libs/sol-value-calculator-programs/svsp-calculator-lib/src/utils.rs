@@ -0,0 +1,18 @@
+use solana_program::stake::state::StakeStateV2;
+use solana_readonly_account::ReadonlyAccountData;
+use svsp_calculator_interface::SvspCalculatorError;
+
+/// Attempts to deserialize the SVSP pool's stake account and
+/// return its `delegation.stake`, which is what SVSP values
+/// its LST against in `process_withdraw_stake()`
+pub fn read_pool_stake_amount<D: ReadonlyAccountData>(
+    pool_stake: D,
+) -> Result<u64, SvspCalculatorError> {
+    let data = pool_stake.data();
+    let stake_state: StakeStateV2 =
+        bincode::deserialize(&data).map_err(|_e| SvspCalculatorError::InvalidPoolStake)?;
+    match stake_state {
+        StakeStateV2::Stake(_meta, stake, _flags) => Ok(stake.delegation.stake),
+        _ => Err(SvspCalculatorError::InvalidPoolStake),
+    }
+}
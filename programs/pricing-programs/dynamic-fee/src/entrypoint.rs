@@ -0,0 +1,42 @@
+use dynamic_fee_interface::DynamicFeeProgramIx;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::processor::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if *program_id != dynamic_fee_lib::program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let ix = DynamicFeeProgramIx::deserialize(instruction_data)?;
+    solana_program::msg!("{:?}", ix);
+
+    match ix {
+        DynamicFeeProgramIx::PriceExactIn(args) => process_price_exact_in(accounts, args),
+        DynamicFeeProgramIx::PriceExactOut(args) => process_price_exact_out(accounts, args),
+        DynamicFeeProgramIx::PriceLpTokensToMint(args) => {
+            process_price_lp_tokens_to_mint(accounts, args)
+        }
+        DynamicFeeProgramIx::PriceLpTokensToRedeem(args) => {
+            process_price_lp_tokens_to_redeem(accounts, args)
+        }
+        DynamicFeeProgramIx::SetLpWithdrawalFee(args) => {
+            process_set_lp_withdrawal_fee(accounts, args)
+        }
+        DynamicFeeProgramIx::SetLstFee(args) => process_set_lst_fee(accounts, args),
+        DynamicFeeProgramIx::RemoveLst => process_remove_lst(accounts),
+        DynamicFeeProgramIx::AddLst(args) => process_add_lst(accounts, args),
+        DynamicFeeProgramIx::SetManager => process_set_manager(accounts),
+        DynamicFeeProgramIx::Initialize => process_initialize(accounts),
+    }
+}
@@ -0,0 +1,4 @@
+#![allow(unexpected_cfgs)]
+
+pub mod entrypoint;
+pub mod processor;
@@ -0,0 +1,93 @@
+use dynamic_fee_interface::{
+    price_exact_in_verify_account_keys, PriceExactInAccounts, PriceExactInIxArgs, PriceExactInKeys,
+};
+use dynamic_fee_lib::{
+    account_resolvers::{PriceExactInFreeArgs, PriceExactInWithBumpFreeArgs},
+    calc::{calculate_price_exact_in, CalculatePriceExactInArgs, LstFeeArgs, SwapSide},
+    utils::{try_fee_account, try_lst_sol_value, try_pool_reserves_address},
+};
+use s_controller_lib::{try_lst_state_list, try_pool_state};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
+    program_error::ProgramError,
+};
+
+pub fn process_price_exact_in(
+    accounts: &[AccountInfo],
+    PriceExactInIxArgs { sol_value, .. }: PriceExactInIxArgs,
+) -> ProgramResult {
+    let PriceExactInAccounts {
+        input_lst_mint,
+        output_lst_mint,
+        input_fee_acc,
+        output_fee_acc,
+        pool_state,
+        lst_state_list,
+        ..
+    } = verify_price_exact_in(accounts)?;
+
+    let input_fee_acc_bytes = input_fee_acc.try_borrow_data()?;
+    let input_fee_acc = try_fee_account(&input_fee_acc_bytes)?;
+    let output_fee_acc_bytes = output_fee_acc.try_borrow_data()?;
+    let output_fee_acc = try_fee_account(&output_fee_acc_bytes)?;
+
+    let pool_state_bytes = pool_state.try_borrow_data()?;
+    let pool_total_sol_value = try_pool_state(&pool_state_bytes)?.total_sol_value;
+    let lst_state_list_bytes = lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let input_lst_sol_value = try_lst_sol_value(input_lst_mint.key, lst_state_list)?;
+    let output_lst_sol_value = try_lst_sol_value(output_lst_mint.key, lst_state_list)?;
+
+    let result = calculate_price_exact_in(CalculatePriceExactInArgs {
+        input: LstFeeArgs::new(SwapSide::Input, input_fee_acc, input_lst_sol_value),
+        output: LstFeeArgs::new(SwapSide::Output, output_fee_acc, output_lst_sol_value),
+        pool_total_sol_value,
+        in_sol_value: sol_value,
+    })?;
+    let result_le = result.to_le_bytes();
+    set_return_data(&result_le);
+
+    Ok(())
+}
+
+fn verify_price_exact_in<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<PriceExactInAccounts<'me, 'info>, ProgramError> {
+    let actual: PriceExactInAccounts = load_accounts(accounts)?;
+
+    let input_fee_acc_bytes = actual.input_fee_acc.try_borrow_data()?;
+    let input_fee_acc_bump = try_fee_account(&input_fee_acc_bytes)?.bump;
+    let output_fee_acc_bytes = actual.output_fee_acc.try_borrow_data()?;
+    let output_fee_acc_bump = try_fee_account(&output_fee_acc_bytes)?.bump;
+
+    // the reserves must be the pool's, owned by each LST's token program
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let input_pool_reserves = try_pool_reserves_address(
+        actual.input_lst_mint.key,
+        lst_state_list,
+        *actual.input_pool_reserves.owner,
+    )?;
+    let output_pool_reserves = try_pool_reserves_address(
+        actual.output_lst_mint.key,
+        lst_state_list,
+        *actual.output_pool_reserves.owner,
+    )?;
+
+    let free_args = PriceExactInWithBumpFreeArgs {
+        args: PriceExactInFreeArgs {
+            input_lst_mint: *actual.input_lst_mint.key,
+            output_lst_mint: *actual.output_lst_mint.key,
+            input_pool_reserves,
+            output_pool_reserves,
+        },
+        input_fee_acc_bump,
+        output_fee_acc_bump,
+    };
+    let expected: PriceExactInKeys = free_args.resolve()?;
+
+    price_exact_in_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+
+    Ok(actual)
+}
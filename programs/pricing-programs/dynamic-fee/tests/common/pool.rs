@@ -0,0 +1,68 @@
+use dynamic_fee_interface::ProgramState;
+use dynamic_fee_test_utils::MockFeeAccountArgs;
+use s_controller_interface::PoolState;
+use s_controller_lib::{find_pool_reserves_address, FindLstPdaAtaKeys};
+use s_controller_test_utils::{
+    LstStateListProgramTest, MockLstStateArgs, PoolStateProgramTest, DEFAULT_POOL_STATE,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+
+use super::normal_program_test;
+
+pub const SWAP_IMBALANCE_FEE_BPS: u16 = 100;
+pub const SWAP_BASE_FEE_BPS: i16 = 1;
+pub const SWAP_TARGET_WEIGHT_BPS: u16 = 5_000;
+
+/// 30% of the pool, 50% target weight
+pub const UNDERWEIGHT_LST_SOL_VALUE: u64 = 300_000_000_000;
+
+/// 70% of the pool, 50% target weight
+pub const OVERWEIGHT_LST_SOL_VALUE: u64 = 700_000_000_000;
+
+/// Program test with the S controller's pool state, LST state list and pool reserves
+/// containing 2 LSTs with the same fees and target weights:
+/// - (underweight_mint, UNDERWEIGHT_LST_SOL_VALUE)
+/// - (overweight_mint, OVERWEIGHT_LST_SOL_VALUE)
+pub fn two_lst_pool_program_test(underweight_mint: Pubkey, overweight_mint: Pubkey) -> ProgramTest {
+    let fee_args = |lst_mint| MockFeeAccountArgs {
+        input_fee_bps: SWAP_BASE_FEE_BPS,
+        output_fee_bps: SWAP_BASE_FEE_BPS,
+        target_weight_bps: SWAP_TARGET_WEIGHT_BPS,
+        imbalance_fee_bps: SWAP_IMBALANCE_FEE_BPS,
+        lst_mint,
+    };
+    let lst_state = |mint, sol_value| MockLstStateArgs {
+        mint,
+        sol_value_calculator: Pubkey::default(),
+        token_program: spl_token::ID,
+        sol_value,
+        reserves_amt: sol_value,
+        protocol_fee_accumulator_amt: 0,
+        is_input_disabled: false,
+    };
+    normal_program_test(
+        ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 0,
+        },
+        &[fee_args(underweight_mint), fee_args(overweight_mint)],
+    )
+    .add_pool_state(PoolState {
+        total_sol_value: UNDERWEIGHT_LST_SOL_VALUE + OVERWEIGHT_LST_SOL_VALUE,
+        ..DEFAULT_POOL_STATE
+    })
+    .add_mock_lst_states(&[
+        lst_state(underweight_mint, UNDERWEIGHT_LST_SOL_VALUE),
+        lst_state(overweight_mint, OVERWEIGHT_LST_SOL_VALUE),
+    ])
+}
+
+/// The pool's reserves token account for an LST of [`two_lst_pool_program_test`]
+pub fn pool_reserves(lst_mint: Pubkey) -> Pubkey {
+    find_pool_reserves_address(FindLstPdaAtaKeys {
+        lst_mint,
+        token_program: spl_token::ID,
+    })
+    .0
+}
@@ -0,0 +1,34 @@
+use dynamic_fee_interface::initialize_ix;
+use dynamic_fee_lib::{account_resolvers::InitializeFreeArgs, utils::try_program_state};
+use dynamic_fee_test_utils::{DynamicFeePricingProgramTestBanksClient, DEFAULT_PROGRAM_STATE};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+#[tokio::test]
+async fn initialize_basic() {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "dynamic_fee",
+        dynamic_fee_lib::program::ID,
+        processor!(dynamic_fee::entrypoint::process_instruction),
+    );
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = initialize_ix(
+        InitializeFreeArgs {
+            payer: payer.pubkey(),
+        }
+        .resolve(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_dynamic_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+    assert_eq!(*state, DEFAULT_PROGRAM_STATE);
+}
@@ -0,0 +1,139 @@
+use dynamic_fee_interface::{price_exact_in_ix, DynamicFeeError, PriceExactInIxArgs};
+use dynamic_fee_lib::account_resolvers::PriceExactInFreeArgs;
+use dynamic_fee_test_utils::{MockFeeAccount, MockFeeAccountArgs};
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, IntoAccount};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::*;
+
+const IN_SOL_VALUE: u64 = 200_000_000_000;
+
+#[tokio::test]
+async fn price_exact_in_away_from_target() {
+    // overweight LST input: midpoint 30% over target, 1 + 30 bps
+    // underweight LST output: midpoint 30% under target, 1 + 30 bps
+    const EXPECTED_OUT_SOL_VALUE: u64 = 198_760_000_000;
+
+    let underweight_mint = Pubkey::new_unique();
+    let overweight_mint = Pubkey::new_unique();
+    let program_test = two_lst_pool_program_test(underweight_mint, overweight_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint: overweight_mint,
+            output_lst_mint: underweight_mint,
+            input_pool_reserves: pool_reserves(overweight_mint),
+            output_pool_reserves: pool_reserves(underweight_mint),
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: IN_SOL_VALUE,
+            sol_value: IN_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, EXPECTED_OUT_SOL_VALUE)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_towards_target() {
+    // underweight LST input: midpoint 10% under target, 1 - 10 bps
+    // overweight LST output: midpoint 10% over target, 1 - 10 bps
+    // total fee of -18 bps is a rebate
+    const EXPECTED_OUT_SOL_VALUE: u64 = 200_360_000_000;
+
+    let underweight_mint = Pubkey::new_unique();
+    let overweight_mint = Pubkey::new_unique();
+    let program_test = two_lst_pool_program_test(underweight_mint, overweight_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint: underweight_mint,
+            output_lst_mint: overweight_mint,
+            input_pool_reserves: pool_reserves(underweight_mint),
+            output_pool_reserves: pool_reserves(overweight_mint),
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: IN_SOL_VALUE,
+            sol_value: IN_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, EXPECTED_OUT_SOL_VALUE)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_fail_lst_not_in_pool() {
+    let underweight_mint = Pubkey::new_unique();
+    let overweight_mint = Pubkey::new_unique();
+    let mut program_test = two_lst_pool_program_test(underweight_mint, Pubkey::new_unique());
+    let (fee_acc, fee_acc_addr) = MockFeeAccountArgs {
+        input_fee_bps: SWAP_BASE_FEE_BPS,
+        output_fee_bps: SWAP_BASE_FEE_BPS,
+        target_weight_bps: SWAP_TARGET_WEIGHT_BPS,
+        imbalance_fee_bps: SWAP_IMBALANCE_FEE_BPS,
+        lst_mint: overweight_mint,
+    }
+    .to_fee_account_and_addr(dynamic_fee_lib::program::ID);
+    program_test.add_account(fee_acc_addr, MockFeeAccount(fee_acc).into_account());
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint: overweight_mint,
+            output_lst_mint: underweight_mint,
+            input_pool_reserves: pool_reserves(overweight_mint),
+            output_pool_reserves: pool_reserves(underweight_mint),
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: IN_SOL_VALUE,
+            sol_value: IN_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, DynamicFeeError::LstNotInPool);
+}
+
+#[tokio::test]
+async fn price_exact_in_fail_not_pool_reserves() {
+    let underweight_mint = Pubkey::new_unique();
+    let overweight_mint = Pubkey::new_unique();
+    let program_test = two_lst_pool_program_test(underweight_mint, overweight_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint: overweight_mint,
+            output_lst_mint: underweight_mint,
+            input_pool_reserves: pool_reserves(overweight_mint),
+            output_pool_reserves: Pubkey::new_unique(),
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: IN_SOL_VALUE,
+            sol_value: IN_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
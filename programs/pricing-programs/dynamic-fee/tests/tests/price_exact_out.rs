@@ -0,0 +1,90 @@
+use dynamic_fee_interface::{price_exact_out_ix, PriceExactOutIxArgs};
+use dynamic_fee_lib::{
+    account_resolvers::PriceExactOutFreeArgs,
+    calc::{calculate_price_exact_out, CalculatePriceExactOutArgs, LstFeeArgs},
+};
+use solana_program::pubkey::Pubkey;
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::*;
+
+const OUT_SOL_VALUE: u64 = 200_000_000_000;
+
+fn lst_fee_args(lst_sol_value: u64) -> LstFeeArgs {
+    LstFeeArgs {
+        base_fee_bps: SWAP_BASE_FEE_BPS,
+        target_weight_bps: SWAP_TARGET_WEIGHT_BPS,
+        imbalance_fee_bps: SWAP_IMBALANCE_FEE_BPS,
+        lst_sol_value,
+    }
+}
+
+#[tokio::test]
+async fn price_exact_out_away_from_target() {
+    let underweight_mint = Pubkey::new_unique();
+    let overweight_mint = Pubkey::new_unique();
+    let program_test = two_lst_pool_program_test(underweight_mint, overweight_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let expected_in_sol_value = calculate_price_exact_out(CalculatePriceExactOutArgs {
+        input: lst_fee_args(OVERWEIGHT_LST_SOL_VALUE),
+        output: lst_fee_args(UNDERWEIGHT_LST_SOL_VALUE),
+        pool_total_sol_value: UNDERWEIGHT_LST_SOL_VALUE + OVERWEIGHT_LST_SOL_VALUE,
+        out_sol_value: OUT_SOL_VALUE,
+    })
+    .unwrap();
+    // fee is charged on the larger input SOL value:
+    // midpoints just over 30% off target, 1 + 31 bps on each LST
+    assert_eq!(expected_in_sol_value * 9_936 / 10_000, OUT_SOL_VALUE);
+
+    let ix = price_exact_out_ix(
+        PriceExactOutFreeArgs {
+            input_lst_mint: overweight_mint,
+            output_lst_mint: underweight_mint,
+            input_pool_reserves: pool_reserves(overweight_mint),
+            output_pool_reserves: pool_reserves(underweight_mint),
+        }
+        .resolve(),
+        PriceExactOutIxArgs {
+            amount: OUT_SOL_VALUE,
+            sol_value: OUT_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, expected_in_sol_value)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_out_towards_target() {
+    let underweight_mint = Pubkey::new_unique();
+    let overweight_mint = Pubkey::new_unique();
+    let program_test = two_lst_pool_program_test(underweight_mint, overweight_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_out_ix(
+        PriceExactOutFreeArgs {
+            input_lst_mint: underweight_mint,
+            output_lst_mint: overweight_mint,
+            input_pool_reserves: pool_reserves(underweight_mint),
+            output_pool_reserves: pool_reserves(overweight_mint),
+        }
+        .resolve(),
+        PriceExactOutIxArgs {
+            amount: OUT_SOL_VALUE,
+            sol_value: OUT_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    // rebates exceed base fees:
+    // midpoints just over 10% off target, 1 - 10 bps on each LST, total fee of -18 bps
+    const EXPECTED_IN_SOL_VALUE: u64 = 199_640_646_836;
+    assert_eq!(EXPECTED_IN_SOL_VALUE * 10_018 / 10_000, OUT_SOL_VALUE);
+
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, EXPECTED_IN_SOL_VALUE)
+        .await;
+}
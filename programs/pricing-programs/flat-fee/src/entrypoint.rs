@@ -30,6 +30,25 @@ pub fn process_instruction(
         FlatFeeProgramIx::PriceLpTokensToRedeem(args) => {
             process_price_lp_tokens_to_redeem(accounts, args)
         }
+        FlatFeeProgramIx::SetAdmin => process_set_admin(accounts),
+        FlatFeeProgramIx::SetFeeBounds(args) => process_set_fee_bounds(accounts, args),
+        FlatFeeProgramIx::ScheduleLpWithdrawalFee(args) => {
+            process_schedule_lp_withdrawal_fee(accounts, args)
+        }
+        FlatFeeProgramIx::ScheduleLstFee(args) => process_schedule_lst_fee(accounts, args),
+        FlatFeeProgramIx::SetLstLpWithdrawalFee(args) => {
+            process_set_lst_lp_withdrawal_fee(accounts, args)
+        }
+        FlatFeeProgramIx::MigrateAccount => process_migrate_account(accounts),
+        FlatFeeProgramIx::SetLstLpDepositFee(args) => {
+            process_set_lst_lp_deposit_fee(accounts, args)
+        }
+        FlatFeeProgramIx::SetLpDepositFee(args) => process_set_lp_deposit_fee(accounts, args),
+        FlatFeeProgramIx::RemovePairFee => process_remove_pair_fee(accounts),
+        FlatFeeProgramIx::SetPairFee(args) => process_set_pair_fee(accounts, args),
+        FlatFeeProgramIx::AddPairFee(args) => process_add_pair_fee(accounts, args),
+        FlatFeeProgramIx::SetLstSurcharge(args) => process_set_lst_surcharge(accounts, args),
+        FlatFeeProgramIx::SetSurgeFee(args) => process_set_surge_fee(accounts, args),
         FlatFeeProgramIx::SetLpWithdrawalFee(args) => process_set_lp_withdrawal_fee(accounts, args),
         FlatFeeProgramIx::SetLstFee(args) => process_set_lst_fee(accounts, args),
         FlatFeeProgramIx::RemoveLst => process_remove_lst(accounts),
@@ -3,8 +3,11 @@ use flat_fee_interface::{
     AddLstKeys,
 };
 use flat_fee_lib::{
-    account_resolvers::AddLstFreeArgs, fee_bound::verify_signed_fee_bps_bound,
-    pda::FeeAccountCreatePdaArgs, program, utils::try_fee_account_mut,
+    account_resolvers::AddLstFreeArgs,
+    fee_bound::{verify_lst_fee_bps_configured_bound, verify_signed_fee_bps_bound},
+    pda::FeeAccountCreatePdaArgs,
+    program,
+    utils::{try_fee_account_mut, try_program_state_allow_legacy},
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -78,5 +81,10 @@ fn verify_add_lst<'me, 'info>(
     verify_signed_fee_bps_bound(args.input_fee_bps)?;
     verify_signed_fee_bps_bound(args.output_fee_bps)?;
 
+    let state_bytes = actual.state.try_borrow_data()?;
+    let state = &try_program_state_allow_legacy(&state_bytes)?;
+    verify_lst_fee_bps_configured_bound(state, args.input_fee_bps)?;
+    verify_lst_fee_bps_configured_bound(state, args.output_fee_bps)?;
+
     Ok((actual, args, fee_account_create_pda_args))
 }
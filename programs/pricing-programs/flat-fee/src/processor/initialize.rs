@@ -4,7 +4,9 @@ use flat_fee_interface::{
 };
 use flat_fee_lib::{
     account_resolvers::InitializeFreeArgs,
-    initial_constants::{initial_manager, INITIAL_LP_WITHDRAWAL_FEE_BPS},
+    initial_constants::{
+        initial_manager, INITIAL_LP_DEPOSIT_FEE_BPS, INITIAL_LP_WITHDRAWAL_FEE_BPS,
+    },
     program,
     utils::try_program_state_mut,
 };
@@ -37,7 +39,9 @@ pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
     let state = try_program_state_mut(&mut bytes)?;
 
     state.manager = initial_manager::ID;
+    state.admin = initial_manager::ID;
     state.lp_withdrawal_fee_bps = INITIAL_LP_WITHDRAWAL_FEE_BPS;
+    state.lp_deposit_fee_bps = INITIAL_LP_DEPOSIT_FEE_BPS;
 
     Ok(())
 }
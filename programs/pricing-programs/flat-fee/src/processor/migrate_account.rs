@@ -0,0 +1,100 @@
+use flat_fee_interface::{
+    migrate_account_verify_account_keys, migrate_account_verify_account_privileges, FlatFeeError,
+    MigrateAccountAccounts, MigrateAccountKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::MigrateAccountFreeArgs,
+    pda::{FeeAccountCreatePdaArgs, FeeAccountFindPdaArgs},
+    program::{self, FEE_ACCOUNT_SIZE, LEGACY_FEE_ACCOUNT_SIZE, LEGACY_STATE_SIZE, STATE_SIZE},
+    utils::try_program_state_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::ResizableAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use system_program_interface::{transfer_invoke, TransferAccounts, TransferIxArgs};
+
+/// Grows a ProgramState or FeeAccount with the legacy layout to its current size,
+/// zero-initializing the new fields except for the ProgramState's admin,
+/// which is initialized to the manager
+pub fn process_migrate_account(accounts: &[AccountInfo]) -> ProgramResult {
+    let (
+        MigrateAccountAccounts {
+            payer, migrate_acc, ..
+        },
+        new_size,
+    ) = verify_migrate_account(accounts)?;
+
+    let lamports_short = migrate_acc.extend_by(new_size - migrate_acc.data_len())?;
+
+    if lamports_short > 0 {
+        transfer_invoke(
+            TransferAccounts {
+                from: payer,
+                to: migrate_acc,
+            },
+            TransferIxArgs {
+                lamports: lamports_short,
+            },
+        )?;
+    }
+
+    if *migrate_acc.key == program::STATE_ID {
+        let mut bytes = migrate_acc.try_borrow_mut_data()?;
+        let state = try_program_state_mut(&mut bytes)?;
+        state.admin = state.manager;
+    }
+
+    Ok(())
+}
+
+/// Returns the size the account should be migrated to
+fn verify_migrate_account<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<(MigrateAccountAccounts<'me, 'info>, usize), ProgramError> {
+    let actual: MigrateAccountAccounts = load_accounts(accounts)?;
+
+    let free_args = MigrateAccountFreeArgs {
+        payer: *actual.payer.key,
+        migrate_acc: *actual.migrate_acc.key,
+        lst_mint: Some(*actual.lst_mint.key),
+    };
+    let expected: MigrateAccountKeys = free_args.resolve();
+
+    migrate_account_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    migrate_account_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    if *actual.migrate_acc.owner != program::ID {
+        return Err(FlatFeeError::AccountNotMigratable.into());
+    }
+    if *actual.migrate_acc.key == program::STATE_ID {
+        if actual.migrate_acc.data_len() != LEGACY_STATE_SIZE {
+            return Err(FlatFeeError::AccountNotMigratable.into());
+        }
+        return Ok((actual, STATE_SIZE));
+    }
+
+    if actual.migrate_acc.data_len() != LEGACY_FEE_ACCOUNT_SIZE {
+        return Err(FlatFeeError::AccountNotMigratable.into());
+    }
+    // other program accounts may share the legacy FeeAccount size,
+    // so only migrate accounts at the FeeAccount PDA of lst_mint
+    let bump = actual.migrate_acc.try_borrow_data()?[0];
+    let expected_fee_acc = FeeAccountCreatePdaArgs {
+        find_pda_args: FeeAccountFindPdaArgs {
+            program_id: program::ID,
+            lst_mint: *actual.lst_mint.key,
+        },
+        bump,
+    }
+    .get_fee_account_address()
+    .map_err(|_e| FlatFeeError::AccountNotMigratable)?;
+    if expected_fee_acc != *actual.migrate_acc.key {
+        return Err(FlatFeeError::AccountNotMigratable.into());
+    }
+
+    Ok((actual, FEE_ACCOUNT_SIZE))
+}
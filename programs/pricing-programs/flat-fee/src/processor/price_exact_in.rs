@@ -1,15 +1,22 @@
 use flat_fee_interface::{
-    price_exact_in_verify_account_keys, PriceExactInAccounts, PriceExactInIxArgs, PriceExactInKeys,
+    price_exact_in_verify_account_keys, FlatFeeError, PriceExactInAccounts, PriceExactInIxArgs,
+    PriceExactInKeys, PRICE_EXACT_IN_IX_ACCOUNTS_LEN,
 };
 use flat_fee_lib::{
     account_resolvers::{PriceExactInFreeArgs, PriceExactInWithBumpFreeArgs},
-    calc::{calculate_price_exact_in, CalculatePriceExactInArgs},
-    utils::try_fee_account,
+    calc::{
+        calculate_price_exact_in, fee_account_lst_fees, lst_surcharge_acc_fee_bps,
+        surge_config_acc_fee_bps, CalculatePriceExactInArgs,
+    },
+    pda::PairFeeAccountFindPdaArgs,
+    program,
+    utils::{try_fee_account_allow_legacy, try_lst_surcharge_bump_opt, try_pair_fee_override},
 };
 use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
-    program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    epoch_schedule::EpochSchedule, program::set_return_data, program_error::ProgramError,
+    sysvar::Sysvar,
 };
 
 pub fn process_price_exact_in(
@@ -17,19 +24,54 @@ pub fn process_price_exact_in(
     PriceExactInIxArgs { sol_value, .. }: PriceExactInIxArgs,
 ) -> ProgramResult {
     let PriceExactInAccounts {
+        input_lst_mint,
+        output_lst_mint,
         input_fee_acc,
         output_fee_acc,
+        surge_config,
+        input_surcharge_acc,
+        output_surcharge_acc,
+        input_stake_pool,
+        output_stake_pool,
         ..
     } = verify_price_exact_in(accounts)?;
 
     let input_fee_acc_bytes = input_fee_acc.try_borrow_data()?;
-    let input_fee_acc = try_fee_account(&input_fee_acc_bytes)?;
+    let input_fee_acc = &try_fee_account_allow_legacy(&input_fee_acc_bytes)?;
     let output_fee_acc_bytes = output_fee_acc.try_borrow_data()?;
-    let output_fee_acc = try_fee_account(&output_fee_acc_bytes)?;
+    let output_fee_acc = &try_fee_account_allow_legacy(&output_fee_acc_bytes)?;
+
+    // optional PairFeeAccount override passed as the first remaining account
+    let pair_fee_bps = accounts
+        .get(PRICE_EXACT_IN_IX_ACCOUNTS_LEN)
+        .map(|pair_fee_acc| {
+            try_pair_fee_override(
+                pair_fee_acc,
+                PairFeeAccountFindPdaArgs {
+                    program_id: program::ID,
+                    input_lst_mint: *input_lst_mint.key,
+                    output_lst_mint: *output_lst_mint.key,
+                },
+            )
+        })
+        .transpose()?;
+
+    let clock = Clock::get()?;
+    let epoch_schedule = EpochSchedule::get()?;
+    let surge_fee_bps = [
+        surge_config_acc_fee_bps(surge_config, &clock, &epoch_schedule)?,
+        lst_surcharge_acc_fee_bps(input_surcharge_acc, input_stake_pool, clock.epoch)?,
+        lst_surcharge_acc_fee_bps(output_surcharge_acc, output_stake_pool, clock.epoch)?,
+    ]
+    .into_iter()
+    .try_fold(0u16, |acc, bps| acc.checked_add(bps))
+    .ok_or(FlatFeeError::MathError)?;
 
     let result = calculate_price_exact_in(CalculatePriceExactInArgs {
-        input_fee_bps: input_fee_acc.input_fee_bps,
-        output_fee_bps: output_fee_acc.output_fee_bps,
+        input_fee_bps: fee_account_lst_fees(input_fee_acc, clock.epoch).input_fee_bps,
+        output_fee_bps: fee_account_lst_fees(output_fee_acc, clock.epoch).output_fee_bps,
+        pair_fee_bps,
+        surge_fee_bps,
         in_sol_value: sol_value,
     })?;
     let result_le = result.to_le_bytes();
@@ -44,17 +86,27 @@ fn verify_price_exact_in<'me, 'info>(
     let actual: PriceExactInAccounts = load_accounts(accounts)?;
 
     let input_fee_acc_bytes = actual.input_fee_acc.try_borrow_data()?;
-    let input_fee_acc_bump = try_fee_account(&input_fee_acc_bytes)?.bump;
+    let input_fee_acc_bump = try_fee_account_allow_legacy(&input_fee_acc_bytes)?.bump;
     let output_fee_acc_bytes = actual.output_fee_acc.try_borrow_data()?;
-    let output_fee_acc_bump = try_fee_account(&output_fee_acc_bytes)?.bump;
+    let output_fee_acc_bump = try_fee_account_allow_legacy(&output_fee_acc_bytes)?.bump;
+    let input_surcharge_acc_bump = try_lst_surcharge_bump_opt(actual.input_surcharge_acc)?;
+    let output_surcharge_acc_bump = try_lst_surcharge_bump_opt(actual.output_surcharge_acc)?;
 
     let free_args = PriceExactInWithBumpFreeArgs {
         args: PriceExactInFreeArgs {
             input_lst_mint: *actual.input_lst_mint.key,
             output_lst_mint: *actual.output_lst_mint.key,
+            pool_state: *actual.pool_state.key,
+            lst_state_list: *actual.lst_state_list.key,
+            input_pool_reserves: *actual.input_pool_reserves.key,
+            output_pool_reserves: *actual.output_pool_reserves.key,
+            input_stake_pool: *actual.input_stake_pool.key,
+            output_stake_pool: *actual.output_stake_pool.key,
         },
         input_fee_acc_bump,
         output_fee_acc_bump,
+        input_surcharge_acc_bump,
+        output_surcharge_acc_bump,
     };
     let expected: PriceExactInKeys = free_args.resolve()?;
 
@@ -1,15 +1,62 @@
-use flat_fee_interface::PriceLpTokensToMintIxArgs;
+use flat_fee_interface::{
+    price_lp_tokens_to_mint_verify_account_keys, PriceLpTokensToMintAccounts,
+    PriceLpTokensToMintIxArgs, PriceLpTokensToMintKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::{PriceLpTokensToMintFreeArgs, PriceLpTokensToMintWithBumpFreeArgs},
+    calc::calculate_price_lp_tokens_to_mint,
+    utils::{try_fee_account_allow_legacy, try_program_state_allow_legacy},
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
+    program_error::ProgramError,
 };
 
-// flat fee program doesnt charge add liquidity fees,
-// simply return passed in sol_value
 pub fn process_price_lp_tokens_to_mint(
-    _accounts: &[AccountInfo],
+    accounts: &[AccountInfo],
     PriceLpTokensToMintIxArgs { sol_value, .. }: PriceLpTokensToMintIxArgs,
 ) -> ProgramResult {
-    let result_le = sol_value.to_le_bytes();
+    let PriceLpTokensToMintAccounts { state, fee_acc, .. } =
+        verify_price_lp_tokens_to_mint(accounts)?;
+
+    let state_bytes = state.try_borrow_data()?;
+    let state = &try_program_state_allow_legacy(&state_bytes)?;
+    let fee_acc_bytes = fee_acc.try_borrow_data()?;
+    let fee_acc = &try_fee_account_allow_legacy(&fee_acc_bytes)?;
+
+    let result = calculate_price_lp_tokens_to_mint(
+        state.lp_deposit_fee_bps,
+        fee_acc.lp_deposit_fee_bps,
+        sol_value,
+    )?;
+    let result_le = result.to_le_bytes();
     set_return_data(&result_le);
+
     Ok(())
 }
+
+fn verify_price_lp_tokens_to_mint<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<PriceLpTokensToMintAccounts<'me, 'info>, ProgramError> {
+    let actual: PriceLpTokensToMintAccounts = load_accounts(accounts)?;
+
+    let fee_acc_bytes = actual.fee_acc.try_borrow_data()?;
+    let fee_acc_bump = try_fee_account_allow_legacy(&fee_acc_bytes)?.bump;
+
+    let free_args = PriceLpTokensToMintWithBumpFreeArgs {
+        args: PriceLpTokensToMintFreeArgs {
+            input_lst_mint: *actual.input_lst_mint.key,
+            pool_state: *actual.pool_state.key,
+            lst_state_list: *actual.lst_state_list.key,
+            pool_reserves: *actual.pool_reserves.key,
+        },
+        fee_acc_bump,
+    };
+    let expected: PriceLpTokensToMintKeys = free_args.resolve()?;
+
+    price_lp_tokens_to_mint_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+
+    Ok(actual)
+}
@@ -3,25 +3,47 @@ use flat_fee_interface::{
     PriceLpTokensToRedeemIxArgs, PriceLpTokensToRedeemKeys,
 };
 use flat_fee_lib::{
-    account_resolvers::PriceLpTokensToRedeemFreeArgs, calc::calculate_price_lp_tokens_to_redeem,
-    utils::try_program_state,
+    account_resolvers::{PriceLpTokensToRedeemFreeArgs, PriceLpTokensToRedeemWithBumpFreeArgs},
+    calc::{
+        calculate_price_lp_tokens_to_redeem, effective_lp_withdrawal_fee_bps,
+        program_state_lp_withdrawal_fee_bps, surge_config_acc_fee_bps,
+    },
+    utils::{try_fee_account_allow_legacy, try_program_state_allow_legacy},
 };
 use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
-    program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    epoch_schedule::EpochSchedule, program::set_return_data, program_error::ProgramError,
+    sysvar::Sysvar,
 };
 
 pub fn process_price_lp_tokens_to_redeem(
     accounts: &[AccountInfo],
     PriceLpTokensToRedeemIxArgs { sol_value, .. }: PriceLpTokensToRedeemIxArgs,
 ) -> ProgramResult {
-    let PriceLpTokensToRedeemAccounts { state, .. } = verify_price_lp_tokens_to_redeem(accounts)?;
+    let PriceLpTokensToRedeemAccounts {
+        state,
+        surge_config,
+        fee_acc,
+        ..
+    } = verify_price_lp_tokens_to_redeem(accounts)?;
 
-    let bytes = state.try_borrow_data()?;
-    let state = try_program_state(&bytes)?;
+    let state_bytes = state.try_borrow_data()?;
+    let state = &try_program_state_allow_legacy(&state_bytes)?;
+    let fee_acc_bytes = fee_acc.try_borrow_data()?;
+    let fee_acc = &try_fee_account_allow_legacy(&fee_acc_bytes)?;
 
-    let result = calculate_price_lp_tokens_to_redeem(state.lp_withdrawal_fee_bps, sol_value)?;
+    let clock = Clock::get()?;
+    let surge_fee_bps = surge_config_acc_fee_bps(surge_config, &clock, &EpochSchedule::get()?)?;
+
+    let result = calculate_price_lp_tokens_to_redeem(
+        effective_lp_withdrawal_fee_bps(
+            program_state_lp_withdrawal_fee_bps(state, clock.epoch),
+            fee_acc,
+        ),
+        surge_fee_bps,
+        sol_value,
+    )?;
     let result_le = result.to_le_bytes();
     set_return_data(&result_le);
 
@@ -33,10 +55,19 @@ fn verify_price_lp_tokens_to_redeem<'me, 'info>(
 ) -> Result<PriceLpTokensToRedeemAccounts<'me, 'info>, ProgramError> {
     let actual: PriceLpTokensToRedeemAccounts = load_accounts(accounts)?;
 
-    let free_args = PriceLpTokensToRedeemFreeArgs {
-        output_lst_mint: *actual.output_lst_mint.key,
+    let fee_acc_bytes = actual.fee_acc.try_borrow_data()?;
+    let fee_acc_bump = try_fee_account_allow_legacy(&fee_acc_bytes)?.bump;
+
+    let free_args = PriceLpTokensToRedeemWithBumpFreeArgs {
+        args: PriceLpTokensToRedeemFreeArgs {
+            output_lst_mint: *actual.output_lst_mint.key,
+            pool_state: *actual.pool_state.key,
+            lst_state_list: *actual.lst_state_list.key,
+            pool_reserves: *actual.pool_reserves.key,
+        },
+        fee_acc_bump,
     };
-    let expected: PriceLpTokensToRedeemKeys = free_args.resolve();
+    let expected: PriceLpTokensToRedeemKeys = free_args.resolve()?;
 
     price_lp_tokens_to_redeem_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
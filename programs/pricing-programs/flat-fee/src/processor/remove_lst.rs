@@ -1,8 +1,8 @@
 use flat_fee_interface::{
-    remove_lst_verify_account_keys, remove_lst_verify_account_privileges, RemoveLstAccounts,
-    RemoveLstKeys,
+    remove_lst_verify_account_keys, remove_lst_verify_account_privileges, FlatFeeError,
+    RemoveLstAccounts, RemoveLstKeys,
 };
-use flat_fee_lib::account_resolvers::RemoveLstFreeArgs;
+use flat_fee_lib::{account_resolvers::RemoveLstFreeArgs, utils::try_fee_account_allow_legacy};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -41,5 +41,12 @@ fn verify_remove_lst<'me, 'info>(
     remove_lst_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     remove_lst_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
 
+    // pair fee overrides must be removed first
+    // so that none outlive their input LST's FeeAccount
+    let fee_acc_bytes = actual.fee_acc.try_borrow_data()?;
+    if try_fee_account_allow_legacy(&fee_acc_bytes)?.num_pair_fees != 0 {
+        return Err(FlatFeeError::LstHasPairFees.into());
+    }
+
     Ok(actual)
 }
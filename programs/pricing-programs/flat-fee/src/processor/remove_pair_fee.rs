@@ -0,0 +1,54 @@
+use flat_fee_interface::{
+    remove_pair_fee_verify_account_keys, remove_pair_fee_verify_account_privileges, FlatFeeError,
+    RemovePairFeeAccounts, RemovePairFeeKeys,
+};
+use flat_fee_lib::{account_resolvers::RemovePairFeeFreeArgs, utils::try_fee_account_mut};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{close_account, CloseAccountAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_remove_pair_fee(accounts: &[AccountInfo]) -> ProgramResult {
+    let RemovePairFeeAccounts {
+        pair_fee_acc,
+        input_fee_acc,
+        refund_rent_to,
+        ..
+    } = verify_remove_pair_fee(accounts)?;
+
+    let mut input_fee_acc_bytes = input_fee_acc.try_borrow_mut_data()?;
+    let input_fee_acc = try_fee_account_mut(&mut input_fee_acc_bytes)?;
+    input_fee_acc.num_pair_fees = input_fee_acc
+        .num_pair_fees
+        .checked_sub(1)
+        .ok_or(FlatFeeError::MathError)?;
+
+    close_account(CloseAccountAccounts {
+        refund_rent_to,
+        close: pair_fee_acc,
+    })?;
+
+    Ok(())
+}
+
+fn verify_remove_pair_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<RemovePairFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: RemovePairFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = RemovePairFeeFreeArgs {
+        refund_rent_to: *actual.refund_rent_to.key,
+        state_acc: actual.state,
+        input_lst_mint: *actual.input_lst_mint.key,
+        output_lst_mint: *actual.output_lst_mint.key,
+    };
+    let expected: RemovePairFeeKeys = free_args.resolve()?;
+
+    remove_pair_fee_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    remove_pair_fee_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,89 @@
+use flat_fee_interface::{
+    schedule_lp_withdrawal_fee_verify_account_keys,
+    schedule_lp_withdrawal_fee_verify_account_privileges, ScheduleLpWithdrawalFeeAccounts,
+    ScheduleLpWithdrawalFeeIxArgs, ScheduleLpWithdrawalFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::ScheduleLpWithdrawalFeeFreeArgs,
+    calc::{
+        promote_program_state_pending_lp_withdrawal_fee, record_program_state_fee_baseline,
+        set_program_state_pending_lp_withdrawal_fee, verify_activation_epoch, PendingFee,
+    },
+    fee_bound::{
+        verify_lp_withdrawal_fee_bps_update, verify_scheduled_lp_withdrawal_fee_bps_activation,
+        verify_unsigned_fee_bps_bound,
+    },
+    utils::try_program_state_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+pub fn process_schedule_lp_withdrawal_fee(
+    accounts: &[AccountInfo],
+    args: ScheduleLpWithdrawalFeeIxArgs,
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    let ScheduleLpWithdrawalFeeAccounts { state, .. } =
+        verify_schedule_lp_withdrawal_fee(accounts, &args, clock.epoch)?;
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+
+    let baseline = record_program_state_fee_baseline(state, clock.epoch);
+    verify_lp_withdrawal_fee_bps_update(
+        state,
+        baseline.lp_withdrawal_fee_bps,
+        args.lp_withdrawal_fee_bps,
+    )?;
+
+    // promote any previously scheduled change that is already in effect
+    // so that it isn't lost when overwritten
+    promote_program_state_pending_lp_withdrawal_fee(state, clock.epoch);
+    // the fee in effect before activation could have been moved away from the baseline
+    // earlier this epoch, so the step at activation must be limited separately
+    verify_scheduled_lp_withdrawal_fee_bps_activation(
+        state,
+        state.lp_withdrawal_fee_bps,
+        args.lp_withdrawal_fee_bps,
+    )?;
+    set_program_state_pending_lp_withdrawal_fee(
+        state,
+        Some(PendingFee {
+            fee: args.lp_withdrawal_fee_bps,
+            activation_epoch: args.activation_epoch,
+        }),
+    );
+
+    Ok(())
+}
+
+fn verify_schedule_lp_withdrawal_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    ScheduleLpWithdrawalFeeIxArgs {
+        lp_withdrawal_fee_bps,
+        activation_epoch,
+    }: &ScheduleLpWithdrawalFeeIxArgs,
+    current_epoch: u64,
+) -> Result<ScheduleLpWithdrawalFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: ScheduleLpWithdrawalFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = ScheduleLpWithdrawalFeeFreeArgs {
+        state_acc: actual.state,
+    };
+    let expected: ScheduleLpWithdrawalFeeKeys = free_args.resolve()?;
+
+    schedule_lp_withdrawal_fee_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    schedule_lp_withdrawal_fee_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_unsigned_fee_bps_bound(*lp_withdrawal_fee_bps)?;
+    verify_activation_epoch(*activation_epoch, current_epoch)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,88 @@
+use flat_fee_interface::{
+    schedule_lst_fee_verify_account_keys, schedule_lst_fee_verify_account_privileges,
+    ScheduleLstFeeAccounts, ScheduleLstFeeIxArgs, ScheduleLstFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::ScheduleLstFeeFreeArgs,
+    calc::{
+        promote_fee_account_pending_fees, record_fee_account_fee_baseline,
+        set_fee_account_pending_fees, verify_activation_epoch, LstFees, PendingFee,
+    },
+    fee_bound::{
+        verify_lst_fee_bps_update, verify_scheduled_lst_fee_bps_activation,
+        verify_signed_fee_bps_bound,
+    },
+    utils::{try_fee_account_mut, try_program_state_allow_legacy},
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+pub fn process_schedule_lst_fee(
+    accounts: &[AccountInfo],
+    args: ScheduleLstFeeIxArgs,
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    let ScheduleLstFeeAccounts { fee_acc, state, .. } =
+        verify_schedule_lst_fee(accounts, &args, clock.epoch)?;
+
+    let mut bytes = fee_acc.try_borrow_mut_data()?;
+    let fee_acc = try_fee_account_mut(&mut bytes)?;
+
+    let state_bytes = state.try_borrow_data()?;
+    let state = &try_program_state_allow_legacy(&state_bytes)?;
+    let baseline = record_fee_account_fee_baseline(fee_acc, state, clock.epoch);
+    verify_lst_fee_bps_update(state, baseline.input_fee_bps, args.input_fee_bps)?;
+    verify_lst_fee_bps_update(state, baseline.output_fee_bps, args.output_fee_bps)?;
+
+    // promote any previously scheduled change that is already in effect
+    // so that it isn't lost when overwritten
+    promote_fee_account_pending_fees(fee_acc, clock.epoch);
+    // the fee in effect before activation could have been moved away from the baseline
+    // earlier this epoch, so the step at activation must be limited separately
+    verify_scheduled_lst_fee_bps_activation(state, fee_acc.input_fee_bps, args.input_fee_bps)?;
+    verify_scheduled_lst_fee_bps_activation(state, fee_acc.output_fee_bps, args.output_fee_bps)?;
+    set_fee_account_pending_fees(
+        fee_acc,
+        Some(PendingFee {
+            fee: LstFees {
+                input_fee_bps: args.input_fee_bps,
+                output_fee_bps: args.output_fee_bps,
+            },
+            activation_epoch: args.activation_epoch,
+        }),
+    );
+
+    Ok(())
+}
+
+fn verify_schedule_lst_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    ScheduleLstFeeIxArgs {
+        input_fee_bps,
+        output_fee_bps,
+        activation_epoch,
+    }: &ScheduleLstFeeIxArgs,
+    current_epoch: u64,
+) -> Result<ScheduleLstFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: ScheduleLstFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = ScheduleLstFeeFreeArgs {
+        state_acc: actual.state,
+        fee_acc: *actual.fee_acc.key,
+    };
+    let expected: ScheduleLstFeeKeys = free_args.resolve()?;
+
+    schedule_lst_fee_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    schedule_lst_fee_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_signed_fee_bps_bound(*input_fee_bps)?;
+    verify_signed_fee_bps_bound(*output_fee_bps)?;
+    verify_activation_epoch(*activation_epoch, current_epoch)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,41 @@
+use flat_fee_interface::{
+    set_admin_verify_account_keys, set_admin_verify_account_privileges, SetAdminAccounts,
+    SetAdminKeys,
+};
+use flat_fee_lib::{account_resolvers::SetAdminFreeArgs, utils::try_program_state_mut};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_set_admin(accounts: &[AccountInfo]) -> ProgramResult {
+    let SetAdminAccounts {
+        new_admin, state, ..
+    } = verify_set_admin(accounts)?;
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+
+    state.admin = *new_admin.key;
+
+    Ok(())
+}
+
+fn verify_set_admin<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<SetAdminAccounts<'me, 'info>, ProgramError> {
+    let actual: SetAdminAccounts = load_accounts(accounts)?;
+
+    let free_args = SetAdminFreeArgs {
+        new_admin: *actual.new_admin.key,
+        state_acc: actual.state,
+    };
+    let expected: SetAdminKeys = free_args.resolve()?;
+
+    set_admin_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_admin_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,63 @@
+use flat_fee_interface::{
+    set_fee_bounds_verify_account_keys, set_fee_bounds_verify_account_privileges,
+    SetFeeBoundsAccounts, SetFeeBoundsIxArgs, SetFeeBoundsKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetFeeBoundsFreeArgs, fee_bound::verify_fee_bounds,
+    utils::try_program_state_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_set_fee_bounds(accounts: &[AccountInfo], args: SetFeeBoundsIxArgs) -> ProgramResult {
+    let SetFeeBoundsAccounts { state, .. } = verify_set_fee_bounds(accounts, &args)?;
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+
+    state.min_lst_fee_bps = args.min_lst_fee_bps;
+    state.max_lst_fee_bps = args.max_lst_fee_bps;
+    state.max_lp_withdrawal_fee_bps = args.max_lp_withdrawal_fee_bps;
+    state.max_fee_change_bps = args.max_fee_change_bps;
+    state.max_lp_deposit_fee_bps = args.max_lp_deposit_fee_bps;
+    state.max_surge_fee_bps = args.max_surge_fee_bps;
+    state.has_fee_bounds = 1;
+
+    Ok(())
+}
+
+fn verify_set_fee_bounds<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetFeeBoundsIxArgs {
+        min_lst_fee_bps,
+        max_lst_fee_bps,
+        max_lp_withdrawal_fee_bps,
+        max_lp_deposit_fee_bps,
+        max_surge_fee_bps,
+        ..
+    }: &SetFeeBoundsIxArgs,
+) -> Result<SetFeeBoundsAccounts<'me, 'info>, ProgramError> {
+    let actual: SetFeeBoundsAccounts = load_accounts(accounts)?;
+
+    let free_args = SetFeeBoundsFreeArgs {
+        state_acc: actual.state,
+    };
+    let expected: SetFeeBoundsKeys = free_args.resolve()?;
+
+    set_fee_bounds_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_fee_bounds_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_fee_bounds(
+        *min_lst_fee_bps,
+        *max_lst_fee_bps,
+        *max_lp_withdrawal_fee_bps,
+        *max_lp_deposit_fee_bps,
+        *max_surge_fee_bps,
+    )?;
+
+    Ok(actual)
+}
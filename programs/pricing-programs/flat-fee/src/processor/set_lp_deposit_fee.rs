@@ -0,0 +1,60 @@
+use flat_fee_interface::{
+    set_lp_deposit_fee_verify_account_keys, set_lp_deposit_fee_verify_account_privileges,
+    SetLpDepositFeeAccounts, SetLpDepositFeeIxArgs, SetLpDepositFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLpDepositFeeFreeArgs,
+    calc::record_program_state_fee_baseline,
+    fee_bound::{
+        verify_combined_lp_deposit_fee_bps, verify_lp_deposit_fee_bps_bound,
+        verify_lp_deposit_fee_bps_update, MAX_LP_DEPOSIT_FEE_BPS,
+    },
+    utils::try_program_state_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+pub fn process_set_lp_deposit_fee(
+    accounts: &[AccountInfo],
+    args: SetLpDepositFeeIxArgs,
+) -> ProgramResult {
+    let SetLpDepositFeeAccounts { state, .. } = verify_set_lp_deposit_fee(accounts, &args)?;
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+
+    let baseline = record_program_state_fee_baseline(state, Clock::get()?.epoch);
+    verify_lp_deposit_fee_bps_update(state, baseline.lp_deposit_fee_bps, args.lp_deposit_fee_bps)?;
+
+    state.lp_deposit_fee_bps = args.lp_deposit_fee_bps;
+
+    Ok(())
+}
+
+fn verify_set_lp_deposit_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetLpDepositFeeIxArgs { lp_deposit_fee_bps }: &SetLpDepositFeeIxArgs,
+) -> Result<SetLpDepositFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: SetLpDepositFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = SetLpDepositFeeFreeArgs {
+        state_acc: actual.state,
+    };
+    let expected: SetLpDepositFeeKeys = free_args.resolve()?;
+
+    set_lp_deposit_fee_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_lp_deposit_fee_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_lp_deposit_fee_bps_bound(*lp_deposit_fee_bps)?;
+    // FeeAccounts are not passed in, so check against the highest per-LST LP deposit fee that can be set
+    verify_combined_lp_deposit_fee_bps(*lp_deposit_fee_bps, MAX_LP_DEPOSIT_FEE_BPS)?;
+
+    Ok(actual)
+}
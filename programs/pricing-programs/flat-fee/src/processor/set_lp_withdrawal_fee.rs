@@ -3,14 +3,20 @@ use flat_fee_interface::{
     SetLpWithdrawalFeeAccounts, SetLpWithdrawalFeeIxArgs, SetLpWithdrawalFeeKeys,
 };
 use flat_fee_lib::{
-    account_resolvers::SetLpWithdrawalFeeFreeArgs, fee_bound::verify_unsigned_fee_bps_bound,
+    account_resolvers::SetLpWithdrawalFeeFreeArgs,
+    calc::{
+        program_state_lp_withdrawal_fee_bps, record_program_state_fee_baseline,
+        set_program_state_pending_lp_withdrawal_fee, verify_immediate_fee_change,
+    },
+    fee_bound::{verify_lp_withdrawal_fee_bps_update, verify_unsigned_fee_bps_bound},
     utils::try_program_state_mut,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
 };
 
 pub fn process_set_lp_withdrawal_fee(
@@ -21,7 +27,20 @@ pub fn process_set_lp_withdrawal_fee(
 
     let mut bytes = state.try_borrow_mut_data()?;
     let state = try_program_state_mut(&mut bytes)?;
+
+    let current_epoch = Clock::get()?.epoch;
+    let lp_withdrawal_fee_bps = program_state_lp_withdrawal_fee_bps(state, current_epoch);
+    verify_immediate_fee_change(lp_withdrawal_fee_bps, args.lp_withdrawal_fee_bps)?;
+    let baseline = record_program_state_fee_baseline(state, current_epoch);
+    verify_lp_withdrawal_fee_bps_update(
+        state,
+        baseline.lp_withdrawal_fee_bps,
+        args.lp_withdrawal_fee_bps,
+    )?;
+
     state.lp_withdrawal_fee_bps = args.lp_withdrawal_fee_bps;
+    // an immediate change supersedes any scheduled change
+    set_program_state_pending_lp_withdrawal_fee(state, None);
 
     Ok(())
 }
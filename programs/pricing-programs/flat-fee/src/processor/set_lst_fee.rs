@@ -3,24 +3,45 @@ use flat_fee_interface::{
     SetLstFeeIxArgs, SetLstFeeKeys,
 };
 use flat_fee_lib::{
-    account_resolvers::SetLstFeeFreeArgs, fee_bound::verify_signed_fee_bps_bound,
-    utils::try_fee_account_mut,
+    account_resolvers::SetLstFeeFreeArgs,
+    calc::{
+        fee_account_lst_fees, record_fee_account_fee_baseline, set_fee_account_pending_fees,
+        verify_immediate_fee_change, LstFees,
+    },
+    fee_bound::{verify_lst_fee_bps_update, verify_signed_fee_bps_bound},
+    utils::{try_fee_account_mut, try_program_state_allow_legacy},
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
 };
 
 pub fn process_set_lst_fee(accounts: &[AccountInfo], args: SetLstFeeIxArgs) -> ProgramResult {
-    let SetLstFeeAccounts { fee_acc, .. } = verify_set_lst_fee(accounts, &args)?;
+    let SetLstFeeAccounts { fee_acc, state, .. } = verify_set_lst_fee(accounts, &args)?;
 
     let mut bytes = fee_acc.try_borrow_mut_data()?;
     let fee_acc = try_fee_account_mut(&mut bytes)?;
 
+    let state_bytes = state.try_borrow_data()?;
+    let state = &try_program_state_allow_legacy(&state_bytes)?;
+    let current_epoch = Clock::get()?.epoch;
+    let LstFees {
+        input_fee_bps,
+        output_fee_bps,
+    } = fee_account_lst_fees(fee_acc, current_epoch);
+    verify_immediate_fee_change(input_fee_bps, args.input_fee_bps)?;
+    verify_immediate_fee_change(output_fee_bps, args.output_fee_bps)?;
+    let baseline = record_fee_account_fee_baseline(fee_acc, state, current_epoch);
+    verify_lst_fee_bps_update(state, baseline.input_fee_bps, args.input_fee_bps)?;
+    verify_lst_fee_bps_update(state, baseline.output_fee_bps, args.output_fee_bps)?;
+
     fee_acc.input_fee_bps = args.input_fee_bps;
     fee_acc.output_fee_bps = args.output_fee_bps;
+    // an immediate change supersedes any scheduled change
+    set_fee_account_pending_fees(fee_acc, None);
 
     Ok(())
 }
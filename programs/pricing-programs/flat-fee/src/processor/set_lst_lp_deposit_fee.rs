@@ -0,0 +1,63 @@
+use flat_fee_interface::{
+    set_lst_lp_deposit_fee_verify_account_keys, set_lst_lp_deposit_fee_verify_account_privileges,
+    SetLstLpDepositFeeAccounts, SetLstLpDepositFeeIxArgs, SetLstLpDepositFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLstLpDepositFeeFreeArgs,
+    calc::record_fee_account_fee_baseline,
+    fee_bound::{
+        verify_combined_lp_deposit_fee_bps, verify_lp_deposit_fee_bps_bound,
+        verify_lp_deposit_fee_bps_update,
+    },
+    utils::{try_fee_account_mut, try_program_state_allow_legacy},
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+pub fn process_set_lst_lp_deposit_fee(
+    accounts: &[AccountInfo],
+    args: SetLstLpDepositFeeIxArgs,
+) -> ProgramResult {
+    let SetLstLpDepositFeeAccounts { fee_acc, state, .. } =
+        verify_set_lst_lp_deposit_fee(accounts, &args)?;
+
+    let mut bytes = fee_acc.try_borrow_mut_data()?;
+    let fee_acc = try_fee_account_mut(&mut bytes)?;
+
+    let state_bytes = state.try_borrow_data()?;
+    let state = &try_program_state_allow_legacy(&state_bytes)?;
+    verify_combined_lp_deposit_fee_bps(state.lp_deposit_fee_bps, args.lp_deposit_fee_bps)?;
+    let baseline = record_fee_account_fee_baseline(fee_acc, state, Clock::get()?.epoch);
+    verify_lp_deposit_fee_bps_update(state, baseline.lp_deposit_fee_bps, args.lp_deposit_fee_bps)?;
+
+    fee_acc.lp_deposit_fee_bps = args.lp_deposit_fee_bps;
+
+    Ok(())
+}
+
+fn verify_set_lst_lp_deposit_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetLstLpDepositFeeIxArgs { lp_deposit_fee_bps }: &SetLstLpDepositFeeIxArgs,
+) -> Result<SetLstLpDepositFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: SetLstLpDepositFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = SetLstLpDepositFeeFreeArgs {
+        state_acc: actual.state,
+        fee_acc: *actual.fee_acc.key,
+    };
+    let expected: SetLstLpDepositFeeKeys = free_args.resolve()?;
+
+    set_lst_lp_deposit_fee_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_lst_lp_deposit_fee_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_lp_deposit_fee_bps_bound(*lp_deposit_fee_bps)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,80 @@
+use flat_fee_interface::{
+    set_lst_lp_withdrawal_fee_verify_account_keys,
+    set_lst_lp_withdrawal_fee_verify_account_privileges, SetLstLpWithdrawalFeeAccounts,
+    SetLstLpWithdrawalFeeIxArgs, SetLstLpWithdrawalFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLstLpWithdrawalFeeFreeArgs,
+    calc::{program_state_lp_withdrawal_fee_bps, record_fee_account_fee_baseline},
+    fee_bound::{verify_lp_withdrawal_fee_bps_update, verify_unsigned_fee_bps_bound},
+    utils::{try_fee_account_mut, try_program_state_allow_legacy},
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+pub fn process_set_lst_lp_withdrawal_fee(
+    accounts: &[AccountInfo],
+    args: SetLstLpWithdrawalFeeIxArgs,
+) -> ProgramResult {
+    let SetLstLpWithdrawalFeeAccounts { fee_acc, state, .. } =
+        verify_set_lst_lp_withdrawal_fee(accounts, &args)?;
+
+    let mut bytes = fee_acc.try_borrow_mut_data()?;
+    let fee_acc = try_fee_account_mut(&mut bytes)?;
+
+    let state_bytes = state.try_borrow_data()?;
+    let state = &try_program_state_allow_legacy(&state_bytes)?;
+    let current_epoch = Clock::get()?.epoch;
+    let global_lp_withdrawal_fee_bps = program_state_lp_withdrawal_fee_bps(state, current_epoch);
+    let baseline = record_fee_account_fee_baseline(fee_acc, state, current_epoch);
+    verify_lp_withdrawal_fee_bps_update(
+        state,
+        baseline.lp_withdrawal_fee_bps,
+        args.lp_withdrawal_fee_bps
+            .unwrap_or(global_lp_withdrawal_fee_bps),
+    )?;
+
+    match args.lp_withdrawal_fee_bps {
+        Some(lp_withdrawal_fee_bps) => {
+            fee_acc.has_lp_withdrawal_fee = 1;
+            fee_acc.lp_withdrawal_fee_bps = lp_withdrawal_fee_bps;
+        }
+        None => {
+            fee_acc.has_lp_withdrawal_fee = 0;
+            fee_acc.lp_withdrawal_fee_bps = 0;
+        }
+    }
+
+    Ok(())
+}
+
+fn verify_set_lst_lp_withdrawal_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetLstLpWithdrawalFeeIxArgs {
+        lp_withdrawal_fee_bps,
+    }: &SetLstLpWithdrawalFeeIxArgs,
+) -> Result<SetLstLpWithdrawalFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: SetLstLpWithdrawalFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = SetLstLpWithdrawalFeeFreeArgs {
+        state_acc: actual.state,
+        fee_acc: *actual.fee_acc.key,
+    };
+    let expected: SetLstLpWithdrawalFeeKeys = free_args.resolve()?;
+
+    set_lst_lp_withdrawal_fee_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_lst_lp_withdrawal_fee_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    if let Some(lp_withdrawal_fee_bps) = lp_withdrawal_fee_bps {
+        verify_unsigned_fee_bps_bound(*lp_withdrawal_fee_bps)?;
+    }
+
+    Ok(actual)
+}
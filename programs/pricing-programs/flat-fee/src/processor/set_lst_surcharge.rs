@@ -0,0 +1,107 @@
+use flat_fee_interface::{
+    set_lst_surcharge_verify_account_keys, set_lst_surcharge_verify_account_privileges,
+    SetLstSurchargeAccounts, SetLstSurchargeIxArgs, SetLstSurchargeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLstSurchargeFreeArgs,
+    calc::record_lst_surcharge_fee_baseline,
+    fee_bound::{verify_surge_fee_bps_update, verify_unsigned_fee_bps_bound},
+    pda::LstSurchargeCreatePdaArgs,
+    program,
+    utils::{try_lst_surcharge_mut, try_program_state_allow_legacy},
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_s_common::token::verify_tokenkeg_or_22_mint;
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+use system_program_interface::CreateAccountAccounts;
+
+pub fn process_set_lst_surcharge(
+    accounts: &[AccountInfo],
+    args: SetLstSurchargeIxArgs,
+) -> ProgramResult {
+    let (
+        SetLstSurchargeAccounts {
+            payer,
+            surcharge_acc,
+            stake_pool,
+            state,
+            ..
+        },
+        SetLstSurchargeIxArgs {
+            surcharge_bps,
+            last_update_epoch_offset,
+        },
+        create_pda_args,
+    ) = verify_set_lst_surcharge(accounts, args)?;
+
+    if surcharge_acc.data_is_empty() {
+        init_rent_exempt_account_invoke_signed(
+            CreateAccountAccounts {
+                from: payer,
+                to: surcharge_acc,
+            },
+            InitRentExemptAccountArgs {
+                space: program::LST_SURCHARGE_SIZE,
+                owner: program::ID,
+            },
+            &[create_pda_args.to_signer_seeds().as_slice()],
+        )?;
+    }
+
+    let mut bytes = surcharge_acc.try_borrow_mut_data()?;
+    let surcharge_acc = try_lst_surcharge_mut(&mut bytes)?;
+
+    let state_bytes = state.try_borrow_data()?;
+    let state = &try_program_state_allow_legacy(&state_bytes)?;
+    // a newly created LstSurcharge has a baseline of 0
+    let baseline_fee_bps = record_lst_surcharge_fee_baseline(surcharge_acc, Clock::get()?.epoch);
+    verify_surge_fee_bps_update(state, baseline_fee_bps, surcharge_bps)?;
+
+    surcharge_acc.stake_pool = *stake_pool.key;
+    surcharge_acc.bump = create_pda_args.bump;
+    surcharge_acc.surcharge_bps = surcharge_bps;
+    surcharge_acc.last_update_epoch_offset = last_update_epoch_offset;
+
+    Ok(())
+}
+
+fn verify_set_lst_surcharge<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    args: SetLstSurchargeIxArgs,
+) -> Result<
+    (
+        SetLstSurchargeAccounts<'me, 'info>,
+        SetLstSurchargeIxArgs,
+        LstSurchargeCreatePdaArgs,
+    ),
+    ProgramError,
+> {
+    let actual: SetLstSurchargeAccounts = load_accounts(accounts)?;
+
+    let free_args = SetLstSurchargeFreeArgs {
+        payer: *actual.payer.key,
+        state_acc: actual.state,
+        lst_mint: *actual.lst_mint.key,
+        stake_pool: *actual.stake_pool.key,
+    };
+    let (expected, create_pda_args): (SetLstSurchargeKeys, LstSurchargeCreatePdaArgs) =
+        free_args.resolve()?;
+
+    set_lst_surcharge_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_lst_surcharge_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_tokenkeg_or_22_mint(actual.lst_mint)?;
+    verify_unsigned_fee_bps_bound(args.surcharge_bps)?;
+
+    Ok((actual, args, create_pda_args))
+}
@@ -0,0 +1,81 @@
+use flat_fee_interface::{
+    set_surge_fee_verify_account_keys, set_surge_fee_verify_account_privileges,
+    SetSurgeFeeAccounts, SetSurgeFeeIxArgs, SetSurgeFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetSurgeFeeFreeArgs,
+    calc::record_surge_config_fee_baseline,
+    fee_bound::{verify_surge_fee_bps_update, verify_unsigned_fee_bps_bound},
+    program,
+    utils::{try_program_state_allow_legacy, try_surge_config_mut},
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+use system_program_interface::CreateAccountAccounts;
+
+pub fn process_set_surge_fee(accounts: &[AccountInfo], args: SetSurgeFeeIxArgs) -> ProgramResult {
+    let SetSurgeFeeAccounts {
+        payer,
+        surge_config,
+        state,
+        ..
+    } = verify_set_surge_fee(accounts, &args)?;
+
+    if surge_config.data_is_empty() {
+        init_rent_exempt_account_invoke_signed(
+            CreateAccountAccounts {
+                from: payer,
+                to: surge_config,
+            },
+            InitRentExemptAccountArgs {
+                space: program::SURGE_CONFIG_SIZE,
+                owner: program::ID,
+            },
+            &[&[program::SURGE_CONFIG_SEED, &[program::SURGE_CONFIG_BUMP]]],
+        )?;
+    }
+
+    let mut bytes = surge_config.try_borrow_mut_data()?;
+    let surge_config = try_surge_config_mut(&mut bytes)?;
+
+    let state_bytes = state.try_borrow_data()?;
+    let state = &try_program_state_allow_legacy(&state_bytes)?;
+    // a newly created SurgeConfig has a baseline of 0
+    let baseline_fee_bps = record_surge_config_fee_baseline(surge_config, Clock::get()?.epoch);
+    verify_surge_fee_bps_update(state, baseline_fee_bps, args.max_surge_fee_bps)?;
+
+    surge_config.max_surge_fee_bps = args.max_surge_fee_bps;
+    surge_config.decay_slots = args.decay_slots;
+
+    Ok(())
+}
+
+fn verify_set_surge_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetSurgeFeeIxArgs {
+        max_surge_fee_bps, ..
+    }: &SetSurgeFeeIxArgs,
+) -> Result<SetSurgeFeeAccounts<'me, 'info>, ProgramError> {
+    let actual: SetSurgeFeeAccounts = load_accounts(accounts)?;
+
+    let free_args = SetSurgeFeeFreeArgs {
+        payer: *actual.payer.key,
+        state_acc: actual.state,
+    };
+    let expected: SetSurgeFeeKeys = free_args.resolve()?;
+
+    set_surge_fee_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_surge_fee_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_unsigned_fee_bps_bound(*max_surge_fee_bps)?;
+
+    Ok(actual)
+}
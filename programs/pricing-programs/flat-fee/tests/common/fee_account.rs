@@ -1,8 +1,33 @@
 use flat_fee_interface::AddLstIxArgs;
 use flat_fee_lib::{pda::FeeAccountFindPdaArgs, program, utils::try_fee_account};
-use sanctum_solana_test_utils::ExtendedBanksClient;
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedBanksClient};
 use solana_program::pubkey::Pubkey;
 use solana_program_test::BanksClient;
+use solana_sdk::account::Account;
+
+/// A FeeAccount with the legacy layout that has not been migrated
+pub fn legacy_fee_account_and_addr(
+    lst_mint: Pubkey,
+    input_fee_bps: i16,
+    output_fee_bps: i16,
+) -> (Account, Pubkey) {
+    let (addr, bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let mut data = vec![bump, 0u8];
+    data.extend(input_fee_bps.to_le_bytes());
+    data.extend(output_fee_bps.to_le_bytes());
+    let acc = Account {
+        lamports: est_rent_exempt_lamports(data.len()),
+        data,
+        owner: program::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    };
+    (acc, addr)
+}
 
 pub async fn verify_fee_account(
     banks_client: &mut BanksClient,
@@ -21,6 +46,21 @@ pub async fn verify_fee_account(
     assert_eq!(actual.output_fee_bps, expected.output_fee_bps);
 }
 
+pub async fn verify_num_pair_fees(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    expected_num_pair_fees: u8,
+) {
+    let (addr, _bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let actual_acc = banks_client.get_account_unwrapped(addr).await;
+    let actual = try_fee_account(&actual_acc.data).unwrap();
+    assert_eq!(actual.num_pair_fees, expected_num_pair_fees);
+}
+
 pub async fn verify_fee_account_does_not_exist(banks_client: &mut BanksClient, lst_mint: Pubkey) {
     let find_pda_args = FeeAccountFindPdaArgs {
         lst_mint,
@@ -0,0 +1,36 @@
+use flat_fee_lib::{pda::PairFeeAccountFindPdaArgs, program, utils::try_pair_fee_account};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClient;
+
+pub async fn verify_pair_fee_account(
+    banks_client: &mut BanksClient,
+    input_lst_mint: Pubkey,
+    output_lst_mint: Pubkey,
+    expected_fee_bps: i16,
+) {
+    let (addr, bump) = PairFeeAccountFindPdaArgs {
+        program_id: program::ID,
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .get_pair_fee_account_address_and_bump_seed();
+    let actual_acc = banks_client.get_account_unwrapped(addr).await;
+    let actual = try_pair_fee_account(&actual_acc.data).unwrap();
+    assert_eq!(actual.bump, bump);
+    assert_eq!(actual.fee_bps, expected_fee_bps);
+}
+
+pub async fn verify_pair_fee_account_does_not_exist(
+    banks_client: &mut BanksClient,
+    input_lst_mint: Pubkey,
+    output_lst_mint: Pubkey,
+) {
+    let (addr, _bump) = PairFeeAccountFindPdaArgs {
+        program_id: program::ID,
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .get_pair_fee_account_address_and_bump_seed();
+    assert!(banks_client.get_account(addr).await.unwrap().is_none());
+}
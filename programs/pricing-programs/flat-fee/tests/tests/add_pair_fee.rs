@@ -0,0 +1,203 @@
+use flat_fee_interface::{add_pair_fee_ix, AddPairFeeIxArgs, FlatFeeError, ProgramState};
+use flat_fee_lib::{account_resolvers::AddPairFeeFreeArgs, program::STATE_ID};
+use flat_fee_test_utils::{FlatFeePricingProgramTestBanksClient, MockFeeAccountArgs};
+use sanctum_solana_test_utils::{
+    assert_custom_err,
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::{
+    legacy_fee_account_and_addr, normal_program_test, verify_num_pair_fees,
+    verify_pair_fee_account, verify_pair_fee_account_does_not_exist,
+};
+
+fn add_pair_fee_program_test() -> (ProgramTest, Keypair, Pubkey, Pubkey) {
+    let manager = Keypair::new();
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+
+    let mint_args = || MockMintArgs {
+        mint_authority: None,
+        freeze_authority: None,
+        supply: 0,
+        decimals: 9,
+    };
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: 0,
+            output_fee_bps: 0,
+            lp_deposit_fee_bps: 0,
+            lp_withdrawal_fee_bps: None,
+            lst_mint: input_lst_mint,
+        }],
+    )
+    .add_tokenkeg_mint_from_args(input_lst_mint, mint_args())
+    .add_tokenkeg_mint_from_args(output_lst_mint, mint_args());
+    (program_test, manager, input_lst_mint, output_lst_mint)
+}
+
+#[tokio::test]
+async fn add_pair_fee_basic() {
+    const FEE_BPS: i16 = 3;
+
+    let (program_test, manager, input_lst_mint, output_lst_mint) = add_pair_fee_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    verify_pair_fee_account_does_not_exist(&mut banks_client, input_lst_mint, output_lst_mint)
+        .await;
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+
+    let (keys, _pda) = AddPairFeeFreeArgs {
+        payer: payer.pubkey(),
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .resolve()
+    .unwrap();
+    let ix = add_pair_fee_ix(keys, AddPairFeeIxArgs { fee_bps: FEE_BPS }).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_pair_fee_account(&mut banks_client, input_lst_mint, output_lst_mint, FEE_BPS).await;
+    verify_num_pair_fees(&mut banks_client, input_lst_mint, 1).await;
+    // overrides are directional
+    verify_pair_fee_account_does_not_exist(&mut banks_client, output_lst_mint, input_lst_mint)
+        .await;
+}
+
+#[tokio::test]
+async fn add_pair_fee_fail_invalid_fee() {
+    let (program_test, manager, input_lst_mint, output_lst_mint) = add_pair_fee_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let keyed_state_acc = KeyedAccount {
+        pubkey: STATE_ID,
+        account: state_acc,
+    };
+
+    for bad_fee_bps in [10_001, -10_001] {
+        let (keys, _pda) = AddPairFeeFreeArgs {
+            payer: payer.pubkey(),
+            state_acc: &keyed_state_acc,
+            input_lst_mint,
+            output_lst_mint,
+        }
+        .resolve()
+        .unwrap();
+        let ix = add_pair_fee_ix(
+            keys,
+            AddPairFeeIxArgs {
+                fee_bps: bad_fee_bps,
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, FlatFeeError::SignedFeeOutOfBound);
+
+        verify_pair_fee_account_does_not_exist(&mut banks_client, input_lst_mint, output_lst_mint)
+            .await;
+        verify_num_pair_fees(&mut banks_client, input_lst_mint, 0).await;
+    }
+}
+
+#[tokio::test]
+async fn add_pair_fee_fail_input_lst_not_added() {
+    let (program_test, manager, input_lst_mint, output_lst_mint) = add_pair_fee_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+
+    // the output LST has no FeeAccount, so it cannot be the input LST of an override
+    let (keys, _pda) = AddPairFeeFreeArgs {
+        payer: payer.pubkey(),
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+        input_lst_mint: output_lst_mint,
+        output_lst_mint: input_lst_mint,
+    }
+    .resolve()
+    .unwrap();
+    let ix = add_pair_fee_ix(keys, AddPairFeeIxArgs { fee_bps: 1 }).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::UnsupportedLstMint);
+
+    verify_pair_fee_account_does_not_exist(&mut banks_client, output_lst_mint, input_lst_mint)
+        .await;
+}
+
+#[tokio::test]
+async fn add_pair_fee_fail_legacy_input_fee_account() {
+    let (mut program_test, manager, input_lst_mint, output_lst_mint) = add_pair_fee_program_test();
+    let (acc, addr) = legacy_fee_account_and_addr(input_lst_mint, 0, 0);
+    program_test.add_account(addr, acc);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+
+    let (keys, _pda) = AddPairFeeFreeArgs {
+        payer: payer.pubkey(),
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .resolve()
+    .unwrap();
+    let ix = add_pair_fee_ix(keys, AddPairFeeIxArgs { fee_bps: 1 }).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::FeeAccountNeedsMigration);
+
+    verify_pair_fee_account_does_not_exist(&mut banks_client, input_lst_mint, output_lst_mint)
+        .await;
+}
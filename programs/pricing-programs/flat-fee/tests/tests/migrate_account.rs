@@ -0,0 +1,228 @@
+use flat_fee_interface::{migrate_account_ix, FlatFeeError};
+use flat_fee_lib::{
+    account_resolvers::MigrateAccountFreeArgs,
+    pda::FeeAccountFindPdaArgs,
+    program::{
+        self, FEE_ACCOUNT_SIZE, LEGACY_FEE_ACCOUNT_SIZE, LEGACY_STATE_SIZE, STATE_SIZE,
+        SURGE_CONFIG_SIZE,
+    },
+    utils::{try_fee_account, try_program_state},
+};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::{assert_custom_err, est_rent_exempt_lamports, ExtendedBanksClient};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, BanksClient, BanksClientError, ProgramTest};
+use solana_sdk::{
+    account::Account, hash::Hash, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+fn legacy_account(data: Vec<u8>) -> Account {
+    Account {
+        lamports: est_rent_exempt_lamports(data.len()),
+        data,
+        owner: program::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+fn migrate_program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "flat_fee",
+        program::ID,
+        processor!(flat_fee::entrypoint::process_instruction),
+    );
+    program_test
+}
+
+async fn exec_migrate(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    last_blockhash: Hash,
+    migrate_acc: Pubkey,
+    lst_mint: Option<Pubkey>,
+) -> Result<(), BanksClientError> {
+    let ix = migrate_account_ix(
+        MigrateAccountFreeArgs {
+            payer: payer.pubkey(),
+            migrate_acc,
+            lst_mint,
+        }
+        .resolve(),
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer], last_blockhash);
+    banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn migrate_program_state_basic() {
+    const LP_WITHDRAWAL_FEE_BPS: u16 = 5;
+
+    let manager = Pubkey::new_unique();
+    let mut data = manager.to_bytes().to_vec();
+    data.extend(LP_WITHDRAWAL_FEE_BPS.to_le_bytes());
+    assert_eq!(data.len(), LEGACY_STATE_SIZE);
+
+    let mut program_test = migrate_program_test();
+    program_test.add_account(program::STATE_ID, legacy_account(data));
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    exec_migrate(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        program::STATE_ID,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    assert_eq!(state_acc.data.len(), STATE_SIZE);
+    assert!(state_acc.lamports >= est_rent_exempt_lamports(STATE_SIZE));
+    let state = try_program_state(&state_acc.data).unwrap();
+    assert_eq!(state.manager, manager);
+    assert_eq!(state.lp_withdrawal_fee_bps, LP_WITHDRAWAL_FEE_BPS);
+    assert_eq!(state.lp_deposit_fee_bps, 0);
+    assert_eq!(state.has_pending_lp_withdrawal_fee, 0);
+    assert_eq!(state.admin, manager);
+    assert_eq!(state.has_fee_bounds, 0);
+}
+
+#[tokio::test]
+async fn migrate_fee_account_basic() {
+    const INPUT_FEE_BPS: i16 = -3;
+    const OUTPUT_FEE_BPS: i16 = 8;
+
+    let lst_mint = Pubkey::new_unique();
+    let (addr, bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let mut data = vec![bump, 0u8];
+    data.extend(INPUT_FEE_BPS.to_le_bytes());
+    data.extend(OUTPUT_FEE_BPS.to_le_bytes());
+    assert_eq!(data.len(), LEGACY_FEE_ACCOUNT_SIZE);
+
+    let mut program_test = migrate_program_test();
+    program_test.add_account(addr, legacy_account(data));
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    exec_migrate(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        addr,
+        Some(lst_mint),
+    )
+    .await
+    .unwrap();
+
+    let fee_acc = banks_client.get_account_unwrapped(addr).await;
+    assert_eq!(fee_acc.data.len(), FEE_ACCOUNT_SIZE);
+    assert!(fee_acc.lamports >= est_rent_exempt_lamports(FEE_ACCOUNT_SIZE));
+    let fee_acc = try_fee_account(&fee_acc.data).unwrap();
+    assert_eq!(fee_acc.bump, bump);
+    assert_eq!(fee_acc.input_fee_bps, INPUT_FEE_BPS);
+    assert_eq!(fee_acc.output_fee_bps, OUTPUT_FEE_BPS);
+    assert_eq!(fee_acc.lp_deposit_fee_bps, 0);
+    assert_eq!(fee_acc.has_lp_withdrawal_fee, 0);
+    assert_eq!(fee_acc.has_pending_fees, 0);
+}
+
+#[tokio::test]
+async fn migrate_fail_surge_config() {
+    let mut program_test = migrate_program_test();
+    program_test.add_account(
+        program::SURGE_CONFIG_ID,
+        legacy_account(vec![0u8; SURGE_CONFIG_SIZE]),
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let err = exec_migrate(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        program::SURGE_CONFIG_ID,
+        None,
+    )
+    .await
+    .unwrap_err();
+    assert_custom_err(err, FlatFeeError::AccountNotMigratable);
+}
+
+#[tokio::test]
+async fn migrate_fail_already_migrated() {
+    let mut program_test = migrate_program_test();
+    program_test.add_account(program::STATE_ID, legacy_account(vec![0u8; STATE_SIZE]));
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let err = exec_migrate(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        program::STATE_ID,
+        None,
+    )
+    .await
+    .unwrap_err();
+    assert_custom_err(err, FlatFeeError::AccountNotMigratable);
+}
+
+#[tokio::test]
+async fn migrate_fail_fee_account_already_migrated() {
+    let lst_mint = Pubkey::new_unique();
+    let (addr, bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let mut data = vec![0u8; FEE_ACCOUNT_SIZE];
+    data[0] = bump;
+
+    let mut program_test = migrate_program_test();
+    program_test.add_account(addr, legacy_account(data));
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let err = exec_migrate(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        addr,
+        Some(lst_mint),
+    )
+    .await
+    .unwrap_err();
+    assert_custom_err(err, FlatFeeError::AccountNotMigratable);
+}
+
+#[tokio::test]
+async fn migrate_fail_fee_account_wrong_lst_mint() {
+    let (addr, bump) = FeeAccountFindPdaArgs {
+        lst_mint: Pubkey::new_unique(),
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let mut data = vec![bump, 0u8];
+    data.extend(0i16.to_le_bytes());
+    data.extend(0i16.to_le_bytes());
+
+    let mut program_test = migrate_program_test();
+    program_test.add_account(addr, legacy_account(data));
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let err = exec_migrate(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        addr,
+        Some(Pubkey::new_unique()),
+    )
+    .await
+    .unwrap_err();
+    assert_custom_err(err, FlatFeeError::AccountNotMigratable);
+}
@@ -1,10 +1,23 @@
 mod add_lst;
+mod add_pair_fee;
 mod initialize;
+mod migrate_account;
 mod price_exact_in;
 mod price_exact_out;
 mod price_lp_tokens_to_mint;
 mod price_lp_tokens_to_redeem;
 mod remove_lst;
+mod remove_pair_fee;
+mod schedule_lp_withdrawal_fee;
+mod schedule_lst_fee;
+mod set_admin;
+mod set_fee_bounds;
+mod set_lp_deposit_fee;
 mod set_lp_withdrawal_fee;
 mod set_lst_fee;
+mod set_lst_lp_deposit_fee;
+mod set_lst_lp_withdrawal_fee;
+mod set_lst_surcharge;
 mod set_manager;
+mod set_pair_fee;
+mod set_surge_fee;
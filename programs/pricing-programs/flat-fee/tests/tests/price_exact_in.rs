@@ -1 +1,170 @@
+use flat_fee_interface::{price_exact_in_ix, FlatFeeError, PriceExactInIxArgs};
+use flat_fee_lib::{
+    account_resolvers::PriceExactInFreeArgs,
+    calc::{calculate_price_exact_in, CalculatePriceExactInArgs},
+    pda::PairFeeAccountFindPdaArgs,
+};
+use flat_fee_test_utils::{
+    MockFeeAccountArgs, MockPairFeeAccount, MockPairFeeAccountArgs, DEFAULT_PROGRAM_STATE,
+};
+use sanctum_solana_test_utils::{assert_custom_err, ExtendedBanksClient, IntoAccount};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+};
+use solana_program_test::ProgramTest;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use test_utils::jitosol;
 
+use crate::common::{legacy_fee_account_and_addr, normal_program_test};
+
+const MSOL_MINT: Pubkey = pubkey!("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So");
+
+const INPUT_FEE_BPS: i16 = 10;
+const OUTPUT_FEE_BPS: i16 = 20;
+const PAIR_FEE_BPS: i16 = -5;
+const SOL_VALUE: u64 = 1_000_000_000;
+
+/// jitoSOL -> mSOL has a PairFeeAccount, mSOL -> jitoSOL does not
+fn price_exact_in_program_test() -> ProgramTest {
+    let fee_args = |lst_mint| MockFeeAccountArgs {
+        input_fee_bps: INPUT_FEE_BPS,
+        output_fee_bps: OUTPUT_FEE_BPS,
+        lp_deposit_fee_bps: 0,
+        lp_withdrawal_fee_bps: None,
+        lst_mint,
+    };
+    let mut program_test = normal_program_test(
+        DEFAULT_PROGRAM_STATE,
+        &[fee_args(jitosol::ID), fee_args(MSOL_MINT)],
+    );
+    let (acc, addr) = MockPairFeeAccountArgs {
+        fee_bps: PAIR_FEE_BPS,
+        input_lst_mint: jitosol::ID,
+        output_lst_mint: MSOL_MINT,
+    }
+    .to_pair_fee_account_and_addr(flat_fee_lib::program::ID);
+    program_test.add_account(addr, MockPairFeeAccount(acc).into_account());
+    program_test
+}
+
+fn price_exact_in_ix_for(input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> Instruction {
+    price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+            pool_state: Pubkey::new_unique(),
+            lst_state_list: Pubkey::new_unique(),
+            input_pool_reserves: Pubkey::new_unique(),
+            output_pool_reserves: Pubkey::new_unique(),
+            input_stake_pool: input_lst_mint,
+            output_stake_pool: output_lst_mint,
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: SOL_VALUE,
+            sol_value: SOL_VALUE,
+        },
+    )
+    .unwrap()
+}
+
+fn pair_fee_acc_meta(input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> AccountMeta {
+    let (pair_fee_acc, _bump) = PairFeeAccountFindPdaArgs {
+        program_id: flat_fee_lib::program::ID,
+        input_lst_mint,
+        output_lst_mint,
+    }
+    .get_pair_fee_account_address_and_bump_seed();
+    AccountMeta::new_readonly(pair_fee_acc, false)
+}
+
+async fn verify_price_exact_in(ix: Instruction, expected: u64) {
+    let (mut banks_client, payer, last_blockhash) = price_exact_in_program_test().start().await;
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, expected)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_pair_fee_override() {
+    let expected = calculate_price_exact_in(CalculatePriceExactInArgs {
+        input_fee_bps: INPUT_FEE_BPS,
+        output_fee_bps: OUTPUT_FEE_BPS,
+        pair_fee_bps: Some(PAIR_FEE_BPS),
+        surge_fee_bps: 0,
+        in_sol_value: SOL_VALUE,
+    })
+    .unwrap();
+    let mut ix = price_exact_in_ix_for(jitosol::ID, MSOL_MINT);
+    ix.accounts.push(pair_fee_acc_meta(jitosol::ID, MSOL_MINT));
+    verify_price_exact_in(ix, expected).await;
+}
+
+#[tokio::test]
+async fn price_exact_in_pair_fee_acc_omitted() {
+    let expected = calculate_price_exact_in(CalculatePriceExactInArgs {
+        input_fee_bps: INPUT_FEE_BPS,
+        output_fee_bps: OUTPUT_FEE_BPS,
+        pair_fee_bps: None,
+        surge_fee_bps: 0,
+        in_sol_value: SOL_VALUE,
+    })
+    .unwrap();
+    verify_price_exact_in(price_exact_in_ix_for(jitosol::ID, MSOL_MINT), expected).await;
+}
+
+#[tokio::test]
+async fn price_exact_in_uninitialized_pair_fee_acc_fails() {
+    let (mut banks_client, payer, last_blockhash) = price_exact_in_program_test().start().await;
+
+    let mut ix = price_exact_in_ix_for(MSOL_MINT, jitosol::ID);
+    ix.accounts.push(pair_fee_acc_meta(MSOL_MINT, jitosol::ID));
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::InvalidPairFeeAccountData);
+}
+
+#[tokio::test]
+async fn price_exact_in_wrong_pair_fee_acc_fails() {
+    let (mut banks_client, payer, last_blockhash) = price_exact_in_program_test().start().await;
+
+    let mut ix = price_exact_in_ix_for(MSOL_MINT, jitosol::ID);
+    // PairFeeAccount of the opposite direction
+    ix.accounts.push(pair_fee_acc_meta(jitosol::ID, MSOL_MINT));
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::IncorrectPairFeeAccount);
+}
+
+#[tokio::test]
+async fn price_exact_in_legacy_fee_accounts() {
+    let mut program_test = normal_program_test(DEFAULT_PROGRAM_STATE, &[]);
+    for lst_mint in [jitosol::ID, MSOL_MINT] {
+        let (acc, addr) = legacy_fee_account_and_addr(lst_mint, INPUT_FEE_BPS, OUTPUT_FEE_BPS);
+        program_test.add_account(addr, acc);
+    }
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let expected = calculate_price_exact_in(CalculatePriceExactInArgs {
+        input_fee_bps: INPUT_FEE_BPS,
+        output_fee_bps: OUTPUT_FEE_BPS,
+        pair_fee_bps: None,
+        surge_fee_bps: 0,
+        in_sol_value: SOL_VALUE,
+    })
+    .unwrap();
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(
+            &payer,
+            last_blockhash,
+            price_exact_in_ix_for(jitosol::ID, MSOL_MINT),
+            expected,
+        )
+        .await;
+}
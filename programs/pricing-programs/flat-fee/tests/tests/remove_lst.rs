@@ -1,8 +1,10 @@
 // use flat_fee_interface::{remove_lst_ix, AddLstIxArgs, ProgramState, RemoveLstIxArgs};
-use flat_fee_interface::{remove_lst_ix, AddLstIxArgs, ProgramState};
+use flat_fee_interface::{remove_lst_ix, AddLstIxArgs, FlatFeeError, ProgramState};
 use flat_fee_lib::{account_resolvers::RemoveLstFreeArgs, program::STATE_ID};
-use flat_fee_test_utils::{MockFeeAccountArgs, DEFAULT_PROGRAM_STATE};
-use sanctum_solana_test_utils::{assert_program_error, ExtendedBanksClient};
+use flat_fee_test_utils::{MockFeeAccount, MockFeeAccountArgs, DEFAULT_PROGRAM_STATE};
+use sanctum_solana_test_utils::{
+    assert_custom_err, assert_program_error, ExtendedBanksClient, IntoAccount,
+};
 use solana_program::program_error::ProgramError;
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
@@ -22,6 +24,8 @@ async fn remove_lst_basic() {
         &[MockFeeAccountArgs {
             input_fee_bps: 1,
             output_fee_bps: 2,
+            lp_deposit_fee_bps: 0,
+            lp_withdrawal_fee_bps: None,
             lst_mint: jitosol::ID,
         }],
     );
@@ -50,6 +54,8 @@ async fn remove_lst_fail_unauthorized() {
     const MOCK_FEE_ACCOUNT_ARGS: MockFeeAccountArgs = MockFeeAccountArgs {
         input_fee_bps: 6,
         output_fee_bps: 9,
+        lp_deposit_fee_bps: 0,
+        lp_withdrawal_fee_bps: None,
         lst_mint: jitosol::ID,
     };
     let manager = Keypair::new();
@@ -106,6 +112,8 @@ async fn remove_lst_fail_fee_account_is_program_state() {
         &[MockFeeAccountArgs {
             input_fee_bps: 1,
             output_fee_bps: 2,
+            lp_deposit_fee_bps: 0,
+            lp_withdrawal_fee_bps: None,
             lst_mint: jitosol::ID,
         }],
     );
@@ -134,3 +142,53 @@ async fn remove_lst_fail_fee_account_is_program_state() {
     // make sure state wasnt deleted
     assert!(banks_client.get_account(STATE_ID).await.unwrap().is_some());
 }
+
+#[tokio::test]
+async fn remove_lst_fail_has_pair_fees() {
+    let manager = Keypair::new();
+
+    let mut program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let (mut fee_acc, addr) = MockFeeAccountArgs {
+        input_fee_bps: 1,
+        output_fee_bps: 2,
+        lp_deposit_fee_bps: 0,
+        lp_withdrawal_fee_bps: None,
+        lst_mint: jitosol::ID,
+    }
+    .to_fee_account_and_addr(flat_fee_lib::program::ID);
+    fee_acc.num_pair_fees = 1;
+    program_test.add_account(addr, MockFeeAccount(fee_acc).into_account());
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let free_args = RemoveLstFreeArgs {
+        refund_rent_to: payer.pubkey(),
+        lst_mint: jitosol::ID,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: banks_client.get_account_unwrapped(STATE_ID).await,
+        },
+    };
+    let ix = remove_lst_ix(free_args.resolve().unwrap()).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::LstHasPairFees);
+
+    verify_fee_account(
+        &mut banks_client,
+        jitosol::ID,
+        AddLstIxArgs {
+            input_fee_bps: 1,
+            output_fee_bps: 2,
+        },
+    )
+    .await;
+}
@@ -0,0 +1,67 @@
+use flat_fee_interface::{remove_pair_fee_ix, ProgramState};
+use flat_fee_lib::{account_resolvers::RemovePairFeeFreeArgs, program::STATE_ID};
+use flat_fee_test_utils::{
+    MockFeeAccount, MockFeeAccountArgs, MockPairFeeAccount, MockPairFeeAccountArgs,
+    DEFAULT_PROGRAM_STATE,
+};
+use sanctum_solana_test_utils::{ExtendedBanksClient, IntoAccount};
+use solana_program::{pubkey, pubkey::Pubkey};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use test_utils::jitosol;
+
+use crate::common::{
+    normal_program_test, verify_num_pair_fees, verify_pair_fee_account_does_not_exist,
+};
+
+const MSOL_MINT: Pubkey = pubkey!("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So");
+
+#[tokio::test]
+async fn remove_pair_fee_basic() {
+    let manager = Keypair::new();
+
+    let mut program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let (mut fee_acc, addr) = MockFeeAccountArgs {
+        input_fee_bps: 0,
+        output_fee_bps: 0,
+        lp_deposit_fee_bps: 0,
+        lp_withdrawal_fee_bps: None,
+        lst_mint: jitosol::ID,
+    }
+    .to_fee_account_and_addr(flat_fee_lib::program::ID);
+    fee_acc.num_pair_fees = 1;
+    program_test.add_account(addr, MockFeeAccount(fee_acc).into_account());
+    let (acc, addr) = MockPairFeeAccountArgs {
+        fee_bps: 1,
+        input_lst_mint: jitosol::ID,
+        output_lst_mint: MSOL_MINT,
+    }
+    .to_pair_fee_account_and_addr(flat_fee_lib::program::ID);
+    program_test.add_account(addr, MockPairFeeAccount(acc).into_account());
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let free_args = RemovePairFeeFreeArgs {
+        refund_rent_to: payer.pubkey(),
+        input_lst_mint: jitosol::ID,
+        output_lst_mint: MSOL_MINT,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: banks_client.get_account_unwrapped(STATE_ID).await,
+        },
+    };
+    let ix = remove_pair_fee_ix(free_args.resolve().unwrap()).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_pair_fee_account_does_not_exist(&mut banks_client, jitosol::ID, MSOL_MINT).await;
+    verify_num_pair_fees(&mut banks_client, jitosol::ID, 0).await;
+}
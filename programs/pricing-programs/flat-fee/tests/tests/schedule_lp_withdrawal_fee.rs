@@ -0,0 +1,135 @@
+use flat_fee_interface::{
+    schedule_lp_withdrawal_fee_ix, FlatFeeError, ProgramState, ScheduleLpWithdrawalFeeIxArgs,
+};
+use flat_fee_lib::{
+    account_resolvers::ScheduleLpWithdrawalFeeFreeArgs,
+    calc::{program_state_pending_lp_withdrawal_fee, PendingFee, MIN_ACTIVATION_EPOCH_DELAY},
+    program::STATE_ID,
+    utils::try_program_state,
+};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::assert_custom_err;
+use solana_program::clock::Clock;
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+use crate::common::normal_program_test;
+
+const CURR_LP_WITHDRAWAL_FEE_BPS: u16 = 5;
+
+fn program_test_with_manager(manager: &Keypair) -> ProgramTest {
+    normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: CURR_LP_WITHDRAWAL_FEE_BPS,
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[],
+    )
+}
+
+async fn schedule_lp_withdrawal_fee_ix_for(
+    banks_client: &mut BanksClient,
+    lp_withdrawal_fee_bps: u16,
+    activation_epoch: u64,
+) -> Instruction {
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    schedule_lp_withdrawal_fee_ix(
+        ScheduleLpWithdrawalFeeFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        ScheduleLpWithdrawalFeeIxArgs {
+            lp_withdrawal_fee_bps,
+            activation_epoch,
+        },
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn schedule_lp_withdrawal_fee_basic() {
+    const NEW_LP_WITHDRAWAL_FEE_BPS: u16 = 420;
+    let manager = Keypair::new();
+
+    let program_test = program_test_with_manager(&manager);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+    let activation_epoch = clock.epoch + MIN_ACTIVATION_EPOCH_DELAY;
+    let ix = schedule_lp_withdrawal_fee_ix_for(
+        &mut banks_client,
+        NEW_LP_WITHDRAWAL_FEE_BPS,
+        activation_epoch,
+    )
+    .await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.lp_withdrawal_fee_bps, CURR_LP_WITHDRAWAL_FEE_BPS);
+    assert_eq!(
+        program_state_pending_lp_withdrawal_fee(state),
+        Some(PendingFee {
+            fee: NEW_LP_WITHDRAWAL_FEE_BPS,
+            activation_epoch,
+        })
+    );
+}
+
+#[tokio::test]
+async fn schedule_lp_withdrawal_fee_fail_exceed_bound() {
+    const BAD_LP_WITHDRAWAL_FEE_BPS: u16 = 10_001;
+    let manager = Keypair::new();
+
+    let program_test = program_test_with_manager(&manager);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+    let ix = schedule_lp_withdrawal_fee_ix_for(
+        &mut banks_client,
+        BAD_LP_WITHDRAWAL_FEE_BPS,
+        clock.epoch + MIN_ACTIVATION_EPOCH_DELAY,
+    )
+    .await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::UnsignedFeeOutOfBound);
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+    assert_eq!(program_state_pending_lp_withdrawal_fee(state), None);
+}
@@ -0,0 +1,279 @@
+use flat_fee_interface::{
+    schedule_lst_fee_ix, set_lst_fee_ix, FlatFeeError, ProgramState, ScheduleLstFeeIxArgs,
+    SetLstFeeIxArgs,
+};
+use flat_fee_lib::{
+    account_resolvers::{ScheduleLstFeeByMintFreeArgs, SetLstFeeByMintFreeArgs},
+    calc::{fee_account_pending_fees, LstFees, PendingFee, MIN_ACTIVATION_EPOCH_DELAY},
+    pda::FeeAccountFindPdaArgs,
+    program,
+    utils::try_fee_account,
+};
+use flat_fee_test_utils::{FlatFeePricingProgramTestBanksClient, MockFeeAccountArgs};
+use sanctum_solana_test_utils::{assert_custom_err, ExtendedBanksClient};
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+use crate::common::normal_program_test;
+
+const CURR_INPUT_FEE_BPS: i16 = 1;
+const CURR_OUTPUT_FEE_BPS: i16 = 2;
+const NEW_INPUT_FEE_BPS: i16 = 3;
+const NEW_OUTPUT_FEE_BPS: i16 = 4;
+
+fn program_test_with_lst(manager: &Keypair, lst_mint: Pubkey) -> ProgramTest {
+    normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: CURR_INPUT_FEE_BPS,
+            output_fee_bps: CURR_OUTPUT_FEE_BPS,
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
+            lst_mint,
+        }],
+    )
+}
+
+async fn schedule_lst_fee_ix_for(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    activation_epoch: u64,
+) -> Instruction {
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    schedule_lst_fee_ix(
+        ScheduleLstFeeByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: program::STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        ScheduleLstFeeIxArgs {
+            input_fee_bps: NEW_INPUT_FEE_BPS,
+            output_fee_bps: NEW_OUTPUT_FEE_BPS,
+            activation_epoch,
+        },
+    )
+    .unwrap()
+}
+
+async fn fee_account_data(banks_client: &mut BanksClient, lst_mint: Pubkey) -> Vec<u8> {
+    let (addr, _bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    banks_client.get_account_unwrapped(addr).await.data
+}
+
+#[tokio::test]
+async fn schedule_lst_fee_basic() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = program_test_with_lst(&manager, lst_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+    let activation_epoch = clock.epoch + MIN_ACTIVATION_EPOCH_DELAY;
+    let ix = schedule_lst_fee_ix_for(&mut banks_client, lst_mint, activation_epoch).await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let data = fee_account_data(&mut banks_client, lst_mint).await;
+    let fee_acc = try_fee_account(&data).unwrap();
+    // current fees untouched until activation
+    assert_eq!(fee_acc.input_fee_bps, CURR_INPUT_FEE_BPS);
+    assert_eq!(fee_acc.output_fee_bps, CURR_OUTPUT_FEE_BPS);
+    assert_eq!(
+        fee_account_pending_fees(fee_acc),
+        Some(PendingFee {
+            fee: LstFees {
+                input_fee_bps: NEW_INPUT_FEE_BPS,
+                output_fee_bps: NEW_OUTPUT_FEE_BPS,
+            },
+            activation_epoch,
+        })
+    );
+}
+
+#[tokio::test]
+async fn schedule_lst_fee_fail_activation_epoch_not_in_future() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = program_test_with_lst(&manager, lst_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+    let ix = schedule_lst_fee_ix_for(&mut banks_client, lst_mint, clock.epoch).await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::InvalidActivationEpoch);
+
+    let data = fee_account_data(&mut banks_client, lst_mint).await;
+    assert_eq!(
+        fee_account_pending_fees(try_fee_account(&data).unwrap()),
+        None
+    );
+}
+
+#[tokio::test]
+async fn schedule_lst_fee_fail_activation_epoch_next_epoch() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = program_test_with_lst(&manager, lst_mint);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+    let ix = schedule_lst_fee_ix_for(
+        &mut banks_client,
+        lst_mint,
+        clock.epoch + MIN_ACTIVATION_EPOCH_DELAY - 1,
+    )
+    .await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::InvalidActivationEpoch);
+}
+
+#[tokio::test]
+async fn schedule_lst_fee_fail_activation_change_too_large() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: -50,
+            max_lst_fee_bps: 50,
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: 10,
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: 1,
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: 10,
+            output_fee_bps: 20,
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let set_ix = set_lst_fee_ix(
+        SetLstFeeByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: program::STATE_ID,
+                account: state_acc.clone(),
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLstFeeIxArgs {
+            input_fee_bps: 10,
+            output_fee_bps: 10,
+        },
+    )
+    .unwrap();
+    let schedule_keys = ScheduleLstFeeByMintFreeArgs {
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: program::STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+    let activation_epoch = clock.epoch + MIN_ACTIVATION_EPOCH_DELAY;
+
+    // within the limit of the baseline output fee of 20,
+    // but 20 above the output fee of 10 in effect until activation
+    let schedule_ix = schedule_lst_fee_ix(
+        schedule_keys,
+        ScheduleLstFeeIxArgs {
+            input_fee_bps: 10,
+            output_fee_bps: 30,
+            activation_epoch,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[set_ix, schedule_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::FeeChangeTooLarge);
+
+    // without the earlier decrease, the step at activation is within the limit
+    let schedule_ix = schedule_lst_fee_ix(
+        schedule_keys,
+        ScheduleLstFeeIxArgs {
+            input_fee_bps: 10,
+            output_fee_bps: 30,
+            activation_epoch,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[schedule_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+}
@@ -0,0 +1,159 @@
+use flat_fee_interface::{set_admin_ix, FlatFeeError, ProgramState, SetAdminKeys};
+use flat_fee_lib::{
+    account_resolvers::SetAdminFreeArgs,
+    program::STATE_ID,
+    utils::{try_program_state, try_program_state_admin},
+};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::normal_program_test;
+
+fn program_state(manager: &Keypair, admin: Pubkey) -> ProgramState {
+    ProgramState {
+        manager: manager.pubkey(),
+        lp_withdrawal_fee_bps: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        pending_lp_withdrawal_fee_bps: Default::default(),
+        has_pending_lp_withdrawal_fee: Default::default(),
+        padding: Default::default(),
+        pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+        admin,
+        min_lst_fee_bps: Default::default(),
+        max_lst_fee_bps: Default::default(),
+        max_lp_withdrawal_fee_bps: Default::default(),
+        max_fee_change_bps: Default::default(),
+        max_lp_deposit_fee_bps: Default::default(),
+        max_surge_fee_bps: Default::default(),
+        has_fee_bounds: Default::default(),
+        fee_bounds_padding: Default::default(),
+        baseline_lp_withdrawal_fee_bps: Default::default(),
+        baseline_lp_deposit_fee_bps: Default::default(),
+        has_fee_baseline: Default::default(),
+        fee_baseline_padding: Default::default(),
+        fee_baseline_epoch: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn set_admin_basic() {
+    let new_admin = Keypair::new();
+
+    let manager = Keypair::new();
+    let admin = Keypair::new();
+
+    let program_test = normal_program_test(program_state(&manager, admin.pubkey()), &[]);
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = set_admin_ix(
+        SetAdminFreeArgs {
+            new_admin: new_admin.pubkey(),
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &admin], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(try_program_state_admin(state).unwrap(), new_admin.pubkey());
+    assert_eq!(state.manager, manager.pubkey());
+}
+
+#[tokio::test]
+async fn set_admin_fail_admin_not_set() {
+    let new_admin = Keypair::new();
+
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(program_state(&manager, Pubkey::default()), &[]);
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    assert_eq!(
+        SetAdminFreeArgs {
+            new_admin: new_admin.pubkey(),
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap_err(),
+        FlatFeeError::AdminNotSet
+    );
+
+    // the manager does not act as the admin if the admin is unset
+    let ix = set_admin_ix(SetAdminKeys {
+        current_admin: manager.pubkey(),
+        new_admin: new_admin.pubkey(),
+        state: STATE_ID,
+    })
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, FlatFeeError::AdminNotSet);
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.admin, Pubkey::default());
+}
+
+#[tokio::test]
+async fn set_admin_fail_manager_not_admin() {
+    let new_admin = Keypair::new();
+
+    let manager = Keypair::new();
+    let admin = Keypair::new();
+
+    let program_test = normal_program_test(program_state(&manager, admin.pubkey()), &[]);
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let mut keys = SetAdminFreeArgs {
+        new_admin: new_admin.pubkey(),
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+    keys.current_admin = manager.pubkey();
+
+    let ix = set_admin_ix(keys).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.admin, admin.pubkey());
+}
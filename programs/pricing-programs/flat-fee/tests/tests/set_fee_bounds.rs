@@ -0,0 +1,160 @@
+use flat_fee_interface::{
+    set_fee_bounds_ix, FlatFeeError, ProgramState, SetFeeBoundsIxArgs, SetFeeBoundsKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetFeeBoundsFreeArgs, program::STATE_ID, utils::try_program_state,
+};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error};
+use solana_program::program_error::ProgramError;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::normal_program_test;
+
+const FEE_BOUNDS_ARGS: SetFeeBoundsIxArgs = SetFeeBoundsIxArgs {
+    min_lst_fee_bps: -5,
+    max_lst_fee_bps: 50,
+    max_lp_withdrawal_fee_bps: 30,
+    max_fee_change_bps: 10,
+    max_lp_deposit_fee_bps: 20,
+    max_surge_fee_bps: 100,
+};
+
+fn program_state(manager: &Keypair, admin: &Keypair) -> ProgramState {
+    ProgramState {
+        manager: manager.pubkey(),
+        lp_withdrawal_fee_bps: Default::default(),
+        lp_deposit_fee_bps: Default::default(),
+        pending_lp_withdrawal_fee_bps: Default::default(),
+        has_pending_lp_withdrawal_fee: Default::default(),
+        padding: Default::default(),
+        pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+        admin: admin.pubkey(),
+        min_lst_fee_bps: Default::default(),
+        max_lst_fee_bps: Default::default(),
+        max_lp_withdrawal_fee_bps: Default::default(),
+        max_fee_change_bps: Default::default(),
+        max_lp_deposit_fee_bps: Default::default(),
+        max_surge_fee_bps: Default::default(),
+        has_fee_bounds: Default::default(),
+        fee_bounds_padding: Default::default(),
+        baseline_lp_withdrawal_fee_bps: Default::default(),
+        baseline_lp_deposit_fee_bps: Default::default(),
+        has_fee_baseline: Default::default(),
+        fee_baseline_padding: Default::default(),
+        fee_baseline_epoch: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn set_fee_bounds_basic() {
+    let manager = Keypair::new();
+    let admin = Keypair::new();
+
+    let program_test = normal_program_test(program_state(&manager, &admin), &[]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let ix = set_fee_bounds_ix(
+        SetFeeBoundsFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        FEE_BOUNDS_ARGS,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &admin], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.has_fee_bounds, 1);
+    assert_eq!(state.min_lst_fee_bps, FEE_BOUNDS_ARGS.min_lst_fee_bps);
+    assert_eq!(state.max_lst_fee_bps, FEE_BOUNDS_ARGS.max_lst_fee_bps);
+    assert_eq!(
+        state.max_lp_withdrawal_fee_bps,
+        FEE_BOUNDS_ARGS.max_lp_withdrawal_fee_bps
+    );
+    assert_eq!(state.max_fee_change_bps, FEE_BOUNDS_ARGS.max_fee_change_bps);
+    assert_eq!(
+        state.max_lp_deposit_fee_bps,
+        FEE_BOUNDS_ARGS.max_lp_deposit_fee_bps
+    );
+    assert_eq!(state.max_surge_fee_bps, FEE_BOUNDS_ARGS.max_surge_fee_bps);
+}
+
+#[tokio::test]
+async fn set_fee_bounds_fail_invalid_bounds() {
+    let manager = Keypair::new();
+    let admin = Keypair::new();
+
+    let program_test = normal_program_test(program_state(&manager, &admin), &[]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let ix = set_fee_bounds_ix(
+        SetFeeBoundsFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetFeeBoundsIxArgs {
+            min_lst_fee_bps: FEE_BOUNDS_ARGS.max_lst_fee_bps,
+            max_lst_fee_bps: FEE_BOUNDS_ARGS.min_lst_fee_bps,
+            ..FEE_BOUNDS_ARGS
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &admin], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::InvalidFeeBounds);
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.has_fee_bounds, 0);
+}
+
+#[tokio::test]
+async fn set_fee_bounds_fail_manager_not_admin() {
+    let manager = Keypair::new();
+    let admin = Keypair::new();
+
+    let program_test = normal_program_test(program_state(&manager, &admin), &[]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_fee_bounds_ix(
+        SetFeeBoundsKeys {
+            admin: manager.pubkey(),
+            state: STATE_ID,
+        },
+        FEE_BOUNDS_ARGS,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.has_fee_bounds, 0);
+}
@@ -0,0 +1,224 @@
+use flat_fee_interface::{
+    set_lp_deposit_fee_ix, FlatFeeError, ProgramState, SetLpDepositFeeIxArgs,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLpDepositFeeFreeArgs, fee_bound::MAX_LP_DEPOSIT_FEE_BPS,
+    program::STATE_ID, utils::try_program_state,
+};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::assert_custom_err;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::normal_program_test;
+
+#[tokio::test]
+async fn set_lp_deposit_fee_basic() {
+    const NEW_LP_DEPOSIT_FEE_BPS: u16 = 7;
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let ix = set_lp_deposit_fee_ix(
+        SetLpDepositFeeFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLpDepositFeeIxArgs {
+            lp_deposit_fee_bps: NEW_LP_DEPOSIT_FEE_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.lp_deposit_fee_bps, NEW_LP_DEPOSIT_FEE_BPS);
+}
+
+#[tokio::test]
+async fn set_lp_deposit_fee_fail_invalid_fee() {
+    const BAD_LP_DEPOSIT_FEE_BPS: u16 = MAX_LP_DEPOSIT_FEE_BPS + 1;
+
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let ix = set_lp_deposit_fee_ix(
+        SetLpDepositFeeFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLpDepositFeeIxArgs {
+            lp_deposit_fee_bps: BAD_LP_DEPOSIT_FEE_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::UnsignedFeeOutOfBound);
+}
+
+#[tokio::test]
+async fn set_lp_deposit_fee_fee_bounds() {
+    const NEW_LP_DEPOSIT_FEE_BPS: u16 = 10;
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: 10,
+            max_lp_deposit_fee_bps: 20,
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: 1,
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let keys = SetLpDepositFeeFreeArgs {
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+
+    for (bad_lp_deposit_fee_bps, expected_err) in [
+        (21, FlatFeeError::FeeOutOfConfiguredBound),
+        (11, FlatFeeError::FeeChangeTooLarge),
+    ] {
+        let ix = set_lp_deposit_fee_ix(
+            keys,
+            SetLpDepositFeeIxArgs {
+                lp_deposit_fee_bps: bad_lp_deposit_fee_bps,
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, expected_err);
+    }
+
+    // each step is within the limit of the previous value,
+    // but the total change within the epoch is not
+    let ixs = [5, 15].map(|lp_deposit_fee_bps| {
+        set_lp_deposit_fee_ix(keys, SetLpDepositFeeIxArgs { lp_deposit_fee_bps }).unwrap()
+    });
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::FeeChangeTooLarge);
+
+    let ix = set_lp_deposit_fee_ix(
+        keys,
+        SetLpDepositFeeIxArgs {
+            lp_deposit_fee_bps: NEW_LP_DEPOSIT_FEE_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.lp_deposit_fee_bps, NEW_LP_DEPOSIT_FEE_BPS);
+    assert_eq!(state.baseline_lp_deposit_fee_bps, 0);
+}
@@ -5,7 +5,7 @@ use flat_fee_interface::{
 use flat_fee_lib::{
     account_resolvers::SetLpWithdrawalFeeFreeArgs, program::STATE_ID, utils::try_program_state,
 };
-use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use flat_fee_test_utils::{FlatFeePricingProgramTestBanksClient, DEFAULT_PROGRAM_STATE};
 use sanctum_solana_test_utils::{assert_custom_err, assert_program_error};
 use solana_program::program_error::ProgramError;
 use solana_readonly_account::sdk::KeyedAccount;
@@ -21,7 +21,27 @@ async fn set_lp_withdrawal_fee_basic() {
     let program_test = normal_program_test(
         ProgramState {
             manager: manager.pubkey(),
-            lp_withdrawal_fee_bps: Default::default(),
+            // fees can only be decreased immediately
+            lp_withdrawal_fee_bps: 1_000,
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         &[],
     );
@@ -64,6 +84,25 @@ async fn set_lp_withdrawal_fee_fail_invalid_fee() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         &[],
     );
@@ -106,6 +145,25 @@ async fn set_lp_withdrawal_fee_fail_unauthorized() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         &[],
     );
@@ -134,3 +192,144 @@ async fn set_lp_withdrawal_fee_fail_unauthorized() {
 
     assert_ne!(state.lp_withdrawal_fee_bps, NEW_LP_WITHDRAWAL_FEE_BPS);
 }
+
+#[tokio::test]
+async fn set_lp_withdrawal_fee_fee_bounds() {
+    const NEW_LP_WITHDRAWAL_FEE_BPS: u16 = 30;
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: 40,
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: 30,
+            max_fee_change_bps: 10,
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: 1,
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let keys = SetLpWithdrawalFeeFreeArgs {
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+
+    for (bad_lp_withdrawal_fee_bps, expected_err) in [
+        (31, FlatFeeError::FeeOutOfConfiguredBound),
+        (29, FlatFeeError::FeeChangeTooLarge),
+    ] {
+        let ix = set_lp_withdrawal_fee_ix(
+            keys,
+            SetLpWithdrawalFeeIxArgs {
+                lp_withdrawal_fee_bps: bad_lp_withdrawal_fee_bps,
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, expected_err);
+    }
+
+    let ix = set_lp_withdrawal_fee_ix(
+        keys,
+        SetLpWithdrawalFeeIxArgs {
+            lp_withdrawal_fee_bps: NEW_LP_WITHDRAWAL_FEE_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.lp_withdrawal_fee_bps, NEW_LP_WITHDRAWAL_FEE_BPS);
+    assert_eq!(state.baseline_lp_withdrawal_fee_bps, 40);
+
+    // the change is measured against the fee at the start of the epoch,
+    // so a further decrease within the same epoch exceeds the limit
+    let ix = set_lp_withdrawal_fee_ix(
+        keys,
+        SetLpWithdrawalFeeIxArgs {
+            lp_withdrawal_fee_bps: NEW_LP_WITHDRAWAL_FEE_BPS - 5,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::FeeChangeTooLarge);
+}
+
+#[tokio::test]
+async fn set_lp_withdrawal_fee_fail_increase() {
+    const LP_WITHDRAWAL_FEE_BPS: u16 = 10;
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: LP_WITHDRAWAL_FEE_BPS,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let ix = set_lp_withdrawal_fee_ix(
+        SetLpWithdrawalFeeFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLpWithdrawalFeeIxArgs {
+            lp_withdrawal_fee_bps: LP_WITHDRAWAL_FEE_BPS + 1,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::FeeIncreaseNotScheduled);
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let state = try_program_state(&state_acc.data).unwrap();
+
+    assert_eq!(state.lp_withdrawal_fee_bps, LP_WITHDRAWAL_FEE_BPS);
+}
@@ -7,7 +7,7 @@ use flat_fee_lib::{
     program::{self, STATE_ID},
     utils::try_fee_account,
 };
-use flat_fee_test_utils::MockFeeAccountArgs;
+use flat_fee_test_utils::{MockFeeAccountArgs, DEFAULT_PROGRAM_STATE};
 use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
 use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 use solana_readonly_account::sdk::KeyedAccount;
@@ -29,10 +29,32 @@ async fn set_lst_fee_basic() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
+        // fees can only be decreased immediately
         &[MockFeeAccountArgs {
-            input_fee_bps: Default::default(),
-            output_fee_bps: Default::default(),
+            input_fee_bps: 10,
+            output_fee_bps: 10_000,
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
             lst_mint,
         }],
     );
@@ -82,10 +104,31 @@ async fn set_lst_fee_fail_invalid_fee() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         &[MockFeeAccountArgs {
             input_fee_bps: Default::default(),
             output_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
             lst_mint,
         }],
     );
@@ -155,10 +198,31 @@ async fn set_lst_fee_fail_unauthorized() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         &[MockFeeAccountArgs {
             input_fee_bps: Default::default(),
             output_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
             lst_mint,
         }],
     );
@@ -222,6 +286,25 @@ async fn set_lst_fee_fail_fee_account_not_exists() {
         ProgramState {
             manager: manager.pubkey(),
             lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         &[],
     );
@@ -252,3 +335,179 @@ async fn set_lst_fee_fail_fee_account_not_exists() {
 
     assert_custom_err(err, FlatFeeError::UnsupportedLstMint);
 }
+
+#[tokio::test]
+async fn set_lst_fee_fee_bounds() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: -5,
+            max_lst_fee_bps: 50,
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: 10,
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: 1,
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: 10,
+            output_fee_bps: 20,
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let keys = SetLstFeeByMintFreeArgs {
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+
+    for (bad_fee_args, expected_err) in [
+        (
+            AddLstIxArgs {
+                input_fee_bps: -6,
+                output_fee_bps: 20,
+            },
+            FlatFeeError::FeeOutOfConfiguredBound,
+        ),
+        (
+            AddLstIxArgs {
+                input_fee_bps: 10,
+                output_fee_bps: 9,
+            },
+            FlatFeeError::FeeChangeTooLarge,
+        ),
+    ] {
+        let ix = set_lst_fee_ix(
+            keys,
+            SetLstFeeIxArgs {
+                input_fee_bps: bad_fee_args.input_fee_bps,
+                output_fee_bps: bad_fee_args.output_fee_bps,
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, expected_err);
+    }
+
+    const FEE_ARGS: AddLstIxArgs = AddLstIxArgs {
+        input_fee_bps: 0,
+        output_fee_bps: 10,
+    };
+    let ix = set_lst_fee_ix(
+        keys,
+        SetLstFeeIxArgs {
+            input_fee_bps: FEE_ARGS.input_fee_bps,
+            output_fee_bps: FEE_ARGS.output_fee_bps,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_fee_account(&mut banks_client, lst_mint, FEE_ARGS).await;
+
+    // the change is measured against the fees at the start of the epoch,
+    // so a further decrease within the same epoch exceeds the limit
+    let ix = set_lst_fee_ix(
+        keys,
+        SetLstFeeIxArgs {
+            input_fee_bps: FEE_ARGS.input_fee_bps,
+            output_fee_bps: FEE_ARGS.output_fee_bps - 1,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::FeeChangeTooLarge);
+}
+
+#[tokio::test]
+async fn set_lst_fee_fail_increase() {
+    const INITIAL_FEE_ARGS: AddLstIxArgs = AddLstIxArgs {
+        input_fee_bps: 10,
+        output_fee_bps: 20,
+    };
+
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: INITIAL_FEE_ARGS.input_fee_bps,
+            output_fee_bps: INITIAL_FEE_ARGS.output_fee_bps,
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let keys = SetLstFeeByMintFreeArgs {
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+
+    for (input_fee_bps, output_fee_bps) in [(11, 20), (0, 21)] {
+        let ix = set_lst_fee_ix(
+            keys,
+            SetLstFeeIxArgs {
+                input_fee_bps,
+                output_fee_bps,
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, FlatFeeError::FeeIncreaseNotScheduled);
+    }
+
+    verify_fee_account(&mut banks_client, lst_mint, INITIAL_FEE_ARGS).await;
+}
@@ -0,0 +1,217 @@
+use flat_fee_interface::{
+    set_lst_lp_deposit_fee_ix, FlatFeeError, ProgramState, SetLstLpDepositFeeIxArgs,
+    SetLstLpDepositFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLstLpDepositFeeByMintFreeArgs, pda::FeeAccountFindPdaArgs, program,
+    utils::try_fee_account,
+};
+use flat_fee_test_utils::{
+    FlatFeePricingProgramTestBanksClient, MockFeeAccountArgs, DEFAULT_PROGRAM_STATE,
+};
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::BanksClient;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::normal_program_test;
+
+async fn lst_lp_deposit_fee_bps(banks_client: &mut BanksClient, lst_mint: Pubkey) -> u16 {
+    let (addr, _bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let acc = banks_client.get_account_unwrapped(addr).await;
+    try_fee_account(&acc.data).unwrap().lp_deposit_fee_bps
+}
+
+#[tokio::test]
+async fn set_lst_lp_deposit_fee_basic() {
+    const NEW_LP_DEPOSIT_FEE_BPS: u16 = 3;
+
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let ix = set_lst_lp_deposit_fee_ix(
+        SetLstLpDepositFeeByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: program::STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLstLpDepositFeeIxArgs {
+            lp_deposit_fee_bps: NEW_LP_DEPOSIT_FEE_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        lst_lp_deposit_fee_bps(&mut banks_client, lst_mint).await,
+        NEW_LP_DEPOSIT_FEE_BPS
+    );
+}
+
+#[tokio::test]
+async fn set_lst_lp_deposit_fee_fail_unauthorized() {
+    const NEW_LP_DEPOSIT_FEE_BPS: u16 = 3;
+
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let (fee_acc, _bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let ix = set_lst_lp_deposit_fee_ix(
+        SetLstLpDepositFeeKeys {
+            manager: payer.pubkey(),
+            fee_acc,
+            state: program::STATE_ID,
+        },
+        SetLstLpDepositFeeIxArgs {
+            lp_deposit_fee_bps: NEW_LP_DEPOSIT_FEE_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    assert_eq!(lst_lp_deposit_fee_bps(&mut banks_client, lst_mint).await, 0);
+}
+
+#[tokio::test]
+async fn set_lst_lp_deposit_fee_fail_fee_too_high() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    // set before LP deposit fees were capped
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_deposit_fee_bps: 9_000,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let keys = SetLstLpDepositFeeByMintFreeArgs {
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: program::STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+
+    // over the per-fee cap, and over 100% combined with the global LP deposit fee
+    for bad_lp_deposit_fee_bps in [5_001, 1_001] {
+        let ix = set_lst_lp_deposit_fee_ix(
+            keys,
+            SetLstLpDepositFeeIxArgs {
+                lp_deposit_fee_bps: bad_lp_deposit_fee_bps,
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, FlatFeeError::UnsignedFeeOutOfBound);
+    }
+
+    assert_eq!(lst_lp_deposit_fee_bps(&mut banks_client, lst_mint).await, 0);
+}
@@ -0,0 +1,164 @@
+use flat_fee_interface::{
+    set_lst_lp_withdrawal_fee_ix, FeeAccount, ProgramState, SetLstLpWithdrawalFeeIxArgs,
+};
+use flat_fee_lib::{
+    account_resolvers::SetLstLpWithdrawalFeeByMintFreeArgs, pda::FeeAccountFindPdaArgs, program,
+    utils::try_fee_account,
+};
+use flat_fee_test_utils::{FlatFeePricingProgramTestBanksClient, MockFeeAccountArgs};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClient;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    hash::Hash, instruction::Instruction, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::common::normal_program_test;
+
+async fn fee_account(banks_client: &mut BanksClient, lst_mint: Pubkey) -> FeeAccount {
+    let (addr, _bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let acc = banks_client.get_account_unwrapped(addr).await;
+    *try_fee_account(&acc.data).unwrap()
+}
+
+async fn set_lst_lp_withdrawal_fee_ix_for(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    lp_withdrawal_fee_bps: Option<u16>,
+) -> Instruction {
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    set_lst_lp_withdrawal_fee_ix(
+        SetLstLpWithdrawalFeeByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: program::STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLstLpWithdrawalFeeIxArgs {
+            lp_withdrawal_fee_bps,
+        },
+    )
+    .unwrap()
+}
+
+async fn exec_as_manager(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    manager: &Keypair,
+    last_blockhash: Hash,
+    ix: Instruction,
+) {
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, manager], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn set_lst_lp_withdrawal_fee_basic() {
+    const NEW_LP_WITHDRAWAL_FEE_BPS: u16 = 7;
+
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: None,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_lst_lp_withdrawal_fee_ix_for(
+        &mut banks_client,
+        lst_mint,
+        Some(NEW_LP_WITHDRAWAL_FEE_BPS),
+    )
+    .await;
+    exec_as_manager(&mut banks_client, &payer, &manager, last_blockhash, ix).await;
+
+    let fee_acc = fee_account(&mut banks_client, lst_mint).await;
+    assert_eq!(fee_acc.has_lp_withdrawal_fee, 1);
+    assert_eq!(fee_acc.lp_withdrawal_fee_bps, NEW_LP_WITHDRAWAL_FEE_BPS);
+}
+
+#[tokio::test]
+async fn set_lst_lp_withdrawal_fee_unset() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            lp_withdrawal_fee_bps: Some(7),
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_lst_lp_withdrawal_fee_ix_for(&mut banks_client, lst_mint, None).await;
+    exec_as_manager(&mut banks_client, &payer, &manager, last_blockhash, ix).await;
+
+    let fee_acc = fee_account(&mut banks_client, lst_mint).await;
+    assert_eq!(fee_acc.has_lp_withdrawal_fee, 0);
+    assert_eq!(fee_acc.lp_withdrawal_fee_bps, 0);
+}
@@ -0,0 +1,159 @@
+use flat_fee_interface::{set_lst_surcharge_ix, FlatFeeError, ProgramState, SetLstSurchargeIxArgs};
+use flat_fee_lib::{
+    account_resolvers::SetLstSurchargeFreeArgs,
+    pda::LstSurchargeFindPdaArgs,
+    program::{self, STATE_ID},
+    utils::try_lst_surcharge,
+};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::{
+    assert_custom_err,
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs},
+    ExtendedBanksClient,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::normal_program_test;
+
+const SPL_STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET: u16 = 274;
+
+fn set_lst_surcharge_program_test() -> (ProgramTest, Keypair, Pubkey) {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[],
+    )
+    .add_tokenkeg_mint_from_args(
+        lst_mint,
+        MockMintArgs {
+            mint_authority: None,
+            freeze_authority: None,
+            supply: 0,
+            decimals: 9,
+        },
+    );
+    (program_test, manager, lst_mint)
+}
+
+#[tokio::test]
+async fn set_lst_surcharge_basic() {
+    const SURCHARGE_BPS: [u16; 2] = [25, 0];
+
+    let (program_test, manager, lst_mint) = set_lst_surcharge_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let (addr, bump) = LstSurchargeFindPdaArgs {
+        program_id: program::ID,
+        lst_mint,
+    }
+    .get_lst_surcharge_address_and_bump_seed();
+    assert!(banks_client.get_account(addr).await.unwrap().is_none());
+
+    // first call creates the LstSurcharge account, second call updates it
+    for surcharge_bps in SURCHARGE_BPS {
+        let stake_pool = Pubkey::new_unique();
+        let state_acc = banks_client.get_flat_fee_program_state().await;
+        let (keys, _pda) = SetLstSurchargeFreeArgs {
+            payer: payer.pubkey(),
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+            lst_mint,
+            stake_pool,
+        }
+        .resolve()
+        .unwrap();
+        let ix = set_lst_surcharge_ix(
+            keys,
+            SetLstSurchargeIxArgs {
+                surcharge_bps,
+                last_update_epoch_offset: SPL_STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET,
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let surcharge_acc = banks_client.get_account_unwrapped(addr).await;
+        let surcharge = try_lst_surcharge(&surcharge_acc.data).unwrap();
+
+        assert_eq!(surcharge.bump, bump);
+        assert_eq!(surcharge.stake_pool, stake_pool);
+        assert_eq!(surcharge.surcharge_bps, surcharge_bps);
+        assert_eq!(
+            surcharge.last_update_epoch_offset,
+            SPL_STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET
+        );
+    }
+}
+
+#[tokio::test]
+async fn set_lst_surcharge_fail_invalid_fee() {
+    let (program_test, manager, lst_mint) = set_lst_surcharge_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let (keys, _pda) = SetLstSurchargeFreeArgs {
+        payer: payer.pubkey(),
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+        lst_mint,
+        stake_pool: Pubkey::new_unique(),
+    }
+    .resolve()
+    .unwrap();
+    let surcharge_acc = keys.surcharge_acc;
+    let ix = set_lst_surcharge_ix(
+        keys,
+        SetLstSurchargeIxArgs {
+            surcharge_bps: 10_001,
+            last_update_epoch_offset: SPL_STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::UnsignedFeeOutOfBound);
+
+    assert!(banks_client
+        .get_account(surcharge_acc)
+        .await
+        .unwrap()
+        .is_none());
+}
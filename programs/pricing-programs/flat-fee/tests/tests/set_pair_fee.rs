@@ -0,0 +1,190 @@
+use flat_fee_interface::{
+    set_pair_fee_ix, FlatFeeError, ProgramState, SetPairFeeIxArgs, SetPairFeeKeys,
+};
+use flat_fee_lib::{account_resolvers::SetPairFeeByMintsFreeArgs, program::STATE_ID};
+use flat_fee_test_utils::{
+    FlatFeePricingProgramTestBanksClient, MockPairFeeAccount, MockPairFeeAccountArgs,
+    DEFAULT_PROGRAM_STATE,
+};
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, IntoAccount};
+use solana_program::{program_error::ProgramError, pubkey, pubkey::Pubkey};
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use test_utils::jitosol;
+
+use crate::common::{normal_program_test, verify_pair_fee_account};
+
+const MSOL_MINT: Pubkey = pubkey!("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So");
+
+const MOCK_PAIR_FEE_ACCOUNT_ARGS: MockPairFeeAccountArgs = MockPairFeeAccountArgs {
+    fee_bps: 1,
+    input_lst_mint: jitosol::ID,
+    output_lst_mint: MSOL_MINT,
+};
+
+fn set_pair_fee_program_test(manager: &Keypair) -> ProgramTest {
+    let mut program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let (acc, addr) =
+        MOCK_PAIR_FEE_ACCOUNT_ARGS.to_pair_fee_account_and_addr(flat_fee_lib::program::ID);
+    program_test.add_account(addr, MockPairFeeAccount(acc).into_account());
+    program_test
+}
+
+#[tokio::test]
+async fn set_pair_fee_basic() {
+    const NEW_FEE_BPS: i16 = -2;
+    let manager = Keypair::new();
+
+    let program_test = set_pair_fee_program_test(&manager);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let ix = set_pair_fee_ix(
+        SetPairFeeByMintsFreeArgs {
+            input_lst_mint: jitosol::ID,
+            output_lst_mint: MSOL_MINT,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetPairFeeIxArgs {
+            fee_bps: NEW_FEE_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_pair_fee_account(&mut banks_client, jitosol::ID, MSOL_MINT, NEW_FEE_BPS).await;
+}
+
+#[tokio::test]
+async fn set_pair_fee_fail_unauthorized() {
+    let manager = Keypair::new();
+
+    let program_test = set_pair_fee_program_test(&manager);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let (_acc, pair_fee_acc) =
+        MOCK_PAIR_FEE_ACCOUNT_ARGS.to_pair_fee_account_and_addr(flat_fee_lib::program::ID);
+    let ix = set_pair_fee_ix(
+        SetPairFeeKeys {
+            manager: payer.pubkey(),
+            pair_fee_acc,
+            state: STATE_ID,
+        },
+        SetPairFeeIxArgs { fee_bps: 69 },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    verify_pair_fee_account(
+        &mut banks_client,
+        jitosol::ID,
+        MSOL_MINT,
+        MOCK_PAIR_FEE_ACCOUNT_ARGS.fee_bps,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn set_pair_fee_fee_bounds() {
+    const NEW_FEE_BPS: i16 = 11;
+    let manager = Keypair::new();
+
+    let mut program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            min_lst_fee_bps: -5,
+            max_lst_fee_bps: 50,
+            max_fee_change_bps: 10,
+            has_fee_bounds: 1,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let (acc, addr) =
+        MOCK_PAIR_FEE_ACCOUNT_ARGS.to_pair_fee_account_and_addr(flat_fee_lib::program::ID);
+    program_test.add_account(addr, MockPairFeeAccount(acc).into_account());
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let keys = SetPairFeeByMintsFreeArgs {
+        input_lst_mint: jitosol::ID,
+        output_lst_mint: MSOL_MINT,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+
+    for (bad_fee_bps, expected_err) in [
+        (-6, FlatFeeError::FeeOutOfConfiguredBound),
+        (12, FlatFeeError::FeeChangeTooLarge),
+    ] {
+        let ix = set_pair_fee_ix(
+            keys,
+            SetPairFeeIxArgs {
+                fee_bps: bad_fee_bps,
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, expected_err);
+    }
+
+    let ix = set_pair_fee_ix(
+        keys,
+        SetPairFeeIxArgs {
+            fee_bps: NEW_FEE_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_pair_fee_account(&mut banks_client, jitosol::ID, MSOL_MINT, NEW_FEE_BPS).await;
+
+    // the change is measured against the fee at the start of the epoch,
+    // so a further increase within the same epoch exceeds the limit
+    let ix = set_pair_fee_ix(
+        keys,
+        SetPairFeeIxArgs {
+            fee_bps: NEW_FEE_BPS + 1,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::FeeChangeTooLarge);
+}
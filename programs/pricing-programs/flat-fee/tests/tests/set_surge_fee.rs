@@ -0,0 +1,217 @@
+use flat_fee_interface::{
+    set_surge_fee_ix, FlatFeeError, ProgramState, SetSurgeFeeIxArgs, SetSurgeFeeKeys,
+};
+use flat_fee_lib::{
+    account_resolvers::SetSurgeFeeFreeArgs,
+    program::{STATE_ID, SURGE_CONFIG_ID},
+    utils::try_surge_config,
+};
+use flat_fee_test_utils::FlatFeePricingProgramTestBanksClient;
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
+use solana_program::{program_error::ProgramError, system_program};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::normal_program_test;
+
+#[tokio::test]
+async fn set_surge_fee_basic() {
+    const SURGE_FEE_ARGS: [SetSurgeFeeIxArgs; 2] = [
+        SetSurgeFeeIxArgs {
+            max_surge_fee_bps: 50,
+            decay_slots: 1_000,
+        },
+        SetSurgeFeeIxArgs {
+            max_surge_fee_bps: 10,
+            decay_slots: 0,
+        },
+    ];
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    assert!(banks_client
+        .get_account(SURGE_CONFIG_ID)
+        .await
+        .unwrap()
+        .is_none());
+
+    // first call creates the SurgeConfig account, second call updates it
+    for args in SURGE_FEE_ARGS {
+        let state_acc = banks_client.get_flat_fee_program_state().await;
+        let ix = set_surge_fee_ix(
+            SetSurgeFeeFreeArgs {
+                payer: payer.pubkey(),
+                state_acc: KeyedAccount {
+                    pubkey: STATE_ID,
+                    account: state_acc,
+                },
+            }
+            .resolve()
+            .unwrap(),
+            args.clone(),
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let surge_config_acc = banks_client.get_account_unwrapped(SURGE_CONFIG_ID).await;
+        let surge_config = try_surge_config(&surge_config_acc.data).unwrap();
+
+        assert_eq!(surge_config.max_surge_fee_bps, args.max_surge_fee_bps);
+        assert_eq!(surge_config.decay_slots, args.decay_slots);
+    }
+}
+
+#[tokio::test]
+async fn set_surge_fee_fail_invalid_fee() {
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_flat_fee_program_state().await;
+    let ix = set_surge_fee_ix(
+        SetSurgeFeeFreeArgs {
+            payer: payer.pubkey(),
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetSurgeFeeIxArgs {
+            max_surge_fee_bps: 10_001,
+            decay_slots: 1_000,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, FlatFeeError::UnsignedFeeOutOfBound);
+
+    assert!(banks_client
+        .get_account(SURGE_CONFIG_ID)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn set_surge_fee_fail_unauthorized() {
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_surge_fee_ix(
+        SetSurgeFeeKeys {
+            manager: payer.pubkey(),
+            payer: payer.pubkey(),
+            surge_config: SURGE_CONFIG_ID,
+            state: STATE_ID,
+            system_program: system_program::ID,
+        },
+        SetSurgeFeeIxArgs {
+            max_surge_fee_bps: 50,
+            decay_slots: 1_000,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    assert!(banks_client
+        .get_account(SURGE_CONFIG_ID)
+        .await
+        .unwrap()
+        .is_none());
+}
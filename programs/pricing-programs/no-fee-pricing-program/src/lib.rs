@@ -5,34 +5,52 @@ use pricing_programs_interface::{
     PriceLpTokensToRedeemIxArgs, PricingProgramsProgramIx,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data, pubkey::Pubkey,
+    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
+    program_error::ProgramError, pubkey::Pubkey,
 };
 
 #[cfg(not(feature = "no-entrypoint"))]
 solana_program::entrypoint!(process_instruction);
 
-sanctum_macros::declare_program_keys!("NoFEEPR1C1NGPRoGRAM111111111111111111111111", []);
+pub use no_fee_pricing_program_keys::no_fee_pricing_program::*;
+
+/// input_lst_mint, output_lst_mint
+const SWAP_LST_MINTS_LEN: usize = 2;
+
+/// lst_mint
+const LP_LST_MINTS_LEN: usize = 1;
 
 pub fn process_instruction(
     _program_id: &Pubkey,
-    _accounts: &[AccountInfo],
+    accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
     let ix = PricingProgramsProgramIx::deserialize(instruction_data)?;
     solana_program::msg!("{:?}", ix);
 
-    let sol_value = match ix {
-        PricingProgramsProgramIx::PriceExactIn(PriceExactInIxArgs { sol_value, .. }) => sol_value,
-        PricingProgramsProgramIx::PriceExactOut(PriceExactOutIxArgs { sol_value, .. }) => sol_value,
+    // pricing interface accounts are not used,
+    // but still require the LST mints to be passed in to conform to the interface.
+    // The pool context accounts that follow them are optional so that this program
+    // works whether or not the pool has opted in to them with SetPricingProgramV2
+    let (sol_value, interface_accounts_len) = match ix {
+        PricingProgramsProgramIx::PriceExactIn(PriceExactInIxArgs { sol_value, .. }) => {
+            (sol_value, SWAP_LST_MINTS_LEN)
+        }
+        PricingProgramsProgramIx::PriceExactOut(PriceExactOutIxArgs { sol_value, .. }) => {
+            (sol_value, SWAP_LST_MINTS_LEN)
+        }
         PricingProgramsProgramIx::PriceLpTokensToMint(PriceLpTokensToMintIxArgs {
             sol_value,
             ..
-        }) => sol_value,
+        }) => (sol_value, LP_LST_MINTS_LEN),
         PricingProgramsProgramIx::PriceLpTokensToRedeem(PriceLpTokensToRedeemIxArgs {
             sol_value,
             ..
-        }) => sol_value,
+        }) => (sol_value, LP_LST_MINTS_LEN),
     };
+    if accounts.len() < interface_accounts_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
     let sol_value_le = sol_value.to_le_bytes();
     set_return_data(&sol_value_le);
     Ok(())
@@ -0,0 +1,43 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use tiered_fee_interface::TieredFeeProgramIx;
+
+use crate::processor::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if *program_id != tiered_fee_lib::program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let ix = TieredFeeProgramIx::deserialize(instruction_data)?;
+    solana_program::msg!("{:?}", ix);
+
+    match ix {
+        TieredFeeProgramIx::PriceExactIn(args) => process_price_exact_in(accounts, args),
+        TieredFeeProgramIx::PriceExactOut(args) => process_price_exact_out(accounts, args),
+        TieredFeeProgramIx::PriceLpTokensToMint(args) => {
+            process_price_lp_tokens_to_mint(accounts, args)
+        }
+        TieredFeeProgramIx::PriceLpTokensToRedeem(args) => {
+            process_price_lp_tokens_to_redeem(accounts, args)
+        }
+        TieredFeeProgramIx::SetLstFeeTiers(args) => process_set_lst_fee_tiers(accounts, args),
+        TieredFeeProgramIx::SetLpWithdrawalFee(args) => {
+            process_set_lp_withdrawal_fee(accounts, args)
+        }
+        TieredFeeProgramIx::SetLstFee(args) => process_set_lst_fee(accounts, args),
+        TieredFeeProgramIx::RemoveLst => process_remove_lst(accounts),
+        TieredFeeProgramIx::AddLst(args) => process_add_lst(accounts, args),
+        TieredFeeProgramIx::SetManager => process_set_manager(accounts),
+        TieredFeeProgramIx::Initialize => process_initialize(accounts),
+    }
+}
@@ -0,0 +1,61 @@
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use tiered_fee_interface::{
+    set_lst_fee_tiers_verify_account_keys, set_lst_fee_tiers_verify_account_privileges, FeeTier,
+    SetLstFeeTiersAccounts, SetLstFeeTiersIxArgs, SetLstFeeTiersKeys,
+};
+use tiered_fee_lib::{
+    account_resolvers::SetLstFeeTiersFreeArgs, calc::TierBasis, fee_bound::verify_fee_tiers,
+    program::MAX_FEE_TIERS, utils::try_fee_account_mut,
+};
+
+pub fn process_set_lst_fee_tiers(
+    accounts: &[AccountInfo],
+    args: SetLstFeeTiersIxArgs,
+) -> ProgramResult {
+    let (SetLstFeeTiersAccounts { fee_acc, .. }, tier_basis) =
+        verify_set_lst_fee_tiers(accounts, &args)?;
+
+    let mut bytes = fee_acc.try_borrow_mut_data()?;
+    let fee_acc = try_fee_account_mut(&mut bytes)?;
+
+    let mut tiers = [FeeTier {
+        threshold_bps: 0,
+        fee_bps: 0,
+    }; MAX_FEE_TIERS];
+    tiers[..args.tiers.len()].copy_from_slice(&args.tiers);
+
+    fee_acc.tier_basis = tier_basis.into();
+    // verify_fee_tiers() checks tiers.len() <= MAX_FEE_TIERS
+    fee_acc.tier_count = args.tiers.len() as u8;
+    fee_acc.tiers = tiers;
+
+    Ok(())
+}
+
+fn verify_set_lst_fee_tiers<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetLstFeeTiersIxArgs { tier_basis, tiers }: &SetLstFeeTiersIxArgs,
+) -> Result<(SetLstFeeTiersAccounts<'me, 'info>, TierBasis), ProgramError> {
+    let actual: SetLstFeeTiersAccounts = load_accounts(accounts)?;
+
+    let free_args = SetLstFeeTiersFreeArgs {
+        state_acc: actual.state,
+        fee_acc: *actual.fee_acc.key,
+    };
+    let expected: SetLstFeeTiersKeys = free_args.resolve()?;
+
+    set_lst_fee_tiers_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_lst_fee_tiers_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let tier_basis = TierBasis::try_from(*tier_basis)?;
+    verify_fee_tiers(tiers)?;
+
+    Ok((actual, tier_basis))
+}
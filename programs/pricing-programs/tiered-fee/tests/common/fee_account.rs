@@ -0,0 +1,56 @@
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClient;
+use tiered_fee_interface::{AddLstIxArgs, FeeTier};
+use tiered_fee_lib::{
+    calc::TierBasis,
+    pda::FeeAccountFindPdaArgs,
+    program,
+    utils::{fee_account_tiers, try_fee_account},
+};
+
+pub async fn verify_fee_account(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    expected: AddLstIxArgs,
+) {
+    let find_pda_args = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    };
+    let (addr, bump) = find_pda_args.get_fee_account_address_and_bump_seed();
+    let actual_acc = banks_client.get_account_unwrapped(addr).await;
+    let actual = try_fee_account(&actual_acc.data).unwrap();
+    assert_eq!(actual.bump, bump);
+    assert_eq!(actual.input_fee_bps, expected.input_fee_bps);
+    assert_eq!(actual.output_fee_bps, expected.output_fee_bps);
+}
+
+pub async fn verify_fee_account_tiers(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    expected_tier_basis: TierBasis,
+    expected_tiers: &[FeeTier],
+) {
+    let (addr, _bump) = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_fee_account_address_and_bump_seed();
+    let actual_acc = banks_client.get_account_unwrapped(addr).await;
+    let actual = try_fee_account(&actual_acc.data).unwrap();
+    assert_eq!(
+        TierBasis::try_from(actual.tier_basis).unwrap(),
+        expected_tier_basis
+    );
+    assert_eq!(fee_account_tiers(actual).unwrap(), expected_tiers);
+}
+
+pub async fn verify_fee_account_does_not_exist(banks_client: &mut BanksClient, lst_mint: Pubkey) {
+    let find_pda_args = FeeAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    };
+    let (addr, _bump) = find_pda_args.get_fee_account_address_and_bump_seed();
+    assert!(banks_client.get_account(addr).await.unwrap().is_none());
+}
@@ -0,0 +1,7 @@
+mod fee_account;
+mod pool;
+mod program_test;
+
+pub use fee_account::*;
+pub use pool::*;
+pub use program_test::*;
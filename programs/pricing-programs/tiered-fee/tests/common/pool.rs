@@ -0,0 +1,81 @@
+use s_controller_interface::{LstState, PoolState};
+use s_controller_test_utils::{LstStateListProgramTest, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use tiered_fee_interface::{FeeTier, ProgramState};
+use tiered_fee_lib::calc::TierBasis;
+use tiered_fee_test_utils::MockFeeAccountArgs;
+
+use super::normal_program_test;
+
+pub const SWAP_BASE_FEE_BPS: i16 = 1;
+
+/// - swaps of at least 10% of the basis pay 10 bps
+/// - swaps of at least 50% of the basis pay 50 bps
+pub const SWAP_FEE_TIERS: [FeeTier; 2] = [
+    FeeTier {
+        threshold_bps: 1_000,
+        fee_bps: 10,
+    },
+    FeeTier {
+        threshold_bps: 5_000,
+        fee_bps: 50,
+    },
+];
+
+/// 60% of the pool
+pub const LARGE_LST_SOL_VALUE: u64 = 600_000_000_000;
+
+/// 40% of the pool
+pub const SMALL_LST_SOL_VALUE: u64 = 400_000_000_000;
+
+pub const POOL_TOTAL_SOL_VALUE: u64 = LARGE_LST_SOL_VALUE + SMALL_LST_SOL_VALUE;
+
+pub fn swap_fee_account_args(lst_mint: Pubkey, tier_basis: TierBasis) -> MockFeeAccountArgs {
+    MockFeeAccountArgs {
+        input_fee_bps: SWAP_BASE_FEE_BPS,
+        output_fee_bps: SWAP_BASE_FEE_BPS,
+        tier_basis,
+        tiers: &SWAP_FEE_TIERS,
+        lst_mint,
+    }
+}
+
+/// Program test with the S controller's pool state and LST state list
+/// containing 2 LSTs with the same fees and fee tiers relative to `tier_basis`:
+/// - (large_mint, LARGE_LST_SOL_VALUE)
+/// - (small_mint, SMALL_LST_SOL_VALUE)
+pub fn two_lst_pool_program_test(
+    large_mint: Pubkey,
+    small_mint: Pubkey,
+    tier_basis: TierBasis,
+) -> ProgramTest {
+    let lst_state = |mint, sol_value| LstState {
+        is_input_disabled: 0,
+        pool_reserves_bump: 0,
+        protocol_fee_accumulator_bump: 0,
+        is_batch_calc_enabled: 0,
+        padding: Default::default(),
+        sol_value,
+        mint,
+        sol_value_calculator: Pubkey::default(),
+    };
+    normal_program_test(
+        ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 0,
+        },
+        &[
+            swap_fee_account_args(large_mint, tier_basis),
+            swap_fee_account_args(small_mint, tier_basis),
+        ],
+    )
+    .add_pool_state(PoolState {
+        total_sol_value: POOL_TOTAL_SOL_VALUE,
+        ..DEFAULT_POOL_STATE
+    })
+    .add_lst_state_list(&[
+        lst_state(large_mint, LARGE_LST_SOL_VALUE),
+        lst_state(small_mint, SMALL_LST_SOL_VALUE),
+    ])
+}
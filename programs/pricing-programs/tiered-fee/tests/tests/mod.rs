@@ -0,0 +1,6 @@
+mod add_lst;
+mod initialize;
+mod price_exact_in;
+mod price_exact_out;
+mod set_lst_fee;
+mod set_lst_fee_tiers;
@@ -0,0 +1,151 @@
+use sanctum_solana_test_utils::{assert_custom_err, IntoAccount};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClient;
+use solana_sdk::{hash::Hash, signature::Keypair, signer::Signer, transaction::Transaction};
+use test_utils::BorshReturnDataBanksClient;
+use tiered_fee_interface::{price_exact_in_ix, PriceExactInIxArgs, TieredFeeError};
+use tiered_fee_lib::{account_resolvers::PriceExactInFreeArgs, calc::TierBasis};
+use tiered_fee_test_utils::MockFeeAccount;
+
+use crate::common::*;
+
+async fn exec_verify_price_exact_in(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    last_blockhash: Hash,
+    (input_lst_mint, output_lst_mint): (Pubkey, Pubkey),
+    in_sol_value: u64,
+    expected_out_sol_value: u64,
+) {
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+            input_pool_reserves: Pubkey::new_unique(),
+            output_pool_reserves: Pubkey::new_unique(),
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: in_sol_value,
+            sol_value: in_sol_value,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(payer, last_blockhash, ix, expected_out_sol_value)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_output_reserves_tiers() {
+    // (in_sol_value, expected_out_sol_value)
+    // output LST reserves of 400 SOL: 10% tier from 40 SOL, 50% tier from 200 SOL
+    const CASES: [(u64, u64); 4] = [
+        // below all tiers, 1 + 1 bps
+        (10_000_000_000, 9_998_000_000),
+        // 1 + 1 + 10 bps
+        (40_000_000_000, 39_952_000_000),
+        // 1 + 1 + 10 bps
+        (100_000_000_000, 99_880_000_000),
+        // 1 + 1 + 50 bps
+        (200_000_000_000, 198_960_000_000),
+    ];
+
+    let large_mint = Pubkey::new_unique();
+    let small_mint = Pubkey::new_unique();
+    let program_test = two_lst_pool_program_test(large_mint, small_mint, TierBasis::OutputReserves);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    for (in_sol_value, expected_out_sol_value) in CASES {
+        exec_verify_price_exact_in(
+            &mut banks_client,
+            &payer,
+            last_blockhash,
+            (large_mint, small_mint),
+            in_sol_value,
+            expected_out_sol_value,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn price_exact_in_tiers_follow_output_lst() {
+    // same swap size, the larger output LST's reserves of 600 SOL
+    // puts it in the 10% tier instead of the 50% tier: 1 + 1 + 10 bps
+    const IN_SOL_VALUE: u64 = 200_000_000_000;
+    const EXPECTED_OUT_SOL_VALUE: u64 = 199_760_000_000;
+
+    let large_mint = Pubkey::new_unique();
+    let small_mint = Pubkey::new_unique();
+    let program_test = two_lst_pool_program_test(large_mint, small_mint, TierBasis::OutputReserves);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    exec_verify_price_exact_in(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        (small_mint, large_mint),
+        IN_SOL_VALUE,
+        EXPECTED_OUT_SOL_VALUE,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_pool_total_tiers() {
+    // pool total of 1000 SOL: 10% tier from 100 SOL, 50% tier from 500 SOL
+    // 1 + 1 + 10 bps
+    const IN_SOL_VALUE: u64 = 200_000_000_000;
+    const EXPECTED_OUT_SOL_VALUE: u64 = 199_760_000_000;
+
+    let large_mint = Pubkey::new_unique();
+    let small_mint = Pubkey::new_unique();
+    let program_test = two_lst_pool_program_test(large_mint, small_mint, TierBasis::PoolTotal);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    exec_verify_price_exact_in(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        (large_mint, small_mint),
+        IN_SOL_VALUE,
+        EXPECTED_OUT_SOL_VALUE,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_fail_lst_not_in_pool() {
+    const IN_SOL_VALUE: u64 = 200_000_000_000;
+
+    let large_mint = Pubkey::new_unique();
+    let small_mint = Pubkey::new_unique();
+    let mut program_test =
+        two_lst_pool_program_test(large_mint, Pubkey::new_unique(), TierBasis::OutputReserves);
+    let (fee_acc, fee_acc_addr) = swap_fee_account_args(small_mint, TierBasis::OutputReserves)
+        .to_fee_account_and_addr(tiered_fee_lib::program::ID);
+    program_test.add_account(fee_acc_addr, MockFeeAccount(fee_acc).into_account());
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        PriceExactInFreeArgs {
+            input_lst_mint: large_mint,
+            output_lst_mint: small_mint,
+            input_pool_reserves: Pubkey::new_unique(),
+            output_pool_reserves: Pubkey::new_unique(),
+        }
+        .resolve(),
+        PriceExactInIxArgs {
+            amount: IN_SOL_VALUE,
+            sol_value: IN_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, TieredFeeError::LstNotInPool);
+}
@@ -0,0 +1,108 @@
+use solana_program::pubkey::Pubkey;
+use test_utils::BorshReturnDataBanksClient;
+use tiered_fee_interface::{price_exact_out_ix, PriceExactOutIxArgs};
+use tiered_fee_lib::{
+    account_resolvers::PriceExactOutFreeArgs,
+    calc::{
+        calculate_price_exact_in, calculate_price_exact_out, CalculatePriceExactInArgs,
+        CalculatePriceExactOutArgs, SwapFeeArgs, TierBasis,
+    },
+};
+
+use crate::common::*;
+
+const SMALL_OUTPUT_FEES: SwapFeeArgs = SwapFeeArgs {
+    input_fee_bps: SWAP_BASE_FEE_BPS,
+    output_fee_bps: SWAP_BASE_FEE_BPS,
+    tiers: &SWAP_FEE_TIERS,
+    tier_basis_sol_value: SMALL_LST_SOL_VALUE,
+};
+
+#[tokio::test]
+async fn price_exact_out_output_reserves_tiers() {
+    // (out_sol_value, expected_in_sol_value)
+    // output LST reserves of 400 SOL: 10% tier from 40 SOL, 50% tier from 200 SOL
+    const CASES: [(u64, u64); 2] = [
+        // below all tiers, 1 + 1 bps
+        (9_998_000_000, 10_000_000_000),
+        // 1 + 1 + 10 bps
+        (99_880_000_000, 100_000_000_000),
+    ];
+
+    let large_mint = Pubkey::new_unique();
+    let small_mint = Pubkey::new_unique();
+    let program_test = two_lst_pool_program_test(large_mint, small_mint, TierBasis::OutputReserves);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    for (out_sol_value, expected_in_sol_value) in CASES {
+        let ix = price_exact_out_ix(
+            PriceExactOutFreeArgs {
+                input_lst_mint: large_mint,
+                output_lst_mint: small_mint,
+                input_pool_reserves: Pubkey::new_unique(),
+                output_pool_reserves: Pubkey::new_unique(),
+            }
+            .resolve(),
+            PriceExactOutIxArgs {
+                amount: out_sol_value,
+                sol_value: out_sol_value,
+            },
+        )
+        .unwrap();
+
+        banks_client
+            .exec_verify_borsh_return_data::<u64, 8>(
+                &payer,
+                last_blockhash,
+                ix,
+                expected_in_sol_value,
+            )
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn price_exact_out_largest_tier() {
+    // can only be reached by swapping at least 200 SOL in: 1 + 1 + 50 bps
+    const OUT_SOL_VALUE: u64 = 250_000_000_000;
+
+    let large_mint = Pubkey::new_unique();
+    let small_mint = Pubkey::new_unique();
+    let program_test = two_lst_pool_program_test(large_mint, small_mint, TierBasis::OutputReserves);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let expected_in_sol_value = calculate_price_exact_out(CalculatePriceExactOutArgs {
+        fees: SMALL_OUTPUT_FEES,
+        out_sol_value: OUT_SOL_VALUE,
+    })
+    .unwrap();
+    // ceil(250_000_000_000 * 10_000 / 9_948)
+    assert_eq!(expected_in_sol_value, 251_306_795_336);
+    assert_eq!(
+        calculate_price_exact_in(CalculatePriceExactInArgs {
+            fees: SMALL_OUTPUT_FEES,
+            in_sol_value: expected_in_sol_value,
+        })
+        .unwrap(),
+        OUT_SOL_VALUE
+    );
+
+    let ix = price_exact_out_ix(
+        PriceExactOutFreeArgs {
+            input_lst_mint: large_mint,
+            output_lst_mint: small_mint,
+            input_pool_reserves: Pubkey::new_unique(),
+            output_pool_reserves: Pubkey::new_unique(),
+        }
+        .resolve(),
+        PriceExactOutIxArgs {
+            amount: OUT_SOL_VALUE,
+            sol_value: OUT_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, expected_in_sol_value)
+        .await;
+}
@@ -0,0 +1,127 @@
+use sanctum_solana_test_utils::{assert_custom_err, ExtendedBanksClient};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use tiered_fee_interface::{
+    set_lst_fee_ix, AddLstIxArgs, ProgramState, SetLstFeeIxArgs, TieredFeeError,
+};
+use tiered_fee_lib::{
+    account_resolvers::SetLstFeeByMintFreeArgs, calc::TierBasis, program::STATE_ID,
+};
+use tiered_fee_test_utils::MockFeeAccountArgs;
+
+use crate::common::*;
+
+#[tokio::test]
+async fn set_lst_fee_basic() {
+    const FEE_ARGS: AddLstIxArgs = AddLstIxArgs {
+        input_fee_bps: -2,
+        output_fee_bps: 10_000,
+    };
+
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            tier_basis: TierBasis::PoolTotal,
+            tiers: &SWAP_FEE_TIERS,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = set_lst_fee_ix(
+        SetLstFeeByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLstFeeIxArgs {
+            input_fee_bps: FEE_ARGS.input_fee_bps,
+            output_fee_bps: FEE_ARGS.output_fee_bps,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_fee_account(&mut banks_client, lst_mint, FEE_ARGS).await;
+    // fee tiers are left untouched
+    verify_fee_account_tiers(
+        &mut banks_client,
+        lst_mint,
+        TierBasis::PoolTotal,
+        &SWAP_FEE_TIERS,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn set_lst_fee_fail_invalid_fee() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: 1,
+            output_fee_bps: 2,
+            tier_basis: TierBasis::OutputReserves,
+            tiers: &[],
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let ix = set_lst_fee_ix(
+        SetLstFeeByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLstFeeIxArgs {
+            input_fee_bps: 0,
+            output_fee_bps: -10_001,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, TieredFeeError::SignedFeeOutOfBound);
+
+    verify_fee_account(
+        &mut banks_client,
+        lst_mint,
+        AddLstIxArgs {
+            input_fee_bps: 1,
+            output_fee_bps: 2,
+        },
+    )
+    .await;
+}
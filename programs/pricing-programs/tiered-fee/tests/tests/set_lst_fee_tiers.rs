@@ -0,0 +1,178 @@
+use sanctum_solana_test_utils::{assert_custom_err, ExtendedBanksClient};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use tiered_fee_interface::{
+    set_lst_fee_tiers_ix, FeeTier, ProgramState, SetLstFeeTiersIxArgs, TieredFeeError,
+};
+use tiered_fee_lib::{
+    account_resolvers::SetLstFeeTiersByMintFreeArgs, calc::TierBasis, program::STATE_ID,
+};
+use tiered_fee_test_utils::MockFeeAccountArgs;
+
+use crate::common::*;
+
+fn set_lst_fee_tiers_program_test() -> (ProgramTest, Keypair, Pubkey) {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: Default::default(),
+        },
+        &[MockFeeAccountArgs {
+            input_fee_bps: 1,
+            output_fee_bps: 2,
+            tier_basis: TierBasis::OutputReserves,
+            tiers: &SWAP_FEE_TIERS,
+            lst_mint,
+        }],
+    );
+    (program_test, manager, lst_mint)
+}
+
+#[tokio::test]
+async fn set_lst_fee_tiers_basic() {
+    const TIERS: [FeeTier; 3] = [
+        FeeTier {
+            threshold_bps: 100,
+            fee_bps: 5,
+        },
+        FeeTier {
+            threshold_bps: 500,
+            fee_bps: 20,
+        },
+        FeeTier {
+            threshold_bps: 2_000,
+            fee_bps: 100,
+        },
+    ];
+
+    let (program_test, manager, lst_mint) = set_lst_fee_tiers_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    for (tier_basis, tiers) in [
+        (TierBasis::PoolTotal, TIERS.as_slice()),
+        // clearing all tiers
+        (TierBasis::OutputReserves, [].as_slice()),
+    ] {
+        let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+        let ix = set_lst_fee_tiers_ix(
+            SetLstFeeTiersByMintFreeArgs {
+                lst_mint,
+                state_acc: KeyedAccount {
+                    pubkey: STATE_ID,
+                    account: state_acc,
+                },
+            }
+            .resolve()
+            .unwrap(),
+            SetLstFeeTiersIxArgs {
+                tier_basis: tier_basis.into(),
+                tiers: tiers.to_vec(),
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        banks_client.process_transaction(tx).await.unwrap();
+
+        verify_fee_account_tiers(&mut banks_client, lst_mint, tier_basis, tiers).await;
+    }
+}
+
+#[tokio::test]
+async fn set_lst_fee_tiers_fail_invalid() {
+    const VALID_TIER: FeeTier = FeeTier {
+        threshold_bps: 1_000,
+        fee_bps: 10,
+    };
+    let bad_args = [
+        (
+            SetLstFeeTiersIxArgs {
+                tier_basis: 2,
+                tiers: vec![VALID_TIER],
+            },
+            TieredFeeError::InvalidTierBasis,
+        ),
+        (
+            SetLstFeeTiersIxArgs {
+                tier_basis: TierBasis::OutputReserves.into(),
+                tiers: vec![VALID_TIER, VALID_TIER],
+            },
+            TieredFeeError::InvalidFeeTiers,
+        ),
+        (
+            SetLstFeeTiersIxArgs {
+                tier_basis: TierBasis::OutputReserves.into(),
+                tiers: vec![FeeTier {
+                    threshold_bps: 10_001,
+                    ..VALID_TIER
+                }],
+            },
+            TieredFeeError::InvalidFeeTiers,
+        ),
+        (
+            SetLstFeeTiersIxArgs {
+                tier_basis: TierBasis::OutputReserves.into(),
+                tiers: (1..=5)
+                    .map(|i| FeeTier {
+                        threshold_bps: i * 1_000,
+                        ..VALID_TIER
+                    })
+                    .collect(),
+            },
+            TieredFeeError::InvalidFeeTiers,
+        ),
+        (
+            SetLstFeeTiersIxArgs {
+                tier_basis: TierBasis::OutputReserves.into(),
+                tiers: vec![FeeTier {
+                    fee_bps: 10_001,
+                    ..VALID_TIER
+                }],
+            },
+            TieredFeeError::UnsignedFeeOutOfBound,
+        ),
+    ];
+
+    let (program_test, manager, lst_mint) = set_lst_fee_tiers_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let keyed_state_acc = KeyedAccount {
+        pubkey: STATE_ID,
+        account: state_acc,
+    };
+
+    for (args, expected_err) in bad_args {
+        let ix = set_lst_fee_tiers_ix(
+            SetLstFeeTiersByMintFreeArgs {
+                lst_mint,
+                state_acc: &keyed_state_acc,
+            }
+            .resolve()
+            .unwrap(),
+            args,
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, expected_err);
+
+        verify_fee_account_tiers(
+            &mut banks_client,
+            lst_mint,
+            TierBasis::OutputReserves,
+            &SWAP_FEE_TIERS,
+        )
+        .await;
+    }
+}
@@ -1,5 +1,6 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts, SyncSolValueAccounts,
+    AddLiquidityAccounts, DonateAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
+    SyncSolValueAccounts,
 };
 use solana_program::account_info::AccountInfo;
 
@@ -31,6 +32,12 @@ impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for AddLiquidityAccounts<'me,
     }
 }
 
+impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for DonateAccounts<'me, 'info> {
+    fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_mint
+    }
+}
+
 impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for RemoveLiquidityAccounts<'me, 'info> {
     fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
         self.lst_mint
@@ -1,5 +1,5 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
+    AddLiquidityAccounts, DonateAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
     SetSolValueCalculatorAccounts, StartRebalanceAccounts, SwapExactInAccounts,
     SwapExactOutAccounts, SyncSolValueAccounts,
 };
@@ -43,6 +43,12 @@ impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for AddLiquidityAccounts
     }
 }
 
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for DonateAccounts<'me, 'info> {
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
 impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for RemoveLiquidityAccounts<'me, 'info> {
     fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
         self.lst_state_list
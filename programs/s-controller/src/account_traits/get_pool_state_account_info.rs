@@ -1,5 +1,5 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
+    AddLiquidityAccounts, DonateAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
     SetSolValueCalculatorAccounts, StartRebalanceAccounts, SwapExactInAccounts,
     SwapExactOutAccounts, SyncSolValueAccounts,
 };
@@ -43,6 +43,12 @@ impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for AddLiquidityAccounts<'m
     }
 }
 
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for DonateAccounts<'me, 'info> {
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
 impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for RemoveLiquidityAccounts<'me, 'info> {
     fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
         self.pool_state
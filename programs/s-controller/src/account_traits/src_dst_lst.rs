@@ -61,9 +61,22 @@ impl<'me, 'info> GetSrcDstLstMintAccountInfo<'me, 'info> for SwapExactOutAccount
     }
 }
 
+#[derive(Clone, Copy, Debug)]
+pub struct SrcDstLstPoolReservesAccountInfos<'me, 'info> {
+    pub src_pool_reserves: &'me AccountInfo<'info>,
+    pub dst_pool_reserves: &'me AccountInfo<'info>,
+}
+
 pub trait GetSrcDstLstPoolReservesAccountInfo<'me, 'info> {
     fn get_src_lst_pool_reserves(&self) -> &'me AccountInfo<'info>;
     fn get_dst_lst_pool_reserves(&self) -> &'me AccountInfo<'info>;
+
+    fn get_src_dst_lst_pool_reserves(&self) -> SrcDstLstPoolReservesAccountInfos<'me, 'info> {
+        SrcDstLstPoolReservesAccountInfos {
+            src_pool_reserves: self.get_src_lst_pool_reserves(),
+            dst_pool_reserves: self.get_dst_lst_pool_reserves(),
+        }
+    }
 }
 
 /// For use with GetPoolReservesAccountInfo
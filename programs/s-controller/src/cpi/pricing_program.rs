@@ -4,7 +4,7 @@ use pricing_programs_interface::{
     PriceLpTokensToRedeemIxData,
 };
 use s_controller_interface::SControllerError;
-use s_controller_lib::try_pool_state;
+use s_controller_lib::{try_pool_state, U8Bool};
 use sanctum_misc_utils::{get_borsh_return_data, ToAccountMeta};
 use solana_program::{
     account_info::AccountInfo,
@@ -13,7 +13,7 @@ use solana_program::{
     program_error::ProgramError,
 };
 
-use crate::account_traits::SrcDstLstMintAccountInfos;
+use crate::account_traits::{SrcDstLstMintAccountInfos, SrcDstLstPoolReservesAccountInfos};
 
 #[derive(Clone, Copy, Debug)]
 pub struct PricingProgramIxArgs {
@@ -32,37 +32,53 @@ pub struct PricingProgramPriceLpCpi<'me, 'info> {
     /// The mint of the LST that the pricing program is being called for
     pub lst_mint: &'me AccountInfo<'info>,
 
+    pub pool_state: &'me AccountInfo<'info>,
+
+    pub lst_state_list: &'me AccountInfo<'info>,
+
+    /// The pool reserves of `lst_mint`
+    pub pool_reserves: &'me AccountInfo<'info>,
+
+    /// Whether to prepend `pool_state`, `lst_state_list` and `pool_reserves`
+    /// to the CPI accounts, see `PoolState::is_pricing_pool_context_enabled`
+    pub is_pool_context_enabled: bool,
+
     /// Remaining accounts required by the pricing program
     pub remaining_accounts: &'me [AccountInfo<'info>],
 }
 
 impl<'me, 'info> PricingProgramPriceLpCpi<'me, 'info> {
     /// Args:
-    /// - `ix_accounts`: the calling instruction's accounts, excluding accounts_suffix_slice.
-    ///     Should be a `*Accounts` struct generated by solores
+    /// - `lst_mint`, `pool_state`, `lst_state_list`, `pool_reserves`: the calling instruction's
+    ///     verified accounts that make up the common interface accounts prefix
     /// - `accounts_suffix_slice`: subslice of instruction accounts where first account is the pricing program
-    ///     and remaining slice is remaining_accounts (excludes `lst_mint`)
-    pub fn from_lst_mint_and_account_suffix_slice(
+    ///     and remaining slice is remaining_accounts (excludes the common interface accounts prefix)
+    pub fn from_interface_accounts_and_account_suffix_slice(
         lst_mint: &'me AccountInfo<'info>,
+        pool_state: &'me AccountInfo<'info>,
+        lst_state_list: &'me AccountInfo<'info>,
+        pool_reserves: &'me AccountInfo<'info>,
         accounts_suffix_slice: &'me [AccountInfo<'info>],
     ) -> Result<Self, ProgramError> {
         let program = accounts_suffix_slice
             .first()
             .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let is_pool_context_enabled = is_pool_context_enabled(pool_state)?;
         Ok(Self {
             program,
             lst_mint,
+            pool_state,
+            lst_state_list,
+            pool_reserves,
+            is_pool_context_enabled,
             remaining_accounts: accounts_suffix_slice
                 .get(1..)
                 .ok_or(ProgramError::NotEnoughAccountKeys)?,
         })
     }
 
-    pub fn verify_correct_pricing_program(
-        &self,
-        pool_state: &'me AccountInfo<'info>,
-    ) -> Result<(), ProgramError> {
-        let pool_state_bytes = pool_state.try_borrow_data()?;
+    pub fn verify_correct_pricing_program(&self) -> Result<(), ProgramError> {
+        let pool_state_bytes = self.pool_state.try_borrow_data()?;
         let pool_state = try_pool_state(&pool_state_bytes)?;
         if *self.program.key != pool_state.pricing_program {
             return Err(SControllerError::IncorrectPricingProgram.into());
@@ -120,14 +136,35 @@ impl<'me, 'info> PricingProgramPriceLpCpi<'me, 'info> {
     fn create_account_info_slice(self) -> Vec<AccountInfo<'info>> {
         let Self {
             lst_mint,
+            pool_state,
+            lst_state_list,
+            pool_reserves,
+            is_pool_context_enabled,
             remaining_accounts,
             ..
         } = self;
-        [std::slice::from_ref(lst_mint), remaining_accounts].concat()
+        if !is_pool_context_enabled {
+            return [std::slice::from_ref(lst_mint), remaining_accounts].concat();
+        }
+        [
+            std::slice::from_ref(lst_mint),
+            std::slice::from_ref(pool_state),
+            std::slice::from_ref(lst_state_list),
+            std::slice::from_ref(pool_reserves),
+            remaining_accounts,
+        ]
+        .concat()
     }
 
     fn create_account_metas(&self) -> Vec<AccountMeta> {
         let mut res = vec![AccountMeta::new_readonly(*self.lst_mint.key, false)];
+        if self.is_pool_context_enabled {
+            res.extend([
+                AccountMeta::new_readonly(*self.pool_state.key, false),
+                AccountMeta::new_readonly(*self.lst_state_list.key, false),
+                AccountMeta::new_readonly(*self.pool_reserves.key, false),
+            ]);
+        }
         for r in self.remaining_accounts.iter() {
             res.push(r.to_account_meta());
         }
@@ -135,7 +172,7 @@ impl<'me, 'info> PricingProgramPriceLpCpi<'me, 'info> {
     }
 }
 
-/// CPI call to either `PriceLpTokensToRedeem` or `PriceLpTokensToMint`
+/// CPI call to either `PriceExactIn` or `PriceExactOut`
 #[derive(Clone, Copy, Debug)]
 pub struct PricingProgramPriceSwapCpi<'me, 'info> {
     /// The pricing program to invoke
@@ -145,41 +182,64 @@ pub struct PricingProgramPriceSwapCpi<'me, 'info> {
 
     pub output_lst_mint: &'me AccountInfo<'info>,
 
+    pub pool_state: &'me AccountInfo<'info>,
+
+    pub lst_state_list: &'me AccountInfo<'info>,
+
+    /// The pool reserves of `input_lst_mint`
+    pub input_pool_reserves: &'me AccountInfo<'info>,
+
+    /// The pool reserves of `output_lst_mint`
+    pub output_pool_reserves: &'me AccountInfo<'info>,
+
+    /// Whether to prepend `pool_state`, `lst_state_list` and the pool reserves
+    /// to the CPI accounts, see `PoolState::is_pricing_pool_context_enabled`
+    pub is_pool_context_enabled: bool,
+
     /// Remaining accounts required by the pricing program
     pub remaining_accounts: &'me [AccountInfo<'info>],
 }
 
 impl<'me, 'info> PricingProgramPriceSwapCpi<'me, 'info> {
     /// Args:
-    /// - `ix_accounts`: the calling instruction's accounts, excluding accounts_suffix_slice.
-    ///     Should be a `*Accounts` struct generated by solores
+    /// - `src_dst_lst_mints`, `src_dst_lst_pool_reserves`, `pool_state`, `lst_state_list`:
+    ///     the calling instruction's verified accounts that make up the common interface accounts prefix
     /// - `accounts_suffix_slice`: subslice of instruction accounts where first account is the pricing program
-    ///     and remaining slice is remaining_accounts (excludes `input_lst_mint` and `output_lst_mint`)
-    pub fn from_src_dst_lst_mints_and_account_suffix_slice(
+    ///     and remaining slice is remaining_accounts (excludes the common interface accounts prefix)
+    pub fn from_interface_accounts_and_account_suffix_slice(
         SrcDstLstMintAccountInfos {
             src_lst_mint,
             dst_lst_mint,
         }: SrcDstLstMintAccountInfos<'me, 'info>,
+        SrcDstLstPoolReservesAccountInfos {
+            src_pool_reserves,
+            dst_pool_reserves,
+        }: SrcDstLstPoolReservesAccountInfos<'me, 'info>,
+        pool_state: &'me AccountInfo<'info>,
+        lst_state_list: &'me AccountInfo<'info>,
         accounts_suffix_slice: &'me [AccountInfo<'info>],
     ) -> Result<Self, ProgramError> {
         let program = accounts_suffix_slice
             .first()
             .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let is_pool_context_enabled = is_pool_context_enabled(pool_state)?;
         Ok(Self {
             program,
             input_lst_mint: src_lst_mint,
             output_lst_mint: dst_lst_mint,
+            pool_state,
+            lst_state_list,
+            input_pool_reserves: src_pool_reserves,
+            output_pool_reserves: dst_pool_reserves,
+            is_pool_context_enabled,
             remaining_accounts: accounts_suffix_slice
                 .get(1..)
                 .ok_or(ProgramError::NotEnoughAccountKeys)?,
         })
     }
 
-    pub fn verify_correct_pricing_program(
-        &self,
-        pool_state: &'me AccountInfo<'info>,
-    ) -> Result<(), ProgramError> {
-        let pool_state_bytes = pool_state.try_borrow_data()?;
+    pub fn verify_correct_pricing_program(&self) -> Result<(), ProgramError> {
+        let pool_state_bytes = self.pool_state.try_borrow_data()?;
         let pool_state = try_pool_state(&pool_state_bytes)?;
         if *self.program.key != pool_state.pricing_program {
             return Err(SControllerError::IncorrectPricingProgram.into());
@@ -230,12 +290,29 @@ impl<'me, 'info> PricingProgramPriceSwapCpi<'me, 'info> {
         let Self {
             input_lst_mint,
             output_lst_mint,
+            pool_state,
+            lst_state_list,
+            input_pool_reserves,
+            output_pool_reserves,
+            is_pool_context_enabled,
             remaining_accounts,
             ..
         } = self;
+        if !is_pool_context_enabled {
+            return [
+                std::slice::from_ref(input_lst_mint),
+                std::slice::from_ref(output_lst_mint),
+                remaining_accounts,
+            ]
+            .concat();
+        }
         [
             std::slice::from_ref(input_lst_mint),
             std::slice::from_ref(output_lst_mint),
+            std::slice::from_ref(pool_state),
+            std::slice::from_ref(lst_state_list),
+            std::slice::from_ref(input_pool_reserves),
+            std::slice::from_ref(output_pool_reserves),
             remaining_accounts,
         ]
         .concat()
@@ -246,9 +323,23 @@ impl<'me, 'info> PricingProgramPriceSwapCpi<'me, 'info> {
             AccountMeta::new_readonly(*self.input_lst_mint.key, false),
             AccountMeta::new_readonly(*self.output_lst_mint.key, false),
         ];
+        if self.is_pool_context_enabled {
+            res.extend([
+                AccountMeta::new_readonly(*self.pool_state.key, false),
+                AccountMeta::new_readonly(*self.lst_state_list.key, false),
+                AccountMeta::new_readonly(*self.input_pool_reserves.key, false),
+                AccountMeta::new_readonly(*self.output_pool_reserves.key, false),
+            ]);
+        }
         for r in self.remaining_accounts.iter() {
             res.push(r.to_account_meta());
         }
         res
     }
 }
+
+fn is_pool_context_enabled(pool_state: &AccountInfo) -> Result<bool, ProgramError> {
+    let pool_state_bytes = pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    Ok(U8Bool(pool_state.is_pricing_pool_context_enabled).is_true())
+}
@@ -1,9 +1,11 @@
+use borsh::BorshDeserialize;
 use s_controller_interface::SControllerError;
 use s_controller_lib::try_lst_state_list;
 use sanctum_misc_utils::{get_borsh_return_data, ToAccountMeta};
 use sanctum_token_ratio::U64ValueRange;
 use sol_value_calculator_interface::{
-    LstToSolIxArgs, LstToSolIxData, SolToLstIxArgs, SolToLstIxData,
+    BatchCalcIxArgs, BatchCalcIxData, LstToSolIxArgs, LstToSolIxData, SolToLstIxArgs,
+    SolToLstIxData,
 };
 use solana_program::{
     account_info::AccountInfo,
@@ -75,7 +77,27 @@ impl<'me, 'info> SolValueCalculatorCpi<'me, 'info> {
         self.invoke_interface_ix(ix)
     }
 
-    fn invoke_interface_ix(self, interface_ix: Instruction) -> Result<U64ValueRange, ProgramError> {
+    /// Evaluates LstToSol for each of `lst_amts` followed by SolToLst for each of `sol_amts`
+    /// in a single CPI, so the calculator only has to deserialize its accounts once.
+    ///
+    /// Returned ranges are in the same order as the amounts
+    pub fn invoke_batch_calc(
+        self,
+        lst_amts: &[u64],
+        sol_amts: &[u64],
+    ) -> Result<Vec<U64ValueRange>, ProgramError> {
+        let ix = self.create_batch_calc_ix(lst_amts, sol_amts)?;
+        let res: Vec<U64ValueRange> = self.invoke_interface_ix(ix)?;
+        if res.len() != lst_amts.len() + sol_amts.len() {
+            return Err(SControllerError::FaultySolValueCalculator.into());
+        }
+        Ok(res)
+    }
+
+    fn invoke_interface_ix<T: BorshDeserialize>(
+        self,
+        interface_ix: Instruction,
+    ) -> Result<T, ProgramError> {
         let accounts = self.create_account_info_slice();
         invoke(&interface_ix, &accounts)?;
         let (_pk, res) =
@@ -108,6 +130,22 @@ impl<'me, 'info> SolValueCalculatorCpi<'me, 'info> {
         })
     }
 
+    fn create_batch_calc_ix(
+        &self,
+        lst_amts: &[u64],
+        sol_amts: &[u64],
+    ) -> Result<Instruction, ProgramError> {
+        Ok(Instruction {
+            program_id: *self.program.key,
+            accounts: self.create_account_metas(),
+            data: BatchCalcIxData(BatchCalcIxArgs {
+                lst_to_sol_amounts: lst_amts.to_vec(),
+                sol_to_lst_amounts: sol_amts.to_vec(),
+            })
+            .try_to_vec()?,
+        })
+    }
+
     fn create_lst_to_sol_ix(&self, lst_amt: u64) -> Result<Instruction, ProgramError> {
         Ok(Instruction {
             program_id: *self.program.key,
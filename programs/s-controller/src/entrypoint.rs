@@ -57,6 +57,18 @@ pub fn process_instruction(
         SControllerProgramIx::EndRebalance => process_end_rebalance(accounts),
         SControllerProgramIx::SetRebalanceAuthority => process_set_rebalance_authority(accounts),
         SControllerProgramIx::Initialize => process_initialize(accounts),
+        SControllerProgramIx::Donate(args) => process_donate(accounts, args),
+        SControllerProgramIx::AddDonor => process_add_donor(accounts),
+        SControllerProgramIx::RemoveDonor(args) => process_remove_donor(accounts, args),
+        SControllerProgramIx::SetLstBatchCalc(args) => process_set_lst_batch_calc(accounts, args),
+        SControllerProgramIx::SetMaxRebalanceLoss(args) => {
+            process_set_max_rebalance_loss(accounts, args)
+        }
+        SControllerProgramIx::StartRebalanceV2(args) => process_start_rebalance_v2(accounts, args),
+        SControllerProgramIx::EndRebalanceV2(args) => process_end_rebalance_v2(accounts, args),
+        SControllerProgramIx::SetPricingProgramV2(args) => {
+            process_set_pricing_program_v2(accounts, args)
+        }
     };
     if let Err(e) = res.as_ref() {
         e.print::<SControllerError>();
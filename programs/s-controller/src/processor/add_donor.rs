@@ -0,0 +1,69 @@
+use s_controller_interface::{
+    add_donor_verify_account_keys, add_donor_verify_account_privileges, AddDonorAccounts,
+    SControllerError,
+};
+use s_controller_lib::{
+    program::{DONOR_ALLOWLIST_BUMP, DONOR_ALLOWLIST_SEED},
+    try_donor_allowlist, try_donor_allowlist_mut, AddDonorFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::list_account::{extend_list_pda, ExtendListPdaAccounts};
+
+pub fn process_add_donor(accounts: &[AccountInfo]) -> ProgramResult {
+    let checked = verify_add_donor(accounts)?;
+
+    extend_list_pda::<Pubkey>(
+        ExtendListPdaAccounts {
+            list_pda: checked.donor_allowlist,
+            payer: checked.payer,
+        },
+        &[&[DONOR_ALLOWLIST_SEED, &[DONOR_ALLOWLIST_BUMP]]],
+    )?;
+
+    let mut donor_allowlist_data = checked.donor_allowlist.try_borrow_mut_data()?;
+    let list = try_donor_allowlist_mut(&mut donor_allowlist_data)?;
+    let new_entry = list
+        .last_mut()
+        .ok_or(SControllerError::InvalidDonorAllowlistData)?;
+
+    *new_entry = *checked.new_donor.key;
+
+    Ok(())
+}
+
+fn verify_not_duplicate(donor_allowlist: &AccountInfo, donor: Pubkey) -> Result<(), ProgramError> {
+    let d = donor_allowlist.try_borrow_data()?;
+    let donor_allowlist = try_donor_allowlist(&d)?;
+    if donor_allowlist.contains(&donor) {
+        Err(SControllerError::DuplicateDonor.into())
+    } else {
+        Ok(())
+    }
+}
+
+fn verify_add_donor<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<AddDonorAccounts<'me, 'info>, ProgramError> {
+    let actual: AddDonorAccounts = load_accounts(accounts)?;
+
+    let free_args = AddDonorFreeArgs {
+        payer: *actual.payer.key,
+        new_donor: *actual.new_donor.key,
+        pool_state_acc: actual.pool_state,
+    };
+    let expected = free_args.resolve()?;
+
+    add_donor_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    add_donor_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_not_duplicate(actual.donor_allowlist, *actual.new_donor.key)?;
+
+    Ok(actual)
+}
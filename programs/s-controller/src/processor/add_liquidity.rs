@@ -12,9 +12,10 @@ use s_controller_lib::{
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
+use sanctum_s_common::token::{amount_after_transfer_fee, current_epoch_transfer_fee};
 use sanctum_token_lib::{
-    mint_supply, mint_to_invoke_signed, transfer_checked_decimal_agnostic_invoke, MintToAccounts,
-    TransferCheckedAccounts,
+    mint_supply, mint_to_invoke_signed, transfer_checked_decimal_agnostic_invoke,
+    transfer_checked_decimal_agnostic_invoke_signed, MintToAccounts, TransferCheckedAccounts,
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
@@ -28,7 +29,10 @@ use crate::{
     },
 };
 
-use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+use super::{
+    sync_sol_value_and_lst_to_sol_unchecked, sync_sol_value_unchecked,
+    SyncSolValueUncheckedAccounts,
+};
 
 pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs) -> ProgramResult {
     let (
@@ -45,16 +49,26 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
         pricing_cpi,
     ) = verify_add_liquidity(accounts, args)?;
 
+    // Token-2022 transfer fees are deducted from the amount received:
+    // only the LST that reaches the pool reserves is valued
+    let transfer_fee = current_epoch_transfer_fee(accounts.lst_mint)?;
+    let lst_amount_received = amount_after_transfer_fee(transfer_fee.as_ref(), lst_amount)?;
+
     let sync_sol_value_accounts = SyncSolValueUncheckedAccounts::from(accounts);
 
-    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+    let lst_amount_sol_value = sync_sol_value_and_lst_to_sol_unchecked(
+        sync_sol_value_accounts,
+        lst_cpi,
+        lst_index,
+        lst_amount_received,
+    )?
+    .get_min();
 
     let start_total_sol_value = accounts.pool_state.total_sol_value()?;
 
-    let lst_amount_sol_value = lst_cpi.invoke_lst_to_sol(lst_amount)?.get_min();
     let lst_amount_sol_value_after_fees =
         pricing_cpi.invoke_price_lp_tokens_to_mint(PricingProgramIxArgs {
-            amount: lst_amount,
+            amount: lst_amount_received,
             sol_value: lst_amount_sol_value,
         })?;
     // Will dilute existing LPs if unchecked
@@ -66,7 +80,7 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
         to_reserves_lst_amount,
         to_protocol_fees_lst_amount,
     } = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
-        lst_amount,
+        lst_amount: lst_amount_received,
         lst_amount_sol_value,
         lst_amount_sol_value_after_fees,
         lp_protocol_fee_bps: accounts.pool_state.lp_protocol_fee_bps()?,
@@ -90,6 +104,9 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
         return Err(SControllerError::SlippageToleranceExceeded.into());
     }
 
+    // Transfer everything to the reserves before forwarding protocol fees
+    // so that the reserves receive exactly lst_amount_received
+    // regardless of how transfer fees round across multiple transfers
     transfer_checked_decimal_agnostic_invoke(
         TransferCheckedAccounts {
             from: accounts.src_lst_acc,
@@ -98,17 +115,21 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
             authority: accounts.signer,
             mint: accounts.lst_mint,
         },
-        to_reserves_lst_amount,
+        lst_amount,
     )?;
-    transfer_checked_decimal_agnostic_invoke(
+    // The protocol fee accumulator absorbs any Token-2022 transfer fee on this transfer
+    // instead of it being grossed up, so that the pool reserves lose exactly
+    // to_protocol_fees_lst_amount, which is what the protocol fee was calculated as
+    transfer_checked_decimal_agnostic_invoke_signed(
         TransferCheckedAccounts {
-            from: accounts.src_lst_acc,
+            from: accounts.pool_reserves,
             to: accounts.protocol_fee_accumulator,
             token_program: accounts.lst_token_program,
-            authority: accounts.signer,
+            authority: accounts.pool_state,
             mint: accounts.lst_mint,
         },
         to_protocol_fees_lst_amount,
+        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
     )?;
     mint_to_invoke_signed(
         MintToAccounts {
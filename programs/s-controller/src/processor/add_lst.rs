@@ -71,7 +71,8 @@ pub fn process_add_lst(accounts: &[AccountInfo]) -> ProgramResult {
         mint: *accounts.lst_mint.key,
         sol_value_calculator: *accounts.sol_value_calculator.key,
         is_input_disabled: 0,
-        padding: [0u8; 5],
+        is_batch_calc_enabled: 0,
+        padding: [0u8; 4],
     };
 
     Ok(())
@@ -0,0 +1,149 @@
+use borsh::BorshSerialize;
+use s_controller_interface::{
+    donate_verify_account_keys, donate_verify_account_privileges, DonateAccounts, DonateIxArgs,
+    SControllerError, DONATE_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    index_to_usize, try_donor_allowlist, try_lst_state_list, try_pool_state, DonateEvent,
+    DonateFreeArgs, DonateIxFullArgs, PoolStateAccount, DONATE_EVENT_TAG,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_s_common::token::{amount_after_transfer_fee, current_epoch_transfer_fee};
+use sanctum_token_lib::{transfer_checked_decimal_agnostic_invoke, TransferCheckedAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, log::sol_log_data,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{
+    cpi::SolValueCalculatorCpi,
+    verify::{
+        verify_lst_input_not_disabled, verify_lst_sol_val_calc_cpi,
+        verify_not_rebalancing_and_not_disabled, VerifyLstSolValCalcCpiAccounts,
+    },
+};
+
+use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+pub fn process_donate(accounts: &[AccountInfo], args: DonateIxArgs) -> ProgramResult {
+    let (accounts, DonateIxFullArgs { lst_index, amount }, cpi) = verify_donate(accounts, args)?;
+
+    let sync_sol_value_accounts = SyncSolValueUncheckedAccounts::from(accounts);
+
+    // sync first so that any SOL value not previously recorded
+    // is not attributed to this donation
+    sync_sol_value_unchecked(sync_sol_value_accounts, cpi, lst_index)?;
+
+    let start_total_sol_value = accounts.pool_state.total_sol_value()?;
+
+    // Token-2022 transfer fees are deducted from the amount received:
+    // only the LST that reaches the pool reserves is donated
+    let transfer_fee = current_epoch_transfer_fee(accounts.lst_mint)?;
+    let amount_received = amount_after_transfer_fee(transfer_fee.as_ref(), amount)?;
+
+    transfer_checked_decimal_agnostic_invoke(
+        TransferCheckedAccounts {
+            from: accounts.src_lst_acc,
+            to: accounts.pool_reserves,
+            token_program: accounts.lst_token_program,
+            authority: accounts.donor,
+            mint: accounts.lst_mint,
+        },
+        amount,
+    )?;
+
+    sync_sol_value_unchecked(sync_sol_value_accounts, cpi, lst_index)?;
+
+    let end_total_sol_value = accounts.pool_state.total_sol_value()?;
+    let sol_value = end_total_sol_value
+        .checked_sub(start_total_sol_value)
+        .ok_or(SControllerError::PoolWouldLoseSolValue)?;
+    if sol_value == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let event = DonateEvent {
+        donor: *accounts.donor.key,
+        lst_mint: *accounts.lst_mint.key,
+        amount: amount_received,
+        sol_value,
+    };
+    sol_log_data(&[DONATE_EVENT_TAG, &event.try_to_vec()?]);
+
+    Ok(())
+}
+
+/// An empty (uninitialized) donor allowlist means donations are permissionless
+fn verify_donor_allowed(donor_allowlist: &AccountInfo, donor: &Pubkey) -> Result<(), ProgramError> {
+    if donor_allowlist.data_is_empty() {
+        return Ok(());
+    }
+    let d = donor_allowlist.try_borrow_data()?;
+    let donor_allowlist = try_donor_allowlist(&d)?;
+    if donor_allowlist.contains(donor) {
+        Ok(())
+    } else {
+        Err(SControllerError::UnauthorizedDonor.into())
+    }
+}
+
+fn verify_donate<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    DonateIxArgs {
+        lst_value_calc_accs: _,
+        lst_index,
+        amount,
+    }: DonateIxArgs,
+) -> Result<
+    (
+        DonateAccounts<'a, 'info>,
+        DonateIxFullArgs,
+        SolValueCalculatorCpi<'a, 'info>,
+    ),
+    ProgramError,
+> {
+    if amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let lst_index = index_to_usize(lst_index)?;
+
+    let actual: DonateAccounts = load_accounts(accounts)?;
+
+    let free_args = DonateFreeArgs {
+        lst_index,
+        donor: *actual.donor.key,
+        src_lst_acc: *actual.src_lst_acc.key,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: actual.lst_mint,
+    };
+    let expected = free_args.resolve()?;
+
+    donate_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    donate_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    // lst_index checked above
+    verify_lst_input_not_disabled(&lst_state_list[lst_index])?;
+
+    verify_donor_allowed(actual.donor_allowlist, actual.donor.key)?;
+
+    let accounts_suffix_slice = accounts
+        .get(DONATE_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let cpi = verify_lst_sol_val_calc_cpi(
+        VerifyLstSolValCalcCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        lst_index,
+    )?;
+
+    Ok((actual, DonateIxFullArgs { lst_index, amount }, cpi))
+}
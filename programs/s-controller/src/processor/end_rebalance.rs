@@ -1,11 +1,12 @@
 use s_controller_interface::{
     end_rebalance_verify_account_keys, end_rebalance_verify_account_privileges,
-    EndRebalanceAccounts, PoolState, RebalanceRecord, SControllerError,
+    EndRebalanceAccounts, EndRebalanceV2IxArgs, PoolState, RebalanceRecord, SControllerError,
     END_REBALANCE_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    try_pool_state, try_pool_state_mut, try_rebalance_record, EndRebalanceFreeArgs,
-    PoolStateAccount, U8Bool, U8BoolMut,
+    index_to_usize, rebalance_record_additional_dst_lst_indexes, try_pool_state,
+    try_pool_state_mut, try_rebalance_record, verify_rebalance_sol_value_delta,
+    EndRebalanceFreeArgs, PoolStateAccount, U8Bool, U8BoolMut,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -17,26 +18,56 @@ use solana_program::{
 
 use crate::{
     cpi::SolValueCalculatorCpi,
-    verify::{verify_lst_sol_val_calc_cpi, VerifyLstSolValCalcCpiAccounts},
+    verify::{
+        verify_additional_rebalance_dsts, verify_lst_sol_val_calc_cpi, AdditionalRebalanceDstSync,
+        VerifyLstSolValCalcCpiAccounts,
+    },
 };
 
 use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
 
 pub fn process_end_rebalance(accounts: &[AccountInfo]) -> ProgramResult {
-    let (accounts, cpi, dst_lst_index) = verify_end_rebalance(accounts)?;
+    // all remaining accounts are the destination LST's SOL value calculator accounts
+    let (accounts, cpi, dst_lst_index, additional_dsts) =
+        verify_end_rebalance(accounts, None, &[])?;
+    end_rebalance(accounts, cpi, dst_lst_index, additional_dsts)
+}
+
+pub fn process_end_rebalance_v2(
+    accounts: &[AccountInfo],
+    EndRebalanceV2IxArgs {
+        dst_lst_calc_accs,
+        additional_dst_lst_calc_accs,
+    }: EndRebalanceV2IxArgs,
+) -> ProgramResult {
+    let (accounts, cpi, dst_lst_index, additional_dsts) = verify_end_rebalance(
+        accounts,
+        Some(dst_lst_calc_accs),
+        &additional_dst_lst_calc_accs,
+    )?;
+    end_rebalance(accounts, cpi, dst_lst_index, additional_dsts)
+}
+
+fn end_rebalance<'a, 'info>(
+    accounts: EndRebalanceAccounts<'a, 'info>,
+    cpi: SolValueCalculatorCpi<'a, 'info>,
+    dst_lst_index: usize,
+    additional_dsts: Vec<AdditionalRebalanceDstSync<'a, 'info>>,
+) -> ProgramResult {
     // braces to limit scope of pool_state_data borrow
     {
         let mut pool_state_data = accounts.pool_state.try_borrow_mut_data()?;
         let pool_state = try_pool_state_mut(&mut pool_state_data)?;
         U8BoolMut(&mut pool_state.is_rebalancing).set_false();
     }
-    let old_total_sol_value = {
+    let (old_total_sol_value, min_sol_value_delta) = {
         let rebalance_record_data = accounts.rebalance_record.try_borrow_data()?;
         let RebalanceRecord {
             old_total_sol_value,
+            min_sol_value_delta,
             ..
         } = try_rebalance_record(&rebalance_record_data)?;
-        *old_total_sol_value
+        (*old_total_sol_value, *min_sol_value_delta)
     };
 
     sync_sol_value_unchecked(
@@ -44,24 +75,47 @@ pub fn process_end_rebalance(accounts: &[AccountInfo]) -> ProgramResult {
         cpi,
         dst_lst_index,
     )?;
-
-    if accounts.pool_state.total_sol_value()? < old_total_sol_value {
-        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    for AdditionalRebalanceDstSync {
+        pool_reserves,
+        cpi,
+        lst_index,
+    } in additional_dsts
+    {
+        sync_sol_value_unchecked(
+            SyncSolValueUncheckedAccounts {
+                pool_reserves,
+                pool_state: accounts.pool_state,
+                lst_state_list: accounts.lst_state_list,
+            },
+            cpi,
+            lst_index,
+        )?;
     }
 
+    verify_rebalance_sol_value_delta(
+        old_total_sol_value,
+        accounts.pool_state.total_sol_value()?,
+        min_sol_value_delta,
+    )?;
+
     close_account(CloseAccountAccounts {
         refund_rent_to: accounts.pool_state,
         close: accounts.rebalance_record,
     })
 }
 
+/// `dst_lst_calc_accs` is None for EndRebalance,
+/// where all accounts after the fixed accounts are the destination LST's calculator accounts
 fn verify_end_rebalance<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
+    dst_lst_calc_accs: Option<u8>,
+    additional_dst_lst_calc_accs: &[u8],
 ) -> Result<
     (
         EndRebalanceAccounts<'a, 'info>,
         SolValueCalculatorCpi<'a, 'info>,
         usize,
+        Vec<AdditionalRebalanceDstSync<'a, 'info>>,
     ),
     ProgramError,
 > {
@@ -82,16 +136,44 @@ fn verify_end_rebalance<'a, 'info>(
     let pool_state = try_pool_state(&pool_state_bytes)?;
     verify_is_rebalancing(pool_state)?;
 
+    let rebalance_record_bytes = actual.rebalance_record.try_borrow_data()?;
+    let rebalance_record = try_rebalance_record(&rebalance_record_bytes)?;
+    let additional_dst_lst_indexes = rebalance_record_additional_dst_lst_indexes(rebalance_record)?
+        .iter()
+        .map(|lst_index| index_to_usize(*lst_index))
+        .collect::<Result<Vec<_>, _>>()?;
+    if additional_dst_lst_indexes.len() != additional_dst_lst_calc_accs.len() {
+        return Err(SControllerError::IncorrectRebalanceDsts.into());
+    }
+
     let accounts_suffix_slice = accounts
         .get(END_REBALANCE_IX_ACCOUNTS_LEN..)
         .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let dst_lst_suffix_slice_end =
+        dst_lst_calc_accs.map_or(accounts_suffix_slice.len(), usize::from);
+    let dst_lst_suffix_slice = accounts_suffix_slice
+        .get(..dst_lst_suffix_slice_end)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
     let dst_lst_cpi = verify_lst_sol_val_calc_cpi(
         VerifyLstSolValCalcCpiAccounts::from(actual),
-        accounts_suffix_slice,
+        dst_lst_suffix_slice,
         dst_lst_index,
     )?;
 
-    Ok((actual, dst_lst_cpi, dst_lst_index))
+    let additional_dsts_suffix_slice = accounts_suffix_slice
+        .get(dst_lst_suffix_slice_end..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    // additional dsts must be passed in the same order as they were recorded in StartRebalanceV2,
+    // verify_additional_rebalance_dsts() errors if lst_mint does not match lst_index
+    let additional_dsts = verify_additional_rebalance_dsts(
+        actual.lst_state_list,
+        additional_dsts_suffix_slice,
+        additional_dst_lst_indexes
+            .into_iter()
+            .zip(additional_dst_lst_calc_accs.iter().copied()),
+    )?;
+
+    Ok((actual, dst_lst_cpi, dst_lst_index, additional_dsts))
 }
 
 const fn verify_is_rebalancing(pool_state: &PoolState) -> Result<(), SControllerError> {
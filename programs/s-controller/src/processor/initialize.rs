@@ -50,7 +50,8 @@ pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
             version: CURRENT_PROGRAM_VERS,
             is_disabled: 0,
             is_rebalancing: 0,
-            padding: [0],
+            // DEFAULT_PRICING_PROGRAM takes the pool context accounts prefix
+            is_pricing_pool_context_enabled: 1,
             admin: *accounts.authority.key,
             rebalance_authority: *accounts.authority.key,
             protocol_fee_beneficiary: *accounts.authority.key,
@@ -0,0 +1,48 @@
+use s_controller_interface::{
+    remove_donor_verify_account_keys, remove_donor_verify_account_privileges, RemoveDonorAccounts,
+    RemoveDonorIxArgs,
+};
+use s_controller_lib::{index_to_usize, RemoveDonorFreeArgs};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::list_account::{remove_from_list_pda, RemoveFromListPdaAccounts};
+
+pub fn process_remove_donor(accounts: &[AccountInfo], args: RemoveDonorIxArgs) -> ProgramResult {
+    let (checked_accounts, index) = verify_remove_donor(accounts, args)?;
+
+    remove_from_list_pda::<Pubkey>(
+        RemoveFromListPdaAccounts {
+            list_pda: checked_accounts.donor_allowlist,
+            refund_rent_to: checked_accounts.refund_rent_to,
+        },
+        index,
+    )
+}
+
+fn verify_remove_donor<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    RemoveDonorIxArgs { index }: RemoveDonorIxArgs,
+) -> Result<(RemoveDonorAccounts<'me, 'info>, usize), ProgramError> {
+    let actual: RemoveDonorAccounts = load_accounts(accounts)?;
+    let index = index_to_usize(index)?;
+
+    // resolve() checks that donor is at index on the list
+    let free_args = RemoveDonorFreeArgs {
+        index,
+        refund_rent_to: *actual.refund_rent_to.key,
+        pool_state_acc: actual.pool_state,
+        donor_allowlist: actual.donor_allowlist,
+    };
+    let expected = free_args.resolve()?;
+
+    remove_donor_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    remove_donor_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok((actual, index))
+}
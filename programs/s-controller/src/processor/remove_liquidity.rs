@@ -12,6 +12,7 @@ use s_controller_lib::{
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
+use sanctum_s_common::token::{amount_after_transfer_fee, current_epoch_transfer_fee};
 use sanctum_token_lib::{
     burn_invoke, mint_supply, transfer_checked_decimal_agnostic_invoke_signed, BurnAccounts,
     TransferCheckedAccounts,
@@ -78,10 +79,14 @@ pub fn process_remove_liquidity(
             lp_protocol_fee_bps: accounts.pool_state.lp_protocol_fee_bps()?,
         })?;
 
-    if to_user_lst_amount == 0 {
+    // Token-2022 transfer fees are deducted from the amount the user receives
+    let transfer_fee = current_epoch_transfer_fee(accounts.lst_mint)?;
+    let to_user_lst_received =
+        amount_after_transfer_fee(transfer_fee.as_ref(), to_user_lst_amount)?;
+    if to_user_lst_received == 0 {
         return Err(SControllerError::ZeroValue.into());
     }
-    if to_user_lst_amount < min_lst_out {
+    if to_user_lst_received < min_lst_out {
         return Err(SControllerError::SlippageToleranceExceeded.into());
     }
 
@@ -107,6 +112,9 @@ pub fn process_remove_liquidity(
         &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
     )?;
 
+    // The protocol fee accumulator absorbs any Token-2022 transfer fee on this transfer
+    // instead of it being grossed up, so that the pool reserves lose exactly
+    // to_protocol_fees_lst_amount, which is what the protocol fee was calculated as
     transfer_checked_decimal_agnostic_invoke_signed(
         TransferCheckedAccounts {
             to: accounts.protocol_fee_accumulator,
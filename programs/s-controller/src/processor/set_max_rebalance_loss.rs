@@ -0,0 +1,84 @@
+use s_controller_interface::{
+    set_max_rebalance_loss_verify_account_keys, set_max_rebalance_loss_verify_account_privileges,
+    SetMaxRebalanceLossAccounts, SetMaxRebalanceLossIxArgs,
+};
+use s_controller_lib::{
+    program::{REBALANCE_CONFIG_BUMP, REBALANCE_CONFIG_SEED},
+    try_pool_state, try_rebalance_config_mut, SetMaxRebalanceLossFreeArgs, REBALANCE_CONFIG_SIZE,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use system_program_interface::CreateAccountAccounts;
+
+use crate::verify::verify_not_rebalancing_and_not_disabled;
+
+pub fn process_set_max_rebalance_loss(
+    accounts: &[AccountInfo],
+    args: SetMaxRebalanceLossIxArgs,
+) -> ProgramResult {
+    let (
+        SetMaxRebalanceLossAccounts {
+            payer,
+            rebalance_config,
+            ..
+        },
+        SetMaxRebalanceLossIxArgs { max_rebalance_loss },
+    ) = verify_set_max_rebalance_loss(accounts, args)?;
+
+    if rebalance_config.data_is_empty() {
+        init_rent_exempt_account_invoke_signed(
+            CreateAccountAccounts {
+                from: payer,
+                to: rebalance_config,
+            },
+            InitRentExemptAccountArgs {
+                space: REBALANCE_CONFIG_SIZE,
+                owner: s_controller_lib::program::ID,
+            },
+            &[&[REBALANCE_CONFIG_SEED, &[REBALANCE_CONFIG_BUMP]]],
+        )?;
+    }
+
+    let mut rebalance_config_bytes = rebalance_config.try_borrow_mut_data()?;
+    let rebalance_config = try_rebalance_config_mut(&mut rebalance_config_bytes)?;
+    rebalance_config.max_rebalance_loss = max_rebalance_loss;
+
+    Ok(())
+}
+
+fn verify_set_max_rebalance_loss<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    args: SetMaxRebalanceLossIxArgs,
+) -> Result<
+    (
+        SetMaxRebalanceLossAccounts<'me, 'info>,
+        SetMaxRebalanceLossIxArgs,
+    ),
+    ProgramError,
+> {
+    let actual: SetMaxRebalanceLossAccounts = load_accounts(accounts)?;
+
+    let free_args = SetMaxRebalanceLossFreeArgs {
+        payer: *actual.payer.key,
+        pool_state: actual.pool_state,
+    };
+    let expected = free_args.resolve()?;
+
+    set_max_rebalance_loss_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_max_rebalance_loss_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    Ok((actual, args))
+}
@@ -1,8 +1,10 @@
 use s_controller_interface::{
     set_pricing_program_verify_account_keys, set_pricing_program_verify_account_privileges,
-    SetPricingProgramAccounts,
+    SetPricingProgramAccounts, SetPricingProgramV2IxArgs,
+};
+use s_controller_lib::{
+    try_pool_state, try_pool_state_mut, SetPricingProgramFreeArgs, U8Bool, U8BoolMut,
 };
-use s_controller_lib::{try_pool_state, try_pool_state_mut, SetPricingProgramFreeArgs};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -13,6 +15,20 @@ use solana_program::{
 use crate::verify::{verify_not_rebalancing_and_not_disabled, verify_pricing_program_is_program};
 
 pub fn process_set_pricing_program(accounts: &[AccountInfo]) -> ProgramResult {
+    set_pricing_program(accounts, false)
+}
+
+pub fn process_set_pricing_program_v2(
+    accounts: &[AccountInfo],
+    SetPricingProgramV2IxArgs {
+        is_pool_context_enabled,
+    }: SetPricingProgramV2IxArgs,
+) -> ProgramResult {
+    set_pricing_program(accounts, U8Bool(is_pool_context_enabled).is_true())
+}
+
+/// SetPricingProgramV2 takes the same accounts as SetPricingProgram
+fn set_pricing_program(accounts: &[AccountInfo], is_pool_context_enabled: bool) -> ProgramResult {
     let SetPricingProgramAccounts {
         admin: _,
         new_pricing_program,
@@ -23,6 +39,13 @@ pub fn process_set_pricing_program(accounts: &[AccountInfo]) -> ProgramResult {
     let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
 
     pool_state.pricing_program = *new_pricing_program.key;
+    let mut is_pricing_pool_context_enabled =
+        U8BoolMut(&mut pool_state.is_pricing_pool_context_enabled);
+    if is_pool_context_enabled {
+        is_pricing_pool_context_enabled.set_true();
+    } else {
+        is_pricing_pool_context_enabled.set_false();
+    }
 
     Ok(())
 }
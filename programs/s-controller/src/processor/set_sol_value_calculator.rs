@@ -5,6 +5,7 @@ use s_controller_interface::{
 };
 use s_controller_lib::{
     index_to_usize, try_lst_state_list_mut, try_pool_state, SetSolValueCalculatorFreeArgs,
+    U8BoolMut,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -30,7 +31,10 @@ pub fn process_set_sol_value_calculator(
         let mut lst_state_list_data = accounts.lst_state_list.try_borrow_mut_data()?;
         let lst_state_list = try_lst_state_list_mut(&mut lst_state_list_data)?;
         // lst_index checked in verify
-        lst_state_list[lst_index].sol_value_calculator = *cpi.program.key;
+        let lst_state = &mut lst_state_list[lst_index];
+        lst_state.sol_value_calculator = *cpi.program.key;
+        // new calculator may not support BatchCalc, must be opted into again
+        U8BoolMut(&mut lst_state.is_batch_calc_enabled).set_false();
     }
 
     sync_sol_value_unchecked(
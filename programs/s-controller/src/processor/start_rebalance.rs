@@ -1,13 +1,18 @@
 use s_controller_interface::{
     start_rebalance_verify_account_keys, start_rebalance_verify_account_privileges,
-    SControllerError, StartRebalanceAccounts, StartRebalanceIxArgs, END_REBALANCE_IX_DISCM,
-    START_REBALANCE_IX_ACCOUNTS_LEN,
+    AdditionalRebalanceDst, SControllerError, StartRebalanceAccounts, StartRebalanceIxArgs,
+    StartRebalanceV2Accounts, StartRebalanceV2IxArgs, END_REBALANCE_IX_DISCM,
+    END_REBALANCE_V2_IX_DISCM, START_REBALANCE_IX_ACCOUNTS_LEN, START_REBALANCE_V2_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED, REBALANCE_RECORD_BUMP, REBALANCE_RECORD_SEED},
-    try_lst_state_list, try_pool_state, try_pool_state_mut, try_rebalance_record_mut,
-    PoolStateAccount, SrcDstLstIndexes, StartRebalanceFreeArgs, U8BoolMut, REBALANCE_RECORD_SIZE,
+    index_to_u32, index_to_usize,
+    program::{
+        POOL_STATE_BUMP, POOL_STATE_SEED, REBALANCE_CONFIG_ID, REBALANCE_RECORD_BUMP,
+        REBALANCE_RECORD_SEED,
+    },
+    rebalance_config_max_rebalance_loss, try_lst_state_list, try_pool_state, try_pool_state_mut,
+    try_rebalance_record_mut, verify_rebalance_loss_within_max, PoolStateAccount, SrcDstLstIndexes,
+    StartRebalanceFreeArgs, U8BoolMut, MAX_ADDITIONAL_REBALANCE_DSTS, REBALANCE_RECORD_SIZE,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -33,16 +38,95 @@ use crate::{
     account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
     cpi::SrcDstLstSolValueCalculatorCpis,
     verify::{
-        verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
-        verify_src_dst_lst_sol_val_calc_cpis, VerifySrcDstLstSolValCalcCpiAccounts,
+        verify_additional_rebalance_dsts, verify_lst_input_not_disabled,
+        verify_not_rebalancing_and_not_disabled, verify_src_dst_lst_sol_val_calc_cpis,
+        AdditionalRebalanceDstSync, VerifySrcDstLstSolValCalcCpiAccounts,
     },
 };
 
 use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
 
+/// Common args of StartRebalance and StartRebalanceV2
+struct StartRebalanceArgs<'a> {
+    src_lst_calc_accs: u8,
+    /// None for StartRebalance, where all accounts after the source LST's
+    /// calculator accounts are the destination LST's calculator accounts
+    dst_lst_calc_accs: Option<u8>,
+    src_lst_index: u32,
+    dst_lst_index: u32,
+    amount: u64,
+    min_starting_src_lst: u64,
+    max_starting_dst_lst: u64,
+    min_sol_value_delta: i64,
+    additional_dsts: &'a [AdditionalRebalanceDst],
+}
+
 pub fn process_start_rebalance(
     accounts: &[AccountInfo],
-    args: StartRebalanceIxArgs,
+    StartRebalanceIxArgs {
+        src_lst_calc_accs,
+        src_lst_index,
+        dst_lst_index,
+        amount,
+        min_starting_src_lst,
+        max_starting_dst_lst,
+    }: StartRebalanceIxArgs,
+) -> ProgramResult {
+    start_rebalance(
+        accounts,
+        START_REBALANCE_IX_ACCOUNTS_LEN,
+        StartRebalanceArgs {
+            src_lst_calc_accs,
+            dst_lst_calc_accs: None,
+            src_lst_index,
+            dst_lst_index,
+            amount,
+            min_starting_src_lst,
+            max_starting_dst_lst,
+            // pool must not lose any SOL value
+            min_sol_value_delta: 0,
+            additional_dsts: &[],
+        },
+    )
+}
+
+pub fn process_start_rebalance_v2(
+    accounts: &[AccountInfo],
+    StartRebalanceV2IxArgs {
+        src_lst_calc_accs,
+        dst_lst_calc_accs,
+        src_lst_index,
+        dst_lst_index,
+        amount,
+        min_starting_src_lst,
+        max_starting_dst_lst,
+        min_sol_value_delta,
+        additional_dsts,
+    }: StartRebalanceV2IxArgs,
+) -> ProgramResult {
+    verify_start_rebalance_v2_loss_tolerance(accounts, min_sol_value_delta)?;
+    start_rebalance(
+        accounts,
+        START_REBALANCE_V2_IX_ACCOUNTS_LEN,
+        StartRebalanceArgs {
+            src_lst_calc_accs,
+            dst_lst_calc_accs: Some(dst_lst_calc_accs),
+            src_lst_index,
+            dst_lst_index,
+            amount,
+            min_starting_src_lst,
+            max_starting_dst_lst,
+            min_sol_value_delta,
+            additional_dsts: &additional_dsts,
+        },
+    )
+}
+
+/// `fixed_accounts_len` is the number of accounts before the calculator accounts suffix
+fn start_rebalance(
+    accounts: &[AccountInfo],
+    fixed_accounts_len: usize,
+    args: StartRebalanceArgs,
 ) -> ProgramResult {
     let (
         accounts,
@@ -54,7 +138,8 @@ pub fn process_start_rebalance(
             src_lst_index,
             dst_lst_index,
         },
-    ) = verify_start_rebalance(accounts, &args)?;
+        additional_dsts,
+    ) = verify_start_rebalance(accounts, fixed_accounts_len, &args)?;
 
     let src_sync_sol_value_accounts =
         SyncSolValueUncheckedAccounts::from(SrcLstPoolReservesOf(accounts));
@@ -64,6 +149,9 @@ pub fn process_start_rebalance(
         dst_lst_cpi,
         dst_lst_index,
     )?;
+    for dst in additional_dsts.iter() {
+        sync_additional_dst_sol_value(&accounts, dst)?;
+    }
 
     let old_total_sol_value = accounts.pool_state.total_sol_value()?;
 
@@ -111,6 +199,16 @@ pub fn process_start_rebalance(
     let rebalance_record = try_rebalance_record_mut(&mut rebalance_record_data)?;
     rebalance_record.dst_lst_index = args.dst_lst_index;
     rebalance_record.old_total_sol_value = old_total_sol_value;
+    rebalance_record.min_sol_value_delta = args.min_sol_value_delta;
+    for (recorded, dst) in rebalance_record
+        .additional_dst_lst_indexes
+        .iter_mut()
+        .zip(additional_dsts.iter())
+    {
+        *recorded = index_to_u32(dst.lst_index)?;
+    }
+    // len checked in verify against MAX_ADDITIONAL_REBALANCE_DSTS
+    rebalance_record.additional_dst_lst_count = additional_dsts.len() as u8;
 
     let mut pool_state_data = accounts.pool_state.try_borrow_mut_data()?;
     let pool_state = try_pool_state_mut(&mut pool_state_data)?;
@@ -119,24 +217,72 @@ pub fn process_start_rebalance(
     Ok(())
 }
 
+fn sync_additional_dst_sol_value(
+    accounts: &StartRebalanceAccounts,
+    AdditionalRebalanceDstSync {
+        pool_reserves,
+        cpi,
+        lst_index,
+    }: &AdditionalRebalanceDstSync,
+) -> Result<(), ProgramError> {
+    sync_sol_value_unchecked(
+        SyncSolValueUncheckedAccounts {
+            pool_reserves,
+            pool_state: accounts.pool_state,
+            lst_state_list: accounts.lst_state_list,
+        },
+        *cpi,
+        *lst_index,
+    )
+}
+
+/// Verifies that the rebalance_config account of StartRebalanceV2 is correct
+/// and that `min_sol_value_delta` is within its max_rebalance_loss
+fn verify_start_rebalance_v2_loss_tolerance(
+    accounts: &[AccountInfo],
+    min_sol_value_delta: i64,
+) -> Result<(), ProgramError> {
+    let StartRebalanceV2Accounts {
+        rebalance_config, ..
+    } = load_accounts(accounts)?;
+    if *rebalance_config.key != REBALANCE_CONFIG_ID {
+        return Err(log_and_return_wrong_acc_err((
+            *rebalance_config.key,
+            REBALANCE_CONFIG_ID,
+        )));
+    }
+    let rebalance_config_bytes = rebalance_config.try_borrow_data()?;
+    let max_rebalance_loss = rebalance_config_max_rebalance_loss(&rebalance_config_bytes)?;
+    verify_rebalance_loss_within_max(min_sol_value_delta, max_rebalance_loss)?;
+    Ok(())
+}
+
 fn verify_start_rebalance<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
-    StartRebalanceIxArgs {
+    fixed_accounts_len: usize,
+    StartRebalanceArgs {
         src_lst_calc_accs,
+        dst_lst_calc_accs,
         src_lst_index,
         dst_lst_index,
         amount: _,
         min_starting_src_lst,
         max_starting_dst_lst,
-    }: &StartRebalanceIxArgs,
+        min_sol_value_delta: _,
+        additional_dsts,
+    }: &StartRebalanceArgs,
 ) -> Result<
     (
         StartRebalanceAccounts<'a, 'info>,
         SrcDstLstSolValueCalculatorCpis<'a, 'info>,
         SrcDstLstIndexes,
+        Vec<AdditionalRebalanceDstSync<'a, 'info>>,
     ),
     ProgramError,
 > {
+    if additional_dsts.len() > MAX_ADDITIONAL_REBALANCE_DSTS {
+        return Err(SControllerError::TooManyRebalanceDsts.into());
+    }
     let src_lst_index = index_to_usize(*src_lst_index)?;
     let dst_lst_index = index_to_usize(*dst_lst_index)?;
 
@@ -173,7 +319,7 @@ fn verify_start_rebalance<'a, 'info>(
     verify_lst_input_not_disabled(&dst_lst_state)?;
 
     let accounts_suffix_slice = accounts
-        .get(START_REBALANCE_IX_ACCOUNTS_LEN..)
+        .get(fixed_accounts_len..)
         .ok_or(ProgramError::NotEnoughAccountKeys)?;
 
     let src_dst_lst_indexes = SrcDstLstIndexes {
@@ -181,21 +327,61 @@ fn verify_start_rebalance<'a, 'info>(
         dst_lst_index,
     };
 
+    // no overflow, u8
+    let src_dst_lst_suffix_slice_end = match dst_lst_calc_accs {
+        Some(dst_lst_calc_accs) => {
+            usize::from(*src_lst_calc_accs) + usize::from(*dst_lst_calc_accs)
+        }
+        None => accounts_suffix_slice.len(),
+    };
+    let src_dst_lst_suffix_slice = accounts_suffix_slice
+        .get(..src_dst_lst_suffix_slice_end)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
     let src_dst_lst_cpis = verify_src_dst_lst_sol_val_calc_cpis(
         VerifySrcDstLstSolValCalcCpiAccounts::from(actual),
-        accounts_suffix_slice,
+        src_dst_lst_suffix_slice,
         *src_lst_calc_accs,
         src_dst_lst_indexes,
     )?;
 
-    verify_has_succeeding_end_rebalance_ix(actual.instructions, *actual.dst_lst_mint.key)?;
+    let additional_dsts_suffix_slice = accounts_suffix_slice
+        .get(src_dst_lst_suffix_slice_end..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let additional_dst_lst_indexes = additional_dsts
+        .iter()
+        .map(|dst| index_to_usize(dst.lst_index))
+        .collect::<Result<Vec<_>, _>>()?;
+    let additional_dsts = verify_additional_rebalance_dsts(
+        actual.lst_state_list,
+        additional_dsts_suffix_slice,
+        additional_dst_lst_indexes
+            .iter()
+            .zip(additional_dsts.iter())
+            .map(|(lst_index, dst)| (*lst_index, dst.lst_calc_accs)),
+    )?;
+    for lst_index in additional_dst_lst_indexes {
+        // lst_index checked in verify_additional_rebalance_dsts()
+        verify_lst_input_not_disabled(&lst_state_list[lst_index])?;
+    }
+
+    verify_has_succeeding_end_rebalance_ix(
+        actual.instructions,
+        *actual.dst_lst_mint.key,
+        *actual.rebalance_authority.key,
+    )?;
 
-    Ok((actual, src_dst_lst_cpis, src_dst_lst_indexes))
+    Ok((
+        actual,
+        src_dst_lst_cpis,
+        src_dst_lst_indexes,
+        additional_dsts,
+    ))
 }
 
 fn verify_has_succeeding_end_rebalance_ix(
     instructions_sysvar: &AccountInfo,
     dst_lst_mint: Pubkey,
+    rebalance_authority: Pubkey,
 ) -> Result<(), ProgramError> {
     let mut next_ix_idx: usize = load_current_index_checked(instructions_sysvar)?.into();
     loop {
@@ -207,10 +393,36 @@ fn verify_has_succeeding_end_rebalance_ix(
         if is_end_rebalance_ix(&next_ix, dst_lst_mint) {
             break;
         }
+        verify_ix_allowed_during_rebalance(&next_ix, rebalance_authority)?;
+    }
+    Ok(())
+}
+
+/// Reserves are drained while the pool is rebalancing,
+/// so pool instructions placed between StartRebalance and EndRebalance
+/// must be signed by the rebalance authority and no one else.
+///
+/// Instructions of other programs are allowed since they carry out the rebalance.
+/// Any pool instruction they CPI is rejected by verify_not_rebalancing_and_not_disabled() instead.
+///
+/// Pool instructions that pass this check still fail if they reject a rebalancing pool themselves,
+/// as all instructions that move pool funds or change SOL values do. What remains for the
+/// rebalance authority are instructions that don't, e.g. AddDonor if it is also the admin.
+fn verify_ix_allowed_during_rebalance(
+    ix: &Instruction,
+    rebalance_authority: Pubkey,
+) -> Result<(), SControllerError> {
+    if ix.program_id != s_controller_lib::program::ID {
+        return Ok(());
+    }
+    let mut signers = ix.accounts.iter().filter(|a| a.is_signer).peekable();
+    if signers.peek().is_none() || signers.any(|a| a.pubkey != rebalance_authority) {
+        return Err(SControllerError::IxNotAllowedDuringRebalance);
     }
     Ok(())
 }
 
+/// Same for EndRebalance and EndRebalanceV2
 const END_REBALANCE_IX_DST_LST_MINT_INDEX: usize = 4;
 
 fn is_end_rebalance_ix(ix: &Instruction, dst_lst_mint: Pubkey) -> bool {
@@ -218,7 +430,7 @@ fn is_end_rebalance_ix(ix: &Instruction, dst_lst_mint: Pubkey) -> bool {
         Some(d) => d,
         None => return false,
     };
-    if *discm != END_REBALANCE_IX_DISCM {
+    if *discm != END_REBALANCE_IX_DISCM && *discm != END_REBALANCE_V2_IX_DISCM {
         return false;
     }
     if ix.program_id != s_controller_lib::program::ID {
@@ -5,12 +5,13 @@ use s_controller_interface::{
 use s_controller_lib::{
     calc_swap_protocol_fees, index_to_usize,
     program::{POOL_STATE_BUMP, POOL_STATE_SEED},
-    try_lst_state_list, try_pool_state, CalcSwapProtocolFeesArgs, PoolStateAccount,
-    SrcDstLstIndexes, SrcDstLstValueCalcAccs, SwapExactInAmounts, SwapFreeArgs,
+    try_lst_state_list, try_pool_state, verify_swap_sol_value_change, CalcSwapProtocolFeesArgs,
+    PoolStateAccount, SrcDstLstIndexes, SrcDstLstValueCalcAccs, SwapExactInAmounts, SwapFreeArgs,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
+use sanctum_s_common::token::{amount_after_transfer_fee, current_epoch_transfer_fee};
 use sanctum_token_lib::{
     token_account_balance, transfer_checked_decimal_agnostic_invoke,
     transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
@@ -22,7 +23,10 @@ use solana_program::{
 use crate::{
     account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
     cpi::{PricingProgramIxArgs, PricingProgramPriceSwapCpi, SrcDstLstSolValueCalculatorCpis},
-    processor::sync_sol_value_unchecked,
+    processor::{
+        sync_sol_value_and_lst_to_sol_with_deposit_unchecked, sync_sol_value_unchecked,
+        sync_sol_value_with_range,
+    },
     verify::{
         verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled, verify_swap_cpis,
         verify_swap_not_same_lst, VerifySwapCpiAccounts,
@@ -49,29 +53,48 @@ pub fn process_swap_exact_in(accounts: &[AccountInfo], args: SwapExactInIxArgs)
         pricing_cpi,
     ) = verify_swap_exact_in(accounts, args)?;
 
+    // Token-2022 transfer fees are deducted from the amount received:
+    // value only the src LST that reaches the pool reserves and
+    // check slippage against the dst LST that reaches the user
+    let src_transfer_fee = current_epoch_transfer_fee(accounts.src_lst_mint)?;
+    let dst_transfer_fee = current_epoch_transfer_fee(accounts.dst_lst_mint)?;
+    let src_lst_in = amount_after_transfer_fee(src_transfer_fee.as_ref(), amount)?;
+
     let src_sync_sol_value_accounts =
         SyncSolValueUncheckedAccounts::from(SrcLstPoolReservesOf(accounts));
     let dst_sync_sol_value_accounts =
         SyncSolValueUncheckedAccounts::from(DstLstPoolReservesOf(accounts));
-    sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
+    // src LST's syncs before and after the transfer are batched with its valuation
+    // in the same calculator CPI since src_lst_in is known upfront.
+    // dst LST must still be synced separately before the pricing CPI since
+    // pricing programs may price from the LSTs' synced SOL values,
+    // and its SolToLst and sync after the transfer depend on the pricing CPI's result
+    let (in_sol_value_range, src_reserves_after_sol_value_range) =
+        sync_sol_value_and_lst_to_sol_with_deposit_unchecked(
+            src_sync_sol_value_accounts,
+            src_lst_cpi,
+            src_lst_index,
+            src_lst_in,
+        )?;
+    let in_sol_value = in_sol_value_range.get_min();
     sync_sol_value_unchecked(dst_sync_sol_value_accounts, dst_lst_cpi, dst_lst_index)?;
 
     let start_total_sol_value = accounts.pool_state.total_sol_value()?;
 
-    let in_sol_value = src_lst_cpi.invoke_lst_to_sol(amount)?.get_min();
     if in_sol_value == 0 {
         return Err(SControllerError::ZeroValue.into());
     }
     let out_sol_value = pricing_cpi.invoke_price_exact_in(PricingProgramIxArgs {
-        amount,
+        amount: src_lst_in,
         sol_value: in_sol_value,
     })?;
     let dst_lst_out = dst_lst_cpi.invoke_sol_to_lst(out_sol_value)?.get_min();
 
-    if dst_lst_out < min_amount_out {
+    let dst_lst_received = amount_after_transfer_fee(dst_transfer_fee.as_ref(), dst_lst_out)?;
+    if dst_lst_received < min_amount_out {
         return Err(SControllerError::SlippageToleranceExceeded.into());
     }
-    if dst_lst_out == 0 {
+    if dst_lst_received == 0 {
         return Err(SControllerError::ZeroValue.into());
     }
 
@@ -100,6 +123,9 @@ pub fn process_swap_exact_in(accounts: &[AccountInfo], args: SwapExactInIxArgs)
         },
         amount,
     )?;
+    // The protocol fee accumulator absorbs any Token-2022 transfer fee on this transfer
+    // instead of it being grossed up, so that the pool reserves lose exactly
+    // to_protocol_fees_lst_amount, which is what the protocol fee was calculated as
     transfer_checked_decimal_agnostic_invoke_signed(
         TransferCheckedAccounts {
             from: accounts.dst_pool_reserves,
@@ -123,13 +149,21 @@ pub fn process_swap_exact_in(accounts: &[AccountInfo], args: SwapExactInIxArgs)
         &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
     )?;
 
-    sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
+    match src_reserves_after_sol_value_range {
+        Some(range) => {
+            sync_sol_value_with_range(src_sync_sol_value_accounts, src_lst_index, range)?
+        }
+        None => sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?,
+    }
     sync_sol_value_unchecked(dst_sync_sol_value_accounts, dst_lst_cpi, dst_lst_index)?;
 
     let end_total_sol_value = accounts.pool_state.total_sol_value()?;
-    if end_total_sol_value < start_total_sol_value {
-        return Err(SControllerError::PoolWouldLoseSolValue.into());
-    }
+    verify_swap_sol_value_change(
+        start_total_sol_value,
+        end_total_sol_value,
+        in_sol_value,
+        out_sol_value,
+    )?;
 
     Ok(())
 }
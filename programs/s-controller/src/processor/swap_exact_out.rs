@@ -5,12 +5,13 @@ use s_controller_interface::{
 use s_controller_lib::{
     calc_swap_protocol_fees, index_to_usize,
     program::{POOL_STATE_BUMP, POOL_STATE_SEED},
-    try_lst_state_list, try_pool_state, CalcSwapProtocolFeesArgs, PoolStateAccount,
-    SrcDstLstIndexes, SrcDstLstValueCalcAccs, SwapExactOutAmounts, SwapFreeArgs,
+    try_lst_state_list, try_pool_state, verify_swap_sol_value_change, CalcSwapProtocolFeesArgs,
+    PoolStateAccount, SrcDstLstIndexes, SrcDstLstValueCalcAccs, SwapExactOutAmounts, SwapFreeArgs,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
+use sanctum_s_common::token::{amount_before_transfer_fee, current_epoch_transfer_fee};
 use sanctum_token_lib::{
     token_account_balance, transfer_checked_decimal_agnostic_invoke,
     transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
@@ -28,7 +29,10 @@ use crate::{
     },
 };
 
-use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+use super::{
+    sync_sol_value_and_lst_to_sol_unchecked, sync_sol_value_unchecked,
+    SyncSolValueUncheckedAccounts,
+};
 
 pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs) -> ProgramResult {
     let (
@@ -48,26 +52,42 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
         pricing_cpi,
     ) = verify_swap_exact_out(accounts, args)?;
 
+    // Token-2022 transfer fees are deducted from the amount received:
+    // the pool must send enough dst LST for the user to receive `amount`
+    // and the user must send enough src LST for the pool reserves to receive src_lst_in
+    let src_transfer_fee = current_epoch_transfer_fee(accounts.src_lst_mint)?;
+    let dst_transfer_fee = current_epoch_transfer_fee(accounts.dst_lst_mint)?;
+    let dst_lst_out = amount_before_transfer_fee(dst_transfer_fee.as_ref(), amount)?;
+
     let src_sync_sol_value_accounts =
         SyncSolValueUncheckedAccounts::from(SrcLstPoolReservesOf(accounts));
     let dst_sync_sol_value_accounts =
         SyncSolValueUncheckedAccounts::from(DstLstPoolReservesOf(accounts));
+    // dst LST's sync is batched with its valuation in the same calculator CPI.
+    // src LST must still be synced before the pricing CPI since
+    // pricing programs may price from the LSTs' synced SOL values
+    let out_sol_value = sync_sol_value_and_lst_to_sol_unchecked(
+        dst_sync_sol_value_accounts,
+        dst_lst_cpi,
+        dst_lst_index,
+        dst_lst_out,
+    )?
+    .get_max();
     sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
-    sync_sol_value_unchecked(dst_sync_sol_value_accounts, dst_lst_cpi, dst_lst_index)?;
 
     let start_total_sol_value = accounts.pool_state.total_sol_value()?;
 
-    let out_sol_value = dst_lst_cpi.invoke_lst_to_sol(amount)?.get_max();
     if out_sol_value == 0 {
         return Err(SControllerError::ZeroValue.into());
     }
     let in_sol_value = pricing_cpi.invoke_price_exact_out(PricingProgramIxArgs {
-        amount,
+        amount: dst_lst_out,
         sol_value: out_sol_value,
     })?;
     let src_lst_in = src_lst_cpi.invoke_sol_to_lst(in_sol_value)?.get_max();
 
-    if src_lst_in > max_amount_in {
+    let src_lst_sent = amount_before_transfer_fee(src_transfer_fee.as_ref(), src_lst_in)?;
+    if src_lst_sent > max_amount_in {
         return Err(SControllerError::SlippageToleranceExceeded.into());
     }
     if src_lst_in == 0 {
@@ -78,11 +98,11 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
     let to_protocol_fees_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
         in_sol_value,
         out_sol_value,
-        dst_lst_out: amount,
+        dst_lst_out,
         trading_protocol_fee_bps,
     })?;
 
-    let total_dst_lst_out = amount
+    let total_dst_lst_out = dst_lst_out
         .checked_add(to_protocol_fees_lst_amount)
         .ok_or(SControllerError::MathError)?;
     if total_dst_lst_out > token_account_balance(accounts.dst_pool_reserves)? {
@@ -97,8 +117,11 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
             authority: accounts.signer,
             mint: accounts.src_lst_mint,
         },
-        src_lst_in,
+        src_lst_sent,
     )?;
+    // The protocol fee accumulator absorbs any Token-2022 transfer fee on this transfer
+    // instead of it being grossed up, so that the pool reserves lose exactly
+    // to_protocol_fees_lst_amount, which is what the protocol fee was calculated as
     transfer_checked_decimal_agnostic_invoke_signed(
         TransferCheckedAccounts {
             from: accounts.dst_pool_reserves,
@@ -118,7 +141,7 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
             authority: accounts.pool_state,
             mint: accounts.dst_lst_mint,
         },
-        amount,
+        dst_lst_out,
         &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
     )?;
 
@@ -126,9 +149,12 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
     sync_sol_value_unchecked(dst_sync_sol_value_accounts, dst_lst_cpi, dst_lst_index)?;
 
     let end_total_sol_value = accounts.pool_state.total_sol_value()?;
-    if end_total_sol_value < start_total_sol_value {
-        return Err(SControllerError::PoolWouldLoseSolValue.into());
-    }
+    verify_swap_sol_value_change(
+        start_total_sol_value,
+        end_total_sol_value,
+        in_sol_value,
+        out_sol_value,
+    )?;
 
     Ok(())
 }
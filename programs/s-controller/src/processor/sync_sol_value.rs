@@ -1,15 +1,16 @@
 use s_controller_interface::{
-    sync_sol_value_verify_account_keys, sync_sol_value_verify_account_privileges,
+    sync_sol_value_verify_account_keys, sync_sol_value_verify_account_privileges, SControllerError,
     SyncSolValueAccounts, SyncSolValueIxArgs, SYNC_SOL_VALUE_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    index_to_usize, sync_sol_value_with_retval, try_lst_state_list_mut, try_pool_state,
-    try_pool_state_mut, SyncSolValueFreeArgs,
+    index_to_usize, sync_sol_value_with_retval, try_lst_state_list, try_lst_state_list_mut,
+    try_pool_state, try_pool_state_mut, SyncSolValueFreeArgs, U8Bool,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
 use sanctum_token_lib::token_account_balance;
+use sanctum_token_ratio::U64ValueRange;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
@@ -59,17 +60,84 @@ where
 
 /// SyncSolValue's full subroutine, exported for use by other instruction processors
 pub fn sync_sol_value_unchecked<'a, 'info>(
-    SyncSolValueUncheckedAccounts {
-        pool_reserves,
-        pool_state,
-        lst_state_list,
-    }: SyncSolValueUncheckedAccounts<'a, 'info>,
+    accounts: SyncSolValueUncheckedAccounts<'a, 'info>,
     cpi: SolValueCalculatorCpi<'a, 'info>,
     lst_index: usize,
 ) -> Result<(), ProgramError> {
-    let lst_balance = token_account_balance(pool_reserves)?;
+    let lst_balance = token_account_balance(accounts.pool_reserves)?;
     let returned_sol_value_range = cpi.invoke_lst_to_sol(lst_balance)?;
+    sync_sol_value_with_range(accounts, lst_index, returned_sol_value_range)
+}
+
+/// [`sync_sol_value_unchecked`] followed by a LstToSol valuation of `lst_amount`.
+///
+/// Both are batched into a single BatchCalc CPI if the LST's SOL value calculator
+/// has been opted into BatchCalc with SetLstBatchCalc,
+/// otherwise separate LstToSol CPIs are made.
+///
+/// Returns the SOL value range of `lst_amount`
+pub fn sync_sol_value_and_lst_to_sol_unchecked<'a, 'info>(
+    accounts: SyncSolValueUncheckedAccounts<'a, 'info>,
+    cpi: SolValueCalculatorCpi<'a, 'info>,
+    lst_index: usize,
+    lst_amount: u64,
+) -> Result<U64ValueRange, ProgramError> {
+    if !is_batch_calc_enabled(accounts, lst_index)? {
+        sync_sol_value_unchecked(accounts, cpi, lst_index)?;
+        return cpi.invoke_lst_to_sol(lst_amount);
+    }
+    let lst_balance = token_account_balance(accounts.pool_reserves)?;
+    let res = cpi.invoke_batch_calc(&[lst_balance, lst_amount], &[])?;
+    sync_sol_value_with_range(accounts, lst_index, res[0])?;
+    Ok(res[1])
+}
+
+/// [`sync_sol_value_and_lst_to_sol_unchecked`] for `lst_amount` about to be deposited into pool reserves.
+///
+/// If the LST has been opted into BatchCalc, the pool reserves' balance after the deposit
+/// is also valued in the same BatchCalc CPI and returned, for the sync after the deposit
+/// to be done with [`sync_sol_value_with_range`] instead of another calculator CPI.
+///
+/// Returns the SOL value range of `lst_amount` and, if batched, that of the pool reserves after the deposit
+pub fn sync_sol_value_and_lst_to_sol_with_deposit_unchecked<'a, 'info>(
+    accounts: SyncSolValueUncheckedAccounts<'a, 'info>,
+    cpi: SolValueCalculatorCpi<'a, 'info>,
+    lst_index: usize,
+    lst_amount: u64,
+) -> Result<(U64ValueRange, Option<U64ValueRange>), ProgramError> {
+    if !is_batch_calc_enabled(accounts, lst_index)? {
+        sync_sol_value_unchecked(accounts, cpi, lst_index)?;
+        return Ok((cpi.invoke_lst_to_sol(lst_amount)?, None));
+    }
+    let lst_balance = token_account_balance(accounts.pool_reserves)?;
+    let lst_balance_after_deposit = lst_balance
+        .checked_add(lst_amount)
+        .ok_or(SControllerError::MathError)?;
+    let res = cpi.invoke_batch_calc(&[lst_balance, lst_amount, lst_balance_after_deposit], &[])?;
+    sync_sol_value_with_range(accounts, lst_index, res[0])?;
+    Ok((res[1], Some(res[2])))
+}
+
+fn is_batch_calc_enabled(
+    SyncSolValueUncheckedAccounts { lst_state_list, .. }: SyncSolValueUncheckedAccounts,
+    lst_index: usize,
+) -> Result<bool, ProgramError> {
+    let lst_state_list_bytes = lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    Ok(U8Bool(lst_state_list[lst_index].is_batch_calc_enabled).is_true())
+}
 
+/// Syncs the LST's SOL value to the min of `returned_sol_value_range`,
+/// a LstToSol result for the pool reserves' current balance
+pub fn sync_sol_value_with_range(
+    SyncSolValueUncheckedAccounts {
+        pool_state,
+        lst_state_list,
+        ..
+    }: SyncSolValueUncheckedAccounts,
+    lst_index: usize,
+    returned_sol_value_range: U64ValueRange,
+) -> Result<(), ProgramError> {
     let mut pool_state_bytes = pool_state.try_borrow_mut_data()?;
     let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
 
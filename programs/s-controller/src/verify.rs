@@ -2,15 +2,16 @@
 
 use s_controller_interface::{LstState, PoolState, SControllerError};
 use s_controller_lib::{
-    try_disable_pool_authority_list, try_find_element_in_list, SrcDstLstIndexes,
-    SrcDstLstValueCalcAccs, U8Bool,
+    try_disable_pool_authority_list, try_find_element_in_list, AdditionalRebalanceDstFreeArgs,
+    SrcDstLstIndexes, SrcDstLstValueCalcAccs, U8Bool, ADDITIONAL_REBALANCE_DST_ACCOUNTS_LEN,
 };
 use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
     account_traits::{
-        GetLstMintAccountInfo, GetLstStateListAccountInfo, GetPoolStateAccountInfo,
-        GetSrcDstLstMintAccountInfo, SrcDstLstMintAccountInfos,
+        GetLstMintAccountInfo, GetLstStateListAccountInfo, GetPoolReservesAccountInfo,
+        GetPoolStateAccountInfo, GetSrcDstLstMintAccountInfo, GetSrcDstLstPoolReservesAccountInfo,
+        SrcDstLstMintAccountInfos, SrcDstLstPoolReservesAccountInfos,
     },
     cpi::{
         PricingProgramPriceLpCpi, PricingProgramPriceSwapCpi, SolValueCalculatorCpi,
@@ -89,17 +90,24 @@ pub fn verify_lst_sol_val_calc_cpi<'a, 'info>(
 #[derive(Clone, Copy, Debug)]
 pub struct VerifyPricingSwapCpiAccounts<'me, 'info> {
     pub pool_state: &'me AccountInfo<'info>,
+    pub lst_state_list: &'me AccountInfo<'info>,
     pub src_dst_lst_mints: SrcDstLstMintAccountInfos<'me, 'info>,
+    pub src_dst_lst_pool_reserves: SrcDstLstPoolReservesAccountInfos<'me, 'info>,
 }
 
 impl<'me, 'info, A> From<A> for VerifyPricingSwapCpiAccounts<'me, 'info>
 where
-    A: GetPoolStateAccountInfo<'me, 'info> + GetSrcDstLstMintAccountInfo<'me, 'info>,
+    A: GetPoolStateAccountInfo<'me, 'info>
+        + GetLstStateListAccountInfo<'me, 'info>
+        + GetSrcDstLstMintAccountInfo<'me, 'info>
+        + GetSrcDstLstPoolReservesAccountInfo<'me, 'info>,
 {
     fn from(ix_accounts: A) -> Self {
         Self {
             pool_state: ix_accounts.get_pool_state_account_info(),
+            lst_state_list: ix_accounts.get_lst_state_list_account_info(),
             src_dst_lst_mints: ix_accounts.get_src_dst_lst_mints(),
+            src_dst_lst_pool_reserves: ix_accounts.get_src_dst_lst_pool_reserves(),
         }
     }
 }
@@ -107,33 +115,45 @@ where
 pub fn verify_pricing_swap_cpi<'a, 'info>(
     VerifyPricingSwapCpiAccounts {
         pool_state,
+        lst_state_list,
         src_dst_lst_mints,
+        src_dst_lst_pool_reserves,
     }: VerifyPricingSwapCpiAccounts<'a, 'info>,
     accounts_suffix_slice: &'a [AccountInfo<'info>],
 ) -> Result<PricingProgramPriceSwapCpi<'a, 'info>, ProgramError> {
     let pricing_program_cpi =
-        PricingProgramPriceSwapCpi::from_src_dst_lst_mints_and_account_suffix_slice(
+        PricingProgramPriceSwapCpi::from_interface_accounts_and_account_suffix_slice(
             src_dst_lst_mints,
+            src_dst_lst_pool_reserves,
+            pool_state,
+            lst_state_list,
             accounts_suffix_slice,
         )?;
-    pricing_program_cpi.verify_correct_pricing_program(pool_state)?;
+    pricing_program_cpi.verify_correct_pricing_program()?;
     Ok(pricing_program_cpi)
 }
 
 #[derive(Clone, Copy, Debug)]
 pub struct VerifyPricingLpCpiAccounts<'me, 'info> {
     pub pool_state: &'me AccountInfo<'info>,
+    pub lst_state_list: &'me AccountInfo<'info>,
     pub lst_mint: &'me AccountInfo<'info>,
+    pub pool_reserves: &'me AccountInfo<'info>,
 }
 
 impl<'me, 'info, A> From<A> for VerifyPricingLpCpiAccounts<'me, 'info>
 where
-    A: GetPoolStateAccountInfo<'me, 'info> + GetLstMintAccountInfo<'me, 'info>,
+    A: GetPoolStateAccountInfo<'me, 'info>
+        + GetLstStateListAccountInfo<'me, 'info>
+        + GetLstMintAccountInfo<'me, 'info>
+        + GetPoolReservesAccountInfo<'me, 'info>,
 {
     fn from(ix_accounts: A) -> Self {
         Self {
             pool_state: ix_accounts.get_pool_state_account_info(),
+            lst_state_list: ix_accounts.get_lst_state_list_account_info(),
             lst_mint: ix_accounts.get_lst_mint_account_info(),
+            pool_reserves: ix_accounts.get_pool_reserves_account_info(),
         }
     }
 }
@@ -141,15 +161,21 @@ where
 pub fn verify_pricing_lp_cpi<'a, 'info>(
     VerifyPricingLpCpiAccounts {
         pool_state,
+        lst_state_list,
         lst_mint,
+        pool_reserves,
     }: VerifyPricingLpCpiAccounts<'a, 'info>,
     accounts_suffix_slice: &'a [AccountInfo<'info>],
 ) -> Result<PricingProgramPriceLpCpi<'a, 'info>, ProgramError> {
-    let pricing_program_cpi = PricingProgramPriceLpCpi::from_lst_mint_and_account_suffix_slice(
-        lst_mint,
-        accounts_suffix_slice,
-    )?;
-    pricing_program_cpi.verify_correct_pricing_program(pool_state)?;
+    let pricing_program_cpi =
+        PricingProgramPriceLpCpi::from_interface_accounts_and_account_suffix_slice(
+            lst_mint,
+            pool_state,
+            lst_state_list,
+            pool_reserves,
+            accounts_suffix_slice,
+        )?;
+    pricing_program_cpi.verify_correct_pricing_program()?;
     Ok(pricing_program_cpi)
 }
 
@@ -221,19 +247,22 @@ pub struct VerifyLpCpiAccounts<'me, 'info> {
     pub lst_state_list: &'me AccountInfo<'info>,
     pub pool_state: &'me AccountInfo<'info>,
     pub lst_mint: &'me AccountInfo<'info>,
+    pub pool_reserves: &'me AccountInfo<'info>,
 }
 
 impl<'me, 'info, A> From<A> for VerifyLpCpiAccounts<'me, 'info>
 where
     A: GetLstStateListAccountInfo<'me, 'info>
         + GetLstMintAccountInfo<'me, 'info>
-        + GetPoolStateAccountInfo<'me, 'info>,
+        + GetPoolStateAccountInfo<'me, 'info>
+        + GetPoolReservesAccountInfo<'me, 'info>,
 {
     fn from(ix_accounts: A) -> Self {
         Self {
             lst_state_list: ix_accounts.get_lst_state_list_account_info(),
             pool_state: ix_accounts.get_pool_state_account_info(),
             lst_mint: ix_accounts.get_lst_mint_account_info(),
+            pool_reserves: ix_accounts.get_pool_reserves_account_info(),
         }
     }
 }
@@ -243,6 +272,7 @@ pub fn verify_lp_cpis<'a, 'info>(
         lst_state_list,
         pool_state,
         lst_mint,
+        pool_reserves,
     }: VerifyLpCpiAccounts<'a, 'info>,
     accounts_suffix_slice: &'a [AccountInfo<'info>],
     lst_value_calc_accs: u8,
@@ -274,7 +304,9 @@ pub fn verify_lp_cpis<'a, 'info>(
     let pricing_cpi = verify_pricing_lp_cpi(
         VerifyPricingLpCpiAccounts {
             pool_state,
+            lst_state_list,
             lst_mint,
+            pool_reserves,
         },
         pricing_accounts_suffix_slice,
     )?;
@@ -287,19 +319,22 @@ pub struct VerifySwapCpiAccounts<'me, 'info> {
     pub lst_state_list: &'me AccountInfo<'info>,
     pub pool_state: &'me AccountInfo<'info>,
     pub src_dst_lst_mints: SrcDstLstMintAccountInfos<'me, 'info>,
+    pub src_dst_lst_pool_reserves: SrcDstLstPoolReservesAccountInfos<'me, 'info>,
 }
 
 impl<'me, 'info, A> From<A> for VerifySwapCpiAccounts<'me, 'info>
 where
     A: GetLstStateListAccountInfo<'me, 'info>
         + GetSrcDstLstMintAccountInfo<'me, 'info>
-        + GetPoolStateAccountInfo<'me, 'info>,
+        + GetPoolStateAccountInfo<'me, 'info>
+        + GetSrcDstLstPoolReservesAccountInfo<'me, 'info>,
 {
     fn from(ix_accounts: A) -> Self {
         Self {
             lst_state_list: ix_accounts.get_lst_state_list_account_info(),
             pool_state: ix_accounts.get_pool_state_account_info(),
             src_dst_lst_mints: ix_accounts.get_src_dst_lst_mints(),
+            src_dst_lst_pool_reserves: ix_accounts.get_src_dst_lst_pool_reserves(),
         }
     }
 }
@@ -309,6 +344,7 @@ pub fn verify_swap_cpis<'a, 'info>(
         lst_state_list,
         pool_state,
         src_dst_lst_mints,
+        src_dst_lst_pool_reserves,
     }: VerifySwapCpiAccounts<'a, 'info>,
     accounts_suffix_slice: &'a [AccountInfo<'info>],
     SrcDstLstValueCalcAccs {
@@ -347,7 +383,9 @@ pub fn verify_swap_cpis<'a, 'info>(
     let pricing_program_cpi = verify_pricing_swap_cpi(
         VerifyPricingSwapCpiAccounts {
             pool_state,
+            lst_state_list,
             src_dst_lst_mints,
+            src_dst_lst_pool_reserves,
         },
         pricing_program_accounts_suffix_slice,
     )?;
@@ -355,6 +393,72 @@ pub fn verify_swap_cpis<'a, 'info>(
     Ok((sol_val_calc_cpis, pricing_program_cpi))
 }
 
+/// A verified additional destination LST of a multi-destination rebalance
+#[derive(Clone, Copy, Debug)]
+pub struct AdditionalRebalanceDstSync<'me, 'info> {
+    pub pool_reserves: &'me AccountInfo<'info>,
+    pub cpi: SolValueCalculatorCpi<'me, 'info>,
+    pub lst_index: usize,
+}
+
+/// Verifies the accounts of a rebalance's additional destination LSTs.
+/// Each destination's accounts are laid out in `accounts_suffix_slice` as
+/// `[lst_mint, pool_reserves, ...lst_value_calc_accs]`,
+/// where `lst_value_calc_accs` starts with the LST's SOL value calculator program.
+///
+/// `dsts` yields (lst_index, lst_value_calc_accs) for each additional destination LST
+pub fn verify_additional_rebalance_dsts<'a, 'info>(
+    lst_state_list: &'a AccountInfo<'info>,
+    accounts_suffix_slice: &'a [AccountInfo<'info>],
+    dsts: impl IntoIterator<Item = (usize, u8)>,
+) -> Result<Vec<AdditionalRebalanceDstSync<'a, 'info>>, ProgramError> {
+    let mut res = Vec::new();
+    let mut dst_accounts_start: usize = 0;
+    for (lst_index, lst_value_calc_accs) in dsts {
+        let calc_accounts_start = dst_accounts_start
+            .checked_add(ADDITIONAL_REBALANCE_DST_ACCOUNTS_LEN)
+            .ok_or(SControllerError::MathError)?;
+        let dst_accounts_end = calc_accounts_start
+            .checked_add(lst_value_calc_accs.into())
+            .ok_or(SControllerError::MathError)?;
+
+        let (lst_mint, pool_reserves) =
+            match accounts_suffix_slice.get(dst_accounts_start..calc_accounts_start) {
+                Some([lst_mint, pool_reserves]) => (lst_mint, pool_reserves),
+                _ => return Err(ProgramError::NotEnoughAccountKeys),
+            };
+        let expected = AdditionalRebalanceDstFreeArgs {
+            lst_index,
+            lst_state_list,
+            lst_mint,
+        }
+        .resolve()?;
+        if *pool_reserves.key != expected.pool_reserves {
+            return Err(SControllerError::InvalidReserves.into());
+        }
+
+        let calc_accounts_suffix_slice = accounts_suffix_slice
+            .get(calc_accounts_start..dst_accounts_end)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let cpi = verify_lst_sol_val_calc_cpi(
+            VerifyLstSolValCalcCpiAccounts {
+                lst_state_list,
+                lst_mint,
+            },
+            calc_accounts_suffix_slice,
+            lst_index,
+        )?;
+
+        res.push(AdditionalRebalanceDstSync {
+            pool_reserves,
+            cpi,
+            lst_index,
+        });
+        dst_accounts_start = dst_accounts_end;
+    }
+    Ok(res)
+}
+
 pub fn verify_swap_not_same_lst(
     src_lst_mint: &AccountInfo,
     dst_lst_mint: &AccountInfo,
@@ -0,0 +1,71 @@
+//! Mock program that CPIs the program in its first account
+//! with the remaining accounts and its own instruction data.
+//! Used to test instructions nested in CPIs.
+
+use solana_program::{
+    account_info::AccountInfo,
+    declare_id,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+
+declare_id!("2jqAGjdbugsz3Lni6hCoV596pkYsK4MTJjmf5YvW9PFY");
+
+pub fn cpi_forwarder_process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (program, ix_accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let ix = Instruction {
+        program_id: *program.key,
+        accounts: ix_accounts
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: *a.key,
+                is_signer: a.is_signer,
+                is_writable: a.is_writable,
+            })
+            .collect(),
+        data: instruction_data.to_vec(),
+    };
+    invoke(&ix, accounts)
+}
+
+/// Wraps `ix` in a CPI forwarder instruction that invokes it via CPI
+pub fn cpi_forwarder_ix(
+    Instruction {
+        program_id,
+        accounts,
+        data,
+    }: Instruction,
+) -> Instruction {
+    let mut forwarder_accounts = vec![AccountMeta::new_readonly(program_id, false)];
+    forwarder_accounts.extend(accounts);
+    Instruction {
+        program_id: ID,
+        accounts: forwarder_accounts,
+        data,
+    }
+}
+
+pub trait CpiForwarderProgramTest {
+    fn add_cpi_forwarder_program(self) -> Self;
+}
+
+impl CpiForwarderProgramTest for ProgramTest {
+    fn add_cpi_forwarder_program(mut self) -> Self {
+        self.add_program(
+            "cpi_forwarder",
+            ID,
+            processor!(cpi_forwarder_process_instruction),
+        );
+        self
+    }
+}
@@ -1,3 +1,7 @@
+mod cpi_forwarder;
+
+pub use cpi_forwarder::{cpi_forwarder_ix, CpiForwarderProgramTest};
+
 use solana_program_test::{processor, ProgramTest};
 
 pub trait SControllerProgramTest {
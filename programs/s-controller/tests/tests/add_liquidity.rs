@@ -1,13 +1,15 @@
+use flat_fee_lib::account_resolvers::PriceLpTokensToMintFreeArgs;
 use flat_fee_test_utils::MockFeeAccountArgs;
 use lido_calculator_lib::lido_sol_val_calc_account_metas;
 use lido_keys::stsol;
 use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
 use marinade_keys::msol;
+use pricing_programs_interface::PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN;
 use s_controller_interface::SControllerError;
 use s_controller_lib::{
-    add_liquidity_ix_full, create_pool_reserves_address, try_lst_state_list, try_pool_state,
-    AddLiquidityByMintFreeArgs, AddLiquidityIxAmts, AddLiquidityIxFullArgs,
-    AddRemoveLiquidityExtraAccounts,
+    add_liquidity_ix_full, create_pool_reserves_address, price_lp_tokens_to_mint_keys,
+    try_lst_state_list, try_pool_state, AddLiquidityByMintFreeArgs, AddLiquidityIxAmts,
+    AddLiquidityIxFullArgs, AddRemoveLiquidityExtraAccounts,
 };
 use s_controller_test_utils::{
     jito_marinade_no_fee_program_test, lido_wsol_flat_fee_program_test,
@@ -95,11 +97,6 @@ async fn basic_add_liquidity_twice_no_fee() {
         .resolve_spl_to_account_metas()
         .unwrap(),
         pricing_program_id: no_fee_pricing_program::ID,
-        pricing_program_accounts: &[AccountMeta {
-            pubkey: jitosol::ID,
-            is_signer: false,
-            is_writable: false,
-        }],
     })
     .await;
 
@@ -112,11 +109,6 @@ async fn basic_add_liquidity_twice_no_fee() {
         lst_calculator_program_id: marinade_calculator_lib::program::ID,
         lst_calculator_accounts: &marinade_sol_val_calc_account_metas(),
         pricing_program_id: no_fee_pricing_program::ID,
-        pricing_program_accounts: &[AccountMeta {
-            pubkey: jitosol::ID,
-            is_signer: false,
-            is_writable: false,
-        }],
     })
     .await;
 }
@@ -145,16 +137,39 @@ async fn basic_add_liquidity_twice_flat_fee() {
         flat_fee_interface::ProgramState {
             manager: Default::default(),
             lp_withdrawal_fee_bps: 1000,
+            lp_deposit_fee_bps: 0,
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         [
             MockFeeAccountArgs {
                 input_fee_bps: 100,
                 output_fee_bps: 100,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
                 lst_mint: stsol::ID,
             },
             MockFeeAccountArgs {
                 input_fee_bps: 100,
                 output_fee_bps: 100,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
                 lst_mint: native_mint::ID,
             },
         ],
@@ -188,8 +203,6 @@ async fn basic_add_liquidity_twice_flat_fee() {
         ..Default::default()
     });
 
-    // not used, set by _ix_full()
-    let flat_fee_add_liq_account_metas: [AccountMeta; 1] = [AccountMeta::default()];
     exec_verify_add_liq_success_no_fees(ExecVerifyAddLiqSuccessNoFeesArgs {
         program_test_ctx: &mut ctx,
         liquidity_provider: &liquidity_provider,
@@ -199,7 +212,6 @@ async fn basic_add_liquidity_twice_flat_fee() {
         lst_calculator_program_id: lido_calculator_lib::program::ID,
         lst_calculator_accounts: &lido_sol_val_calc_account_metas(),
         pricing_program_id: flat_fee_lib::program::ID,
-        pricing_program_accounts: &flat_fee_add_liq_account_metas,
     })
     .await;
 
@@ -212,7 +224,6 @@ async fn basic_add_liquidity_twice_flat_fee() {
         lst_calculator_program_id: wsol_calculator_lib::program::ID,
         lst_calculator_accounts: &WSOL_LST_SOL_COMMON_METAS,
         pricing_program_id: flat_fee_lib::program::ID,
-        pricing_program_accounts: &flat_fee_add_liq_account_metas,
     })
     .await;
 }
@@ -226,7 +237,6 @@ struct ExecVerifyAddLiqSuccessNoFeesArgs<'a> {
     pub lst_calculator_program_id: Pubkey,
     pub lst_calculator_accounts: &'a [AccountMeta],
     pub pricing_program_id: Pubkey,
-    pub pricing_program_accounts: &'a [AccountMeta],
 }
 
 async fn exec_verify_add_liq_success_no_fees(
@@ -244,7 +254,6 @@ async fn exec_verify_add_liq_success_no_fees(
         lst_mint,
         lst_calculator_program_id,
         lst_calculator_accounts,
-        pricing_program_accounts,
         pricing_program_id,
     }: ExecVerifyAddLiqSuccessNoFeesArgs<'_>,
 ) {
@@ -277,6 +286,18 @@ async fn exec_verify_add_liq_success_no_fees(
         },
     };
     let (keys, lst_index, _program_ids) = args.resolve().unwrap();
+    // no-fee only takes the common interface accounts,
+    // flat-fee additionally takes its state and the LST's FeeAccount
+    let common_keys = price_lp_tokens_to_mint_keys(&keys);
+    let pricing_program_price_lp_accounts: Vec<AccountMeta> =
+        if pricing_program_id == flat_fee_lib::program::ID {
+            <[AccountMeta; flat_fee_interface::PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>::from(
+                PriceLpTokensToMintFreeArgs::from(common_keys).resolve(),
+            )
+            .into()
+        } else {
+            <[AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>::from(common_keys).into()
+        };
     let ix = add_liquidity_ix_full(
         keys,
         AddLiquidityIxFullArgs {
@@ -290,7 +311,7 @@ async fn exec_verify_add_liq_success_no_fees(
             lst_calculator_program_id,
             pricing_program_id,
             lst_calculator_accounts,
-            pricing_program_price_lp_accounts: pricing_program_accounts,
+            pricing_program_price_lp_accounts: &pricing_program_price_lp_accounts,
         },
     )
     .unwrap();
@@ -418,6 +439,8 @@ async fn fail_add_liquidity_slippage() {
         },
     };
     let (keys, lst_index, _program_ids) = args.resolve().unwrap();
+    let pricing_program_price_lp_accounts: [AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN] =
+        price_lp_tokens_to_mint_keys(&keys).into();
     let ix = add_liquidity_ix_full(
         keys,
         AddLiquidityIxFullArgs {
@@ -438,11 +461,7 @@ async fn fail_add_liquidity_slippage() {
             }
             .resolve_spl_to_account_metas()
             .unwrap(),
-            pricing_program_price_lp_accounts: &[AccountMeta {
-                pubkey: jitosol::ID,
-                is_signer: false,
-                is_writable: false,
-            }],
+            pricing_program_price_lp_accounts: &pricing_program_price_lp_accounts,
         },
     )
     .unwrap();
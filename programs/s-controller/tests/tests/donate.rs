@@ -0,0 +1,343 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use lido_keys::stsol;
+use s_controller_interface::{add_donor_ix, remove_donor_ix, SControllerError};
+use s_controller_lib::{
+    donate_ix_by_mint_full, program::POOL_STATE_ID, try_donor_allowlist, try_pool_state,
+    AddDonorFreeArgs, DonateByMintFreeArgs, RemoveDonorByPubkeyFreeArgs,
+};
+use s_controller_test_utils::{
+    lido_wsol_flat_fee_program_test, DonorAllowlistBanksClient, DonorAllowlistProgramTest,
+    GenAndAddTokenAccountProgramTest, LidoWsolProgramTestArgs, LstStateListBanksClient,
+    MockPoolState, MockProtocolFeeBps, PoolStateBanksClient, PoolStateProgramTest,
+    DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
+    IntoAccount,
+};
+use sanctum_token_lib::token_account_balance;
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    hash::Hash,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_token::native_mint;
+use test_utils::LIDO_STATE_LAST_UPDATE_EPOCH;
+use wsol_calculator_lib::WSOL_LST_SOL_COMMON_METAS;
+
+use crate::common::*;
+
+const WSOL_RESERVES: u64 = 1_000_000_000;
+const DONATE_AMT: u64 = 500_000_000;
+
+fn lido_wsol_program_test() -> ProgramTest {
+    lido_wsol_flat_fee_program_test(
+        LidoWsolProgramTestArgs {
+            wsol_reserves: WSOL_RESERVES,
+            stsol_sol_value: 0,
+            stsol_reserves: 0,
+            wsol_protocol_fee_accumulator: 0,
+            stsol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: WSOL_RESERVES,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 0,
+            lp_deposit_fee_bps: 0,
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 0,
+                output_fee_bps: 0,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
+                lst_mint: stsol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 0,
+                output_fee_bps: 0,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
+                lst_mint: native_mint::ID,
+            },
+        ],
+        MockProtocolFeeBps { trading: 0, lp: 0 },
+    )
+    .add_s_program()
+}
+
+async fn donate_wsol(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    last_blockhash: Hash,
+    donor: &Keypair,
+    src_lst_acc: Pubkey,
+) -> Result<(), BanksClientError> {
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let wsol_mint_acc = banks_client.get_account_unwrapped(native_mint::ID).await;
+    let ix = donate_ix_by_mint_full(
+        DonateByMintFreeArgs {
+            donor: donor.pubkey(),
+            src_lst_acc,
+            lst_state_list: lst_state_list_acc,
+            lst_mint: KeyedAccount {
+                pubkey: native_mint::ID,
+                account: wsol_mint_acc,
+            },
+        },
+        DONATE_AMT,
+        &WSOL_LST_SOL_COMMON_METAS,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, donor], last_blockhash);
+    banks_client.process_transaction(tx).await
+}
+
+async fn start_with_donor(mut program_test: ProgramTest) -> (ProgramTestContext, Keypair, Pubkey) {
+    let donor = Keypair::new();
+    let donor_wsol_acc = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: native_mint::ID,
+        authority: donor.pubkey(),
+        amount: DONATE_AMT,
+    });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: LIDO_STATE_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    (ctx, donor, donor_wsol_acc)
+}
+
+#[tokio::test]
+async fn basic_donate_permissionless() {
+    let (
+        ProgramTestContext {
+            mut banks_client,
+            last_blockhash,
+            payer,
+            ..
+        },
+        donor,
+        donor_wsol_acc,
+    ) = start_with_donor(lido_wsol_program_test()).await;
+
+    donate_wsol(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        &donor,
+        donor_wsol_acc,
+    )
+    .await
+    .unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(pool_state.total_sol_value, WSOL_RESERVES + DONATE_AMT);
+
+    let donor_wsol_acc = banks_client.get_account_unwrapped(donor_wsol_acc).await;
+    assert_eq!(token_account_balance(donor_wsol_acc).unwrap(), 0);
+}
+
+#[tokio::test]
+async fn donate_allowlisted() {
+    let other_donor = Pubkey::new_unique();
+    let donor = Keypair::new();
+    let mut program_test =
+        lido_wsol_program_test().add_donor_allowlist(&[other_donor, donor.pubkey()]);
+    let donor_wsol_acc = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: native_mint::ID,
+        authority: donor.pubkey(),
+        amount: DONATE_AMT,
+    });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: LIDO_STATE_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    donate_wsol(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        &donor,
+        donor_wsol_acc,
+    )
+    .await
+    .unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(pool_state.total_sol_value, WSOL_RESERVES + DONATE_AMT);
+}
+
+#[tokio::test]
+async fn fail_donate_not_allowlisted() {
+    let (
+        ProgramTestContext {
+            mut banks_client,
+            last_blockhash,
+            payer,
+            ..
+        },
+        donor,
+        donor_wsol_acc,
+    ) = start_with_donor(lido_wsol_program_test().add_donor_allowlist(&[Pubkey::new_unique()]))
+        .await;
+
+    let err = donate_wsol(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        &donor,
+        donor_wsol_acc,
+    )
+    .await
+    .unwrap_err();
+    assert_custom_err(err, SControllerError::UnauthorizedDonor);
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(pool_state.total_sol_value, WSOL_RESERVES);
+}
+
+#[tokio::test]
+async fn add_remove_donor() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool_state_account = MockPoolState(DEFAULT_POOL_STATE).into_account();
+    let donors = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let add_donor_ixs = donors.map(|new_donor| {
+        let keys = AddDonorFreeArgs {
+            payer: payer.pubkey(),
+            new_donor,
+            pool_state_acc: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: pool_state_account.clone(),
+            },
+        }
+        .resolve()
+        .unwrap();
+        add_donor_ix(keys).unwrap()
+    });
+    let mut tx = Transaction::new_with_payer(&add_donor_ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let donor_allowlist_acc = banks_client.get_donor_allowlist_acc().await;
+    let donor_allowlist = try_donor_allowlist(&donor_allowlist_acc.data).unwrap();
+    assert_eq!(donor_allowlist, &donors);
+
+    // duplicates not allowed
+    let mut tx = Transaction::new_with_payer(&add_donor_ixs[..1], Some(&payer.pubkey()));
+    let last_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::DuplicateDonor);
+
+    let (keys, args) = RemoveDonorByPubkeyFreeArgs {
+        refund_rent_to: payer.pubkey(),
+        donor: donors[0],
+        pool_state_acc: pool_state_account.clone(),
+        donor_allowlist: donor_allowlist_acc,
+    }
+    .resolve()
+    .unwrap();
+    let ix = remove_donor_ix(keys, args).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let donor_allowlist_acc = banks_client.get_donor_allowlist_acc().await;
+    let donor_allowlist = try_donor_allowlist(&donor_allowlist_acc.data).unwrap();
+    assert_eq!(donor_allowlist, &donors[1..]);
+}
+
+#[tokio::test]
+async fn remove_last_donor_closes_allowlist() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let allowed_donor = Pubkey::new_unique();
+
+    let (
+        ProgramTestContext {
+            mut banks_client,
+            last_blockhash,
+            payer,
+            ..
+        },
+        donor,
+        donor_wsol_acc,
+    ) = start_with_donor(lido_wsol_program_test().add_donor_allowlist(&[allowed_donor])).await;
+
+    let (keys, args) = RemoveDonorByPubkeyFreeArgs {
+        refund_rent_to: payer.pubkey(),
+        donor: allowed_donor,
+        pool_state_acc: banks_client.get_pool_state_acc().await,
+        donor_allowlist: banks_client.get_donor_allowlist_acc().await,
+    }
+    .resolve()
+    .unwrap();
+    let ix = remove_donor_ix(keys, args).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // list account closed once empty
+    assert!(banks_client
+        .get_account(s_controller_lib::program::DONOR_ALLOWLIST_ID)
+        .await
+        .unwrap()
+        .is_none());
+
+    // donations are permissionless again
+    let last_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    donate_wsol(
+        &mut banks_client,
+        &payer,
+        last_blockhash,
+        &donor,
+        donor_wsol_acc,
+    )
+    .await
+    .unwrap();
+}
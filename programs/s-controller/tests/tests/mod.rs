@@ -2,6 +2,7 @@ mod add_disable_pool_authority;
 mod add_liquidity;
 mod add_lst;
 mod disable_pool;
+mod donate;
 mod enable_disable_lst_input;
 mod enable_pool;
 mod initialize;
@@ -10,6 +11,8 @@ mod remove_disable_pool_authority;
 mod remove_liquidity;
 mod remove_lst;
 mod set_admin;
+mod set_lst_batch_calc;
+mod set_max_rebalance_loss;
 mod set_pricing_program;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
@@ -17,5 +20,7 @@ mod set_rebalance_authority;
 mod set_sol_value_calculator;
 mod swap_exact_in;
 mod swap_exact_out;
+mod swap_sync_before_pricing;
 mod sync_sol_value;
+mod transfer_fee;
 mod withdraw_protocol_fees;
@@ -1,15 +1,25 @@
 use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
 use marinade_keys::msol;
-use s_controller_interface::SControllerError;
+use pricing_programs_interface::PRICE_EXACT_IN_IX_ACCOUNTS_LEN;
+use s_controller_interface::{
+    add_donor_ix, set_max_rebalance_loss_ix, SControllerError, SetMaxRebalanceLossIxArgs,
+    StartRebalanceKeys, START_REBALANCE_IX_ACCOUNTS_LEN,
+};
 use s_controller_lib::{
-    end_rebalance_ix_full,
+    end_rebalance_ix_from_start_rebalance_ix, end_rebalance_ix_full, end_rebalance_v2_ix_full,
+    price_exact_in_keys,
     program::{LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID},
-    start_rebalance_ix_full, try_lst_state_list, try_pool_state,
-    EndRebalanceFromStartRebalanceKeys, SrcDstLstIndexes, SrcDstLstSolValueCalcAccounts,
-    StartRebalanceByMintsFreeArgs, StartRebalanceIxFullArgs, StartRebalanceIxLstAmts, U8Bool,
+    start_rebalance_ix_full, start_rebalance_v2_ix_full, swap_exact_in_ix_by_mint_full,
+    try_donor_allowlist, try_lst_state_list, try_pool_state, AddDonorFreeArgs,
+    AdditionalRebalanceDstByMintFreeArgs, AdditionalRebalanceDstFullArgs,
+    EndRebalanceFromStartRebalanceKeys, SetMaxRebalanceLossFreeArgs, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcAccountSuffixes, SrcDstLstSolValueCalcAccounts,
+    StartRebalanceByMintsFreeArgs, StartRebalanceIxFullArgs, StartRebalanceIxLstAmts,
+    StartRebalanceV2FromStartRebalanceKeys, StartRebalanceV2IxFullArgs, SwapByMintsFreeArgs,
+    SwapExactInAmounts, U8Bool,
 };
 use s_controller_test_utils::{
-    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    jito_marinade_no_fee_program_test, DonorAllowlistBanksClient, GenAndAddTokenAccountProgramTest,
     JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
 };
 use sanctum_solana_test_utils::{
@@ -17,15 +27,22 @@ use sanctum_solana_test_utils::{
     ExtendedBanksClient,
 };
 use sanctum_token_lib::{
-    transfer_checked_ix, MintWithTokenProgram, TransferCheckedArgs, TransferCheckedKeys,
+    token_account_balance, transfer_checked_ix, MintWithTokenProgram, TransferCheckedArgs,
+    TransferCheckedKeys,
 };
 use solana_program::{
-    clock::Clock, instruction::Instruction, program_error::ProgramError, pubkey::Pubkey,
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction,
 };
-use solana_program_test::ProgramTestContext;
+use solana_program_test::{BanksClient, ProgramTestContext};
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::{
     account::Account,
+    hash::Hash,
     signature::{read_keypair_file, Keypair},
     signer::Signer,
     transaction::Transaction,
@@ -33,7 +50,7 @@ use solana_sdk::{
 use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
 use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
 
-use crate::common::SControllerProgramTest;
+use crate::common::{cpi_forwarder_ix, CpiForwarderProgramTest, SControllerProgramTest};
 
 struct CreateRebalanceDonateIxsArgs {
     pub jito_stake_pool_acc: Account,
@@ -46,6 +63,9 @@ struct CreateRebalanceDonateIxsArgs {
     pub msol_donate_amt: u64,
     pub min_starting_src_lst: u64,
     pub max_starting_dst_lst: u64,
+    /// Some to rebalance with StartRebalanceV2 and EndRebalanceV2 instead,
+    /// allowing the pool's SOL value to change by as little as this
+    pub min_sol_value_delta: Option<i64>,
 }
 
 fn create_rebalance_donate_ixs(
@@ -60,6 +80,7 @@ fn create_rebalance_donate_ixs(
         msol_donate_amt,
         min_starting_src_lst,
         max_starting_dst_lst,
+        min_sol_value_delta,
     }: CreateRebalanceDonateIxsArgs,
 ) -> [Instruction; 3] {
     let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
@@ -102,24 +123,39 @@ fn create_rebalance_donate_ixs(
     ) = args.resolve().unwrap();
     let end_rebalance_keys = EndRebalanceFromStartRebalanceKeys(&start_rebalance_keys).resolve();
 
-    let start_rebalance_ix = start_rebalance_ix_full(
-        start_rebalance_keys,
-        StartRebalanceIxFullArgs {
-            src_lst_index,
-            dst_lst_index,
-            lst_amts: StartRebalanceIxLstAmts {
-                amount: jitosol_withdraw_amt,
-                min_starting_src_lst,
-                max_starting_dst_lst,
+    let lst_amts = StartRebalanceIxLstAmts {
+        amount: jitosol_withdraw_amt,
+        min_starting_src_lst,
+        max_starting_dst_lst,
+    };
+    let sol_val_calc_keys = SrcDstLstSolValueCalcAccounts {
+        src_lst_calculator_program_id: spl_calculator_lib::program::ID,
+        dst_lst_calculator_program_id: marinade_calculator_lib::program::ID,
+        src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        dst_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+    };
+    let start_rebalance_ix = match min_sol_value_delta {
+        None => start_rebalance_ix_full(
+            start_rebalance_keys,
+            StartRebalanceIxFullArgs {
+                src_lst_index,
+                dst_lst_index,
+                lst_amts,
             },
-        },
-        SrcDstLstSolValueCalcAccounts {
-            src_lst_calculator_program_id: spl_calculator_lib::program::ID,
-            dst_lst_calculator_program_id: marinade_calculator_lib::program::ID,
-            src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
-            dst_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
-        },
-    )
+            sol_val_calc_keys,
+        ),
+        Some(min_sol_value_delta) => start_rebalance_v2_ix_full(
+            StartRebalanceV2FromStartRebalanceKeys(&start_rebalance_keys).resolve(),
+            StartRebalanceV2IxFullArgs {
+                src_lst_index,
+                dst_lst_index,
+                lst_amts,
+                min_sol_value_delta,
+            },
+            sol_val_calc_keys,
+            &[],
+        ),
+    }
     .unwrap();
 
     let donate_msol_ix = transfer_checked_ix(
@@ -136,11 +172,19 @@ fn create_rebalance_donate_ixs(
         },
     )
     .unwrap();
-    let end_rebalance_ix = end_rebalance_ix_full(
-        end_rebalance_keys,
-        &marinade_sol_val_calc_accounts,
-        marinade_calculator_lib::program::ID,
-    )
+    let end_rebalance_ix = match min_sol_value_delta {
+        None => end_rebalance_ix_full(
+            end_rebalance_keys,
+            &marinade_sol_val_calc_accounts,
+            marinade_calculator_lib::program::ID,
+        ),
+        Some(_) => end_rebalance_v2_ix_full(
+            end_rebalance_keys,
+            &marinade_sol_val_calc_accounts,
+            marinade_calculator_lib::program::ID,
+            &[],
+        ),
+    }
     .unwrap();
     [start_rebalance_ix, donate_msol_ix, end_rebalance_ix]
 }
@@ -208,6 +252,7 @@ async fn rebalance_basic() {
         msol_donate_amt: MSOL_DONATE_AMT,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        min_sol_value_delta: None,
     });
 
     let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
@@ -402,6 +447,7 @@ async fn rebalance_fail_unauthorized() {
         msol_donate_amt: 500_000_000,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        min_sol_value_delta: None,
     });
     // change rebalance authority
     ixs[0].accounts[0].pubkey = unauthorized.pubkey();
@@ -477,6 +523,7 @@ async fn rebalance_fail_not_enough_sol_value_returned() {
         msol_donate_amt: TINY_MSOL_DONATE_AMT,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        min_sol_value_delta: None,
     });
 
     let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
@@ -544,6 +591,7 @@ async fn rebalance_fail_wrong_end_rebalance_dst_lst_mint() {
         msol_donate_amt: 500_000_000,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        min_sol_value_delta: None,
     });
     // change dst_lst_mint of end rebalance ix
     ixs[2].accounts[4].pubkey = jitosol::ID;
@@ -623,6 +671,7 @@ async fn rebalance_fail_slippage_tolerance_exceeded() {
             msol_donate_amt: MSOL_DONATE_AMT,
             min_starting_src_lst,
             max_starting_dst_lst,
+            min_sol_value_delta: None,
         });
 
         let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
@@ -632,3 +681,885 @@ async fn rebalance_fail_slippage_tolerance_exceeded() {
         assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
     }
 }
+
+struct StartedRebalanceProgramTest {
+    banks_client: BanksClient,
+    payer: Keypair,
+    last_blockhash: Hash,
+    mock_auth_kp: Keypair,
+    withdraw_jitosol_to_addr: Pubkey,
+    donate_msol_from_addr: Pubkey,
+}
+
+async fn start_jito_marinade_rebalance_program_test(
+    jitosol_start_sol_value: u64,
+    msol_start_sol_value: u64,
+    msol_donate_amt: u64,
+) -> StartedRebalanceProgramTest {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: jitosol_start_sol_value,
+        msol_sol_value: msol_start_sol_value,
+        jitosol_reserves: jitosol_start_sol_value,
+        msol_reserves: msol_start_sol_value,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program()
+    .add_cpi_forwarder_program();
+
+    let withdraw_jitosol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: msol_donate_amt,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    StartedRebalanceProgramTest {
+        banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    }
+}
+
+async fn set_max_rebalance_loss(
+    StartedRebalanceProgramTest {
+        banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        ..
+    }: &mut StartedRebalanceProgramTest,
+    max_rebalance_loss: u64,
+) {
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let ix = set_max_rebalance_loss_ix(
+        SetMaxRebalanceLossFreeArgs {
+            payer: payer.pubkey(),
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: pool_state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetMaxRebalanceLossIxArgs { max_rebalance_loss },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&*payer, &*mock_auth_kp], *last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn rebalance_fail_sol_value_delta_too_low() {
+    const JITOSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const JITOSOL_WITHDRAW_AMT: u64 = 500_000_000;
+    const MSOL_DONATE_AMT: u64 = 500_000_000;
+    // more than the pool could possibly gain from swapping 0.5 jitoSOL for 0.5 mSOL
+    const MIN_SOL_VALUE_DELTA: i64 = 1_000_000_000;
+
+    let StartedRebalanceProgramTest {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    } = start_jito_marinade_rebalance_program_test(
+        JITOSOL_START_SOL_VALUE,
+        MSOL_START_SOL_VALUE,
+        MSOL_DONATE_AMT,
+    )
+    .await;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+        jito_stake_pool_acc,
+        pool_state_acc,
+        lst_state_list_acc,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+        donate_msol_authority: mock_auth_kp.pubkey(),
+        jitosol_withdraw_amt: JITOSOL_WITHDRAW_AMT,
+        msol_donate_amt: MSOL_DONATE_AMT,
+        min_starting_src_lst: 0,
+        max_starting_dst_lst: u64::MAX,
+        min_sol_value_delta: Some(MIN_SOL_VALUE_DELTA),
+    });
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::RebalanceSolValueDeltaTooLow);
+}
+
+#[tokio::test]
+async fn rebalance_loss_within_tolerance() {
+    const JITOSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const JITOSOL_WITHDRAW_AMT: u64 = 500_000_000;
+    const TINY_MSOL_DONATE_AMT: u64 = 100_000;
+    // tolerate losing up to 1 SOL
+    const MIN_SOL_VALUE_DELTA: i64 = -1_000_000_000;
+    const MAX_REBALANCE_LOSS: u64 = 1_000_000_000;
+
+    let mut started = start_jito_marinade_rebalance_program_test(
+        JITOSOL_START_SOL_VALUE,
+        MSOL_START_SOL_VALUE,
+        TINY_MSOL_DONATE_AMT,
+    )
+    .await;
+    set_max_rebalance_loss(&mut started, MAX_REBALANCE_LOSS).await;
+    let StartedRebalanceProgramTest {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    } = started;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let old_total_sol_value = try_pool_state(&pool_state_acc.data)
+        .unwrap()
+        .total_sol_value;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+        jito_stake_pool_acc,
+        pool_state_acc,
+        lst_state_list_acc,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+        donate_msol_authority: mock_auth_kp.pubkey(),
+        jitosol_withdraw_amt: JITOSOL_WITHDRAW_AMT,
+        msol_donate_amt: TINY_MSOL_DONATE_AMT,
+        min_starting_src_lst: 0,
+        max_starting_dst_lst: u64::MAX,
+        min_sol_value_delta: Some(MIN_SOL_VALUE_DELTA),
+    });
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+    assert!(pool_state.total_sol_value < old_total_sol_value);
+    assert!(
+        i128::from(pool_state.total_sol_value) - i128::from(old_total_sol_value)
+            >= i128::from(MIN_SOL_VALUE_DELTA)
+    );
+
+    let rebalance_record = banks_client.get_account(REBALANCE_RECORD_ID).await.unwrap();
+    assert!(rebalance_record.is_none());
+}
+
+#[tokio::test]
+async fn rebalance_fail_loss_above_max() {
+    const JITOSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const JITOSOL_WITHDRAW_AMT: u64 = 500_000_000;
+    const MSOL_DONATE_AMT: u64 = 500_000_000;
+    const MAX_REBALANCE_LOSS: u64 = 500_000_000;
+    // tolerates losing 1 lamport more than the pool allows
+    const MIN_SOL_VALUE_DELTA: i64 = -500_000_001;
+
+    let mut started = start_jito_marinade_rebalance_program_test(
+        JITOSOL_START_SOL_VALUE,
+        MSOL_START_SOL_VALUE,
+        MSOL_DONATE_AMT,
+    )
+    .await;
+    set_max_rebalance_loss(&mut started, MAX_REBALANCE_LOSS).await;
+    let StartedRebalanceProgramTest {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    } = started;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+        jito_stake_pool_acc,
+        pool_state_acc,
+        lst_state_list_acc,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+        donate_msol_authority: mock_auth_kp.pubkey(),
+        jitosol_withdraw_amt: JITOSOL_WITHDRAW_AMT,
+        msol_donate_amt: MSOL_DONATE_AMT,
+        min_starting_src_lst: 0,
+        max_starting_dst_lst: u64::MAX,
+        min_sol_value_delta: Some(MIN_SOL_VALUE_DELTA),
+    });
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::RebalanceLossTooHigh);
+}
+
+/// Withdraws jitoSOL and splits the proceeds between a mSOL deposit (primary destination)
+/// and returning some of the jitoSOL (additional destination)
+fn create_multi_dst_rebalance_ixs(
+    banks_accounts: [Account; 4],
+    withdraw_jitosol_to_addr: Pubkey,
+    donate_msol_from_addr: Pubkey,
+    authority: Pubkey,
+    jitosol_withdraw_amt: u64,
+    msol_donate_amt: u64,
+    jitosol_return_amt: u64,
+) -> (Instruction, [Instruction; 2], Instruction) {
+    let [jito_stake_pool_acc, pool_state_acc, lst_state_list_acc, jitosol_mint_acc] =
+        banks_accounts;
+
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let (additional_dst_keys, additional_dst_lst_index, additional_dst_calc_program_id) =
+        AdditionalRebalanceDstByMintFreeArgs {
+            lst_state_list: lst_state_list_acc.clone(),
+            lst_mint: KeyedAccount {
+                pubkey: jitosol::ID,
+                account: jitosol_mint_acc,
+            },
+        }
+        .resolve()
+        .unwrap();
+
+    let (
+        start_rebalance_keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        _program_ids,
+    ) = StartRebalanceByMintsFreeArgs {
+        withdraw_to: withdraw_jitosol_to_addr,
+        lst_state_list: KeyedAccount {
+            pubkey: LST_STATE_LIST_ID,
+            account: lst_state_list_acc,
+        },
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: pool_state_acc,
+        },
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let end_rebalance_keys = EndRebalanceFromStartRebalanceKeys(&start_rebalance_keys).resolve();
+
+    let start_rebalance_ix = start_rebalance_v2_ix_full(
+        StartRebalanceV2FromStartRebalanceKeys(&start_rebalance_keys).resolve(),
+        StartRebalanceV2IxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            lst_amts: StartRebalanceIxLstAmts {
+                amount: jitosol_withdraw_amt,
+                min_starting_src_lst: 0,
+                max_starting_dst_lst: u64::MAX,
+            },
+            min_sol_value_delta: 0,
+        },
+        SrcDstLstSolValueCalcAccounts {
+            src_lst_calculator_program_id: spl_calculator_lib::program::ID,
+            dst_lst_calculator_program_id: marinade_calculator_lib::program::ID,
+            src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+        &[AdditionalRebalanceDstFullArgs {
+            lst_index: additional_dst_lst_index,
+            keys: additional_dst_keys,
+            lst_calculator_program_id: additional_dst_calc_program_id,
+            lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        }],
+    )
+    .unwrap();
+
+    let donate_msol_ix = transfer_checked_ix(
+        TransferCheckedKeys {
+            token_program: spl_token::ID,
+            from: donate_msol_from_addr,
+            to: end_rebalance_keys.dst_pool_reserves,
+            authority,
+            mint: msol::ID,
+        },
+        TransferCheckedArgs {
+            amount: msol_donate_amt,
+            decimals: 9,
+        },
+    )
+    .unwrap();
+    let return_jitosol_ix = transfer_checked_ix(
+        TransferCheckedKeys {
+            token_program: spl_token::ID,
+            from: withdraw_jitosol_to_addr,
+            to: additional_dst_keys.pool_reserves,
+            authority,
+            mint: jitosol::ID,
+        },
+        TransferCheckedArgs {
+            amount: jitosol_return_amt,
+            decimals: 9,
+        },
+    )
+    .unwrap();
+
+    let end_rebalance_ix = end_rebalance_ix_from_start_rebalance_ix(&start_rebalance_ix).unwrap();
+
+    (
+        start_rebalance_ix,
+        [donate_msol_ix, return_jitosol_ix],
+        end_rebalance_ix,
+    )
+}
+
+#[tokio::test]
+async fn rebalance_multi_dst() {
+    const JITOSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const JITOSOL_WITHDRAW_AMT: u64 = 500_000_000;
+    const MSOL_DONATE_AMT: u64 = 300_000_000;
+    const JITOSOL_RETURN_AMT: u64 = 250_000_000;
+
+    let StartedRebalanceProgramTest {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    } = start_jito_marinade_rebalance_program_test(
+        JITOSOL_START_SOL_VALUE,
+        MSOL_START_SOL_VALUE,
+        MSOL_DONATE_AMT,
+    )
+    .await;
+
+    let banks_accounts = [
+        banks_client
+            .get_account_unwrapped(jito_stake_pool::ID)
+            .await,
+        banks_client.get_pool_state_acc().await,
+        banks_client.get_lst_state_list_acc().await,
+        banks_client.get_account_unwrapped(jitosol::ID).await,
+    ];
+
+    let (start_rebalance_ix, [donate_msol_ix, return_jitosol_ix], end_rebalance_ix) =
+        create_multi_dst_rebalance_ixs(
+            banks_accounts,
+            withdraw_jitosol_to_addr,
+            donate_msol_from_addr,
+            mock_auth_kp.pubkey(),
+            JITOSOL_WITHDRAW_AMT,
+            MSOL_DONATE_AMT,
+            JITOSOL_RETURN_AMT,
+        );
+
+    let mut tx = Transaction::new_with_payer(
+        &[
+            start_rebalance_ix,
+            donate_msol_ix,
+            return_jitosol_ix,
+            end_rebalance_ix,
+        ],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+    assert!(pool_state.total_sol_value >= JITOSOL_START_SOL_VALUE + MSOL_START_SOL_VALUE);
+
+    let withdraw_jitosol_to = banks_client
+        .get_account_unwrapped(withdraw_jitosol_to_addr)
+        .await;
+    assert_eq!(
+        token_account_balance(withdraw_jitosol_to).unwrap(),
+        JITOSOL_WITHDRAW_AMT - JITOSOL_RETURN_AMT
+    );
+
+    let rebalance_record = banks_client.get_account(REBALANCE_RECORD_ID).await.unwrap();
+    assert!(rebalance_record.is_none());
+}
+
+#[tokio::test]
+async fn rebalance_fail_multi_dst_end_missing_additional_dst() {
+    const JITOSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const JITOSOL_WITHDRAW_AMT: u64 = 500_000_000;
+    const MSOL_DONATE_AMT: u64 = 300_000_000;
+    const JITOSOL_RETURN_AMT: u64 = 250_000_000;
+
+    let StartedRebalanceProgramTest {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    } = start_jito_marinade_rebalance_program_test(
+        JITOSOL_START_SOL_VALUE,
+        MSOL_START_SOL_VALUE,
+        MSOL_DONATE_AMT,
+    )
+    .await;
+
+    let banks_accounts = [
+        banks_client
+            .get_account_unwrapped(jito_stake_pool::ID)
+            .await,
+        banks_client.get_pool_state_acc().await,
+        banks_client.get_lst_state_list_acc().await,
+        banks_client.get_account_unwrapped(jitosol::ID).await,
+    ];
+
+    let (start_rebalance_ix, [donate_msol_ix, return_jitosol_ix], _end_rebalance_ix) =
+        create_multi_dst_rebalance_ixs(
+            banks_accounts,
+            withdraw_jitosol_to_addr,
+            donate_msol_from_addr,
+            mock_auth_kp.pubkey(),
+            JITOSOL_WITHDRAW_AMT,
+            MSOL_DONATE_AMT,
+            JITOSOL_RETURN_AMT,
+        );
+    // legacy EndRebalance, which can only sync the primary destination
+    let end_rebalance_ix = end_rebalance_ix_full(
+        EndRebalanceFromStartRebalanceKeys(&StartRebalanceKeys::from(
+            <[Pubkey; START_REBALANCE_IX_ACCOUNTS_LEN]>::try_from(
+                start_rebalance_ix.accounts[..START_REBALANCE_IX_ACCOUNTS_LEN]
+                    .iter()
+                    .map(|meta| meta.pubkey)
+                    .collect::<Vec<_>>(),
+            )
+            .unwrap(),
+        ))
+        .resolve(),
+        &marinade_sol_val_calc_account_metas(),
+        marinade_calculator_lib::program::ID,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(
+        &[
+            start_rebalance_ix,
+            donate_msol_ix,
+            return_jitosol_ix,
+            end_rebalance_ix,
+        ],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::IncorrectRebalanceDsts);
+}
+
+/// Swaps mSOL from donate_msol_from_addr into jitoSOL at withdraw_jitosol_to_addr
+fn create_swap_msol_to_jitosol_ix(
+    jito_stake_pool_acc: Account,
+    lst_state_list_acc: Account,
+    donate_msol_from_addr: Pubkey,
+    withdraw_jitosol_to_addr: Pubkey,
+    authority: Pubkey,
+    msol_swap_amt: u64,
+) -> Instruction {
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let free_args = SwapByMintsFreeArgs {
+        signer: authority,
+        src_lst_acc: donate_msol_from_addr,
+        dst_lst_acc: withdraw_jitosol_to_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: lst_state_list_acc,
+    };
+    let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+    let pricing_program_accounts: [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] =
+        price_exact_in_keys(&keys).into();
+    swap_exact_in_ix_by_mint_full(
+        free_args,
+        SwapExactInAmounts {
+            min_amount_out: 0,
+            amount: msol_swap_amt,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        },
+        &pricing_program_accounts,
+        no_fee_pricing_program::ID,
+    )
+    .unwrap()
+}
+
+const SANDWICH_JITOSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+const SANDWICH_MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+const SANDWICH_JITOSOL_WITHDRAW_AMT: u64 = 500_000_000;
+const SANDWICH_MSOL_DONATE_AMT: u64 = 500_000_000;
+const SANDWICH_MSOL_SWAP_AMT: u64 = 1_000_000;
+
+/// Returns [start_rebalance_ix, swap_ix, donate_msol_ix, end_rebalance_ix]
+async fn create_sandwiched_swap_ixs(
+    banks_client: &mut BanksClient,
+    mock_auth_kp: &Keypair,
+    swap_signer: Pubkey,
+    withdraw_jitosol_to_addr: Pubkey,
+    donate_msol_from_addr: Pubkey,
+) -> [Instruction; 4] {
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let swap_ix = create_swap_msol_to_jitosol_ix(
+        jito_stake_pool_acc.clone(),
+        lst_state_list_acc.clone(),
+        donate_msol_from_addr,
+        withdraw_jitosol_to_addr,
+        swap_signer,
+        SANDWICH_MSOL_SWAP_AMT,
+    );
+    let [start_rebalance_ix, donate_msol_ix, end_rebalance_ix] =
+        create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+            jito_stake_pool_acc,
+            pool_state_acc,
+            lst_state_list_acc,
+            withdraw_jitosol_to_addr,
+            donate_msol_from_addr,
+            donate_msol_authority: mock_auth_kp.pubkey(),
+            jitosol_withdraw_amt: SANDWICH_JITOSOL_WITHDRAW_AMT,
+            msol_donate_amt: SANDWICH_MSOL_DONATE_AMT,
+            min_starting_src_lst: 0,
+            max_starting_dst_lst: u64::MAX,
+            min_sol_value_delta: None,
+        });
+    [
+        start_rebalance_ix,
+        swap_ix,
+        donate_msol_ix,
+        end_rebalance_ix,
+    ]
+}
+
+#[tokio::test]
+async fn rebalance_fail_swap_sandwiched() {
+    let StartedRebalanceProgramTest {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    } = start_jito_marinade_rebalance_program_test(
+        SANDWICH_JITOSOL_START_SOL_VALUE,
+        SANDWICH_MSOL_START_SOL_VALUE,
+        SANDWICH_MSOL_DONATE_AMT + SANDWICH_MSOL_SWAP_AMT,
+    )
+    .await;
+
+    let swapper = Keypair::new();
+    let ixs = create_sandwiched_swap_ixs(
+        &mut banks_client,
+        &mock_auth_kp,
+        swapper.pubkey(),
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    )
+    .await;
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp, &swapper], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::IxNotAllowedDuringRebalance);
+}
+
+#[tokio::test]
+async fn rebalance_fail_rebalance_authority_swap_sandwiched() {
+    let StartedRebalanceProgramTest {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    } = start_jito_marinade_rebalance_program_test(
+        SANDWICH_JITOSOL_START_SOL_VALUE,
+        SANDWICH_MSOL_START_SOL_VALUE,
+        SANDWICH_MSOL_DONATE_AMT + SANDWICH_MSOL_SWAP_AMT,
+    )
+    .await;
+
+    let ixs = create_sandwiched_swap_ixs(
+        &mut banks_client,
+        &mock_auth_kp,
+        mock_auth_kp.pubkey(),
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    )
+    .await;
+
+    // mock_auth_kp is also the rebalance authority,
+    // so StartRebalance allows the swap but the swap itself
+    // still checks that the pool is not rebalancing
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::PoolRebalancing);
+}
+
+#[tokio::test]
+async fn rebalance_rebalance_authority_admin_ix_sandwiched() {
+    let StartedRebalanceProgramTest {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    } = start_jito_marinade_rebalance_program_test(
+        SANDWICH_JITOSOL_START_SOL_VALUE,
+        SANDWICH_MSOL_START_SOL_VALUE,
+        SANDWICH_MSOL_DONATE_AMT,
+    )
+    .await;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let [start_rebalance_ix, donate_msol_ix, end_rebalance_ix] =
+        create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+            jito_stake_pool_acc,
+            pool_state_acc: pool_state_acc.clone(),
+            lst_state_list_acc,
+            withdraw_jitosol_to_addr,
+            donate_msol_from_addr,
+            donate_msol_authority: mock_auth_kp.pubkey(),
+            jitosol_withdraw_amt: SANDWICH_JITOSOL_WITHDRAW_AMT,
+            msol_donate_amt: SANDWICH_MSOL_DONATE_AMT,
+            min_starting_src_lst: 0,
+            max_starting_dst_lst: u64::MAX,
+            min_sol_value_delta: None,
+        });
+    // mock_auth_kp is both the admin and the rebalance authority,
+    // so an AddDonor signed and paid for by it alone is allowed between
+    // StartRebalance and EndRebalance. AddDonor does not check is_rebalancing.
+    let new_donor = Pubkey::new_unique();
+    let add_donor_ix = add_donor_ix(
+        AddDonorFreeArgs {
+            payer: mock_auth_kp.pubkey(),
+            new_donor,
+            pool_state_acc: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: pool_state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+    let fund_mock_auth_ix =
+        system_instruction::transfer(&payer.pubkey(), &mock_auth_kp.pubkey(), LAMPORTS_PER_SOL);
+
+    let mut tx = Transaction::new_with_payer(
+        &[
+            fund_mock_auth_ix,
+            start_rebalance_ix,
+            add_donor_ix,
+            donate_msol_ix,
+            end_rebalance_ix,
+        ],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+
+    let donor_allowlist_acc = banks_client.get_donor_allowlist_acc().await;
+    let donor_allowlist = try_donor_allowlist(&donor_allowlist_acc.data).unwrap();
+    assert_eq!(donor_allowlist, &[new_donor]);
+}
+
+#[tokio::test]
+async fn rebalance_fail_cpi_swap_sandwiched() {
+    let StartedRebalanceProgramTest {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    } = start_jito_marinade_rebalance_program_test(
+        SANDWICH_JITOSOL_START_SOL_VALUE,
+        SANDWICH_MSOL_START_SOL_VALUE,
+        SANDWICH_MSOL_DONATE_AMT + SANDWICH_MSOL_SWAP_AMT,
+    )
+    .await;
+
+    let swapper = Keypair::new();
+    let [start_rebalance_ix, swap_ix, donate_msol_ix, end_rebalance_ix] =
+        create_sandwiched_swap_ixs(
+            &mut banks_client,
+            &mock_auth_kp,
+            swapper.pubkey(),
+            withdraw_jitosol_to_addr,
+            donate_msol_from_addr,
+        )
+        .await;
+
+    // top-level instruction belongs to another program,
+    // so the swap can only be caught when it's invoked,
+    // by its own check that the pool is not rebalancing
+    let mut tx = Transaction::new_with_payer(
+        &[
+            start_rebalance_ix,
+            cpi_forwarder_ix(swap_ix),
+            donate_msol_ix,
+            end_rebalance_ix,
+        ],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &mock_auth_kp, &swapper], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::PoolRebalancing);
+}
+
+#[tokio::test]
+async fn rebalance_fail_cpi_start_rebalance_swap_sandwiched() {
+    let StartedRebalanceProgramTest {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        mock_auth_kp,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+    } = start_jito_marinade_rebalance_program_test(
+        SANDWICH_JITOSOL_START_SOL_VALUE,
+        SANDWICH_MSOL_START_SOL_VALUE,
+        SANDWICH_MSOL_DONATE_AMT + SANDWICH_MSOL_SWAP_AMT,
+    )
+    .await;
+
+    let swapper = Keypair::new();
+    let [start_rebalance_ix, swap_ix, donate_msol_ix, end_rebalance_ix] =
+        create_sandwiched_swap_ixs(
+            &mut banks_client,
+            &mock_auth_kp,
+            swapper.pubkey(),
+            withdraw_jitosol_to_addr,
+            donate_msol_from_addr,
+        )
+        .await;
+
+    // StartRebalance invoked via CPI should still check the top-level instructions following it
+    let mut tx = Transaction::new_with_payer(
+        &[
+            cpi_forwarder_ix(start_rebalance_ix),
+            swap_ix,
+            donate_msol_ix,
+            end_rebalance_ix,
+        ],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &mock_auth_kp, &swapper], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::IxNotAllowedDuringRebalance);
+}
@@ -1,10 +1,12 @@
 use flat_fee_lib::account_resolvers::PriceLpTokensToRedeemFreeArgs;
 use flat_fee_test_utils::MockFeeAccountArgs;
 use lido_keys::stsol;
+use pricing_programs_interface::PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN;
 use s_controller_interface::SControllerError;
 use s_controller_lib::{
-    remove_liquidity_ix_full, try_pool_state, AddRemoveLiquidityExtraAccounts,
-    RemoveLiquidityByMintFreeArgs, RemoveLiquidityIxAmts, RemoveLiquidityIxFullArgs,
+    price_lp_tokens_to_redeem_keys, remove_liquidity_ix_full, try_pool_state,
+    AddRemoveLiquidityExtraAccounts, RemoveLiquidityByMintFreeArgs, RemoveLiquidityIxAmts,
+    RemoveLiquidityIxFullArgs,
 };
 use s_controller_test_utils::{
     jito_marinade_no_fee_program_test, lido_wsol_flat_fee_program_test,
@@ -89,6 +91,8 @@ async fn basic_redeem_full_no_fees() {
         },
     };
     let (keys, lst_index, _program_ids) = args.resolve().unwrap();
+    let pricing_program_price_lp_accounts: [AccountMeta;
+        PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN] = price_lp_tokens_to_redeem_keys(&keys).into();
     let pool_reserves = keys.pool_reserves;
     let protocol_fee_accumulator = keys.protocol_fee_accumulator;
     let ix = remove_liquidity_ix_full(
@@ -111,11 +115,7 @@ async fn basic_redeem_full_no_fees() {
             }
             .resolve_spl_to_account_metas()
             .unwrap(),
-            pricing_program_price_lp_accounts: &[AccountMeta {
-                pubkey: jitosol::ID,
-                is_signer: false,
-                is_writable: false,
-            }],
+            pricing_program_price_lp_accounts: &pricing_program_price_lp_accounts,
         },
     )
     .unwrap();
@@ -183,16 +183,39 @@ async fn basic_redeem_full_flat_fees() {
         flat_fee_interface::ProgramState {
             manager: Pubkey::default(),
             lp_withdrawal_fee_bps: LP_WITHDRAWAL_FEE_BPS,
+            lp_deposit_fee_bps: 0,
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         [
             MockFeeAccountArgs {
                 input_fee_bps: Default::default(),
                 output_fee_bps: Default::default(),
+                lp_deposit_fee_bps: Default::default(),
+                lp_withdrawal_fee_bps: None,
                 lst_mint: native_mint::ID,
             },
             MockFeeAccountArgs {
                 input_fee_bps: Default::default(),
                 output_fee_bps: Default::default(),
+                lp_deposit_fee_bps: Default::default(),
+                lp_withdrawal_fee_bps: None,
                 lst_mint: stsol::ID,
             },
         ],
@@ -247,9 +270,9 @@ async fn basic_redeem_full_flat_fees() {
             lst_calculator_program_id: wsol_calculator_lib::program::ID,
             lst_calculator_accounts: &WSOL_LST_SOL_COMMON_METAS,
             pricing_program_id: flat_fee_lib::program::ID,
-            pricing_program_price_lp_accounts: &PriceLpTokensToRedeemFreeArgs {
-                output_lst_mint: native_mint::ID,
-            }
+            pricing_program_price_lp_accounts: &PriceLpTokensToRedeemFreeArgs::from(
+                price_lp_tokens_to_redeem_keys(&keys),
+            )
             .resolve_to_account_metas(),
         },
     )
@@ -363,6 +386,8 @@ async fn fail_remove_liquidity_slippage() {
         },
     };
     let (keys, lst_index, _program_ids) = args.resolve().unwrap();
+    let pricing_program_price_lp_accounts: [AccountMeta;
+        PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN] = price_lp_tokens_to_redeem_keys(&keys).into();
     let ix = remove_liquidity_ix_full(
         keys,
         RemoveLiquidityIxFullArgs {
@@ -383,11 +408,7 @@ async fn fail_remove_liquidity_slippage() {
             }
             .resolve_spl_to_account_metas()
             .unwrap(),
-            pricing_program_price_lp_accounts: &[AccountMeta {
-                pubkey: jitosol::ID,
-                is_signer: false,
-                is_writable: false,
-            }],
+            pricing_program_price_lp_accounts: &pricing_program_price_lp_accounts,
         },
     )
     .unwrap();
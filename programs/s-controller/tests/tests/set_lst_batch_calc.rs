@@ -0,0 +1,245 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use pricing_programs_interface::{PRICE_EXACT_IN_IX_ACCOUNTS_LEN, PRICE_EXACT_OUT_IX_ACCOUNTS_LEN};
+use s_controller_lib::{
+    price_exact_in_keys, price_exact_out_keys, set_lst_batch_calc_ix_by_mint_full,
+    swap_exact_in_ix_by_mint_full, swap_exact_out_ix_by_mint_full,
+    DisableEnableLstInputByMintFreeArgs, SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs,
+    SwapExactInAmounts, SwapExactOutAmounts, U8Bool,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::*;
+
+const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_STARTING_BALANCE: u64 = 1_000_000_000;
+const JITOSOL_TO_RECEIVE: u64 = 500_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SwapKind {
+    ExactIn,
+    ExactOut,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SwapResult {
+    msol_sent: u64,
+    jitosol_received: u64,
+    compute_units_consumed: u64,
+}
+
+async fn assert_batch_calc_enabled(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    expected: bool,
+) {
+    let lst_state = banks_client.get_lst_state(lst_mint).await;
+    assert_eq!(U8Bool(lst_state.is_batch_calc_enabled).is_true(), expected);
+}
+
+async fn set_batch_calc(ctx: &mut ProgramTestContext, lst_mint: Pubkey, is_enabled: bool) {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let args = DisableEnableLstInputByMintFreeArgs {
+        lst_mint,
+        pool_state: ctx.banks_client.get_pool_state_acc().await,
+        lst_state_list: ctx.banks_client.get_lst_state_list_acc().await,
+    };
+    let ix = set_lst_batch_calc_ix_by_mint_full(&args, is_enabled).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer, &mock_auth_kp], ctx.last_blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn basic_enable_then_disable() {
+    let program_test = jito_marinade_no_fee_program_test(
+        JitoMarinadeProgramTestArgs::default().with_lp_token_mint(Pubkey::new_unique()),
+    )
+    .add_s_program();
+    let mut ctx = program_test.start_with_context().await;
+
+    assert_batch_calc_enabled(&mut ctx.banks_client, jitosol::ID, false).await;
+
+    set_batch_calc(&mut ctx, jitosol::ID, true).await;
+    assert_batch_calc_enabled(&mut ctx.banks_client, jitosol::ID, true).await;
+    assert_batch_calc_enabled(&mut ctx.banks_client, msol::ID, false).await;
+
+    set_batch_calc(&mut ctx, jitosol::ID, false).await;
+    assert_batch_calc_enabled(&mut ctx.banks_client, jitosol::ID, false).await;
+}
+
+/// Swaps mSOL for jitoSOL, exact in MSOL_STARTING_BALANCE or exact out JITOSOL_TO_RECEIVE
+async fn swap_msol_for_jitosol(kind: SwapKind, is_batch_calc_enabled: bool) -> SwapResult {
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+        // dont cares
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_STARTING_BALANCE,
+    });
+
+    let mut ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    if is_batch_calc_enabled {
+        for lst_mint in [jitosol::ID, msol::ID] {
+            set_batch_calc(&mut ctx, lst_mint, true).await;
+        }
+    }
+
+    let jito_stake_pool_acc = ctx
+        .banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_msol_acc_addr,
+        dst_lst_acc: swapper_jitosol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: ctx.banks_client.get_lst_state_list_acc().await,
+    };
+    let calc_accounts = SrcDstLstSolValueCalcAccountSuffixes {
+        dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+    };
+    let ix = match kind {
+        SwapKind::ExactIn => {
+            let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+            let pricing_program_accounts: [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] =
+                price_exact_in_keys(&keys).into();
+            swap_exact_in_ix_by_mint_full(
+                free_args,
+                SwapExactInAmounts {
+                    min_amount_out: 0,
+                    amount: MSOL_STARTING_BALANCE,
+                },
+                calc_accounts,
+                &pricing_program_accounts,
+                no_fee_pricing_program::ID,
+            )
+            .unwrap()
+        }
+        SwapKind::ExactOut => {
+            let (keys, _, _) = free_args.resolve_exact_out().unwrap();
+            let pricing_program_accounts: [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN] =
+                price_exact_out_keys(&keys).into();
+            swap_exact_out_ix_by_mint_full(
+                free_args,
+                SwapExactOutAmounts {
+                    max_amount_in: MSOL_STARTING_BALANCE,
+                    amount: JITOSOL_TO_RECEIVE,
+                },
+                calc_accounts,
+                &pricing_program_accounts,
+                no_fee_pricing_program::ID,
+            )
+            .unwrap()
+        }
+    };
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer, &swapper], ctx.last_blockhash);
+    let res = ctx
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    res.result.unwrap();
+    let compute_units_consumed = res.metadata.unwrap().compute_units_consumed;
+
+    let msol_account = ctx
+        .banks_client
+        .get_account_unwrapped(swapper_msol_acc_addr)
+        .await;
+    let jitosol_account = ctx
+        .banks_client
+        .get_account_unwrapped(swapper_jitosol_acc_addr)
+        .await;
+    SwapResult {
+        msol_sent: MSOL_STARTING_BALANCE - token_account_balance(msol_account).unwrap(),
+        jitosol_received: token_account_balance(jitosol_account).unwrap(),
+        compute_units_consumed,
+    }
+}
+
+async fn assert_batch_calc_same_result_fewer_cus(kind: SwapKind) {
+    let separate = swap_msol_for_jitosol(kind, false).await;
+    let batched = swap_msol_for_jitosol(kind, true).await;
+    assert!(separate.jitosol_received > 0);
+    assert_eq!(separate.msol_sent, batched.msol_sent);
+    assert_eq!(separate.jitosol_received, batched.jitosol_received);
+    assert!(
+        batched.compute_units_consumed < separate.compute_units_consumed,
+        "{kind:?}: batched {} CUs, separate {} CUs",
+        batched.compute_units_consumed,
+        separate.compute_units_consumed,
+    );
+}
+
+#[tokio::test]
+async fn swap_exact_in_same_with_and_without_batch_calc_fewer_cus() {
+    assert_batch_calc_same_result_fewer_cus(SwapKind::ExactIn).await;
+}
+
+#[tokio::test]
+async fn swap_exact_out_same_with_and_without_batch_calc_fewer_cus() {
+    assert_batch_calc_same_result_fewer_cus(SwapKind::ExactOut).await;
+}
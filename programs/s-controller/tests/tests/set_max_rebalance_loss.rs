@@ -0,0 +1,125 @@
+use s_controller_interface::{
+    set_max_rebalance_loss_ix, SetMaxRebalanceLossIxArgs, SetMaxRebalanceLossKeys,
+};
+use s_controller_lib::{
+    program::{POOL_STATE_ID, REBALANCE_CONFIG_ID},
+    try_rebalance_config, SetMaxRebalanceLossFreeArgs, REBALANCE_CONFIG_SIZE,
+};
+use s_controller_test_utils::{MockPoolState, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use sanctum_solana_test_utils::{
+    assert_program_error, est_rent_exempt_lamports, test_fixtures_dir, ExtendedBanksClient,
+    IntoAccount,
+};
+use solana_program::{program_error::ProgramError, system_program};
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+
+use crate::common::SControllerProgramTest;
+
+const MAX_REBALANCE_LOSS: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn admin_set_max_rebalance_loss() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_max_rebalance_loss_ix(
+        SetMaxRebalanceLossFreeArgs {
+            payer: payer.pubkey(),
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: MockPoolState(DEFAULT_POOL_STATE).into_account(),
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetMaxRebalanceLossIxArgs {
+            max_rebalance_loss: MAX_REBALANCE_LOSS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let rebalance_config_acc = banks_client
+        .get_account_unwrapped(REBALANCE_CONFIG_ID)
+        .await;
+    assert_eq!(rebalance_config_acc.owner, s_controller_lib::program::ID);
+    assert!(rebalance_config_acc.lamports >= est_rent_exempt_lamports(REBALANCE_CONFIG_SIZE));
+    let rebalance_config = try_rebalance_config(&rebalance_config_acc.data).unwrap();
+    assert_eq!(rebalance_config.max_rebalance_loss, MAX_REBALANCE_LOSS);
+}
+
+#[tokio::test]
+async fn admin_update_max_rebalance_loss() {
+    const NEW_MAX_REBALANCE_LOSS: u64 = 1;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let keys = SetMaxRebalanceLossFreeArgs {
+        payer: payer.pubkey(),
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: MockPoolState(DEFAULT_POOL_STATE).into_account(),
+        },
+    }
+    .resolve()
+    .unwrap();
+    let ixs = [MAX_REBALANCE_LOSS, NEW_MAX_REBALANCE_LOSS].map(|max_rebalance_loss| {
+        set_max_rebalance_loss_ix(keys, SetMaxRebalanceLossIxArgs { max_rebalance_loss }).unwrap()
+    });
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let rebalance_config_acc = banks_client
+        .get_account_unwrapped(REBALANCE_CONFIG_ID)
+        .await;
+    let rebalance_config = try_rebalance_config(&rebalance_config_acc.data).unwrap();
+    assert_eq!(rebalance_config.max_rebalance_loss, NEW_MAX_REBALANCE_LOSS);
+}
+
+#[tokio::test]
+async fn unauthorized_signer() {
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_max_rebalance_loss_ix(
+        SetMaxRebalanceLossKeys {
+            admin: payer.pubkey(), // payer is unauthorized
+            payer: payer.pubkey(),
+            pool_state: POOL_STATE_ID,
+            rebalance_config: REBALANCE_CONFIG_ID,
+            system_program: system_program::ID,
+        },
+        SetMaxRebalanceLossIxArgs {
+            max_rebalance_loss: u64::MAX,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    // InvalidArgument thrown by mismatch keys in *_verify_account_keys()
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
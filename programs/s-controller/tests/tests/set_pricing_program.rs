@@ -1,10 +1,13 @@
-use s_controller_interface::{set_pricing_program_ix, SControllerError};
+use s_controller_interface::{
+    set_pricing_program_ix, set_pricing_program_v2_ix, SControllerError, SetPricingProgramV2IxArgs,
+};
 use s_controller_lib::{
     program::POOL_STATE_ID, try_pool_state, SetPricingProgramFreeArgs, DEFAULT_PRICING_PROGRAM,
 };
 
 use s_controller_test_utils::{
-    assert_pricing_prog_set, PoolStateBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE,
+    assert_pricing_pool_context_enabled, assert_pricing_prog_set, PoolStateBanksClient,
+    PoolStateProgramTest, DEFAULT_POOL_STATE,
 };
 use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, test_fixtures_dir};
 use solana_program::{program_error::ProgramError, pubkey::Pubkey};
@@ -58,6 +61,41 @@ async fn basic_success() {
     banks_client.process_transaction(tx).await.unwrap();
 
     assert_pricing_prog_set(&mut banks_client, no_fee_pricing_program::ID).await;
+    assert_pricing_pool_context_enabled(&mut banks_client, false).await;
+}
+
+#[tokio::test]
+async fn v2_basic_success() {
+    let (program_test, mock_auth_kp) = no_fee_program_test();
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    for is_pool_context_enabled in [false, true] {
+        let pool_state_account = banks_client.get_pool_state_acc().await;
+        let keys = SetPricingProgramFreeArgs {
+            new_pricing_program: no_fee_pricing_program::ID,
+            pool_state_acc: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: pool_state_account,
+            },
+        }
+        .resolve_v2()
+        .unwrap();
+        let ix = set_pricing_program_v2_ix(
+            keys,
+            SetPricingProgramV2IxArgs {
+                is_pool_context_enabled: is_pool_context_enabled.into(),
+            },
+        )
+        .unwrap();
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+        banks_client.process_transaction(tx).await.unwrap();
+
+        assert_pricing_prog_set(&mut banks_client, no_fee_pricing_program::ID).await;
+        assert_pricing_pool_context_enabled(&mut banks_client, is_pool_context_enabled).await;
+    }
 }
 
 #[tokio::test]
@@ -1,27 +1,39 @@
 use flat_fee_lib::account_resolvers::PriceExactInFreeArgs;
 use flat_fee_test_utils::MockFeeAccountArgs;
+use generic_pool_calculator_test_utils::MockCalculatorStateAccountArgs;
 use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
 use marinade_keys::msol;
-use s_controller_interface::SControllerError;
+use pricing_programs_interface::PRICE_EXACT_IN_IX_ACCOUNTS_LEN;
+use s_controller_interface::{set_pricing_program_ix, SControllerError};
 use s_controller_lib::{
-    swap_exact_in_ix_by_mint_full, try_pool_state, SrcDstLstSolValueCalcAccountSuffixes,
-    SwapByMintsFreeArgs, SwapExactInAmounts,
+    price_exact_in_keys, program::POOL_STATE_ID, swap_exact_in_ix_by_mint_full, try_pool_state,
+    SetPricingProgramFreeArgs, SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs,
+    SwapExactInAmounts,
 };
 use s_controller_test_utils::{
-    jito_marinade_flat_fee_program_test, jito_marinade_no_fee_program_test,
-    GenAndAddTokenAccountProgramTest, JitoMarinadeProgramTestArgs, LstStateListBanksClient,
-    MockProtocolFeeBps, PoolStateBanksClient,
+    assert_pricing_pool_context_enabled, jito_marinade_flat_fee_program_test,
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, MockProtocolFeeBps, PoolStateBanksClient,
 };
 use sanctum_solana_test_utils::{
-    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+    assert_custom_err, test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
+    IntoAccount,
 };
 use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
 use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
 use solana_program_test::ProgramTestContext;
 use solana_readonly_account::sdk::KeyedAccount;
-use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_interface::SplCalculatorError;
 use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
-use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+use test_utils::{
+    jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+    SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
+};
 
 use crate::common::SControllerProgramTest;
 
@@ -90,21 +102,25 @@ async fn basic_swap_exact_in_no_fee() {
 
     let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
 
-    let ix = swap_exact_in_ix_by_mint_full(
-        SwapByMintsFreeArgs {
-            signer: swapper.pubkey(),
-            src_lst_acc: swapper_msol_acc_addr,
-            dst_lst_acc: swapper_jitosol_acc_addr,
-            src_lst_mint: MintWithTokenProgram {
-                pubkey: msol::ID,
-                token_program: spl_token::ID,
-            },
-            dst_lst_mint: MintWithTokenProgram {
-                pubkey: jitosol::ID,
-                token_program: spl_token::ID,
-            },
-            lst_state_list: lst_state_list_account,
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_msol_acc_addr,
+        dst_lst_acc: swapper_jitosol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
         },
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+    let pricing_program_accounts: [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] =
+        price_exact_in_keys(&keys).into();
+    let ix = swap_exact_in_ix_by_mint_full(
+        free_args,
         SwapExactInAmounts {
             // mSOL worth more than jitoSOL
             min_amount_out: MSOL_TO_SWAP_IN,
@@ -114,18 +130,7 @@ async fn basic_swap_exact_in_no_fee() {
             dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
             src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
         },
-        &[
-            AccountMeta {
-                pubkey: msol::ID,
-                is_signer: false,
-                is_writable: false,
-            },
-            AccountMeta {
-                pubkey: jitosol::ID,
-                is_signer: false,
-                is_writable: false,
-            },
-        ],
+        &pricing_program_accounts,
         no_fee_pricing_program::ID,
     )
     .unwrap();
@@ -170,6 +175,163 @@ async fn basic_swap_exact_in_no_fee() {
     assert!(start_pool_total_sol_value < end_pool_total_sol_value);
 }
 
+#[tokio::test]
+async fn basic_swap_exact_in_no_fee_without_pool_context() {
+    const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    let swapper = Keypair::new();
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+        // dont cares
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    // hasnt synced yet, should be MSOL_POOL_RESERVES + JITOSOL_POOL_RESERVES
+    let start_pool_total_sol_value = try_pool_state(&pool_state_account.data)
+        .unwrap()
+        .total_sol_value;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_msol_acc_addr,
+        dst_lst_acc: swapper_jitosol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: lst_state_list_account,
+    };
+    // set the pricing program with the legacy instruction so that
+    // the pricing CPIs only get the LST mints prefix
+    let set_pricing_program_ix = set_pricing_program_ix(
+        SetPricingProgramFreeArgs {
+            new_pricing_program: no_fee_pricing_program::ID,
+            pool_state_acc: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: pool_state_account,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+    let pricing_program_accounts: [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] =
+        price_exact_in_keys(&keys).into();
+    let ix = swap_exact_in_ix_by_mint_full(
+        free_args,
+        SwapExactInAmounts {
+            // mSOL worth more than jitoSOL
+            min_amount_out: MSOL_TO_SWAP_IN,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+        &pricing_program_accounts,
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    let msol_pool_reserves = ix.accounts[10].pubkey;
+    let jitosol_pool_reserves = ix.accounts[11].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[set_pricing_program_ix, ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper, &mock_auth_kp], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+    assert_pricing_pool_context_enabled(&mut banks_client, false).await;
+
+    let msol_account = banks_client
+        .get_account_unwrapped(swapper_msol_acc_addr)
+        .await;
+    assert_eq!(token_account_balance(msol_account).unwrap(), 0);
+
+    let jitosol_account = banks_client
+        .get_account_unwrapped(swapper_jitosol_acc_addr)
+        .await;
+    let jitosol_received = token_account_balance(jitosol_account).unwrap();
+    // mSOL worth more than jitoSOL
+    assert!(jitosol_received > MSOL_TO_SWAP_IN);
+
+    let msol_pool_reserves_account = banks_client.get_account_unwrapped(msol_pool_reserves).await;
+    assert_eq!(
+        token_account_balance(msol_pool_reserves_account).unwrap(),
+        MSOL_STARTING_POOL_RESERVES + MSOL_TO_SWAP_IN
+    );
+
+    let jitosol_pool_reserves_account = banks_client
+        .get_account_unwrapped(jitosol_pool_reserves)
+        .await;
+    assert_eq!(
+        token_account_balance(jitosol_pool_reserves_account).unwrap(),
+        JITOSOL_STARTING_POOL_RESERVES - jitosol_received
+    );
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let end_pool_total_sol_value = try_pool_state(&pool_state_account.data)
+        .unwrap()
+        .total_sol_value;
+    assert!(start_pool_total_sol_value < end_pool_total_sol_value);
+}
+
 #[tokio::test]
 async fn basic_swap_exact_in_flat_fee() {
     const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
@@ -197,16 +359,39 @@ async fn basic_swap_exact_in_flat_fee() {
         flat_fee_interface::ProgramState {
             manager: Default::default(),
             lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         [
             MockFeeAccountArgs {
                 input_fee_bps: Default::default(),
                 output_fee_bps: JITOSOL_OUT_FEE_BPS,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
                 lst_mint: jitosol::ID,
             },
             MockFeeAccountArgs {
                 input_fee_bps: MSOL_IN_FEE_BPS,
                 output_fee_bps: Default::default(),
+                lp_deposit_fee_bps: Default::default(),
+                lp_withdrawal_fee_bps: None,
                 lst_mint: msol::ID,
             },
         ],
@@ -261,21 +446,23 @@ async fn basic_swap_exact_in_flat_fee() {
 
     let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
 
-    let ix = swap_exact_in_ix_by_mint_full(
-        SwapByMintsFreeArgs {
-            signer: swapper.pubkey(),
-            src_lst_acc: swapper_msol_acc_addr,
-            dst_lst_acc: swapper_jitosol_acc_addr,
-            src_lst_mint: MintWithTokenProgram {
-                pubkey: msol::ID,
-                token_program: spl_token::ID,
-            },
-            dst_lst_mint: MintWithTokenProgram {
-                pubkey: jitosol::ID,
-                token_program: spl_token::ID,
-            },
-            lst_state_list: lst_state_list_account,
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_msol_acc_addr,
+        dst_lst_acc: swapper_jitosol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
         },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+    let ix = swap_exact_in_ix_by_mint_full(
+        free_args,
         SwapExactInAmounts {
             // mSOL worth more than jitoSOL
             min_amount_out: MSOL_TO_SWAP_IN,
@@ -285,11 +472,7 @@ async fn basic_swap_exact_in_flat_fee() {
             dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
             src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
         },
-        &PriceExactInFreeArgs {
-            input_lst_mint: msol::ID,
-            output_lst_mint: jitosol::ID,
-        }
-        .resolve_to_account_metas(),
+        &PriceExactInFreeArgs::from(price_exact_in_keys(&keys)).resolve_to_account_metas(),
         flat_fee_lib::program::ID,
     )
     .unwrap();
@@ -345,6 +528,192 @@ async fn basic_swap_exact_in_flat_fee() {
     assert!(start_pool_total_sol_value < end_pool_total_sol_value);
 }
 
+#[tokio::test]
+async fn swap_exact_in_flat_fee_rebate() {
+    const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    // total fee of -15 bps, a rebate paid out of the pool's SOL value
+    const JITOSOL_OUT_FEE_BPS: i16 = -6;
+    const MSOL_IN_FEE_BPS: i16 = -9;
+    const TRADING_PROTOCOL_FEE_BPS: u16 = 5_000;
+
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+            msol_reserves: MSOL_STARTING_POOL_RESERVES,
+            jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+            msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+            // dont cares
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 0,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Default::default(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: JITOSOL_OUT_FEE_BPS,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: MSOL_IN_FEE_BPS,
+                output_fee_bps: Default::default(),
+                lp_deposit_fee_bps: Default::default(),
+                lp_withdrawal_fee_bps: None,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: TRADING_PROTOCOL_FEE_BPS,
+            lp: Default::default(),
+        },
+    )
+    .add_s_program();
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_msol_acc_addr,
+        dst_lst_acc: swapper_jitosol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+    let ix = swap_exact_in_ix_by_mint_full(
+        free_args,
+        SwapExactInAmounts {
+            // mSOL worth more than jitoSOL
+            min_amount_out: MSOL_TO_SWAP_IN,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+        &PriceExactInFreeArgs::from(price_exact_in_keys(&keys)).resolve_to_account_metas(),
+        flat_fee_lib::program::ID,
+    )
+    .unwrap();
+    let msol_pool_reserves = ix.accounts[10].pubkey;
+    let jitosol_pool_reserves = ix.accounts[11].pubkey;
+    let jitosol_protocol_fee_accumulator = ix.accounts[5].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let msol_account = banks_client
+        .get_account_unwrapped(swapper_msol_acc_addr)
+        .await;
+    assert_eq!(token_account_balance(msol_account).unwrap(), 0);
+
+    let jitosol_account = banks_client
+        .get_account_unwrapped(swapper_jitosol_acc_addr)
+        .await;
+    let jitosol_received = token_account_balance(jitosol_account).unwrap();
+    // mSOL worth more than jitoSOL
+    assert!(jitosol_received > MSOL_TO_SWAP_IN);
+
+    let msol_pool_reserves_account = banks_client.get_account_unwrapped(msol_pool_reserves).await;
+    assert_eq!(
+        token_account_balance(msol_pool_reserves_account).unwrap(),
+        MSOL_STARTING_POOL_RESERVES + MSOL_TO_SWAP_IN
+    );
+
+    let jitosol_pool_reserves_account = banks_client
+        .get_account_unwrapped(jitosol_pool_reserves)
+        .await;
+    let jitosol_pool_reserves_balance =
+        token_account_balance(jitosol_pool_reserves_account).unwrap();
+    let jitosol_protocol_fee_accumulator_account = banks_client
+        .get_account_unwrapped(jitosol_protocol_fee_accumulator)
+        .await;
+    // no fees to levy protocol fees on
+    assert_eq!(
+        token_account_balance(jitosol_protocol_fee_accumulator_account).unwrap(),
+        0
+    );
+    assert_eq!(
+        jitosol_pool_reserves_balance + jitosol_received,
+        JITOSOL_STARTING_POOL_RESERVES
+    );
+}
+
 #[tokio::test]
 async fn fail_swap_exact_in_same_mint() {
     const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
@@ -398,21 +767,25 @@ async fn fail_swap_exact_in_same_mint() {
     .resolve_spl_to_account_metas()
     .unwrap();
 
-    let ix = swap_exact_in_ix_by_mint_full(
-        SwapByMintsFreeArgs {
-            signer: swapper.pubkey(),
-            src_lst_acc: swapper_jitosol_acc_addr,
-            dst_lst_acc: swapper_jitosol_acc_addr,
-            src_lst_mint: MintWithTokenProgram {
-                pubkey: jitosol::ID,
-                token_program: spl_token::ID,
-            },
-            dst_lst_mint: MintWithTokenProgram {
-                pubkey: jitosol::ID,
-                token_program: spl_token::ID,
-            },
-            lst_state_list: lst_state_list_account,
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_jitosol_acc_addr,
+        dst_lst_acc: swapper_jitosol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
         },
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+    let pricing_program_accounts: [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] =
+        price_exact_in_keys(&keys).into();
+    let ix = swap_exact_in_ix_by_mint_full(
+        free_args,
         SwapExactInAmounts {
             min_amount_out: 0,
             amount: JITOSOL_TO_SWAP_IN,
@@ -421,18 +794,7 @@ async fn fail_swap_exact_in_same_mint() {
             dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
             src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
         },
-        &[
-            AccountMeta {
-                pubkey: jitosol::ID,
-                is_signer: false,
-                is_writable: false,
-            },
-            AccountMeta {
-                pubkey: jitosol::ID,
-                is_signer: false,
-                is_writable: false,
-            },
-        ],
+        &pricing_program_accounts,
         no_fee_pricing_program::ID,
     )
     .unwrap();
@@ -443,3 +805,117 @@ async fn fail_swap_exact_in_same_mint() {
     let err = banks_client.process_transaction(tx).await.unwrap_err();
     assert_custom_err(err, SControllerError::SwapSameLst);
 }
+
+#[tokio::test]
+async fn fail_swap_exact_in_stale_src_lst() {
+    const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const JITOSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+    const STALE_DISCOUNT_BPS: u16 = 100;
+
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+        // dont cares
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: JITOSOL_TO_SWAP_IN,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+
+    let mut ctx = program_test.start_with_context().await;
+    // jito stake pool was last updated an epoch ago, with stale valuation enabled
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH + 1,
+        ..Default::default()
+    });
+    ctx.set_account(
+        &spl_calculator_lib::program::SPL_CALCULATOR_STATE_ID,
+        &MockCalculatorStateAccountArgs {
+            manager: Pubkey::default(),
+            last_upgrade_slot: SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
+            stale_discount_bps: STALE_DISCOUNT_BPS,
+            approved_program_data_hash: Default::default(),
+            owner: spl_calculator_lib::program::ID,
+        }
+        .into_account()
+        .into(),
+    );
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_jitosol_acc_addr,
+        dst_lst_acc: swapper_msol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+    let pricing_program_accounts: [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] =
+        price_exact_in_keys(&keys).into();
+    let ix = swap_exact_in_ix_by_mint_full(
+        free_args,
+        SwapExactInAmounts {
+            min_amount_out: 0,
+            amount: JITOSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+        &pricing_program_accounts,
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    // stale LSTs may not be valued as an input, even with stale valuation enabled
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SplCalculatorError::PoolNotUpdated);
+}
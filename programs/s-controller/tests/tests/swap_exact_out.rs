@@ -1,15 +1,12 @@
 use flat_fee_lib::account_resolvers::PriceExactOutFreeArgs;
 use flat_fee_test_utils::MockFeeAccountArgs;
-use generic_pool_calculator_interface::LST_TO_SOL_IX_ACCOUNTS_LEN;
-use marinade_calculator_lib::{
-    marinade_sol_val_calc_account_metas, MarinadeSolValCalc,
-    MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
-};
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
 use marinade_keys::msol;
+use pricing_programs_interface::PRICE_EXACT_OUT_IX_ACCOUNTS_LEN;
 use s_controller_interface::SControllerError;
 use s_controller_lib::{
-    swap_exact_out_ix_by_mint_full, try_pool_state, SrcDstLstSolValueCalcAccountSuffixes,
-    SwapByMintsFreeArgs, SwapExactOutAmounts,
+    price_exact_out_keys, swap_exact_out_ix_by_mint_full, try_pool_state,
+    SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs, SwapExactOutAmounts,
 };
 use s_controller_test_utils::{
     jito_marinade_flat_fee_program_test, jito_marinade_no_fee_program_test,
@@ -26,7 +23,7 @@ use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
 use solana_program_test::ProgramTestContext;
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
-use spl_calculator_lib::{SplLstSolCommonFreeArgsConst, SplSolValCalc};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
 use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
 
 use crate::common::*;
@@ -96,43 +93,36 @@ async fn basic_swap_exact_out_no_fee() {
     let jito_stake_pool_acc = banks_client
         .get_account_unwrapped(jito_stake_pool::ID)
         .await;
-    let jito_sol_val_calc_args = SplLstSolCommonFreeArgsConst {
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
         spl_stake_pool: KeyedAccount {
             pubkey: jito_stake_pool::ID,
             account: jito_stake_pool_acc,
         },
-    };
-    let jito_sol_val_calc_keys: generic_pool_calculator_interface::LstToSolKeys =
-        jito_sol_val_calc_args
-            .resolve_spl()
-            .unwrap()
-            .resolve::<SplSolValCalc>()
-            .into();
-    let jito_sol_val_calc_accounts: [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] =
-        jito_sol_val_calc_keys.into();
-
-    let marinade_sol_val_calc_keys: generic_pool_calculator_interface::SolToLstKeys =
-        MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS
-            .resolve::<MarinadeSolValCalc>()
-            .into();
-    let marinade_sol_val_calc_accounts: [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] =
-        marinade_sol_val_calc_keys.into();
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
 
-    let ix = swap_exact_out_ix_by_mint_full(
-        SwapByMintsFreeArgs {
-            signer: swapper.pubkey(),
-            src_lst_acc: swapper_msol_acc_addr,
-            dst_lst_acc: swapper_jitosol_acc_addr,
-            src_lst_mint: MintWithTokenProgram {
-                pubkey: msol::ID,
-                token_program: spl_token::ID,
-            },
-            dst_lst_mint: MintWithTokenProgram {
-                pubkey: jitosol::ID,
-                token_program: spl_token::ID,
-            },
-            lst_state_list: lst_state_list_account,
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_msol_acc_addr,
+        dst_lst_acc: swapper_jitosol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
         },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_out().unwrap();
+    let pricing_program_accounts: [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN] =
+        price_exact_out_keys(&keys).into();
+    let ix = swap_exact_out_ix_by_mint_full(
+        free_args,
         SwapExactOutAmounts {
             // mSOL worth more than jitoSOL
             max_amount_in: JITOSOL_TO_RECEIVE,
@@ -142,18 +132,7 @@ async fn basic_swap_exact_out_no_fee() {
             dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
             src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
         },
-        &[
-            AccountMeta {
-                pubkey: msol::ID,
-                is_signer: false,
-                is_writable: false,
-            },
-            AccountMeta {
-                pubkey: jitosol::ID,
-                is_signer: false,
-                is_writable: false,
-            },
-        ],
+        &pricing_program_accounts,
         no_fee_pricing_program::ID,
     )
     .unwrap();
@@ -228,16 +207,39 @@ async fn basic_swap_exact_out_flat_fee() {
         flat_fee_interface::ProgramState {
             manager: Default::default(),
             lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
         },
         [
             MockFeeAccountArgs {
                 input_fee_bps: Default::default(),
                 output_fee_bps: JITOSOL_OUT_FEE_BPS,
+                lp_deposit_fee_bps: 0,
+                lp_withdrawal_fee_bps: None,
                 lst_mint: jitosol::ID,
             },
             MockFeeAccountArgs {
                 input_fee_bps: MSOL_IN_FEE_BPS,
                 output_fee_bps: Default::default(),
+                lp_deposit_fee_bps: Default::default(),
+                lp_withdrawal_fee_bps: None,
                 lst_mint: msol::ID,
             },
         ],
@@ -292,21 +294,23 @@ async fn basic_swap_exact_out_flat_fee() {
 
     let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
 
-    let ix = swap_exact_out_ix_by_mint_full(
-        SwapByMintsFreeArgs {
-            signer: swapper.pubkey(),
-            src_lst_acc: swapper_msol_acc_addr,
-            dst_lst_acc: swapper_jitosol_acc_addr,
-            src_lst_mint: MintWithTokenProgram {
-                pubkey: msol::ID,
-                token_program: spl_token::ID,
-            },
-            dst_lst_mint: MintWithTokenProgram {
-                pubkey: jitosol::ID,
-                token_program: spl_token::ID,
-            },
-            lst_state_list: lst_state_list_account,
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_msol_acc_addr,
+        dst_lst_acc: swapper_jitosol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
         },
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_out().unwrap();
+    let ix = swap_exact_out_ix_by_mint_full(
+        free_args,
         SwapExactOutAmounts {
             // mSOL worth more than jitoSOL, so this shouldnt trigger slippage exceeded
             max_amount_in: JITOSOL_TO_RECEIVE,
@@ -316,11 +320,7 @@ async fn basic_swap_exact_out_flat_fee() {
             dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
             src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
         },
-        &PriceExactOutFreeArgs {
-            input_lst_mint: msol::ID,
-            output_lst_mint: jitosol::ID,
-        }
-        .resolve_to_account_metas(),
+        &PriceExactOutFreeArgs::from(price_exact_out_keys(&keys)).resolve_to_account_metas(),
         flat_fee_lib::program::ID,
     )
     .unwrap();
@@ -411,28 +411,27 @@ async fn fail_swap_exact_out_same_mint() {
 
     let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
 
-    let marinade_sol_val_calc_keys: generic_pool_calculator_interface::SolToLstKeys =
-        MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS
-            .resolve::<MarinadeSolValCalc>()
-            .into();
-    let marinade_sol_val_calc_accounts: [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] =
-        marinade_sol_val_calc_keys.into();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
 
-    let ix = swap_exact_out_ix_by_mint_full(
-        SwapByMintsFreeArgs {
-            signer: swapper.pubkey(),
-            src_lst_acc: swapper_msol_acc_addr,
-            dst_lst_acc: swapper_msol_acc_addr,
-            src_lst_mint: MintWithTokenProgram {
-                pubkey: msol::ID,
-                token_program: spl_token::ID,
-            },
-            dst_lst_mint: MintWithTokenProgram {
-                pubkey: msol::ID,
-                token_program: spl_token::ID,
-            },
-            lst_state_list: lst_state_list_account,
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_msol_acc_addr,
+        dst_lst_acc: swapper_msol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
         },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_out().unwrap();
+    let pricing_program_accounts: [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN] =
+        price_exact_out_keys(&keys).into();
+    let ix = swap_exact_out_ix_by_mint_full(
+        free_args,
         SwapExactOutAmounts {
             max_amount_in: u64::MAX,
             amount: MSOL_STARTING_BALANCE,
@@ -441,18 +440,7 @@ async fn fail_swap_exact_out_same_mint() {
             dst_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
             src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
         },
-        &[
-            AccountMeta {
-                pubkey: msol::ID,
-                is_signer: false,
-                is_writable: false,
-            },
-            AccountMeta {
-                pubkey: msol::ID,
-                is_signer: false,
-                is_writable: false,
-            },
-        ],
+        &pricing_program_accounts,
         no_fee_pricing_program::ID,
     )
     .unwrap();
@@ -0,0 +1,256 @@
+//! Pricing programs such as dynamic-fee price from the LSTs' `LstState.sol_value`
+//! and the pool's `total_sol_value`, so both LSTs must be synced before the pricing CPI.
+//! A swap on a stale pool must therefore be priced the same as one on a freshly synced pool.
+
+use dynamic_fee_lib::account_resolvers::{PriceExactInFreeArgs, PriceExactOutFreeArgs};
+use dynamic_fee_test_utils::{MockFeeAccount, MockFeeAccountArgs};
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_lib::{
+    price_exact_in_keys, price_exact_out_keys, swap_exact_in_ix_by_mint_full,
+    swap_exact_out_ix_by_mint_full, sync_sol_value_ix_by_mint_full,
+    SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs, SwapExactInAmounts,
+    SwapExactOutAmounts, SyncSolValueByMintFreeArgs,
+};
+use s_controller_test_utils::{
+    jito_marinade_base_program_test, GenAndAddTokenAccountProgramTest, JitoMarinadeProgramTestArgs,
+    LstStateListBanksClient, MockPoolState,
+};
+use sanctum_solana_test_utils::{token::MockTokenAccountArgs, ExtendedBanksClient, IntoAccount};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{processor, BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::SControllerProgramTest;
+
+const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_STARTING_BALANCE: u64 = 1_000_000_000;
+const JITOSOL_TO_RECEIVE: u64 = 500_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SwapKind {
+    ExactIn,
+    ExactOut,
+}
+
+struct SwapProgramTest {
+    ctx: ProgramTestContext,
+    swapper: Keypair,
+    swapper_jitosol_acc_addr: Pubkey,
+    swapper_msol_acc_addr: Pubkey,
+}
+
+/// LstState.sol_values are stale and updated on sync.
+/// Fee accounts have a large imbalance fee so that stale SOL values change the fee charged
+async fn dynamic_fee_swap_program_test() -> SwapProgramTest {
+    let swapper = Keypair::new();
+
+    let (mut program_test, mut pool_state) =
+        jito_marinade_base_program_test(JitoMarinadeProgramTestArgs {
+            jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+            msol_reserves: MSOL_STARTING_POOL_RESERVES,
+            jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+            msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+            // dont cares
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 0,
+        });
+    program_test.add_program(
+        "dynamic_fee",
+        dynamic_fee_lib::program::ID,
+        processor!(dynamic_fee::entrypoint::process_instruction),
+    );
+    pool_state.pricing_program = dynamic_fee_lib::program::ID;
+    program_test.add_account(
+        s_controller_lib::program::POOL_STATE_ID,
+        MockPoolState(pool_state).into_account(),
+    );
+    for lst_mint in [jitosol::ID, msol::ID] {
+        let (acc, addr) = MockFeeAccountArgs {
+            input_fee_bps: 1,
+            output_fee_bps: 2,
+            target_weight_bps: 5_000,
+            imbalance_fee_bps: 1_000,
+            lst_mint,
+        }
+        .to_fee_account_and_addr(dynamic_fee_lib::program::ID);
+        program_test.add_account(addr, MockFeeAccount(acc).into_account());
+    }
+    let mut program_test = program_test.add_s_program();
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_STARTING_BALANCE,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    SwapProgramTest {
+        ctx,
+        swapper,
+        swapper_jitosol_acc_addr,
+        swapper_msol_acc_addr,
+    }
+}
+
+async fn jito_sol_val_calc_accounts(banks_client: &mut BanksClient) -> Vec<AccountMeta> {
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    Vec::from(
+        SplLstSolCommonFreeArgsConst {
+            spl_stake_pool: KeyedAccount {
+                pubkey: jito_stake_pool::ID,
+                account: jito_stake_pool_acc,
+            },
+        }
+        .resolve_spl_to_account_metas()
+        .unwrap(),
+    )
+}
+
+async fn sync_all(ctx: &mut ProgramTestContext) {
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut ctx.banks_client).await;
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let mut ixs = vec![];
+    for (lst_mint, calc_accounts) in [
+        (jitosol::ID, jito_sol_val_calc_accounts.as_slice()),
+        (msol::ID, marinade_sol_val_calc_accounts.as_slice()),
+    ] {
+        let lst_state_list_acc = ctx.banks_client.get_lst_state_list_acc().await;
+        let lst_mint_acc = ctx.banks_client.get_account_unwrapped(lst_mint).await;
+        ixs.push(
+            sync_sol_value_ix_by_mint_full(
+                SyncSolValueByMintFreeArgs {
+                    lst_state_list: lst_state_list_acc,
+                    lst_mint: KeyedAccount {
+                        pubkey: lst_mint,
+                        account: lst_mint_acc,
+                    },
+                },
+                calc_accounts,
+            )
+            .unwrap(),
+        );
+    }
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer], ctx.last_blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Swaps mSOL for jitoSOL.
+///
+/// Returns (mSOL paid, jitoSOL received)
+async fn swap_msol_for_jitosol(kind: SwapKind, presync: bool) -> (u64, u64) {
+    let SwapProgramTest {
+        mut ctx,
+        swapper,
+        swapper_jitosol_acc_addr,
+        swapper_msol_acc_addr,
+    } = dynamic_fee_swap_program_test().await;
+    if presync {
+        sync_all(&mut ctx).await;
+    }
+
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut ctx.banks_client).await;
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let lst_state_list_account = ctx.banks_client.get_lst_state_list_acc().await;
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_msol_acc_addr,
+        dst_lst_acc: swapper_jitosol_acc_addr,
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: lst_state_list_account,
+    };
+    let calc_accounts = SrcDstLstSolValueCalcAccountSuffixes {
+        src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+    };
+    let ix = match kind {
+        SwapKind::ExactIn => {
+            let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+            swap_exact_in_ix_by_mint_full(
+                free_args,
+                SwapExactInAmounts {
+                    min_amount_out: 0,
+                    amount: MSOL_STARTING_BALANCE,
+                },
+                calc_accounts,
+                &PriceExactInFreeArgs::from(price_exact_in_keys(&keys)).resolve_to_account_metas(),
+                dynamic_fee_lib::program::ID,
+            )
+            .unwrap()
+        }
+        SwapKind::ExactOut => {
+            let (keys, _, _) = free_args.resolve_exact_out().unwrap();
+            swap_exact_out_ix_by_mint_full(
+                free_args,
+                SwapExactOutAmounts {
+                    max_amount_in: MSOL_STARTING_BALANCE,
+                    amount: JITOSOL_TO_RECEIVE,
+                },
+                calc_accounts,
+                &PriceExactOutFreeArgs::from(price_exact_out_keys(&keys))
+                    .resolve_to_account_metas(),
+                dynamic_fee_lib::program::ID,
+            )
+            .unwrap()
+        }
+    };
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer, &swapper], ctx.last_blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let msol_account = ctx
+        .banks_client
+        .get_account_unwrapped(swapper_msol_acc_addr)
+        .await;
+    let jitosol_account = ctx
+        .banks_client
+        .get_account_unwrapped(swapper_jitosol_acc_addr)
+        .await;
+    (
+        MSOL_STARTING_BALANCE - token_account_balance(msol_account).unwrap(),
+        token_account_balance(jitosol_account).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn swap_exact_in_dynamic_fee_priced_from_synced_sol_values() {
+    let stale = swap_msol_for_jitosol(SwapKind::ExactIn, false).await;
+    let synced = swap_msol_for_jitosol(SwapKind::ExactIn, true).await;
+    assert_eq!(stale, synced);
+    assert_eq!(stale.0, MSOL_STARTING_BALANCE);
+}
+
+#[tokio::test]
+async fn swap_exact_out_dynamic_fee_priced_from_synced_sol_values() {
+    let stale = swap_msol_for_jitosol(SwapKind::ExactOut, false).await;
+    let synced = swap_msol_for_jitosol(SwapKind::ExactOut, true).await;
+    assert_eq!(stale, synced);
+    assert_eq!(stale.1, JITOSOL_TO_RECEIVE);
+}
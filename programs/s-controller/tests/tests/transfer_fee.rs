@@ -0,0 +1,764 @@
+use borsh::BorshDeserialize;
+use data_encoding::BASE64;
+use flat_fee_lib::account_resolvers::{PriceExactInFreeArgs, PriceLpTokensToMintFreeArgs};
+use flat_fee_test_utils::MockFeeAccountArgs;
+use pricing_programs_interface::{
+    PRICE_EXACT_IN_IX_ACCOUNTS_LEN, PRICE_EXACT_OUT_IX_ACCOUNTS_LEN,
+    PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    add_liquidity_ix_full, create_pool_reserves_address, donate_ix_by_mint_full,
+    price_exact_in_keys, price_exact_out_keys, price_lp_tokens_to_mint_keys,
+    swap_exact_in_ix_by_mint_full, swap_exact_out_ix_by_mint_full, try_lst_state_list,
+    AddLiquidityByMintFreeArgs, AddLiquidityIxAmts, AddLiquidityIxFullArgs,
+    AddRemoveLiquidityExtraAccounts, DonateByMintFreeArgs, DonateEvent,
+    SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs, SwapExactInAmounts,
+    SwapExactOutAmounts, DONATE_EVENT_TAG,
+};
+use s_controller_test_utils::{
+    jito_22_wsol_flat_fee_program_test, jito_22_wsol_no_fee_program_test,
+    GenAndAddTokenAccountProgramTest, Jito22WsolProgramTestArgs, LstStateListBanksClient,
+    MockProtocolFeeBps, MockTransferFeeMintArgs, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{token::MockTokenAccountArgs, ExtendedBanksClient};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use spl_token::native_mint;
+use spl_token_2022::extension::{
+    transfer_fee::TransferFeeAmount, BaseStateWithExtensions, StateWithExtensions,
+};
+use test_utils::{jito_22_stake_pool, jitosol_22, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+use wsol_calculator_lib::WSOL_LST_SOL_COMMON_METAS;
+
+use crate::common::SControllerProgramTest;
+
+const JITOSOL_22_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const WSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+
+const JITOSOL_22_MINT_ARGS: MockTransferFeeMintArgs = MockTransferFeeMintArgs {
+    supply: 1_000_000_000_000_000,
+    decimals: 9,
+    // 1%
+    transfer_fee_basis_points: 100,
+    maximum_fee: u64::MAX,
+};
+
+const JITOSOL_22_MINT: MintWithTokenProgram = MintWithTokenProgram {
+    pubkey: jitosol_22::ID,
+    token_program: spl_token_2022::ID,
+};
+
+const WSOL_MINT: MintWithTokenProgram = MintWithTokenProgram {
+    pubkey: native_mint::ID,
+    token_program: spl_token::ID,
+};
+
+fn jito_22_wsol_program_test_args(lp_token_mint: Pubkey) -> Jito22WsolProgramTestArgs {
+    Jito22WsolProgramTestArgs {
+        jitosol_22_reserves: JITOSOL_22_STARTING_POOL_RESERVES,
+        wsol_reserves: WSOL_STARTING_POOL_RESERVES,
+        jitosol_22_sol_value: JITOSOL_22_STARTING_POOL_RESERVES, // updated on sync
+        wsol_sol_value: WSOL_STARTING_POOL_RESERVES,
+        jitosol_22_mint: JITOSOL_22_MINT_ARGS,
+        jitosol_22_protocol_fee_accumulator: 0,
+        wsol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 0,
+    }
+}
+
+fn jito_22_wsol_program_test(lp_token_mint: Pubkey) -> ProgramTest {
+    jito_22_wsol_no_fee_program_test(jito_22_wsol_program_test_args(lp_token_mint)).add_s_program()
+}
+
+fn jito_22_wsol_flat_fee_program_test_with_fees(
+    lp_token_mint: Pubkey,
+    [jitosol_22_fee_account, wsol_fee_account]: [MockFeeAccountArgs; 2],
+    protocol_fee_bps: MockProtocolFeeBps,
+) -> ProgramTest {
+    jito_22_wsol_flat_fee_program_test(
+        jito_22_wsol_program_test_args(lp_token_mint),
+        flat_fee_interface::ProgramState {
+            manager: Default::default(),
+            lp_withdrawal_fee_bps: Default::default(),
+            lp_deposit_fee_bps: Default::default(),
+            pending_lp_withdrawal_fee_bps: Default::default(),
+            has_pending_lp_withdrawal_fee: Default::default(),
+            padding: Default::default(),
+            pending_lp_withdrawal_fee_activation_epoch: Default::default(),
+            admin: Default::default(),
+            min_lst_fee_bps: Default::default(),
+            max_lst_fee_bps: Default::default(),
+            max_lp_withdrawal_fee_bps: Default::default(),
+            max_fee_change_bps: Default::default(),
+            max_lp_deposit_fee_bps: Default::default(),
+            max_surge_fee_bps: Default::default(),
+            has_fee_bounds: Default::default(),
+            fee_bounds_padding: Default::default(),
+            baseline_lp_withdrawal_fee_bps: Default::default(),
+            baseline_lp_deposit_fee_bps: Default::default(),
+            has_fee_baseline: Default::default(),
+            fee_baseline_padding: Default::default(),
+            fee_baseline_epoch: Default::default(),
+        },
+        [jitosol_22_fee_account, wsol_fee_account],
+        protocol_fee_bps,
+    )
+    .add_s_program()
+}
+
+async fn jito_22_sol_val_calc_accounts(banks_client: &mut BanksClient) -> Vec<AccountMeta> {
+    let jito_22_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_22_stake_pool::ID)
+        .await;
+    SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_22_stake_pool::ID,
+            account: jito_22_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap()
+    .into()
+}
+
+async fn balance_of(banks_client: &mut BanksClient, token_acc: Pubkey) -> u64 {
+    let account = banks_client.get_account_unwrapped(token_acc).await;
+    token_account_balance(account).unwrap()
+}
+
+/// Transfer fees withheld in a Token-2022 token account,
+/// i.e. the amount transferred in less the amount it received
+async fn withheld_of(banks_client: &mut BanksClient, token_acc: Pubkey) -> u64 {
+    let account = banks_client.get_account_unwrapped(token_acc).await;
+    let state =
+        StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data).unwrap();
+    state
+        .get_extension::<TransferFeeAmount>()
+        .unwrap()
+        .withheld_amount
+        .into()
+}
+
+#[tokio::test]
+async fn swap_exact_in_src_transfer_fee() {
+    const JITOSOL_22_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_22_wsol_program_test(Pubkey::new_unique());
+    let swapper_jitosol_22_acc_addr =
+        program_test.gen_and_add_token_2022_account(MockTokenAccountArgs {
+            mint: jitosol_22::ID,
+            authority: swapper.pubkey(),
+            amount: JITOSOL_22_TO_SWAP_IN,
+        });
+    let swapper_wsol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: native_mint::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let jito_22_sol_val_calc_accounts = jito_22_sol_val_calc_accounts(&mut banks_client).await;
+
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_jitosol_22_acc_addr,
+        dst_lst_acc: swapper_wsol_acc_addr,
+        src_lst_mint: JITOSOL_22_MINT,
+        dst_lst_mint: WSOL_MINT,
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+    let pricing_program_accounts: [AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN] =
+        price_exact_in_keys(&keys).into();
+    let ix = swap_exact_in_ix_by_mint_full(
+        free_args,
+        SwapExactInAmounts {
+            min_amount_out: 0,
+            amount: JITOSOL_22_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &jito_22_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &WSOL_LST_SOL_COMMON_METAS,
+        },
+        &pricing_program_accounts,
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    let jitosol_22_pool_reserves = ix.accounts[10].pubkey;
+    let wsol_pool_reserves = ix.accounts[11].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        balance_of(&mut banks_client, swapper_jitosol_22_acc_addr).await,
+        0
+    );
+    let jitosol_22_received_by_pool = JITOSOL_22_MINT_ARGS
+        .transfer_fee()
+        .calculate_post_fee_amount(JITOSOL_22_TO_SWAP_IN)
+        .unwrap();
+    assert!(jitosol_22_received_by_pool < JITOSOL_22_TO_SWAP_IN);
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_22_pool_reserves).await,
+        JITOSOL_22_STARTING_POOL_RESERVES + jitosol_22_received_by_pool
+    );
+
+    let wsol_received = balance_of(&mut banks_client, swapper_wsol_acc_addr).await;
+    assert!(wsol_received > 0);
+    assert_eq!(
+        balance_of(&mut banks_client, wsol_pool_reserves).await,
+        WSOL_STARTING_POOL_RESERVES - wsol_received
+    );
+}
+
+#[tokio::test]
+async fn swap_exact_out_dst_transfer_fee() {
+    const JITOSOL_22_TO_SWAP_OUT: u64 = 1_000_000_000;
+    const WSOL_STARTING_BALANCE: u64 = 10_000_000_000;
+
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_22_wsol_program_test(Pubkey::new_unique());
+    let swapper_jitosol_22_acc_addr =
+        program_test.gen_and_add_token_2022_account(MockTokenAccountArgs {
+            mint: jitosol_22::ID,
+            authority: swapper.pubkey(),
+            amount: 0,
+        });
+    let swapper_wsol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: native_mint::ID,
+        authority: swapper.pubkey(),
+        amount: WSOL_STARTING_BALANCE,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let jito_22_sol_val_calc_accounts = jito_22_sol_val_calc_accounts(&mut banks_client).await;
+
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_wsol_acc_addr,
+        dst_lst_acc: swapper_jitosol_22_acc_addr,
+        src_lst_mint: WSOL_MINT,
+        dst_lst_mint: JITOSOL_22_MINT,
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_out().unwrap();
+    let pricing_program_accounts: [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN] =
+        price_exact_out_keys(&keys).into();
+    let ix = swap_exact_out_ix_by_mint_full(
+        free_args,
+        SwapExactOutAmounts {
+            max_amount_in: WSOL_STARTING_BALANCE,
+            amount: JITOSOL_22_TO_SWAP_OUT,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &WSOL_LST_SOL_COMMON_METAS,
+            dst_lst_calculator_accounts: &jito_22_sol_val_calc_accounts,
+        },
+        &pricing_program_accounts,
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    let wsol_pool_reserves = ix.accounts[10].pubkey;
+    let jitosol_22_pool_reserves = ix.accounts[11].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // user receives exactly the requested amount, transfer fee is paid out of the pool's side
+    assert_eq!(
+        balance_of(&mut banks_client, swapper_jitosol_22_acc_addr).await,
+        JITOSOL_22_TO_SWAP_OUT
+    );
+    let jitosol_22_sent_by_pool = JITOSOL_22_MINT_ARGS
+        .transfer_fee()
+        .calculate_pre_fee_amount(JITOSOL_22_TO_SWAP_OUT)
+        .unwrap();
+    assert!(jitosol_22_sent_by_pool > JITOSOL_22_TO_SWAP_OUT);
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_22_pool_reserves).await,
+        JITOSOL_22_STARTING_POOL_RESERVES - jitosol_22_sent_by_pool
+    );
+
+    let wsol_paid =
+        WSOL_STARTING_BALANCE - balance_of(&mut banks_client, swapper_wsol_acc_addr).await;
+    assert!(wsol_paid > 0);
+    assert_eq!(
+        balance_of(&mut banks_client, wsol_pool_reserves).await,
+        WSOL_STARTING_POOL_RESERVES + wsol_paid
+    );
+}
+
+#[tokio::test]
+async fn add_liquidity_transfer_fee() {
+    const JITOSOL_22_TO_ADD: u64 = 1_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_22_wsol_program_test(lp_token_mint);
+    let liquidity_provider_jitosol_22_acc_addr =
+        program_test.gen_and_add_token_2022_account(MockTokenAccountArgs {
+            mint: jitosol_22::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: JITOSOL_22_TO_ADD,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let lst_mint_account = banks_client.get_account_unwrapped(jitosol_22::ID).await;
+    let jito_22_sol_val_calc_accounts = jito_22_sol_val_calc_accounts(&mut banks_client).await;
+
+    let args = AddLiquidityByMintFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        src_lst_acc: liquidity_provider_jitosol_22_acc_addr,
+        dst_lp_acc: liquidity_provider_lp_token_acc_addr,
+        pool_state: pool_state_account,
+        lst_state_list: &lst_state_list_account,
+        lst_mint: KeyedAccount {
+            pubkey: jitosol_22::ID,
+            account: lst_mint_account,
+        },
+    };
+    let (keys, lst_index, _program_ids) = args.resolve().unwrap();
+    let pricing_program_price_lp_accounts: [AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN] =
+        price_lp_tokens_to_mint_keys(&keys).into();
+    let ix = add_liquidity_ix_full(
+        keys,
+        AddLiquidityIxFullArgs {
+            lst_index,
+            amts: AddLiquidityIxAmts {
+                lst_amount: JITOSOL_22_TO_ADD,
+                min_lp_out: 0,
+            },
+        },
+        AddRemoveLiquidityExtraAccounts {
+            lst_calculator_program_id: spl_calculator_lib::program::ID,
+            pricing_program_id: no_fee_pricing_program::ID,
+            lst_calculator_accounts: &jito_22_sol_val_calc_accounts,
+            pricing_program_price_lp_accounts: &pricing_program_price_lp_accounts,
+        },
+    )
+    .unwrap();
+    let jitosol_22_pool_reserves = {
+        let lst_state_list = try_lst_state_list(&lst_state_list_account.data).unwrap();
+        create_pool_reserves_address(&lst_state_list[lst_index], spl_token_2022::ID).unwrap()
+    };
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        balance_of(&mut banks_client, liquidity_provider_jitosol_22_acc_addr).await,
+        0
+    );
+    let jitosol_22_received_by_pool = JITOSOL_22_MINT_ARGS
+        .transfer_fee()
+        .calculate_post_fee_amount(JITOSOL_22_TO_ADD)
+        .unwrap();
+    assert!(jitosol_22_received_by_pool < JITOSOL_22_TO_ADD);
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_22_pool_reserves).await,
+        JITOSOL_22_STARTING_POOL_RESERVES + jitosol_22_received_by_pool
+    );
+    assert!(balance_of(&mut banks_client, liquidity_provider_lp_token_acc_addr).await > 0);
+}
+
+#[tokio::test]
+async fn swap_exact_in_protocol_fee_transfer_fee() {
+    const WSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    const JITOSOL_22_OUT_FEE_BPS: i16 = 6;
+    const WSOL_IN_FEE_BPS: i16 = 9;
+    const TRADING_PROTOCOL_FEE_BPS: u16 = 5_000;
+
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_22_wsol_flat_fee_program_test_with_fees(
+        Pubkey::new_unique(),
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: JITOSOL_22_OUT_FEE_BPS,
+                lp_deposit_fee_bps: Default::default(),
+                lp_withdrawal_fee_bps: None,
+                lst_mint: jitosol_22::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: WSOL_IN_FEE_BPS,
+                output_fee_bps: Default::default(),
+                lp_deposit_fee_bps: Default::default(),
+                lp_withdrawal_fee_bps: None,
+                lst_mint: native_mint::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: TRADING_PROTOCOL_FEE_BPS,
+            lp: Default::default(),
+        },
+    );
+    let swapper_wsol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: native_mint::ID,
+        authority: swapper.pubkey(),
+        amount: WSOL_TO_SWAP_IN,
+    });
+    let swapper_jitosol_22_acc_addr =
+        program_test.gen_and_add_token_2022_account(MockTokenAccountArgs {
+            mint: jitosol_22::ID,
+            authority: swapper.pubkey(),
+            amount: 0,
+        });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let jito_22_sol_val_calc_accounts = jito_22_sol_val_calc_accounts(&mut banks_client).await;
+
+    let free_args = SwapByMintsFreeArgs {
+        signer: swapper.pubkey(),
+        src_lst_acc: swapper_wsol_acc_addr,
+        dst_lst_acc: swapper_jitosol_22_acc_addr,
+        src_lst_mint: WSOL_MINT,
+        dst_lst_mint: JITOSOL_22_MINT,
+        lst_state_list: lst_state_list_account,
+    };
+    let (keys, _, _) = free_args.resolve_exact_in().unwrap();
+    let ix = swap_exact_in_ix_by_mint_full(
+        free_args,
+        SwapExactInAmounts {
+            min_amount_out: 0,
+            amount: WSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &WSOL_LST_SOL_COMMON_METAS,
+            dst_lst_calculator_accounts: &jito_22_sol_val_calc_accounts,
+        },
+        &PriceExactInFreeArgs::from(price_exact_in_keys(&keys)).resolve_to_account_metas(),
+        flat_fee_lib::program::ID,
+    )
+    .unwrap();
+    let jitosol_22_protocol_fee_accumulator = ix.accounts[5].pubkey;
+    let jitosol_22_pool_reserves = ix.accounts[11].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let jitosol_22_sent_to_swapper = balance_of(&mut banks_client, swapper_jitosol_22_acc_addr)
+        .await
+        + withheld_of(&mut banks_client, swapper_jitosol_22_acc_addr).await;
+    let protocol_fees_received =
+        balance_of(&mut banks_client, jitosol_22_protocol_fee_accumulator).await;
+    let protocol_fees_sent = protocol_fees_received
+        + withheld_of(&mut banks_client, jitosol_22_protocol_fee_accumulator).await;
+
+    // accumulator absorbs the transfer fee on the protocol fees,
+    // pool reserves only lose the protocol fees amount
+    assert!(protocol_fees_received > 0);
+    assert!(protocol_fees_received < protocol_fees_sent);
+    assert_eq!(
+        protocol_fees_received,
+        JITOSOL_22_MINT_ARGS
+            .transfer_fee()
+            .calculate_post_fee_amount(protocol_fees_sent)
+            .unwrap()
+    );
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_22_pool_reserves).await,
+        JITOSOL_22_STARTING_POOL_RESERVES - jitosol_22_sent_to_swapper - protocol_fees_sent
+    );
+}
+
+#[tokio::test]
+async fn add_liquidity_protocol_fee_transfer_fee() {
+    const JITOSOL_22_TO_ADD: u64 = 1_000_000_000;
+
+    const JITOSOL_22_LP_DEPOSIT_FEE_BPS: u16 = 10;
+    const LP_PROTOCOL_FEE_BPS: u16 = 5_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_22_wsol_flat_fee_program_test_with_fees(
+        lp_token_mint,
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: Default::default(),
+                lp_deposit_fee_bps: JITOSOL_22_LP_DEPOSIT_FEE_BPS,
+                lp_withdrawal_fee_bps: None,
+                lst_mint: jitosol_22::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: Default::default(),
+                lp_deposit_fee_bps: Default::default(),
+                lp_withdrawal_fee_bps: None,
+                lst_mint: native_mint::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: Default::default(),
+            lp: LP_PROTOCOL_FEE_BPS,
+        },
+    );
+    let liquidity_provider_jitosol_22_acc_addr =
+        program_test.gen_and_add_token_2022_account(MockTokenAccountArgs {
+            mint: jitosol_22::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: JITOSOL_22_TO_ADD,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let lst_mint_account = banks_client.get_account_unwrapped(jitosol_22::ID).await;
+    let jito_22_sol_val_calc_accounts = jito_22_sol_val_calc_accounts(&mut banks_client).await;
+
+    let args = AddLiquidityByMintFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        src_lst_acc: liquidity_provider_jitosol_22_acc_addr,
+        dst_lp_acc: liquidity_provider_lp_token_acc_addr,
+        pool_state: pool_state_account,
+        lst_state_list: &lst_state_list_account,
+        lst_mint: KeyedAccount {
+            pubkey: jitosol_22::ID,
+            account: lst_mint_account,
+        },
+    };
+    let (keys, lst_index, _program_ids) = args.resolve().unwrap();
+    let jitosol_22_protocol_fee_accumulator = keys.protocol_fee_accumulator;
+    let jitosol_22_pool_reserves = keys.pool_reserves;
+    let pricing_program_price_lp_accounts: [AccountMeta;
+        flat_fee_interface::PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN] =
+        PriceLpTokensToMintFreeArgs::from(price_lp_tokens_to_mint_keys(&keys))
+            .resolve()
+            .into();
+    let ix = add_liquidity_ix_full(
+        keys,
+        AddLiquidityIxFullArgs {
+            lst_index,
+            amts: AddLiquidityIxAmts {
+                lst_amount: JITOSOL_22_TO_ADD,
+                min_lp_out: 0,
+            },
+        },
+        AddRemoveLiquidityExtraAccounts {
+            lst_calculator_program_id: spl_calculator_lib::program::ID,
+            pricing_program_id: flat_fee_lib::program::ID,
+            lst_calculator_accounts: &jito_22_sol_val_calc_accounts,
+            pricing_program_price_lp_accounts: &pricing_program_price_lp_accounts,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let jitosol_22_received_by_pool = JITOSOL_22_MINT_ARGS
+        .transfer_fee()
+        .calculate_post_fee_amount(JITOSOL_22_TO_ADD)
+        .unwrap();
+    let protocol_fees_received =
+        balance_of(&mut banks_client, jitosol_22_protocol_fee_accumulator).await;
+    let protocol_fees_sent = protocol_fees_received
+        + withheld_of(&mut banks_client, jitosol_22_protocol_fee_accumulator).await;
+
+    // accumulator absorbs the transfer fee on the protocol fees,
+    // pool reserves only lose the protocol fees amount
+    assert!(protocol_fees_received > 0);
+    assert!(protocol_fees_received < protocol_fees_sent);
+    assert_eq!(
+        protocol_fees_received,
+        JITOSOL_22_MINT_ARGS
+            .transfer_fee()
+            .calculate_post_fee_amount(protocol_fees_sent)
+            .unwrap()
+    );
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_22_pool_reserves).await,
+        JITOSOL_22_STARTING_POOL_RESERVES + jitosol_22_received_by_pool - protocol_fees_sent
+    );
+    assert!(balance_of(&mut banks_client, liquidity_provider_lp_token_acc_addr).await > 0);
+}
+
+/// Returns the [`DonateEvent`] logged in `log_messages`
+fn find_donate_event(log_messages: &[String]) -> DonateEvent {
+    log_messages
+        .iter()
+        .find_map(|log| {
+            let mut slices = log.strip_prefix("Program data: ")?.split(' ');
+            let tag = BASE64.decode(slices.next()?.as_bytes()).ok()?;
+            if tag != DONATE_EVENT_TAG {
+                return None;
+            }
+            let data = BASE64.decode(slices.next()?.as_bytes()).ok()?;
+            DonateEvent::try_from_slice(&data).ok()
+        })
+        .unwrap()
+}
+
+#[tokio::test]
+async fn donate_transfer_fee() {
+    const JITOSOL_22_TO_DONATE: u64 = 1_000_000_000;
+
+    let donor = Keypair::new();
+
+    let mut program_test = jito_22_wsol_program_test(Pubkey::new_unique());
+    let donor_jitosol_22_acc_addr =
+        program_test.gen_and_add_token_2022_account(MockTokenAccountArgs {
+            mint: jitosol_22::ID,
+            authority: donor.pubkey(),
+            amount: JITOSOL_22_TO_DONATE,
+        });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let lst_mint_account = banks_client.get_account_unwrapped(jitosol_22::ID).await;
+    let jito_22_sol_val_calc_accounts = jito_22_sol_val_calc_accounts(&mut banks_client).await;
+    let jitosol_22_pool_reserves = {
+        let lst_state_list = try_lst_state_list(&lst_state_list_account.data).unwrap();
+        let lst_state = lst_state_list
+            .iter()
+            .find(|s| s.mint == jitosol_22::ID)
+            .unwrap();
+        create_pool_reserves_address(lst_state, spl_token_2022::ID).unwrap()
+    };
+
+    let ix = donate_ix_by_mint_full(
+        DonateByMintFreeArgs {
+            donor: donor.pubkey(),
+            src_lst_acc: donor_jitosol_22_acc_addr,
+            lst_state_list: lst_state_list_account,
+            lst_mint: KeyedAccount {
+                pubkey: jitosol_22::ID,
+                account: lst_mint_account,
+            },
+        },
+        JITOSOL_22_TO_DONATE,
+        &jito_22_sol_val_calc_accounts,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &donor], last_blockhash);
+
+    let res = banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    res.result.unwrap();
+
+    let jitosol_22_received_by_pool = JITOSOL_22_MINT_ARGS
+        .transfer_fee()
+        .calculate_post_fee_amount(JITOSOL_22_TO_DONATE)
+        .unwrap();
+    assert!(jitosol_22_received_by_pool < JITOSOL_22_TO_DONATE);
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_22_pool_reserves).await,
+        JITOSOL_22_STARTING_POOL_RESERVES + jitosol_22_received_by_pool
+    );
+
+    // event logs the amount that reached the reserves, not the amount transferred
+    let event = find_donate_event(&res.metadata.unwrap().log_messages);
+    assert_eq!(event.donor, donor.pubkey());
+    assert_eq!(event.lst_mint, jitosol_22::ID);
+    assert_eq!(event.amount, jitosol_22_received_by_pool);
+    assert!(event.sol_value > 0);
+}
@@ -0,0 +1,8 @@
+use everstake_calculator_lib::{initial_manager, EverstakeSolValCalc};
+use generic_pool_calculator_onchain::processor::{process_init_unchecked, verify_init};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+pub fn process_init(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let checked = verify_init::<EverstakeSolValCalc>(accounts)?;
+    process_init_unchecked::<EverstakeSolValCalc>(checked, initial_manager::ID)
+}
@@ -0,0 +1,42 @@
+use everstake_calculator_lib::{
+    EverstakeLstSolCommonFreeArgs, EverstakeSolValCalc, EverstakeStakePoolCalc,
+};
+use generic_pool_calculator_interface::{lst_to_sol_verify_account_keys, LstToSolAccounts};
+use generic_pool_calculator_lib::utils::{
+    read_stale_discount_bps, verify_no_stake_pool_prog_upgrade, VerifyNoStakePoolProgUpgradeArgs,
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sol_value_calculator_lib::StaleDiscountCalc;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError, sysvar::Sysvar,
+};
+
+/// Assumes:
+/// - LstToSolAccounts/Keys and SolToLstAccounts/Keys are identical
+pub fn verify_lst_sol_common(
+    accounts: &[AccountInfo<'_>],
+) -> Result<StaleDiscountCalc<EverstakeStakePoolCalc>, ProgramError> {
+    let actual: LstToSolAccounts = load_accounts(accounts)?;
+
+    let root_keys = EverstakeLstSolCommonFreeArgs {
+        everstake_stake_pool: actual.pool_state,
+        everstake_stake_pool_prog: actual.pool_program,
+    };
+    let (intermediate, stake_pool) = root_keys.resolve()?;
+    let expected = intermediate.resolve::<EverstakeSolValCalc>()?.into();
+
+    lst_to_sol_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    // accounts should all be read-only, no need to verify_account_privileges
+
+    verify_no_stake_pool_prog_upgrade(VerifyNoStakePoolProgUpgradeArgs {
+        stake_pool_prog_data: actual.pool_program_data,
+        calculator_state: actual.state,
+    })?;
+
+    let calc: EverstakeStakePoolCalc = stake_pool.into();
+    let stale_discount_bps = read_stale_discount_bps(actual.state)?;
+    let calc =
+        calc.verify_pool_updated_or_stale_bounded(Clock::get()?.epoch, stale_discount_bps)?;
+
+    Ok(calc)
+}
@@ -0,0 +1,52 @@
+use everstake_calculator_lib::EverstakeSolValCalc;
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use sanctum_solana_test_utils::{ExtendedProgramTest, KeyedUiAccount};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use test_utils::EVERSTAKE_STAKE_POOL_PROG_LAST_UPDATED_SLOT;
+
+pub struct EversolNormalProgramTest {
+    pub program_test: ProgramTest,
+    pub eversol_stake_pool: KeyedAccount,
+    pub everstake_stake_pool_prog: KeyedAccount,
+}
+
+pub fn eversol_normal_program_test() -> EversolNormalProgramTest {
+    let mut program_test = ProgramTest::default();
+    // name must match <name>.so filename
+    program_test.add_program(
+        "everstake_calculator",
+        everstake_calculator_lib::program::ID,
+        processor!(everstake_calculator::entrypoint::process_instruction),
+    );
+
+    let everstake_stake_pool_prog_ui_acc =
+        KeyedUiAccount::from_test_fixtures_file("everstake-stake-pool-prog.json");
+    let eversol_stake_pool_ui_acc =
+        KeyedUiAccount::from_test_fixtures_file("eversol-stake-pool.json");
+
+    let everstake_stake_pool_prog = everstake_stake_pool_prog_ui_acc.to_keyed_account();
+    let eversol_stake_pool = eversol_stake_pool_ui_acc.to_keyed_account();
+
+    program_test = program_test
+        .add_mock_calculator_state(MockCalculatorStateAccountArgs {
+            manager: Pubkey::default(),
+            last_upgrade_slot: EVERSTAKE_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
+            stale_discount_bps: Default::default(),
+            approved_program_data_hash: Default::default(),
+            owner: EverstakeSolValCalc::ID,
+        })
+        .add_keyed_ui_account(everstake_stake_pool_prog_ui_acc)
+        .add_keyed_ui_account(eversol_stake_pool_ui_acc)
+        .add_test_fixtures_account("everstake-stake-pool-prog-data.json");
+
+    EversolNormalProgramTest {
+        program_test,
+        everstake_stake_pool_prog,
+        eversol_stake_pool,
+    }
+}
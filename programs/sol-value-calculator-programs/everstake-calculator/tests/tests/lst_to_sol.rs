@@ -0,0 +1,57 @@
+use generic_pool_calculator_interface::{LstToSolIxArgs, LstToSolKeys};
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program::clock::Clock;
+use solana_program_test::ProgramTestContext;
+
+use everstake_calculator_lib::{
+    everstake_lst_to_sol_ix, EverstakeLstSolCommonFreeArgs, EverstakeSolValCalc,
+};
+use test_utils::{BorshReturnDataBanksClient, EVERSOL_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{eversol_normal_program_test, EversolNormalProgramTest};
+
+#[tokio::test]
+async fn eversol_basic() {
+    const LST_AMOUNT: u64 = 1_000_000_000;
+    const EXPECTED_LAMPORTS_RANGE: U64ValueRange = U64ValueRange::single(1_050_000_000);
+
+    let EversolNormalProgramTest {
+        program_test,
+        eversol_stake_pool,
+        everstake_stake_pool_prog,
+    } = eversol_normal_program_test();
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: EVERSOL_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let free_args = EverstakeLstSolCommonFreeArgs {
+        everstake_stake_pool: eversol_stake_pool,
+        everstake_stake_pool_prog,
+    };
+    let (intermediate, _stake_pool) = free_args.resolve().unwrap();
+    let accounts: LstToSolKeys = intermediate
+        .resolve::<EverstakeSolValCalc>()
+        .unwrap()
+        .into();
+
+    let ix = everstake_lst_to_sol_ix(accounts, LstToSolIxArgs { amount: LST_AMOUNT }).unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LAMPORTS_RANGE,
+        )
+        .await;
+}
@@ -0,0 +1,63 @@
+use generic_pool_calculator_interface::{SolToLstIxArgs, SolToLstKeys};
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program::clock::Clock;
+use solana_program_test::ProgramTestContext;
+
+use everstake_calculator_lib::{
+    everstake_sol_to_lst_ix, EverstakeLstSolCommonFreeArgs, EverstakeSolValCalc,
+};
+use test_utils::{BorshReturnDataBanksClient, EVERSOL_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{eversol_normal_program_test, EversolNormalProgramTest};
+
+#[tokio::test]
+async fn eversol_basic() {
+    const LAMPORTS_AMOUNT: u64 = 1_050_000_000;
+    const EXPECTED_LST_RANGE: U64ValueRange = U64ValueRange::single(1_000_000_000);
+
+    let EversolNormalProgramTest {
+        program_test,
+        eversol_stake_pool,
+        everstake_stake_pool_prog,
+    } = eversol_normal_program_test();
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: EVERSOL_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let free_args = EverstakeLstSolCommonFreeArgs {
+        everstake_stake_pool: eversol_stake_pool,
+        everstake_stake_pool_prog,
+    };
+    let (intermediate, _stake_pool) = free_args.resolve().unwrap();
+    let accounts: SolToLstKeys = intermediate
+        .resolve::<EverstakeSolValCalc>()
+        .unwrap()
+        .into();
+
+    let ix = everstake_sol_to_lst_ix(
+        accounts,
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LST_RANGE,
+        )
+        .await;
+}
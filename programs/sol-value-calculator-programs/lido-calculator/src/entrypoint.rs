@@ -5,7 +5,9 @@ use solana_program::{
 };
 
 use crate::processor::{
-    process_init, process_lst_to_sol, process_set_manager, process_sol_to_lst,
+    process_batch_calc, process_init, process_lst_to_sol, process_migrate_state,
+    process_permissionless_update_last_upgrade_slot, process_set_approved_program_data_hash,
+    process_set_manager, process_set_stale_discount, process_sol_to_lst,
     process_update_last_upgrade_slot,
 };
 
@@ -27,6 +29,17 @@ pub fn process_instruction(
     match ix {
         GenericPoolCalculatorProgramIx::LstToSol(args) => process_lst_to_sol(accounts, args),
         GenericPoolCalculatorProgramIx::SolToLst(args) => process_sol_to_lst(accounts, args),
+        GenericPoolCalculatorProgramIx::BatchCalc(args) => process_batch_calc(accounts, args),
+        GenericPoolCalculatorProgramIx::PermissionlessUpdateLastUpgradeSlot => {
+            process_permissionless_update_last_upgrade_slot(accounts)
+        }
+        GenericPoolCalculatorProgramIx::SetApprovedProgramDataHash(args) => {
+            process_set_approved_program_data_hash(accounts, args)
+        }
+        GenericPoolCalculatorProgramIx::MigrateState => process_migrate_state(accounts),
+        GenericPoolCalculatorProgramIx::SetStaleDiscount(args) => {
+            process_set_stale_discount(accounts, args)
+        }
         GenericPoolCalculatorProgramIx::UpdateLastUpgradeSlot => {
             process_update_last_upgrade_slot(accounts)
         }
@@ -1,18 +1,21 @@
 use borsh::BorshDeserialize;
 use generic_pool_calculator_interface::{lst_to_sol_verify_account_keys, LstToSolAccounts};
 use generic_pool_calculator_lib::utils::{
-    verify_no_stake_pool_prog_upgrade, VerifyNoStakePoolProgUpgradeArgs,
+    read_stale_discount_bps, verify_no_stake_pool_prog_upgrade, VerifyNoStakePoolProgUpgradeArgs,
 };
 use lido_calculator_interface::{AccountType, Lido};
 use lido_calculator_lib::{LidoCalc, LidoSolValCalc, LIDO_LST_SOL_COMMON_INTERMEDIATE_KEYS};
 use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sol_value_calculator_lib::StaleDiscountCalc;
 use solana_program::{
     account_info::AccountInfo, clock::Clock, program_error::ProgramError, sysvar::Sysvar,
 };
 
 /// Assumes:
 /// - LstToSolAccounts/Keys and SolToLstAccounts/Keys are identical
-pub fn verify_lst_sol_common(accounts: &[AccountInfo<'_>]) -> Result<LidoCalc, ProgramError> {
+pub fn verify_lst_sol_common(
+    accounts: &[AccountInfo<'_>],
+) -> Result<StaleDiscountCalc<LidoCalc>, ProgramError> {
     let actual: LstToSolAccounts = load_accounts(accounts)?;
 
     let expected = LIDO_LST_SOL_COMMON_INTERMEDIATE_KEYS
@@ -33,7 +36,9 @@ pub fn verify_lst_sol_common(accounts: &[AccountInfo<'_>]) -> Result<LidoCalc, P
     }
     let calc: LidoCalc = state.into();
 
-    calc.verify_pool_updated_for_this_epoch(Clock::get()?.epoch)?;
+    let stale_discount_bps = read_stale_discount_bps(actual.state)?;
+    let calc =
+        calc.verify_pool_updated_or_stale_bounded(Clock::get()?.epoch, stale_discount_bps)?;
 
     Ok(calc)
 }
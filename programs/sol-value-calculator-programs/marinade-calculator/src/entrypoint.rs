@@ -1,12 +1,17 @@
 use generic_pool_calculator_interface::GenericPoolCalculatorProgramIx;
+use marinade_calculator_interface::{
+    MarinadeCalculatorProgramIx, SET_DELAYED_UNSTAKE_FALLBACK_IX_DISCM,
+};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
 use crate::processor::{
-    process_init, process_lst_to_sol, process_set_manager, process_sol_to_lst,
-    process_update_last_upgrade_slot,
+    process_batch_calc, process_init, process_lst_to_sol, process_migrate_state,
+    process_permissionless_update_last_upgrade_slot, process_set_approved_program_data_hash,
+    process_set_delayed_unstake_fallback, process_set_manager, process_set_stale_discount,
+    process_sol_to_lst, process_update_last_upgrade_slot,
 };
 
 #[cfg(not(feature = "no-entrypoint"))]
@@ -21,12 +26,33 @@ pub fn process_instruction(
         return Err(ProgramError::IncorrectProgramId);
     }
 
+    if instruction_data.first() == Some(&SET_DELAYED_UNSTAKE_FALLBACK_IX_DISCM) {
+        let ix = MarinadeCalculatorProgramIx::deserialize(instruction_data)?;
+        solana_program::msg!("{:?}", ix);
+        return match ix {
+            MarinadeCalculatorProgramIx::SetDelayedUnstakeFallback(args) => {
+                process_set_delayed_unstake_fallback(accounts, args)
+            }
+        };
+    }
+
     let ix = GenericPoolCalculatorProgramIx::deserialize(instruction_data)?;
     solana_program::msg!("{:?}", ix);
 
     match ix {
         GenericPoolCalculatorProgramIx::LstToSol(args) => process_lst_to_sol(accounts, args),
         GenericPoolCalculatorProgramIx::SolToLst(args) => process_sol_to_lst(accounts, args),
+        GenericPoolCalculatorProgramIx::BatchCalc(args) => process_batch_calc(accounts, args),
+        GenericPoolCalculatorProgramIx::PermissionlessUpdateLastUpgradeSlot => {
+            process_permissionless_update_last_upgrade_slot(accounts)
+        }
+        GenericPoolCalculatorProgramIx::SetApprovedProgramDataHash(args) => {
+            process_set_approved_program_data_hash(accounts, args)
+        }
+        GenericPoolCalculatorProgramIx::MigrateState => process_migrate_state(accounts),
+        GenericPoolCalculatorProgramIx::SetStaleDiscount(args) => {
+            process_set_stale_discount(accounts, args)
+        }
         GenericPoolCalculatorProgramIx::UpdateLastUpgradeSlot => {
             process_update_last_upgrade_slot(accounts)
         }
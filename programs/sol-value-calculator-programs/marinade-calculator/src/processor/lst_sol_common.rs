@@ -1,11 +1,14 @@
 use borsh::BorshDeserialize;
-use generic_pool_calculator_interface::{lst_to_sol_verify_account_keys, LstToSolAccounts};
+use generic_pool_calculator_interface::{
+    lst_to_sol_verify_account_keys, LstToSolAccounts, LST_TO_SOL_IX_ACCOUNTS_LEN,
+};
 use generic_pool_calculator_lib::utils::{
     verify_no_stake_pool_prog_upgrade, VerifyNoStakePoolProgUpgradeArgs,
 };
 use marinade_calculator_interface::MarinadeState;
 use marinade_calculator_lib::{
-    MarinadeSolValCalc, MarinadeStateCalc, MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
+    read_delayed_unstake_fallback, MarinadeSolValCalc, MarinadeStateCalc,
+    MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
 };
 use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
 use solana_program::{account_info::AccountInfo, program_error::ProgramError};
@@ -14,6 +17,8 @@ const MARINADE_STATE_DISCM: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
 
 /// Assumes:
 /// - LstToSolAccounts/Keys and SolToLstAccounts/Keys are identical
+///
+/// Expects the MarinadeCalcConfig PDA right after LstToSolAccounts
 pub fn verify_lst_sol_common(
     accounts: &[AccountInfo<'_>],
 ) -> Result<MarinadeStateCalc, ProgramError> {
@@ -35,9 +40,16 @@ pub fn verify_lst_sol_common(
     if state.discriminant != MARINADE_STATE_DISCM {
         return Err(ProgramError::InvalidAccountData);
     }
-    let calc: MarinadeStateCalc = state.into();
 
-    calc.verify_can_withdraw_stake()?;
+    let calc_config = accounts
+        .get(LST_TO_SOL_IX_ACCOUNTS_LEN)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let delayed_unstake_fallback_enabled = read_delayed_unstake_fallback(calc_config)?;
+
+    let calc = MarinadeStateCalc::from(state)
+        .with_delayed_unstake_fallback(delayed_unstake_fallback_enabled);
+
+    calc.verify_can_unstake()?;
 
     Ok(calc)
 }
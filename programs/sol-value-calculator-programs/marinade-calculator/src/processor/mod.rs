@@ -1,12 +1,24 @@
+mod batch_calc;
 mod init;
 mod lst_sol_common;
 mod lst_to_sol;
+mod migrate_state;
+mod permissionless_update_last_upgrade_slot;
+mod set_approved_program_data_hash;
+mod set_delayed_unstake_fallback;
 mod set_manager;
+mod set_stale_discount;
 mod sol_to_lst;
 mod update_last_upgrade_slot;
 
+pub use batch_calc::*;
 pub use init::*;
 pub use lst_to_sol::*;
+pub use migrate_state::*;
+pub use permissionless_update_last_upgrade_slot::*;
+pub use set_approved_program_data_hash::*;
+pub use set_delayed_unstake_fallback::*;
 pub use set_manager::*;
+pub use set_stale_discount::*;
 pub use sol_to_lst::*;
 pub use update_last_upgrade_slot::*;
@@ -0,0 +1,73 @@
+use bytemuck::try_from_bytes_mut;
+use marinade_calculator_interface::{
+    set_delayed_unstake_fallback_verify_account_keys,
+    set_delayed_unstake_fallback_verify_account_privileges, MarinadeCalcConfig,
+    MarinadeCalculatorError, SetDelayedUnstakeFallbackAccounts, SetDelayedUnstakeFallbackIxArgs,
+    SetDelayedUnstakeFallbackKeys,
+};
+use marinade_calculator_lib::{
+    program, SetDelayedUnstakeFallbackFreeArgs, MARINADE_CALC_CONFIG_SIZE,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use system_program_interface::CreateAccountAccounts;
+
+pub fn process_set_delayed_unstake_fallback(
+    accounts: &[AccountInfo],
+    SetDelayedUnstakeFallbackIxArgs { enabled }: SetDelayedUnstakeFallbackIxArgs,
+) -> ProgramResult {
+    let SetDelayedUnstakeFallbackAccounts {
+        payer, calc_config, ..
+    } = verify_set_delayed_unstake_fallback(accounts)?;
+
+    if calc_config.data_is_empty() {
+        init_rent_exempt_account_invoke_signed(
+            CreateAccountAccounts {
+                from: payer,
+                to: calc_config,
+            },
+            InitRentExemptAccountArgs {
+                space: MARINADE_CALC_CONFIG_SIZE,
+                owner: program::ID,
+            },
+            &[&[
+                program::MARINADE_CALCULATOR_CONFIG_SEED,
+                &[program::MARINADE_CALCULATOR_CONFIG_BUMP],
+            ]],
+        )?;
+    }
+
+    let mut bytes = calc_config.try_borrow_mut_data()?;
+    let calc_config: &mut MarinadeCalcConfig = try_from_bytes_mut(&mut bytes)
+        .map_err(|_e| MarinadeCalculatorError::IncorrectCalcConfig)?;
+
+    calc_config.delayed_unstake_fallback = enabled.into();
+
+    Ok(())
+}
+
+fn verify_set_delayed_unstake_fallback<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<SetDelayedUnstakeFallbackAccounts<'me, 'info>, ProgramError> {
+    let actual: SetDelayedUnstakeFallbackAccounts = load_accounts(accounts)?;
+
+    let free_args = SetDelayedUnstakeFallbackFreeArgs {
+        payer: *actual.payer.key,
+        state: actual.state,
+    };
+    let expected: SetDelayedUnstakeFallbackKeys = free_args.resolve()?;
+
+    set_delayed_unstake_fallback_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_delayed_unstake_fallback_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,14 @@
+use generic_pool_calculator_interface::SetStaleDiscountIxArgs;
+use generic_pool_calculator_onchain::processor::{
+    process_set_stale_discount_unchecked, verify_set_stale_discount,
+};
+use marinade_calculator_lib::MarinadeSolValCalc;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+pub fn process_set_stale_discount(
+    accounts: &[AccountInfo],
+    SetStaleDiscountIxArgs { stale_discount_bps }: SetStaleDiscountIxArgs,
+) -> Result<(), ProgramError> {
+    let checked = verify_set_stale_discount::<MarinadeSolValCalc>(accounts, stale_discount_bps)?;
+    process_set_stale_discount_unchecked(checked, stale_discount_bps)
+}
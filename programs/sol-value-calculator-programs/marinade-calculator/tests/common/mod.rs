@@ -1,14 +1,38 @@
+use borsh::{BorshDeserialize, BorshSerialize};
 use generic_pool_calculator_lib::GenericPoolSolValCalc;
 use generic_pool_calculator_test_utils::{
     GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
 };
+use marinade_calculator_interface::MarinadeState;
 use marinade_calculator_lib::MarinadeSolValCalc;
-use sanctum_solana_test_utils::ExtendedProgramTest;
+use sanctum_solana_test_utils::{ExtendedProgramTest, KeyedUiAccount};
 use solana_program::pubkey::Pubkey;
 use solana_program_test::{processor, ProgramTest};
 use test_utils::MARINADE_PROG_LAST_UPDATED_SLOT;
 
 pub fn marinade_normal_program_test() -> ProgramTest {
+    marinade_program_test(Pubkey::default()).add_test_fixtures_account("marinade-state.json")
+}
+
+/// Returns the program test and the modified MarinadeState
+pub fn marinade_stake_withdraw_disabled_program_test(
+    manager: Pubkey,
+) -> (ProgramTest, MarinadeState) {
+    let mut marinade_state =
+        KeyedUiAccount::from_test_fixtures_file("marinade-state.json").to_keyed_account();
+    let mut state =
+        MarinadeState::deserialize(&mut marinade_state.account.data.as_slice()).unwrap();
+    state.withdraw_stake_account_enabled = false;
+    state
+        .serialize(&mut marinade_state.account.data.as_mut_slice())
+        .unwrap();
+
+    let mut program_test = marinade_program_test(manager);
+    program_test.add_account(marinade_state.pubkey, marinade_state.account);
+    (program_test, state)
+}
+
+fn marinade_program_test(manager: Pubkey) -> ProgramTest {
     let mut program_test = ProgramTest::default();
     // name must match <name>.so filename
     program_test.add_program(
@@ -18,11 +42,12 @@ pub fn marinade_normal_program_test() -> ProgramTest {
     );
     program_test
         .add_mock_calculator_state(MockCalculatorStateAccountArgs {
-            manager: Pubkey::default(),
+            manager,
             last_upgrade_slot: MARINADE_PROG_LAST_UPDATED_SLOT,
+            stale_discount_bps: Default::default(),
+            approved_program_data_hash: Default::default(),
             owner: MarinadeSolValCalc::ID,
         })
-        .add_test_fixtures_account("marinade-state.json")
         .add_test_fixtures_account("marinade-prog.json")
         .add_test_fixtures_account("marinade-prog-data.json")
 }
@@ -1,2 +1,3 @@
 mod lst_to_sol;
+mod set_delayed_unstake_fallback;
 mod sol_to_lst;
@@ -0,0 +1,154 @@
+use generic_pool_calculator_interface::{LstToSolIxArgs, LstToSolKeys};
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use marinade_calculator_interface::{MarinadeCalculatorError, SetDelayedUnstakeFallbackIxArgs};
+use marinade_calculator_lib::{
+    marinade_lst_to_sol_ix, marinade_set_delayed_unstake_fallback_ix, program,
+    try_marinade_calc_config, MarinadeSolValCalc, MarinadeStateCalc,
+    SetDelayedUnstakeFallbackFreeArgs, MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
+};
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{instruction::AccountMeta, program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::BanksClient;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::marinade_stake_withdraw_disabled_program_test;
+
+const LST_AMOUNT: u64 = 1_000_000_000;
+
+async fn set_delayed_unstake_fallback(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    manager: &Keypair,
+    enabled: bool,
+) {
+    let state = banks_client
+        .get_account_unwrapped(MarinadeSolValCalc::CALCULATOR_STATE_PDA)
+        .await;
+    let keys = SetDelayedUnstakeFallbackFreeArgs {
+        payer: payer.pubkey(),
+        state: KeyedAccount {
+            pubkey: MarinadeSolValCalc::CALCULATOR_STATE_PDA,
+            account: state,
+        },
+    }
+    .resolve()
+    .unwrap();
+
+    let ix =
+        marinade_set_delayed_unstake_fallback_ix(keys, SetDelayedUnstakeFallbackIxArgs { enabled })
+            .unwrap();
+    let last_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, manager], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let calc_config_acc = banks_client
+        .get_account_unwrapped(program::MARINADE_CALCULATOR_CONFIG_ID)
+        .await;
+    assert_eq!(calc_config_acc.owner, MarinadeSolValCalc::ID);
+    let calc_config = try_marinade_calc_config(&calc_config_acc.data).unwrap();
+    assert_eq!(calc_config.delayed_unstake_fallback, u8::from(enabled));
+}
+
+fn lst_to_sol_ix() -> solana_program::instruction::Instruction {
+    let accounts: LstToSolKeys = MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS
+        .resolve::<MarinadeSolValCalc>()
+        .into();
+    marinade_lst_to_sol_ix(accounts, LstToSolIxArgs { amount: LST_AMOUNT }).unwrap()
+}
+
+#[tokio::test]
+async fn fail_stake_withdraw_disabled_no_fallback() {
+    let (program_test, _state) = marinade_stake_withdraw_disabled_program_test(Pubkey::default());
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let mut tx = Transaction::new_with_payer(&[lst_to_sol_ix()], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, MarinadeCalculatorError::MarinadeStakeWithdrawDisabled);
+}
+
+#[tokio::test]
+async fn enable_then_disable_fallback() {
+    let manager = Keypair::new();
+    let (program_test, state) = marinade_stake_withdraw_disabled_program_test(manager.pubkey());
+
+    let (mut banks_client, payer, _last_blockhash) = program_test.start().await;
+
+    set_delayed_unstake_fallback(&mut banks_client, &payer, &manager, true).await;
+
+    let calc = MarinadeStateCalc::from(state).with_delayed_unstake_fallback(true);
+    let expected = calc.calc_lst_to_sol(LST_AMOUNT).unwrap();
+    let last_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            lst_to_sol_ix(),
+            expected,
+        )
+        .await;
+
+    set_delayed_unstake_fallback(&mut banks_client, &payer, &manager, false).await;
+
+    let last_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let mut tx = Transaction::new_with_payer(&[lst_to_sol_ix()], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, MarinadeCalculatorError::MarinadeStakeWithdrawDisabled);
+}
+
+#[tokio::test]
+async fn fail_unauthorized_manager() {
+    let (program_test, _state) =
+        marinade_stake_withdraw_disabled_program_test(Pubkey::new_unique());
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state = banks_client
+        .get_account_unwrapped(MarinadeSolValCalc::CALCULATOR_STATE_PDA)
+        .await;
+    let mut keys = SetDelayedUnstakeFallbackFreeArgs {
+        payer: payer.pubkey(),
+        state: KeyedAccount {
+            pubkey: MarinadeSolValCalc::CALCULATOR_STATE_PDA,
+            account: state,
+        },
+    }
+    .resolve()
+    .unwrap();
+    keys.manager = payer.pubkey();
+
+    let ix = marinade_set_delayed_unstake_fallback_ix(
+        keys,
+        SetDelayedUnstakeFallbackIxArgs { enabled: true },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
+
+#[tokio::test]
+async fn fail_lst_to_sol_wrong_calc_config() {
+    let (program_test, _state) = marinade_stake_withdraw_disabled_program_test(Pubkey::default());
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let mut ix = lst_to_sol_ix();
+    *ix.accounts.last_mut().unwrap() = AccountMeta::new_readonly(Pubkey::new_unique(), false);
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, MarinadeCalculatorError::IncorrectCalcConfig);
+}
@@ -0,0 +1,37 @@
+use posted_rate_calculator_interface::PostedRateCalculatorProgramIx;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::processor::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if *program_id != posted_rate_calculator_lib::program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let ix = PostedRateCalculatorProgramIx::deserialize(instruction_data)?;
+    solana_program::msg!("{:?}", ix);
+
+    match ix {
+        PostedRateCalculatorProgramIx::LstToSol(args) => process_lst_to_sol(accounts, args),
+        PostedRateCalculatorProgramIx::SolToLst(args) => process_sol_to_lst(accounts, args),
+        PostedRateCalculatorProgramIx::BatchCalc(args) => process_batch_calc(accounts, args),
+        PostedRateCalculatorProgramIx::SetRateGuardrails(args) => {
+            process_set_rate_guardrails(accounts, args)
+        }
+        PostedRateCalculatorProgramIx::SetRate(args) => process_set_rate(accounts, args),
+        PostedRateCalculatorProgramIx::RemoveLst => process_remove_lst(accounts),
+        PostedRateCalculatorProgramIx::AddLst(args) => process_add_lst(accounts, args),
+        PostedRateCalculatorProgramIx::SetManager => process_set_manager(accounts),
+        PostedRateCalculatorProgramIx::Initialize => process_initialize(accounts),
+    }
+}
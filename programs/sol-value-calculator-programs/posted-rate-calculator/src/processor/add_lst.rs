@@ -0,0 +1,91 @@
+use posted_rate_calculator_interface::{
+    add_lst_verify_account_keys, add_lst_verify_account_privileges, AddLstAccounts, AddLstIxArgs,
+    AddLstKeys,
+};
+use posted_rate_calculator_lib::{
+    account_resolvers::AddLstFreeArgs, guardrails::verify_rate, pda::RateAccountCreatePdaArgs,
+    program, utils::try_rate_account_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_s_common::token::verify_tokenkeg_or_22_mint;
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+use system_program_interface::CreateAccountAccounts;
+
+pub fn process_add_lst(accounts: &[AccountInfo], args: AddLstIxArgs) -> ProgramResult {
+    let (
+        AddLstAccounts {
+            payer, rate_acc, ..
+        },
+        AddLstIxArgs {
+            rate_num,
+            rate_denom,
+            max_rate_change_bps,
+            is_monotonic,
+            max_staleness_slots,
+        },
+        create_pda_args,
+    ) = verify_add_lst(accounts, args)?;
+
+    init_rent_exempt_account_invoke_signed(
+        CreateAccountAccounts {
+            from: payer,
+            to: rate_acc,
+        },
+        InitRentExemptAccountArgs {
+            space: program::RATE_ACCOUNT_SIZE,
+            owner: program::ID,
+        },
+        &[create_pda_args.to_signer_seeds().as_slice()],
+    )?;
+
+    let mut bytes = rate_acc.try_borrow_mut_data()?;
+    let rate_acc = try_rate_account_mut(&mut bytes)?;
+
+    rate_acc.bump = create_pda_args.bump;
+    rate_acc.is_monotonic = is_monotonic.into();
+    rate_acc.max_rate_change_bps = max_rate_change_bps;
+    rate_acc.max_staleness_slots = max_staleness_slots;
+    rate_acc.last_updated_slot = Clock::get()?.slot;
+    rate_acc.rate_num = rate_num;
+    rate_acc.rate_denom = rate_denom;
+
+    Ok(())
+}
+
+fn verify_add_lst<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    args: AddLstIxArgs,
+) -> Result<
+    (
+        AddLstAccounts<'me, 'info>,
+        AddLstIxArgs,
+        RateAccountCreatePdaArgs,
+    ),
+    ProgramError,
+> {
+    let actual: AddLstAccounts = load_accounts(accounts)?;
+
+    let free_args = AddLstFreeArgs {
+        payer: *actual.payer.key,
+        state_acc: actual.state,
+        lst_mint: *actual.lst_mint.key,
+    };
+    let (expected, rate_account_create_pda_args): (AddLstKeys, RateAccountCreatePdaArgs) =
+        free_args.resolve()?;
+
+    add_lst_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    add_lst_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_tokenkeg_or_22_mint(actual.lst_mint)?;
+    verify_rate(args.rate_num, args.rate_denom)?;
+
+    Ok((actual, args, rate_account_create_pda_args))
+}
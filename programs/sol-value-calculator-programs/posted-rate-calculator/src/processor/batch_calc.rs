@@ -0,0 +1,16 @@
+use posted_rate_calculator_interface::BatchCalcIxArgs;
+use sol_value_calculator_onchain::process_batch_calc_unchecked;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+use super::lst_sol_common::verify_lst_sol_common;
+
+pub fn process_batch_calc(
+    accounts: &[AccountInfo],
+    BatchCalcIxArgs {
+        lst_to_sol_amounts,
+        sol_to_lst_amounts,
+    }: BatchCalcIxArgs,
+) -> Result<(), ProgramError> {
+    let calc = verify_lst_sol_common(accounts)?;
+    process_batch_calc_unchecked(&calc, &lst_to_sol_amounts, &sol_to_lst_amounts)
+}
@@ -0,0 +1,35 @@
+use posted_rate_calculator_interface::{lst_to_sol_verify_account_keys, LstToSolAccounts};
+use posted_rate_calculator_lib::{
+    account_resolvers::{PostedRateLstSolCommonFreeArgs, PostedRateLstSolCommonWithBumpFreeArgs},
+    calc::PostedRateCalc,
+    utils::try_rate_account,
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError, sysvar::Sysvar,
+};
+
+/// Assumes:
+/// - LstToSolAccounts/Keys and SolToLstAccounts/Keys are identical
+pub fn verify_lst_sol_common(accounts: &[AccountInfo<'_>]) -> Result<PostedRateCalc, ProgramError> {
+    let actual: LstToSolAccounts = load_accounts(accounts)?;
+
+    let bytes = actual.rate_acc.try_borrow_data()?;
+    let rate_acc = try_rate_account(&bytes)?;
+
+    let expected = PostedRateLstSolCommonWithBumpFreeArgs {
+        args: PostedRateLstSolCommonFreeArgs {
+            lst_mint: *actual.lst_mint.key,
+        },
+        rate_acc_bump: rate_acc.bump,
+    }
+    .resolve()?;
+
+    lst_to_sol_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    // accounts should all be read-only, no need to verify_account_privileges
+
+    let calc: PostedRateCalc = rate_acc.into();
+    calc.verify_rate_not_stale(Clock::get()?.slot)?;
+
+    Ok(calc)
+}
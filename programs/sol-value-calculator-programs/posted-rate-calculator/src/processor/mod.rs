@@ -0,0 +1,20 @@
+mod add_lst;
+mod batch_calc;
+mod initialize;
+mod lst_sol_common;
+mod lst_to_sol;
+mod remove_lst;
+mod set_manager;
+mod set_rate;
+mod set_rate_guardrails;
+mod sol_to_lst;
+
+pub use add_lst::*;
+pub use batch_calc::*;
+pub use initialize::*;
+pub use lst_to_sol::*;
+pub use remove_lst::*;
+pub use set_manager::*;
+pub use set_rate::*;
+pub use set_rate_guardrails::*;
+pub use sol_to_lst::*;
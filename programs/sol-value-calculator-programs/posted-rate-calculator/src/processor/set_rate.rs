@@ -0,0 +1,58 @@
+use posted_rate_calculator_interface::{
+    set_rate_verify_account_keys, set_rate_verify_account_privileges, SetRateAccounts,
+    SetRateIxArgs, SetRateKeys,
+};
+use posted_rate_calculator_lib::{
+    account_resolvers::SetRateFreeArgs,
+    guardrails::verify_rate_update,
+    utils::{try_rate_account, try_rate_account_mut},
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+pub fn process_set_rate(
+    accounts: &[AccountInfo],
+    SetRateIxArgs {
+        rate_num,
+        rate_denom,
+    }: SetRateIxArgs,
+) -> ProgramResult {
+    let SetRateAccounts { rate_acc, .. } = verify_set_rate(accounts, rate_num, rate_denom)?;
+
+    let mut bytes = rate_acc.try_borrow_mut_data()?;
+    let rate_acc = try_rate_account_mut(&mut bytes)?;
+
+    rate_acc.rate_num = rate_num;
+    rate_acc.rate_denom = rate_denom;
+    rate_acc.last_updated_slot = Clock::get()?.slot;
+
+    Ok(())
+}
+
+fn verify_set_rate<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    rate_num: u64,
+    rate_denom: u64,
+) -> Result<SetRateAccounts<'me, 'info>, ProgramError> {
+    let actual: SetRateAccounts = load_accounts(accounts)?;
+
+    let free_args = SetRateFreeArgs {
+        state_acc: actual.state,
+        rate_acc: *actual.rate_acc.key,
+    };
+    let expected: SetRateKeys = free_args.resolve()?;
+
+    set_rate_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_rate_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    let bytes = actual.rate_acc.try_borrow_data()?;
+    let rate_acc = try_rate_account(&bytes)?;
+    verify_rate_update(rate_acc, rate_num, rate_denom)?;
+
+    Ok(actual)
+}
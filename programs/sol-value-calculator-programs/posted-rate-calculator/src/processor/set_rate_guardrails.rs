@@ -0,0 +1,52 @@
+use posted_rate_calculator_interface::{
+    set_rate_guardrails_verify_account_keys, set_rate_guardrails_verify_account_privileges,
+    SetRateGuardrailsAccounts, SetRateGuardrailsIxArgs, SetRateGuardrailsKeys,
+};
+use posted_rate_calculator_lib::{
+    account_resolvers::SetRateGuardrailsFreeArgs, utils::try_rate_account_mut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+pub fn process_set_rate_guardrails(
+    accounts: &[AccountInfo],
+    SetRateGuardrailsIxArgs {
+        max_rate_change_bps,
+        is_monotonic,
+        max_staleness_slots,
+    }: SetRateGuardrailsIxArgs,
+) -> ProgramResult {
+    let SetRateGuardrailsAccounts { rate_acc, .. } = verify_set_rate_guardrails(accounts)?;
+
+    let mut bytes = rate_acc.try_borrow_mut_data()?;
+    let rate_acc = try_rate_account_mut(&mut bytes)?;
+
+    rate_acc.max_rate_change_bps = max_rate_change_bps;
+    rate_acc.is_monotonic = is_monotonic.into();
+    rate_acc.max_staleness_slots = max_staleness_slots;
+
+    Ok(())
+}
+
+fn verify_set_rate_guardrails<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<SetRateGuardrailsAccounts<'me, 'info>, ProgramError> {
+    let actual: SetRateGuardrailsAccounts = load_accounts(accounts)?;
+
+    let free_args = SetRateGuardrailsFreeArgs {
+        state_acc: actual.state,
+        rate_acc: *actual.rate_acc.key,
+    };
+    let expected: SetRateGuardrailsKeys = free_args.resolve()?;
+
+    set_rate_guardrails_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_rate_guardrails_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,35 @@
+use posted_rate_calculator_interface::ProgramState;
+use posted_rate_calculator_test_utils::MockRateAccountArgs;
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::ProgramTestContext;
+
+use super::normal_program_test;
+
+pub const LAST_UPDATED_SLOT: u64 = 1_000;
+
+pub const MAX_STALENESS_SLOTS: u64 = 100;
+
+/// 1 LST = 1.05 SOL
+pub async fn lst_sol_program_test_ctx(current_slot: u64) -> (ProgramTestContext, Pubkey) {
+    let lst_mint = Pubkey::new_unique();
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: Pubkey::new_unique(),
+        },
+        &[MockRateAccountArgs {
+            rate_num: 105,
+            rate_denom: 100,
+            max_rate_change_bps: 100,
+            is_monotonic: true,
+            max_staleness_slots: MAX_STALENESS_SLOTS,
+            last_updated_slot: LAST_UPDATED_SLOT,
+            lst_mint,
+        }],
+    );
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        slot: current_slot,
+        ..Default::default()
+    });
+    (ctx, lst_mint)
+}
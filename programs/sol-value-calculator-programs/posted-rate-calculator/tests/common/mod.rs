@@ -0,0 +1,7 @@
+mod lst_sol;
+mod program_test;
+mod rate_account;
+
+pub use lst_sol::*;
+pub use program_test::*;
+pub use rate_account::*;
@@ -0,0 +1,23 @@
+use posted_rate_calculator_interface::ProgramState;
+use posted_rate_calculator_lib::program::STATE_ID;
+use posted_rate_calculator_test_utils::{MockProgramState, MockRateAccount, MockRateAccountArgs};
+use sanctum_solana_test_utils::IntoAccount;
+use solana_program_test::{processor, ProgramTest};
+
+pub fn normal_program_test(
+    state: ProgramState,
+    mock_rate_accounts: &[MockRateAccountArgs],
+) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "posted_rate_calculator",
+        posted_rate_calculator_lib::program::ID,
+        processor!(posted_rate_calculator::entrypoint::process_instruction),
+    );
+    program_test.add_account(STATE_ID, MockProgramState(state).into_account());
+    for mra in mock_rate_accounts {
+        let (acc, addr) = mra.to_rate_account_and_addr(posted_rate_calculator_lib::program::ID);
+        program_test.add_account(addr, MockRateAccount(acc).into_account());
+    }
+    program_test
+}
@@ -0,0 +1,33 @@
+use posted_rate_calculator_lib::{pda::RateAccountFindPdaArgs, program, utils::try_rate_account};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClient;
+
+/// Verifies the rate and returns the rate account's `last_updated_slot`
+pub async fn verify_rate_account_rate(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    expected_rate_num: u64,
+    expected_rate_denom: u64,
+) -> u64 {
+    let find_pda_args = RateAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    };
+    let (addr, bump) = find_pda_args.get_rate_account_address_and_bump_seed();
+    let actual_acc = banks_client.get_account_unwrapped(addr).await;
+    let actual = try_rate_account(&actual_acc.data).unwrap();
+    assert_eq!(actual.bump, bump);
+    assert_eq!(actual.rate_num, expected_rate_num);
+    assert_eq!(actual.rate_denom, expected_rate_denom);
+    actual.last_updated_slot
+}
+
+pub async fn verify_rate_account_does_not_exist(banks_client: &mut BanksClient, lst_mint: Pubkey) {
+    let find_pda_args = RateAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    };
+    let (addr, _bump) = find_pda_args.get_rate_account_address_and_bump_seed();
+    assert!(banks_client.get_account(addr).await.unwrap().is_none());
+}
@@ -0,0 +1,162 @@
+use posted_rate_calculator_interface::{
+    add_lst_ix, AddLstIxArgs, AddLstKeys, PostedRateCalculatorError, ProgramState,
+};
+use posted_rate_calculator_lib::{
+    account_resolvers::AddLstFreeArgs,
+    pda::RateAccountFindPdaArgs,
+    program::{self, STATE_ID},
+};
+use posted_rate_calculator_test_utils::PostedRateCalculatorProgramTestBanksClient;
+use sanctum_solana_test_utils::{
+    assert_custom_err, assert_program_error,
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs},
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::{
+    normal_program_test, verify_rate_account_does_not_exist, verify_rate_account_rate,
+};
+
+const VALID_ADD_LST_ARGS: AddLstIxArgs = AddLstIxArgs {
+    rate_num: 1_050_000_000,
+    rate_denom: 1_000_000_000,
+    max_rate_change_bps: 100,
+    is_monotonic: true,
+    max_staleness_slots: 432_000,
+};
+
+fn add_lst_program_test() -> (ProgramTest, Keypair, Pubkey) {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+        },
+        &[],
+    )
+    .add_tokenkeg_mint_from_args(
+        lst_mint,
+        MockMintArgs {
+            mint_authority: None,
+            freeze_authority: None,
+            supply: 0,
+            decimals: 9,
+        },
+    );
+    (program_test, manager, lst_mint)
+}
+
+#[tokio::test]
+async fn add_lst_basic() {
+    let (program_test, manager, lst_mint) = add_lst_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    verify_rate_account_does_not_exist(&mut banks_client, lst_mint).await;
+    let state_acc = banks_client
+        .get_posted_rate_calculator_program_state()
+        .await;
+
+    let free_args = AddLstFreeArgs {
+        payer: payer.pubkey(),
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    };
+    let (keys, _pda) = free_args.resolve().unwrap();
+    let ix = add_lst_ix(keys, VALID_ADD_LST_ARGS).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_rate_account_rate(
+        &mut banks_client,
+        lst_mint,
+        VALID_ADD_LST_ARGS.rate_num,
+        VALID_ADD_LST_ARGS.rate_denom,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn add_lst_fail_invalid_rate() {
+    const BAD_ADD_LST_ARGS: [AddLstIxArgs; 2] = [
+        AddLstIxArgs {
+            rate_num: 0,
+            ..VALID_ADD_LST_ARGS
+        },
+        AddLstIxArgs {
+            rate_denom: 0,
+            ..VALID_ADD_LST_ARGS
+        },
+    ];
+
+    let (program_test, manager, lst_mint) = add_lst_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client
+        .get_posted_rate_calculator_program_state()
+        .await;
+    let keyed_state_acc = KeyedAccount {
+        pubkey: STATE_ID,
+        account: state_acc,
+    };
+
+    for bad_args in BAD_ADD_LST_ARGS {
+        let free_args = AddLstFreeArgs {
+            payer: payer.pubkey(),
+            lst_mint,
+            state_acc: &keyed_state_acc,
+        };
+        let (keys, _pda) = free_args.resolve().unwrap();
+        let ix = add_lst_ix(keys, bad_args).unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, PostedRateCalculatorError::InvalidRate);
+
+        verify_rate_account_does_not_exist(&mut banks_client, lst_mint).await;
+    }
+}
+
+#[tokio::test]
+async fn add_lst_fail_unauthorized() {
+    let (program_test, _manager, lst_mint) = add_lst_program_test();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let (rate_acc, _bump) = RateAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_rate_account_address_and_bump_seed();
+
+    let ix = add_lst_ix(
+        AddLstKeys {
+            manager: payer.pubkey(),
+            payer: payer.pubkey(),
+            rate_acc,
+            lst_mint,
+            state: STATE_ID,
+            system_program: solana_program::system_program::ID,
+        },
+        VALID_ADD_LST_ARGS,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    verify_rate_account_does_not_exist(&mut banks_client, lst_mint).await;
+}
@@ -0,0 +1,64 @@
+use posted_rate_calculator_interface::{lst_to_sol_ix, LstToSolIxArgs, PostedRateCalculatorError};
+use posted_rate_calculator_lib::account_resolvers::PostedRateLstSolCommonFreeArgs;
+use sanctum_solana_test_utils::assert_custom_err;
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::{lst_sol_program_test_ctx, LAST_UPDATED_SLOT, MAX_STALENESS_SLOTS};
+
+#[tokio::test]
+async fn lst_to_sol_basic() {
+    const LST_AMOUNT: u64 = 1_000_000_000;
+    const EXPECTED_LAMPORTS_RANGE: U64ValueRange = U64ValueRange::single(1_050_000_000);
+
+    let (ctx, lst_mint) = lst_sol_program_test_ctx(LAST_UPDATED_SLOT + MAX_STALENESS_SLOTS).await;
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let ix = lst_to_sol_ix(
+        PostedRateLstSolCommonFreeArgs { lst_mint }.resolve(),
+        LstToSolIxArgs { amount: LST_AMOUNT },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LAMPORTS_RANGE,
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn lst_to_sol_fail_stale_rate() {
+    let (ctx, lst_mint) =
+        lst_sol_program_test_ctx(LAST_UPDATED_SLOT + MAX_STALENESS_SLOTS + 1).await;
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let ix = lst_to_sol_ix(
+        PostedRateLstSolCommonFreeArgs { lst_mint }.resolve(),
+        LstToSolIxArgs {
+            amount: 1_000_000_000,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, PostedRateCalculatorError::StaleRate);
+}
@@ -0,0 +1,4 @@
+mod add_lst;
+mod lst_to_sol;
+mod set_rate;
+mod sol_to_lst;
@@ -0,0 +1,140 @@
+use posted_rate_calculator_interface::{
+    set_rate_ix, PostedRateCalculatorError, ProgramState, SetRateIxArgs,
+};
+use posted_rate_calculator_lib::{account_resolvers::SetRateByMintFreeArgs, program::STATE_ID};
+use posted_rate_calculator_test_utils::MockRateAccountArgs;
+use sanctum_solana_test_utils::{assert_custom_err, ExtendedBanksClient};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::*;
+
+const INITIAL_RATE_NUM: u64 = 1_050_000_000;
+const INITIAL_RATE_DENOM: u64 = 1_000_000_000;
+
+async fn set_rate_test(
+    max_rate_change_bps: u16,
+    is_monotonic: bool,
+    new_rate: SetRateIxArgs,
+    expected_err: Option<PostedRateCalculatorError>,
+) {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+        },
+        &[MockRateAccountArgs {
+            rate_num: INITIAL_RATE_NUM,
+            rate_denom: INITIAL_RATE_DENOM,
+            max_rate_change_bps,
+            is_monotonic,
+            max_staleness_slots: u64::MAX,
+            last_updated_slot: 0,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_account_unwrapped(STATE_ID).await;
+    let SetRateIxArgs {
+        rate_num,
+        rate_denom,
+    } = new_rate;
+    let ix = set_rate_ix(
+        SetRateByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetRateIxArgs {
+            rate_num,
+            rate_denom,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    match expected_err {
+        None => {
+            banks_client.process_transaction(tx).await.unwrap();
+            verify_rate_account_rate(&mut banks_client, lst_mint, rate_num, rate_denom).await;
+        }
+        Some(expected_err) => {
+            let err = banks_client.process_transaction(tx).await.unwrap_err();
+            assert_custom_err(err, expected_err);
+            verify_rate_account_rate(
+                &mut banks_client,
+                lst_mint,
+                INITIAL_RATE_NUM,
+                INITIAL_RATE_DENOM,
+            )
+            .await;
+        }
+    }
+}
+
+#[tokio::test]
+async fn set_rate_basic() {
+    // +1% of 1.05 = 0.0105, so 1.06 is within bounds
+    set_rate_test(
+        100,
+        true,
+        SetRateIxArgs {
+            rate_num: 1_060_000_000,
+            rate_denom: 1_000_000_000,
+        },
+        None,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn set_rate_fail_exceeds_max_change() {
+    set_rate_test(
+        100,
+        false,
+        SetRateIxArgs {
+            rate_num: 1_070_000_000,
+            rate_denom: 1_000_000_000,
+        },
+        Some(PostedRateCalculatorError::RateChangeExceedsMax),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn set_rate_fail_monotonic_decrease() {
+    set_rate_test(
+        100,
+        true,
+        SetRateIxArgs {
+            rate_num: 1_049_000_000,
+            rate_denom: 1_000_000_000,
+        },
+        Some(PostedRateCalculatorError::RateDecreased),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn set_rate_non_monotonic_decrease() {
+    set_rate_test(
+        100,
+        false,
+        SetRateIxArgs {
+            rate_num: 1_049_000_000,
+            rate_denom: 1_000_000_000,
+        },
+        None,
+    )
+    .await;
+}
@@ -0,0 +1,38 @@
+use posted_rate_calculator_interface::{sol_to_lst_ix, SolToLstIxArgs};
+use posted_rate_calculator_lib::account_resolvers::PostedRateLstSolCommonFreeArgs;
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program_test::ProgramTestContext;
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::{lst_sol_program_test_ctx, LAST_UPDATED_SLOT};
+
+#[tokio::test]
+async fn sol_to_lst_basic() {
+    const LAMPORTS_AMOUNT: u64 = 1_050_000_000;
+    const EXPECTED_LST_RANGE: U64ValueRange = U64ValueRange::single(1_000_000_000);
+
+    let (ctx, lst_mint) = lst_sol_program_test_ctx(LAST_UPDATED_SLOT).await;
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let ix = sol_to_lst_ix(
+        PostedRateLstSolCommonFreeArgs { lst_mint }.resolve_sol_to_lst(),
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LST_RANGE,
+        )
+        .await;
+}
@@ -0,0 +1,29 @@
+use s_lp_calculator_interface::SLpCalculatorProgramIx;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::processor::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if *program_id != s_lp_calculator_lib::program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let ix = SLpCalculatorProgramIx::deserialize(instruction_data)?;
+    solana_program::msg!("{:?}", ix);
+
+    match ix {
+        SLpCalculatorProgramIx::LstToSol(args) => process_lst_to_sol(accounts, args),
+        SLpCalculatorProgramIx::SolToLst(args) => process_sol_to_lst(accounts, args),
+        SLpCalculatorProgramIx::BatchCalc(args) => process_batch_calc(accounts, args),
+    }
+}
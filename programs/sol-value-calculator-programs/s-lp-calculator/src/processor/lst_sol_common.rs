@@ -0,0 +1,90 @@
+use s_controller_lib::{try_lst_state_list, try_pool_state};
+use s_lp_calculator_interface::{
+    lst_to_sol_verify_account_keys, LstToSolAccounts, SLpCalculatorError,
+};
+use s_lp_calculator_lib::{
+    account_resolvers::SLpLstSolCommonFreeArgs,
+    calc::SLpSolValCalc,
+    sync::SPoolSyncArgs,
+    utils::{try_s_lp_sol_val_calc, verify_pool_not_rebalancing},
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sanctum_s_common::token::verify_tokenkeg_or_22_mint;
+use solana_program::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+/// Assumes:
+/// - LstToSolAccounts/Keys, SolToLstAccounts/Keys and BatchCalcAccounts/Keys are identical
+pub fn verify_lst_sol_common(accounts: &[AccountInfo<'_>]) -> Result<SLpSolValCalc, ProgramError> {
+    let actual: LstToSolAccounts = load_accounts(accounts)?;
+
+    let expected = SLpLstSolCommonFreeArgs {
+        pool_state: actual.pool_state,
+    }
+    .resolve()?;
+
+    lst_to_sol_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    // accounts should all be read-only, no need to verify_account_privileges
+
+    let s_program_id = actual.pool_state.owner;
+    if actual.lst_state_list.owner != s_program_id {
+        return Err(SLpCalculatorError::InvalidLstStateList.into());
+    }
+    verify_tokenkeg_or_22_mint(actual.lst_mint)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_not_rebalancing(pool_state)?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    verify_pool_synced(
+        actual.instructions,
+        &pool_state.lp_token_mint,
+        SPoolSyncArgs {
+            s_program_id,
+            pool_state: actual.pool_state.key,
+            lst_state_list,
+        },
+    )?;
+
+    let calc = try_s_lp_sol_val_calc(
+        pool_state,
+        actual.pool_state.key,
+        &actual.lst_mint.try_borrow_data()?,
+    )?;
+    Ok(calc)
+}
+
+/// Since the pool's total_sol_value is only updated on SyncSolValue,
+/// every LST in the pool must have been synced by a SyncSolValue instruction
+/// placed before the currently executing instruction in this transaction
+/// for the LP token's SOL value to not be stale.
+///
+/// The LP token must not be one of those LSTs,
+/// else its SOL value would be derived from itself.
+fn verify_pool_synced(
+    instructions_sysvar: &AccountInfo,
+    lp_token_mint: &Pubkey,
+    args: SPoolSyncArgs,
+) -> Result<(), ProgramError> {
+    if args
+        .lst_state_list
+        .iter()
+        .any(|lst_state| lst_state.mint == *lp_token_mint)
+    {
+        return Err(SLpCalculatorError::LpTokenMintIsPoolLst.into());
+    }
+    let current_ix_idx = load_current_index_checked(instructions_sysvar)?;
+    let prev_ixs = (0..current_ix_idx)
+        .map(|i| load_instruction_at_checked(i.into(), instructions_sysvar))
+        .collect::<Result<Vec<_>, _>>()?;
+    if !args.all_synced(&prev_ixs) {
+        return Err(SLpCalculatorError::PoolNotSynced.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,8 @@
+mod batch_calc;
+mod lst_sol_common;
+mod lst_to_sol;
+mod sol_to_lst;
+
+pub use batch_calc::*;
+pub use lst_to_sol::*;
+pub use sol_to_lst::*;
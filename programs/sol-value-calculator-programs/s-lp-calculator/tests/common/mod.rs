@@ -0,0 +1,147 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_lib::{
+    program::POOL_STATE_ID, sync_sol_value_ix_by_mint_full, try_pool_state,
+    SyncSolValueByMintFreeArgs, U8BoolMut,
+};
+use s_controller_test_utils::{
+    jito_marinade_base_program_test, JitoMarinadeProgramTestArgs, LstStateListBanksClient,
+    MockPoolState, PoolStateBanksClient,
+};
+use s_lp_calculator_lib::account_resolvers::SLpLstSolCommonFreeArgs;
+use sanctum_solana_test_utils::{ExtendedBanksClient, IntoAccount};
+use solana_program::{clock::Clock, instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::{processor, BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+pub const LP_TOKEN_SUPPLY: u64 = 1_000_000_000_000;
+
+pub const JITOSOL_RESERVES: u64 = 500_000_000_000;
+
+pub const MSOL_RESERVES: u64 = 500_000_000_000;
+
+/// S pool with equal jitoSOL and mSOL reserves.
+/// jitoSOL's recorded SOL value is stale and changes on SyncSolValue.
+pub async fn s_lp_program_test_ctx(is_rebalancing: bool) -> ProgramTestContext {
+    s_lp_program_test_ctx_with_lp_token_mint(is_rebalancing, Pubkey::new_unique()).await
+}
+
+/// Same as [`s_lp_program_test_ctx`], but with the given LP token mint
+pub async fn s_lp_program_test_ctx_with_lp_token_mint(
+    is_rebalancing: bool,
+    lp_token_mint: Pubkey,
+) -> ProgramTestContext {
+    let (mut program_test, mut pool_state) =
+        jito_marinade_base_program_test(JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: JITOSOL_RESERVES,
+            msol_sol_value: MSOL_RESERVES,
+            jitosol_reserves: JITOSOL_RESERVES,
+            msol_reserves: MSOL_RESERVES,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: LP_TOKEN_SUPPLY,
+        });
+    program_test.add_program(
+        "no_fee_pricing_program",
+        no_fee_pricing_program::ID,
+        processor!(no_fee_pricing_program::process_instruction),
+    );
+    pool_state.pricing_program = no_fee_pricing_program::ID;
+    if is_rebalancing {
+        U8BoolMut(&mut pool_state.is_rebalancing).set_true();
+    }
+    program_test.add_account(POOL_STATE_ID, MockPoolState(pool_state).into_account());
+    program_test = program_test.add_s_lp_programs();
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    ctx
+}
+
+trait SLpProgramTest {
+    fn add_s_lp_programs(self) -> Self;
+}
+
+impl SLpProgramTest for ProgramTest {
+    fn add_s_lp_programs(mut self) -> Self {
+        self.add_program(
+            "s_controller",
+            s_controller_lib::program::ID,
+            processor!(s_controller::entrypoint::process_instruction),
+        );
+        self.add_program(
+            "s_lp_calculator",
+            s_lp_calculator_lib::program::ID,
+            processor!(s_lp_calculator::entrypoint::process_instruction),
+        );
+        self
+    }
+}
+
+pub async fn s_lp_free_args(
+    banks_client: &mut BanksClient,
+) -> SLpLstSolCommonFreeArgs<KeyedAccount> {
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    SLpLstSolCommonFreeArgs {
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: pool_state_acc,
+        },
+    }
+}
+
+/// SyncSolValue instructions for the pool's (jitoSOL, mSOL)
+pub async fn sync_sol_value_ixs(banks_client: &mut BanksClient) -> (Instruction, Instruction) {
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+
+    let jitosol_mint_acc = banks_client.get_account_unwrapped(jitosol::ID).await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let sync_jitosol = sync_sol_value_ix_by_mint_full(
+        SyncSolValueByMintFreeArgs {
+            lst_state_list: lst_state_list_acc.clone(),
+            lst_mint: KeyedAccount {
+                pubkey: jitosol::ID,
+                account: jitosol_mint_acc,
+            },
+        },
+        &SplLstSolCommonFreeArgsConst {
+            spl_stake_pool: KeyedAccount {
+                pubkey: jito_stake_pool::ID,
+                account: jito_stake_pool_acc,
+            },
+        }
+        .resolve_spl_to_account_metas()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let msol_mint_acc = banks_client.get_account_unwrapped(msol::ID).await;
+    let sync_msol = sync_sol_value_ix_by_mint_full(
+        SyncSolValueByMintFreeArgs {
+            lst_state_list: lst_state_list_acc,
+            lst_mint: KeyedAccount {
+                pubkey: msol::ID,
+                account: msol_mint_acc,
+            },
+        },
+        &marinade_sol_val_calc_account_metas(),
+    )
+    .unwrap();
+
+    (sync_jitosol, sync_msol)
+}
+
+pub async fn pool_total_sol_value(banks_client: &mut BanksClient) -> u64 {
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    try_pool_state(&pool_state_acc.data)
+        .unwrap()
+        .total_sol_value
+}
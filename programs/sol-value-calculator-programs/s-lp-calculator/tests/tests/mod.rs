@@ -0,0 +1,2 @@
+mod lst_to_sol;
+mod sol_to_lst;
@@ -0,0 +1,106 @@
+use borsh::BorshDeserialize;
+use s_lp_calculator_interface::{sol_to_lst_ix, SLpCalculatorError, SolToLstIxArgs};
+use sanctum_solana_test_utils::{assert_custom_err, zero_padded_return_data, ExtendedBanksClient};
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::common::*;
+
+const LAMPORTS_AMOUNT: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn basic() {
+    let ctx = s_lp_program_test_ctx(false).await;
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let (sync_jitosol, sync_msol) = sync_sol_value_ixs(&mut banks_client).await;
+    let keys = s_lp_free_args(&mut banks_client)
+        .await
+        .resolve_sol_to_lst()
+        .unwrap();
+    let ix = sol_to_lst_ix(
+        keys,
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+    let ix_program_id = ix.program_id;
+
+    let mut tx = Transaction::new_with_payer(&[sync_jitosol, sync_msol, ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let return_data = banks_client.exec_get_return_data(tx).await;
+    assert_eq!(return_data.program_id, ix_program_id);
+    let buf: [u8; U64_VALUE_RANGE_BORSH_SER_LEN] = zero_padded_return_data(&return_data.data);
+    let res = U64ValueRange::deserialize(&mut buf.as_ref()).unwrap();
+
+    let total_sol_value = pool_total_sol_value(&mut banks_client).await;
+    let expected_lp_tokens = ((LAMPORTS_AMOUNT as u128) * (LP_TOKEN_SUPPLY as u128)
+        / (total_sol_value as u128))
+        .try_into()
+        .unwrap();
+    assert_eq!(res, U64ValueRange::single(expected_lp_tokens));
+}
+
+#[tokio::test]
+async fn fail_not_synced() {
+    let ctx = s_lp_program_test_ctx(false).await;
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let (_sync_jitosol, sync_msol) = sync_sol_value_ixs(&mut banks_client).await;
+    let keys = s_lp_free_args(&mut banks_client)
+        .await
+        .resolve_sol_to_lst()
+        .unwrap();
+    let ix = sol_to_lst_ix(
+        keys,
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[sync_msol, ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SLpCalculatorError::PoolNotSynced);
+}
+
+#[tokio::test]
+async fn fail_pool_rebalancing() {
+    let ctx = s_lp_program_test_ctx(true).await;
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let keys = s_lp_free_args(&mut banks_client)
+        .await
+        .resolve_sol_to_lst()
+        .unwrap();
+    let ix = sol_to_lst_ix(
+        keys,
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SLpCalculatorError::PoolRebalancing);
+}
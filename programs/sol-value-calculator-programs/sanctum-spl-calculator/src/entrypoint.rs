@@ -3,10 +3,17 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
     pubkey::Pubkey,
 };
+use spl_calculator_interface::{
+    SplCalculatorProgramIx, RECORD_WITHDRAWAL_FEES_IX_DISCM, SET_VALUATION_MODE_IX_DISCM,
+    SET_WITHDRAWAL_FEE_GUARD_IX_DISCM,
+};
 use spl_calculator_lib::sanctum_spl_sol_val_calc_program;
 
 use crate::processor::{
-    process_init, process_lst_to_sol, process_set_manager, process_sol_to_lst,
+    process_batch_calc, process_init, process_lst_to_sol, process_migrate_state,
+    process_permissionless_update_last_upgrade_slot, process_record_withdrawal_fees,
+    process_set_approved_program_data_hash, process_set_manager, process_set_stale_discount,
+    process_set_valuation_mode, process_set_withdrawal_fee_guard, process_sol_to_lst,
     process_update_last_upgrade_slot,
 };
 
@@ -22,12 +29,46 @@ pub fn process_instruction(
         return Err(ProgramError::IncorrectProgramId);
     }
 
+    if matches!(
+        instruction_data.first(),
+        Some(
+            &(SET_VALUATION_MODE_IX_DISCM
+                | SET_WITHDRAWAL_FEE_GUARD_IX_DISCM
+                | RECORD_WITHDRAWAL_FEES_IX_DISCM)
+        )
+    ) {
+        let ix = SplCalculatorProgramIx::deserialize(instruction_data)?;
+        solana_program::msg!("{:?}", ix);
+        return match ix {
+            SplCalculatorProgramIx::SetValuationMode(args) => {
+                process_set_valuation_mode(accounts, args)
+            }
+            SplCalculatorProgramIx::SetWithdrawalFeeGuard(args) => {
+                process_set_withdrawal_fee_guard(accounts, args)
+            }
+            SplCalculatorProgramIx::RecordWithdrawalFees => {
+                process_record_withdrawal_fees(accounts)
+            }
+        };
+    }
+
     let ix = GenericPoolCalculatorProgramIx::deserialize(instruction_data)?;
     solana_program::msg!("{:?}", ix);
 
     match ix {
         GenericPoolCalculatorProgramIx::LstToSol(args) => process_lst_to_sol(accounts, args),
         GenericPoolCalculatorProgramIx::SolToLst(args) => process_sol_to_lst(accounts, args),
+        GenericPoolCalculatorProgramIx::BatchCalc(args) => process_batch_calc(accounts, args),
+        GenericPoolCalculatorProgramIx::PermissionlessUpdateLastUpgradeSlot => {
+            process_permissionless_update_last_upgrade_slot(accounts)
+        }
+        GenericPoolCalculatorProgramIx::SetApprovedProgramDataHash(args) => {
+            process_set_approved_program_data_hash(accounts, args)
+        }
+        GenericPoolCalculatorProgramIx::MigrateState => process_migrate_state(accounts),
+        GenericPoolCalculatorProgramIx::SetStaleDiscount(args) => {
+            process_set_stale_discount(accounts, args)
+        }
         GenericPoolCalculatorProgramIx::UpdateLastUpgradeSlot => {
             process_update_last_upgrade_slot(accounts)
         }
@@ -1,18 +1,30 @@
-use generic_pool_calculator_interface::{lst_to_sol_verify_account_keys, LstToSolAccounts};
-use generic_pool_calculator_lib::utils::{
-    verify_no_stake_pool_prog_upgrade, VerifyNoStakePoolProgUpgradeArgs,
+use generic_pool_calculator_interface::{
+    lst_to_sol_verify_account_keys, LstToSolAccounts, LST_TO_SOL_IX_ACCOUNTS_LEN,
+};
+use generic_pool_calculator_lib::{
+    utils::{
+        read_stale_discount_bps, verify_no_stake_pool_prog_upgrade,
+        VerifyNoStakePoolProgUpgradeArgs,
+    },
+    GenericPoolSolValCalc,
 };
 use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sol_value_calculator_lib::StaleDiscountCalc;
 use solana_program::{
     account_info::AccountInfo, clock::Clock, program_error::ProgramError, sysvar::Sysvar,
 };
-use spl_calculator_lib::{SanctumSplSolValCalc, SplLstSolCommonFreeArgs, SplStakePoolCalc};
+use spl_calculator_lib::{
+    read_spl_pool_params, ReadSplPoolConfigArgs, SanctumSplSolValCalc, SplLstSolCommonFreeArgs,
+    SplStakePoolCalc, StakeWithdrawalFees,
+};
 
 /// Assumes:
 /// - LstToSolAccounts/Keys and SolToLstAccounts/Keys are identical
+///
+/// Expects the stake pool's SplPoolConfig PDA right after LstToSolAccounts
 pub fn verify_lst_sol_common(
     accounts: &[AccountInfo<'_>],
-) -> Result<SplStakePoolCalc, ProgramError> {
+) -> Result<StaleDiscountCalc<SplStakePoolCalc>, ProgramError> {
     let actual: LstToSolAccounts = load_accounts(accounts)?;
 
     let root_keys = SplLstSolCommonFreeArgs {
@@ -30,8 +42,23 @@ pub fn verify_lst_sol_common(
         calculator_state: actual.state,
     })?;
 
-    let calc: SplStakePoolCalc = stake_pool.into();
-    calc.verify_pool_updated_for_this_epoch(Clock::get()?.epoch)?;
+    let pool_config = accounts
+        .get(LST_TO_SOL_IX_ACCOUNTS_LEN)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let pool_params = read_spl_pool_params(ReadSplPoolConfigArgs {
+        program_id: SanctumSplSolValCalc::ID,
+        pool_state: *actual.pool_state.key,
+        pool_config,
+    })?;
+
+    let current_epoch = Clock::get()?.epoch;
+    pool_params
+        .withdrawal_fee_guard
+        .verify(&StakeWithdrawalFees::from(&stake_pool), current_epoch)?;
+
+    let calc = SplStakePoolCalc::from(stake_pool).with_valuation_mode(pool_params.valuation_mode);
+    let stale_discount_bps = read_stale_discount_bps(actual.state)?;
+    let calc = calc.verify_pool_updated_or_stale_bounded(current_epoch, stale_discount_bps)?;
 
     Ok(calc)
 }
@@ -0,0 +1,16 @@
+use generic_pool_calculator_interface::SetApprovedProgramDataHashIxArgs;
+use generic_pool_calculator_onchain::processor::{
+    process_set_approved_program_data_hash_unchecked, verify_set_approved_program_data_hash,
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use spl_calculator_lib::SanctumSplSolValCalc;
+
+pub fn process_set_approved_program_data_hash(
+    accounts: &[AccountInfo],
+    SetApprovedProgramDataHashIxArgs {
+        approved_program_data_hash,
+    }: SetApprovedProgramDataHashIxArgs,
+) -> Result<(), ProgramError> {
+    let checked = verify_set_approved_program_data_hash::<SanctumSplSolValCalc>(accounts)?;
+    process_set_approved_program_data_hash_unchecked(checked, approved_program_data_hash)
+}
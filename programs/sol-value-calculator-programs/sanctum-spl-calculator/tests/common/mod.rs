@@ -35,6 +35,8 @@ pub fn pwr_normal_program_test() -> PwrNormalProgramTest {
         .add_mock_calculator_state(MockCalculatorStateAccountArgs {
             manager: Pubkey::default(),
             last_upgrade_slot: SANCTUM_SPL_STAKE_POOL_PROG_LAST_UDPATED_SLOT,
+            stale_discount_bps: Default::default(),
+            approved_program_data_hash: Default::default(),
             owner: SanctumSplSolValCalc::ID,
         })
         .add_keyed_ui_account(sanctum_spl_stake_pool_prog_ui_acc)
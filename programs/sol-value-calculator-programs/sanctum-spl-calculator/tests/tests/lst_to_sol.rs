@@ -1,12 +1,11 @@
-use generic_pool_calculator_interface::{
-    lst_to_sol_ix_with_program_id, LstToSolIxArgs, LstToSolKeys,
-};
-use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use generic_pool_calculator_interface::{LstToSolIxArgs, LstToSolKeys};
 use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
 use solana_program::clock::Clock;
 use solana_program_test::ProgramTestContext;
 
-use spl_calculator_lib::{SanctumSplSolValCalc, SplLstSolCommonFreeArgs};
+use spl_calculator_lib::{
+    sanctum_spl_lst_to_sol_ix, SanctumSplSolValCalc, SplLstSolCommonFreeArgs,
+};
 use test_utils::{BorshReturnDataBanksClient, PWR_STAKE_POOL_LAST_UPDATE_EPOCH};
 
 use crate::common::{pwr_normal_program_test, PwrNormalProgramTest};
@@ -45,12 +44,7 @@ async fn pwr_basic() {
         .unwrap()
         .into();
 
-    let ix = lst_to_sol_ix_with_program_id(
-        SanctumSplSolValCalc::ID,
-        accounts,
-        LstToSolIxArgs { amount: LST_AMOUNT },
-    )
-    .unwrap();
+    let ix = sanctum_spl_lst_to_sol_ix(accounts, LstToSolIxArgs { amount: LST_AMOUNT }).unwrap();
 
     banks_client
         .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
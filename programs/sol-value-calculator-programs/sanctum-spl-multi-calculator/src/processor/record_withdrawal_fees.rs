@@ -0,0 +1,11 @@
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use spl_calculator_lib::SanctumSplMultiSolValCalc;
+use spl_calculator_onchain::processor::{
+    process_record_withdrawal_fees_unchecked, verify_record_withdrawal_fees,
+};
+
+pub fn process_record_withdrawal_fees(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let (checked, current_fees) =
+        verify_record_withdrawal_fees::<SanctumSplMultiSolValCalc>(accounts)?;
+    process_record_withdrawal_fees_unchecked(checked, &current_fees)
+}
@@ -0,0 +1,15 @@
+use generic_pool_calculator_interface::SetStaleDiscountIxArgs;
+use generic_pool_calculator_onchain::processor::{
+    process_set_stale_discount_unchecked, verify_set_stale_discount,
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use spl_calculator_lib::SanctumSplMultiSolValCalc;
+
+pub fn process_set_stale_discount(
+    accounts: &[AccountInfo],
+    SetStaleDiscountIxArgs { stale_discount_bps }: SetStaleDiscountIxArgs,
+) -> Result<(), ProgramError> {
+    let checked =
+        verify_set_stale_discount::<SanctumSplMultiSolValCalc>(accounts, stale_discount_bps)?;
+    process_set_stale_discount_unchecked(checked, stale_discount_bps)
+}
@@ -0,0 +1,20 @@
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use spl_calculator_interface::SetWithdrawalFeeGuardIxArgs;
+use spl_calculator_lib::SanctumSplMultiSolValCalc;
+use spl_calculator_onchain::processor::{
+    process_set_withdrawal_fee_guard_unchecked, verify_set_withdrawal_fee_guard,
+};
+
+pub fn process_set_withdrawal_fee_guard(
+    accounts: &[AccountInfo],
+    args: SetWithdrawalFeeGuardIxArgs,
+) -> Result<(), ProgramError> {
+    let (checked, pool_config_bump, current_fees) =
+        verify_set_withdrawal_fee_guard::<SanctumSplMultiSolValCalc>(accounts)?;
+    process_set_withdrawal_fee_guard_unchecked::<SanctumSplMultiSolValCalc>(
+        checked,
+        pool_config_bump,
+        &current_fees,
+        args,
+    )
+}
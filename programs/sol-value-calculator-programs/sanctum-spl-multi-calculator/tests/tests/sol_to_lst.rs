@@ -1,12 +1,11 @@
-use generic_pool_calculator_interface::{
-    sol_to_lst_ix_with_program_id, SolToLstIxArgs, SolToLstKeys,
-};
-use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use generic_pool_calculator_interface::{SolToLstIxArgs, SolToLstKeys};
 use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
 use solana_program::clock::Clock;
 use solana_program_test::ProgramTestContext;
 
-use spl_calculator_lib::{SanctumSplMultiSolValCalc, SplLstSolCommonFreeArgs};
+use spl_calculator_lib::{
+    sanctum_spl_multi_sol_to_lst_ix, SanctumSplMultiSolValCalc, SplLstSolCommonFreeArgs,
+};
 use test_utils::{BorshReturnDataBanksClient, JUP_STAKE_POOL_LAST_UPDATE_EPOCH};
 
 use crate::common::{jup_normal_program_test, JupNormalProgramTest};
@@ -46,8 +45,7 @@ async fn jup_basic() {
         .unwrap()
         .into();
 
-    let ix = sol_to_lst_ix_with_program_id(
-        SanctumSplMultiSolValCalc::ID,
+    let ix = sanctum_spl_multi_sol_to_lst_ix(
         accounts,
         SolToLstIxArgs {
             amount: LAMPORTS_AMOUNT,
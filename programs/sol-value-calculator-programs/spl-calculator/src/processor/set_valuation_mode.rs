@@ -0,0 +1,15 @@
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use spl_calculator_interface::SetValuationModeIxArgs;
+use spl_calculator_lib::SplSolValCalc;
+use spl_calculator_onchain::processor::{
+    process_set_valuation_mode_unchecked, verify_set_valuation_mode,
+};
+
+pub fn process_set_valuation_mode(
+    accounts: &[AccountInfo],
+    SetValuationModeIxArgs { valuation_mode }: SetValuationModeIxArgs,
+) -> Result<(), ProgramError> {
+    let (checked, pool_config_bump, valuation_mode) =
+        verify_set_valuation_mode::<SplSolValCalc>(accounts, valuation_mode)?;
+    process_set_valuation_mode_unchecked::<SplSolValCalc>(checked, pool_config_bump, valuation_mode)
+}
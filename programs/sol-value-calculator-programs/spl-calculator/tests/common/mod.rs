@@ -16,6 +16,18 @@ pub struct JitoNormalProgramTest {
 }
 
 pub fn jito_normal_program_test() -> JitoNormalProgramTest {
+    jito_program_test_with_stale_discount(0)
+}
+
+pub fn jito_program_test_with_stale_discount(stale_discount_bps: u16) -> JitoNormalProgramTest {
+    jito_program_test(Pubkey::default(), stale_discount_bps)
+}
+
+pub fn jito_program_test_with_manager(manager: Pubkey) -> JitoNormalProgramTest {
+    jito_program_test(manager, 0)
+}
+
+fn jito_program_test(manager: Pubkey, stale_discount_bps: u16) -> JitoNormalProgramTest {
     let mut program_test = ProgramTest::default();
     // name must match <name>.so filename
     program_test.add_program(
@@ -33,8 +45,10 @@ pub fn jito_normal_program_test() -> JitoNormalProgramTest {
 
     program_test = program_test
         .add_mock_calculator_state(MockCalculatorStateAccountArgs {
-            manager: Pubkey::default(),
+            manager,
             last_upgrade_slot: SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
+            stale_discount_bps,
+            approved_program_data_hash: Default::default(),
             owner: SplSolValCalc::ID,
         })
         .add_keyed_ui_account(spl_stake_pool_prog_ui_acc)
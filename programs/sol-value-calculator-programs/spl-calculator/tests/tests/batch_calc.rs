@@ -0,0 +1,113 @@
+use generic_pool_calculator_interface::{BatchCalcIxArgs, BatchCalcKeys};
+use sanctum_solana_test_utils::assert_program_error;
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use sol_value_calculator_onchain::MAX_BATCH_CALC_LEN;
+use solana_program::{clock::Clock, program_error::ProgramError};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use spl_calculator_lib::{spl_batch_calc_ix, SplLstSolCommonFreeArgs, SplSolValCalc};
+use test_utils::{BorshReturnDataBanksClient, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{jito_normal_program_test, JitoNormalProgramTest};
+
+/// u32 vec len prefix + 2 ranges
+const TWO_RANGES_BORSH_SER_LEN: usize = 4 + 2 * U64_VALUE_RANGE_BORSH_SER_LEN;
+
+#[tokio::test]
+async fn jito_basic() {
+    const LST_AMOUNT: u64 = 1_000_000_000;
+    const LAMPORTS_AMOUNT: u64 = 1_072_326_756;
+    // same results as the individual LstToSol and SolToLst tests
+    const EXPECTED_LAMPORTS_RANGE: U64ValueRange = U64ValueRange::single(1_072_326_756);
+    const EXPECTED_LST_RANGE: U64ValueRange =
+        U64ValueRange::from_min_max_unchecked(999_999_998, 1_000_000_003);
+
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        spl_stake_pool_prog,
+    } = jito_normal_program_test();
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let free_args = SplLstSolCommonFreeArgs {
+        spl_stake_pool: jito_stake_pool,
+        spl_stake_pool_prog,
+    };
+    let (intermediate, _stake_pool) = free_args.resolve_spl().unwrap();
+    let accounts: BatchCalcKeys = intermediate.resolve::<SplSolValCalc>().unwrap().into();
+
+    let ix = spl_batch_calc_ix(
+        accounts,
+        BatchCalcIxArgs {
+            lst_to_sol_amounts: vec![LST_AMOUNT],
+            sol_to_lst_amounts: vec![LAMPORTS_AMOUNT],
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<Vec<U64ValueRange>, TWO_RANGES_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            vec![EXPECTED_LAMPORTS_RANGE, EXPECTED_LST_RANGE],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn jito_fail_batch_too_large() {
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        spl_stake_pool_prog,
+    } = jito_normal_program_test();
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let free_args = SplLstSolCommonFreeArgs {
+        spl_stake_pool: jito_stake_pool,
+        spl_stake_pool_prog,
+    };
+    let (intermediate, _stake_pool) = free_args.resolve_spl().unwrap();
+    let accounts: BatchCalcKeys = intermediate.resolve::<SplSolValCalc>().unwrap().into();
+
+    let ix = spl_batch_calc_ix(
+        accounts,
+        BatchCalcIxArgs {
+            lst_to_sol_amounts: vec![1_000_000_000; MAX_BATCH_CALC_LEN],
+            sol_to_lst_amounts: vec![1_000_000_000],
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidInstructionData);
+}
@@ -1,12 +1,17 @@
 use generic_pool_calculator_interface::{LstToSolIxArgs, LstToSolKeys};
+use sanctum_solana_test_utils::assert_custom_err;
 use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
 use solana_program::clock::Clock;
 use solana_program_test::ProgramTestContext;
+use solana_sdk::{signer::Signer, transaction::Transaction};
 
+use spl_calculator_interface::SplCalculatorError;
 use spl_calculator_lib::{spl_lst_to_sol_ix, SplLstSolCommonFreeArgs, SplSolValCalc};
 use test_utils::{BorshReturnDataBanksClient, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
 
-use crate::common::{jito_normal_program_test, JitoNormalProgramTest};
+use crate::common::{
+    jito_normal_program_test, jito_program_test_with_stale_discount, JitoNormalProgramTest,
+};
 
 #[tokio::test]
 async fn jito_basic() {
@@ -50,3 +55,92 @@ async fn jito_basic() {
         )
         .await;
 }
+
+#[tokio::test]
+async fn jito_fail_stale_with_discount() {
+    const STALE_DISCOUNT_BPS: u16 = 100;
+
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        spl_stake_pool_prog,
+    } = jito_program_test_with_stale_discount(STALE_DISCOUNT_BPS);
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH + 1,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let free_args = SplLstSolCommonFreeArgs {
+        spl_stake_pool: jito_stake_pool,
+        spl_stake_pool_prog,
+    };
+    let (intermediate, _stake_pool) = free_args.resolve_spl().unwrap();
+    let accounts: LstToSolKeys = intermediate.resolve::<SplSolValCalc>().unwrap().into();
+
+    // input valuation stays strict even if stale valuation is enabled
+    let ix = spl_lst_to_sol_ix(
+        accounts,
+        LstToSolIxArgs {
+            amount: 1_000_000_000,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SplCalculatorError::PoolNotUpdated);
+}
+
+#[tokio::test]
+async fn jito_fail_stale_without_discount() {
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        spl_stake_pool_prog,
+    } = jito_normal_program_test();
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH + 1,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let free_args = SplLstSolCommonFreeArgs {
+        spl_stake_pool: jito_stake_pool,
+        spl_stake_pool_prog,
+    };
+    let (intermediate, _stake_pool) = free_args.resolve_spl().unwrap();
+    let accounts: LstToSolKeys = intermediate.resolve::<SplSolValCalc>().unwrap().into();
+
+    let ix = spl_lst_to_sol_ix(
+        accounts,
+        LstToSolIxArgs {
+            amount: 1_000_000_000,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SplCalculatorError::PoolNotUpdated);
+}
@@ -1,2 +1,5 @@
+mod batch_calc;
 mod lst_to_sol;
+mod set_valuation_mode;
 mod sol_to_lst;
+mod withdrawal_fee_guard;
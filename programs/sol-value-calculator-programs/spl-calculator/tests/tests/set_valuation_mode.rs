@@ -0,0 +1,235 @@
+use generic_pool_calculator_interface::{LstToSolIxArgs, LstToSolKeys};
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error, ExtendedBanksClient};
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{
+    clock::Clock, instruction::AccountMeta, program_error::ProgramError, pubkey::Pubkey,
+};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_calculator_interface::{SetValuationModeIxArgs, SplCalculatorError};
+use spl_calculator_lib::{
+    deserialize_stake_pool_checked, spl_lst_to_sol_ix, spl_set_valuation_mode_ix,
+    try_spl_pool_config, SetValuationModeFreeArgs, SplLstSolCommonFreeArgs, SplSolValCalc,
+    SplStakePoolCalc, SplValuationMode,
+};
+use test_utils::{BorshReturnDataBanksClient, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{jito_program_test_with_manager, JitoNormalProgramTest};
+
+const LST_AMOUNT: u64 = 1_000_000_000;
+
+async fn set_valuation_mode(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    manager: &Keypair,
+    jito_stake_pool: &KeyedAccount,
+    valuation_mode: u8,
+) -> Pubkey {
+    let state = banks_client
+        .get_account_unwrapped(SplSolValCalc::CALCULATOR_STATE_PDA)
+        .await;
+    let (keys, _bump) = SetValuationModeFreeArgs {
+        payer: payer.pubkey(),
+        state: KeyedAccount {
+            pubkey: SplSolValCalc::CALCULATOR_STATE_PDA,
+            account: state,
+        },
+        pool_state: jito_stake_pool,
+    }
+    .resolve::<SplSolValCalc>()
+    .unwrap();
+    let pool_config = keys.pool_config;
+
+    let ix = spl_set_valuation_mode_ix(keys, SetValuationModeIxArgs { valuation_mode }).unwrap();
+    let last_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, manager], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    pool_config
+}
+
+async fn verify_valuation_mode(
+    banks_client: &mut BanksClient,
+    pool_config: Pubkey,
+    expected: SplValuationMode,
+) {
+    let pool_config_acc = banks_client.get_account_unwrapped(pool_config).await;
+    assert_eq!(pool_config_acc.owner, SplSolValCalc::ID);
+    let config = try_spl_pool_config(&pool_config_acc.data).unwrap();
+    assert_eq!(config.valuation_mode, u8::from(expected));
+}
+
+#[tokio::test]
+async fn jito_sol_withdrawal_then_min_of_both() {
+    let manager = Keypair::new();
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        spl_stake_pool_prog,
+    } = jito_program_test_with_manager(manager.pubkey());
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        ..
+    } = ctx;
+
+    let calc: SplStakePoolCalc = deserialize_stake_pool_checked(&jito_stake_pool)
+        .unwrap()
+        .into();
+    let (intermediate, _stake_pool) = SplLstSolCommonFreeArgs {
+        spl_stake_pool: &jito_stake_pool,
+        spl_stake_pool_prog,
+    }
+    .resolve_spl()
+    .unwrap();
+    let accounts: LstToSolKeys = intermediate.resolve::<SplSolValCalc>().unwrap().into();
+
+    for valuation_mode in [SplValuationMode::SolWithdrawal, SplValuationMode::MinOfBoth] {
+        let pool_config = set_valuation_mode(
+            &mut banks_client,
+            &payer,
+            &manager,
+            &jito_stake_pool,
+            valuation_mode.into(),
+        )
+        .await;
+        verify_valuation_mode(&mut banks_client, pool_config, valuation_mode).await;
+
+        let expected = calc
+            .with_valuation_mode(valuation_mode)
+            .calc_lst_to_sol(LST_AMOUNT)
+            .unwrap();
+        let ix = spl_lst_to_sol_ix(accounts, LstToSolIxArgs { amount: LST_AMOUNT }).unwrap();
+        let last_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+        banks_client
+            .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+                &payer,
+                last_blockhash,
+                ix,
+                expected,
+            )
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn jito_fail_invalid_valuation_mode() {
+    let manager = Keypair::new();
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        ..
+    } = jito_program_test_with_manager(manager.pubkey());
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state = banks_client
+        .get_account_unwrapped(SplSolValCalc::CALCULATOR_STATE_PDA)
+        .await;
+    let (keys, _bump) = SetValuationModeFreeArgs {
+        payer: payer.pubkey(),
+        state: KeyedAccount {
+            pubkey: SplSolValCalc::CALCULATOR_STATE_PDA,
+            account: state,
+        },
+        pool_state: &jito_stake_pool,
+    }
+    .resolve::<SplSolValCalc>()
+    .unwrap();
+
+    let ix = spl_set_valuation_mode_ix(keys, SetValuationModeIxArgs { valuation_mode: 3 }).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SplCalculatorError::InvalidValuationMode);
+}
+
+#[tokio::test]
+async fn jito_fail_unauthorized_manager() {
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        ..
+    } = jito_program_test_with_manager(Pubkey::new_unique());
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state = banks_client
+        .get_account_unwrapped(SplSolValCalc::CALCULATOR_STATE_PDA)
+        .await;
+    let (mut keys, _bump) = SetValuationModeFreeArgs {
+        payer: payer.pubkey(),
+        state: KeyedAccount {
+            pubkey: SplSolValCalc::CALCULATOR_STATE_PDA,
+            account: state,
+        },
+        pool_state: &jito_stake_pool,
+    }
+    .resolve::<SplSolValCalc>()
+    .unwrap();
+    keys.manager = payer.pubkey();
+
+    let ix = spl_set_valuation_mode_ix(
+        keys,
+        SetValuationModeIxArgs {
+            valuation_mode: SplValuationMode::SolWithdrawal.into(),
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
+
+#[tokio::test]
+async fn jito_fail_lst_to_sol_wrong_pool_config() {
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        spl_stake_pool_prog,
+    } = jito_program_test_with_manager(Pubkey::default());
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let (intermediate, _stake_pool) = SplLstSolCommonFreeArgs {
+        spl_stake_pool: jito_stake_pool,
+        spl_stake_pool_prog,
+    }
+    .resolve_spl()
+    .unwrap();
+    let accounts: LstToSolKeys = intermediate.resolve::<SplSolValCalc>().unwrap().into();
+
+    let mut ix = spl_lst_to_sol_ix(accounts, LstToSolIxArgs { amount: LST_AMOUNT }).unwrap();
+    *ix.accounts.last_mut().unwrap() = AccountMeta::new_readonly(Pubkey::new_unique(), false);
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SplCalculatorError::IncorrectPoolConfig);
+}
@@ -6,7 +6,9 @@ use solana_program_test::ProgramTestContext;
 use spl_calculator_lib::{spl_sol_to_lst_ix, SplLstSolCommonFreeArgs, SplSolValCalc};
 use test_utils::{BorshReturnDataBanksClient, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
 
-use crate::common::{jito_normal_program_test, JitoNormalProgramTest};
+use crate::common::{
+    jito_normal_program_test, jito_program_test_with_stale_discount, JitoNormalProgramTest,
+};
 
 #[tokio::test]
 async fn jito_basic() {
@@ -57,3 +59,55 @@ async fn jito_basic() {
         )
         .await;
 }
+
+#[tokio::test]
+async fn jito_stale_discounted() {
+    const LAMPORTS_AMOUNT: u64 = 1_072_326_756;
+    const STALE_DISCOUNT_BPS: u16 = 100;
+    // min is discounted by 1%, max is left as-is
+    const EXPECTED_LST_RANGE: U64ValueRange =
+        U64ValueRange::from_min_max_unchecked(989_999_998, 1_000_000_003);
+
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        spl_stake_pool_prog,
+    } = jito_program_test_with_stale_discount(STALE_DISCOUNT_BPS);
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH + 1,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let free_args = SplLstSolCommonFreeArgs {
+        spl_stake_pool: jito_stake_pool,
+        spl_stake_pool_prog,
+    };
+    let (intermediate, _stake_pool) = free_args.resolve_spl().unwrap();
+    let accounts: SolToLstKeys = intermediate.resolve::<SplSolValCalc>().unwrap().into();
+
+    let ix = spl_sol_to_lst_ix(
+        accounts,
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LST_RANGE,
+        )
+        .await;
+}
@@ -0,0 +1,322 @@
+use borsh::BorshSerialize;
+use generic_pool_calculator_interface::{LstToSolIxArgs, LstToSolKeys};
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use sanctum_solana_test_utils::{assert_custom_err, ExtendedBanksClient};
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{clock::Clock, instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_calculator_interface::{
+    Fee, SetWithdrawalFeeGuardIxArgs, SplCalculatorError, SplStakePool,
+};
+use spl_calculator_lib::{
+    deserialize_stake_pool_checked, spl_lst_to_sol_ix, spl_record_withdrawal_fees_ix,
+    spl_set_withdrawal_fee_guard_ix, try_spl_pool_config, RecordWithdrawalFeesFreeArgs,
+    SetWithdrawalFeeGuardFreeArgs, SplLstSolCommonFreeArgs, SplSolValCalc, SplStakePoolCalc,
+};
+use test_utils::{BorshReturnDataBanksClient, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{jito_program_test_with_manager, JitoNormalProgramTest};
+
+const LST_AMOUNT: u64 = 1_000_000_000;
+
+/// Jito's stake withdrawal fee in the test fixture
+const JITO_STAKE_WITHDRAWAL_FEE: Fee = Fee {
+    denominator: 1_000,
+    numerator: 1,
+};
+
+async fn set_withdrawal_fee_guard(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    manager: &Keypair,
+    jito_stake_pool: &KeyedAccount,
+    args: SetWithdrawalFeeGuardIxArgs,
+) -> Pubkey {
+    let state = banks_client
+        .get_account_unwrapped(SplSolValCalc::CALCULATOR_STATE_PDA)
+        .await;
+    let (keys, _bump) = SetWithdrawalFeeGuardFreeArgs {
+        payer: payer.pubkey(),
+        state: KeyedAccount {
+            pubkey: SplSolValCalc::CALCULATOR_STATE_PDA,
+            account: state,
+        },
+        pool_state: jito_stake_pool,
+    }
+    .resolve::<SplSolValCalc>()
+    .unwrap();
+    let pool_config = keys.pool_config;
+
+    let ix = spl_set_withdrawal_fee_guard_ix(keys, args).unwrap();
+    let last_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, manager], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    pool_config
+}
+
+fn record_withdrawal_fees_ix(jito_stake_pool: &KeyedAccount) -> Instruction {
+    let (keys, _stake_pool) = RecordWithdrawalFeesFreeArgs {
+        pool_state: jito_stake_pool,
+    }
+    .resolve::<SplSolValCalc>()
+    .unwrap();
+    spl_record_withdrawal_fees_ix(keys).unwrap()
+}
+
+fn jito_lst_to_sol_ix(
+    jito_stake_pool: &KeyedAccount,
+    spl_stake_pool_prog: &KeyedAccount,
+    amount: u64,
+) -> Instruction {
+    let (intermediate, _stake_pool) = SplLstSolCommonFreeArgs {
+        spl_stake_pool: jito_stake_pool,
+        spl_stake_pool_prog,
+    }
+    .resolve_spl()
+    .unwrap();
+    let accounts: LstToSolKeys = intermediate.resolve::<SplSolValCalc>().unwrap().into();
+    spl_lst_to_sol_ix(accounts, LstToSolIxArgs { amount }).unwrap()
+}
+
+/// Overwrites the jito stake pool account with `stake_pool`
+fn set_jito_stake_pool(
+    ctx: &mut ProgramTestContext,
+    jito_stake_pool: &mut KeyedAccount,
+    stake_pool: &SplStakePool,
+) {
+    let mut data = jito_stake_pool.account.data.as_mut_slice();
+    stake_pool.serialize(&mut data).unwrap();
+    ctx.set_account(
+        &jito_stake_pool.pubkey,
+        &jito_stake_pool.account.clone().into(),
+    );
+}
+
+async fn exec_lst_to_sol(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    ix: Instruction,
+    stake_pool: &SplStakePool,
+    amount: u64,
+) {
+    let expected = SplStakePoolCalc::from(stake_pool)
+        .calc_lst_to_sol(amount)
+        .unwrap();
+    let last_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            payer,
+            last_blockhash,
+            ix,
+            expected,
+        )
+        .await;
+}
+
+async fn exec_lst_to_sol_expect_err(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    ix: Instruction,
+    expected: SplCalculatorError,
+) {
+    let last_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, expected);
+}
+
+#[tokio::test]
+async fn jito_fee_ceiling() {
+    let manager = Keypair::new();
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        spl_stake_pool_prog,
+    } = jito_program_test_with_manager(manager.pubkey());
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        payer,
+        ..
+    } = ctx;
+
+    let stake_pool = deserialize_stake_pool_checked(&jito_stake_pool).unwrap();
+    assert_eq!(stake_pool.stake_withdrawal_fee, JITO_STAKE_WITHDRAWAL_FEE);
+
+    set_withdrawal_fee_guard(
+        &mut banks_client,
+        &payer,
+        &manager,
+        &jito_stake_pool,
+        SetWithdrawalFeeGuardIxArgs {
+            fee_change_window_epochs: 0,
+            max_stake_withdrawal_fee: Fee {
+                denominator: 10_000,
+                numerator: 1,
+            },
+        },
+    )
+    .await;
+    exec_lst_to_sol_expect_err(
+        &mut banks_client,
+        &payer,
+        jito_lst_to_sol_ix(&jito_stake_pool, &spl_stake_pool_prog, LST_AMOUNT),
+        SplCalculatorError::WithdrawalFeeAboveCeiling,
+    )
+    .await;
+
+    // a fee equal to the ceiling is allowed
+    set_withdrawal_fee_guard(
+        &mut banks_client,
+        &payer,
+        &manager,
+        &jito_stake_pool,
+        SetWithdrawalFeeGuardIxArgs {
+            fee_change_window_epochs: 0,
+            max_stake_withdrawal_fee: JITO_STAKE_WITHDRAWAL_FEE,
+        },
+    )
+    .await;
+    exec_lst_to_sol(
+        &mut banks_client,
+        &payer,
+        jito_lst_to_sol_ix(&jito_stake_pool, &spl_stake_pool_prog, LST_AMOUNT + 1),
+        &stake_pool,
+        LST_AMOUNT + 1,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn jito_fee_change_window() {
+    const FEE_CHANGE_WINDOW_EPOCHS: u64 = 2;
+
+    let manager = Keypair::new();
+    let JitoNormalProgramTest {
+        program_test,
+        mut jito_stake_pool,
+        spl_stake_pool_prog,
+    } = jito_program_test_with_manager(manager.pubkey());
+
+    let mut ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let mut stake_pool = deserialize_stake_pool_checked(&jito_stake_pool).unwrap();
+
+    let pool_config = set_withdrawal_fee_guard(
+        &mut ctx.banks_client,
+        &ctx.payer,
+        &manager,
+        &jito_stake_pool,
+        SetWithdrawalFeeGuardIxArgs {
+            fee_change_window_epochs: FEE_CHANGE_WINDOW_EPOCHS,
+            max_stake_withdrawal_fee: Fee {
+                denominator: 0,
+                numerator: 0,
+            },
+        },
+    )
+    .await;
+    // fees acknowledged by the manager do not start a fee change window
+    exec_lst_to_sol(
+        &mut ctx.banks_client,
+        &ctx.payer,
+        jito_lst_to_sol_ix(&jito_stake_pool, &spl_stake_pool_prog, LST_AMOUNT),
+        &stake_pool,
+        LST_AMOUNT,
+    )
+    .await;
+
+    stake_pool.stake_withdrawal_fee = Fee {
+        denominator: 1_000,
+        numerator: 2,
+    };
+    set_jito_stake_pool(&mut ctx, &mut jito_stake_pool, &stake_pool);
+
+    // unrecorded fee change
+    exec_lst_to_sol_expect_err(
+        &mut ctx.banks_client,
+        &ctx.payer,
+        jito_lst_to_sol_ix(&jito_stake_pool, &spl_stake_pool_prog, LST_AMOUNT + 1),
+        SplCalculatorError::WithdrawalFeeChanged,
+    )
+    .await;
+
+    let ix = record_withdrawal_fees_ix(&jito_stake_pool);
+    let last_blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer], last_blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_config_acc = ctx.banks_client.get_account_unwrapped(pool_config).await;
+    let config = try_spl_pool_config(&pool_config_acc.data).unwrap();
+    assert_eq!(
+        config.last_fee_change_epoch,
+        JITO_STAKE_POOL_LAST_UPDATE_EPOCH
+    );
+    assert_eq!(
+        config.recorded_stake_withdrawal_fee,
+        stake_pool.stake_withdrawal_fee
+    );
+
+    // recorded fee change, still within window
+    exec_lst_to_sol_expect_err(
+        &mut ctx.banks_client,
+        &ctx.payer,
+        jito_lst_to_sol_ix(&jito_stake_pool, &spl_stake_pool_prog, LST_AMOUNT + 2),
+        SplCalculatorError::WithdrawalFeeChanged,
+    )
+    .await;
+
+    let epoch_after_window = JITO_STAKE_POOL_LAST_UPDATE_EPOCH + FEE_CHANGE_WINDOW_EPOCHS;
+    ctx.set_sysvar(&Clock {
+        epoch: epoch_after_window,
+        ..Default::default()
+    });
+    stake_pool.last_update_epoch = epoch_after_window;
+    set_jito_stake_pool(&mut ctx, &mut jito_stake_pool, &stake_pool);
+
+    exec_lst_to_sol(
+        &mut ctx.banks_client,
+        &ctx.payer,
+        jito_lst_to_sol_ix(&jito_stake_pool, &spl_stake_pool_prog, LST_AMOUNT + 3),
+        &stake_pool,
+        LST_AMOUNT + 3,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn jito_fail_record_uninitialized_pool_config() {
+    let JitoNormalProgramTest {
+        program_test,
+        jito_stake_pool,
+        ..
+    } = jito_program_test_with_manager(Pubkey::default());
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = record_withdrawal_fees_ix(&jito_stake_pool);
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SplCalculatorError::IncorrectPoolConfig);
+}
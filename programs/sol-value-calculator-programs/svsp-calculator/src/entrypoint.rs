@@ -0,0 +1,37 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use svsp_calculator_interface::SvspCalculatorProgramIx;
+
+use crate::processor::{
+    process_batch_calc, process_init, process_lst_to_sol, process_set_manager, process_sol_to_lst,
+    process_update_last_upgrade_slot,
+};
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if *program_id != svsp_calculator_lib::program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let ix = SvspCalculatorProgramIx::deserialize(instruction_data)?;
+    solana_program::msg!("{:?}", ix);
+
+    match ix {
+        SvspCalculatorProgramIx::LstToSol(args) => process_lst_to_sol(accounts, args),
+        SvspCalculatorProgramIx::SolToLst(args) => process_sol_to_lst(accounts, args),
+        SvspCalculatorProgramIx::BatchCalc(args) => process_batch_calc(accounts, args),
+        SvspCalculatorProgramIx::UpdateLastUpgradeSlot => {
+            process_update_last_upgrade_slot(accounts)
+        }
+        SvspCalculatorProgramIx::SetManager => process_set_manager(accounts),
+        SvspCalculatorProgramIx::Init => process_init(accounts),
+    }
+}
@@ -0,0 +1,8 @@
+use generic_pool_calculator_onchain::processor::{process_init_unchecked, verify_init};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use svsp_calculator_lib::{initial_manager, SvspSolValCalc};
+
+pub fn process_init(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let checked = verify_init::<SvspSolValCalc>(accounts)?;
+    process_init_unchecked::<SvspSolValCalc>(checked, initial_manager::ID)
+}
@@ -0,0 +1,33 @@
+use generic_pool_calculator_lib::utils::{
+    verify_no_stake_pool_prog_upgrade, VerifyNoStakePoolProgUpgradeArgs,
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sanctum_token_lib::mint_supply;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use svsp_calculator_interface::{lst_to_sol_verify_account_keys, LstToSolAccounts};
+use svsp_calculator_lib::{read_pool_stake_amount, SvspLstSolCommonFreeArgs, SvspPoolCalc};
+
+/// Assumes:
+/// - LstToSolAccounts/Keys and SolToLstAccounts/Keys are identical
+pub fn verify_lst_sol_common(accounts: &[AccountInfo<'_>]) -> Result<SvspPoolCalc, ProgramError> {
+    let actual: LstToSolAccounts = load_accounts(accounts)?;
+
+    let free_args = SvspLstSolCommonFreeArgs {
+        svsp_pool: actual.pool_state,
+        svsp_prog: actual.pool_program,
+    };
+    let expected = free_args.resolve()?.into();
+
+    lst_to_sol_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    // accounts should all be read-only, no need to verify_account_privileges
+
+    verify_no_stake_pool_prog_upgrade(VerifyNoStakePoolProgUpgradeArgs {
+        stake_pool_prog_data: actual.pool_program_data,
+        calculator_state: actual.state,
+    })?;
+
+    Ok(SvspPoolCalc {
+        pool_stake: read_pool_stake_amount(actual.pool_stake)?,
+        pool_token_supply: mint_supply(actual.lst_mint)?,
+    })
+}
@@ -0,0 +1,14 @@
+mod batch_calc;
+mod init;
+mod lst_sol_common;
+mod lst_to_sol;
+mod set_manager;
+mod sol_to_lst;
+mod update_last_upgrade_slot;
+
+pub use batch_calc::*;
+pub use init::*;
+pub use lst_to_sol::*;
+pub use set_manager::*;
+pub use sol_to_lst::*;
+pub use update_last_upgrade_slot::*;
@@ -0,0 +1,52 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use sanctum_solana_test_utils::{ExtendedProgramTest, KeyedUiAccount};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use svsp_calculator_lib::SvspSolValCalc;
+use test_utils::SVSP_PROG_LAST_UPDATED_SLOT;
+
+pub struct SvspNormalProgramTest {
+    pub program_test: ProgramTest,
+    pub svsp_pool: KeyedAccount,
+    pub svsp_prog: KeyedAccount,
+}
+
+pub fn svsp_normal_program_test() -> SvspNormalProgramTest {
+    let mut program_test = ProgramTest::default();
+    // name must match <name>.so filename
+    program_test.add_program(
+        "svsp_calculator",
+        svsp_calculator_lib::program::ID,
+        processor!(svsp_calculator::entrypoint::process_instruction),
+    );
+
+    let svsp_prog_ui_acc = KeyedUiAccount::from_test_fixtures_file("svsp-prog.json");
+    let svsp_pool_ui_acc = KeyedUiAccount::from_test_fixtures_file("svsp-pool.json");
+
+    let svsp_prog = svsp_prog_ui_acc.to_keyed_account();
+    let svsp_pool = svsp_pool_ui_acc.to_keyed_account();
+
+    program_test = program_test
+        .add_mock_calculator_state(MockCalculatorStateAccountArgs {
+            manager: Pubkey::default(),
+            last_upgrade_slot: SVSP_PROG_LAST_UPDATED_SLOT,
+            stale_discount_bps: Default::default(),
+            approved_program_data_hash: Default::default(),
+            owner: SvspSolValCalc::ID,
+        })
+        .add_keyed_ui_account(svsp_prog_ui_acc)
+        .add_keyed_ui_account(svsp_pool_ui_acc)
+        .add_test_fixtures_account("svsp-prog-data.json")
+        .add_test_fixtures_account("svsp-pool-mint.json")
+        .add_test_fixtures_account("svsp-pool-stake.json");
+
+    SvspNormalProgramTest {
+        program_test,
+        svsp_pool,
+        svsp_prog,
+    }
+}
@@ -0,0 +1,49 @@
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program_test::ProgramTestContext;
+use svsp_calculator_interface::{sol_to_lst_ix, SolToLstIxArgs, SolToLstKeys};
+use svsp_calculator_lib::SvspLstSolCommonFreeArgs;
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::{svsp_normal_program_test, SvspNormalProgramTest};
+
+#[tokio::test]
+async fn svsp_basic() {
+    const LAMPORTS_AMOUNT: u64 = 1_028_806_575;
+    const EXPECTED_LST_RANGE: U64ValueRange = U64ValueRange::single(1_000_000_000);
+
+    let SvspNormalProgramTest {
+        program_test,
+        svsp_pool,
+        svsp_prog,
+    } = svsp_normal_program_test();
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = program_test.start_with_context().await;
+
+    let free_args = SvspLstSolCommonFreeArgs {
+        svsp_pool,
+        svsp_prog,
+    };
+    let accounts: SolToLstKeys = free_args.resolve().unwrap().into();
+
+    let ix = sol_to_lst_ix(
+        accounts,
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LST_RANGE,
+        )
+        .await;
+}
@@ -1,14 +1,16 @@
 #![allow(unexpected_cfgs)]
 
 use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
-use sol_value_calculator_onchain::{process_lst_to_sol_unchecked, process_sol_to_lst_unchecked};
+use sol_value_calculator_onchain::{
+    process_batch_calc_unchecked, process_lst_to_sol_unchecked, process_sol_to_lst_unchecked,
+};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
     pubkey::Pubkey,
 };
 use wsol_calculator_interface::{
-    lst_to_sol_verify_account_keys, LstToSolAccounts, LstToSolIxArgs, SolToLstIxArgs,
-    WsolCalculatorProgramIx,
+    lst_to_sol_verify_account_keys, BatchCalcIxArgs, LstToSolAccounts, LstToSolIxArgs,
+    SolToLstIxArgs, WsolCalculatorProgramIx,
 };
 use wsol_calculator_lib::{WsolSolCalc, WSOL_LST_TO_SOL_KEYS};
 
@@ -24,7 +26,7 @@ pub fn process_instruction(
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    // Assumes account interfaces of the 2 instructions are the same
+    // Assumes account interfaces of the 3 instructions are the same
     let accounts: LstToSolAccounts = load_accounts(accounts)?;
     lst_to_sol_verify_account_keys(accounts, WSOL_LST_TO_SOL_KEYS)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -40,5 +42,9 @@ pub fn process_instruction(
         WsolCalculatorProgramIx::SolToLst(SolToLstIxArgs { amount }) => {
             process_sol_to_lst_unchecked(&WsolSolCalc, amount)
         }
+        WsolCalculatorProgramIx::BatchCalc(BatchCalcIxArgs {
+            lst_to_sol_amounts,
+            sol_to_lst_amounts,
+        }) => process_batch_calc_unchecked(&WsolSolCalc, &lst_to_sol_amounts, &sol_to_lst_amounts),
     }
 }
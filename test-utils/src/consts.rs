@@ -18,6 +18,12 @@ pub const LIDO_PROG_LAST_UPDATED_SLOT: u64 = 165_468_732;
 
 pub const LIDO_STATE_LAST_UPDATE_EPOCH: u64 = 543;
 
+pub const SVSP_PROG_LAST_UPDATED_SLOT: u64 = 254_486_155;
+
+pub const EVERSTAKE_STAKE_POOL_PROG_LAST_UPDATED_SLOT: u64 = 250_812_443;
+
+pub const EVERSOL_STAKE_POOL_LAST_UPDATE_EPOCH: u64 = 600;
+
 pub mod jito_stake_pool {
     sanctum_macros::declare_program_keys!("Jito4APyf642JPZPx3hGc6WWJ8zPKtRbRs4P815Awbb", []);
 }
@@ -25,3 +31,13 @@ pub mod jito_stake_pool {
 pub mod jitosol {
     sanctum_macros::declare_program_keys!("J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn", []);
 }
+
+/// Mock copy of the jito stake pool whose pool mint is [`jitosol_22`]
+pub mod jito_22_stake_pool {
+    sanctum_macros::declare_program_keys!("DNRiFEaC36HW2PjhQB25YjDkuoaPh8GHvQH6uXZWtaHs", []);
+}
+
+/// Mock Token-2022 LST mint with the TransferFeeConfig extension
+pub mod jitosol_22 {
+    sanctum_macros::declare_program_keys!("6AF3A3bckmxRk4MYBZnPmgqWwcCmo6Sa8fv9Lfp3YHow", []);
+}